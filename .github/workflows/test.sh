@@ -4,12 +4,17 @@ main() {
     local cargo=cross
 
     # all features except those that sometimes should be skipped.
-    local features="--features std,more-image-formats,image-shrinking,rendering,svg-rendering,default-text-engine,font-loading"
+    local features="--features std,more-image-formats,image-shrinking,rendering,svg-rendering,default-text-engine,font-loading,gzip,zip-import,async,terminal-rendering"
 
     if [ "$SKIP_CROSS" = "skip" ]; then
         cargo=cargo
     fi
 
+    # wgpu only supports the targets that get tested natively.
+    if [ "$SKIP_CROSS" = "skip" ] && [ "$TARGET" != "wasm32-wasip1" ]; then
+        features="$features,wgpu-rendering"
+    fi
+
     if [ "$SKIP_AUTO_SPLITTING" != "skip" ]; then
         features="$features,auto-splitting"
     fi
@@ -31,6 +36,7 @@ main() {
 
     $cargo test -p livesplit-core $features --target $TARGET
     $cargo test -p livesplit-core --no-default-features --features std --target $TARGET
+    $cargo test -p livesplit-core --no-default-features --features std,parsers --target $TARGET
 }
 
 main
@@ -4,7 +4,7 @@ export type ComponentStateJson =
     { DetailedTimer: DetailedTimerComponentStateJson } |
     { Graph: GraphComponentStateJson } |
     { KeyValue: KeyValueComponentStateJson } |
-    { Separator: null } |
+    { Separator: SeparatorComponentStateJson } |
     { Splits: SplitsComponentStateJson } |
     { Text: TextComponentStateJson } |
     { Timer: TimerComponentStateJson } |
@@ -72,8 +72,19 @@ export interface BackgroundImage {
      * sigma = BLUR_FACTOR * blur * max(width, height)
      */
     blur: number,
+    /** Describes how the image is fit into the background. */
+    fit: BackgroundImageFit,
 }
 
+/**
+ * Describes how a background image is fit into the background. `Cover` scales
+ * the image to fill the full background while preserving its aspect ratio.
+ * `Contain` scales the image to fit into the background while preserving its
+ * aspect ratio. `Stretch` stretches the image to fill the full background
+ * without preserving its aspect ratio.
+ */
+export type BackgroundImageFit = "Cover" | "Contain" | "Stretch";
+
 /** The background of a layout. */
 export type LayoutBackground = Gradient | BackgroundImage;
 
@@ -259,6 +270,16 @@ export enum Event {
     LoadingTimesSet = 16,
     /** A custom variable has been set. */
     CustomVariableSet = 17,
+    /** The timer has been armed to start at a specific point in time. */
+    StartScheduled = 18,
+    /** The scheduled start of the timer has been cancelled. */
+    ScheduledStartCancelled = 19,
+    /** The game started loading and the game time has been paused. */
+    LoadingStarted = 20,
+    /** The game stopped loading and the game time has been resumed. */
+    LoadingEnded = 21,
+    /** The time of the last split has been adjusted. */
+    SplitAdjusted = 22,
 }
 
 /** An error that occurred when a command was being processed. */
@@ -297,6 +318,24 @@ export enum CommandError {
     TimerPaused = -16,
     /** The runner decided to not reset the run. */
     RunnerDecidedAgainstReset = -17,
+    /** There is no scheduled start. */
+    NoStartScheduled = -18,
+    /**
+     * The split happened too soon after the previous split or the start of
+     * the attempt and got rejected.
+     */
+    SplitTooSoon = -19,
+    /** The game is already loading. */
+    AlreadyLoading = -20,
+    /** The game is not loading. */
+    NotLoading = -21,
+    /**
+     * There is no split that can be adjusted, either because there is no
+     * split yet or because the correction window has passed.
+     */
+    SplitCorrectionWindowClosed = -22,
+    /** The split time can't be moved past the current time. */
+    SplitTimeInFuture = -23,
 }
 
 /** The result of a command that was processed. */
@@ -317,6 +356,25 @@ export interface BlankSpaceComponentStateJson {
     size: number,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface SeparatorComponentStateJson {
+    /**
+     * The thickness of the separator in pixels. A thickness of 0 means that
+     * the default thickness of separators should be used.
+     */
+    thickness: number,
+    /**
+     * The color of the separator. If null is specified, the color of the
+     * separators specified by the layout should be used.
+     */
+    color: Color | null,
+    /**
+     * The color the separator fades into along its length. If null is
+     * specified, the separator should be drawn with a single color.
+     */
+    gradient_color: Color | null,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface TimerComponentStateJson {
     /** The background shown behind the component. */
@@ -391,6 +449,11 @@ export interface TitleComponentStateJson {
 export interface SplitsComponentStateJson {
     /** The background shown behind the splits. */
     background: ListGradient,
+    /**
+     * Specifies whether the background alternates between two colors for the
+     * odd and even rows.
+     */
+    show_alternating_backgrounds: boolean,
     /**
      * The column labels to visualize about the list of splits. If this is
      * `null`, no labels are supposed to be visualized. The list is specified
@@ -406,6 +469,12 @@ export interface SplitsComponentStateJson {
      * splits component.
      */
     has_icons: boolean,
+    /**
+     * The size the icons are supposed to be shown at in pixels. If this is 0,
+     * the icons are supposed to fill the height of the rows. The icons should
+     * never exceed the height of the rows.
+     */
+    icon_size: number,
     /**
      * Specifies whether thin separators should be shown between the individual
      * segments shown by the component.
@@ -448,6 +517,31 @@ export interface SplitStateJson {
      * on.
      */
     is_current_split: boolean,
+    /**
+     * Describes if this row doesn't show a segment, but is blank space that
+     * fills up the list because there are not enough segments to fill it.
+     * Blank space has no icon, name or columns.
+     */
+    is_blank_space: boolean,
+    /**
+     * Describes if this row doesn't show a segment, but is the header of the
+     * section the active attempt is currently on. Section headers only have a
+     * name, but no icon or columns.
+     */
+    is_section_header: boolean,
+    /**
+     * The nesting level of the row. Subsplits of the section the active
+     * attempt is currently on are nested one level deeper than all the other
+     * rows.
+     */
+    nesting_level: number,
+    /**
+     * Describes if this row is an odd row of the list, which is meant to be
+     * used for alternating backgrounds. The rows are counted based on all the
+     * rows of the list, not just the ones that are shown, so the rows keep
+     * their backgrounds while the list scrolls.
+     */
+    is_odd_row: boolean,
     /**
      * The index of the segment based on all the segments of the run. This may
      * differ from the index of this `SplitStateJson` in the
@@ -500,6 +594,11 @@ export interface KeyValueComponentStateJson {
      * of the key, if there is not enough space to show the whole key.
      */
     key_abbreviations: string[],
+    /**
+     * Additional keys and values to visualize below the key and the value.
+     * Each of them is shown in a row of its own.
+     */
+    additional_rows: KeyValueComponentStateRowJson[],
     /**
      * Specifies whether to display the name of the component and its value in
      * two separate rows.
@@ -512,6 +611,22 @@ export interface KeyValueComponentStateJson {
     updates_frequently: boolean,
 }
 
+/**
+ * An additional key and value that a key value based component visualizes
+ * below its main key and value.
+ */
+export interface KeyValueComponentStateRowJson {
+    /** The key to visualize. */
+    key: string,
+    /** The value to visualize. */
+    value: string,
+    /**
+     * Specifies additional abbreviations for the key that can be used instead
+     * of the key, if there is not enough space to show the whole key.
+     */
+    key_abbreviations: string[],
+}
+
 /**
  * The state object describes the information to visualize for this component.
  * All the coordinates are in the range 0..1.
@@ -573,6 +688,31 @@ export interface GraphComponentStateJson {
     best_segment_color: Color,
     /** The height of the graph. */
     height: number,
+    /**
+     * Describes whether the points of the graph are supposed to be connected
+     * by smooth curves instead of straight lines.
+     */
+    smooth_lines: boolean,
+    /**
+     * The series of points for each of the additional comparisons. They use
+     * the same coordinate system as the main graph.
+     */
+    additional_series: GraphComponentStateSeriesJson[],
+}
+
+/**
+ * Describes a series of points that visualizes how far the current attempt has
+ * been ahead or behind an additional comparison.
+ */
+export interface GraphComponentStateSeriesJson {
+    /**
+     * All of the series' points. Connect them to visualize the series. Unlike
+     * the main graph, the region beneath the series is not supposed to be
+     * filled.
+     */
+    points: GraphComponentStatePointJson[],
+    /** The color of the lines connecting the series' points. */
+    color: Color,
 }
 
 /** Describes a point on the graph to visualize. */
@@ -641,6 +781,12 @@ export interface DetailedTimerComponentStateJson {
      * is no icon.
      */
     icon: ImageId,
+    /**
+     * The size the icon is supposed to be shown at in pixels. If this is 0,
+     * the icon is supposed to fill the height of the component. The icon
+     * should never exceed the height of the component.
+     */
+    icon_size: number,
     /**
      * The color of the segment name if it's shown. If `null` is specified, the
      * color is taken from the layout.
@@ -754,6 +900,7 @@ export type SettingsDescriptionValueJson =
     { ColumnUpdateWith: ColumnUpdateWith } |
     { ColumnUpdateTrigger: ColumnUpdateTrigger } |
     { Hotkey: string } |
+    { HotkeyGesture: HotkeyGesture } |
     { LayoutDirection: LayoutDirection } |
     { Font: Font | null } |
     { DeltaGradient: DeltaGradient } |
@@ -766,6 +913,9 @@ export type ColumnKind = "Time" | "Variable";
 /** Represents the possible backgrounds for a timer. */
 export type DeltaGradient = Gradient | "DeltaPlain" | "DeltaVertical" | "DeltaHorizontal";
 
+/** Describes how a hotkey needs to be pressed to trigger its action. */
+export type HotkeyGesture = "Press" | "Hold" | "DoublePress";
+
 /** Describes the direction the components of a layout are laid out in. */
 export type LayoutDirection = "Vertical" | "Horizontal";
 
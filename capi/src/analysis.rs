@@ -1,11 +1,15 @@
 //! The analysis module provides a variety of functions for calculating
 //! information about runs.
 
-use super::time_span::{NullableOwnedTimeSpan, OwnedTimeSpan};
+use super::{
+    output_str,
+    time_span::{NullableOwnedTimeSpan, OwnedTimeSpan},
+};
 use livesplit_core::{
-    analysis::{sum_of_segments::calculate_best, total_playtime::calculate},
+    analysis::{sum_of_segments::calculate_best, summary, total_playtime::calculate},
     Run, Timer, TimingMethod,
 };
+use std::os::raw::c_char;
 
 /// Calculates the Sum of Best Segments for the timing method provided. This is
 /// the fastest time possible to complete a run of a category, based on
@@ -38,3 +42,13 @@ pub extern "C" fn Analysis_calculate_total_playtime_for_run(run: &Run) -> OwnedT
 pub extern "C" fn Analysis_calculate_total_playtime_for_timer(timer: &Timer) -> OwnedTimeSpan {
     Box::new(calculate(timer))
 }
+
+/// Calculates a concise textual summary of the current state of the Timer,
+/// such as `Segment 5 of 20, 12.3 seconds ahead of Personal Best, best
+/// possible time 1:02:11`. This is suitable for screen readers and text to
+/// speech integrations. The summary only describes the splits, so it only
+/// changes on split events.
+#[no_mangle]
+pub extern "C" fn Analysis_summary_for_timer(timer: &Timer) -> *const c_char {
+    output_str(summary::calculate(&timer.snapshot()))
+}
@@ -58,6 +58,8 @@ pub(crate) trait CommandSinkAndQuery: Send + Sync + 'static {
     fn dyn_pause_game_time(&self) -> Fut;
     fn dyn_resume_game_time(&self) -> Fut;
     fn dyn_set_loading_times(&self, time: TimeSpan) -> Fut;
+    fn dyn_start_loading(&self) -> Fut;
+    fn dyn_end_loading(&self) -> Fut;
     fn dyn_set_custom_variable(&self, name: Cow<'_, str>, value: Cow<'_, str>) -> Fut;
 }
 
@@ -131,6 +133,12 @@ where
     fn dyn_set_loading_times(&self, time: TimeSpan) -> Fut {
         Box::pin(self.set_loading_times(time))
     }
+    fn dyn_start_loading(&self) -> Fut {
+        Box::pin(self.start_loading())
+    }
+    fn dyn_end_loading(&self) -> Fut {
+        Box::pin(self.end_loading())
+    }
     fn dyn_set_custom_variable(&self, name: Cow<'_, str>, value: Cow<'_, str>) -> Fut {
         Box::pin(self.set_custom_variable(name, value))
     }
@@ -223,6 +231,14 @@ impl event::CommandSink for CommandSink {
         self.0.dyn_set_loading_times(time)
     }
 
+    fn start_loading(&self) -> impl Future<Output = Result> + 'static {
+        self.0.dyn_start_loading()
+    }
+
+    fn end_loading(&self) -> impl Future<Output = Result> + 'static {
+        self.0.dyn_end_loading()
+    }
+
     fn set_custom_variable(
         &self,
         name: Cow<'_, str>,
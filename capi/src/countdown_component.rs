@@ -0,0 +1,56 @@
+//! The Countdown Component shows how much time is remaining until the attempt
+//! begins. Once the attempt begins, the component either hides itself or
+//! switches to showing the time of the attempt.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::timer_component_state::OwnedTimerComponentState;
+use livesplit_core::component::countdown::Component as CountdownComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedCountdownComponent = Box<CountdownComponent>;
+
+/// Creates a new Countdown Component.
+#[no_mangle]
+pub extern "C" fn CountdownComponent_new() -> OwnedCountdownComponent {
+    Box::new(CountdownComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CountdownComponent_drop(this: OwnedCountdownComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn CountdownComponent_into_generic(this: OwnedCountdownComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn CountdownComponent_state_as_json(
+    this: &CountdownComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot(), layout_settings)
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
+#[no_mangle]
+pub extern "C" fn CountdownComponent_state(
+    this: &CountdownComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedTimerComponentState {
+    Box::new(this.state(&timer.snapshot(), layout_settings))
+}
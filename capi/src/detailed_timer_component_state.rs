@@ -140,6 +140,13 @@ pub extern "C" fn DetailedTimerComponentState_icon(
     output_str(this.icon.format_str(&mut [0; 64]))
 }
 
+/// The size the icon is supposed to be shown at in pixels. If this is 0, the
+/// icon is supposed to fill the height of the component.
+#[no_mangle]
+pub extern "C" fn DetailedTimerComponentState_icon_size(this: &DetailedTimerComponentState) -> u32 {
+    this.icon_size
+}
+
 /// The name of the segment. This may be <NULL> if it's not supposed to be
 /// visualized.
 #[no_mangle]
@@ -46,6 +46,44 @@ pub extern "C" fn GraphComponentState_point_is_best_segment(
     this.points[index].is_best_segment
 }
 
+/// Returns the amount of series of points for the additional comparisons.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_additional_series_len(this: &GraphComponentState) -> usize {
+    this.additional_series.len()
+}
+
+/// Returns the amount of points of the additional series specified. Connect all
+/// of them to visualize the series. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_additional_series_points_len(
+    this: &GraphComponentState,
+    series_index: usize,
+) -> usize {
+    this.additional_series[series_index].points.len()
+}
+
+/// Returns the x coordinate of the point of the additional series specified.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_additional_series_point_x(
+    this: &GraphComponentState,
+    series_index: usize,
+    index: usize,
+) -> f32 {
+    this.additional_series[series_index].points[index].x
+}
+
+/// Returns the y coordinate of the point of the additional series specified.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_additional_series_point_y(
+    this: &GraphComponentState,
+    series_index: usize,
+    index: usize,
+) -> f32 {
+    this.additional_series[series_index].points[index].y
+}
+
 /// Describes how many horizontal grid lines to visualize.
 #[no_mangle]
 pub extern "C" fn GraphComponentState_horizontal_grid_lines_len(
@@ -102,3 +140,10 @@ pub extern "C" fn GraphComponentState_is_live_delta_active(this: &GraphComponent
 pub extern "C" fn GraphComponentState_is_flipped(this: &GraphComponentState) -> bool {
     this.is_flipped
 }
+
+/// Describes whether the points of the graph are supposed to be connected by
+/// smooth curves instead of straight lines.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_smooth_lines(this: &GraphComponentState) -> bool {
+    this.smooth_lines
+}
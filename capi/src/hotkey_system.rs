@@ -4,10 +4,13 @@
 //! out on platforms that don't support hotkeys. You can turn off a Hotkey
 //! System temporarily. By default the Hotkey System is activated.
 
-use std::{os::raw::c_char, str::FromStr};
+use std::{os::raw::c_char, ptr, str::FromStr};
 
-use crate::{command_sink::CommandSink, hotkey_config::OwnedHotkeyConfig, output_str, str};
-use livesplit_core::hotkey::KeyCode;
+use crate::{
+    command_sink::CommandSink, hotkey_config::OwnedHotkeyConfig, output_str,
+    setting_value::OwnedSettingValue, str, Nullablec_char,
+};
+use livesplit_core::{hotkey::KeyCode, Run, SuppressionMode};
 
 type HotkeySystem = livesplit_core::HotkeySystem<CommandSink>;
 
@@ -54,6 +57,70 @@ pub extern "C" fn HotkeySystem_activate(this: &mut HotkeySystem) -> bool {
     this.activate().is_ok()
 }
 
+/// Returns <TRUE> if the Hotkey System is active, <FALSE> otherwise.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_is_active(this: &HotkeySystem) -> bool {
+    this.is_active()
+}
+
+/// Returns <TRUE> if the hotkeys are currently suppressed, either because a
+/// text input is focused or because a suppressing process is in the
+/// foreground.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_is_suppressed(this: &HotkeySystem) -> bool {
+    this.is_suppressed()
+}
+
+/// Marks whether a text input of the application is focused. While it is, the
+/// hotkeys are suppressed, so typing doesn't trigger any actions.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_set_text_input_focused(this: &mut HotkeySystem, is_focused: bool) {
+    this.set_text_input_focused(is_focused);
+}
+
+/// Reports the name of the process that is currently in the foreground. While
+/// it is one of the suppressing processes, the hotkeys are suppressed. <NULL>
+/// or an empty name means that the foreground process is unknown.
+#[no_mangle]
+pub unsafe extern "C" fn HotkeySystem_set_foreground_process(
+    this: &mut HotkeySystem,
+    name: *const c_char,
+) {
+    this.set_foreground_process(Some(str(name)).filter(|name| !name.is_empty()));
+}
+
+/// Adds a process that suppresses the hotkeys while it is in the foreground.
+/// The names of the processes are compared case-insensitively.
+#[no_mangle]
+pub unsafe extern "C" fn HotkeySystem_add_suppressing_process(
+    this: &mut HotkeySystem,
+    name: *const c_char,
+) {
+    this.add_suppressing_process(str(name));
+}
+
+/// Removes a process that suppresses the hotkeys while it is in the
+/// foreground. Returns <FALSE> if it wasn't one of the suppressing processes.
+#[no_mangle]
+pub unsafe extern "C" fn HotkeySystem_remove_suppressing_process(
+    this: &mut HotkeySystem,
+    name: *const c_char,
+) -> bool {
+    this.remove_suppressing_process(str(name))
+}
+
+/// Sets whether the hotkeys that are pressed while suppressed are queued up
+/// and carried out once the hotkeys are no longer suppressed, instead of being
+/// discarded.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_set_queue_suppressed_hotkeys(this: &mut HotkeySystem, queue: bool) {
+    this.set_suppression_mode(if queue {
+        SuppressionMode::Queue
+    } else {
+        SuppressionMode::Discard
+    });
+}
+
 /// Returns the hotkey configuration currently in use by the Hotkey System.
 #[no_mangle]
 pub extern "C" fn HotkeySystem_config(this: &HotkeySystem) -> OwnedHotkeyConfig {
@@ -72,6 +139,92 @@ pub extern "C" fn HotkeySystem_set_config(
     this.set_config(*config).is_ok()
 }
 
+/// Sets a setting's value of the hotkey configuration in use by its index to
+/// the given value. Only the hotkey affected by the setting is registered
+/// again. <FALSE> is returned if the hotkey is already in use by a different
+/// action or can't be registered.
+///
+/// This panics if the type of the value to be set is not compatible with the
+/// type of the setting's value. A panic can also occur if the index of the
+/// setting provided is out of bounds.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_set_value(
+    this: &mut HotkeySystem,
+    index: usize,
+    value: OwnedSettingValue,
+) -> bool {
+    this.set_value(index, *value).is_ok()
+}
+
+/// Applies a new hotkey configuration as the configuration that is used when no
+/// named profile is active. Returns <FALSE> if the operation failed.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_set_default_config(
+    this: &mut HotkeySystem,
+    config: OwnedHotkeyConfig,
+) -> bool {
+    this.set_default_config(*config).is_ok()
+}
+
+/// Returns the name of the profile that is currently in use. If the default
+/// hotkeys are in use, <NULL> is returned.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_active_profile(this: &HotkeySystem) -> *const Nullablec_char {
+    this.active_profile().map_or_else(ptr::null, output_str)
+}
+
+/// Stores a hotkey configuration as the profile with the name provided. If the
+/// profile is currently in use, the hotkeys get registered again. Returns
+/// <FALSE> if the operation failed.
+#[no_mangle]
+pub unsafe extern "C" fn HotkeySystem_set_profile(
+    this: &mut HotkeySystem,
+    name: *const c_char,
+    config: OwnedHotkeyConfig,
+) -> bool {
+    this.set_profile(str(name), *config).is_ok()
+}
+
+/// Removes the profile with the name provided. If the profile is currently in
+/// use, the Hotkey System switches back to the default hotkeys first. Returns
+/// <FALSE> if there was no such profile or the operation failed.
+#[no_mangle]
+pub unsafe extern "C" fn HotkeySystem_remove_profile(
+    this: &mut HotkeySystem,
+    name: *const c_char,
+) -> bool {
+    this.remove_profile(str(name)).unwrap_or_default()
+}
+
+/// Switches to the profile with the name provided. If there is no profile with
+/// that name, the default hotkeys are used instead. Returns <FALSE> if the
+/// hotkeys couldn't be registered.
+#[no_mangle]
+pub unsafe extern "C" fn HotkeySystem_switch_to_profile(
+    this: &mut HotkeySystem,
+    name: *const c_char,
+) -> bool {
+    this.switch_to_profile(str(name)).is_ok()
+}
+
+/// Switches back to the default hotkeys that are used when no named profile is
+/// active. Returns <FALSE> if the hotkeys couldn't be registered.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_switch_to_default_profile(this: &mut HotkeySystem) -> bool {
+    this.switch_to_default_profile().is_ok()
+}
+
+/// Switches to the profile that is named after the game of the Run provided.
+/// If there is no profile for the game, the default hotkeys are used. Returns
+/// <FALSE> if the hotkeys couldn't be registered.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_switch_to_profile_for_run(
+    this: &mut HotkeySystem,
+    run: &Run,
+) -> bool {
+    this.switch_to_profile_for_run(run).is_ok()
+}
+
 /// Resolves the key according to the current keyboard layout.
 #[no_mangle]
 pub unsafe extern "C" fn HotkeySystem_resolve(
@@ -33,3 +33,31 @@ pub extern "C" fn KeyValueComponentState_semantic_color(
 ) -> *const c_char {
     output_vec(|f| write!(f, "{:?}", this.semantic_color).unwrap())
 }
+
+/// The amount of additional rows to visualize below the key and the value.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_additional_rows_len(
+    this: &KeyValueComponentState,
+) -> usize {
+    this.additional_rows.len()
+}
+
+/// The key of the additional row with the specified index. You may not provide
+/// an out of bounds index.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_additional_row_key(
+    this: &KeyValueComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.additional_rows[index].key)
+}
+
+/// The value of the additional row with the specified index. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn KeyValueComponentState_additional_row_value(
+    this: &KeyValueComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.additional_rows[index].value)
+}
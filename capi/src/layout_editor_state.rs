@@ -79,6 +79,23 @@ pub extern "C" fn LayoutEditorState_field_text(
     }
 }
 
+/// Returns the tooltip of the layout's setting at the specified index. The
+/// tooltip describes what the setting does.
+///
+/// Set `component_settings` to true to use the selected component's settings instead.
+#[no_mangle]
+pub extern "C" fn LayoutEditorState_field_tooltip(
+    this: &LayoutEditorState,
+    component_settings: bool,
+    index: usize,
+) -> *const c_char {
+    if component_settings {
+        output_str(&this.component_settings.fields[index].tooltip)
+    } else {
+        output_str(&this.general_settings.fields[index].tooltip)
+    }
+}
+
 /// Returns the value of the layout's setting at the specified index.
 ///
 /// Set `component_settings` to true to use the selected component's settings instead.
@@ -94,3 +111,51 @@ pub extern "C" fn LayoutEditorState_field_value(
         &this.general_settings.fields[index].value
     }
 }
+
+/// Returns the name of the type of the layout's setting at the specified
+/// index. This is the same name that the setting's value is tagged with in
+/// its JSON representation.
+///
+/// Set `component_settings` to true to use the selected component's settings instead.
+#[no_mangle]
+pub extern "C" fn LayoutEditorState_field_type(
+    this: &LayoutEditorState,
+    component_settings: bool,
+    index: usize,
+) -> *const c_char {
+    output_str(LayoutEditorState_field_value(this, component_settings, index).type_name())
+}
+
+/// Returns the number of values that the layout's setting at the specified
+/// index may be set to. This is 0 if the setting isn't restricted to a fixed
+/// set of values.
+///
+/// Set `component_settings` to true to use the selected component's settings instead.
+#[no_mangle]
+pub extern "C" fn LayoutEditorState_field_allowed_values_len(
+    this: &LayoutEditorState,
+    component_settings: bool,
+    index: usize,
+) -> usize {
+    LayoutEditorState_field_value(this, component_settings, index)
+        .allowed_values()
+        .len()
+}
+
+/// Returns the name of one of the values that the layout's setting at the
+/// specified index may be set to. The name can be used to create the setting
+/// value, such as with `SettingValue_from_accuracy`.
+///
+/// Set `component_settings` to true to use the selected component's settings instead.
+#[no_mangle]
+pub extern "C" fn LayoutEditorState_field_allowed_value(
+    this: &LayoutEditorState,
+    component_settings: bool,
+    index: usize,
+    value_index: usize,
+) -> *const c_char {
+    output_str(
+        LayoutEditorState_field_value(this, component_settings, index).allowed_values()
+            [value_index],
+    )
+}
@@ -28,6 +28,7 @@ pub mod blank_space_component;
 pub mod blank_space_component_state;
 pub mod command_sink;
 pub mod component;
+pub mod countdown_component;
 pub mod current_comparison_component;
 pub mod current_pace_component;
 pub mod delta_component;
@@ -63,6 +64,7 @@ pub mod segment_history;
 pub mod segment_history_element;
 pub mod segment_history_iter;
 pub mod segment_time_component;
+pub mod segment_tracker_component;
 pub mod separator_component;
 pub mod separator_component_state;
 #[cfg(all(target_family = "wasm", feature = "wasm-web"))]
@@ -74,6 +76,7 @@ pub mod splits_component;
 pub mod splits_component_state;
 pub mod sum_of_best_cleaner;
 pub mod sum_of_best_component;
+pub mod svg_renderer;
 pub mod text_component;
 pub mod text_component_state;
 pub mod time;
@@ -90,6 +93,7 @@ pub mod total_playtime_component;
 pub mod web_command_sink;
 #[cfg(all(target_family = "wasm", feature = "web-rendering"))]
 pub mod web_rendering;
+pub mod world_record_component;
 
 use crate::{
     run_metadata_custom_variable::RunMetadataCustomVariable,
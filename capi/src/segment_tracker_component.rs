@@ -0,0 +1,53 @@
+//! The Segment Tracker Component shows how many of the segments of the run
+//! have already been completed in the current attempt, out of all the segments
+//! of the run. Alternatively it can show how many segments are remaining.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::segment_tracker::Component as SegmentTrackerComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedSegmentTrackerComponent = Box<SegmentTrackerComponent>;
+
+/// Creates a new Segment Tracker Component.
+#[no_mangle]
+pub extern "C" fn SegmentTrackerComponent_new() -> OwnedSegmentTrackerComponent {
+    Box::new(SegmentTrackerComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentTrackerComponent_drop(this: OwnedSegmentTrackerComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SegmentTrackerComponent_into_generic(
+    this: OwnedSegmentTrackerComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SegmentTrackerComponent_state_as_json(
+    this: &SegmentTrackerComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn SegmentTrackerComponent_state(
+    this: &SegmentTrackerComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(timer))
+}
@@ -1,6 +1,7 @@
 //! The Separator Component is a simple component that only serves to render
 //! separators between components.
 
+use super::{output_vec, Json};
 use crate::component::OwnedComponent;
 use crate::separator_component_state::OwnedSeparatorComponentState;
 use livesplit_core::component::separator::Component as SeparatorComponent;
@@ -27,6 +28,14 @@ pub extern "C" fn SeparatorComponent_into_generic(this: OwnedSeparatorComponent)
     Box::new((*this).into())
 }
 
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SeparatorComponent_state_as_json(this: &mut SeparatorComponent) -> Json {
+    output_vec(|o| {
+        this.state().write_json(o).unwrap();
+    })
+}
+
 /// Calculates the component's state.
 #[no_mangle]
 pub extern "C" fn SeparatorComponent_state(
@@ -10,3 +10,10 @@ pub type OwnedSeparatorComponentState = Box<SeparatorComponentState>;
 pub extern "C" fn SeparatorComponentState_drop(this: OwnedSeparatorComponentState) {
     drop(this);
 }
+
+/// The thickness of the separator in pixels. A thickness of 0 means that the
+/// default thickness of separators should be used.
+#[no_mangle]
+pub extern "C" fn SeparatorComponentState_thickness(this: &SeparatorComponentState) -> u32 {
+    this.thickness
+}
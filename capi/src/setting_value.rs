@@ -9,8 +9,9 @@ use livesplit_core::{
     },
     layout::LayoutDirection,
     settings::{
-        Alignment, BackgroundImage, Color, ColumnKind, Font, FontStretch, FontStyle, FontWeight,
-        Gradient, ImageId, LayoutBackground, ListGradient, Value as SettingValue,
+        Alignment, BackgroundImage, BackgroundImageFit, Color, ColumnKind, Font, FontStretch,
+        FontStyle, FontWeight, Gradient, HotkeyGesture, ImageId, LayoutBackground, ListGradient,
+        Value as SettingValue,
     },
     timing::formatter::{Accuracy, DigitsFormat},
     TimingMethod,
@@ -302,6 +303,22 @@ pub unsafe extern "C" fn SettingValue_from_column_update_trigger(
     Some(Box::new(value.into()))
 }
 
+/// Creates a new setting value from the hotkey gesture. If it doesn't match a
+/// known hotkey gesture, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_hotkey_gesture(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Press" => HotkeyGesture::Press,
+        "Hold" => HotkeyGesture::Hold,
+        "DoublePress" => HotkeyGesture::DoublePress,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
 /// Creates a new setting value from the layout direction. If it doesn't
 /// match a known layout direction, <NULL> is returned.
 #[no_mangle]
@@ -387,21 +404,30 @@ pub unsafe extern "C" fn SettingValue_from_delta_gradient(
 }
 
 /// Creates a new setting value from the background image with the image ID and
-/// the brightness, opacity, and blur provided. If the image ID is invalid,
-/// <NULL> is returned.
+/// the brightness, opacity, blur, and fit provided. The fit is either `Cover`,
+/// `Contain`, or `Stretch`. If the image ID or the fit is invalid, <NULL> is
+/// returned.
 #[no_mangle]
 pub unsafe extern "C" fn SettingValue_from_background_image(
     image_id: *const c_char,
     brightness: f32,
     opacity: f32,
     blur: f32,
+    fit: *const c_char,
 ) -> NullableOwnedSettingValue {
+    let fit = match str(fit) {
+        "Cover" => BackgroundImageFit::Cover,
+        "Contain" => BackgroundImageFit::Contain,
+        "Stretch" => BackgroundImageFit::Stretch,
+        _ => return None,
+    };
     Some(Box::new(
         LayoutBackground::Image(BackgroundImage {
             image: ImageId::from_str(str(image_id)).ok()?,
             brightness,
             opacity,
             blur,
+            fit,
         })
         .into(),
     ))
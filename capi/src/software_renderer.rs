@@ -1,22 +1,44 @@
 //! The software renderer allows rendering layouts entirely on the CPU. This is
 //! surprisingly fast and can be considered the default renderer.
 
-use livesplit_core::{layout::LayoutState, settings::ImageCache};
+use livesplit_core::{layout::LayoutState, settings::ImageCache, TimeSpan};
 
 #[cfg(feature = "software-rendering")]
-use livesplit_core::rendering::software::BorrowedRenderer as SoftwareRenderer;
+use livesplit_core::rendering::{
+    software::BorrowedRenderer as SoftwareRenderer, BackgroundMode, Rotation,
+};
 
-use crate::slice_mut;
+use crate::{slice, slice_mut};
 
 #[cfg(not(feature = "software-rendering"))]
 /// dummy
 pub struct SoftwareRenderer;
 #[cfg(not(feature = "software-rendering"))]
+enum BackgroundMode {
+    Layout,
+    Transparent,
+    ChromaKey(#[allow(dead_code)] [f32; 4]),
+}
+#[cfg(not(feature = "software-rendering"))]
+enum Rotation {
+    None,
+    Clockwise,
+    CounterClockwise,
+}
+#[cfg(not(feature = "software-rendering"))]
 impl SoftwareRenderer {
     fn new() -> Self {
         panic!("The software renderer is not compiled in.")
     }
 
+    fn load_font_data(&mut self, _: Vec<u8>) {}
+
+    fn set_background_mode(&mut self, _: BackgroundMode) {}
+
+    fn set_animation_duration(&mut self, _: TimeSpan) {}
+
+    fn set_rotation(&mut self, _: Rotation) {}
+
     fn render(
         &mut self,
         _: &LayoutState,
@@ -51,8 +73,13 @@ pub extern "C" fn SoftwareRenderer_drop(this: OwnedSoftwareRenderer) {
 /// 128x64. In that case you provide the real dimensions of 100x50 as the width
 /// and height, but a stride of 128 pixels as that correlates with the real
 /// width of the underlying buffer. By default the renderer will try not to
-/// redraw parts of the image that haven't changed. You can force a redraw in
-/// case the image provided or its contents have changed.
+/// redraw parts of the image that haven't changed. Only the regions of the
+/// components that changed are redrawn and if nothing changed at all, the image
+/// isn't touched. This assumes that the image provided is the same one as in
+/// the previous call and still contains the previous frame. If that's not the
+/// case, such as when swapping between multiple buffers, you need to force a
+/// redraw. The same applies if the image's contents have changed in any other
+/// way.
 #[no_mangle]
 pub unsafe extern "C" fn SoftwareRenderer_render(
     this: &mut SoftwareRenderer,
@@ -73,3 +100,71 @@ pub unsafe extern "C" fn SoftwareRenderer_render(
         force_redraw,
     );
 }
+
+/// Loads the font data provided, such as the contents of a TrueType or
+/// OpenType font file or font collection. The fonts it contains can then be
+/// chosen by their family name in the font settings of the layout and the
+/// components. They also serve as fallback fonts for characters that the
+/// chosen fonts don't support.
+#[no_mangle]
+pub unsafe extern "C" fn SoftwareRenderer_load_font_data(
+    this: &mut SoftwareRenderer,
+    data: *const u8,
+    len: usize,
+) {
+    this.load_font_data(slice(data, len).to_vec());
+}
+
+/// Renders the background of the layout as configured in the layout. This is
+/// the default.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_use_layout_background(this: &mut SoftwareRenderer) {
+    this.set_background_mode(BackgroundMode::Layout);
+}
+
+/// Leaves out the background of the layout, so the image stays transparent
+/// where the components don't draw anything. The pixels are premultiplied, so
+/// capture software can composite the image cleanly, keeping the translucency
+/// of the components.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_use_transparent_background(this: &mut SoftwareRenderer) {
+    this.set_background_mode(BackgroundMode::Transparent);
+}
+
+/// Replaces the background of the layout with a solid chroma key color. The
+/// color is provided as red, green, blue and alpha components between 0 and 1.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_use_chroma_key_background(
+    this: &mut SoftwareRenderer,
+    red: f32,
+    green: f32,
+    blue: f32,
+    alpha: f32,
+) {
+    this.set_background_mode(BackgroundMode::ChromaKey([red, green, blue, alpha]));
+}
+
+/// Sets how long the transitions between the states of the layout take in
+/// seconds, such as the splits scrolling or the deltas changing their colors.
+/// The animations are driven by the point in time each frame is rendered at. A
+/// duration of zero, which is the default, disables the animations.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_set_animation_duration(
+    this: &mut SoftwareRenderer,
+    seconds: f64,
+) {
+    this.set_animation_duration(TimeSpan::from_seconds(seconds));
+}
+
+/// Rotates the layout in the image by 90°. 0 doesn't rotate the layout, 1
+/// rotates it clockwise and 2 rotates it counterclockwise. The resolution
+/// passed to the renderer is always the one of the image, so the layout itself
+/// is rendered with its width and height swapped.
+#[no_mangle]
+pub extern "C" fn SoftwareRenderer_set_rotation(this: &mut SoftwareRenderer, rotation: u8) {
+    this.set_rotation(match rotation {
+        1 => Rotation::Clockwise,
+        2 => Rotation::CounterClockwise,
+        _ => Rotation::None,
+    });
+}
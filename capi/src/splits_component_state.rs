@@ -20,6 +20,22 @@ pub extern "C" fn SplitsComponentState_final_separator_shown(this: &SplitsCompon
     this.show_final_separator
 }
 
+/// Describes whether the background alternates between two colors for the
+/// odd and even rows.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_alternating_backgrounds_shown(
+    this: &SplitsComponentState,
+) -> bool {
+    this.show_alternating_backgrounds
+}
+
+/// The size the icons are supposed to be shown at in pixels. If this is 0, the
+/// icons are supposed to fill the height of the rows.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_icon_size(this: &SplitsComponentState) -> u32 {
+    this.icon_size
+}
+
 /// Returns the amount of segments to visualize.
 #[no_mangle]
 pub extern "C" fn SplitsComponentState_len(this: &SplitsComponentState) -> usize {
@@ -100,6 +116,51 @@ pub extern "C" fn SplitsComponentState_is_current_split(
     this.splits[index].is_current_split
 }
 
+/// Describes if the row with the specified index doesn't show a segment, but
+/// is blank space that fills up the list because there are not enough
+/// segments to fill it.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_is_blank_space(
+    this: &SplitsComponentState,
+    index: usize,
+) -> bool {
+    this.splits[index].is_blank_space
+}
+
+/// Describes if the row at the specified index is the header of the section
+/// the active attempt is currently on. Section headers have a name, but no
+/// icon or columns.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_is_section_header(
+    this: &SplitsComponentState,
+    index: usize,
+) -> bool {
+    this.splits[index].is_section_header
+}
+
+/// Returns the nesting level of the row at the specified index. Subsplits of
+/// the section the active attempt is currently on are nested one level deeper
+/// than all the other rows.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_nesting_level(
+    this: &SplitsComponentState,
+    index: usize,
+) -> u32 {
+    this.splits[index].nesting_level
+}
+
+/// Describes if the row at the specified index is an odd row of the list. The
+/// rows are counted based on all the rows of the list, not just the ones that
+/// are shown, so the rows keep their alternating backgrounds while the list
+/// scrolls.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_is_odd_row(
+    this: &SplitsComponentState,
+    index: usize,
+) -> bool {
+    this.splits[index].is_odd_row
+}
+
 /// Describes if the columns have labels that are meant to be shown. If this is
 /// `false`, no labels are supposed to be visualized.
 #[no_mangle]
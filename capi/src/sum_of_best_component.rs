@@ -41,7 +41,7 @@ pub extern "C" fn SumOfBestComponent_state_as_json(
     timer: &Timer,
 ) -> Json {
     output_vec(|o| {
-        this.state(timer).write_json(o).unwrap();
+        this.state(&timer.snapshot()).write_json(o).unwrap();
     })
 }
 
@@ -51,5 +51,5 @@ pub extern "C" fn SumOfBestComponent_state(
     this: &SumOfBestComponent,
     timer: &Timer,
 ) -> OwnedKeyValueComponentState {
-    Box::new(this.state(timer))
+    Box::new(this.state(&timer.snapshot()))
 }
@@ -0,0 +1,77 @@
+//! The SVG renderer allows rendering layouts to vector images in the SVG
+//! format.
+
+use livesplit_core::{layout::LayoutState, settings::ImageCache};
+use std::os::raw::c_char;
+
+#[cfg(feature = "svg-rendering")]
+use livesplit_core::rendering::svg::Renderer as SvgRenderer;
+
+use crate::{output_str, slice};
+
+#[cfg(not(feature = "svg-rendering"))]
+/// dummy
+pub struct SvgRenderer;
+#[cfg(not(feature = "svg-rendering"))]
+impl SvgRenderer {
+    fn new() -> Self {
+        panic!("The SVG renderer is not compiled in.")
+    }
+
+    fn load_font_data(&mut self, _: Vec<u8>) {}
+
+    fn render(
+        &mut self,
+        _: &mut String,
+        _: &LayoutState,
+        _: &ImageCache,
+        _: [f32; 2],
+    ) -> Result<Option<[f32; 2]>, std::fmt::Error> {
+        Ok(None)
+    }
+}
+
+/// type
+pub type OwnedSvgRenderer = Box<SvgRenderer>;
+
+/// Creates a new SVG renderer.
+#[no_mangle]
+pub extern "C" fn SvgRenderer_new() -> OwnedSvgRenderer {
+    Box::new(SvgRenderer::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SvgRenderer_drop(this: OwnedSvgRenderer) {
+    drop(this);
+}
+
+/// Renders the layout state provided with the chosen dimensions and returns the
+/// resulting SVG document. The dimensions don't need to be integers, as the
+/// image is a vector image.
+#[no_mangle]
+pub extern "C" fn SvgRenderer_render(
+    this: &mut SvgRenderer,
+    layout_state: &LayoutState,
+    image_cache: &ImageCache,
+    width: f32,
+    height: f32,
+) -> *const c_char {
+    let mut svg = String::new();
+    let _ = this.render(&mut svg, layout_state, image_cache, [width, height]);
+    output_str(svg)
+}
+
+/// Loads the font data provided, such as the contents of a TrueType or
+/// OpenType font file or font collection. The fonts it contains can then be
+/// chosen by their family name in the font settings of the layout and the
+/// components. They also serve as fallback fonts for characters that the
+/// chosen fonts don't support.
+#[no_mangle]
+pub unsafe extern "C" fn SvgRenderer_load_font_data(
+    this: &mut SvgRenderer,
+    data: *const u8,
+    len: usize,
+) {
+    this.load_font_data(slice(data, len).to_vec());
+}
@@ -311,6 +311,33 @@ pub extern "C" fn Timer_set_loading_times(this: &mut Timer, time: &TimeSpan) ->
     convert(this.set_loading_times(*time))
 }
 
+/// Informs the Timer that the game started loading. This is meant to be used
+/// by load removal tools, which only need to report when loading starts and
+/// ends. The Timer then maintains the loads removed Game Time on its own by
+/// pausing the Game Timer until the loading ends and adding the time spent
+/// loading to the loading times. Game Time automatically gets initialized if
+/// it isn't already.
+#[no_mangle]
+pub extern "C" fn Timer_start_loading(this: &mut Timer) -> i32 {
+    convert(this.start_loading())
+}
+
+/// Informs the Timer that the game stopped loading. The time spent loading is
+/// added to the loading times and the Game Timer continues from where it was
+/// paused at. If the Game Timer was already paused before the game started
+/// loading, it stays paused.
+#[no_mangle]
+pub extern "C" fn Timer_end_loading(this: &mut Timer) -> i32 {
+    convert(this.end_loading())
+}
+
+/// Returns whether the game is currently loading, as reported through
+/// Timer_start_loading.
+#[no_mangle]
+pub extern "C" fn Timer_is_loading(this: &Timer) -> bool {
+    this.is_loading()
+}
+
 /// Sets the value of a custom variable with the name specified. If the variable
 /// does not exist, a temporary variable gets created that will not be stored in
 /// the splits file.
@@ -41,6 +41,8 @@ pub struct WebCommandSink {
     pause_game_time: Option<Function>,
     resume_game_time: Option<Function>,
     set_loading_times: Option<Function>,
+    start_loading: Option<Function>,
+    end_loading: Option<Function>,
     set_custom_variable: Option<Function>,
 
     get_timer: Function,
@@ -73,6 +75,8 @@ impl WebCommandSink {
             pause_game_time: get_func(&obj, "pauseGameTime"),
             resume_game_time: get_func(&obj, "resumeGameTime"),
             set_loading_times: get_func(&obj, "setLoadingTimes"),
+            start_loading: get_func(&obj, "startLoading"),
+            end_loading: get_func(&obj, "endLoading"),
             set_custom_variable: get_func(&obj, "setCustomVariable"),
 
             get_timer: get_func(&obj, "getTimer").unwrap(),
@@ -302,6 +306,24 @@ impl CommandSink for WebCommandSink {
         }))
     }
 
+    fn start_loading(&self) -> impl Future<Output = Result> + 'static {
+        debug_assert!(!self.locked.get());
+        handle_action_value(
+            self.start_loading
+                .as_ref()
+                .and_then(|f| f.call0(&self.obj).ok()),
+        )
+    }
+
+    fn end_loading(&self) -> impl Future<Output = Result> + 'static {
+        debug_assert!(!self.locked.get());
+        handle_action_value(
+            self.end_loading
+                .as_ref()
+                .and_then(|f| f.call0(&self.obj).ok()),
+        )
+    }
+
     fn set_custom_variable(
         &self,
         name: Cow<'_, str>,
@@ -0,0 +1,52 @@
+//! The World Record Component shows the time of the world record of the game
+//! and category of the run, and optionally the runners who achieved it.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::world_record::Component as WorldRecordComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedWorldRecordComponent = Box<WorldRecordComponent>;
+
+/// Creates a new World Record Component.
+#[no_mangle]
+pub extern "C" fn WorldRecordComponent_new() -> OwnedWorldRecordComponent {
+    Box::new(WorldRecordComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn WorldRecordComponent_drop(this: OwnedWorldRecordComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn WorldRecordComponent_into_generic(
+    this: OwnedWorldRecordComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn WorldRecordComponent_state_as_json(
+    this: &WorldRecordComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn WorldRecordComponent_state(
+    this: &WorldRecordComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(timer))
+}
@@ -550,6 +550,77 @@
 //! - There is no networking.
 //! - There is no threading.
 //! - Time and random numbers are available.
+//!
+//! # Plugins
+//!
+//! Besides auto splitters, the runtime can also run plugins that extend the
+//! timer with comparisons and the text of custom components. Plugins are
+//! compiled with [`Runtime::compile_plugin`] and instantiated with the
+//! [`PluginPermissions`] they were granted. Unlike auto splitters, plugins have
+//! no access to WASI or any of the functions above. The following functions are
+//! provided to them in the module `env`. Each of them traps if the plugin lacks
+//! the permission it belongs to:
+//!
+//! ```rust
+//! extern "C" {
+//!     /// Registers a comparison with the name provided. Requires the
+//!     /// `comparisons` permission.
+//!     pub fn plugin_register_comparison(name_ptr: *const u8, name_len: usize);
+//!     /// Returns the amount of segments while generating a comparison.
+//!     /// Requires the `comparisons` permission.
+//!     pub fn plugin_get_segment_count() -> u32;
+//!     /// Stores the personal best split time of a segment for the timing method
+//!     /// (0 for real time, 1 for game time) and returns whether there is one.
+//!     /// Requires the `comparisons` permission.
+//!     pub fn plugin_get_personal_best_split_time(
+//!         index: u32,
+//!         timing_method: u32,
+//!         secs_ptr: *mut i64,
+//!         nanos_ptr: *mut i32,
+//!     ) -> bool;
+//!     /// Stores the best segment time of a segment for the timing method and
+//!     /// returns whether there is one. Requires the `comparisons` permission.
+//!     pub fn plugin_get_best_segment_time(
+//!         index: u32,
+//!         timing_method: u32,
+//!         secs_ptr: *mut i64,
+//!         nanos_ptr: *mut i32,
+//!     ) -> bool;
+//!     /// Sets the split time of a segment in the comparison that is being
+//!     /// generated. Requires the `comparisons` permission.
+//!     pub fn plugin_set_comparison_time(index: u32, timing_method: u32, secs: i64, nanos: i32);
+//!     /// Sets the text of a custom component. Requires the `component_text`
+//!     /// permission.
+//!     pub fn plugin_set_component_text(
+//!         name_ptr: *const u8,
+//!         name_len: usize,
+//!         value_ptr: *const u8,
+//!         value_len: usize,
+//!     );
+//! }
+//! ```
+//!
+//! A plugin needs to export a memory called `memory` and may export the
+//! following functions:
+//!
+//! ```rust
+//! /// Called once when the plugin is instantiated.
+//! #[no_mangle]
+//! pub extern "C" fn plugin_load() {}
+//!
+//! /// Generates the comparison with the index it got registered at.
+//! #[no_mangle]
+//! pub extern "C" fn plugin_generate_comparison(index: u32) {}
+//!
+//! /// Called whenever the host updates the texts of the custom components.
+//! #[no_mangle]
+//! pub extern "C" fn plugin_update() {}
+//!
+//! /// Called for each event of the timer. Only called with the `events`
+//! /// permission.
+//! #[no_mangle]
+//! pub extern "C" fn plugin_handle_event(event: u32) {}
+//! ```
 
 #![warn(
     clippy::complexity,
@@ -571,8 +642,8 @@ pub mod wasi_path;
 
 pub use process::Process;
 pub use runtime::{
-    AutoSplitter, CompiledAutoSplitter, Config, CreationError, ExecutionGuard, InterruptHandle,
-    Runtime,
+    AutoSplitter, CompiledAutoSplitter, CompiledPlugin, Config, CreationError, ExecutionGuard,
+    InterruptHandle, Plugin, PluginPermissions, PluginSegment, Runtime,
 };
 pub use time;
 pub use timer::{LogLevel, Timer, TimerState};
@@ -583,6 +654,8 @@ const _: () = {
     assert_send_sync::<Process>();
     assert_send_sync::<Runtime>();
     assert_send_sync::<CompiledAutoSplitter>();
+    assert_send_sync::<CompiledPlugin>();
+    assert_send_sync::<Plugin>();
     const fn with_timer<T: Send + Sync + Timer>() {
         assert_send_sync::<AutoSplitter<T>>();
     }
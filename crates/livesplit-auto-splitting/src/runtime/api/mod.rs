@@ -7,6 +7,7 @@ use crate::{CreationError, Timer};
 
 use super::Context;
 
+pub mod plugin;
 mod process;
 mod runtime;
 mod setting_value;
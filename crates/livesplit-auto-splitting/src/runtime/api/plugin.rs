@@ -0,0 +1,168 @@
+use anyhow::{ensure, Context as _, Result};
+use wasmtime::{Caller, Linker};
+
+use crate::{runtime::plugin::PluginContext, CreationError};
+
+use super::{get_arr_mut, get_str};
+
+fn memory_and_context<'a>(
+    caller: &'a mut Caller<'_, PluginContext>,
+) -> (&'a mut [u8], &'a mut PluginContext) {
+    caller.data().memory.unwrap().data_and_store_mut(caller)
+}
+
+fn timing_method(timing_method: u32) -> Result<usize> {
+    ensure!(timing_method < 2, "Invalid timing method.");
+    Ok(timing_method as usize)
+}
+
+fn write_duration(
+    memory: &mut [u8],
+    duration: Option<time::Duration>,
+    secs_ptr: u32,
+    nanos_ptr: u32,
+) -> Result<u32> {
+    let Some(duration) = duration else {
+        return Ok(0);
+    };
+    *get_arr_mut(memory, secs_ptr)? = duration.whole_seconds().to_le_bytes();
+    *get_arr_mut(memory, nanos_ptr)? = duration.subsec_nanoseconds().to_le_bytes();
+    Ok(1)
+}
+
+pub fn bind(linker: &mut Linker<PluginContext>) -> Result<(), CreationError> {
+    linker
+        .func_wrap("env", "plugin_register_comparison", {
+            |mut caller: Caller<'_, PluginContext>, ptr: u32, len: u32| {
+                ensure!(
+                    caller.data().permissions.comparisons,
+                    "The plugin lacks the `Comparisons` permission."
+                );
+                let (memory, context) = memory_and_context(&mut caller);
+                let name = get_str(memory, ptr, len)?;
+                if !context.comparisons.iter().any(|c| &**c == name) {
+                    context.comparisons.push(name.into());
+                }
+                Ok(())
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "plugin_register_comparison",
+        })?
+        .func_wrap("env", "plugin_get_segment_count", {
+            |caller: Caller<'_, PluginContext>| {
+                ensure!(
+                    caller.data().permissions.comparisons,
+                    "The plugin lacks the `Comparisons` permission."
+                );
+                Ok(caller.data().segments.len() as u32)
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "plugin_get_segment_count",
+        })?
+        .func_wrap("env", "plugin_get_personal_best_split_time", {
+            |mut caller: Caller<'_, PluginContext>,
+             index: u32,
+             timing_method_index: u32,
+             secs_ptr: u32,
+             nanos_ptr: u32| {
+                ensure!(
+                    caller.data().permissions.comparisons,
+                    "The plugin lacks the `Comparisons` permission."
+                );
+                let timing_method = timing_method(timing_method_index)?;
+                let (memory, context) = memory_and_context(&mut caller);
+                let segment = context
+                    .segments
+                    .get(index as usize)
+                    .context("Invalid segment index.")?;
+                write_duration(
+                    memory,
+                    segment.personal_best_split_time[timing_method],
+                    secs_ptr,
+                    nanos_ptr,
+                )
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "plugin_get_personal_best_split_time",
+        })?
+        .func_wrap("env", "plugin_get_best_segment_time", {
+            |mut caller: Caller<'_, PluginContext>,
+             index: u32,
+             timing_method_index: u32,
+             secs_ptr: u32,
+             nanos_ptr: u32| {
+                ensure!(
+                    caller.data().permissions.comparisons,
+                    "The plugin lacks the `Comparisons` permission."
+                );
+                let timing_method = timing_method(timing_method_index)?;
+                let (memory, context) = memory_and_context(&mut caller);
+                let segment = context
+                    .segments
+                    .get(index as usize)
+                    .context("Invalid segment index.")?;
+                write_duration(
+                    memory,
+                    segment.best_segment_time[timing_method],
+                    secs_ptr,
+                    nanos_ptr,
+                )
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "plugin_get_best_segment_time",
+        })?
+        .func_wrap("env", "plugin_set_comparison_time", {
+            |mut caller: Caller<'_, PluginContext>,
+             index: u32,
+             timing_method_index: u32,
+             secs: i64,
+             nanos: i32| {
+                ensure!(
+                    caller.data().permissions.comparisons,
+                    "The plugin lacks the `Comparisons` permission."
+                );
+                let timing_method = timing_method(timing_method_index)?;
+                let time = caller
+                    .data_mut()
+                    .comparison_times
+                    .get_mut(index as usize)
+                    .context("Invalid segment index.")?;
+                time[timing_method] = Some(time::Duration::new(secs, nanos));
+                Ok(())
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "plugin_set_comparison_time",
+        })?
+        .func_wrap("env", "plugin_set_component_text", {
+            |mut caller: Caller<'_, PluginContext>,
+             name_ptr: u32,
+             name_len: u32,
+             value_ptr: u32,
+             value_len: u32| {
+                ensure!(
+                    caller.data().permissions.component_text,
+                    "The plugin lacks the `ComponentText` permission."
+                );
+                let (memory, context) = memory_and_context(&mut caller);
+                let name = get_str(memory, name_ptr, name_len)?;
+                let value = get_str(memory, value_ptr, value_len)?;
+                context.component_texts.push((name.into(), value.into()));
+                Ok(())
+            }
+        })
+        .map_err(|source| CreationError::LinkFunction {
+            source,
+            name: "plugin_set_component_text",
+        })?;
+    Ok(())
+}
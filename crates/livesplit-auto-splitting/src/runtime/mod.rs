@@ -27,6 +27,9 @@ use wasmtime::{
 use wasmtime_wasi::preview1::WasiP1Ctx;
 
 mod api;
+mod plugin;
+
+pub use plugin::{CompiledPlugin, Plugin, PluginPermissions, PluginSegment};
 
 /// An error that is returned when the creation of a new runtime fails.
 #[derive(Debug, Snafu)]
@@ -75,6 +78,11 @@ pub enum CreationError {
         /// The underlying error.
         source: anyhow::Error,
     },
+    /// Failed running the `plugin_load` function of a plugin.
+    PluginLoad {
+        /// The underlying error.
+        source: anyhow::Error,
+    },
 }
 
 slotmap::new_key_type! {
@@ -0,0 +1,192 @@
+use std::mem;
+
+use anyhow::Result;
+use wasmtime::{Engine, Extern, Linker, Memory, Module, Store, TypedFunc};
+
+use super::{api, CreationError, InterruptHandle, Runtime};
+
+/// The capabilities a plugin is allowed to use. The host functions belonging
+/// to a capability check for it and trap if the plugin lacks it. The
+/// `plugin_handle_event` export is never called without the
+/// [`events`](Self::events) capability.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PluginPermissions {
+    /// The plugin may register and generate comparisons.
+    pub comparisons: bool,
+    /// The plugin may provide the text of custom components.
+    pub component_text: bool,
+    /// The plugin may observe the events of the timer.
+    pub events: bool,
+}
+
+/// The times of a segment that are provided to a plugin while it generates a
+/// comparison. Each array is indexed by the timing method, with `0` being
+/// real time and `1` being game time.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PluginSegment {
+    /// The split time of the segment in the personal best.
+    pub personal_best_split_time: [Option<time::Duration>; 2],
+    /// The best segment time of the segment.
+    pub best_segment_time: [Option<time::Duration>; 2],
+}
+
+pub struct PluginContext {
+    pub(super) permissions: PluginPermissions,
+    pub(super) memory: Option<Memory>,
+    pub(super) comparisons: Vec<Box<str>>,
+    pub(super) segments: Vec<PluginSegment>,
+    pub(super) comparison_times: Vec<[Option<time::Duration>; 2]>,
+    pub(super) component_texts: Vec<(String, String)>,
+}
+
+/// A compiled plugin that can be instantiated.
+pub struct CompiledPlugin {
+    module: Module,
+}
+
+/// An instantiated plugin. Unlike an auto splitter, a plugin has no access to
+/// WASI or any other host functionality than the `plugin_*` functions, so it
+/// can't access the file system, the network or other processes. Once a
+/// plugin traps, none of its functions are called anymore.
+pub struct Plugin {
+    trapped: bool,
+    store: Store<PluginContext>,
+    generate_comparison: Option<TypedFunc<u32, ()>>,
+    update: Option<TypedFunc<(), ()>>,
+    handle_event: Option<TypedFunc<u32, ()>>,
+}
+
+impl Runtime {
+    /// Compiles the given plugin that is provided as a WebAssembly module.
+    pub fn compile_plugin(&self, module: &[u8]) -> Result<CompiledPlugin, CreationError> {
+        Ok(CompiledPlugin {
+            module: Module::from_binary(&self.engine, module)
+                .map_err(|source| CreationError::ModuleLoading { source })?,
+        })
+    }
+}
+
+impl CompiledPlugin {
+    /// Instantiates the plugin with the permissions provided. This calls the
+    /// optional `plugin_load` export of the module, which is where the plugin
+    /// is expected to register its comparisons.
+    pub fn instantiate(&self, permissions: PluginPermissions) -> Result<Plugin, CreationError> {
+        let engine = self.module.engine();
+
+        let mut store = Store::new(
+            engine,
+            PluginContext {
+                permissions,
+                memory: None,
+                comparisons: Vec::new(),
+                segments: Vec::new(),
+                comparison_times: Vec::new(),
+                component_texts: Vec::new(),
+            },
+        );
+
+        store.set_epoch_deadline(1);
+
+        let mut linker = Linker::new(engine);
+        api::plugin::bind(&mut linker)?;
+
+        let instance = linker
+            .instantiate(&mut store, &self.module)
+            .map_err(|source| CreationError::ModuleInstantiation { source })?;
+
+        let Some(Extern::Memory(mem)) = instance.get_export(&mut store, "memory") else {
+            return Err(CreationError::MissingMemory);
+        };
+        store.data_mut().memory = Some(mem);
+
+        if let Ok(func) = instance.get_typed_func::<(), ()>(&mut store, "plugin_load") {
+            func.call(&mut store, ())
+                .map_err(|source| CreationError::PluginLoad { source })?;
+        }
+
+        Ok(Plugin {
+            trapped: false,
+            generate_comparison: instance
+                .get_typed_func(&mut store, "plugin_generate_comparison")
+                .ok(),
+            update: instance.get_typed_func(&mut store, "plugin_update").ok(),
+            handle_event: instance
+                .get_typed_func(&mut store, "plugin_handle_event")
+                .ok(),
+            store,
+        })
+    }
+}
+
+impl Plugin {
+    /// Accesses an interrupt handle that allows you to interrupt the ongoing
+    /// execution of the WebAssembly module. A WebAssembly module may
+    /// accidentally or maliciously loop forever, which is why this is needed.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle(Engine::clone(self.store.engine()))
+    }
+
+    /// Returns the permissions the plugin got instantiated with.
+    pub fn permissions(&self) -> PluginPermissions {
+        self.store.data().permissions
+    }
+
+    /// Returns the names of the comparisons the plugin registered.
+    pub fn comparisons(&self) -> &[Box<str>] {
+        &self.store.data().comparisons
+    }
+
+    /// Runs the exported `plugin_generate_comparison` function for the
+    /// comparison at the index provided. The plugin may query the segments
+    /// provided and returns a split time for each of them, indexed by the
+    /// timing method like the times of the segments.
+    pub fn generate_comparison(
+        &mut self,
+        index: usize,
+        segments: &[PluginSegment],
+    ) -> Result<Vec<[Option<time::Duration>; 2]>> {
+        let data = self.store.data_mut();
+        data.segments.clear();
+        data.segments.extend_from_slice(segments);
+        data.comparison_times.clear();
+        data.comparison_times.resize(segments.len(), [None; 2]);
+
+        if let Some(func) = &self.generate_comparison {
+            if !self.trapped && index < self.store.data().comparisons.len() {
+                let result = func.call(&mut self.store, index as u32);
+                self.trapped = result.is_err();
+                result?;
+            }
+        }
+
+        Ok(mem::take(&mut self.store.data_mut().comparison_times))
+    }
+
+    /// Runs the exported `plugin_update` function of the WebAssembly module
+    /// and returns the texts of the custom components that the plugin set
+    /// since the last update as pairs of names and values.
+    pub fn update(&mut self) -> Result<Vec<(String, String)>> {
+        if let Some(func) = &self.update {
+            if !self.trapped {
+                let result = func.call(&mut self.store, ());
+                self.trapped = result.is_err();
+                result?;
+            }
+        }
+        Ok(mem::take(&mut self.store.data_mut().component_texts))
+    }
+
+    /// Runs the exported `plugin_handle_event` function of the WebAssembly
+    /// module with the event provided. This does nothing if the plugin doesn't
+    /// have the [`events`](PluginPermissions::events) permission.
+    pub fn handle_event(&mut self, event: u32) -> Result<()> {
+        if let Some(func) = &self.handle_event {
+            if !self.trapped && self.store.data().permissions.events {
+                let result = func.call(&mut self.store, event);
+                self.trapped = result.is_err();
+                result?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use livesplit_auto_splitting::{Config, Plugin, PluginPermissions, PluginSegment, Runtime};
+
+const PLUGIN: &str = r#"
+(module
+    (import "env" "plugin_register_comparison" (func $register (param i32 i32)))
+    (import "env" "plugin_get_segment_count" (func $count (result i32)))
+    (import "env" "plugin_get_best_segment_time"
+        (func $best (param i32 i32 i32 i32) (result i32)))
+    (import "env" "plugin_set_comparison_time"
+        (func $set_time (param i32 i32 i64 i32)))
+    (import "env" "plugin_set_component_text"
+        (func $set_text (param i32 i32 i32 i32)))
+    (memory (export "memory") 1)
+    (data (i32.const 0) "Doubled Best")
+    (data (i32.const 16) "Greeting")
+    (data (i32.const 32) "Hello")
+    (global $events (mut i32) (i32.const 0))
+
+    (func (export "plugin_load")
+        (call $register (i32.const 0) (i32.const 12)))
+
+    ;; Sets every split time to twice the best segment time of the segment.
+    (func (export "plugin_generate_comparison") (param $comparison i32)
+        (local $i i32)
+        (block $done
+            (loop $next
+                (br_if $done (i32.ge_u (local.get $i) (call $count)))
+                (if (call $best (local.get $i) (i32.const 0) (i32.const 64) (i32.const 72))
+                    (then
+                        (call $set_time
+                            (local.get $i)
+                            (i32.const 0)
+                            (i64.mul (i64.load (i32.const 64)) (i64.const 2))
+                            (i32.const 0))))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br $next))))
+
+    (func (export "plugin_update")
+        (call $set_text (i32.const 16) (i32.const 8) (i32.const 32) (i32.const 5)))
+
+    ;; Traps on the second event, so the plugin can be observed to stop.
+    (func (export "plugin_handle_event") (param $event i32)
+        (if (global.get $events) (then unreachable))
+        (global.set $events (i32.const 1)))
+)
+"#;
+
+fn instantiate(permissions: PluginPermissions) -> anyhow::Result<Plugin> {
+    let module = wat::parse_str(PLUGIN)?;
+    Ok(Runtime::new(Config::default())?
+        .compile_plugin(&module)?
+        .instantiate(permissions)?)
+}
+
+fn all_permissions() -> PluginPermissions {
+    PluginPermissions {
+        comparisons: true,
+        component_text: true,
+        events: true,
+    }
+}
+
+#[test]
+fn registering_a_comparison_requires_the_permission() {
+    assert!(instantiate(PluginPermissions::default()).is_err());
+    let plugin = instantiate(PluginPermissions {
+        comparisons: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(plugin.comparisons(), [Box::from("Doubled Best")]);
+}
+
+#[test]
+fn generates_comparisons() {
+    let mut plugin = instantiate(all_permissions()).unwrap();
+    let segments = [
+        PluginSegment {
+            best_segment_time: [Some(time::Duration::seconds(5)), None],
+            ..Default::default()
+        },
+        PluginSegment::default(),
+    ];
+    assert_eq!(
+        plugin.generate_comparison(0, &segments).unwrap(),
+        [[Some(time::Duration::seconds(10)), None], [None, None]],
+    );
+}
+
+#[test]
+fn setting_component_texts_requires_the_permission() {
+    let mut plugin = instantiate(PluginPermissions {
+        comparisons: true,
+        ..Default::default()
+    })
+    .unwrap();
+    assert!(plugin.update().is_err());
+
+    let mut plugin = instantiate(all_permissions()).unwrap();
+    assert_eq!(
+        plugin.update().unwrap(),
+        [(String::from("Greeting"), String::from("Hello"))],
+    );
+}
+
+#[test]
+fn events_require_the_permission() {
+    let mut plugin = instantiate(PluginPermissions {
+        comparisons: true,
+        ..Default::default()
+    })
+    .unwrap();
+    plugin.handle_event(0).unwrap();
+    plugin.handle_event(0).unwrap();
+
+    let mut plugin = instantiate(all_permissions()).unwrap();
+    plugin.handle_event(0).unwrap();
+    assert!(plugin.handle_event(0).is_err());
+    plugin.handle_event(0).unwrap();
+    assert!(plugin.update().unwrap().is_empty());
+}
+
+#[test]
+fn plugins_have_no_access_to_wasi() {
+    let module = wat::parse_str(
+        r#"(module
+            (import "wasi_snapshot_preview1" "proc_exit" (func (param i32)))
+            (memory (export "memory") 1))"#,
+    )
+    .unwrap();
+    assert!(Runtime::new(Config::default())
+        .unwrap()
+        .compile_plugin(&module)
+        .unwrap()
+        .instantiate(all_permissions())
+        .is_err());
+}
@@ -2,15 +2,43 @@ use core::{fmt, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{KeyCode, Modifiers};
+use crate::{KeyCode, KeyCodeClass, Modifiers};
 
-/// A hotkey is a combination of a key code and a set of modifiers.
+/// A hotkey is a combination of a key code and a set of modifiers. On gamepads
+/// a hotkey can also be a combination of two buttons.
 #[derive(Eq, PartialEq, Hash, Copy, Clone)]
 pub struct Hotkey {
     /// The key code of the hotkey.
     pub key_code: KeyCode,
     /// The modifiers of the hotkey.
     pub modifiers: Modifiers,
+    pub(crate) held_button: Option<KeyCode>,
+}
+
+impl Hotkey {
+    /// Creates a hotkey that only triggers while the gamepad button provided
+    /// is held down, such as holding `Select` while pressing `Start`. Both the
+    /// key code and the held button need to be different gamepad buttons,
+    /// otherwise [`None`] is returned.
+    pub fn with_held_button(self, held_button: KeyCode) -> Option<Self> {
+        let is_gamepad_button = |key_code: KeyCode| key_code.classify() == KeyCodeClass::Gamepad;
+        if !is_gamepad_button(self.key_code)
+            || !is_gamepad_button(held_button)
+            || held_button == self.key_code
+        {
+            return None;
+        }
+        Some(Self {
+            held_button: Some(held_button),
+            ..self
+        })
+    }
+
+    /// The gamepad button that needs to be held down while pressing the key
+    /// code. If the button is not held down, the hotkey doesn't trigger.
+    pub const fn held_button(&self) -> Option<KeyCode> {
+        self.held_button
+    }
 }
 
 impl fmt::Debug for Hotkey {
@@ -21,11 +49,13 @@ impl fmt::Debug for Hotkey {
 
 impl fmt::Display for Hotkey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.modifiers.is_empty() {
-            f.write_str(self.key_code.name())
-        } else {
-            write!(f, "{} + {}", self.modifiers, self.key_code.name())
+        if !self.modifiers.is_empty() {
+            write!(f, "{} + ", self.modifiers)?;
+        }
+        if let Some(held_button) = self.held_button {
+            write!(f, "{} + ", held_button.name())?;
         }
+        f.write_str(self.key_code.name())
     }
 }
 
@@ -33,19 +63,26 @@ impl FromStr for Hotkey {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some((modifiers, key_code)) = s.rsplit_once('+') {
-            let modifiers = modifiers.trim_end().parse()?;
-            let key_code = key_code.trim_start().parse()?;
-            Ok(Self {
-                key_code,
-                modifiers,
-            })
+        if let Some((prefix, key_code)) = s.rsplit_once('+') {
+            let key_code: KeyCode = key_code.trim_start().parse()?;
+            let prefix = prefix.trim_end();
+            if let Ok(modifiers) = prefix.parse() {
+                return Ok(key_code.with_modifiers(modifiers));
+            }
+            // The key right before the key code is the held button, if it's
+            // not a modifier.
+            let (modifiers, held_button) = match prefix.rsplit_once('+') {
+                Some((modifiers, held_button)) => {
+                    (modifiers.trim_end().parse()?, held_button.trim_start())
+                }
+                None => (Modifiers::empty(), prefix),
+            };
+            key_code
+                .with_modifiers(modifiers)
+                .with_held_button(held_button.parse()?)
+                .ok_or(())
         } else {
-            let key_code = s.parse()?;
-            Ok(Self {
-                key_code,
-                modifiers: Modifiers::empty(),
-            })
+            Ok(s.parse::<KeyCode>()?.into())
         }
     }
 }
@@ -55,7 +92,7 @@ impl Serialize for Hotkey {
     where
         S: serde::Serializer,
     {
-        if self.modifiers.is_empty() {
+        if self.modifiers.is_empty() && self.held_button.is_none() {
             self.key_code.serialize(serializer)
         } else {
             serializer.collect_str(self)
@@ -94,6 +131,24 @@ impl From<KeyCode> for Hotkey {
         Self {
             key_code,
             modifiers: Modifiers::empty(),
+            held_button: None,
         }
     }
 }
+
+/// Returns all the combinations of the modifiers and held buttons that a key
+/// press may trigger, starting with the most specific one. Combinations with a
+/// held button take precedence over the key on its own, so a button that is
+/// part of a combination can still be used on its own.
+#[allow(dead_code)] // Not every implementation supports gamepads.
+pub(crate) fn combinations<K: Copy>(
+    key: K,
+    modifiers: Modifiers,
+    held_buttons: impl IntoIterator<Item = K>,
+) -> impl Iterator<Item = (K, Modifiers, Option<K>)> {
+    held_buttons
+        .into_iter()
+        .map(Some)
+        .chain([None])
+        .flat_map(move |held| modifiers.subsets().map(move |m| (key, m, held)))
+}
@@ -1326,9 +1326,17 @@ impl KeyCode {
         Hotkey {
             key_code: self,
             modifiers,
+            held_button: None,
         }
     }
 
+    /// Combines the key code with a gamepad button that needs to be held down
+    /// while pressing the key code to form a [`Hotkey`]. Both need to be
+    /// different gamepad buttons, otherwise [`None`] is returned.
+    pub fn with_held_button(self, held_button: KeyCode) -> Option<Hotkey> {
+        Hotkey::from(self).with_held_button(held_button)
+    }
+
     /// Returns the name of the key code.
     pub const fn name(self) -> &'static str {
         match self {
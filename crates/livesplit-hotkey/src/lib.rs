@@ -42,6 +42,7 @@ cfg_if::cfg_if! {
 mod hotkey;
 mod key_code;
 mod modifiers;
+use alloc::boxed::Box;
 use core::fmt;
 
 pub use self::{hotkey::*, key_code::*, modifiers::*};
@@ -86,7 +87,36 @@ impl Hook {
     where
         F: FnMut() + Send + 'static,
     {
-        self.0.register(hotkey, callback)
+        self.0.register(
+            hotkey,
+            Callbacks {
+                on_press: Box::new(callback),
+                on_release: None,
+            },
+        )
+    }
+
+    /// Registers a hotkey to listen to. In addition to the callback that gets
+    /// called when the hotkey is pressed, the second callback gets called when
+    /// the key of the hotkey is released again. This allows telling apart how
+    /// long the hotkey was held down.
+    pub fn register_with_release<F, R>(
+        &self,
+        hotkey: Hotkey,
+        on_press: F,
+        on_release: R,
+    ) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+        R: FnMut() + Send + 'static,
+    {
+        self.0.register(
+            hotkey,
+            Callbacks {
+                on_press: Box::new(on_press),
+                on_release: Some(Box::new(on_release)),
+            },
+        )
     }
 
     /// Unregisters a previously registered hotkey.
@@ -95,6 +125,27 @@ impl Hook {
     }
 }
 
+/// The callbacks of a registered hotkey.
+// The stubbed out implementation never calls any of them.
+#[allow(dead_code)]
+pub(crate) struct Callbacks {
+    on_press: Box<dyn FnMut() + Send + 'static>,
+    on_release: Option<Box<dyn FnMut() + Send + 'static>>,
+}
+
+#[allow(dead_code)]
+impl Callbacks {
+    pub(crate) fn press(&mut self) {
+        (self.on_press)();
+    }
+
+    pub(crate) fn release(&mut self) {
+        if let Some(on_release) = &mut self.on_release {
+            on_release();
+        }
+    }
+}
+
 /// The result type for this crate.
 pub type Result<T> = core::result::Result<T, Error>;
 
@@ -168,6 +219,58 @@ mod tests {
         hook.unregister(KeyCode::Numpad1.into()).unwrap();
     }
 
+    #[test]
+    fn modifier_subsets() {
+        let subsets: Vec<_> = (Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::ALT)
+            .subsets()
+            .collect();
+        assert_eq!(
+            subsets,
+            [
+                Modifiers::SHIFT | Modifiers::CONTROL | Modifiers::ALT,
+                Modifiers::SHIFT | Modifiers::CONTROL,
+                Modifiers::SHIFT | Modifiers::ALT,
+                Modifiers::CONTROL | Modifiers::ALT,
+                Modifiers::SHIFT,
+                Modifiers::CONTROL,
+                Modifiers::ALT,
+                Modifiers::empty(),
+            ]
+        );
+        assert!(Modifiers::empty().subsets().eq([Modifiers::empty()]));
+    }
+
+    #[test]
+    fn parse_held_buttons() {
+        for (text, hotkey) in [
+            ("Numpad1", KeyCode::Numpad1.into()),
+            (
+                "Ctrl + Shift + Numpad1",
+                KeyCode::Numpad1.with_modifiers(Modifiers::CONTROL | Modifiers::SHIFT),
+            ),
+            (
+                "Gamepad8 + Gamepad9",
+                KeyCode::Gamepad9
+                    .with_held_button(KeyCode::Gamepad8)
+                    .unwrap(),
+            ),
+            (
+                "Ctrl + Gamepad4 + Gamepad0",
+                KeyCode::Gamepad0
+                    .with_modifiers(Modifiers::CONTROL)
+                    .with_held_button(KeyCode::Gamepad4)
+                    .unwrap(),
+            ),
+        ] {
+            assert_eq!(text.parse::<Hotkey>(), Ok(hotkey));
+            assert_eq!(hotkey.to_string(), text);
+        }
+        assert!("Gamepad8 + Ctrl + Gamepad9".parse::<Hotkey>().is_err());
+        assert!("KeyA + Gamepad9".parse::<Hotkey>().is_err());
+        assert!("Gamepad8 + KeyA".parse::<Hotkey>().is_err());
+        assert!("Gamepad8 + Gamepad8".parse::<Hotkey>().is_err());
+    }
+
     #[test]
     fn resolve() {
         let hook = Hook::new().unwrap();
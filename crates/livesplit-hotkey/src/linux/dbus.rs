@@ -0,0 +1,884 @@
+//! A minimal D-Bus client that implements just enough of the protocol to talk
+//! to the XDG desktop portal. It only supports connecting to the session bus
+//! via a Unix socket, sending messages and receiving them.
+
+use std::{
+    env,
+    io::{self, Read, Write},
+    os::{
+        linux::net::SocketAddrExt,
+        unix::{
+            io::{AsRawFd, RawFd},
+            net::{SocketAddr, UnixStream},
+        },
+    },
+    time::Duration,
+};
+
+pub const METHOD_CALL: u8 = 1;
+pub const ERROR: u8 = 3;
+pub const SIGNAL: u8 = 4;
+
+const BUS_NAME: &str = "org.freedesktop.DBus";
+const BUS_PATH: &str = "/org/freedesktop/DBus";
+
+// The bus and the portal usually respond immediately. Anything that takes
+// longer indicates that something is broken, so we rather fall back to a
+// different implementation than blocking forever.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A value of the D-Bus type system. Integers that are not needed by the
+/// desktop portal are read as the next larger supported type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Byte(u8),
+    Bool(bool),
+    U32(u32),
+    U64(u64),
+    Str(String),
+    ObjectPath(String),
+    Signature(String),
+    /// An array with the signature of its elements.
+    Array(String, Vec<Value>),
+    Struct(Vec<Value>),
+    DictEntry(Box<Value>, Box<Value>),
+    Variant(Box<Value>),
+}
+
+impl Value {
+    /// Creates a dictionary of the type `a{sv}`, which is how the desktop
+    /// portal receives options and returns results.
+    pub fn dict(entries: impl IntoIterator<Item = (&'static str, Value)>) -> Self {
+        Value::Array(
+            "{sv}".into(),
+            entries
+                .into_iter()
+                .map(|(key, value)| {
+                    Value::DictEntry(
+                        Box::new(Value::Str(key.into())),
+                        Box::new(Value::Variant(Box::new(value))),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Looks up an entry of a dictionary of the type `a{sv}`.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        let Value::Array(_, entries) = self else {
+            return None;
+        };
+        entries.iter().find_map(|entry| match entry {
+            Value::DictEntry(k, v) if matches!(&**k, Value::Str(k) if k == key) => match &**v {
+                Value::Variant(v) => Some(&**v),
+                v => Some(v),
+            },
+            _ => None,
+        })
+    }
+
+    fn signature(&self, sig: &mut String) {
+        match self {
+            Value::Byte(_) => sig.push('y'),
+            Value::Bool(_) => sig.push('b'),
+            Value::U32(_) => sig.push('u'),
+            Value::U64(_) => sig.push('t'),
+            Value::Str(_) => sig.push('s'),
+            Value::ObjectPath(_) => sig.push('o'),
+            Value::Signature(_) => sig.push('g'),
+            Value::Array(element, _) => {
+                sig.push('a');
+                sig.push_str(element);
+            }
+            Value::Struct(fields) => {
+                sig.push('(');
+                for field in fields {
+                    field.signature(sig);
+                }
+                sig.push(')');
+            }
+            Value::DictEntry(key, value) => {
+                sig.push('{');
+                key.signature(sig);
+                value.signature(sig);
+                sig.push('}');
+            }
+            Value::Variant(_) => sig.push('v'),
+        }
+    }
+}
+
+/// A message sent over the bus. The serial of a message is assigned when it
+/// gets sent and the sender is filled in by the bus.
+#[derive(Debug, Clone, Default)]
+pub struct Message {
+    pub kind: u8,
+    pub serial: u32,
+    pub reply_serial: Option<u32>,
+    pub path: Option<String>,
+    pub interface: Option<String>,
+    pub member: Option<String>,
+    pub error_name: Option<String>,
+    pub destination: Option<String>,
+    pub sender: Option<String>,
+    pub body: Vec<Value>,
+}
+
+impl Message {
+    pub fn method_call(
+        destination: &str,
+        path: &str,
+        interface: &str,
+        member: &str,
+        body: Vec<Value>,
+    ) -> Self {
+        Self {
+            kind: METHOD_CALL,
+            path: Some(path.into()),
+            interface: Some(interface.into()),
+            member: Some(member.into()),
+            destination: Some(destination.into()),
+            body,
+            ..Default::default()
+        }
+    }
+
+    /// Checks whether the message is the signal specified.
+    pub fn is_signal(&self, interface: &str, member: &str) -> bool {
+        self.kind == SIGNAL
+            && self.interface.as_deref() == Some(interface)
+            && self.member.as_deref() == Some(member)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut fields = Vec::new();
+        let mut field = |code, value| {
+            fields.push(Value::Struct(vec![
+                Value::Byte(code),
+                Value::Variant(Box::new(value)),
+            ]))
+        };
+        if let Some(path) = &self.path {
+            field(1, Value::ObjectPath(path.clone()));
+        }
+        if let Some(interface) = &self.interface {
+            field(2, Value::Str(interface.clone()));
+        }
+        if let Some(member) = &self.member {
+            field(3, Value::Str(member.clone()));
+        }
+        if let Some(error_name) = &self.error_name {
+            field(4, Value::Str(error_name.clone()));
+        }
+        if let Some(reply_serial) = self.reply_serial {
+            field(5, Value::U32(reply_serial));
+        }
+        if let Some(destination) = &self.destination {
+            field(6, Value::Str(destination.clone()));
+        }
+        if !self.body.is_empty() {
+            let mut sig = String::new();
+            for value in &self.body {
+                value.signature(&mut sig);
+            }
+            field(8, Value::Signature(sig));
+        }
+
+        let mut writer = Writer { buf: Vec::new() };
+        // We always send little endian messages.
+        writer.buf.extend([b'l', self.kind, 0, 1]);
+        // The length of the body is filled in at the end.
+        writer.buf.extend(0u32.to_le_bytes());
+        writer.buf.extend(self.serial.to_le_bytes());
+        writer.value(&Value::Array("(yv)".into(), fields));
+        writer.align(8);
+
+        let body_start = writer.buf.len();
+        for value in &self.body {
+            writer.value(value);
+        }
+        let body_len = (writer.buf.len() - body_start) as u32;
+        writer.buf[4..8].copy_from_slice(&body_len.to_le_bytes());
+
+        writer.buf
+    }
+
+    /// Decodes the first message in the buffer. If the buffer doesn't contain
+    /// a full message yet, [`None`] is returned.
+    fn decode(buf: &[u8]) -> io::Result<Option<(Self, usize)>> {
+        if buf.len() < 16 {
+            return Ok(None);
+        }
+        let big_endian = match buf[0] {
+            b'l' => false,
+            b'B' => true,
+            _ => return Err(invalid_data()),
+        };
+        let mut reader = Reader {
+            buf,
+            pos: 4,
+            big_endian,
+            depth: 0,
+        };
+        let body_len = reader.u32().ok_or_else(invalid_data)? as usize;
+        reader.pos = 12;
+        let fields_len = reader.u32().ok_or_else(invalid_data)? as usize;
+        let header_len = (16 + fields_len).next_multiple_of(8);
+        let len = header_len + body_len;
+        if buf.len() < len {
+            return Ok(None);
+        }
+
+        let mut reader = Reader {
+            buf: &buf[..len],
+            pos: 8,
+            big_endian,
+            depth: 0,
+        };
+        let serial = reader.u32().ok_or_else(invalid_data)?;
+        let Some(Value::Array(_, fields)) = reader.value(b"a(yv)") else {
+            return Err(invalid_data());
+        };
+
+        let mut message = Message {
+            kind: buf[1],
+            serial,
+            ..Default::default()
+        };
+        let mut signature = String::new();
+        for field in fields {
+            let Value::Struct(field) = field else {
+                continue;
+            };
+            let [Value::Byte(code), Value::Variant(value)] =
+                <[Value; 2]>::try_from(field).map_err(|_| invalid_data())?
+            else {
+                return Err(invalid_data());
+            };
+            match (code, *value) {
+                (1, Value::ObjectPath(v)) => message.path = Some(v),
+                (2, Value::Str(v)) => message.interface = Some(v),
+                (3, Value::Str(v)) => message.member = Some(v),
+                (4, Value::Str(v)) => message.error_name = Some(v),
+                (5, Value::U32(v)) => message.reply_serial = Some(v),
+                (6, Value::Str(v)) => message.destination = Some(v),
+                (7, Value::Str(v)) => message.sender = Some(v),
+                (8, Value::Signature(v)) => signature = v,
+                _ => {}
+            }
+        }
+
+        reader.pos = header_len;
+        let mut signature = signature.as_bytes();
+        while !signature.is_empty() {
+            let type_len = type_len(signature).ok_or_else(invalid_data)?;
+            let value = reader
+                .value(&signature[..type_len])
+                .ok_or_else(invalid_data)?;
+            message.body.push(value);
+            signature = &signature[type_len..];
+        }
+
+        Ok(Some((message, len)))
+    }
+}
+
+fn invalid_data() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "Invalid D-Bus message.")
+}
+
+const fn alignment(code: u8) -> usize {
+    match code {
+        b'n' | b'q' => 2,
+        b'b' | b'i' | b'u' | b'h' | b's' | b'o' | b'a' => 4,
+        b'x' | b't' | b'd' | b'(' | b'{' => 8,
+        _ => 1,
+    }
+}
+
+/// The maximum depth of nested containers the specification allows.
+const MAX_DEPTH: usize = 64;
+
+/// Returns the length of the first complete type of the signature. Signatures
+/// that are malformed, like structs without any fields or dictionary entries
+/// that don't consist of a basic type as the key and a value, are rejected.
+fn type_len(sig: &[u8]) -> Option<usize> {
+    match *sig.first()? {
+        b'y' | b'b' | b'n' | b'q' | b'i' | b'u' | b'x' | b't' | b'd' | b'h' | b's' | b'o'
+        | b'g' | b'v' => Some(1),
+        b'a' => Some(1 + type_len(&sig[1..])?),
+        b'(' => {
+            let mut len = 1;
+            while *sig.get(len)? != b')' {
+                len += type_len(&sig[len..])?;
+            }
+            (len > 1).then_some(len + 1)
+        }
+        b'{' => {
+            let key = *sig.get(1)?;
+            if !b"ybnqiuxtdhsog".contains(&key) {
+                return None;
+            }
+            let value_len = type_len(&sig[2..])?;
+            (*sig.get(2 + value_len)? == b'}').then_some(3 + value_len)
+        }
+        _ => None,
+    }
+}
+
+struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn align(&mut self, alignment: usize) {
+        let len = self.buf.len().next_multiple_of(alignment);
+        self.buf.resize(len, 0);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.align(4);
+        self.buf.extend(value.to_le_bytes());
+    }
+
+    fn str(&mut self, value: &str) {
+        self.u32(value.len() as u32);
+        self.buf.extend(value.as_bytes());
+        self.buf.push(0);
+    }
+
+    fn signature(&mut self, value: &str) {
+        self.buf.push(value.len() as u8);
+        self.buf.extend(value.as_bytes());
+        self.buf.push(0);
+    }
+
+    fn value(&mut self, value: &Value) {
+        match value {
+            Value::Byte(v) => self.buf.push(*v),
+            Value::Bool(v) => self.u32(*v as u32),
+            Value::U32(v) => self.u32(*v),
+            Value::U64(v) => {
+                self.align(8);
+                self.buf.extend(v.to_le_bytes());
+            }
+            Value::Str(v) | Value::ObjectPath(v) => self.str(v),
+            Value::Signature(v) => self.signature(v),
+            Value::Array(element, values) => {
+                self.u32(0);
+                let len_pos = self.buf.len() - 4;
+                // The padding to the first element is not part of the length.
+                self.align(alignment(element.as_bytes()[0]));
+                let start = self.buf.len();
+                for value in values {
+                    self.value(value);
+                }
+                let len = (self.buf.len() - start) as u32;
+                self.buf[len_pos..len_pos + 4].copy_from_slice(&len.to_le_bytes());
+            }
+            Value::Struct(fields) => {
+                self.align(8);
+                for field in fields {
+                    self.value(field);
+                }
+            }
+            Value::DictEntry(key, value) => {
+                self.align(8);
+                self.value(key);
+                self.value(value);
+            }
+            Value::Variant(value) => {
+                let mut sig = String::new();
+                value.signature(&mut sig);
+                self.signature(&sig);
+                self.value(value);
+            }
+        }
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    big_endian: bool,
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn align(&mut self, alignment: usize) -> Option<()> {
+        self.pos = self.pos.next_multiple_of(alignment);
+        (self.pos <= self.buf.len()).then_some(())
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.align(N)?;
+        let bytes = self.buf.get(self.pos..self.pos + N)?.try_into().ok()?;
+        self.pos += N;
+        Some(bytes)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        let bytes = self.bytes()?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        let bytes = self.bytes()?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        let bytes = self.bytes()?;
+        Some(if self.big_endian {
+            u64::from_be_bytes(bytes)
+        } else {
+            u64::from_le_bytes(bytes)
+        })
+    }
+
+    fn str_with_len(&mut self, len: usize) -> Option<String> {
+        let end = self.pos.checked_add(len)?;
+        let bytes = self.buf.get(self.pos..end)?;
+        if self.buf.get(end) != Some(&0) {
+            return None;
+        }
+        // Skip the nul terminator.
+        self.pos = end + 1;
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+
+    fn str(&mut self) -> Option<String> {
+        let len = self.u32()? as usize;
+        self.str_with_len(len)
+    }
+
+    fn signature(&mut self) -> Option<String> {
+        let [len] = self.bytes()?;
+        self.str_with_len(len as usize)
+    }
+
+    /// Reads a value of the single complete type provided.
+    fn value(&mut self, sig: &[u8]) -> Option<Value> {
+        if type_len(sig)? != sig.len() {
+            return None;
+        }
+        if sig[0] == b'a' || sig[0] == b'(' || sig[0] == b'{' || sig[0] == b'v' {
+            // Variants can be nested arbitrarily deep, so without a limit a
+            // malicious message could overflow the stack.
+            if self.depth == MAX_DEPTH {
+                return None;
+            }
+            self.depth += 1;
+            let value = self.container(sig);
+            self.depth -= 1;
+            return value;
+        }
+        Some(match sig[0] {
+            b'y' => Value::Byte(self.bytes::<1>()?[0]),
+            b'b' => Value::Bool(self.u32()? != 0),
+            b'n' | b'q' => Value::U32(self.u16()? as u32),
+            b'i' | b'u' | b'h' => Value::U32(self.u32()?),
+            b'x' | b't' | b'd' => Value::U64(self.u64()?),
+            b's' => Value::Str(self.str()?),
+            b'o' => Value::ObjectPath(self.str()?),
+            b'g' => Value::Signature(self.signature()?),
+            _ => return None,
+        })
+    }
+
+    /// Reads a value of a container type. The signature is already validated.
+    fn container(&mut self, sig: &[u8]) -> Option<Value> {
+        Some(match sig[0] {
+            b'v' => {
+                let sig = self.signature()?;
+                Value::Variant(Box::new(self.value(sig.as_bytes())?))
+            }
+            b'a' => {
+                let len = self.u32()? as usize;
+                let element = &sig[1..];
+                self.align(alignment(element[0]))?;
+                let end = self.pos.checked_add(len)?;
+                if end > self.buf.len() {
+                    return None;
+                }
+                let mut values = Vec::new();
+                while self.pos < end {
+                    // Every element takes up at least one byte, so a
+                    // malformed message can't keep us in this loop forever.
+                    let start = self.pos;
+                    values.push(self.value(element)?);
+                    if self.pos == start {
+                        return None;
+                    }
+                }
+                if self.pos != end {
+                    return None;
+                }
+                Value::Array(String::from_utf8(element.to_vec()).ok()?, values)
+            }
+            b'(' => {
+                self.align(8)?;
+                let mut fields = Vec::new();
+                let mut inner = &sig[1..sig.len() - 1];
+                while !inner.is_empty() {
+                    let len = type_len(inner)?;
+                    fields.push(self.value(&inner[..len])?);
+                    inner = &inner[len..];
+                }
+                Value::Struct(fields)
+            }
+            _ => {
+                self.align(8)?;
+                let key = self.value(&sig[1..2])?;
+                let value = self.value(&sig[2..sig.len() - 1])?;
+                Value::DictEntry(Box::new(key), Box::new(value))
+            }
+        })
+    }
+}
+
+/// A connection to the session bus.
+pub struct Connection {
+    stream: UnixStream,
+    incoming: Vec<u8>,
+    serial: u32,
+    unique_name: String,
+}
+
+impl Connection {
+    /// Connects to the session bus and authenticates as the current user.
+    pub fn session() -> io::Result<Self> {
+        let address = env::var("DBUS_SESSION_BUS_ADDRESS")
+            .map_err(|_| io::Error::new(io::ErrorKind::NotFound, "No session bus address."))?;
+        let mut stream = address
+            .split(';')
+            .filter_map(|address| address.strip_prefix("unix:"))
+            .find_map(|params| {
+                params.split(',').find_map(|param| {
+                    let (key, value) = param.split_once('=')?;
+                    let value = unescape(value)?;
+                    let addr = match key {
+                        "path" => SocketAddr::from_pathname(value).ok()?,
+                        "abstract" => SocketAddr::from_abstract_name(value).ok()?,
+                        _ => return None,
+                    };
+                    UnixStream::connect_addr(&addr).ok()
+                })
+            })
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "Failed connecting to the bus.")
+            })?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+
+        let uid = nix::unistd::getuid().to_string();
+        let mut auth = String::from("\0AUTH EXTERNAL ");
+        for byte in uid.bytes() {
+            auth.push_str(&format!("{byte:02x}"));
+        }
+        auth.push_str("\r\n");
+        stream.write_all(auth.as_bytes())?;
+
+        // We read byte by byte, so we don't accidentally consume anything
+        // past the response.
+        let mut response = Vec::new();
+        while !response.ends_with(b"\r\n") {
+            let mut byte = 0;
+            stream.read_exact(std::slice::from_mut(&mut byte))?;
+            response.push(byte);
+        }
+        if !response.starts_with(b"OK ") {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "Failed authenticating with the bus.",
+            ));
+        }
+        stream.write_all(b"BEGIN\r\n")?;
+
+        let mut connection = Self {
+            stream,
+            incoming: Vec::new(),
+            serial: 0,
+            unique_name: String::new(),
+        };
+        let reply = connection.call(Message::method_call(
+            BUS_NAME,
+            BUS_PATH,
+            BUS_NAME,
+            "Hello",
+            Vec::new(),
+        ))?;
+        let Some(Value::Str(unique_name)) = reply.body.into_iter().next() else {
+            return Err(invalid_data());
+        };
+        connection.unique_name = unique_name;
+
+        Ok(connection)
+    }
+
+    /// The name the bus assigned to the connection.
+    pub fn unique_name(&self) -> &str {
+        &self.unique_name
+    }
+
+    /// Sends the message and returns the serial assigned to it.
+    pub fn send(&mut self, mut message: Message) -> io::Result<u32> {
+        self.serial += 1;
+        message.serial = self.serial;
+        self.stream.write_all(&message.encode())?;
+        Ok(self.serial)
+    }
+
+    /// Calls a method and blocks until its reply arrives. Any other message
+    /// received in the meantime gets dropped.
+    pub fn call(&mut self, message: Message) -> io::Result<Message> {
+        let serial = self.send(message)?;
+        loop {
+            let message = self.read()?;
+            if message.reply_serial == Some(serial) {
+                return match message.kind {
+                    ERROR => Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        message.error_name.unwrap_or_default(),
+                    )),
+                    _ => Ok(message),
+                };
+            }
+        }
+    }
+
+    /// Asks the bus to forward the signals matching the rule to us.
+    pub fn add_match(&mut self, rule: &str) -> io::Result<()> {
+        self.call(Message::method_call(
+            BUS_NAME,
+            BUS_PATH,
+            BUS_NAME,
+            "AddMatch",
+            vec![Value::Str(rule.into())],
+        ))
+        .map(drop)
+    }
+
+    /// Blocks until the next message arrives.
+    pub fn read(&mut self) -> io::Result<Message> {
+        loop {
+            if let Some(message) = self.decode()? {
+                return Ok(message);
+            }
+            self.fill()?;
+        }
+    }
+
+    /// Returns all the messages that arrived without blocking.
+    pub fn read_available(&mut self) -> io::Result<Vec<Message>> {
+        self.stream.set_nonblocking(true)?;
+        let result = loop {
+            match self.fill() {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break Ok(()),
+                Err(e) => break Err(e),
+            }
+        };
+        self.stream.set_nonblocking(false)?;
+        result?;
+
+        let mut messages = Vec::new();
+        while let Some(message) = self.decode()? {
+            messages.push(message);
+        }
+        Ok(messages)
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        let mut buf = [0; 4096];
+        let len = self.stream.read(&mut buf)?;
+        if len == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        self.incoming.extend_from_slice(&buf[..len]);
+        Ok(())
+    }
+
+    fn decode(&mut self) -> io::Result<Option<Message>> {
+        let Some((message, len)) = Message::decode(&self.incoming)? else {
+            return Ok(None);
+        };
+        self.incoming.drain(..len);
+        Ok(Some(message))
+    }
+}
+
+impl AsRawFd for Connection {
+    fn as_raw_fd(&self) -> RawFd {
+        self.stream.as_raw_fd()
+    }
+}
+
+/// Removes the percent-encoding of a value in a bus address.
+fn unescape(value: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a signal with the body and its signature provided as is, so
+    /// they can be malformed.
+    fn raw_message(signature: &str, body: &[u8]) -> Vec<u8> {
+        let mut writer = Writer {
+            buf: vec![b'l', SIGNAL, 0, 1],
+        };
+        writer.buf.extend((body.len() as u32).to_le_bytes());
+        writer.buf.extend(1u32.to_le_bytes());
+        writer.value(&Value::Array(
+            "(yv)".into(),
+            vec![Value::Struct(vec![
+                Value::Byte(8),
+                Value::Variant(Box::new(Value::Signature(signature.into()))),
+            ])],
+        ));
+        writer.align(8);
+        writer.buf.extend(body);
+        writer.buf
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut message = Message::method_call(
+            "org.freedesktop.portal.Desktop",
+            "/org/freedesktop/portal/desktop",
+            "org.freedesktop.portal.GlobalShortcuts",
+            "BindShortcuts",
+            vec![
+                Value::Byte(7),
+                Value::Bool(true),
+                Value::U32(42),
+                Value::U64(1 << 40),
+                Value::Str("Start / Split".into()),
+                Value::ObjectPath("/org/freedesktop/portal/desktop/session/1".into()),
+                Value::Signature("a(sa{sv})".into()),
+                Value::Array("(sa{sv})".into(), Vec::new()),
+                Value::Array(
+                    "(sa{sv})".into(),
+                    vec![Value::Struct(vec![
+                        Value::Str("split".into()),
+                        Value::dict([("description", Value::Str("Split".into()))]),
+                    ])],
+                ),
+                Value::dict([
+                    ("handle_token", Value::Str("livesplit".into())),
+                    ("nested", Value::Variant(Box::new(Value::Byte(1)))),
+                ]),
+            ],
+        );
+        message.serial = 3;
+
+        let mut data = message.encode();
+        let (decoded, len) = Message::decode(&data).unwrap().unwrap();
+        assert_eq!(len, data.len());
+        assert_eq!(decoded.kind, METHOD_CALL);
+        assert_eq!(decoded.serial, 3);
+        assert_eq!(decoded.path, message.path);
+        assert_eq!(decoded.interface, message.interface);
+        assert_eq!(decoded.member, message.member);
+        assert_eq!(decoded.destination, message.destination);
+        assert_eq!(decoded.body, message.body);
+
+        // Messages that didn't fully arrive yet are not decoded.
+        for len in 0..data.len() {
+            assert!(Message::decode(&data[..len]).unwrap().is_none());
+        }
+
+        // Anything after the message belongs to the next one.
+        data.extend([b'l', SIGNAL]);
+        assert_eq!(Message::decode(&data).unwrap().unwrap().1, len);
+    }
+
+    #[test]
+    fn type_lengths() {
+        assert_eq!(type_len(b"s"), Some(1));
+        assert_eq!(type_len(b"a{sv}s"), Some(5));
+        assert_eq!(type_len(b"(sa{sv})"), Some(8));
+        assert_eq!(type_len(b"aa(yv)"), Some(6));
+
+        for sig in [
+            &b""[..],
+            b"a",
+            b"z",
+            b"()",
+            b"(s",
+            b"{}",
+            b"{s}",
+            b"{sss}",
+            b"{vs}",
+            b"{(s)s}",
+        ] {
+            assert_eq!(type_len(sig), None, "{}", String::from_utf8_lossy(sig));
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_signatures() {
+        let empty_str = [0, 0, 0, 0, 0, 0, 0, 0];
+        for sig in ["{s}", "()", "a()", "a{s}", "z", "(s"] {
+            assert!(
+                Message::decode(&raw_message(sig, &empty_str)).is_err(),
+                "{sig}",
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_empty_array_elements() {
+        // An array that claims to contain 8 bytes of empty structs would never
+        // make progress.
+        let body = [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert!(Message::decode(&raw_message("a()", &body)).is_err());
+
+        // An array whose length ends within an element is malformed too.
+        let body = [6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0];
+        assert!(Message::decode(&raw_message("au", &body)).is_err());
+
+        // And so is one that claims to be longer than the message.
+        let body = [0xFF, 0xFF, 0xFF, 0xFF];
+        assert!(Message::decode(&raw_message("ay", &body)).is_err());
+    }
+
+    #[test]
+    fn rejects_deeply_nested_variants() {
+        let nested = |depth| {
+            let mut body = Vec::new();
+            for _ in 0..depth {
+                body.extend([1, b'v', 0]);
+            }
+            body.extend([1, b'y', 0, 0]);
+            raw_message("v", &body)
+        };
+        assert!(Message::decode(&nested(10)).unwrap().is_some());
+        assert!(Message::decode(&nested(100)).is_err());
+    }
+
+    #[test]
+    fn rejects_strings_without_terminator() {
+        let body = [1, 0, 0, 0, b'a', b'b'];
+        assert!(Message::decode(&raw_message("s", &body)).is_err());
+    }
+}
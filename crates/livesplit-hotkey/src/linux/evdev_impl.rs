@@ -2,10 +2,10 @@ use std::{collections::hash_map::HashMap, os::unix::prelude::AsRawFd, ptr, threa
 
 use evdev::{Device, EventType, InputEventKind, Key};
 use mio::{unix::SourceFd, Events, Interest, Poll, Token, Waker};
-use x11_dl::xlib::{Xlib, _XDisplay};
+use x11_dl::xlib::{_XDisplay, Xlib};
 
 use super::{x11_impl, Error, Hook, Message};
-use crate::{KeyCode, Modifiers, Result};
+use crate::{hotkey::combinations, Callbacks, Hotkey, KeyCode, Modifiers, Result};
 
 // Low numbered tokens are allocated to devices.
 const PING_TOKEN: Token = Token(usize::MAX);
@@ -235,6 +235,27 @@ pub const fn code_for(key: KeyCode) -> Option<Key> {
     })
 }
 
+// The key, the modifiers and the gamepad button that needs to be held.
+type Combination = (Key, Modifiers, Option<Key>);
+
+fn combination_for(hotkey: Hotkey) -> Option<Combination> {
+    let held_button = match hotkey.held_button {
+        Some(held_button) => Some(code_for(held_button)?),
+        None => None,
+    };
+    Some((code_for(hotkey.key_code)?, hotkey.modifiers, held_button))
+}
+
+const fn is_gamepad_button(key: Key) -> bool {
+    // These are the ranges of the buttons that are mapped to the standard
+    // gamepad in `code_for`.
+    matches!(
+        key.code(),
+        0x130..=0x13e // BTN_SOUTH ..= BTN_THUMBR
+            | 0x220..=0x223 // BTN_DPAD_UP ..= BTN_DPAD_RIGHT
+    )
+}
+
 pub fn new() -> Result<Hook> {
     let (sender, receiver) = crossbeam_channel::unbounded();
     let mut poll = Poll::new().map_err(|_| Error::EPoll)?;
@@ -254,8 +275,12 @@ pub fn new() -> Result<Hook> {
     let join_handle = thread::spawn(move || -> Result<()> {
         let mut result = Ok(());
         let mut events = Events::with_capacity(1024);
-        let mut hotkeys: HashMap<(Key, Modifiers), Box<dyn FnMut() + Send>> = HashMap::new();
+        let mut hotkeys: HashMap<Combination, Callbacks> = HashMap::new();
+        // The combination each currently pressed key triggered, so we know
+        // which hotkey got released.
+        let mut pressed_hotkeys: HashMap<Key, Combination> = HashMap::new();
         let mut modifiers = Modifiers::empty();
+        let mut held_buttons = Vec::new();
 
         let (mut xlib, mut display) = (None, None);
 
@@ -274,8 +299,18 @@ pub fn new() -> Result<Hook> {
                             const PRESSED: i32 = 1;
                             match ev.value() {
                                 PRESSED => {
-                                    if let Some(callback) = hotkeys.get_mut(&(k, modifiers)) {
-                                        callback();
+                                    if let Some((combination, callbacks)) =
+                                        combinations(k, modifiers, held_buttons.iter().copied())
+                                            .find(|combination| hotkeys.contains_key(combination))
+                                            .and_then(|combination| {
+                                                Some((combination, hotkeys.get_mut(&combination)?))
+                                            })
+                                    {
+                                        callbacks.press();
+                                        pressed_hotkeys.insert(k, combination);
+                                    }
+                                    if is_gamepad_button(k) {
+                                        held_buttons.push(k);
                                     }
                                     match k {
                                         Key::KEY_LEFTALT | Key::KEY_RIGHTALT => {
@@ -293,21 +328,30 @@ pub fn new() -> Result<Hook> {
                                         _ => {}
                                     }
                                 }
-                                RELEASED => match k {
-                                    Key::KEY_LEFTALT | Key::KEY_RIGHTALT => {
-                                        modifiers.remove(Modifiers::ALT);
-                                    }
-                                    Key::KEY_LEFTCTRL | Key::KEY_RIGHTCTRL => {
-                                        modifiers.remove(Modifiers::CONTROL);
-                                    }
-                                    Key::KEY_LEFTMETA | Key::KEY_RIGHTMETA => {
-                                        modifiers.remove(Modifiers::META);
+                                RELEASED => {
+                                    if let Some(callbacks) = pressed_hotkeys
+                                        .remove(&k)
+                                        .and_then(|combination| hotkeys.get_mut(&combination))
+                                    {
+                                        callbacks.release();
                                     }
-                                    Key::KEY_LEFTSHIFT | Key::KEY_RIGHTSHIFT => {
-                                        modifiers.remove(Modifiers::SHIFT);
+                                    held_buttons.retain(|&button| button != k);
+                                    match k {
+                                        Key::KEY_LEFTALT | Key::KEY_RIGHTALT => {
+                                            modifiers.remove(Modifiers::ALT);
+                                        }
+                                        Key::KEY_LEFTCTRL | Key::KEY_RIGHTCTRL => {
+                                            modifiers.remove(Modifiers::CONTROL);
+                                        }
+                                        Key::KEY_LEFTMETA | Key::KEY_RIGHTMETA => {
+                                            modifiers.remove(Modifiers::META);
+                                        }
+                                        Key::KEY_LEFTSHIFT | Key::KEY_RIGHTSHIFT => {
+                                            modifiers.remove(Modifiers::SHIFT);
+                                        }
+                                        _ => {}
                                     }
-                                    _ => {}
-                                },
+                                }
                                 _ => {} // Ignore repeating
                             }
                         }
@@ -315,10 +359,12 @@ pub fn new() -> Result<Hook> {
                 } else if mio_event.token() == PING_TOKEN {
                     for message in receiver.try_iter() {
                         match message {
-                            Message::Register(key, callback, promise) => {
+                            Message::Register(key, callbacks, promise) => {
                                 promise.set(
-                                    if code_for(key.key_code)
-                                        .and_then(|k| hotkeys.insert((k, key.modifiers), callback))
+                                    if combination_for(key)
+                                        .and_then(|combination| {
+                                            hotkeys.insert(combination, callbacks)
+                                        })
                                         .is_some()
                                     {
                                         Err(crate::Error::AlreadyRegistered)
@@ -328,8 +374,8 @@ pub fn new() -> Result<Hook> {
                                 );
                             }
                             Message::Unregister(key, promise) => promise.set(
-                                code_for(key.key_code)
-                                    .and_then(|k| hotkeys.remove(&(k, key.modifiers)).map(drop))
+                                combination_for(key)
+                                    .and_then(|combination| hotkeys.remove(&combination).map(drop))
                                     .ok_or(crate::Error::NotRegistered),
                             ),
                             Message::Resolve(key_code, promise) => {
@@ -1,12 +1,14 @@
-use std::{fmt, thread::JoinHandle};
+use std::{env, fmt, thread::JoinHandle};
 
-use crate::{ConsumePreference, Hotkey, KeyCode, Result};
+use crate::{Callbacks, ConsumePreference, Hotkey, KeyCode, Result};
 use crossbeam_channel::Sender;
 use mio::Waker;
 use nix::unistd::{getgroups, Group};
 use promising_future::{future_promise, Promise};
 
+mod dbus;
 mod evdev_impl;
+mod portal_impl;
 mod x11_impl;
 
 #[derive(Debug, Copy, Clone)]
@@ -16,6 +18,8 @@ pub enum Error {
     EPoll,
     NoXLib,
     OpenXServerConnection,
+    Portal,
+    ShortcutsDenied,
     ThreadStopped,
 }
 
@@ -32,17 +36,15 @@ impl fmt::Display for Error {
             Self::EPoll => "Failed polling the event file descriptors.",
             Self::NoXLib => "Failed dynamically linking to X11.",
             Self::OpenXServerConnection => "Failed opening a connection to the X11 server.",
+            Self::Portal => "Failed communicating with the desktop portal for global shortcuts.",
+            Self::ShortcutsDenied => "The desktop portal refused to bind the global shortcuts.",
             Self::ThreadStopped => "The background thread stopped unexpectedly.",
         })
     }
 }
 
 enum Message {
-    Register(
-        Hotkey,
-        Box<dyn FnMut() + Send + 'static>,
-        Promise<Result<()>>,
-    ),
+    Register(Hotkey, Callbacks, Promise<Result<()>>),
     Unregister(Hotkey, Promise<Result<()>>),
     Resolve(KeyCode, Promise<Option<char>>),
     End,
@@ -70,34 +72,57 @@ fn can_use_evdev() -> Option<()> {
     groups.into_iter().find(|&g| g == group).map(drop)
 }
 
+// On Wayland, X11 only receives the keys while one of its own windows is in
+// focus, so it's not suitable for global hotkeys there.
+fn is_wayland() -> bool {
+    env::var_os("WAYLAND_DISPLAY").is_some()
+}
+
+/// The hook to use if the desktop portal fails to bind the shortcuts. On
+/// Wayland, X11 still works while one of our own windows is in focus.
+fn fallback(consume: ConsumePreference) -> Result<Hook> {
+    if !matches!(consume, ConsumePreference::MustConsume) && can_use_evdev().is_some() {
+        evdev_impl::new()
+    } else {
+        x11_impl::new()
+    }
+}
+
 impl Hook {
     pub fn new(consume: ConsumePreference) -> Result<Self> {
         if matches!(consume, ConsumePreference::PreferConsume) {
+            if is_wayland() {
+                if let Ok(portal) = portal_impl::new(consume) {
+                    return Ok(portal);
+                }
+            }
             if let Ok(x11) = x11_impl::new() {
                 return Ok(x11);
             }
         }
 
         if !matches!(consume, ConsumePreference::MustConsume) && can_use_evdev().is_some() {
-            evdev_impl::new().map_err(Into::into)
+            evdev_impl::new()
         } else if !matches!(
             consume,
             ConsumePreference::MustNotConsume | ConsumePreference::PreferConsume
         ) {
-            x11_impl::new().map_err(Into::into)
+            if is_wayland() {
+                if let Ok(portal) = portal_impl::new(consume) {
+                    return Ok(portal);
+                }
+            }
+            x11_impl::new()
         } else {
             Err(crate::Error::UnmatchedPreference)
         }
     }
 
-    pub fn register<F>(&self, hotkey: Hotkey, callback: F) -> Result<()>
-    where
-        F: FnMut() + Send + 'static,
-    {
+    pub fn register(&self, hotkey: Hotkey, callbacks: Callbacks) -> Result<()> {
         let (future, promise) = future_promise();
 
         self.sender
-            .send(Message::Register(hotkey, Box::new(callback), promise))
+            .send(Message::Register(hotkey, callbacks, promise))
             .map_err(|_| Error::ThreadStopped)?;
 
         self.waker.wake().map_err(|_| Error::ThreadStopped)?;
@@ -0,0 +1,410 @@
+use std::{collections::hash_map::HashMap, os::unix::io::AsRawFd, thread, time::Duration};
+
+use mio::{unix::SourceFd, Events, Interest, Poll, Token, Waker};
+
+use super::{
+    dbus::{Connection, Message, Value, ERROR},
+    Error, Hook, Message as HookMessage,
+};
+use crate::{Callbacks, ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
+
+// The XDG desktop portal is the only way for a sandboxed or Wayland
+// application to listen to global hotkeys. Instead of grabbing the keys
+// ourselves, we ask the portal to bind shortcuts, which the compositor then
+// reports back to us whenever they are pressed. The user may decide to bind
+// them to entirely different keys, so the keys we ask for are only a hint.
+// https://flatpak.github.io/xdg-desktop-portal/docs/doc-org.freedesktop.portal.GlobalShortcuts.html
+
+const PORTAL_NAME: &str = "org.freedesktop.portal.Desktop";
+const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+const GLOBAL_SHORTCUTS: &str = "org.freedesktop.portal.GlobalShortcuts";
+const REQUEST: &str = "org.freedesktop.portal.Request";
+const SESSION: &str = "org.freedesktop.portal.Session";
+
+const DBUS_TOKEN: Token = Token(0);
+const PING_TOKEN: Token = Token(1);
+
+// Hotkeys usually get registered in quick succession. Binding the shortcuts
+// may show a dialog to the user, so we wait for the registrations to settle
+// before binding all of them at once.
+const BIND_DELAY: Duration = Duration::from_millis(100);
+
+struct Portal {
+    connection: Connection,
+    session: String,
+    is_bound: bool,
+    token: u32,
+    /// The serial of the call that binds the shortcuts and the path of the
+    /// request object the portal responds on, while the response is pending.
+    pending_bind: Option<(u32, String)>,
+}
+
+impl Portal {
+    fn next_token(&mut self) -> String {
+        self.token += 1;
+        format!("livesplit_{}", self.token)
+    }
+
+    /// The portal responds with a signal on a request object that is derived
+    /// from the token and our unique name.
+    fn request_path(&self, handle_token: &str) -> String {
+        let sender = self.connection.unique_name()[1..].replace('.', "_");
+        format!("{PORTAL_PATH}/request/{sender}/{handle_token}")
+    }
+
+    fn create_session(&mut self) -> std::io::Result<()> {
+        let handle_token = self.next_token();
+        let session_token = self.next_token();
+        let request_path = self.request_path(&handle_token);
+
+        let serial = self.connection.send(Message::method_call(
+            PORTAL_NAME,
+            PORTAL_PATH,
+            GLOBAL_SHORTCUTS,
+            "CreateSession",
+            vec![Value::dict([
+                ("handle_token", Value::Str(handle_token)),
+                ("session_handle_token", Value::Str(session_token)),
+            ])],
+        ))?;
+
+        loop {
+            let message = self.connection.read()?;
+            if message.reply_serial == Some(serial) && message.kind == ERROR {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    message.error_name.unwrap_or_default(),
+                ));
+            }
+            if message.is_signal(REQUEST, "Response")
+                && message.path.as_deref() == Some(&request_path)
+            {
+                let [Value::U32(0), results] = &message.body[..] else {
+                    return Err(std::io::ErrorKind::PermissionDenied.into());
+                };
+                // The session handle is specified as a string for historical
+                // reasons, but some implementations return an object path.
+                let Some(Value::Str(session) | Value::ObjectPath(session)) =
+                    results.get("session_handle")
+                else {
+                    return Err(std::io::ErrorKind::InvalidData.into());
+                };
+                self.session = session.clone();
+                self.is_bound = false;
+                return Ok(());
+            }
+        }
+    }
+
+    /// Asks the portal to bind the shortcuts. This may show a dialog to the
+    /// user, so the response only arrives later on and needs to be checked
+    /// with [`bind_response`](Self::bind_response).
+    fn bind_shortcuts(&mut self, hotkeys: &HashMap<Hotkey, Callbacks>) -> std::io::Result<()> {
+        // Some portal implementations only allow binding the shortcuts of a
+        // session once, so rebinding them requires a new session.
+        if self.is_bound {
+            self.connection.call(Message::method_call(
+                PORTAL_NAME,
+                &self.session,
+                SESSION,
+                "Close",
+                Vec::new(),
+            ))?;
+            self.create_session()?;
+        }
+
+        let shortcuts = hotkeys
+            .keys()
+            .map(|hotkey| {
+                let name = hotkey.to_string();
+                let mut options = vec![("description", Value::Str(name.clone()))];
+                if let Some(trigger) = preferred_trigger(*hotkey) {
+                    options.push(("preferred_trigger", Value::Str(trigger)));
+                }
+                Value::Struct(vec![Value::Str(name), Value::dict(options)])
+            })
+            .collect();
+
+        let handle_token = self.next_token();
+        let request_path = self.request_path(&handle_token);
+        let serial = self.connection.send(Message::method_call(
+            PORTAL_NAME,
+            PORTAL_PATH,
+            GLOBAL_SHORTCUTS,
+            "BindShortcuts",
+            vec![
+                Value::ObjectPath(self.session.clone()),
+                Value::Array("(sa{sv})".into(), shortcuts),
+                Value::Str(String::new()),
+                Value::dict([("handle_token", Value::Str(handle_token))]),
+            ],
+        ))?;
+        self.pending_bind = Some((serial, request_path));
+        self.is_bound = true;
+        Ok(())
+    }
+
+    /// Checks whether the message is the response to binding the shortcuts.
+    /// If it is, this returns whether the shortcuts got bound. The call
+    /// itself may fail or the user may deny the request.
+    fn bind_response(&mut self, message: &Message) -> Option<bool> {
+        let (serial, request_path) = self.pending_bind.as_ref()?;
+        let is_bound = if message.reply_serial == Some(*serial) {
+            if message.kind != ERROR {
+                return None;
+            }
+            false
+        } else if message.is_signal(REQUEST, "Response")
+            && message.path.as_deref() == Some(request_path)
+        {
+            matches!(message.body.first(), Some(Value::U32(0)))
+        } else {
+            return None;
+        };
+        self.pending_bind = None;
+        Some(is_bound)
+    }
+}
+
+/// Moves the hotkeys over to the hook that would've been chosen if the portal
+/// wasn't available at all.
+fn fall_back(consume: ConsumePreference, hotkeys: &mut HashMap<Hotkey, Callbacks>) -> Option<Hook> {
+    let hook = super::fallback(consume).ok()?;
+    for (hotkey, callbacks) in hotkeys.drain() {
+        let _ = hook.register(hotkey, callbacks);
+    }
+    Some(hook)
+}
+
+pub fn new(consume: ConsumePreference) -> Result<Hook> {
+    let (sender, receiver) = crossbeam_channel::unbounded();
+
+    let mut connection = Connection::session().map_err(|_| Error::Portal)?;
+    for rule in [
+        "type='signal',interface='org.freedesktop.portal.Request',member='Response'",
+        "type='signal',interface='org.freedesktop.portal.GlobalShortcuts',member='Activated'",
+        "type='signal',interface='org.freedesktop.portal.GlobalShortcuts',member='Deactivated'",
+    ] {
+        connection.add_match(rule).map_err(|_| Error::Portal)?;
+    }
+
+    // Creating the session right away ensures that we can fall back to a
+    // different implementation if the portal is not available.
+    let mut portal = Portal {
+        connection,
+        session: String::new(),
+        is_bound: false,
+        token: 0,
+        pending_bind: None,
+    };
+    portal.create_session().map_err(|_| Error::Portal)?;
+
+    let mut poll = Poll::new().map_err(|_| Error::EPoll)?;
+    let waker = Waker::new(poll.registry(), PING_TOKEN).map_err(|_| Error::EPoll)?;
+    poll.registry()
+        .register(
+            &mut SourceFd(&portal.connection.as_raw_fd()),
+            DBUS_TOKEN,
+            Interest::READABLE,
+        )
+        .map_err(|_| Error::EPoll)?;
+
+    let join_handle = thread::spawn(move || -> Result<()> {
+        let mut result = Ok(());
+        let mut events = Events::with_capacity(1024);
+        let mut hotkeys = HashMap::<Hotkey, Callbacks>::new();
+        let mut needs_binding = false;
+        // If the portal fails to bind the shortcuts, such as when the user
+        // denies the request, the hotkeys are handled by a different hook from
+        // then on. If there is none, registering hotkeys fails from then on.
+        let mut fallback = None::<Hook>;
+        let mut is_denied = false;
+
+        'event_loop: loop {
+            let timeout = needs_binding.then_some(BIND_DELAY);
+            if poll.poll(&mut events, timeout).is_err() {
+                result = Err(Error::EPoll);
+                break 'event_loop;
+            }
+
+            if events.is_empty() && needs_binding {
+                needs_binding = false;
+                if portal.bind_shortcuts(&hotkeys).is_err() {
+                    fallback = fall_back(consume, &mut hotkeys);
+                    is_denied = fallback.is_none();
+                }
+            }
+
+            for mio_event in &events {
+                if mio_event.token() == PING_TOKEN {
+                    for message in receiver.try_iter() {
+                        match message {
+                            HookMessage::Register(key, callbacks, promise) => {
+                                promise.set(if let Some(fallback) = &fallback {
+                                    fallback.register(key, callbacks)
+                                } else if is_denied {
+                                    Err(Error::ShortcutsDenied.into())
+                                } else if hotkeys.insert(key, callbacks).is_some() {
+                                    Err(crate::Error::AlreadyRegistered)
+                                } else {
+                                    needs_binding = true;
+                                    Ok(())
+                                });
+                            }
+                            HookMessage::Unregister(key, promise) => {
+                                promise.set(if let Some(fallback) = &fallback {
+                                    fallback.unregister(key)
+                                } else if hotkeys.remove(&key).is_some() {
+                                    needs_binding = !is_denied;
+                                    Ok(())
+                                } else {
+                                    Err(crate::Error::NotRegistered)
+                                });
+                            }
+                            HookMessage::Resolve(key_code, promise) => {
+                                // The portal has no knowledge of the keyboard
+                                // layout.
+                                promise.set(fallback.as_ref().and_then(|fallback| {
+                                    fallback.try_resolve(key_code)?.chars().next()
+                                }));
+                            }
+                            HookMessage::End => {
+                                break 'event_loop;
+                            }
+                        }
+                    }
+                } else if mio_event.token() == DBUS_TOKEN {
+                    let Ok(messages) = portal.connection.read_available() else {
+                        result = Err(Error::Portal);
+                        break 'event_loop;
+                    };
+                    for message in messages {
+                        if portal.bind_response(&message) == Some(false) {
+                            fallback = fall_back(consume, &mut hotkeys);
+                            is_denied = fallback.is_none();
+                            needs_binding = false;
+                            continue;
+                        }
+                        let is_activated = message.is_signal(GLOBAL_SHORTCUTS, "Activated");
+                        if !is_activated && !message.is_signal(GLOBAL_SHORTCUTS, "Deactivated") {
+                            continue;
+                        }
+                        if let [Value::ObjectPath(session), Value::Str(id), ..] = &message.body[..]
+                        {
+                            if *session == portal.session {
+                                if let Some(callbacks) = id
+                                    .parse::<Hotkey>()
+                                    .ok()
+                                    .and_then(|hotkey| hotkeys.get_mut(&hotkey))
+                                {
+                                    if is_activated {
+                                        callbacks.press();
+                                    } else {
+                                        callbacks.release();
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let _ = portal.connection.send(Message::method_call(
+            PORTAL_NAME,
+            &portal.session,
+            SESSION,
+            "Close",
+            Vec::new(),
+        ));
+
+        result.map_err(Into::into)
+    });
+
+    Ok(Hook {
+        sender,
+        waker,
+        join_handle: Some(join_handle),
+    })
+}
+
+/// Describes the hotkey in the format the portal uses for its triggers, which
+/// are modifiers followed by the name of the XKB key symbol.
+fn preferred_trigger(hotkey: Hotkey) -> Option<String> {
+    use self::KeyCode::*;
+    let key = match hotkey.key_code {
+        KeyA | KeyB | KeyC | KeyD | KeyE | KeyF | KeyG | KeyH | KeyI | KeyJ | KeyK | KeyL
+        | KeyM | KeyN | KeyO | KeyP | KeyQ | KeyR | KeyS | KeyT | KeyU | KeyV | KeyW | KeyX
+        | KeyY | KeyZ => &hotkey.key_code.name()[3..],
+        Digit0 | Digit1 | Digit2 | Digit3 | Digit4 | Digit5 | Digit6 | Digit7 | Digit8 | Digit9 => {
+            &hotkey.key_code.name()[5..]
+        }
+        Numpad0 => "KP_0",
+        Numpad1 => "KP_1",
+        Numpad2 => "KP_2",
+        Numpad3 => "KP_3",
+        Numpad4 => "KP_4",
+        Numpad5 => "KP_5",
+        Numpad6 => "KP_6",
+        Numpad7 => "KP_7",
+        Numpad8 => "KP_8",
+        Numpad9 => "KP_9",
+        NumpadAdd => "KP_Add",
+        NumpadSubtract => "KP_Subtract",
+        NumpadMultiply => "KP_Multiply",
+        NumpadDivide => "KP_Divide",
+        NumpadDecimal => "KP_Decimal",
+        NumpadEnter => "KP_Enter",
+        F1 | F2 | F3 | F4 | F5 | F6 | F7 | F8 | F9 | F10 | F11 | F12 | F13 | F14 | F15 | F16
+        | F17 | F18 | F19 | F20 | F21 | F22 | F23 | F24 => hotkey.key_code.name(),
+        Escape => "Escape",
+        Tab => "Tab",
+        Space => "space",
+        Enter => "Return",
+        Backspace => "BackSpace",
+        Insert => "Insert",
+        Delete => "Delete",
+        Home => "Home",
+        End => "End",
+        PageUp => "Page_Up",
+        PageDown => "Page_Down",
+        ArrowUp => "Up",
+        ArrowDown => "Down",
+        ArrowLeft => "Left",
+        ArrowRight => "Right",
+        Minus => "minus",
+        Equal => "equal",
+        Comma => "comma",
+        Period => "period",
+        Slash => "slash",
+        Backslash => "backslash",
+        Semicolon => "semicolon",
+        Quote => "apostrophe",
+        Backquote => "grave",
+        BracketLeft => "bracketleft",
+        BracketRight => "bracketright",
+        PrintScreen => "Print",
+        ScrollLock => "Scroll_Lock",
+        Pause => "Pause",
+        _ => return None,
+    };
+
+    let mut trigger = String::new();
+    for (modifier, name) in [
+        (Modifiers::CONTROL, "CTRL+"),
+        (Modifiers::ALT, "ALT+"),
+        (Modifiers::SHIFT, "SHIFT+"),
+        (Modifiers::META, "LOGO+"),
+    ] {
+        if hotkey.modifiers.contains(modifier) {
+            trigger.push_str(name);
+        }
+    }
+    if key.len() == 1 {
+        // Letters are named after their lowercase variant.
+        trigger.push_str(&key.to_ascii_lowercase());
+    } else {
+        trigger.push_str(key);
+    }
+    Some(trigger)
+}
@@ -7,12 +7,12 @@ use std::{
 
 use mio::{unix::SourceFd, Events, Interest, Poll, Token, Waker};
 use x11_dl::xlib::{
-    AnyKey, AnyModifier, ControlMask, Display, GrabModeAsync, KeyPress, LockMask, Mod1Mask,
-    Mod2Mask, Mod3Mask, Mod4Mask, ShiftMask, XErrorEvent, XKeyEvent, Xlib, _XDisplay,
+    _XDisplay, AnyKey, AnyModifier, ControlMask, Display, GrabModeAsync, KeyPress, KeyRelease,
+    LockMask, Mod1Mask, Mod2Mask, Mod3Mask, Mod4Mask, ShiftMask, XErrorEvent, XKeyEvent, Xlib,
 };
 
 use super::{Error, Hook, Message};
-use crate::{KeyCode, Modifiers, Result};
+use crate::{Callbacks, KeyCode, Modifiers, Result};
 
 unsafe fn ungrab_all(xlib: &Xlib, display: *mut Display) {
     let screencount = (xlib.XScreenCount)(display);
@@ -49,6 +49,12 @@ const IGNORE_MASKS: [u8; 8] = [
     SCROLL_LOCK | NUM_LOCK | CAPS_LOCK,
 ];
 
+fn supersets(modifiers: Modifiers) -> impl Iterator<Item = Modifiers> {
+    (0..=Modifiers::all().bits())
+        .map(Modifiers::from_bits_retain)
+        .filter(move |&other| other.contains(modifiers))
+}
+
 unsafe fn grab_key(
     xlib: &Xlib,
     display: *mut Display,
@@ -150,12 +156,21 @@ pub fn new() -> Result<Hook> {
 
             let mut result = Ok(());
             let mut events = Events::with_capacity(1024);
-            let mut hotkeys = HashMap::new();
+            let mut hotkeys = HashMap::<_, Callbacks>::new();
+            // The modifiers of the hotkey each currently pressed key
+            // triggered, so we know which hotkey got released.
+            let mut pressed_hotkeys = HashMap::new();
 
             // For some reason we need to call this once for any KeyGrabs to
             // actually do anything.
             (xlib.XKeysymToKeycode)(display, 0);
 
+            // By default a held key repeatedly reports being released and
+            // pressed again. This way it only repeatedly reports being pressed,
+            // so the release is only reported once the key is actually
+            // released.
+            (xlib.XkbSetDetectableAutoRepeat)(display, 1, ptr::null_mut());
+
             'event_loop: loop {
                 if poll.poll(&mut events, None).is_err() {
                     result = Err(Error::EPoll);
@@ -166,13 +181,25 @@ pub fn new() -> Result<Hook> {
                     if mio_event.token() == PING_TOKEN {
                         for message in receiver.try_iter() {
                             match message {
-                                Message::Register(key, callback, promise) => {
-                                    promise.set(if let Some(code) = code_for(key.key_code) {
-                                        if hotkeys.insert((code, key.modifiers), callback).is_some()
+                                Message::Register(key, callbacks, promise) => {
+                                    // X11 doesn't report any gamepad buttons,
+                                    // so combinations of them are ignored.
+                                    let code = code_for(key.key_code)
+                                        .filter(|_| key.held_button.is_none());
+                                    promise.set(if let Some(code) = code {
+                                        if hotkeys
+                                            .insert((code, key.modifiers), callbacks)
+                                            .is_some()
                                         {
                                             Err(crate::Error::AlreadyRegistered)
                                         } else {
-                                            grab_key(&xlib, display, code, key.modifiers, false);
+                                            // The hotkey also needs to trigger when
+                                            // additional modifiers are held, so
+                                            // all of those combinations need to be
+                                            // grabbed as well.
+                                            for modifiers in supersets(key.modifiers) {
+                                                grab_key(&xlib, display, code, modifiers, false);
+                                            }
                                             Ok(())
                                         }
                                     } else {
@@ -180,13 +207,23 @@ pub fn new() -> Result<Hook> {
                                     });
                                 }
                                 Message::Unregister(key, promise) => {
-                                    let res = if let Some(code) = code_for(key.key_code) {
+                                    let code = code_for(key.key_code)
+                                        .filter(|_| key.held_button.is_none());
+                                    let res = if let Some(code) = code {
                                         let res = hotkeys
                                             .remove(&(code, key.modifiers))
                                             .map(drop)
                                             .ok_or(crate::Error::NotRegistered);
                                         if res.is_ok() {
-                                            grab_key(&xlib, display, code, key.modifiers, true);
+                                            // Combinations that still trigger
+                                            // another hotkey need to stay grabbed.
+                                            for modifiers in supersets(key.modifiers) {
+                                                if !hotkeys.keys().any(|&(c, other)| {
+                                                    c == code && modifiers.contains(other)
+                                                }) {
+                                                    grab_key(&xlib, display, code, modifiers, true);
+                                                }
+                                            }
                                         }
                                         res
                                     } else {
@@ -208,8 +245,20 @@ pub fn new() -> Result<Hook> {
                             let err_code = (xlib.XNextEvent)(display, event.as_mut_ptr());
                             if err_code == 0 {
                                 let event = event.assume_init();
-                                if event.get_type() == KeyPress {
+                                if event.get_type() == KeyRelease {
                                     let event: &XKeyEvent = event.as_ref();
+                                    if let Some(callbacks) = pressed_hotkeys
+                                        .remove(&event.keycode)
+                                        .and_then(|m| hotkeys.get_mut(&(event.keycode, m)))
+                                    {
+                                        callbacks.release();
+                                    }
+                                } else if event.get_type() == KeyPress {
+                                    let event: &XKeyEvent = event.as_ref();
+                                    if pressed_hotkeys.contains_key(&event.keycode) {
+                                        // Ignore repeating
+                                        continue;
+                                    }
 
                                     let mut modifiers = Modifiers::empty();
                                     if event.state & ShiftMask != 0 {
@@ -225,10 +274,16 @@ pub fn new() -> Result<Hook> {
                                         modifiers.insert(Modifiers::META);
                                     }
 
-                                    if let Some(callback) =
-                                        hotkeys.get_mut(&(event.keycode, modifiers))
+                                    if let Some(m) = modifiers
+                                        .subsets()
+                                        .find(|&m| hotkeys.contains_key(&(event.keycode, m)))
                                     {
-                                        callback();
+                                        if let Some(callbacks) =
+                                            hotkeys.get_mut(&(event.keycode, m))
+                                        {
+                                            callbacks.press();
+                                        }
+                                        pressed_hotkeys.insert(event.keycode, m);
                                     }
                                 }
                             }
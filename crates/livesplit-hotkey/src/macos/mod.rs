@@ -20,7 +20,7 @@ use self::{
         EventRef, EventTapLocation, EventTapOptions, EventTapPlacement, EventTapProxy, EventType,
     },
 };
-use crate::{ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
+use crate::{Callbacks, ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
 use core::ptr::null_mut;
 use std::{
     collections::{hash_map::Entry, HashMap},
@@ -75,7 +75,10 @@ unsafe impl Send for RunLoop {}
 unsafe impl Sync for RunLoop {}
 
 struct State {
-    hotkeys: Mutex<HashMap<Hotkey, Box<dyn FnMut() + Send + 'static>>>,
+    hotkeys: Mutex<HashMap<Hotkey, Callbacks>>,
+    // The hotkey each currently pressed key triggered, so we know which hotkey
+    // got released.
+    pressed_hotkeys: Mutex<HashMap<KeyCode, Hotkey>>,
 }
 
 /// A hook allows you to listen to hotkeys.
@@ -105,6 +108,7 @@ impl Hook {
 
         let state = Arc::new(State {
             hotkeys: Mutex::new(HashMap::new()),
+            pressed_hotkeys: Mutex::new(HashMap::new()),
         });
         let thread_state = state.clone();
 
@@ -131,7 +135,7 @@ impl Hook {
                 } else {
                     EventTapOptions::LISTEN_ONLY
                 },
-                EventMask::KEY_DOWN,
+                EventMask::KEY_DOWN | EventMask::KEY_UP,
                 Some(callback),
                 state_ptr as *mut c_void,
             );
@@ -173,12 +177,9 @@ impl Hook {
         Ok(Hook { event_loop, state })
     }
 
-    pub fn register<F>(&self, hotkey: Hotkey, callback: F) -> Result<()>
-    where
-        F: FnMut() + Send + 'static,
-    {
+    pub fn register(&self, hotkey: Hotkey, callbacks: Callbacks) -> Result<()> {
         if let Entry::Vacant(vacant) = self.state.hotkeys.lock().unwrap().entry(hotkey) {
-            vacant.insert(Box::new(callback));
+            vacant.insert(callbacks);
             Ok(())
         } else {
             Err(crate::Error::AlreadyRegistered)
@@ -304,7 +305,7 @@ impl Hook {
 
 unsafe extern "C" fn callback(
     _: EventTapProxy,
-    _: EventType,
+    event_type: EventType,
     event: EventRef,
     user_info: *mut c_void,
 ) -> EventRef {
@@ -452,6 +453,17 @@ unsafe extern "C" fn callback(
     let state = user_info as *const State;
     let state = &*state;
 
+    if event_type == EventType::KEY_UP {
+        let Some(hotkey) = state.pressed_hotkeys.lock().unwrap().remove(&key_code) else {
+            return event;
+        };
+        if let Some(callbacks) = state.hotkeys.lock().unwrap().get_mut(&hotkey) {
+            callbacks.release();
+        }
+        // The key press got handled, so its release is handled as well.
+        return null_mut();
+    }
+
     let modifier_flags = CGEventGetFlags(event);
     let mut modifiers = Modifiers::empty();
 
@@ -479,13 +491,20 @@ unsafe extern "C" fn callback(
         modifiers.insert(Modifiers::META);
     }
 
-    if let Some(callback) = state
-        .hotkeys
-        .lock()
-        .unwrap()
-        .get_mut(&key_code.with_modifiers(modifiers))
+    let mut hotkeys = state.hotkeys.lock().unwrap();
+    if let Some(hotkey) = modifiers
+        .subsets()
+        .map(|m| key_code.with_modifiers(m))
+        .find(|hotkey| hotkeys.contains_key(hotkey))
     {
-        callback();
+        if let Some(callbacks) = hotkeys.get_mut(&hotkey) {
+            callbacks.press();
+        }
+        state
+            .pressed_hotkeys
+            .lock()
+            .unwrap()
+            .insert(key_code, hotkey);
 
         // If we handled the event and the hook is consuming, we should return
         // null so the system deletes the event. If the hook is not consuming
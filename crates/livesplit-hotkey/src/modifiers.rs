@@ -17,6 +17,23 @@ bitflags::bitflags! {
     }
 }
 
+impl Modifiers {
+    /// Returns every combination of the modifiers that are pressed, starting
+    /// with the most specific one. A hotkey is triggered by the first of these
+    /// combinations that it is registered with, so holding additional
+    /// modifiers, such as shift for sprinting in a game, doesn't prevent it
+    /// from triggering.
+    #[allow(dead_code)] // The stubbed out implementation never receives keys.
+    pub(crate) fn subsets(self) -> impl Iterator<Item = Modifiers> {
+        let bits = self.bits();
+        (0..=bits.count_ones()).rev().flat_map(move |count| {
+            (0..=Self::all().bits())
+                .filter(move |&subset| subset & !bits == 0 && subset.count_ones() == count)
+                .map(Self::from_bits_retain)
+        })
+    }
+}
+
 impl fmt::Display for Modifiers {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut first = true;
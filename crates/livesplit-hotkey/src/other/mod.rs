@@ -1,4 +1,4 @@
-use crate::{ConsumePreference, Hotkey, KeyCode, Result};
+use crate::{Callbacks, ConsumePreference, Hotkey, KeyCode, Result};
 use alloc::{fmt, string::String};
 
 #[derive(Debug)]
@@ -21,10 +21,7 @@ impl Hook {
     }
 
     #[inline]
-    pub fn register<F>(&self, _: Hotkey, _: F) -> Result<()>
-    where
-        F: FnMut() + Send + 'static,
-    {
+    pub fn register(&self, _: Hotkey, _: Callbacks) -> Result<()> {
         Ok(())
     }
 
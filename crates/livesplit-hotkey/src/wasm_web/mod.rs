@@ -1,4 +1,6 @@
-use crate::{ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
+use crate::{
+    hotkey::combinations, Callbacks, ConsumePreference, Hotkey, KeyCode, Modifiers, Result,
+};
 use js_sys::{Function, Promise, Reflect};
 use wasm_bindgen::{prelude::*, JsCast};
 use web_sys::{window, Event, Gamepad, GamepadButton, KeyboardEvent};
@@ -26,8 +28,9 @@ impl fmt::Display for Error {
 }
 
 pub struct Hook {
-    hotkeys: Arc<Mutex<HashMap<Hotkey, Box<dyn FnMut() + Send + 'static>>>>,
+    hotkeys: Arc<Mutex<HashMap<Hotkey, Callbacks>>>,
     keyboard_callback: Closure<dyn FnMut(Event)>,
+    keyboard_release_callback: Closure<dyn FnMut(Event)>,
     gamepad_callback: Closure<dyn FnMut()>,
     interval_id: Cell<Option<i32>>,
     keyboard_layout_resolver: Rc<RefCell<Option<(JsValue, Function)>>>,
@@ -41,6 +44,10 @@ impl Drop for Hook {
                 "keydown",
                 self.keyboard_callback.as_ref().unchecked_ref(),
             );
+            let _ = window.remove_event_listener_with_callback(
+                "keyup",
+                self.keyboard_release_callback.as_ref().unchecked_ref(),
+            );
             if let Some(interval_id) = self.interval_id.get() {
                 window.clear_interval_with_handle(interval_id);
             }
@@ -79,14 +86,15 @@ impl Hook {
             ConsumePreference::PreferConsume | ConsumePreference::MustConsume
         );
 
-        let hotkeys = Arc::new(Mutex::new(HashMap::<
-            Hotkey,
-            Box<dyn FnMut() + Send + 'static>,
-        >::new()));
+        let hotkeys = Arc::new(Mutex::new(HashMap::<Hotkey, Callbacks>::new()));
+        // The hotkey each currently pressed key triggered, so we know which
+        // hotkey got released.
+        let pressed_hotkeys = Arc::new(Mutex::new(HashMap::<KeyCode, Hotkey>::new()));
 
         let window = window().ok_or(crate::Error::Platform(Error::FailedToCreateHook))?;
 
         let hotkey_map = hotkeys.clone();
+        let pressed_map = pressed_hotkeys.clone();
         let keyboard_callback = Closure::wrap(Box::new(move |event: Event| {
             // Despite all sorts of documentation claiming that `keydown` events
             // pass you a `KeyboardEvent`, this is not actually always the case
@@ -116,12 +124,16 @@ impl Hook {
                             modifiers.insert(Modifiers::META);
                         }
 
-                        if let Some(callback) = hotkey_map
-                            .lock()
-                            .unwrap()
-                            .get_mut(&code.with_modifiers(modifiers))
+                        let mut hotkey_map = hotkey_map.lock().unwrap();
+                        if let Some(hotkey) = modifiers
+                            .subsets()
+                            .map(|m| code.with_modifiers(m))
+                            .find(|hotkey| hotkey_map.contains_key(hotkey))
                         {
-                            callback();
+                            if let Some(callbacks) = hotkey_map.get_mut(&hotkey) {
+                                callbacks.press();
+                            }
+                            pressed_map.lock().unwrap().insert(code, hotkey);
                             if prevent_default {
                                 event.prevent_default();
                             }
@@ -135,6 +147,31 @@ impl Hook {
             .add_event_listener_with_callback("keydown", keyboard_callback.as_ref().unchecked_ref())
             .map_err(|_| crate::Error::Platform(Error::FailedToCreateHook))?;
 
+        let hotkey_map = hotkeys.clone();
+        let pressed_map = pressed_hotkeys.clone();
+        let keyboard_release_callback = Closure::wrap(Box::new(move |event: Event| {
+            if let Ok(event) = event.dyn_into::<KeyboardEvent>() {
+                if let Ok(code) = event.code().parse::<KeyCode>() {
+                    let hotkey = pressed_map.lock().unwrap().remove(&code);
+                    if let Some(hotkey) = hotkey {
+                        if let Some(callbacks) = hotkey_map.lock().unwrap().get_mut(&hotkey) {
+                            callbacks.release();
+                        }
+                        if prevent_default {
+                            event.prevent_default();
+                        }
+                    }
+                }
+            }
+        }) as Box<dyn FnMut(Event)>);
+
+        window
+            .add_event_listener_with_callback(
+                "keyup",
+                keyboard_release_callback.as_ref().unchecked_ref(),
+            )
+            .map_err(|_| crate::Error::Platform(Error::FailedToCreateHook))?;
+
         let hotkey_map = hotkeys.clone();
 
         let mut states = Vec::new();
@@ -178,6 +215,12 @@ impl Hook {
                 }
                 for (gamepad, states) in gamepads.iter().zip(&mut states) {
                     if let Ok(gamepad) = gamepad.dyn_into::<Gamepad>() {
+                        let previous_states = *states;
+                        let held_buttons = GAMEPAD_BUTTONS
+                            .iter()
+                            .zip(previous_states)
+                            .filter(|(_, held)| *held)
+                            .map(|(&code, _)| code);
                         for ((button, code), state) in gamepad
                             .buttons()
                             .iter()
@@ -187,10 +230,29 @@ impl Hook {
                             if let Ok(button) = button.dyn_into::<GamepadButton>() {
                                 let pressed = button.pressed();
                                 if pressed && !*state {
-                                    if let Some(callback) =
-                                        hotkey_map.lock().unwrap().get_mut(&code.into())
+                                    let mut hotkey_map = hotkey_map.lock().unwrap();
+                                    if let Some(hotkey) =
+                                        combinations(code, Modifiers::empty(), held_buttons.clone())
+                                            .map(|(key_code, modifiers, held_button)| Hotkey {
+                                                key_code,
+                                                modifiers,
+                                                held_button,
+                                            })
+                                            .find(|hotkey| hotkey_map.contains_key(hotkey))
                                     {
-                                        callback();
+                                        if let Some(callbacks) = hotkey_map.get_mut(&hotkey) {
+                                            callbacks.press();
+                                        }
+                                        pressed_hotkeys.lock().unwrap().insert(code, hotkey);
+                                    }
+                                } else if !pressed && *state {
+                                    let hotkey = pressed_hotkeys.lock().unwrap().remove(&code);
+                                    if let Some(hotkey) = hotkey {
+                                        if let Some(callbacks) =
+                                            hotkey_map.lock().unwrap().get_mut(&hotkey)
+                                        {
+                                            callbacks.release();
+                                        }
                                     }
                                 }
                                 *state = pressed;
@@ -204,6 +266,7 @@ impl Hook {
         Ok(Hook {
             hotkeys,
             keyboard_callback,
+            keyboard_release_callback,
             gamepad_callback,
             interval_id: Cell::new(None),
             keyboard_layout_resolver,
@@ -211,10 +274,7 @@ impl Hook {
         })
     }
 
-    pub fn register<F>(&self, hotkey: Hotkey, callback: F) -> Result<()>
-    where
-        F: FnMut() + Send + 'static,
-    {
+    pub fn register(&self, hotkey: Hotkey, callbacks: Callbacks) -> Result<()> {
         if let Entry::Vacant(vacant) = self.hotkeys.lock().unwrap().entry(hotkey) {
             if GAMEPAD_BUTTONS.contains(&hotkey.key_code) && self.interval_id.get().is_none() {
                 let interval_id = window()
@@ -226,7 +286,7 @@ impl Hook {
                     .map_err(|_| crate::Error::Platform(Error::FailedToCreateHook))?;
                 self.interval_id.set(Some(interval_id));
             }
-            vacant.insert(Box::new(callback));
+            vacant.insert(callbacks);
             Ok(())
         } else {
             Err(crate::Error::AlreadyRegistered)
@@ -1,21 +1,37 @@
-use crate::{ConsumePreference, Hotkey, KeyCode, Modifiers, Result};
+use crate::{
+    hotkey::combinations, Callbacks, ConsumePreference, Hotkey, KeyCode, KeyCodeClass, Modifiers,
+    Result,
+};
 use std::{
     cell::RefCell,
     collections::hash_map::{Entry, HashMap},
-    fmt, mem, ptr,
+    fmt,
+    hash::Hash,
+    mem, ptr,
     sync::{
         mpsc::{channel, Sender},
-        Arc, Mutex,
+        Arc, Mutex, Weak,
     },
     thread,
+    time::Duration,
 };
 
 use windows_sys::Win32::{
-    Foundation::{LPARAM, LRESULT, WPARAM},
+    Foundation::{ERROR_SUCCESS, LPARAM, LRESULT, WPARAM},
     System::{LibraryLoader::GetModuleHandleW, Threading::GetCurrentThreadId},
     UI::{
-        Input::KeyboardAndMouse::{
-            MapVirtualKeyW, MAPVK_VK_TO_CHAR, MAPVK_VK_TO_VSC_EX, MAPVK_VSC_TO_VK_EX,
+        Input::{
+            KeyboardAndMouse::{
+                MapVirtualKeyW, MAPVK_VK_TO_CHAR, MAPVK_VK_TO_VSC_EX, MAPVK_VSC_TO_VK_EX,
+            },
+            XboxController::{
+                XInputGetState, XINPUT_GAMEPAD, XINPUT_GAMEPAD_A, XINPUT_GAMEPAD_B,
+                XINPUT_GAMEPAD_BACK, XINPUT_GAMEPAD_DPAD_DOWN, XINPUT_GAMEPAD_DPAD_LEFT,
+                XINPUT_GAMEPAD_DPAD_RIGHT, XINPUT_GAMEPAD_DPAD_UP, XINPUT_GAMEPAD_LEFT_SHOULDER,
+                XINPUT_GAMEPAD_LEFT_THUMB, XINPUT_GAMEPAD_RIGHT_SHOULDER,
+                XINPUT_GAMEPAD_RIGHT_THUMB, XINPUT_GAMEPAD_START, XINPUT_GAMEPAD_X,
+                XINPUT_GAMEPAD_Y, XINPUT_STATE, XUSER_MAX_COUNT,
+            },
         },
         WindowsAndMessaging::{
             CallNextHookEx, GetMessageW, PostThreadMessageW, SetWindowsHookExW,
@@ -45,11 +61,14 @@ impl fmt::Display for Error {
     }
 }
 
-type Callback = Box<dyn FnMut() + Send + 'static>;
+// The callbacks are shared, so they can be called without the hotkeys being
+// locked. This way the callbacks can register and unregister hotkeys.
+type SharedCallbacks = Arc<Mutex<Callbacks>>;
+type Hotkeys = HashMap<Hotkey, SharedCallbacks>;
 
 pub struct Hook {
     thread_id: u32,
-    hotkeys: Arc<Mutex<HashMap<Hotkey, Callback>>>,
+    hotkeys: Arc<Mutex<Hotkeys>>,
 }
 
 impl Drop for Hook {
@@ -60,9 +79,14 @@ impl Drop for Hook {
     }
 }
 
+enum Event {
+    Pressed(Hotkey),
+    Released(KeyCode),
+}
+
 struct State {
     hook: HHOOK,
-    events: Sender<Hotkey>,
+    events: Sender<Event>,
     modifiers: Modifiers,
     // FIXME: Use variant count when it's stable.
     // https://github.com/rust-lang/rust/issues/73662
@@ -291,10 +315,7 @@ unsafe extern "system" fn callback_proc(code: i32, wparam: WPARAM, lparam: LPARA
 
                         state
                             .events
-                            .send(Hotkey {
-                                key_code,
-                                modifiers: state.modifiers,
-                            })
+                            .send(Event::Pressed(key_code.with_modifiers(state.modifiers)))
                             .expect("Callback Thread disconnected");
 
                         match key_code {
@@ -343,6 +364,11 @@ unsafe extern "system" fn callback_proc(code: i32, wparam: WPARAM, lparam: LPARA
                     let (idx, bit) = key_idx(key_code);
                     state.key_state[idx as usize] &= !bit;
 
+                    state
+                        .events
+                        .send(Event::Released(key_code))
+                        .expect("Callback Thread disconnected");
+
                     match key_code {
                         KeyCode::AltLeft | KeyCode::AltRight => {
                             state.modifiers.remove(Modifiers::ALT);
@@ -368,6 +394,157 @@ unsafe extern "system" fn callback_proc(code: i32, wparam: WPARAM, lparam: LPARA
     CallNextHookEx(hook, code, wparam, lparam)
 }
 
+// XInput reports the buttons as flags, which we map to the buttons of the
+// "Standard Gamepad", just like the other platforms do:
+// https://w3c.github.io/gamepad/#dfn-standard-gamepad
+// The triggers are analog, so they are handled separately.
+const GAMEPAD_BUTTONS: [(u16, KeyCode); 14] = [
+    (XINPUT_GAMEPAD_A, KeyCode::Gamepad0),
+    (XINPUT_GAMEPAD_B, KeyCode::Gamepad1),
+    (XINPUT_GAMEPAD_X, KeyCode::Gamepad2),
+    (XINPUT_GAMEPAD_Y, KeyCode::Gamepad3),
+    (XINPUT_GAMEPAD_LEFT_SHOULDER, KeyCode::Gamepad4),
+    (XINPUT_GAMEPAD_RIGHT_SHOULDER, KeyCode::Gamepad5),
+    (XINPUT_GAMEPAD_BACK, KeyCode::Gamepad8),
+    (XINPUT_GAMEPAD_START, KeyCode::Gamepad9),
+    (XINPUT_GAMEPAD_LEFT_THUMB, KeyCode::Gamepad10),
+    (XINPUT_GAMEPAD_RIGHT_THUMB, KeyCode::Gamepad11),
+    (XINPUT_GAMEPAD_DPAD_UP, KeyCode::Gamepad12),
+    (XINPUT_GAMEPAD_DPAD_DOWN, KeyCode::Gamepad13),
+    (XINPUT_GAMEPAD_DPAD_LEFT, KeyCode::Gamepad14),
+    (XINPUT_GAMEPAD_DPAD_RIGHT, KeyCode::Gamepad15),
+];
+
+// This is the same as XINPUT_GAMEPAD_TRIGGER_THRESHOLD.
+const TRIGGER_THRESHOLD: u8 = 30;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(1000 / 60);
+const IDLE_INTERVAL: Duration = Duration::from_millis(500);
+// Querying a controller that is not connected is fairly expensive, so we only
+// check for new controllers every so often.
+const RECONNECT_FRAMES: u32 = 60;
+
+fn pressed_gamepad_buttons(gamepad: &XINPUT_GAMEPAD) -> Vec<KeyCode> {
+    let mut buttons: Vec<KeyCode> = GAMEPAD_BUTTONS
+        .iter()
+        .filter(|(flag, _)| gamepad.wButtons & flag != 0)
+        .map(|&(_, key_code)| key_code)
+        .collect();
+    if gamepad.bLeftTrigger > TRIGGER_THRESHOLD {
+        buttons.push(KeyCode::Gamepad6);
+    }
+    if gamepad.bRightTrigger > TRIGGER_THRESHOLD {
+        buttons.push(KeyCode::Gamepad7);
+    }
+    buttons
+}
+
+fn released<K: Eq + Hash>(
+    hotkeys: &Hotkeys,
+    pressed_hotkeys: &mut HashMap<K, Hotkey>,
+    key: K,
+) -> Option<SharedCallbacks> {
+    pressed_hotkeys
+        .remove(&key)
+        .and_then(|hotkey| hotkeys.get(&hotkey).cloned())
+}
+
+fn call(actions: &mut Vec<(SharedCallbacks, fn(&mut Callbacks))>) {
+    for (callbacks, action) in actions.drain(..) {
+        action(&mut callbacks.lock().unwrap());
+    }
+}
+
+// Gamepads can't be hooked like the keyboard, so we poll them instead. This
+// only happens while any gamepad buttons are registered as hotkeys. The thread
+// stops once the hook is dropped.
+fn poll_gamepads(hotkeys: Weak<Mutex<Hotkeys>>) {
+    let mut states: [Option<Vec<KeyCode>>; XUSER_MAX_COUNT as usize] = Default::default();
+    // The hotkey each currently pressed button of each gamepad triggered, so
+    // we know which hotkey got released.
+    let mut pressed_hotkeys = HashMap::new();
+    // The callbacks to call once the hotkeys are unlocked again.
+    let mut actions: Vec<(SharedCallbacks, fn(&mut Callbacks))> = Vec::new();
+    let mut frame = 0u32;
+
+    while let Some(hotkeys) = hotkeys.upgrade() {
+        let has_gamepad_hotkeys = hotkeys
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|hotkey| hotkey.key_code.classify() == KeyCodeClass::Gamepad);
+
+        if !has_gamepad_hotkeys {
+            drop(hotkeys);
+            states = Default::default();
+            pressed_hotkeys.clear();
+            thread::sleep(IDLE_INTERVAL);
+            continue;
+        }
+
+        for (user_index, state) in states.iter_mut().enumerate() {
+            if state.is_none() && frame % RECONNECT_FRAMES != 0 {
+                continue;
+            }
+
+            let mut xinput_state: XINPUT_STATE = unsafe { mem::zeroed() };
+            let pressed = if unsafe { XInputGetState(user_index as u32, &mut xinput_state) }
+                == ERROR_SUCCESS
+            {
+                Some(pressed_gamepad_buttons(&xinput_state.Gamepad))
+            } else {
+                None
+            };
+
+            let held_buttons = state.take().unwrap_or_default();
+            let hotkeys = hotkeys.lock().unwrap();
+
+            // All buttons of a gamepad that got disconnected count as released.
+            for &key_code in &held_buttons {
+                if !pressed
+                    .as_ref()
+                    .is_some_and(|pressed| pressed.contains(&key_code))
+                {
+                    if let Some(callbacks) =
+                        released(&hotkeys, &mut pressed_hotkeys, (user_index, key_code))
+                    {
+                        actions.push((callbacks, Callbacks::release));
+                    }
+                }
+            }
+
+            let Some(pressed) = pressed else {
+                continue;
+            };
+
+            for &key_code in &pressed {
+                if held_buttons.contains(&key_code) {
+                    continue;
+                }
+                if let Some((hotkey, callbacks)) =
+                    combinations(key_code, Modifiers::empty(), held_buttons.iter().copied())
+                        .find_map(|(key_code, modifiers, held_button)| {
+                            hotkeys.get_key_value(&Hotkey {
+                                key_code,
+                                modifiers,
+                                held_button,
+                            })
+                        })
+                {
+                    actions.push((callbacks.clone(), Callbacks::press));
+                    pressed_hotkeys.insert((user_index, key_code), *hotkey);
+                }
+            }
+            *state = Some(pressed);
+        }
+
+        drop(hotkeys);
+        call(&mut actions);
+        frame = frame.wrapping_add(1);
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
 #[inline]
 fn key_idx(key_code: KeyCode) -> (u8, u8) {
     let value = key_code as u8;
@@ -380,10 +557,7 @@ impl Hook {
             return Err(crate::Error::UnmatchedPreference);
         }
 
-        let hotkeys = Arc::new(Mutex::new(HashMap::<
-            Hotkey,
-            Box<dyn FnMut() + Send + 'static>,
-        >::new()));
+        let hotkeys = Arc::new(Mutex::new(Hotkeys::new()));
 
         let (initialized_tx, initialized_rx) = channel();
         let (events_tx, events_rx) = channel();
@@ -439,12 +613,35 @@ impl Hook {
             Ok(())
         });
 
+        let gamepad_hotkeys = Arc::downgrade(&hotkeys);
+        thread::spawn(move || poll_gamepads(gamepad_hotkeys));
+
         let hotkey_map = hotkeys.clone();
 
         thread::spawn(move || {
-            while let Ok(key) = events_rx.recv() {
-                if let Some(callback) = hotkey_map.lock().unwrap().get_mut(&key) {
-                    callback();
+            // The hotkey each currently pressed key triggered, so we know which
+            // hotkey got released.
+            let mut pressed_hotkeys = HashMap::new();
+            while let Ok(event) = events_rx.recv() {
+                let hotkey_map = hotkey_map.lock().unwrap();
+                let action = match event {
+                    Event::Pressed(key) => key
+                        .modifiers
+                        .subsets()
+                        .find_map(|m| hotkey_map.get_key_value(&key.key_code.with_modifiers(m)))
+                        .map(|(hotkey, callbacks)| {
+                            pressed_hotkeys.insert(key.key_code, *hotkey);
+                            (callbacks.clone(), Callbacks::press as fn(&mut Callbacks))
+                        }),
+                    Event::Released(key_code) => {
+                        released(&hotkey_map, &mut pressed_hotkeys, key_code)
+                            .map(|callbacks| (callbacks, Callbacks::release as fn(&mut Callbacks)))
+                    }
+                };
+                // The callbacks are called without the hotkeys being locked.
+                drop(hotkey_map);
+                if let Some((callbacks, action)) = action {
+                    action(&mut callbacks.lock().unwrap());
                 }
             }
         });
@@ -456,12 +653,9 @@ impl Hook {
         Ok(Hook { thread_id, hotkeys })
     }
 
-    pub fn register<F>(&self, hotkey: Hotkey, callback: F) -> Result<()>
-    where
-        F: FnMut() + Send + 'static,
-    {
+    pub fn register(&self, hotkey: Hotkey, callbacks: Callbacks) -> Result<()> {
         if let Entry::Vacant(vacant) = self.hotkeys.lock().unwrap().entry(hotkey) {
-            vacant.insert(Box::new(callback));
+            vacant.insert(Arc::new(Mutex::new(callbacks)));
             Ok(())
         } else {
             Err(crate::Error::AlreadyRegistered)
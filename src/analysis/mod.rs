@@ -8,10 +8,12 @@ pub mod possible_time_save;
 mod skill_curve;
 pub mod state_helper;
 pub mod sum_of_segments;
+pub mod summary;
 pub mod total_playtime;
 
 pub use self::skill_curve::SkillCurve;
 pub use self::state_helper::*;
+pub use self::summary::Summary;
 
 #[cfg(test)]
 mod tests;
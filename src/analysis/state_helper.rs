@@ -293,6 +293,31 @@ pub fn check_live_delta(
     None
 }
 
+/// Configures how [`split_color_with`] chooses the color of a delta.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SplitColoring {
+    /// Specifies whether the segment delta, meaning how much the delta changed
+    /// compared to the previous delta, decides between the gaining time and
+    /// losing time colors. Otherwise only the delta itself decides between
+    /// the ahead and behind colors and the gaining and losing colors are
+    /// never distinguished.
+    pub segment_deltas: bool,
+    /// Deltas and segment deltas that don't exceed this threshold are
+    /// considered to be even. An even delta is shown with the default color
+    /// and an even segment delta is treated as neither gaining nor losing
+    /// time.
+    pub threshold: TimeSpan,
+}
+
+impl Default for SplitColoring {
+    fn default() -> Self {
+        Self {
+            segment_deltas: true,
+            threshold: TimeSpan::zero(),
+        }
+    }
+}
+
 /// Chooses a split color from the
 /// [`LayoutSettings`](crate::layout::LayoutSettings) based on the current run.
 ///
@@ -315,19 +340,51 @@ pub fn split_color(
     comparison: &str,
     method: TimingMethod,
 ) -> SemanticColor {
+    split_color_with(
+        timer,
+        time_difference,
+        segment_index,
+        show_segment_deltas,
+        show_best_segments,
+        comparison,
+        method,
+        SplitColoring::default(),
+    )
+}
+
+/// Chooses a split color from the
+/// [`LayoutSettings`](crate::layout::LayoutSettings) based on the current run,
+/// just like [`split_color`], but with the [`SplitColoring`] provided.
+#[allow(clippy::too_many_arguments)]
+pub fn split_color_with(
+    timer: &Timer,
+    time_difference: Option<TimeSpan>,
+    segment_index: usize,
+    show_segment_deltas: bool,
+    show_best_segments: bool,
+    comparison: &str,
+    method: TimingMethod,
+    coloring: SplitColoring,
+) -> SemanticColor {
+    let abs = |t: TimeSpan| if t < TimeSpan::zero() { -t } else { t };
+    let is_even = |delta: &TimeSpan| abs(*delta) <= abs(coloring.threshold);
+
     if show_best_segments && check_best_segment(timer, segment_index, method) {
         SemanticColor::BestSegment
-    } else if let Some(time_difference) = time_difference.filter(|t| t != &TimeSpan::zero()) {
-        let last_delta = segment_index
+    } else if let Some(time_difference) = time_difference.filter(|t| !is_even(t)) {
+        let segment_delta = segment_index
             .checked_sub(1)
-            .and_then(|n| last_delta(timer.run(), n, comparison, method));
+            .filter(|_| show_segment_deltas && coloring.segment_deltas)
+            .and_then(|n| last_delta(timer.run(), n, comparison, method))
+            .map(|last_delta| time_difference - last_delta)
+            .filter(|d| !is_even(d));
         if time_difference < TimeSpan::zero() {
-            if show_segment_deltas && last_delta.is_some_and(|d| time_difference > d) {
+            if segment_delta.is_some_and(|d| d > TimeSpan::zero()) {
                 SemanticColor::AheadLosingTime
             } else {
                 SemanticColor::AheadGainingTime
             }
-        } else if show_segment_deltas && last_delta.is_some_and(|d| time_difference < d) {
+        } else if segment_delta.is_some_and(|d| d < TimeSpan::zero()) {
             SemanticColor::BehindGainingTime
         } else {
             SemanticColor::BehindLosingTime
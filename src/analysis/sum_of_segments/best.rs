@@ -102,7 +102,6 @@ fn populate_predictions(
 /// means that the predictions buffer needs to have one more element than the
 /// list of segments provided, so that you can properly query the total Sum of
 /// Best Segments. This value is also the value that is being returned.
-#[allow(clippy::needless_range_loop)]
 pub fn calculate(
     segments: &[Segment],
     predictions: &mut [Option<Prediction>],
@@ -111,8 +110,30 @@ pub fn calculate(
     method: TimingMethod,
 ) -> Option<TimeSpan> {
     predictions[0] = Some(Prediction::default());
+    calculate_from(
+        segments,
+        predictions,
+        0,
+        simple_calculation,
+        use_current_run,
+        method,
+    )
+}
+
+/// Continues the calculation of the Sum of Best Segments from the segment with
+/// the index provided. The predictions up to and including that segment need
+/// to be populated already.
+#[allow(clippy::needless_range_loop)]
+pub(super) fn calculate_from(
+    segments: &[Segment],
+    predictions: &mut [Option<Prediction>],
+    start_index: usize,
+    simple_calculation: bool,
+    use_current_run: bool,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
     let end_index = segments.len();
-    for segment_index in 0..end_index {
+    for segment_index in start_index..end_index {
         populate_predictions(
             segments,
             predictions[segment_index],
@@ -14,7 +14,7 @@ pub mod worst;
 mod tests;
 
 use crate::platform::prelude::*;
-use crate::{Segment, Time, TimeSpan, TimingMethod};
+use crate::{analysis, timing::Snapshot, Segment, Time, TimeSpan, TimingMethod};
 
 /// Describes the shortest amount of time it takes to reach a certain segment.
 /// Since there is the possibility that the shortest path is actually skipping
@@ -55,6 +55,57 @@ pub fn calculate_best(
     )
 }
 
+/// Calculates the Sum of Best Segments for the timing method provided, while
+/// taking the segment that is currently running into account. If the current
+/// attempt splitting right now would result in a faster Sum of Best Segments,
+/// that time is returned instead, so a gold that is currently in progress is
+/// reflected live. Splits of the current attempt are always taken into account.
+/// Additionally a boolean is returned that indicates if the value is currently
+/// actively changing as time goes on.
+pub fn calculate_live_best(
+    timer: &Snapshot<'_>,
+    simple_calculation: bool,
+    method: TimingMethod,
+) -> (Option<TimeSpan>, bool) {
+    let segments = timer.run().segments();
+    let mut predictions = vec![None; segments.len() + 1];
+    let sum_of_best = best::calculate(segments, &mut predictions, simple_calculation, true, method);
+
+    let live_sum_of_best = catch! {
+        let segment_index = timer.current_split_index()?;
+        segments.get(segment_index)?;
+
+        let start_index = segments[..segment_index]
+            .iter()
+            .rposition(|segment| segment.split_time()[method].is_some())
+            .map_or(0, |index| index + 1);
+        let start_time = predictions[start_index]?.time;
+        let segment_time = analysis::live_segment_time(timer, segment_index, method)?;
+
+        predictions.fill(None);
+        predictions[segment_index + 1] = Some(Prediction {
+            time: start_time + segment_time,
+            predecessor: start_index,
+        });
+        best::calculate_from(
+            segments,
+            &mut predictions,
+            segment_index + 1,
+            simple_calculation,
+            true,
+            method,
+        )?
+    };
+
+    match (sum_of_best, live_sum_of_best) {
+        (Some(sum_of_best), Some(live_sum_of_best)) if live_sum_of_best < sum_of_best => (
+            Some(live_sum_of_best),
+            timer.current_phase().updates_frequently(method),
+        ),
+        _ => (sum_of_best, false),
+    }
+}
+
 /// Calculates the Sum of Worst Segments for the timing method provided. This is
 /// the slowest time possible to complete a run of a category, based on
 /// information collected from all the previous attempts. This obviously isn't
@@ -0,0 +1,161 @@
+//! Provides a concise textual summary of the active attempt, such as `Segment 5
+//! of 20, 12.3 seconds ahead of Personal Best, best possible time 1:02:11`.
+//! This is suitable for screen readers and text to speech integrations. The
+//! [`Summary`] only updates its text on split events, so it can be queried
+//! every frame without the text constantly changing.
+
+use crate::{
+    analysis,
+    comparison::best_segments,
+    platform::prelude::*,
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter},
+        Snapshot,
+    },
+    TimeSpan, TimerPhase, TimingMethod,
+};
+use core::fmt::{self, Write};
+
+/// A textual summary of the active attempt that only gets updated on split
+/// events. These are the timer starting, splitting, skipping or undoing a
+/// split, pausing, resuming, resetting and switching the comparison or timing
+/// method.
+#[derive(Default, Clone)]
+pub struct Summary {
+    text: String,
+    event: Option<(TimerPhase, Option<usize>, String, TimingMethod)>,
+}
+
+impl Summary {
+    /// Creates a new empty summary. It gets filled on the first call to
+    /// [`update`](Self::update).
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Accesses the current text of the summary.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Updates the summary if a split event happened since the last update.
+    /// Returns whether the text got updated, so that it can be announced.
+    pub fn update(&mut self, timer: &Snapshot<'_>) -> bool {
+        let comparison = timer.current_comparison();
+        let method = timer.current_timing_method();
+        let phase = timer.current_phase();
+        let split_index = timer.current_split_index();
+
+        if let Some((last_phase, last_split_index, last_comparison, last_method)) = &self.event {
+            if *last_phase == phase
+                && *last_split_index == split_index
+                && last_comparison == comparison
+                && *last_method == method
+            {
+                return false;
+            }
+        }
+
+        self.text.clear();
+        let _ = write(&mut self.text, timer);
+        self.event = Some((phase, split_index, comparison.into(), method));
+        true
+    }
+}
+
+/// Calculates the summary of the current state of the timer.
+pub fn calculate(timer: &Snapshot<'_>) -> String {
+    let mut text = String::new();
+    let _ = write(&mut text, timer);
+    text
+}
+
+/// Writes the summary of the current state of the timer into the writer
+/// provided.
+pub fn write<W: Write>(f: &mut W, timer: &Snapshot<'_>) -> fmt::Result {
+    let run = timer.run();
+    let comparison = timer.current_comparison();
+    let method = timer.current_timing_method();
+    let last_segment = run.segments().last().unwrap();
+
+    match timer.current_phase() {
+        TimerPhase::NotRunning => {
+            f.write_str("Not running")?;
+            if let Some(time) = last_segment.comparison(comparison)[method] {
+                write!(f, ", {comparison} is ")?;
+                duration(f, time)?;
+            }
+        }
+        phase @ (TimerPhase::Running | TimerPhase::Paused) => {
+            let split_index = timer.current_split_index().unwrap();
+            if phase == TimerPhase::Paused {
+                f.write_str("Paused, segment")?;
+            } else {
+                f.write_str("Segment")?;
+            }
+            write!(
+                f,
+                " {} of {}, {}",
+                split_index + 1,
+                run.len(),
+                run.segment(split_index).name(),
+            )?;
+            if let Some(delta) = analysis::last_delta(run, split_index, comparison, method) {
+                f.write_str(", ")?;
+                delta_to(f, delta, comparison)?;
+            }
+        }
+        TimerPhase::Ended => {
+            f.write_str("Finished")?;
+            if let Some(time) = last_segment.split_time()[method] {
+                f.write_str(" in ")?;
+                duration(f, time)?;
+            }
+            if let Some(delta) = analysis::last_delta(run, run.len() - 1, comparison, method) {
+                f.write_str(", ")?;
+                delta_to(f, delta, comparison)?;
+            }
+            return Ok(());
+        }
+    }
+
+    let best_possible_time = catch! {
+        let delta = timer
+            .current_split_index()
+            .and_then(|i| analysis::last_delta(run, i, best_segments::NAME, method))
+            .unwrap_or_default();
+        last_segment.comparison(best_segments::NAME)[method]? + delta
+    };
+    if let Some(time) = best_possible_time {
+        f.write_str(", best possible time ")?;
+        duration(f, time)?;
+    }
+
+    Ok(())
+}
+
+fn delta_to<W: Write>(f: &mut W, delta: TimeSpan, comparison: &str) -> fmt::Result {
+    if delta == TimeSpan::zero() {
+        return write!(f, "even with {comparison}");
+    }
+    duration(f, delta)?;
+    if delta < TimeSpan::zero() {
+        write!(f, " ahead of {comparison}")
+    } else {
+        write!(f, " behind {comparison}")
+    }
+}
+
+fn duration<W: Write>(f: &mut W, time: TimeSpan) -> fmt::Result {
+    let time = if time < TimeSpan::zero() { -time } else { time };
+    let seconds = time.total_seconds();
+    if seconds < 60.0 {
+        write!(f, "{seconds:.1} seconds")
+    } else {
+        write!(
+            f,
+            "{}",
+            Regular::with_accuracy(Accuracy::Seconds).format(time)
+        )
+    }
+}
@@ -1,2 +1,3 @@
 mod empty_run;
 mod semantic_colors;
+mod summary;
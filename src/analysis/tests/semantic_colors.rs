@@ -1,5 +1,5 @@
 use crate::{
-    analysis::split_color,
+    analysis::{split_color, split_color_with, SplitColoring},
     comparison,
     settings::SemanticColor,
     util::tests_helper::{
@@ -33,6 +33,57 @@ fn segment_colors_are_correct() {
     assert_eq!(color(&timer, 0.0), SemanticColor::Default);
 }
 
+#[test]
+fn deltas_within_the_threshold_are_even() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(7.0)]);
+
+    let coloring = SplitColoring {
+        segment_deltas: true,
+        threshold: span(1.0),
+    };
+
+    assert_eq!(
+        color_with(&timer, -2.5, coloring),
+        SemanticColor::AheadGainingTime,
+    );
+    assert_eq!(
+        color_with(&timer, -1.5, coloring),
+        SemanticColor::AheadLosingTime,
+    );
+    assert_eq!(color_with(&timer, -0.5, coloring), SemanticColor::Default);
+    assert_eq!(color_with(&timer, 0.5, coloring), SemanticColor::Default);
+}
+
+#[test]
+fn segment_deltas_can_be_ignored() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(15.0)]);
+
+    let coloring = SplitColoring {
+        segment_deltas: false,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        color_with(&timer, 2.5, coloring),
+        SemanticColor::BehindLosingTime,
+    );
+    assert_eq!(
+        color_with(&timer, -2.5, coloring),
+        SemanticColor::AheadGainingTime,
+    );
+    assert_eq!(color_with(&timer, 0.0, coloring), SemanticColor::Default);
+}
+
 fn color(timer: &Timer, delta: f64) -> SemanticColor {
     split_color(
         timer,
@@ -44,3 +95,16 @@ fn color(timer: &Timer, delta: f64) -> SemanticColor {
         TimingMethod::GameTime,
     )
 }
+
+fn color_with(timer: &Timer, delta: f64, coloring: SplitColoring) -> SemanticColor {
+    split_color_with(
+        timer,
+        Some(span(delta)),
+        1,
+        true,
+        false,
+        comparison::personal_best::NAME,
+        TimingMethod::GameTime,
+        coloring,
+    )
+}
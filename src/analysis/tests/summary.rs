@@ -0,0 +1,65 @@
+use crate::{
+    analysis::{summary, Summary},
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run,
+    },
+    TimingMethod,
+};
+
+#[test]
+fn describes_the_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 90.0]);
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    assert_eq!(
+        summary::calculate(&timer.snapshot()),
+        "Not running, Personal Best is 1:30, best possible time 1:30",
+    );
+
+    start_run(&mut timer);
+    assert_eq!(
+        summary::calculate(&timer.snapshot()),
+        "Segment 1 of 3, A, best possible time 1:30",
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(7.0), Some(22.5)]);
+    assert_eq!(
+        summary::calculate(&timer.snapshot()),
+        "Segment 3 of 3, C, 2.5 seconds behind Personal Best, best possible time 1:32",
+    );
+
+    timer.pause().unwrap();
+    assert!(summary::calculate(&timer.snapshot()).starts_with("Paused, segment 3 of 3, C"));
+    timer.resume().unwrap();
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(80.0)]);
+    assert_eq!(
+        summary::calculate(&timer.snapshot()),
+        "Finished in 1:20, 10.0 seconds ahead of Personal Best",
+    );
+}
+
+#[test]
+fn only_updates_on_split_events() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut summary = Summary::new();
+    assert!(summary.update(&timer.snapshot()));
+    assert!(!summary.update(&timer.snapshot()));
+
+    start_run(&mut timer);
+    assert!(summary.update(&timer.snapshot()));
+    let text = summary.text().to_owned();
+
+    timer.set_game_time(span(15.0)).unwrap();
+    assert!(!summary.update(&timer.snapshot()));
+    assert_eq!(summary.text(), text);
+
+    timer.split().unwrap();
+    assert!(summary.update(&timer.snapshot()));
+    assert!(summary
+        .text()
+        .starts_with("Segment 2 of 2, B, 5.0 seconds behind"));
+}
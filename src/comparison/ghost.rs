@@ -0,0 +1,59 @@
+//! Defines the Comparison Generator for racing against a ghost. A ghost is a
+//! previously recorded run that is shown as a comparison, so that the current
+//! attempt can be compared against it in real time. The name of the comparison
+//! is prefixed with [`RACE_COMPARISON_PREFIX`], so that it never clashes with
+//! the comparisons stored in the splits.
+
+use super::{ComparisonGenerator, RACE_COMPARISON_PREFIX};
+use crate::{platform::prelude::*, Attempt, Run, Segment, Time};
+
+/// The Comparison Generator for racing against a ghost. It provides the split
+/// times of a previously recorded run as a comparison.
+#[derive(Clone, Debug)]
+pub struct Ghost {
+    name: String,
+    split_times: Vec<Time>,
+}
+
+impl Ghost {
+    /// Creates a new ghost with the name and split times provided. The name is
+    /// automatically prefixed with [`RACE_COMPARISON_PREFIX`]. The split times
+    /// are matched to the segments by their index.
+    pub fn new(name: &str, split_times: Vec<Time>) -> Self {
+        Self {
+            name: format!("{RACE_COMPARISON_PREFIX} {name}"),
+            split_times,
+        }
+    }
+
+    /// Creates a new ghost based on the split times of the comparison of the
+    /// run provided. The name is automatically prefixed with
+    /// [`RACE_COMPARISON_PREFIX`].
+    pub fn from_run(name: &str, run: &Run, comparison: &str) -> Self {
+        Self::new(
+            name,
+            run.segments()
+                .iter()
+                .map(|segment| segment.comparison(comparison))
+                .collect(),
+        )
+    }
+
+    /// Accesses the split times of the ghost.
+    pub fn split_times(&self) -> &[Time] {
+        &self.split_times
+    }
+}
+
+impl ComparisonGenerator for Ghost {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        for (index, segment) in segments.iter_mut().enumerate() {
+            *segment.comparison_mut(&self.name) =
+                self.split_times.get(index).copied().unwrap_or_default();
+        }
+    }
+}
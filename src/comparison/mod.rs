@@ -10,6 +10,7 @@ pub mod average_segments;
 pub mod balanced_pb;
 pub mod best_segments;
 pub mod best_split_times;
+pub mod ghost;
 pub mod goal;
 pub mod latest_run;
 pub mod median_segments;
@@ -18,8 +19,8 @@ pub mod worst_segments;
 
 pub use self::{
     average_segments::AverageSegments, balanced_pb::BalancedPB, best_segments::BestSegments,
-    best_split_times::BestSplitTimes, latest_run::LatestRun, median_segments::MedianSegments,
-    none::None, worst_segments::WorstSegments,
+    best_split_times::BestSplitTimes, ghost::Ghost, latest_run::LatestRun,
+    median_segments::MedianSegments, none::None, worst_segments::WorstSegments,
 };
 
 use crate::{platform::prelude::*, Attempt, Segment, Timer};
@@ -0,0 +1,236 @@
+//! Provides the Countdown Component and relevant types for using it. The
+//! Countdown Component shows how much time is remaining until the attempt
+//! begins. This is the case while the timer is counting up towards zero from a
+//! negative offset or while a start of the timer is scheduled. Once the attempt
+//! begins, the component either hides itself or switches to showing the time of
+//! the attempt.
+
+use super::timer;
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Field, Font, Gradient, SemanticColor, SettingsDescription, Value},
+    timing::{
+        formatter::{timer as formatter, Accuracy, DigitsFormat, TimeFormatter},
+        Snapshot,
+    },
+    GeneralLayoutSettings, TimeSpan, TimerPhase,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Countdown Component shows how much time is remaining until the attempt
+/// begins. Once the attempt begins, the component either hides itself or
+/// switches to showing the time of the attempt.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The height of the countdown.
+    pub height: u32,
+    /// The color of the countdown. If `None` is specified, the color of the
+    /// timer while it is not running is used.
+    pub color_override: Option<Color>,
+    /// Determines how many digits are to always be shown. If the duration is
+    /// lower than the digits to be shown, they are filled up with zeros.
+    pub digits_format: DigitsFormat,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+    /// Specifies whether the component hides itself once the attempt begins.
+    /// Otherwise the time of the attempt is shown instead.
+    pub hide_after_start: bool,
+    /// The font to use for the countdown. If `None` is specified, the timer
+    /// font of the layout is used.
+    pub timer_font: Option<Font>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            height: 60,
+            color_override: None,
+            digits_format: DigitsFormat::SingleDigitSeconds,
+            accuracy: Accuracy::Tenths,
+            hide_after_start: true,
+            timer_font: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Countdown Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Countdown Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Countdown"
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn update_state(
+        &self,
+        state: &mut timer::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let method = timer.current_timing_method();
+        let phase = timer.current_phase();
+        let current_time = timer.current_time();
+        let time = current_time[method]
+            .or(current_time.real_time)
+            .unwrap_or_default();
+
+        let is_counting_down = phase == TimerPhase::NotRunning || time < TimeSpan::zero();
+
+        let (time, semantic_color, height) = if is_counting_down {
+            let remaining = if time < TimeSpan::zero() {
+                TimeSpan::zero() - time
+            } else {
+                TimeSpan::zero()
+            };
+            (
+                Some(remaining),
+                SemanticColor::NotRunning,
+                self.settings.height,
+            )
+        } else if self.settings.hide_after_start {
+            (None, SemanticColor::Default, 0)
+        } else {
+            (Some(time), SemanticColor::Default, self.settings.height)
+        };
+
+        let color = self
+            .settings
+            .color_override
+            .unwrap_or_else(|| semantic_color.visualize(layout_settings));
+        (state.top_color, state.bottom_color) = timer::top_and_bottom_color(color);
+        state.background = self.settings.background;
+
+        state.time.clear();
+        state.fraction.clear();
+        if height != 0 {
+            let _ = write!(
+                state.time,
+                "{}",
+                formatter::Time::with_digits_format(self.settings.digits_format).format(time),
+            );
+            let _ = write!(
+                state.fraction,
+                "{}",
+                formatter::Fraction::with_accuracy(self.settings.accuracy).format(time),
+            );
+        }
+
+        state.semantic_color = semantic_color;
+        state.height = height;
+        state.updates_frequently = height != 0
+            && if is_counting_down {
+                timer.is_start_scheduled() || phase.updates_frequently(method)
+            } else {
+                phase.updates_frequently(method)
+            };
+        state.timer_font.clone_from(&self.settings.timer_font);
+    }
+
+    /// Calculates the component's state based on the timer and the layout
+    /// settings provided.
+    pub fn state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> timer::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer, layout_settings);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Height".into(),
+                "The height of the countdown.".into(),
+                u64::from(self.settings.height).into(),
+            ),
+            Field::new(
+                "Text Color".into(),
+                "The color of the countdown. If not specified, the color of the timer while it is not running is used.".into(),
+                self.settings.color_override.into(),
+            ),
+            Field::new(
+                "Digits Format".into(),
+                "Specifies how many digits to show for the main part of the countdown. If the duration is lower than the digits to be shown, zeros are shown instead.".into(),
+                self.settings.digits_format.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the time shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+            Field::new(
+                "Hide After Start".into(),
+                "Specifies whether the component hides itself once the attempt begins. Otherwise the time of the attempt is shown instead.".into(),
+                self.settings.hide_after_start.into(),
+            ),
+            Field::new(
+                "Custom Countdown Font".into(),
+                "Allows you to specify a custom font for the countdown. If this is not set, the timer font of the layout is used.".into(),
+                self.settings.timer_font.clone().into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.height = value.into_uint().unwrap() as _,
+            2 => self.settings.color_override = value.into(),
+            3 => self.settings.digits_format = value.into(),
+            4 => self.settings.accuracy = value.into(),
+            5 => self.settings.hide_after_start = value.into(),
+            6 => self.settings.timer_font = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use super::Component;
+use crate::{
+    settings::Value,
+    util::tests_helper::{create_run, start_run},
+    GeneralLayoutSettings, TimeSpan, Timer,
+};
+
+fn timer() -> Timer {
+    let mut run = create_run(&["A", "B"]);
+    run.set_offset(TimeSpan::from_seconds(-5.0));
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn shows_the_remaining_countdown() {
+    let component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut timer = timer();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.time, "5");
+    assert_eq!(state.fraction, ".0");
+    assert_eq!(state.height, 60);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(-2.0)).unwrap();
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.time, "2");
+    assert_eq!(state.fraction, ".0");
+}
+
+#[test]
+fn hides_or_switches_once_the_attempt_begins() {
+    let mut component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(3.0)).unwrap();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.height, 0);
+    assert!(state.time.is_empty());
+
+    component.set_value(5, Value::Bool(false));
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.height, 60);
+    assert_eq!(state.time, "3");
+    assert_eq!(state.fraction, ".0");
+}
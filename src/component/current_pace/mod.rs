@@ -1,24 +1,28 @@
 //! Provides the Current Pace Component and relevant types for using it. The
 //! Current Pace Component is a component that shows a prediction of the current
 //! attempt's final time, if the current attempt's pace matches the chosen
-//! comparison for the remainder of the run.
+//! comparison for the remainder of the run. Predictions for additional
+//! comparisons can be shown in rows of their own.
 
 use super::key_value;
 use crate::{
     analysis::current_pace,
     comparison,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
     timing::{
         formatter::{Accuracy, Regular, TimeFormatter},
         Snapshot,
     },
-    TimerPhase,
+    TimeSpan, TimerPhase,
 };
 use alloc::borrow::Cow;
 use core::fmt::Write;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// The Current Pace Component is a component that shows a prediction of the
 /// current attempt's final time, if the current attempt's pace matches the
 /// chosen comparison for the remainder of the run.
@@ -47,6 +51,15 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
+    /// Additional comparisons to predict the final time from. The prediction
+    /// for each of them is shown in a row of its own.
+    pub additional_comparisons: Vec<String>,
 }
 
 impl Default for Settings {
@@ -58,6 +71,9 @@ impl Default for Settings {
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Seconds,
+            text_font: None,
+            times_font: None,
+            additional_comparisons: Vec::new(),
         }
     }
 }
@@ -102,10 +118,11 @@ impl Component {
         }
     }
 
-    /// Updates the component's state based on the timer provided.
-    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
-        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
-        let comparison = comparison::or_current(comparison, timer);
+    fn pace(
+        &self,
+        timer: &Snapshot<'_>,
+        comparison: &str,
+    ) -> (Cow<'static, str>, Option<TimeSpan>, bool) {
         let key = self.text(Some(comparison));
 
         let (current_pace, updates_frequently) =
@@ -115,9 +132,20 @@ impl Component {
                 current_pace::calculate(timer, comparison)
             };
 
+        (key, current_pace, updates_frequently)
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+        let (key, current_pace, mut updates_frequently) = self.pace(timer, comparison);
+
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
         state.value_color = self.settings.value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
         state.semantic_color = Default::default();
 
         state.key.clear();
@@ -131,29 +159,28 @@ impl Component {
         );
 
         state.key_abbreviations.clear();
-        // FIXME: This &* probably is different when key is uncowed
-        match &*key {
-            "Best Possible Time" => {
-                state.key_abbreviations.push("Best Poss. Time".into());
-                state.key_abbreviations.push("Best Time".into());
-                state.key_abbreviations.push("BPT".into());
-            }
-            "Worst Possible Time" => {
-                state.key_abbreviations.push("Worst Poss. Time".into());
-                state.key_abbreviations.push("Worst Time".into());
-            }
-            "Predicted Time" => {
-                state.key_abbreviations.push("Pred. Time".into());
-            }
-            "Current Pace" => {
-                state.key_abbreviations.push("Cur. Pace".into());
-                state.key_abbreviations.push("Pace".into());
-            }
-            _ => {
-                state.key_abbreviations.push("Current Pace".into());
-                state.key_abbreviations.push("Cur. Pace".into());
-                state.key_abbreviations.push("Pace".into());
-            }
+        push_abbreviations(&mut state.key_abbreviations, &key);
+
+        let additional_comparisons = &self.settings.additional_comparisons;
+        state
+            .additional_rows
+            .resize_with(additional_comparisons.len(), Default::default);
+        for (row, comparison) in state.additional_rows.iter_mut().zip(additional_comparisons) {
+            let (key, current_pace, row_updates_frequently) = self.pace(timer, comparison);
+            updates_frequently |= row_updates_frequently;
+
+            row.key.clear();
+            row.key.push_str(&key);
+
+            row.value.clear();
+            let _ = write!(
+                row.value,
+                "{}",
+                Regular::with_accuracy(self.settings.accuracy).format(current_pace)
+            );
+
+            row.key_abbreviations.clear();
+            push_abbreviations(&mut row.key_abbreviations, &key);
         }
 
         state.display_two_rows = self.settings.display_two_rows;
@@ -170,7 +197,7 @@ impl Component {
     /// Accesses a generic description of the settings available for this
     /// component and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut settings = SettingsDescription::with_fields(vec![
             Field::new(
                 "Background".into(),
                 "The background shown behind the component.".into(),
@@ -201,7 +228,32 @@ impl Component {
                 "The accuracy of the predicted time shown.".into(),
                 self.settings.accuracy.into(),
             ),
-        ])
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
+            Field::new(
+                "Additional Comparisons".into(),
+                "The number of additional comparisons to predict the final time from. The prediction for each of them is shown in a row of its own.".into(),
+                Value::UInt(self.settings.additional_comparisons.len() as _),
+            ),
+        ]);
+
+        for comparison in &self.settings.additional_comparisons {
+            settings.fields.push(Field::new(
+                "Comparison".into(),
+                "The additional comparison to predict the final time from.".into(),
+                comparison.clone().into(),
+            ));
+        }
+
+        settings
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -219,7 +271,47 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.value_color = value.into(),
             5 => self.settings.accuracy = value.into(),
-            _ => panic!("Unsupported Setting Index"),
+            6 => self.settings.text_font = value.into(),
+            7 => self.settings.times_font = value.into(),
+            8 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings
+                    .additional_comparisons
+                    .resize_with(new_len, || String::from(comparison::best_segments::NAME));
+            }
+            index => {
+                *self
+                    .settings
+                    .additional_comparisons
+                    .get_mut(index - 9)
+                    .expect("Unsupported Setting Index") = value.into();
+            }
+        }
+    }
+}
+
+fn push_abbreviations(abbreviations: &mut Vec<Cow<'static, str>>, key: &str) {
+    match key {
+        "Best Possible Time" => {
+            abbreviations.push("Best Poss. Time".into());
+            abbreviations.push("Best Time".into());
+            abbreviations.push("BPT".into());
+        }
+        "Worst Possible Time" => {
+            abbreviations.push("Worst Poss. Time".into());
+            abbreviations.push("Worst Time".into());
+        }
+        "Predicted Time" => {
+            abbreviations.push("Pred. Time".into());
+        }
+        "Current Pace" => {
+            abbreviations.push("Cur. Pace".into());
+            abbreviations.push("Pace".into());
+        }
+        _ => {
+            abbreviations.push("Current Pace".into());
+            abbreviations.push("Cur. Pace".into());
+            abbreviations.push("Pace".into());
         }
     }
 }
@@ -0,0 +1,45 @@
+use super::Component;
+use crate::{
+    comparison::{average_segments, best_segments, personal_best},
+    settings::Value,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    Timer,
+};
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[8.0, 22.0, 31.0]);
+    timer
+}
+
+#[test]
+fn shows_additional_comparisons_in_rows_of_their_own() {
+    let mut component = Component::new();
+    component.set_value(1, Value::OptionalString(Some(best_segments::NAME.into())));
+    component.set_value(8, Value::UInt(2));
+    component.set_value(9, Value::String(personal_best::NAME.into()));
+    component.set_value(10, Value::String(average_segments::NAME.into()));
+
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.key, "Best Possible Time");
+    assert_eq!(&*state.value, "0:28");
+
+    let rows = &state.additional_rows;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(&*rows[0].key, "Current Pace");
+    assert_eq!(&*rows[0].value, "0:29");
+    assert_eq!(&*rows[1].key, "Predicted Time");
+    assert_eq!(&*rows[1].key_abbreviations[0], "Pred. Time");
+
+    component.set_value(8, Value::UInt(0));
+    let mut state = state;
+    component.update_state(&mut state, &timer.snapshot());
+    assert!(state.additional_rows.is_empty());
+}
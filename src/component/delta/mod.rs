@@ -1,18 +1,19 @@
 //! Provides the Delta Component and relevant types for using it. The Delta
 //! Component is a component that shows how far ahead or behind the current
-//! attempt is compared to the chosen comparison.
+//! attempt is compared to the chosen comparison. The delta can either be shown
+//! next to the name of the comparison or on its own in large text.
 
-use super::key_value;
+use super::{key_value, timer};
 use crate::{
     analysis::{delta, state_helper},
     comparison,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
         formatter::{Accuracy, Delta, TimeFormatter},
         Snapshot,
     },
-    GeneralLayoutSettings,
+    GeneralLayoutSettings, TimeSpan,
 };
 use alloc::borrow::Cow;
 use core::fmt::Write;
@@ -22,7 +23,9 @@ use serde_derive::{Deserialize, Serialize};
 mod tests;
 
 /// The Delta Component is a component that shows how far ahead or behind the
-/// current attempt is compared to the chosen comparison.
+/// current attempt is compared to the chosen comparison. If the delta is
+/// supposed to be shown in large text, the component's state is a
+/// [`timer::State`] instead of a [`key_value::State`].
 #[derive(Default, Clone)]
 pub struct Component {
     settings: Settings,
@@ -48,6 +51,18 @@ pub struct Settings {
     pub drop_decimals: bool,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
+    /// Specifies whether to only show the delta in large text, similar to a
+    /// timer, instead of showing it next to the name of the comparison.
+    pub display_large: bool,
+    /// The height of the component in pixels when the delta is shown in large
+    /// text.
+    pub large_height: u32,
 }
 
 impl Default for Settings {
@@ -59,6 +74,10 @@ impl Default for Settings {
             label_color: None,
             drop_decimals: true,
             accuracy: Accuracy::Tenths,
+            text_font: None,
+            times_font: None,
+            display_large: false,
+            large_height: 60,
         }
     }
 }
@@ -93,14 +112,13 @@ impl Component {
         }
     }
 
-    /// Updates the component's state based on the timer and layout settings
-    /// provided.
-    pub fn update_state(
-        &self,
-        state: &mut key_value::State,
-        timer: &Snapshot<'_>,
+    /// Calculates the delta, whether it is a live delta, its semantic color and
+    /// the name of the comparison.
+    fn delta<'a>(
+        &'a self,
+        timer: &'a Snapshot<'_>,
         layout_settings: &GeneralLayoutSettings,
-    ) {
+    ) -> (Option<TimeSpan>, bool, SemanticColor, &'a str) {
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let text = comparison.unwrap_or_else(|| timer.current_comparison());
         let comparison = comparison::or_current(comparison, timer);
@@ -113,7 +131,7 @@ impl Component {
         }
 
         let semantic_color = if let Some(index) = index {
-            state_helper::split_color(
+            state_helper::split_color_with(
                 timer,
                 delta,
                 index,
@@ -121,16 +139,33 @@ impl Component {
                 false,
                 comparison,
                 timer.current_timing_method(),
+                layout_settings.split_coloring(),
             )
         } else {
             SemanticColor::Default
         };
 
+        (delta, use_live_delta, semantic_color, text)
+    }
+
+    /// Updates the component's state based on the timer and layout settings
+    /// provided. This is the state of the component if the delta is not shown
+    /// in large text.
+    pub fn update_state(
+        &self,
+        state: &mut key_value::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let (delta, use_live_delta, semantic_color, text) = self.delta(timer, layout_settings);
+
         let value_color = Some(semantic_color.visualize(layout_settings));
 
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
         state.value_color = value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
 
         state.key.clear();
         state.key.push_str(text);
@@ -147,12 +182,15 @@ impl Component {
             state.key_abbreviations.push(abbreviation.into());
         }
 
+        state.additional_rows.clear();
+
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = use_live_delta;
     }
 
     /// Calculates the component's state based on the timer and the layout
-    /// settings provided.
+    /// settings provided. This is the state of the component if the delta is
+    /// not shown in large text.
     pub fn state(
         &self,
         timer: &Snapshot<'_>,
@@ -163,6 +201,53 @@ impl Component {
         state
     }
 
+    /// Updates the component's state based on the timer and layout settings
+    /// provided. This is the state of the component if the delta is shown in
+    /// large text. The delta is colored like a timer showing it would be.
+    pub fn update_large_state(
+        &self,
+        state: &mut timer::State,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) {
+        let (delta, use_live_delta, semantic_color, _) = self.delta(timer, layout_settings);
+
+        (state.top_color, state.bottom_color) =
+            timer::top_and_bottom_color(semantic_color.visualize(layout_settings));
+        state.background = self.settings.background;
+
+        state.time.clear();
+        let _ = write!(
+            state.time,
+            "{}",
+            Delta::custom(self.settings.drop_decimals, self.settings.accuracy).format(delta),
+        );
+
+        state.fraction.clear();
+        if let Some(index) = state.time.rfind('.') {
+            state.fraction.push_str(&state.time[index..]);
+            state.time.truncate(index);
+        }
+
+        state.semantic_color = semantic_color;
+        state.height = self.settings.large_height;
+        state.updates_frequently = use_live_delta;
+        state.timer_font.clone_from(&self.settings.times_font);
+    }
+
+    /// Calculates the component's state based on the timer and the layout
+    /// settings provided. This is the state of the component if the delta is
+    /// shown in large text.
+    pub fn large_state(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> timer::State {
+        let mut state = Default::default();
+        self.update_large_state(&mut state, timer, layout_settings);
+        state
+    }
+
     /// Accesses a generic description of the settings available for this
     /// component and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
@@ -197,6 +282,26 @@ impl Component {
                 "The accuracy of the delta shown.".into(),
                 self.settings.accuracy.into()
             ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
+            Field::new(
+                "Display Large".into(),
+                "Specifies whether to only show the delta in large text, similar to a timer, instead of showing it next to the name of the comparison.".into(),
+                self.settings.display_large.into(),
+            ),
+            Field::new(
+                "Large Height".into(),
+                "The height of the component in pixels when the delta is shown in large text.".into(),
+                u64::from(self.settings.large_height).into(),
+            ),
         ])
     }
 
@@ -215,6 +320,10 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.drop_decimals = value.into(),
             5 => self.settings.accuracy = value.into(),
+            6 => self.settings.text_font = value.into(),
+            7 => self.settings.times_font = value.into(),
+            8 => self.settings.display_large = value.into(),
+            9 => self.settings.large_height = value.into_uint().unwrap() as _,
             _ => panic!("Unsupported Setting Index"),
         }
     }
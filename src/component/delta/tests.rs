@@ -1,5 +1,10 @@
 use super::Component;
-use crate::{GeneralLayoutSettings, Run, Segment, Timer};
+use crate::{
+    layout::{self, ComponentState},
+    settings::{ImageCache, SemanticColor},
+    util::tests_helper::{create_timer, make_progress_run_with_splits_opt, run_with_splits},
+    GeneralLayoutSettings, Run, Segment, Timer,
+};
 
 #[test]
 fn comparison_text() {
@@ -45,3 +50,32 @@ fn comparison_text() {
         timer.current_comparison()
     );
 }
+
+#[test]
+fn can_display_the_delta_large() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    timer.start().unwrap();
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.5)]);
+
+    let mut component = Component::new();
+    let settings = GeneralLayoutSettings::default();
+    assert_eq!(
+        &*component.state(&timer.snapshot(), &settings).value,
+        "+2.5"
+    );
+
+    component.settings_mut().display_large = true;
+    component.settings_mut().large_height = 80;
+    let state = component.large_state(&timer.snapshot(), &settings);
+    assert_eq!(state.time, "+2");
+    assert_eq!(state.fraction, ".5");
+    assert_eq!(state.height, 80);
+    assert_eq!(state.semantic_color, SemanticColor::BehindLosingTime);
+
+    let mut layout = layout::Layout::new();
+    layout.push(component);
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    assert!(matches!(state.components[0], ComponentState::Timer(_)));
+}
@@ -9,7 +9,9 @@ use crate::{
     analysis::comparison_single_segment_time,
     comparison::{self, best_segments, none},
     platform::prelude::*,
-    settings::{Color, Field, Gradient, Image, ImageCache, ImageId, SettingsDescription, Value},
+    settings::{
+        Color, Field, Font, Gradient, Image, ImageCache, ImageId, SettingsDescription, Value,
+    },
     timing::{
         formatter::{Accuracy, DigitsFormat, SegmentTime, TimeFormatter},
         Snapshot,
@@ -54,6 +56,10 @@ pub struct Settings {
     pub segment_timer: timer::Settings,
     /// Specifies whether the segment icon should be shown.
     pub display_icon: bool,
+    /// The size of the segment icon in pixels. If 0 is specified, the icon
+    /// fills the height of the component. The icon never exceeds the height of
+    /// the component.
+    pub icon_size: u32,
     /// Specifies whether the segment name should be shown.
     pub show_segment_name: bool,
     /// The color of the segment name if it's shown. If [`None`] is specified,
@@ -67,6 +73,15 @@ pub struct Settings {
     pub comparison_times_color: Option<Color>,
     /// The accuracy of the comparison times.
     pub comparison_times_accuracy: Accuracy,
+    /// The font to use for the timer of the component. If `None` is specified,
+    /// the timer font of the layout is used.
+    pub timer_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
 }
 
 /// The state object describes the information to visualize for this component.
@@ -89,6 +104,10 @@ pub struct State {
     /// image cache. The image may be the empty image. This indicates that there
     /// is no icon.
     pub icon: ImageId,
+    /// The size the icon is supposed to be shown at in pixels. If this is 0,
+    /// the icon is supposed to fill the height of the component. The icon
+    /// should never exceed the height of the component.
+    pub icon_size: u32,
     /// The color of the segment name if it's shown. If [`None`] is specified,
     /// the color is taken from the layout.
     pub segment_name_color: Option<Color>,
@@ -98,6 +117,13 @@ pub struct State {
     /// The color of the comparison times if they are shown. If [`None`] is
     /// specified, the color is taken from the layout.
     pub comparison_times_color: Option<Color>,
+    /// The font to use for the comparison times. If [`None`] is specified, the
+    /// times font of the layout is used. The font of the timers is part of
+    /// their own states.
+    pub times_font: Option<Font>,
+    /// The font to use for the segment name and the comparison names. If
+    /// [`None`] is specified, the text font of the layout is used.
+    pub text_font: Option<Font>,
 }
 
 /// The state object describing a comparison to visualize.
@@ -154,11 +180,15 @@ impl Default for Settings {
                 ..Default::default()
             },
             display_icon: false,
+            icon_size: 0,
             show_segment_name: false,
             segment_name_color: None,
             comparison_names_color: None,
             comparison_times_color: None,
             comparison_times_accuracy: SegmentTime::DEFAULT_ACCURACY,
+            timer_font: None,
+            times_font: None,
+            text_font: None,
         }
     }
 }
@@ -297,6 +327,7 @@ impl Component {
             .map(|s| s.icon())
             .unwrap_or(Image::EMPTY);
         state.icon = *image_cache.cache(icon.id(), || icon.clone()).id();
+        state.icon_size = self.settings.icon_size;
 
         self.timer
             .update_state(&mut state.timer, timer, layout_settings);
@@ -304,6 +335,12 @@ impl Component {
         self.segment_timer
             .update_state(&mut state.segment_timer, timer, layout_settings);
 
+        state.timer.timer_font.clone_from(&self.settings.timer_font);
+        state
+            .segment_timer
+            .timer_font
+            .clone_from(&self.settings.timer_font);
+
         state.background = self
             .settings
             .background
@@ -332,6 +369,8 @@ impl Component {
         state.segment_name_color = self.settings.segment_name_color;
         state.comparison_names_color = self.settings.comparison_names_color;
         state.comparison_times_color = self.settings.comparison_times_color;
+        state.times_font.clone_from(&self.settings.times_font);
+        state.text_font.clone_from(&self.settings.text_font);
     }
 
     /// Calculates the component's state based on the timer and layout settings
@@ -463,6 +502,26 @@ impl Component {
                 "Specifies whether the segment icon should be shown.".into(),
                 self.settings.display_icon.into(),
             ),
+            Field::new(
+                "Icon Size".into(),
+                "The size of the segment icon in pixels. If set to 0, the icon fills the height of the component. The icon never exceeds the height of the component.".into(),
+                u64::from(self.settings.icon_size).into(),
+            ),
+            Field::new(
+                "Custom Timer Font".into(),
+                "Allows you to specify a custom font for the timer of this component. If this is not set, the timer font of the layout is used.".into(),
+                self.settings.timer_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
         ])
     }
 
@@ -540,6 +599,10 @@ impl Component {
             18 => self.settings.show_segment_name = value.into(),
             19 => self.settings.segment_name_color = value.into(),
             20 => self.settings.display_icon = value.into(),
+            21 => self.settings.icon_size = value.into_uint().unwrap() as _,
+            22 => self.settings.timer_font = value.into(),
+            23 => self.settings.times_font = value.into(),
+            24 => self.settings.text_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -5,6 +5,7 @@
 //! its y-coordinate is proportional to the split delta. The entire diagram is
 //! referred to as the chart and it contains the graph. The x-axis is the
 //! horizontal line that separates positive deltas from negative ones.
+//! Additional comparisons can be plotted into the same chart as separate series.
 
 // The words "padding" and "content" are from the CSS box model. "Padding" is an
 // area at the top/bottom that stays empty so that the graph doesn't touch the
@@ -18,8 +19,12 @@ use crate::{
     GeneralLayoutSettings, TimeSpan, Timer, TimerPhase,
 };
 use alloc::borrow::Cow;
+use core::mem;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 const WIDTH: f32 = 1.0;
 const HEIGHT: f32 = 1.0;
 const DEFAULT_X_AXIS: f32 = HEIGHT / 2.0;
@@ -70,6 +75,28 @@ pub struct Settings {
     pub complete_fill_color: Color,
     /// The height of the chart.
     pub height: u32,
+    /// The amount of the most recent splits to show. The chart then only
+    /// covers the time since the split right before them. If set to 0, all the
+    /// splits are shown.
+    pub visible_split_count: usize,
+    /// Specifies whether the points of the graph are connected by smooth curves
+    /// instead of straight lines.
+    pub smooth_lines: bool,
+    /// Additional comparisons to compare the current attempt against. Each of
+    /// them is visualized as a separate series of points, connected by lines
+    /// of their own color.
+    pub additional_comparisons: Vec<AdditionalComparison>,
+}
+
+/// An additional comparison to compare the current attempt against in the
+/// graph.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdditionalComparison {
+    /// The name of the comparison.
+    pub comparison: String,
+    /// The color of the lines connecting the points of the series.
+    pub color: Color,
 }
 
 /// The state object describes the information to visualize for this component.
@@ -118,11 +145,29 @@ pub struct State {
     pub best_segment_color: Color,
     /// The height of the chart.
     pub height: u32,
+    /// Specifies whether the points of the graph are supposed to be connected
+    /// by smooth curves instead of straight lines.
+    pub smooth_lines: bool,
+    /// The series of points for each of the additional comparisons. They use
+    /// the same coordinate system as the main graph.
+    pub additional_series: Vec<Series>,
     /// This value indicates whether the graph is currently frequently being
     /// updated. This can be used for rendering optimizations.
     pub updates_frequently: bool,
 }
 
+/// Describes a series of points that visualizes how far the current attempt
+/// has been ahead or behind an additional comparison.
+#[derive(Serialize, Deserialize)]
+pub struct Series {
+    /// All of the series' points. Connect them to visualize the series. Unlike
+    /// the main graph, the region beneath the series is not supposed to be
+    /// filled.
+    pub points: Vec<Point>,
+    /// The color of the lines connecting the series' points.
+    pub color: Color,
+}
+
 /// Describes a point on the graph to visualize.
 #[derive(Serialize, Deserialize)]
 pub struct Point {
@@ -151,6 +196,18 @@ impl Default for Settings {
             partial_fill_color: Color::rgba(1.0, 1.0, 1.0, 0.25),
             complete_fill_color: Color::rgba(1.0, 1.0, 1.0, 0.4),
             height: 80,
+            visible_split_count: 0,
+            smooth_lines: false,
+            additional_comparisons: Vec::new(),
+        }
+    }
+}
+
+impl Default for AdditionalComparison {
+    fn default() -> Self {
+        Self {
+            comparison: String::from(comparison::best_segments::NAME),
+            color: Color::rgba(1.0, 212.0 / 255.0, 0.0, 1.0),
         }
     }
 }
@@ -170,6 +227,7 @@ impl State {
 #[derive(Default)]
 struct DrawInfo {
     points: Vec<Point>,
+    additional_points: Vec<Vec<Point>>,
     /// The lowest delta value in seconds.
     min_delta: f32,
     /// The highest delta value in seconds.
@@ -178,6 +236,13 @@ struct DrawInfo {
     scale_factor_y: Option<f32>,
     padding_y: f32,
     split_index: usize,
+    /// The index of the first split shown.
+    first_split_index: usize,
+    /// The index of the segment whose split time is the left edge of the
+    /// chart. If this is `None`, the chart starts at the start of the attempt.
+    start_split_index: Option<usize>,
+    /// The split time at the left edge of the chart in seconds.
+    start_time: f32,
     flip_graph: bool,
     is_live_delta_active: bool,
 }
@@ -260,6 +325,17 @@ impl Component {
         state.middle = x_axis;
         state.is_live_delta_active = draw_info.is_live_delta_active;
         state.points = draw_info.points;
+        state.additional_series.clear();
+        state.additional_series.extend(
+            draw_info
+                .additional_points
+                .into_iter()
+                .zip(&self.settings.additional_comparisons)
+                .map(|(points, comparison)| Series {
+                    points,
+                    color: comparison.color,
+                }),
+        );
         state.updates_frequently = timer
             .current_phase()
             .updates_frequently(timer.current_timing_method());
@@ -276,7 +352,7 @@ impl Component {
     /// Accesses a generic description of the settings available for this
     /// component and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut settings = SettingsDescription::with_fields(vec![
             Field::new(
                 "Comparison".into(),
                 "The comparison to use for the graph. If not specified, the current comparison is used.".into(),
@@ -332,7 +408,38 @@ impl Component {
                 "The color of the region enclosed by the x-axis and the graph, excluding the graph segment with live changes.".into(),
                 self.settings.complete_fill_color.into(),
             ),
-        ])
+            Field::new(
+                "Visible Splits".into(),
+                "The amount of the most recent splits to show. The graph then only covers the time since the split right before them. If set to 0, all the splits are shown.".into(),
+                Value::UInt(self.settings.visible_split_count as _),
+            ),
+            Field::new(
+                "Smooth Lines".into(),
+                "Specifies whether the points of the graph are connected by smooth curves instead of straight lines.".into(),
+                self.settings.smooth_lines.into(),
+            ),
+        ]);
+
+        settings.fields.push(Field::new(
+            "Additional Comparisons".into(),
+            "The number of additional comparisons to compare the current attempt against. Each of them is shown as a separate series of points.".into(),
+            Value::UInt(self.settings.additional_comparisons.len() as _),
+        ));
+
+        for additional in &self.settings.additional_comparisons {
+            settings.fields.push(Field::new(
+                "Comparison".into(),
+                "The additional comparison to compare the current attempt against.".into(),
+                additional.comparison.clone().into(),
+            ));
+            settings.fields.push(Field::new(
+                "Color".into(),
+                "The color of the lines connecting the points of the additional comparison.".into(),
+                additional.color.into(),
+            ));
+        }
+
+        settings
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -355,30 +462,66 @@ impl Component {
             8 => self.settings.graph_lines_color = value.into(),
             9 => self.settings.partial_fill_color = value.into(),
             10 => self.settings.complete_fill_color = value.into(),
-            _ => panic!("Unsupported Setting Index"),
+            11 => self.settings.visible_split_count = value.into_uint().unwrap() as _,
+            12 => self.settings.smooth_lines = value.into(),
+            13 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings
+                    .additional_comparisons
+                    .resize(new_len, Default::default());
+            }
+            index => {
+                let index = index - 14;
+                let additional = self
+                    .settings
+                    .additional_comparisons
+                    .get_mut(index / 2)
+                    .expect("Unsupported Setting Index");
+                match index % 2 {
+                    0 => additional.comparison = value.into(),
+                    _ => additional.color = value.into(),
+                }
+            }
         }
     }
 
     fn calculate_graph(&self, timer: &Snapshot<'_>, draw_info: &mut DrawInfo) -> Option<f32> {
         let settings = &self.settings;
         draw_info.split_index = timer.current_split_index()?;
+        if settings.visible_split_count != 0 {
+            draw_info.first_split_index = draw_info
+                .split_index
+                .saturating_sub(settings.visible_split_count);
+        }
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(comparison, timer);
 
         calculate_horizontal_scaling(timer, draw_info, settings.live_graph);
         draw_info.scale_factor_x?;
 
-        draw_info.points = Vec::with_capacity(draw_info.split_index + 1);
-        draw_info.points.push(Point {
-            x: 0.0,
-            y: 0.0, // Not the final value of y, this will end up on the x-axis.
-            is_best_segment: false,
-        });
-
-        calculate_split_points(timer, draw_info, comparison, settings.show_best_segments);
-        if settings.live_graph {
-            calculate_live_delta_point(timer, draw_info, comparison);
+        draw_info.is_live_delta_active = calculate_series(
+            timer,
+            draw_info,
+            comparison,
+            settings.show_best_segments,
+            settings.live_graph,
+        );
+
+        // The points of the main graph are moved out of the way while the
+        // additional series are calculated, as they are calculated the same way.
+        let points = mem::take(&mut draw_info.points);
+        for additional in &settings.additional_comparisons {
+            calculate_series(
+                timer,
+                draw_info,
+                &additional.comparison,
+                false,
+                settings.live_graph,
+            );
+            let additional_points = mem::take(&mut draw_info.points);
+            draw_info.additional_points.push(additional_points);
         }
+        draw_info.points = points;
 
         calculate_vertical_scaling(draw_info);
         let x_axis = calculate_x_axis(draw_info);
@@ -408,11 +551,58 @@ impl Component {
         state.partial_fill_color = settings.partial_fill_color;
         state.complete_fill_color = settings.complete_fill_color;
         state.height = settings.height;
+        state.smooth_lines = settings.smooth_lines;
+    }
+}
+
+/// Calculates the points of a single series, which are stored in the draw info.
+/// Returns whether the last point is a preview of the live delta.
+fn calculate_series(
+    timer: &Snapshot<'_>,
+    draw_info: &mut DrawInfo,
+    comparison: &str,
+    show_best_segments: bool,
+    live_graph: bool,
+) -> bool {
+    // The first point is on the x-axis, unless the chart doesn't start at the
+    // start of the attempt. In that case it's the split before the first
+    // split shown.
+    let start_delta = draw_info.start_split_index.and_then(|index| {
+        let timing_method = timer.current_timing_method();
+        let segment = timer.run().segment(index);
+        let split_time = segment.split_time()[timing_method]?;
+        let comparison_time = segment.comparison(comparison)[timing_method]?;
+        Some((split_time - comparison_time).total_seconds() as f32)
+    });
+    if let Some(delta) = start_delta {
+        draw_info.max_delta = draw_info.max_delta.max(delta);
+        draw_info.min_delta = draw_info.min_delta.min(delta);
     }
+
+    draw_info.points = Vec::with_capacity(draw_info.split_index - draw_info.first_split_index + 2);
+    draw_info.points.push(Point {
+        x: 0.0,
+        y: start_delta.unwrap_or(0.0), // Not the final value of y.
+        is_best_segment: false,
+    });
+
+    calculate_split_points(timer, draw_info, comparison, show_best_segments);
+    live_graph && calculate_live_delta_point(timer, draw_info, comparison)
 }
 
 fn calculate_horizontal_scaling(timer: &Snapshot<'_>, draw_info: &mut DrawInfo, live_graph: bool) {
     let timing_method = timer.current_timing_method();
+    let segments = timer.run().segments();
+
+    // The chart starts at the last split time before the first split shown.
+    draw_info.start_split_index = segments[..draw_info.first_split_index]
+        .iter()
+        .rposition(|s| s.split_time()[timing_method].is_some());
+    draw_info.start_time = draw_info.start_split_index.map_or(0.0, |index| {
+        segments[index].split_time()[timing_method]
+            .unwrap_or_default()
+            .total_seconds() as f32
+    });
 
     // final_split is the split time of a theoretical point on the right edge of
     // the chart.
@@ -425,7 +615,7 @@ fn calculate_horizontal_scaling(timer: &Snapshot<'_>, draw_info: &mut DrawInfo,
             .total_seconds() as f32;
     } else {
         // Find the last segment with a split time.
-        for segment in timer.run().segments()[..draw_info.split_index].iter().rev() {
+        for segment in segments[..draw_info.split_index].iter().rev() {
             if let Some(time) = segment.split_time()[timing_method] {
                 final_split = time.total_seconds() as f32;
                 break;
@@ -433,8 +623,8 @@ fn calculate_horizontal_scaling(timer: &Snapshot<'_>, draw_info: &mut DrawInfo,
         }
     }
 
-    if final_split > 0.0 {
-        draw_info.scale_factor_x = Some(WIDTH / final_split);
+    if final_split > draw_info.start_time {
+        draw_info.scale_factor_x = Some(WIDTH / (final_split - draw_info.start_time));
     }
 
     // Else scaling doesn't matter and scale_factor_x stays None.
@@ -454,6 +644,7 @@ fn calculate_split_points(
     for (i, segment) in timer.run().segments()[..draw_info.split_index]
         .iter()
         .enumerate()
+        .skip(draw_info.first_split_index)
     {
         catch! {
             let split_time = segment.split_time()[timing_method]?;
@@ -466,7 +657,8 @@ fn calculate_split_points(
                 draw_info.min_delta = delta;
             }
 
-            let x = split_time.total_seconds() as f32 * draw_info.scale_factor_x.unwrap_or(0.0);
+            let x = (split_time.total_seconds() as f32 - draw_info.start_time)
+                * draw_info.scale_factor_x.unwrap_or(0.0);
 
             let is_best_segment =
                 show_best_segments && analysis::check_best_segment(timer, i, timing_method);
@@ -480,9 +672,13 @@ fn calculate_split_points(
     }
 }
 
-fn calculate_live_delta_point(timer: &Snapshot<'_>, draw_info: &mut DrawInfo, comparison: &str) {
+fn calculate_live_delta_point(
+    timer: &Snapshot<'_>,
+    draw_info: &mut DrawInfo,
+    comparison: &str,
+) -> bool {
     if timer.current_phase() == TimerPhase::Ended {
-        return;
+        return false;
     }
 
     let timing_method = timer.current_timing_method();
@@ -516,7 +712,9 @@ fn calculate_live_delta_point(timer: &Snapshot<'_>, draw_info: &mut DrawInfo, co
             y: delta, // Not the final value of y.
             is_best_segment: false,
         });
-        draw_info.is_live_delta_active = true;
+        true
+    } else {
+        false
     }
 }
 
@@ -623,8 +821,13 @@ fn update_grid_line_vecs(state: &mut State, grid_lines: GridLines) {
 /// deltas. The reason why this can't be done in the first loop is that
 /// `min_`/`max_delta` is not known yet at that point in time.
 fn transform_y_coordinates(draw_info: &mut DrawInfo) {
+    let points = draw_info
+        .points
+        .iter_mut()
+        .chain(draw_info.additional_points.iter_mut().flatten());
+
     if let Some(scale_factor_y) = draw_info.scale_factor_y {
-        for point in &mut draw_info.points {
+        for point in points {
             let delta = point.y;
             point.y = (draw_info.max_delta - delta) * scale_factor_y + draw_info.padding_y;
             if draw_info.flip_graph {
@@ -632,7 +835,7 @@ fn transform_y_coordinates(draw_info: &mut DrawInfo) {
             }
         }
     } else {
-        for point in &mut draw_info.points {
+        for point in points {
             point.y = DEFAULT_X_AXIS;
         }
     }
@@ -0,0 +1,96 @@
+use super::{AdditionalComparison, Component};
+use crate::{
+    comparison::best_segments,
+    settings::{Color, Value},
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings,
+};
+
+#[test]
+fn compares_against_additional_comparisons() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 19.0, 31.0]);
+
+    let mut component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0), Some(21.0)]);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert!(state.additional_series.is_empty());
+
+    let color = Color::rgba(0.0, 0.0, 1.0, 1.0);
+    component
+        .settings_mut()
+        .additional_comparisons
+        .push(AdditionalComparison {
+            comparison: best_segments::NAME.into(),
+            color,
+        });
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let [series] = &*state.additional_series else {
+        panic!("Expected a single additional series");
+    };
+    assert_eq!(series.color, color);
+    assert_eq!(series.points.len(), state.points.len());
+
+    // The additional series shares the split times. The current attempt is
+    // just as far behind both comparisons on the first split, but further
+    // behind the best segments on the second split, which shows above.
+    for (point, main_point) in series.points.iter().zip(&state.points) {
+        assert_eq!(point.x, main_point.x);
+    }
+    assert_eq!(series.points[1].y, state.points[1].y);
+    assert!(series.points[2].y < state.points[2].y);
+}
+
+#[test]
+fn additional_comparisons_can_be_configured() {
+    let mut component = Component::new();
+
+    let fields = component.settings_description().fields;
+    assert_eq!(&*fields.last().unwrap().text, "Additional Comparisons");
+    let index = fields.len() - 1;
+
+    component.set_value(index, Value::UInt(2));
+    component.set_value(index + 3, Value::String("Average Segments".into()));
+    component.set_value(index + 4, Color::rgba(1.0, 0.0, 0.0, 1.0).into());
+
+    let comparisons = &component.settings().additional_comparisons;
+    assert_eq!(comparisons.len(), 2);
+    assert_eq!(comparisons[0].comparison, best_segments::NAME);
+    assert_eq!(comparisons[1].comparison, "Average Segments");
+    assert_eq!(comparisons[1].color, Color::rgba(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(component.settings_description().fields.len(), index + 5);
+}
+
+#[test]
+fn only_shows_the_visible_splits() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    let mut component = Component::new();
+    component.settings_mut().live_graph = false;
+    let layout_settings = GeneralLayoutSettings::default();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(8.0), Some(20.0), Some(35.0)]);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let xs = state.points.iter().map(|p| p.x).collect::<Vec<_>>();
+    assert_eq!(xs, [0.0, 8.0 / 35.0, 20.0 / 35.0, 1.0]);
+
+    component.settings_mut().visible_split_count = 2;
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let xs = state.points.iter().map(|p| p.x).collect::<Vec<_>>();
+    assert_eq!(xs, [0.0, 12.0 / 27.0, 1.0]);
+
+    // The graph starts at the delta of the split before the visible ones,
+    // which is 2 seconds ahead, so it's below the x-axis.
+    assert!(state.points[0].y > state.middle);
+}
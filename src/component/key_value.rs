@@ -5,7 +5,7 @@
 
 use crate::{
     platform::prelude::*,
-    settings::{Color, Gradient, SemanticColor},
+    settings::{Color, Font, Gradient, SemanticColor},
 };
 use alloc::borrow::Cow;
 use serde_derive::{Deserialize, Serialize};
@@ -22,6 +22,12 @@ pub struct State {
     /// The color of the value. If `None` is specified, the color is taken from
     /// the layout.
     pub value_color: Option<Color>,
+    /// The font to use for the key. If `None` is specified, the text font of
+    /// the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the value. If `None` is specified, the times font
+    /// of the layout is used.
+    pub times_font: Option<Font>,
     /// The semantic coloring information the value carries.
     pub semantic_color: SemanticColor,
     /// The key to visualize.
@@ -31,6 +37,9 @@ pub struct State {
     /// Specifies additional abbreviations for the key that can be used instead
     /// of the key, if there is not enough space to show the whole key.
     pub key_abbreviations: Vec<Cow<'static, str>>,
+    /// Additional keys and values to visualize below the key and the value.
+    /// Each of them is shown in a row of its own.
+    pub additional_rows: Vec<Row>,
     /// Specifies whether to display the key and the value in two separate rows.
     pub display_two_rows: bool,
     /// This value indicates whether the value is currently frequently being
@@ -38,6 +47,19 @@ pub struct State {
     pub updates_frequently: bool,
 }
 
+/// An additional key and value that a key value based component visualizes
+/// below its main key and value.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Row {
+    /// The key to visualize.
+    pub key: String,
+    /// The value to visualize.
+    pub value: String,
+    /// Specifies additional abbreviations for the key that can be used instead
+    /// of the key, if there is not enough space to show the whole key.
+    pub key_abbreviations: Vec<Cow<'static, str>>,
+}
+
 #[cfg(feature = "std")]
 impl State {
     /// Encodes the state object's information as JSON.
@@ -4,6 +4,7 @@
 //! visualized by any kind of User Interface.
 
 pub mod blank_space;
+pub mod countdown;
 pub mod current_comparison;
 pub mod current_pace;
 pub mod delta;
@@ -13,6 +14,7 @@ pub mod pb_chance;
 pub mod possible_time_save;
 pub mod previous_segment;
 pub mod segment_time;
+pub mod segment_tracker;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
@@ -20,10 +22,12 @@ pub mod text;
 pub mod timer;
 pub mod title;
 pub mod total_playtime;
+pub mod world_record;
 
 pub mod key_value;
 
 pub use blank_space::Component as BlankSpace;
+pub use countdown::Component as Countdown;
 pub use current_comparison::Component as CurrentComparison;
 pub use current_pace::Component as CurrentPace;
 pub use delta::Component as Delta;
@@ -33,6 +37,7 @@ pub use pb_chance::Component as PbChance;
 pub use possible_time_save::Component as PossibleTimeSave;
 pub use previous_segment::Component as PreviousSegment;
 pub use segment_time::Component as SegmentTime;
+pub use segment_tracker::Component as SegmentTracker;
 pub use separator::Component as Separator;
 pub use splits::Component as Splits;
 pub use sum_of_best::Component as SumOfBest;
@@ -40,3 +45,4 @@ pub use text::Component as Text;
 pub use timer::Component as Timer;
 pub use title::Component as Title;
 pub use total_playtime::Component as TotalPlaytime;
+pub use world_record::Component as WorldRecord;
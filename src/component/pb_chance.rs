@@ -8,7 +8,7 @@ use super::key_value;
 use crate::{
     analysis::pb_chance,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
     timing::Snapshot,
 };
 use core::fmt::Write;
@@ -38,6 +38,12 @@ pub struct Settings {
     /// The color of the value. If `None` is specified, the color is taken from
     /// the layout.
     pub value_color: Option<Color>,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -47,6 +53,8 @@ impl Default for Settings {
             display_two_rows: false,
             label_color: None,
             value_color: None,
+            text_font: None,
+            times_font: None,
         }
     }
 }
@@ -84,6 +92,8 @@ impl Component {
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
         state.value_color = self.settings.value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
         state.semantic_color = Default::default();
 
         state.key.clear();
@@ -93,6 +103,7 @@ impl Component {
         let _ = write!(state.value, "{:.1}%", 100.0 * chance);
 
         state.key_abbreviations.clear();
+        state.additional_rows.clear();
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = is_live;
     }
@@ -131,6 +142,16 @@ impl Component {
                     .into(),
                 self.settings.value_color.into(),
             ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
         ])
     }
 
@@ -147,6 +168,8 @@ impl Component {
             1 => self.settings.display_two_rows = value.into(),
             2 => self.settings.label_color = value.into(),
             3 => self.settings.value_color = value.into(),
+            4 => self.settings.text_font = value.into(),
+            5 => self.settings.times_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
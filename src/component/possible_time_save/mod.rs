@@ -9,7 +9,7 @@ use crate::{
     analysis::possible_time_save,
     comparison,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
     timing::{
         formatter::{Accuracy, SegmentTime, TimeFormatter},
         Snapshot,
@@ -20,6 +20,9 @@ use alloc::borrow::Cow;
 use core::fmt::Write as FmtWrite;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// The Possible Time Save Component is a component that shows how much time the
 /// chosen comparison could've saved for the current segment, based on the Best
 /// Segments. This component also allows showing the Total Possible Time Save
@@ -45,6 +48,10 @@ pub struct Settings {
     /// save for the current attempt is shown, instead of the time save for the
     /// current segment.
     pub total_possible_time_save: bool,
+    /// Specifies whether the Total Possible Time Save leaves out the current
+    /// segment, so only the time save of the segments that are still ahead is
+    /// shown.
+    pub exclude_current_segment: bool,
     /// The color of the label. If `None` is specified, the color is taken from
     /// the layout.
     pub label_color: Option<Color>,
@@ -53,6 +60,12 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -62,9 +75,12 @@ impl Default for Settings {
             comparison_override: None,
             display_two_rows: false,
             total_possible_time_save: false,
+            exclude_current_segment: false,
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Hundredths,
+            text_font: None,
+            times_font: None,
         }
     }
 }
@@ -122,8 +138,13 @@ impl Component {
         let comparison = comparison::or_current(comparison, timer);
 
         let (time, updates_frequently) = if self.settings.total_possible_time_save {
+            let first_segment_index = match segment_index {
+                Some(index) if self.settings.exclude_current_segment => index + 1,
+                Some(index) => index,
+                None => 0,
+            };
             let (time, updates_frequently) =
-                possible_time_save::calculate_total(timer, segment_index.unwrap_or(0), comparison);
+                possible_time_save::calculate_total(timer, first_segment_index, comparison);
             (Some(time), updates_frequently)
         } else if current_phase == TimerPhase::Running || current_phase == TimerPhase::Paused {
             possible_time_save::calculate(timer, segment_index.unwrap(), comparison, false)
@@ -134,6 +155,8 @@ impl Component {
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
         state.value_color = self.settings.value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
         state.semantic_color = Default::default();
 
         state.key.clear();
@@ -156,6 +179,8 @@ impl Component {
         state.key_abbreviations.push("Poss. Time Save".into());
         state.key_abbreviations.push("Time Save".into());
 
+        state.additional_rows.clear();
+
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = updates_frequently;
     }
@@ -191,6 +216,11 @@ impl Component {
                 "Specifies whether to show the total possible time save for the remainder of the current attempt, instead of the possible time save for the current segment.".into(),
                 self.settings.total_possible_time_save.into(),
             ),
+            Field::new(
+                "Exclude Current Segment".into(),
+                "Specifies whether the total possible time save leaves out the current segment, so only the time that could be saved on the segments that are still ahead is shown.".into(),
+                self.settings.exclude_current_segment.into(),
+            ),
             Field::new(
                 "Label Color".into(),
                 "The color of the component's name. If not specified, the color is taken from the layout.".into(),
@@ -206,6 +236,16 @@ impl Component {
                 "The accuracy of the possible time save shown.".into(),
                 self.settings.accuracy.into(),
             ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
         ])
     }
 
@@ -222,9 +262,12 @@ impl Component {
             1 => self.settings.comparison_override = value.into(),
             2 => self.settings.display_two_rows = value.into(),
             3 => self.settings.total_possible_time_save = value.into(),
-            4 => self.settings.label_color = value.into(),
-            5 => self.settings.value_color = value.into(),
-            6 => self.settings.accuracy = value.into(),
+            4 => self.settings.exclude_current_segment = value.into(),
+            5 => self.settings.label_color = value.into(),
+            6 => self.settings.value_color = value.into(),
+            7 => self.settings.accuracy = value.into(),
+            8 => self.settings.text_font = value.into(),
+            9 => self.settings.times_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -0,0 +1,46 @@
+use super::Component;
+use crate::{
+    settings::Value,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    Timer,
+};
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[8.0, 22.0, 31.0]);
+    timer
+}
+
+#[test]
+fn shows_the_possible_time_save_of_the_current_segment() {
+    let component = Component::new();
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.key, "Possible Time Save");
+    assert_eq!(&*state.value, "2.00");
+}
+
+#[test]
+fn sums_the_possible_time_save_of_the_remaining_segments() {
+    let mut component = Component::new();
+    component.set_value(3, Value::Bool(true));
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.key, "Total Possible Time Save");
+    assert_eq!(&*state.value, "3.00");
+
+    component.set_value(4, Value::Bool(true));
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "1.00");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0), Some(19.0)]);
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "0.00");
+}
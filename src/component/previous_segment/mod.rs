@@ -4,13 +4,15 @@
 //! chosen comparison. Additionally, the potential time save for the previous
 //! [`Segment`](crate::run::Segment) can be displayed. This component switches
 //! to a `Live Segment` view that shows active time loss whenever the runner is
-//! losing time on the current [`Segment`](crate::run::Segment).
+//! losing time on the current [`Segment`](crate::run::Segment). Instead of the
+//! time saved or lost, the component can also show the comparison's segment
+//! time.
 
 use super::key_value;
 use crate::{
     analysis, comparison,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
         formatter::{Accuracy, Delta, SegmentTime, TimeFormatter},
         Snapshot,
@@ -21,6 +23,9 @@ use alloc::borrow::Cow;
 use core::fmt::Write as FmtWrite;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// The Previous Segment Component is a component that shows how much time was
 /// saved or lost during the previous [`Segment`](crate::run::Segment) based on
 /// the chosen comparison. Additionally, the potential time save for the previous
@@ -55,6 +60,17 @@ pub struct Settings {
     /// Determines if the time save that could've been saved is shown in
     /// addition to the previous segment.
     pub show_possible_time_save: bool,
+    /// The accuracy of the possible time save shown.
+    pub time_save_accuracy: Accuracy,
+    /// Determines if the comparison's segment time is shown instead of how
+    /// much time was saved or lost.
+    pub show_comparison_segment_time: bool,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -67,6 +83,10 @@ impl Default for Settings {
             drop_decimals: true,
             accuracy: Accuracy::Tenths,
             show_possible_time_save: false,
+            time_save_accuracy: Accuracy::Tenths,
+            show_comparison_segment_time: false,
+            text_font: None,
+            times_font: None,
         }
     }
 }
@@ -133,9 +153,11 @@ impl Component {
 
         let phase = timer.current_phase();
         let method = timer.current_timing_method();
+        let mut segment_index = None;
         let semantic_color = if phase != TimerPhase::NotRunning {
             let split_index = timer.current_split_index().unwrap();
             if live_segment.is_some() {
+                segment_index = Some(split_index);
                 time_change = analysis::live_segment_delta(timer, split_index, comparison, method);
                 if self.settings.show_possible_time_save {
                     previous_possible = analysis::possible_time_save::calculate(
@@ -147,6 +169,7 @@ impl Component {
                     .0;
                 }
             } else if let Some(prev_split_index) = split_index.checked_sub(1) {
+                segment_index = Some(prev_split_index);
                 time_change =
                     analysis::previous_segment_delta(timer, prev_split_index, comparison, method);
                 if self.settings.show_possible_time_save {
@@ -160,9 +183,11 @@ impl Component {
                 }
             };
 
-            if let Some(time_change) = time_change {
+            if self.settings.show_comparison_segment_time {
+                SemanticColor::Default
+            } else if let Some(time_change) = time_change {
                 if live_segment.is_some() {
-                    analysis::split_color(
+                    analysis::split_color_with(
                         timer,
                         time_change.into(),
                         split_index,
@@ -170,9 +195,10 @@ impl Component {
                         false,
                         comparison,
                         method,
+                        layout_settings.split_coloring(),
                     )
                 } else if let Some(prev_split_index) = split_index.checked_sub(1) {
-                    analysis::split_color(
+                    analysis::split_color_with(
                         timer,
                         time_change.into(),
                         prev_split_index,
@@ -180,12 +206,13 @@ impl Component {
                         true,
                         comparison,
                         method,
+                        layout_settings.split_coloring(),
                     )
                 } else {
                     SemanticColor::Default
                 }
             } else if let Some(prev_split_index) = split_index.checked_sub(1) {
-                analysis::split_color(
+                analysis::split_color_with(
                     timer,
                     None,
                     prev_split_index,
@@ -193,6 +220,7 @@ impl Component {
                     true,
                     comparison,
                     method,
+                    layout_settings.split_coloring(),
                 )
             } else {
                 SemanticColor::Default
@@ -208,23 +236,43 @@ impl Component {
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
         state.value_color = value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
         state.semantic_color = semantic_color;
 
         state.key.clear();
         state.key.push_str(&text); // FIXME: Uncow
 
         state.value.clear();
-        let _ = write!(
-            state.value,
-            "{}",
-            Delta::custom(self.settings.drop_decimals, self.settings.accuracy).format(time_change),
-        );
+        if self.settings.show_comparison_segment_time {
+            let comparison_segment_time = segment_index.and_then(|segment_index| {
+                analysis::comparison_combined_segment_time(
+                    timer.run(),
+                    segment_index,
+                    comparison,
+                    method,
+                )
+            });
+            let _ = write!(
+                state.value,
+                "{}",
+                SegmentTime::with_accuracy(self.settings.accuracy).format(comparison_segment_time),
+            );
+        } else {
+            let _ = write!(
+                state.value,
+                "{}",
+                Delta::custom(self.settings.drop_decimals, self.settings.accuracy)
+                    .format(time_change),
+            );
+        }
 
         if self.settings.show_possible_time_save {
             let _ = write!(
                 state.value,
                 " / {}",
-                SegmentTime::with_accuracy(self.settings.accuracy).format(previous_possible),
+                SegmentTime::with_accuracy(self.settings.time_save_accuracy)
+                    .format(previous_possible),
             );
         }
 
@@ -238,6 +286,8 @@ impl Component {
             state.key_abbreviations.push("Prev. Seg.".into());
         }
 
+        state.additional_rows.clear();
+
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = live_segment.is_some() && phase.updates_frequently(method);
     }
@@ -293,6 +343,26 @@ impl Component {
                 "Specifies whether to show how much time could've been saved for the previous segment in addition to the time saved or lost.".into(),
                 self.settings.show_possible_time_save.into(),
             ),
+            Field::new(
+                "Time Save Accuracy".into(),
+                "The accuracy of the possible time save shown.".into(),
+                self.settings.time_save_accuracy.into(),
+            ),
+            Field::new(
+                "Show Comparison Segment Time".into(),
+                "Specifies whether to show the comparison's segment time instead of how much time was saved or lost.".into(),
+                self.settings.show_comparison_segment_time.into(),
+            ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
         ])
     }
 
@@ -312,6 +382,10 @@ impl Component {
             4 => self.settings.drop_decimals = value.into(),
             5 => self.settings.accuracy = value.into(),
             6 => self.settings.show_possible_time_save = value.into(),
+            7 => self.settings.time_save_accuracy = value.into(),
+            8 => self.settings.show_comparison_segment_time = value.into(),
+            9 => self.settings.text_font = value.into(),
+            10 => self.settings.times_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
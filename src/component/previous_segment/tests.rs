@@ -0,0 +1,40 @@
+use super::Component;
+use crate::{
+    settings::Value,
+    timing::formatter::Accuracy,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, Timer,
+};
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[8.0, 22.0, 31.0]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+    timer
+}
+
+#[test]
+fn shows_the_possible_time_save_with_its_own_accuracy() {
+    let mut component = Component::new();
+    component.set_value(6, Value::Bool(true));
+    component.set_value(7, Value::Accuracy(Accuracy::Hundredths));
+    let timer = timer();
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(&*state.key, "Previous Segment");
+    assert_eq!(&*state.value, "−1.0 / 2.00");
+}
+
+#[test]
+fn can_show_the_comparison_segment_time() {
+    let mut component = Component::new();
+    component.set_value(8, Value::Bool(true));
+    let timer = timer();
+
+    let state = component.state(&timer.snapshot(), &GeneralLayoutSettings::default());
+    assert_eq!(&*state.value, "10.0");
+}
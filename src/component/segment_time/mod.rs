@@ -8,7 +8,7 @@ use crate::{
     analysis::state_helper::comparison_single_segment_time,
     comparison,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
     timing::formatter::{Accuracy, SegmentTime, TimeFormatter},
     Timer, TimerPhase,
 };
@@ -47,6 +47,12 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -58,6 +64,8 @@ impl Default for Settings {
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Hundredths,
+            text_font: None,
+            times_font: None,
         }
     }
 }
@@ -126,6 +134,8 @@ impl Component {
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
         state.value_color = self.settings.value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
         state.semantic_color = Default::default();
 
         state.key.clear();
@@ -167,6 +177,8 @@ impl Component {
             }
         };
 
+        state.additional_rows.clear();
+
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = false;
     }
@@ -214,6 +226,16 @@ impl Component {
                 "The accuracy of the segment time shown.".into(),
                 self.settings.accuracy.into(),
             ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
         ])
     }
 
@@ -232,6 +254,8 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.value_color = value.into(),
             5 => self.settings.accuracy = value.into(),
+            6 => self.settings.text_font = value.into(),
+            7 => self.settings.times_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
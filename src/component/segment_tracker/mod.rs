@@ -0,0 +1,217 @@
+//! Provides the Segment Tracker Component. The Segment Tracker Component shows
+//! how many of the segments of the run have already been completed in the
+//! current attempt, out of all the segments of the run. Alternatively it can
+//! show how many segments are remaining.
+
+use super::key_value;
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
+    Segment, Timer,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Segment Tracker Component shows how many of the segments of the run
+/// have already been completed in the current attempt, out of all the segments
+/// of the run. Alternatively it can show how many segments are remaining.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// Specifies whether subsplits are counted as segments. Subsplits are the
+    /// segments whose names start with a `-`. They are grouped together with
+    /// the next segment that is not a subsplit. If subsplits are not counted,
+    /// only these groups are counted.
+    pub count_subsplits: bool,
+    /// Specifies whether to show the amount of segments that are remaining
+    /// instead of the ones that are completed.
+    pub show_remaining: bool,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            count_subsplits: true,
+            show_remaining: false,
+            text_font: None,
+            times_font: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Segment Tracker Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Segment Tracker Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Segment Tracker"
+    }
+
+    fn counts(&self, segment: &Segment) -> bool {
+        self.settings.count_subsplits || !segment.name().starts_with('-')
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
+        let segments = timer.run().segments();
+        let completed_segments = timer.current_split_index().unwrap_or(0);
+
+        let total = segments.iter().filter(|s| self.counts(s)).count();
+        let completed = segments[..completed_segments.min(segments.len())]
+            .iter()
+            .filter(|s| self.counts(s))
+            .count();
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key_abbreviations.clear();
+        if self.settings.show_remaining {
+            state.key.push_str("Remaining Segments");
+            state.key_abbreviations.push("Remaining".into());
+        } else {
+            state.key.push_str("Segments");
+        }
+
+        state.value.clear();
+        let shown = if self.settings.show_remaining {
+            total - completed
+        } else {
+            completed
+        };
+        let _ = write!(state.value, "{shown}/{total}");
+
+        state.additional_rows.clear();
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = false;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the amount of segments in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the amount of segments. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Count Subsplits".into(),
+                "Specifies whether subsplits are counted as segments. Subsplits are the segments whose names start with a dash. If they are not counted, only the segments that end a group of subsplits are counted.".into(),
+                self.settings.count_subsplits.into(),
+            ),
+            Field::new(
+                "Show Remaining Segments".into(),
+                "Specifies whether to show the amount of segments that are remaining instead of the ones that are completed.".into(),
+                self.settings.show_remaining.into(),
+            ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the amount of segments. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.count_subsplits = value.into(),
+            5 => self.settings.show_remaining = value.into(),
+            6 => self.settings.text_font = value.into(),
+            7 => self.settings.times_font = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
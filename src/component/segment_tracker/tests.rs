@@ -0,0 +1,57 @@
+use super::Component;
+use crate::{
+    util::tests_helper::{create_timer, make_progress_run_with_splits_opt, start_run},
+    Timer,
+};
+
+fn create_subsplits_timer() -> Timer {
+    create_timer(&["-A", "-B", "C", "-D", "E"])
+}
+
+#[test]
+fn counts_completed_segments() {
+    let component = Component::new();
+    let mut timer = create_subsplits_timer();
+    assert_eq!(&*component.state(&timer).value, "0/5");
+
+    start_run(&mut timer);
+    assert_eq!(&*component.state(&timer).value, "0/5");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0), None, Some(3.0)]);
+    let state = component.state(&timer);
+    assert_eq!(&*state.key, "Segments");
+    assert_eq!(&*state.value, "3/5");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0), Some(5.0)]);
+    assert_eq!(&*component.state(&timer).value, "5/5");
+}
+
+#[test]
+fn can_skip_subsplits() {
+    let mut component = Component::new();
+    component.settings_mut().count_subsplits = false;
+    let mut timer = create_subsplits_timer();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0), Some(2.0)]);
+    assert_eq!(&*component.state(&timer).value, "0/2");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(3.0), Some(4.0)]);
+    assert_eq!(&*component.state(&timer).value, "1/2");
+}
+
+#[test]
+fn can_show_remaining_segments() {
+    let mut component = Component::new();
+    component.settings_mut().show_remaining = true;
+    let mut timer = create_subsplits_timer();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0), Some(2.0)]);
+    let state = component.state(&timer);
+    assert_eq!(&*state.key, "Remaining Segments");
+    assert_eq!(&*state.value, "3/5");
+
+    component.settings_mut().count_subsplits = false;
+    assert_eq!(&*component.state(&timer).value, "2/2");
+}
@@ -0,0 +1,142 @@
+//! Provides the Separator Component and relevant types for using it. The
+//! Separator Component is a simple component that only serves to render
+//! separators between components. Its thickness and coloring can be
+//! configured, so it can also be used for visually dividing the sections of a
+//! layout.
+
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Field, SettingsDescription, Value},
+};
+use serde_derive::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Separator Component is a simple component that only serves to render
+/// separators between components.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The thickness of the separator in pixels. A thickness of 0 uses the
+    /// default thickness of separators.
+    pub thickness: u32,
+    /// The color of the separator. If [`None`] is specified, the color of the
+    /// separators specified by the layout is used.
+    pub color: Option<Color>,
+    /// The color the separator fades into along its length. If [`None`] is
+    /// specified, the separator is drawn with a single color.
+    pub gradient_color: Option<Color>,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The thickness of the separator in pixels. A thickness of 0 means that
+    /// the default thickness of separators should be used.
+    pub thickness: u32,
+    /// The color of the separator. If [`None`] is specified, the color of the
+    /// separators specified by the layout should be used.
+    pub color: Option<Color>,
+    /// The color the separator fades into along its length. If [`None`] is
+    /// specified, the separator should be drawn with a single color.
+    pub gradient_color: Option<Color>,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Separator Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Separator Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Separator"
+    }
+
+    /// Updates the component's state.
+    pub fn update_state(&self, state: &mut State) {
+        state.thickness = self.settings.thickness;
+        state.color = self.settings.color;
+        state.gradient_color = self.settings.gradient_color;
+    }
+
+    /// Calculates the component's state.
+    pub const fn state(&self) -> State {
+        State {
+            thickness: self.settings.thickness,
+            color: self.settings.color,
+            gradient_color: self.settings.gradient_color,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Thickness".into(),
+                "The thickness of the separator in pixels. A thickness of 0 uses the default thickness of separators.".into(),
+                u64::from(self.settings.thickness).into(),
+            ),
+            Field::new(
+                "Color".into(),
+                "The color of the separator. If not specified, the color of the separators specified by the layout is used.".into(),
+                self.settings.color.into(),
+            ),
+            Field::new(
+                "Gradient Color".into(),
+                "The color the separator fades into along its length. If not specified, the separator is drawn with a single color.".into(),
+                self.settings.gradient_color.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.thickness = value.into_uint().unwrap() as _,
+            1 => self.settings.color = value.into(),
+            2 => self.settings.gradient_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
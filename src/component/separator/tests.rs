@@ -0,0 +1,27 @@
+use super::Component;
+use crate::settings::{Color, Value};
+
+#[test]
+fn exposes_the_styling_in_the_state() {
+    let mut component = Component::new();
+
+    let state = component.state();
+    assert_eq!(state.thickness, 0);
+    assert_eq!(state.color, None);
+    assert_eq!(state.gradient_color, None);
+
+    let red = Color::rgba(1.0, 0.0, 0.0, 1.0);
+    let blue = Color::rgba(0.0, 0.0, 1.0, 1.0);
+    component.set_value(0, Value::UInt(6));
+    component.set_value(1, Value::OptionalColor(Some(red)));
+    component.set_value(2, Value::OptionalColor(Some(blue)));
+
+    let mut state = component.state();
+    assert_eq!(state.thickness, 6);
+    assert_eq!(state.color, Some(red));
+    assert_eq!(state.gradient_color, Some(blue));
+
+    component.set_value(2, Value::OptionalColor(None));
+    component.update_state(&mut state);
+    assert_eq!(state.gradient_color, None);
+}
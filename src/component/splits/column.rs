@@ -1,5 +1,5 @@
 use crate::{
-    analysis::{self, possible_time_save, split_color},
+    analysis::{self, possible_time_save, split_color_with, SplitColoring},
     comparison,
     component::splits::Settings as SplitsSettings,
     platform::prelude::*,
@@ -250,6 +250,7 @@ fn update_time_column(
         current_split,
         method,
         comparison,
+        layout_settings.split_coloring(),
     );
     let updated = update_value.is_some();
     let ((column_value, semantic_color, formatter), is_live) = update_value.unwrap_or_else(|| {
@@ -318,6 +319,7 @@ fn update_time_column(
     state.visual_color = semantic_color.visualize(layout_settings);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn time_column_update_value(
     column: &TimeColumn,
     timer: &Snapshot<'_>,
@@ -326,6 +328,7 @@ fn time_column_update_value(
     current_split: Option<usize>,
     method: TimingMethod,
     comparison: &str,
+    coloring: SplitColoring,
 ) -> Option<((Option<TimeSpan>, SemanticColor, ColumnFormatter), bool)> {
     use self::{ColumnUpdateTrigger::*, ColumnUpdateWith::*};
 
@@ -385,7 +388,16 @@ fn time_column_update_value(
             };
             (
                 value,
-                split_color(timer, delta, segment_index, true, true, comparison, method),
+                split_color_with(
+                    timer,
+                    delta,
+                    segment_index,
+                    true,
+                    true,
+                    comparison,
+                    method,
+                    coloring,
+                ),
                 formatter,
             )
         }
@@ -421,7 +433,16 @@ fn time_column_update_value(
             };
             (
                 value,
-                split_color(timer, delta, segment_index, false, true, comparison, method),
+                split_color_with(
+                    timer,
+                    delta,
+                    segment_index,
+                    false,
+                    true,
+                    comparison,
+                    method,
+                    coloring,
+                ),
                 formatter,
             )
         }
@@ -8,7 +8,8 @@
 use crate::{
     platform::prelude::*,
     settings::{
-        self, Color, Field, Gradient, ImageCache, ImageId, ListGradient, SettingsDescription, Value,
+        self, Color, Field, Font, Gradient, ImageCache, ImageId, ListGradient, SettingsDescription,
+        Value,
     },
     timing::{formatter::Accuracy, Snapshot},
     util::{Clear, ClearVec},
@@ -27,7 +28,7 @@ pub use column::{
     ColumnUpdateWith, TimeColumn, VariableColumn,
 };
 
-const SETTINGS_BEFORE_COLUMNS: usize = 15;
+const SETTINGS_BEFORE_COLUMNS: usize = 19;
 const SETTINGS_PER_TIME_COLUMN: usize = 6;
 const SETTINGS_PER_VARIABLE_COLUMN: usize = 2;
 
@@ -42,6 +43,29 @@ pub struct Component {
     settings: Settings,
     current_split_index: Option<usize>,
     scroll_offset: isize,
+    rows: Vec<Row>,
+}
+
+/// A row of the list of splits, before the scrolling window is applied.
+#[derive(Copy, Clone)]
+struct Row {
+    segment_index: usize,
+    kind: RowKind,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum RowKind {
+    /// A segment that is not part of a section.
+    Segment,
+    /// A segment that is part of the section the active attempt is currently
+    /// on.
+    Subsplit,
+    /// The header of the section the active attempt is currently on. The
+    /// segment is the one ending the section.
+    SectionHeader,
+    /// A section the active attempt is not on, collapsed into a single row
+    /// represented by the segment ending the section.
+    CollapsedSection,
 }
 
 /// The Settings for this component.
@@ -96,6 +120,25 @@ pub struct Settings {
     pub delta_drop_decimals: bool,
     /// Specifies whether to show the names of the columns above the splits.
     pub show_column_labels: bool,
+    /// Specifies whether subsplits are grouped into sections. Subsplits are the
+    /// segments whose names start with a `-`. They form a section together
+    /// with the next segment that is not a subsplit. The name of the section
+    /// is the name of that segment, or the text within the curly braces if its
+    /// name is of the form `{Section} Segment`. Only the section the active
+    /// attempt is currently on is expanded and shown with a header. All the
+    /// other sections are collapsed into a single row, showing the values of
+    /// the segment ending the section.
+    pub group_subsplits: bool,
+    /// The size of the segment icons in pixels. If 0 is specified, the icons
+    /// fill the height of the rows. The icons never exceed the height of the
+    /// rows.
+    pub icon_size: u32,
+    /// The font to use for the segment names and the column labels. If `None`
+    /// is specified, the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the columns. If `None` is specified, the times font
+    /// of the layout is used.
+    pub times_font: Option<Font>,
     /// The columns to show on the splits. These can be configured in various
     /// way to show split times, segment times, deltas and so on. The columns
     /// are defined from right to left.
@@ -117,6 +160,24 @@ pub struct SplitState {
     /// Describes if this segment is the segment the active attempt is currently
     /// on.
     pub is_current_split: bool,
+    /// Describes if this row doesn't show a segment, but is blank space that
+    /// fills up the list because there are not enough segments to fill it.
+    /// Blank space has no icon, name or columns.
+    pub is_blank_space: bool,
+    /// Describes if this row doesn't show a segment, but is the header of the
+    /// section the active attempt is currently on. Section headers only have a
+    /// name, but no icon or columns.
+    pub is_section_header: bool,
+    /// The nesting level of the row. Subsplits of the section the active
+    /// attempt is currently on are nested one level deeper than all the other
+    /// rows.
+    pub nesting_level: u32,
+    /// Describes if this row is an odd row of the list, which is meant to be
+    /// used for alternating backgrounds. The rows are counted based on all the
+    /// rows of the list, not just the ones that are shown, so the rows keep
+    /// their backgrounds while the list scrolls. Blank space continues the
+    /// count after the last row of the list.
+    pub is_odd_row: bool,
     /// The index of the segment based on all the segments of the run. This may
     /// differ from the index of this `SplitState` in the `State` object, as
     /// there can be a scrolling window, showing only a subset of segments. Each
@@ -137,12 +198,21 @@ impl Clear for SplitState {
 pub struct State {
     /// The background shown behind the splits.
     pub background: ListGradient,
+    /// Specifies whether the background alternates between two colors for
+    /// the odd and even rows.
+    pub show_alternating_backgrounds: bool,
     /// The column labels to visualize about the list of splits. If this is
     /// `None`, no labels are supposed to be visualized. The list is specified
     /// from right to left.
     pub column_labels: Option<ClearVec<String>>,
     /// The list of all the segments to visualize.
     pub splits: ClearVec<SplitState>,
+    /// The size the icons are supposed to be shown at in pixels. If this is 0,
+    /// the icons are supposed to fill the height of the rows. The icons should
+    /// never exceed the height of the rows. The icons themselves can be cached
+    /// by their [`ImageId`], as their IDs only change when their contents
+    /// change.
+    pub icon_size: u32,
     /// Specifies whether the current run has any icons, even those that are not
     /// currently visible by the splits component. This allows for properly
     /// indenting the icon column, even when the icons are scrolled outside the
@@ -160,6 +230,12 @@ pub struct State {
     /// The gradient to show behind the current segment as an indicator of it
     /// being the current segment.
     pub current_split_gradient: Gradient,
+    /// The font to use for the segment names and the column labels. If `None`
+    /// is specified, the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the columns. If `None` is specified, the times font
+    /// of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -185,6 +261,10 @@ impl Default for Settings {
             delta_time_accuracy: Accuracy::Tenths,
             delta_drop_decimals: true,
             show_column_labels: false,
+            group_subsplits: false,
+            icon_size: 0,
+            text_font: None,
+            times_font: None,
             columns: vec![
                 ColumnSettings {
                     name: String::from("Time"),
@@ -282,32 +362,40 @@ impl Component {
         }
 
         let run = timer.run();
+        let current_split = timer.current_split_index();
+        let method = timer.current_timing_method();
+
+        self.update_rows(timer);
+        let row_count = self.rows.len();
+        let current_row = current_split.map(|current_split| {
+            self.rows
+                .iter()
+                .position(|row| row.segment_index == current_split)
+                .unwrap_or(row_count)
+        });
 
         let mut visual_split_count = self.settings.visual_split_count;
         if visual_split_count == 0 {
-            visual_split_count = run.len();
+            visual_split_count = row_count;
         }
 
-        let current_split = timer.current_split_index();
-        let method = timer.current_timing_method();
-
         let locked_last_split = isize::from(self.settings.always_show_last_split);
         let skip_count = min(
-            current_split.map_or(0, |current_split| {
+            current_row.map_or(0, |current_row| {
                 max(
                     0,
-                    current_split as isize
+                    current_row as isize
                         + self.settings.split_preview_count as isize
                         + locked_last_split
                         + 1
                         - visual_split_count as isize,
                 )
             }),
-            run.len() as isize - visual_split_count as isize,
+            row_count as isize - visual_split_count as isize,
         );
         self.scroll_offset = min(
             max(self.scroll_offset, -skip_count),
-            run.len() as isize - skip_count - visual_split_count as isize,
+            row_count as isize - skip_count - visual_split_count as isize,
         );
         let skip_count = max(0, skip_count + self.scroll_offset) as usize;
         let take_count = visual_split_count - locked_last_split as usize;
@@ -315,7 +403,7 @@ impl Component {
 
         let show_final_separator = self.settings.separator_last_split
             && always_show_last_split
-            && skip_count + take_count + 1 < run.len();
+            && skip_count + take_count + 1 < row_count;
 
         let Settings {
             show_thin_separators,
@@ -326,6 +414,8 @@ impl Component {
         } = self.settings;
 
         state.background = self.settings.background;
+        state.show_alternating_backgrounds =
+            matches!(self.settings.background, ListGradient::Alternating(..));
 
         if self.settings.show_column_labels {
             let column_labels = state.column_labels.get_or_insert_with(Default::default);
@@ -338,13 +428,13 @@ impl Component {
         }
 
         state.splits.clear();
-        for (i, segment) in run
-            .segments()
+        for (row_index, row) in self
+            .rows
             .iter()
             .enumerate()
             .skip(skip_count)
             .filter(|&(i, _)| {
-                i - skip_count < take_count || (always_show_last_split && i + 1 == run.len())
+                i - skip_count < take_count || (always_show_last_split && i + 1 == row_count)
             })
         {
             let state = state.splits.push_with(|| SplitState {
@@ -352,35 +442,61 @@ impl Component {
                 name: String::new(),
                 columns: ClearVec::new(),
                 is_current_split: false,
+                is_blank_space: false,
+                is_section_header: false,
+                nesting_level: 0,
+                is_odd_row: false,
                 index: 0,
             });
 
-            let icon = segment.icon();
-            state.icon = *image_cache.cache(icon.id(), || icon.clone()).id();
+            let i = row.segment_index;
+            let segment = run.segment(i);
+            let is_section_header = row.kind == RowKind::SectionHeader;
 
-            state.name.push_str(segment.name());
+            if !is_section_header {
+                let icon = segment.icon();
+                state.icon = *image_cache.cache(icon.id(), || icon.clone()).id();
+            }
 
-            for column in columns {
-                column::update_state(
-                    state.columns.push_with(|| ColumnState {
-                        value: String::new(),
-                        semantic_color: Default::default(),
-                        visual_color: Color::transparent(),
-                        updates_frequently: false,
-                    }),
-                    column,
-                    timer,
-                    &self.settings,
-                    layout_settings,
-                    segment,
-                    i,
-                    current_split,
-                    method,
-                );
+            state.name.push_str(match row.kind {
+                RowKind::Segment => segment.name(),
+                RowKind::Subsplit => subsplit_name(segment.name()),
+                RowKind::SectionHeader | RowKind::CollapsedSection => section_name(segment.name()),
+            });
+
+            if !is_section_header {
+                for column in columns {
+                    column::update_state(
+                        state.columns.push_with(|| ColumnState {
+                            value: String::new(),
+                            semantic_color: Default::default(),
+                            visual_color: Color::transparent(),
+                            updates_frequently: false,
+                        }),
+                        column,
+                        timer,
+                        &self.settings,
+                        layout_settings,
+                        segment,
+                        i,
+                        current_split,
+                        method,
+                    );
+                }
             }
 
-            state.is_current_split = Some(i) == current_split;
-            state.index = i;
+            state.is_current_split = !is_section_header && Some(i) == current_split;
+            state.is_blank_space = false;
+            state.is_section_header = is_section_header;
+            state.nesting_level = u32::from(row.kind == RowKind::Subsplit);
+            state.is_odd_row = row_index % 2 == 1;
+            // The section headers use the odd indices from the top, so they
+            // don't collide with the segments or the blank space.
+            state.index = if is_section_header {
+                usize::MAX - 2 * i
+            } else {
+                i
+            };
         }
 
         if fill_with_blank_space && state.splits.len() < visual_split_count {
@@ -391,18 +507,77 @@ impl Component {
                     name: String::new(),
                     columns: ClearVec::new(),
                     is_current_split: false,
+                    is_blank_space: true,
+                    is_section_header: false,
+                    nesting_level: 0,
+                    is_odd_row: false,
                     index: 0,
                 });
                 state.is_current_split = false;
+                state.is_blank_space = true;
+                state.is_section_header = false;
+                state.nesting_level = 0;
+                // Blank space is only shown once the list ends, so it
+                // continues right after the last row.
+                state.is_odd_row = (row_count + i) % 2 == 1;
                 state.index = (usize::MAX ^ 1) - 2 * i;
             }
         }
 
+        state.icon_size = self.settings.icon_size;
         state.has_icons = run.segments().iter().any(|s| !s.icon().is_empty());
         state.show_thin_separators = show_thin_separators;
         state.show_final_separator = show_final_separator;
         state.display_two_rows = display_two_rows;
         state.current_split_gradient = self.settings.current_split_gradient;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
+    }
+
+    fn update_rows(&mut self, timer: &Snapshot<'_>) {
+        let segments = timer.run().segments();
+        self.rows.clear();
+
+        if !self.settings.group_subsplits {
+            self.rows
+                .extend((0..segments.len()).map(|segment_index| Row {
+                    segment_index,
+                    kind: RowKind::Segment,
+                }));
+            return;
+        }
+
+        let current_split = timer.current_split_index();
+        let mut section_start = 0;
+        for (end, segment) in segments.iter().enumerate() {
+            // The last segment always ends a section, even if it's a subsplit.
+            if segment.name().starts_with('-') && end + 1 != segments.len() {
+                continue;
+            }
+            let section = section_start..=end;
+            section_start = end + 1;
+
+            if section.start() == section.end() {
+                self.rows.push(Row {
+                    segment_index: end,
+                    kind: RowKind::Segment,
+                });
+            } else if current_split.is_some_and(|current_split| section.contains(&current_split)) {
+                self.rows.push(Row {
+                    segment_index: end,
+                    kind: RowKind::SectionHeader,
+                });
+                self.rows.extend(section.map(|segment_index| Row {
+                    segment_index,
+                    kind: RowKind::Subsplit,
+                }));
+            } else {
+                self.rows.push(Row {
+                    segment_index: end,
+                    kind: RowKind::CollapsedSection,
+                });
+            }
+        }
     }
 
     /// Calculates the component's state based on the timer and layout settings
@@ -495,6 +670,26 @@ impl Component {
                 "Specifies whether to show the names of the columns at the top of the list.".into(),
                 self.settings.show_column_labels.into(),
             ),
+            Field::new(
+                "Group Subsplits".into(),
+                "Specifies whether subsplits are grouped into sections. Subsplits are the segments whose names start with a dash. They form a section together with the next segment. Only the section you are currently on is expanded. All the other sections are collapsed into a single row. If the segment ending a section is named like \"{Section} Segment\", the text within the curly braces is used as the name of the section.".into(),
+                self.settings.group_subsplits.into(),
+            ),
+            Field::new(
+                "Icon Size".into(),
+                "The size of the segment icons in pixels. If set to 0, the icons fill the height of the rows. The icons never exceed the height of the rows.".into(),
+                u64::from(self.settings.icon_size).into(),
+            ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the segment names and the column labels. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the columns. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
             Field::new(
                 "Columns".into(),
                 "The number of columns to show in each row. Each column can be configured to show different information. The columns are defined from right to left.".into(),
@@ -600,7 +795,11 @@ impl Component {
             11 => self.settings.delta_time_accuracy = value.into(),
             12 => self.settings.delta_drop_decimals = value.into(),
             13 => self.settings.show_column_labels = value.into(),
-            14 => {
+            14 => self.settings.group_subsplits = value.into(),
+            15 => self.settings.icon_size = value.into_uint().unwrap() as _,
+            16 => self.settings.text_font = value.into(),
+            17 => self.settings.times_font = value.into(),
+            18 => {
                 let new_len = value.into_uint().unwrap() as usize;
                 self.settings.columns.resize(new_len, Default::default());
             }
@@ -652,3 +851,21 @@ impl Component {
         }
     }
 }
+
+/// Splits a segment name of the form `{Section} Segment` into the name of the
+/// section and the name of the segment.
+fn split_section_name(name: &str) -> Option<(&str, &str)> {
+    let (section, segment) = name.strip_prefix('{')?.split_once('}')?;
+    Some((section, segment.trim_start()))
+}
+
+fn section_name(name: &str) -> &str {
+    split_section_name(name).map_or(name, |(section, _)| section)
+}
+
+fn subsplit_name(name: &str) -> &str {
+    match name.strip_prefix('-') {
+        Some(name) => name,
+        None => split_section_name(name).map_or(name, |(_, segment)| segment),
+    }
+}
@@ -4,8 +4,8 @@ use super::{
 };
 use crate::{
     component::splits::{ColumnKind, TimeColumn},
-    settings::ImageCache,
-    Run, Segment, TimeSpan, Timer, TimingMethod,
+    settings::{self, ImageCache, Value},
+    Run, Segment, Time, TimeSpan, Timer, TimingMethod,
 };
 
 pub mod column;
@@ -146,3 +146,289 @@ fn unique_split_indices() {
 
     assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
 }
+
+#[test]
+fn columns_can_be_configured_through_the_settings() {
+    let mut run = Run::new();
+    let mut segment = Segment::new("A");
+    segment.set_personal_best_split_time(
+        Time::new().with_game_time(Some(TimeSpan::from_seconds(8.0))),
+    );
+    segment.variables_mut().insert("Deaths".into(), "3".into());
+    run.push_segment(segment);
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+
+    let fields = component.settings_description().fields;
+    let columns_index = fields.iter().position(|f| &*f.text == "Columns").unwrap();
+    component.set_value(columns_index, Value::UInt(2));
+
+    // The first column shows the Personal Best's game time.
+    let first_column = columns_index + 1;
+    component.set_value(first_column, Value::String("PB".into()));
+    component.set_value(first_column + 2, ColumnStartWith::ComparisonTime.into());
+    component.set_value(first_column + 3, ColumnUpdateWith::DontUpdate.into());
+    component.set_value(
+        first_column + 5,
+        Value::OptionalString(Some("Personal Best".into())),
+    );
+    component.set_value(first_column + 6, Some(TimingMethod::GameTime).into());
+
+    // The second column shows the segment's variable.
+    let second_column = first_column + 7;
+    component.set_value(second_column + 1, settings::ColumnKind::Variable.into());
+    component.set_value(second_column + 2, Value::String("Deaths".into()));
+
+    let fields = component.settings_description().fields;
+    assert_eq!(fields.len(), second_column + 3);
+    assert_eq!(&*fields[second_column + 2].text, "Variable Name");
+
+    let mut image_cache = ImageCache::new();
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    let columns = &state.splits[0].columns;
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns[0].value, "0:08");
+    assert_eq!(columns[1].value, "3");
+}
+
+#[test]
+fn marks_blank_space() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 5,
+        fill_with_blank_space: true,
+        ..Default::default()
+    });
+
+    let mut image_cache = ImageCache::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    let blank_space = state
+        .splits
+        .iter()
+        .map(|s| s.is_blank_space)
+        .collect::<Vec<_>>();
+    assert_eq!(blank_space, [false, false, false, true, true]);
+
+    component.settings_mut().fill_with_blank_space = false;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert_eq!(state.splits.len(), 3);
+    assert!(state.splits.iter().all(|s| !s.is_blank_space));
+}
+
+#[test]
+fn alternates_rows_consistently() {
+    let mut run = Run::new();
+    for name in ["A", "B", "C", "D"] {
+        run.push_segment(Segment::new(name));
+    }
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 2,
+        always_show_last_split: false,
+        ..Default::default()
+    });
+
+    let mut image_cache = ImageCache::new();
+    let odd_rows = |component: &mut Component, image_cache: &mut ImageCache| {
+        let state = component.state(image_cache, &timer.snapshot(), &Default::default());
+        assert!(state.show_alternating_backgrounds);
+        state
+            .splits
+            .iter()
+            .map(|s| (s.name.clone(), s.is_odd_row))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        odd_rows(&mut component, &mut image_cache),
+        [("A".into(), false), ("B".into(), true)],
+    );
+
+    component.scroll_down();
+    assert_eq!(
+        odd_rows(&mut component, &mut image_cache),
+        [("B".into(), true), ("C".into(), false)],
+    );
+
+    component.settings_mut().visual_split_count = 7;
+    component.settings_mut().fill_with_blank_space = true;
+    let rows = odd_rows(&mut component, &mut image_cache);
+    let odd_rows = rows.iter().map(|&(_, odd)| odd).collect::<Vec<_>>();
+    assert_eq!(odd_rows, [false, true, false, true, false, true, false]);
+
+    component.settings_mut().background = settings::ListGradient::Same(Default::default());
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert!(!state.show_alternating_backgrounds);
+}
+
+#[test]
+fn alternates_rows_of_collapsed_sections_and_blank_space() {
+    let mut run = Run::new();
+    for name in ["-A1", "{A} A2", "B", "-C1", "-C2", "{C} C3"] {
+        run.push_segment(Segment::new(name));
+    }
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 5,
+        fill_with_blank_space: true,
+        ..Default::default()
+    });
+    let fields = component.settings_description().fields;
+    let group_subsplits = fields
+        .iter()
+        .position(|f| &*f.text == "Group Subsplits")
+        .unwrap();
+    component.set_value(group_subsplits, Value::Bool(true));
+
+    let state = component.state(
+        &mut ImageCache::new(),
+        &timer.snapshot(),
+        &Default::default(),
+    );
+    let rows = state
+        .splits
+        .iter()
+        .map(|s| (s.is_blank_space, s.is_odd_row))
+        .collect::<Vec<_>>();
+
+    // The collapsed sections alternate based on their rows rather than the
+    // indices of their segments, and the blank space continues after them.
+    assert_eq!(
+        rows,
+        [
+            (false, false),
+            (false, true),
+            (false, false),
+            (true, true),
+            (true, false),
+        ],
+    );
+}
+
+#[test]
+fn groups_subsplits_into_sections() {
+    let mut run = Run::new();
+    for name in ["-A1", "-A2", "{A} A3", "B", "-C1", "{C} C2"] {
+        run.push_segment(Segment::new(name));
+    }
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        ..Default::default()
+    });
+    let fields = component.settings_description().fields;
+    let group_subsplits = fields
+        .iter()
+        .position(|f| &*f.text == "Group Subsplits")
+        .unwrap();
+    component.set_value(group_subsplits, Value::Bool(true));
+
+    let mut image_cache = ImageCache::new();
+    let rows = |component: &mut Component, timer: &Timer, image_cache: &mut ImageCache| {
+        let state = component.state(image_cache, &timer.snapshot(), &Default::default());
+        state
+            .splits
+            .iter()
+            .map(|s| {
+                (
+                    s.name.clone(),
+                    s.is_section_header,
+                    s.nesting_level,
+                    s.is_current_split,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Before the attempt, all the sections are collapsed.
+    assert_eq!(
+        rows(&mut component, &timer, &mut image_cache),
+        [
+            ("A".into(), false, 0, false),
+            ("B".into(), false, 0, false),
+            ("C".into(), false, 0, false),
+        ],
+    );
+
+    timer.start().unwrap();
+    timer.split().unwrap();
+
+    // The current section is expanded with a header.
+    assert_eq!(
+        rows(&mut component, &timer, &mut image_cache),
+        [
+            ("A".into(), true, 0, false),
+            ("A1".into(), false, 1, false),
+            ("A2".into(), false, 1, true),
+            ("A3".into(), false, 1, false),
+            ("B".into(), false, 0, false),
+            ("C".into(), false, 0, false),
+        ],
+    );
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    assert!(state.splits[0].columns.is_empty());
+    let mut indices = state.splits.iter().map(|s| s.index).collect::<Vec<_>>();
+    indices.sort_unstable();
+    assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
+
+    timer.split().unwrap();
+    timer.split().unwrap();
+    timer.split().unwrap();
+
+    assert_eq!(
+        rows(&mut component, &timer, &mut image_cache),
+        [
+            ("A".into(), false, 0, false),
+            ("B".into(), false, 0, false),
+            ("C".into(), true, 0, false),
+            ("C1".into(), false, 1, true),
+            ("C2".into(), false, 1, false),
+        ],
+    );
+}
+
+#[test]
+fn scrolls_through_the_sections() {
+    let mut run = Run::new();
+    for name in ["-A1", "-A2", "A", "-B1", "-B2", "B", "C"] {
+        run.push_segment(Segment::new(name));
+    }
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 4,
+        split_preview_count: 0,
+        always_show_last_split: true,
+        group_subsplits: true,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    timer.start().unwrap();
+    for _ in 0..4 {
+        timer.split().unwrap();
+    }
+
+    // The window scrolls based on the rows rather than the segments, so the
+    // current subsplit is shown right above the last split.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &Default::default());
+    let names = state
+        .splits
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["B", "B1", "B2", "C"]);
+    assert!(state.splits[2].is_current_split);
+    assert!(state.show_final_separator);
+}
@@ -9,15 +9,20 @@
 
 use super::key_value;
 use crate::{
-    analysis::sum_of_segments::calculate_best,
+    analysis::sum_of_segments::{calculate_best, calculate_live_best},
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SettingsDescription, Value},
-    timing::formatter::{Accuracy, Regular, TimeFormatter},
-    Timer,
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, Regular, TimeFormatter},
+        Snapshot,
+    },
 };
 use core::fmt::Write;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// The Sum of Best Segments Component shows the fastest possible time to
 /// complete a run of this category, based on information collected from all the
 /// previous attempts. This often matches up with the sum of the best segment
@@ -39,6 +44,10 @@ pub struct Settings {
     /// Specifies whether to display the name of the component and its value in
     /// two separate rows.
     pub display_two_rows: bool,
+    /// Specifies whether the segment that is currently running is taken into
+    /// account. If splitting right now would result in a new best segment, the
+    /// Sum of Best Segments is shown as if the split already happened.
+    pub live_sum_of_best: bool,
     /// The color of the label. If `None` is specified, the color is taken from
     /// the layout.
     pub label_color: Option<Color>,
@@ -47,6 +56,12 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -54,9 +69,12 @@ impl Default for Settings {
         Self {
             background: key_value::DEFAULT_GRADIENT,
             display_two_rows: false,
+            live_sum_of_best: false,
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Seconds,
+            text_font: None,
+            times_font: None,
         }
     }
 }
@@ -87,18 +105,25 @@ impl Component {
         "Sum of Best Segments"
     }
 
-    /// Updates the component's state based on the timer provided.
-    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
-        let time = calculate_best(
-            timer.run().segments(),
-            false,
-            true,
-            timer.current_timing_method(),
-        );
+    /// Updates the component's state based on the snapshot of the timer
+    /// provided. A snapshot is needed, so the live sum of best segments is
+    /// based on the same current time as the rest of the layout.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
+        let method = timer.current_timing_method();
+        let (time, updates_frequently) = if self.settings.live_sum_of_best {
+            calculate_live_best(timer, false, method)
+        } else {
+            (
+                calculate_best(timer.run().segments(), false, true, method),
+                false,
+            )
+        };
 
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
         state.value_color = self.settings.value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
         state.semantic_color = Default::default();
 
         state.key.clear();
@@ -115,12 +140,15 @@ impl Component {
         state.key_abbreviations.push("Sum of Best".into());
         state.key_abbreviations.push("SoB".into());
 
+        state.additional_rows.clear();
+
         state.display_two_rows = self.settings.display_two_rows;
-        state.updates_frequently = false;
+        state.updates_frequently = updates_frequently;
     }
 
-    /// Calculates the component's state based on the timer provided.
-    pub fn state(&self, timer: &Timer) -> key_value::State {
+    /// Calculates the component's state based on the snapshot of the timer
+    /// provided.
+    pub fn state(&self, timer: &Snapshot<'_>) -> key_value::State {
         let mut state = Default::default();
         self.update_state(&mut state, timer);
         state
@@ -140,6 +168,11 @@ impl Component {
                 "Specifies whether to display the name of the component and the sum of best segments in two separate rows.".into(),
                 self.settings.display_two_rows.into(),
             ),
+            Field::new(
+                "Live Sum of Best Segments".into(),
+                "Specifies whether the segment that is currently running is taken into account. If splitting right now would result in a new best segment, the sum of best segments is shown as if the split already happened.".into(),
+                self.settings.live_sum_of_best.into(),
+            ),
             Field::new(
                 "Label Color".into(),
                 "The color of the component's name. If not specified, the color is taken from the layout.".into(),
@@ -155,6 +188,16 @@ impl Component {
                 "The accuracy of the sum of best segments shown.".into(),
                 self.settings.accuracy.into(),
             ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
         ])
     }
 
@@ -169,9 +212,12 @@ impl Component {
         match index {
             0 => self.settings.background = value.into(),
             1 => self.settings.display_two_rows = value.into(),
-            2 => self.settings.label_color = value.into(),
-            3 => self.settings.value_color = value.into(),
-            4 => self.settings.accuracy = value.into(),
+            2 => self.settings.live_sum_of_best = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.accuracy = value.into(),
+            6 => self.settings.text_font = value.into(),
+            7 => self.settings.times_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -0,0 +1,52 @@
+use super::Component;
+use crate::{
+    settings::Value,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    TimeSpan, Timer,
+};
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[8.0, 22.0, 31.0]);
+    timer
+}
+
+#[test]
+fn takes_golds_into_account_as_soon_as_they_happen() {
+    let component = Component::new();
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "0:27");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0)]);
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "0:24");
+}
+
+#[test]
+fn live_sum_of_best_includes_the_current_segment() {
+    let mut component = Component::new();
+    component.set_value(2, Value::Bool(true));
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "0:24");
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "0:21");
+
+    // Running slower than the best segment doesn't make the sum of best worse.
+    timer.set_game_time(TimeSpan::from_seconds(20.0)).unwrap();
+    let state = component.state(&timer.snapshot());
+    assert_eq!(&*state.value, "0:24");
+}
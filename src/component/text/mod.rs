@@ -1,18 +1,19 @@
 //! Provides the Text Component and relevant types for using it. The Text
 //! Component simply visualizes any given text. This can either be a single
 //! centered text, or split up into a left and right text, which is suitable for
-//! a situation where you have a label and a value.
+//! a situation where you have a label and a value. The text may contain
+//! placeholders that are replaced with information about the run.
 
 use super::key_value;
 use crate::{
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
     timing::formatter,
     util::PopulateString,
-    Timer,
+    Run, Timer,
 };
 use alloc::borrow::Cow;
-use core::mem;
+use core::{fmt::Write, mem};
 use serde_derive::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -45,9 +46,28 @@ pub struct Settings {
     pub right_color: Option<Color>,
     /// The text to be shown.
     pub text: Text,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the right part of the split up text. If `None` is
+    /// specified, the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 /// The text that is supposed to be shown.
+///
+/// Both the centered and the split up text may contain placeholders that are
+/// replaced with information about the run when the state is calculated:
+///
+/// - `{game}`: The name of the game.
+/// - `{category}`: The name of the category.
+/// - `{attempts}`: The amount of attempts.
+/// - `{platform}`: The name of the platform the game is played on.
+/// - `{region}`: The name of the region of the game.
+/// - `{variable:<name>}`: The value of the custom variable or speedrun.com
+///   variable with the name specified.
+///
+/// Placeholders that aren't known are shown as they are.
 #[derive(Clone, Serialize, Deserialize)]
 pub enum Text {
     /// A single centered text.
@@ -136,6 +156,13 @@ pub struct State {
     pub right_color: Option<Color>,
     /// The text to show for the component.
     pub text: TextState,
+    /// The font to use for the left part of the split up text or the whole text
+    /// if it's not split up. If `None` is specified, the text font of the
+    /// layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the right part of the split up text. If `None` is
+    /// specified, the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -146,6 +173,8 @@ impl Default for Settings {
             left_center_color: None,
             right_color: None,
             text: Text::Center(String::from("")),
+            text_font: None,
+            times_font: None,
         }
     }
 }
@@ -211,13 +240,15 @@ impl Component {
         state.display_two_rows = self.settings.text.is_split() && self.settings.display_two_rows;
         state.left_center_color = self.settings.left_center_color;
         state.right_color = self.settings.right_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
 
-        let (left_center, right) = match &self.settings.text {
-            Text::Center(center) => (center.as_str(), None),
-            Text::Split(left, right) => (left.as_str(), Some(right.as_str())),
+        let run = timer.run();
+        let (left_center, right, is_variable) = match &self.settings.text {
+            Text::Center(center) => (center.as_str(), None, false),
+            Text::Split(left, right) => (left.as_str(), Some(right.as_str()), false),
             Text::Variable(var_name, is_split) => {
-                let value = timer
-                    .run()
+                let value = run
                     .metadata()
                     .custom_variable(var_name)
                     .map(|var| var.value.as_str())
@@ -225,38 +256,42 @@ impl Component {
                     .unwrap_or(formatter::DASH);
 
                 if *is_split {
-                    (var_name.as_str(), Some(value))
+                    (var_name.as_str(), Some(value), true)
                 } else {
-                    (value, None)
+                    (value, None, true)
                 }
             }
         };
 
         // FIXME: We may not want to keep using an enum for this. This is really
         // painful to deal with, and we still don't reuse memory in every case.
-        match (&mut state.text, left_center, right) {
-            (TextState::Center(old), new, None) => {
-                old.clear();
-                old.push_str(new);
+        match (&mut state.text, right) {
+            (TextState::Center(center), Some(_)) => {
+                state.text = TextState::Split(mem::take(center), String::new());
             }
-            (TextState::Center(old), new_l, Some(new_r)) => {
-                let mut new_l_mem = mem::take(old);
-                new_l_mem.clear();
-                new_l_mem.push_str(new_l);
-                state.text = TextState::Split(new_l_mem, new_r.to_owned());
+            (TextState::Split(left, _), None) => {
+                state.text = TextState::Center(mem::take(left));
             }
-            (TextState::Split(l, r), new_l, Some(new_r)) => {
-                l.clear();
-                l.push_str(new_l);
-                r.clear();
-                r.push_str(new_r);
-            }
-            (TextState::Split(l, _), new_center, None) => {
-                let mut new_center_mem = mem::take(l);
-                new_center_mem.clear();
-                new_center_mem.push_str(new_center);
-                state.text = TextState::Center(new_center_mem);
+            _ => {}
+        }
+
+        let (left_center_buf, right_buf) = match &mut state.text {
+            TextState::Center(center) => (center, None),
+            TextState::Split(left, right) => (left, Some(right)),
+        };
+
+        let populate = |buf: &mut String, text: &str| {
+            buf.clear();
+            if is_variable {
+                buf.push_str(text);
+            } else {
+                interpolate(buf, text, run);
             }
+        };
+
+        populate(left_center_buf, left_center);
+        if let (Some(buf), Some(right)) = (right_buf, right) {
+            populate(buf, right);
         }
     }
 
@@ -281,7 +316,7 @@ impl Component {
             Text::Center(text) => (
                 Field::new(
                     "Text".into(),
-                    "Specifies the text to display in the center.".into(),
+                    "Specifies the text to display in the center. Placeholders like {game}, {category}, {attempts} or {variable:<name>} are replaced with information about the run.".into(),
                     text.to_string().into(),
                 ),
                 None,
@@ -293,12 +328,12 @@ impl Component {
             Text::Split(left, right) => (
                 Field::new(
                     "Left".into(),
-                    "Specifies the text to display on the left.".into(),
+                    "Specifies the text to display on the left. Placeholders like {game}, {category}, {attempts} or {variable:<name>} are replaced with information about the run.".into(),
                     left.to_string().into(),
                 ),
                 Some(Field::new(
                     "Right".into(),
-                    "Specifies the text to display on the right.".into(),
+                    "Specifies the text to display on the right. Placeholders like {game}, {category}, {attempts} or {variable:<name>} are replaced with information about the run.".into(),
                     right.to_string().into(),
                 )),
                 false,
@@ -365,6 +400,20 @@ impl Component {
             ));
         }
 
+        fields.push(Field::new(
+            "Custom Text Font".into(),
+            "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+            self.settings.text_font.clone().into(),
+        ));
+
+        if is_split {
+            fields.push(Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the text on the right. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ));
+        }
+
         SettingsDescription::with_fields(fields)
     }
 
@@ -376,6 +425,21 @@ impl Component {
     /// the type of the setting's value. A panic can also occur if the index of
     /// the setting provided is out of bounds.
     pub fn set_value(&mut self, mut index: usize, value: Value) {
+        // The fonts are always the last settings.
+        let font_index = match &self.settings.text {
+            Text::Center(_) | Text::Variable(_, false) => 5,
+            Text::Split(_, _) => 8,
+            Text::Variable(_, true) => 7,
+        };
+        if index >= font_index {
+            match index - font_index {
+                0 => self.settings.text_font = value.into(),
+                1 => self.settings.times_font = value.into(),
+                _ => panic!("Unsupported Setting Index"),
+            }
+            return;
+        }
+
         if index >= 5 {
             if let Text::Variable(_, _) = &self.settings.text {
                 index += 1;
@@ -439,3 +503,59 @@ impl Component {
         }
     }
 }
+
+/// Writes the text into the buffer, replacing all the placeholders with the
+/// information about the run they refer to.
+fn interpolate(buf: &mut String, text: &str, run: &Run) {
+    let mut rem = text;
+    while let Some(start) = rem.find('{') {
+        buf.push_str(&rem[..start]);
+        rem = &rem[start..];
+
+        let Some(end) = rem.find('}') else {
+            break;
+        };
+        let placeholder = &rem[1..end];
+        if placeholder.contains('{') {
+            buf.push('{');
+            rem = &rem[1..];
+            continue;
+        }
+
+        if !resolve_placeholder(buf, placeholder, run) {
+            buf.push_str(&rem[..=end]);
+        }
+        rem = &rem[end + 1..];
+    }
+    buf.push_str(rem);
+}
+
+fn resolve_placeholder(buf: &mut String, placeholder: &str, run: &Run) -> bool {
+    let metadata = run.metadata();
+    match placeholder {
+        "game" => buf.push_str(run.game_name()),
+        "category" => buf.push_str(run.category_name()),
+        "attempts" => {
+            let _ = write!(buf, "{}", run.attempt_count());
+        }
+        "platform" => buf.push_str(metadata.platform_name()),
+        "region" => buf.push_str(metadata.region_name()),
+        _ => {
+            let Some(name) = placeholder.strip_prefix("variable:") else {
+                return false;
+            };
+            let value = metadata
+                .custom_variable_value(name)
+                .or_else(|| {
+                    metadata
+                        .speedrun_com_variables()
+                        .find(|(var_name, _)| *var_name == name)
+                        .map(|(_, value)| value.as_str())
+                })
+                .filter(|value| !value.trim_start().is_empty())
+                .unwrap_or(formatter::DASH);
+            buf.push_str(value);
+        }
+    }
+    true
+}
@@ -1,5 +1,10 @@
 use super::{Component, Text, TextState};
-use crate::{timing::formatter, util::tests_helper::create_run, Timer};
+use crate::{
+    settings::{Font, Value},
+    timing::formatter,
+    util::tests_helper::create_run,
+    Timer,
+};
 
 #[test]
 fn resolves_variables() {
@@ -73,3 +78,81 @@ fn uses_dash_for_whitespace_variable() {
         TextState::Split(String::from("Goal Time"), String::from(formatter::DASH))
     );
 }
+
+#[test]
+fn uses_custom_fonts() {
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+    let font = Font {
+        family: String::from("Comic Sans MS"),
+        ..Default::default()
+    };
+
+    let mut component = Component::new();
+    let fields = component.settings_description().fields;
+    assert_eq!(fields.last().unwrap().text, "Custom Text Font");
+    component.set_value(fields.len() - 1, Value::Font(Some(font.clone())));
+
+    let state = component.state(&timer);
+    assert_eq!(state.text_font, Some(font.clone()));
+    assert_eq!(state.times_font, None);
+
+    component.settings_mut().text = Text::Split(String::from("A"), String::from("B"));
+    let fields = component.settings_description().fields;
+    assert_eq!(fields.last().unwrap().text, "Custom Times Font");
+    component.set_value(fields.len() - 1, Value::Font(Some(font.clone())));
+
+    let state = component.state(&timer);
+    assert_eq!(state.text_font, Some(font.clone()));
+    assert_eq!(state.times_font, Some(font));
+}
+
+#[test]
+fn interpolates_placeholders() {
+    let mut run = create_run(&["A"]);
+    run.set_game_name("Super Mario Odyssey");
+    run.set_category_name("Any%");
+    run.set_attempt_count(42);
+    run.metadata_mut().set_platform_name("Nintendo Switch");
+    run.metadata_mut().custom_variable_mut("Goal Time").value = String::from("1:00:00");
+    run.metadata_mut()
+        .set_speedrun_com_variable("Glitch", "Unrestricted");
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().text = Text::Center(String::from(
+        "{game} - {category} ({variable:Glitch}) on {platform}",
+    ));
+    assert_eq!(
+        component.state(&timer).text,
+        TextState::Center(String::from(
+            "Super Mario Odyssey - Any% (Unrestricted) on Nintendo Switch"
+        ))
+    );
+
+    component.settings_mut().text = Text::Split(
+        String::from("Attempts of {game}"),
+        String::from("{attempts}, Goal {variable:Goal Time}"),
+    );
+    assert_eq!(
+        component.state(&timer).text,
+        TextState::Split(
+            String::from("Attempts of Super Mario Odyssey"),
+            String::from("42, Goal 1:00:00")
+        )
+    );
+}
+
+#[test]
+fn keeps_unknown_placeholders() {
+    let mut run = create_run(&["A"]);
+    run.set_game_name("Game");
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().text =
+        Text::Center(String::from("{unknown} {{game}} {variable:Missing} {game"));
+    assert_eq!(
+        component.state(&timer).text,
+        TextState::Center(format!("{{unknown}} {{Game}} {} {{game", formatter::DASH))
+    );
+}
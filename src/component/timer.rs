@@ -4,9 +4,9 @@
 //! current attempt is doing compared to the chosen comparison.
 
 use crate::{
-    analysis::split_color,
+    analysis::split_color_with,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    settings::{Color, Field, Font, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
         formatter::{timer as formatter, Accuracy, DigitsFormat, TimeFormatter},
         Snapshot,
@@ -107,6 +107,9 @@ pub struct Settings {
     /// current segment, rather than how much time has passed since the start of
     /// the current attempt.
     pub is_segment_timer: bool,
+    /// The font to use for the timer of the component. If `None` is specified,
+    /// the timer font of the layout is used.
+    pub timer_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -120,6 +123,7 @@ impl Default for Settings {
             digits_format: DigitsFormat::SingleDigitSeconds,
             accuracy: Accuracy::Hundredths,
             is_segment_timer: false,
+            timer_font: None,
         }
     }
 }
@@ -144,6 +148,9 @@ pub struct State {
     /// This value indicates whether the timer is currently frequently being
     /// updated. This can be used for rendering optimizations.
     pub updates_frequently: bool,
+    /// The font to use for the timer. If `None` is specified, the timer font of
+    /// the layout is used.
+    pub timer_font: Option<Font>,
 }
 
 #[cfg(feature = "std")]
@@ -229,7 +236,7 @@ impl Component {
                         .comparison(current_comparison)[method];
 
                     if let Some(pb_split_time) = pb_split_time {
-                        split_color(
+                        split_color_with(
                             timer,
                             Some(time - pb_split_time),
                             timer.current_split_index().unwrap(),
@@ -237,6 +244,7 @@ impl Component {
                             false,
                             current_comparison,
                             method,
+                            layout_settings.split_coloring(),
                         )
                         .or(SemanticColor::AheadGainingTime)
                     } else {
@@ -299,6 +307,7 @@ impl Component {
         state.updates_frequently = phase.updates_frequently(method) && time.is_some();
         state.semantic_color = semantic_color;
         state.height = self.settings.height;
+        state.timer_font.clone_from(&self.settings.timer_font);
     }
 
     /// Calculates the component's state based on the timer and the layout
@@ -353,6 +362,11 @@ impl Component {
                 "The accuracy of the time shown.".into(),
                 self.settings.accuracy.into(),
             ),
+            Field::new(
+                "Custom Timer Font".into(),
+                "Allows you to specify a custom font for the timer of this component. If this is not set, the timer font of the layout is used.".into(),
+                self.settings.timer_font.clone().into(),
+            ),
         ])
     }
 
@@ -373,6 +387,7 @@ impl Component {
             5 => self.settings.show_gradient = value.into(),
             6 => self.settings.digits_format = value.into(),
             7 => self.settings.accuracy = value.into(),
+            8 => self.settings.timer_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -6,7 +6,8 @@
 use crate::{
     platform::prelude::*,
     settings::{
-        Alignment, Color, Field, Gradient, Image, ImageCache, ImageId, SettingsDescription, Value,
+        Alignment, Color, Field, Font, Gradient, Image, ImageCache, ImageId, SettingsDescription,
+        Value,
     },
     Timer, TimerPhase,
 };
@@ -66,6 +67,12 @@ pub struct Settings {
     /// The category name can be extended by additional information. This
     /// extends it by additional variables provided by the run's metadata.
     pub show_variables: bool,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 /// The state object describes the information to visualize for this component.
@@ -100,6 +107,12 @@ pub struct State {
     /// The amount of total attempts. If `None` is specified, the amount of
     /// total attempts isn't supposed to be shown.
     pub attempts: Option<u32>,
+    /// The font to use for the title. If `None` is specified, the text font of
+    /// the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the attempt counts. If `None` is specified, the
+    /// times font of the layout is used.
+    pub times_font: Option<Font>,
 }
 
 impl Default for Settings {
@@ -120,6 +133,8 @@ impl Default for Settings {
             show_region: false,
             show_platform: false,
             show_variables: true,
+            text_font: None,
+            times_font: None,
         }
     }
 }
@@ -334,6 +349,8 @@ impl Component {
 
         state.background = self.settings.background;
         state.text_color = self.settings.text_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
         state.finished_runs = finished_runs;
         state.attempts = attempts;
         state.is_centered = is_centered;
@@ -423,6 +440,16 @@ impl Component {
                     .into(),
                 self.settings.show_variables.into(),
             ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
         ])
     }
 
@@ -447,6 +474,8 @@ impl Component {
             9 => self.settings.show_region = value.into(),
             10 => self.settings.show_platform = value.into(),
             11 => self.settings.show_variables = value.into(),
+            12 => self.settings.text_font = value.into(),
+            13 => self.settings.times_font = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -1,5 +1,8 @@
 use super::{Component, Settings};
-use crate::{settings::ImageCache, Run, Segment, Timer};
+use crate::{
+    settings::{Alignment, ImageCache},
+    Run, Segment, Timer,
+};
 
 #[test]
 fn finished_runs_and_attempt_count() {
@@ -42,3 +45,71 @@ fn finished_runs_and_attempt_count() {
     );
     assert_eq!(component.state(&mut image_cache, &timer).attempts, Some(1));
 }
+
+#[test]
+fn extends_the_category_name() {
+    let mut run = Run::new();
+    run.set_game_name("Super Mario Odyssey");
+    run.set_category_name("Any%");
+    run.metadata_mut().set_region_name("JPN");
+    run.metadata_mut().set_platform_name("Nintendo Switch");
+    run.metadata_mut()
+        .set_speedrun_com_variable("Difficulty", "Hard");
+    run.push_segment(Segment::new(""));
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::with_settings(Settings {
+        show_variables: false,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    let line2 = |component: &Component, image_cache: &mut ImageCache| {
+        component
+            .state(image_cache, &timer)
+            .line2
+            .last()
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(line2(&component, &mut image_cache), "Any%");
+
+    component.settings_mut().show_region = true;
+    assert_eq!(line2(&component, &mut image_cache), "Any% (JPN)");
+
+    component.settings_mut().show_region = false;
+    component.settings_mut().show_platform = true;
+    assert_eq!(
+        line2(&component, &mut image_cache),
+        "Any% (Nintendo Switch)"
+    );
+
+    component.settings_mut().show_platform = false;
+    component.settings_mut().show_variables = true;
+    assert_eq!(line2(&component, &mut image_cache), "Any% (Hard)");
+}
+
+#[test]
+fn single_line_and_alignment() {
+    let mut run = Run::new();
+    run.set_game_name("Celeste");
+    run.set_category_name("Any%");
+    run.push_segment(Segment::new(""));
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    let mut image_cache = ImageCache::new();
+
+    let state = component.state(&mut image_cache, &timer);
+    assert_eq!(state.line1.last().map(|l| &**l), Some("Celeste"));
+    assert_eq!(state.line2.last().map(|l| &**l), Some("Any%"));
+    assert!(state.is_centered);
+
+    component.settings_mut().display_as_single_line = true;
+    component.settings_mut().text_alignment = Alignment::Left;
+    let state = component.state(&mut image_cache, &timer);
+    assert_eq!(state.line1.last().map(|l| &**l), Some("Celeste - Any%"));
+    assert!(state.line2.is_empty());
+    assert!(!state.is_centered);
+}
@@ -0,0 +1,270 @@
+//! Provides the World Record Component and relevant types for using it. The
+//! World Record Component shows the time of the world record of the game and
+//! category of the run, and optionally the runners who achieved it.
+//!
+//! The component doesn't fetch the world record on its own. Instead the world
+//! record is provided to the component via
+//! [`set_world_record`](Component::set_world_record). The `speedrun_com`
+//! module of the `networking` module provides the helpers for fetching and
+//! caching the world record from the leaderboards of speedrun.com.
+//! Alternatively the world record can be specified manually in the settings
+//! of the component, which takes precedence over the fetched world record.
+
+use super::key_value;
+use crate::{
+    platform::prelude::*,
+    run::WorldRecord,
+    settings::{Color, Field, Font, Gradient, SettingsDescription, Value},
+    timing::formatter::{Accuracy, Regular, TimeFormatter},
+    TimeSpan, Timer,
+};
+use core::fmt::Write;
+use serde_derive::{Deserialize, Serialize};
+
+/// The World Record Component shows the time of the world record of the game
+/// and category of the run, and optionally the runners who achieved it.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+    world_record: Option<WorldRecord>,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+    /// Specifies whether to show the runners who achieved the world record.
+    pub show_runners: bool,
+    /// The time of the world record to show instead of the one that got
+    /// fetched. If `None` is specified, the fetched world record is shown.
+    pub manual_time: Option<TimeSpan>,
+    /// The runner of the world record to show if the time of the world record
+    /// is specified manually.
+    pub manual_runner: String,
+    /// The font to use for the text of the component. If `None` is specified,
+    /// the text font of the layout is used.
+    pub text_font: Option<Font>,
+    /// The font to use for the times of the component. If `None` is specified,
+    /// the times font of the layout is used.
+    pub times_font: Option<Font>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Hundredths,
+            show_runners: true,
+            manual_time: None,
+            manual_runner: String::new(),
+            text_font: None,
+            times_font: None,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new World Record Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new World Record Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            world_record: None,
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the world record that got provided to the component.
+    pub const fn world_record(&self) -> Option<&WorldRecord> {
+        self.world_record.as_ref()
+    }
+
+    /// Provides the world record of the game and category of the run to the
+    /// component. `None` means that there is no world record, for example
+    /// because the leaderboard is empty or couldn't be found.
+    pub fn set_world_record(&mut self, world_record: Option<WorldRecord>) {
+        self.world_record = world_record;
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "World Record"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
+        let (time, manual_runner, runners) = if let Some(time) = self.settings.manual_time {
+            let runner = Some(self.settings.manual_runner.trim()).filter(|r| !r.is_empty());
+            (Some(time), runner, &[][..])
+        } else if let Some(world_record) = &self.world_record {
+            (
+                world_record.time[timer.current_timing_method()],
+                None,
+                &*world_record.runners,
+            )
+        } else {
+            (None, None, &[][..])
+        };
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.text_font.clone_from(&self.settings.text_font);
+        state.times_font.clone_from(&self.settings.times_font);
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str("World Record");
+
+        state.value.clear();
+        let _ = write!(
+            state.value,
+            "{}",
+            Regular::with_accuracy(self.settings.accuracy).format(time),
+        );
+
+        if self.settings.show_runners && time.is_some() {
+            let mut runners = manual_runner
+                .into_iter()
+                .chain(runners.iter().map(String::as_str));
+            if let Some(first) = runners.next() {
+                let _ = write!(state.value, " by {first}");
+                for runner in runners {
+                    let _ = write!(state.value, ", {runner}");
+                }
+            }
+        }
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push("WR".into());
+
+        state.additional_rows.clear();
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = false;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background".into(),
+                "The background shown behind the component.".into(),
+                self.settings.background.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                "Specifies whether to display the name of the component and the world record in two separate rows.".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Label Color".into(),
+                "The color of the component's name. If not specified, the color is taken from the layout.".into(),
+                self.settings.label_color.into(),
+            ),
+            Field::new(
+                "Value Color".into(),
+                "The color of the world record. If not specified, the color is taken from the layout.".into(),
+                self.settings.value_color.into(),
+            ),
+            Field::new(
+                "Accuracy".into(),
+                "The accuracy of the world record shown.".into(),
+                self.settings.accuracy.into(),
+            ),
+            Field::new(
+                "Show Runners".into(),
+                "Specifies whether to show the runners who achieved the world record.".into(),
+                self.settings.show_runners.into(),
+            ),
+            Field::new(
+                "Manual World Record".into(),
+                "Allows you to specify the time of the world record yourself instead of fetching it from the leaderboards. If this is not set, the fetched world record is shown.".into(),
+                self.settings
+                    .manual_time
+                    .map(|time| Regular::with_accuracy(Accuracy::Hundredths).format(time).to_string())
+                    .into(),
+            ),
+            Field::new(
+                "Manual Runner".into(),
+                "The runner of the world record to show if the time of the world record is specified manually.".into(),
+                self.settings.manual_runner.clone().into(),
+            ),
+            Field::new(
+                "Custom Text Font".into(),
+                "Allows you to specify a custom font for the text of this component. If this is not set, the text font of the layout is used.".into(),
+                self.settings.text_font.clone().into(),
+            ),
+            Field::new(
+                "Custom Times Font".into(),
+                "Allows you to specify a custom font for the times of this component. If this is not set, the times font of the layout is used.".into(),
+                self.settings.times_font.clone().into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value. A manually
+    /// specified world record that can't be parsed as a time is removed.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.accuracy = value.into(),
+            5 => self.settings.show_runners = value.into(),
+            6 => {
+                let time: Option<String> = value.into();
+                self.settings.manual_time =
+                    time.and_then(|time| TimeSpan::parse_opt(&time).ok().flatten());
+            }
+            7 => self.settings.manual_runner = value.into(),
+            8 => self.settings.text_font = value.into(),
+            9 => self.settings.times_font = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
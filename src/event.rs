@@ -59,6 +59,16 @@ pub enum Event {
     LoadingTimesSet = 16,
     /// A custom variable has been set.
     CustomVariableSet = 17,
+    /// The timer has been armed to start at a specific point in time.
+    StartScheduled = 18,
+    /// The scheduled start of the timer has been cancelled.
+    ScheduledStartCancelled = 19,
+    /// The game started loading and the game time has been paused.
+    LoadingStarted = 20,
+    /// The game stopped loading and the game time has been resumed.
+    LoadingEnded = 21,
+    /// The time of the last split has been adjusted.
+    SplitAdjusted = 22,
     /// An unknown event occurred.
     #[serde(other)]
     Unknown,
@@ -85,6 +95,11 @@ impl From<u32> for Event {
             15 => Event::GameTimeResumed,
             16 => Event::LoadingTimesSet,
             17 => Event::CustomVariableSet,
+            18 => Event::StartScheduled,
+            19 => Event::ScheduledStartCancelled,
+            20 => Event::LoadingStarted,
+            21 => Event::LoadingEnded,
+            22 => Event::SplitAdjusted,
             _ => Event::Unknown,
         }
     }
@@ -139,6 +154,20 @@ pub enum Error {
     TimerPaused = 15,
     /// The runner decided to not reset the run.
     RunnerDecidedAgainstReset = 16,
+    /// There is no scheduled start.
+    NoStartScheduled = 17,
+    /// The split happened too soon after the previous split or the start of
+    /// the attempt and got rejected.
+    SplitTooSoon = 18,
+    /// The game is already loading.
+    AlreadyLoading = 19,
+    /// The game is not loading.
+    NotLoading = 20,
+    /// There is no split that can be adjusted, either because there is no
+    /// split yet or because the correction window has passed.
+    SplitCorrectionWindowClosed = 21,
+    /// The split time can't be moved past the current time.
+    SplitTimeInFuture = 22,
     /// An unknown error occurred.
     #[serde(other)]
     Unknown,
@@ -164,6 +193,12 @@ impl From<u32> for Error {
             14 => Error::CouldNotParseTime,
             15 => Error::TimerPaused,
             16 => Error::RunnerDecidedAgainstReset,
+            17 => Error::NoStartScheduled,
+            18 => Error::SplitTooSoon,
+            19 => Error::AlreadyLoading,
+            20 => Error::NotLoading,
+            21 => Error::SplitCorrectionWindowClosed,
+            22 => Error::SplitTimeInFuture,
             _ => Error::Unknown,
         }
     }
@@ -268,6 +303,15 @@ pub trait CommandSink {
     /// just specify the amount of time the game has been loading. The game time
     /// is then automatically determined by Real Time - Loading Times.
     fn set_loading_times(&self, time: TimeSpan) -> impl Future<Output = Result> + 'static;
+    /// Informs the timer that the game started loading. The timer then pauses
+    /// the game time until the game stops loading and adds the time spent
+    /// loading to the loading times. Game time automatically gets initialized
+    /// if it isn't already.
+    fn start_loading(&self) -> impl Future<Output = Result> + 'static;
+    /// Informs the timer that the game stopped loading. The game time
+    /// continues from where it was paused at, unless it was already paused
+    /// before the game started loading.
+    fn end_loading(&self) -> impl Future<Output = Result> + 'static;
     /// Sets the value of a custom variable with the name specified. If the
     /// variable does not exist, a temporary variable gets created that will not
     /// be stored in the splits file.
@@ -397,6 +441,16 @@ impl CommandSink for crate::SharedTimer {
         async move { result }
     }
 
+    fn start_loading(&self) -> impl Future<Output = Result> + 'static {
+        let result = self.write().unwrap().start_loading();
+        async move { result }
+    }
+
+    fn end_loading(&self) -> impl Future<Output = Result> + 'static {
+        let result = self.write().unwrap().end_loading();
+        async move { result }
+    }
+
     fn set_custom_variable(
         &self,
         name: Cow<'_, str>,
@@ -502,6 +556,14 @@ impl<T: CommandSink + ?Sized> CommandSink for Arc<T> {
         CommandSink::set_loading_times(&**self, time)
     }
 
+    fn start_loading(&self) -> impl Future<Output = Result> + 'static {
+        CommandSink::start_loading(&**self)
+    }
+
+    fn end_loading(&self) -> impl Future<Output = Result> + 'static {
+        CommandSink::end_loading(&**self)
+    }
+
     fn set_custom_variable(
         &self,
         name: Cow<'_, str>,
@@ -512,7 +574,10 @@ impl<T: CommandSink + ?Sized> CommandSink for Arc<T> {
 }
 
 impl<T: TimerQuery + ?Sized> TimerQuery for Arc<T> {
-    type Guard<'a> = T::Guard<'a> where T: 'a;
+    type Guard<'a>
+        = T::Guard<'a>
+    where
+        T: 'a;
     fn get_timer(&self) -> Self::Guard<'_> {
         TimerQuery::get_timer(&**self)
     }
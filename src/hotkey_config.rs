@@ -3,12 +3,16 @@
 use crate::{
     hotkey::Hotkey,
     platform::prelude::*,
-    settings::{Field, SettingsDescription, Value},
+    settings::{Field, HotkeyGesture, SettingsDescription, Value},
 };
+use alloc::collections::BTreeMap;
 use serde_derive::{Deserialize, Serialize};
 
+// The settings of the custom actions come after all the other settings.
+const CUSTOM_ACTIONS_INDEX: usize = 13;
+
 /// The configuration to use for a [`HotkeySystem`](crate::HotkeySystem). It describes which [`Hotkey`](livesplit_hotkey::Hotkey) to use as hotkeys for the different actions.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(default)]
 pub struct HotkeyConfig {
     /// The key to use for splitting and starting a new attempt.
@@ -31,6 +35,19 @@ pub struct HotkeyConfig {
     /// The key to use for toggling between the `Real Time` and `Game Time`
     /// timing methods.
     pub toggle_timing_method: Option<Hotkey>,
+    /// How the key for resetting the current attempt needs to be pressed.
+    pub reset_gesture: HotkeyGesture,
+    /// How the key for undoing the last split needs to be pressed.
+    pub undo_gesture: HotkeyGesture,
+    /// How long in milliseconds a key with the [`Hold`](HotkeyGesture::Hold)
+    /// gesture needs to be held down.
+    pub hold_duration: u32,
+    /// How many milliseconds may pass at most between the two presses of a key
+    /// with the [`DoublePress`](HotkeyGesture::DoublePress) gesture.
+    pub double_press_interval: u32,
+    /// The keys to use for the custom actions registered with the
+    /// [`HotkeySystem`](crate::HotkeySystem), by the names of the actions.
+    pub custom_actions: BTreeMap<String, Option<Hotkey>>,
 }
 
 impl Default for HotkeyConfig {
@@ -46,6 +63,11 @@ impl Default for HotkeyConfig {
             previous_comparison: Some(Numpad4.into()),
             next_comparison: Some(Numpad6.into()),
             toggle_timing_method: None,
+            reset_gesture: HotkeyGesture::Press,
+            undo_gesture: HotkeyGesture::Press,
+            hold_duration: 1000,
+            double_press_interval: 300,
+            custom_actions: BTreeMap::new(),
         }
     }
 }
@@ -54,7 +76,7 @@ impl HotkeyConfig {
     /// Accesses a generic description of the settings available for the hotkey
     /// configuration and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut fields = vec![
             Field::new(
                 "Start / Split".into(),
                 "The hotkey to use for splitting and starting a new attempt.".into(),
@@ -100,7 +122,37 @@ impl HotkeyConfig {
                 r#"The hotkey to use for toggling between the "Real Time" and "Game Time" timing methods."#.into(),
                 self.toggle_timing_method.into(),
             ),
-        ])
+            Field::new(
+                "Reset Gesture".into(),
+                "Specifies how the hotkey for resetting needs to be pressed. Requiring it to be held down or pressed twice prevents resetting by accident.".into(),
+                self.reset_gesture.into(),
+            ),
+            Field::new(
+                "Undo Split Gesture".into(),
+                "Specifies how the hotkey for undoing the last split needs to be pressed.".into(),
+                self.undo_gesture.into(),
+            ),
+            Field::new(
+                "Hold Duration".into(),
+                "How long in milliseconds a hotkey needs to be held down if it is supposed to be held.".into(),
+                Value::UInt(self.hold_duration as _),
+            ),
+            Field::new(
+                "Double Press Interval".into(),
+                "How many milliseconds may pass at most between the two presses of a hotkey that is supposed to be pressed twice.".into(),
+                Value::UInt(self.double_press_interval as _),
+            ),
+        ];
+
+        fields.extend(self.custom_actions.iter().map(|(name, &hotkey)| {
+            Field::new(
+                name.clone().into(),
+                "The hotkey to use for this custom action.".into(),
+                hotkey.into(),
+            )
+        }));
+
+        SettingsDescription::with_fields(fields)
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -116,7 +168,25 @@ impl HotkeyConfig {
     /// the type of the setting's value. A panic can also occur if the index of
     /// the setting provided is out of bounds.
     pub fn set_value(&mut self, index: usize, value: Value) -> Result<(), ()> {
-        let value: Option<Hotkey> = value.into();
+        let value: Option<Hotkey> = match index {
+            9 => {
+                self.reset_gesture = value.into();
+                return Ok(());
+            }
+            10 => {
+                self.undo_gesture = value.into();
+                return Ok(());
+            }
+            11 => {
+                self.hold_duration = value.into_uint().unwrap() as _;
+                return Ok(());
+            }
+            12 => {
+                self.double_press_interval = value.into_uint().unwrap() as _;
+                return Ok(());
+            }
+            _ => value.into(),
+        };
 
         if value.is_some() {
             let any = [
@@ -132,6 +202,13 @@ impl HotkeyConfig {
             ]
             .into_iter()
             .enumerate()
+            .chain(
+                self.custom_actions
+                    .values()
+                    .copied()
+                    .enumerate()
+                    .map(|(i, v)| (CUSTOM_ACTIONS_INDEX + i, v)),
+            )
             .filter(|&(i, _)| i != index)
             .any(|(_, v)| v == value);
 
@@ -150,7 +227,12 @@ impl HotkeyConfig {
             6 => self.previous_comparison = value,
             7 => self.next_comparison = value,
             8 => self.toggle_timing_method = value,
-            _ => panic!("Unsupported Setting Index"),
+            _ => {
+                *index
+                    .checked_sub(CUSTOM_ACTIONS_INDEX)
+                    .and_then(|i| self.custom_actions.values_mut().nth(i))
+                    .expect("Unsupported Setting Index") = value
+            }
         }
 
         Ok(())
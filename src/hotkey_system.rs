@@ -1,9 +1,12 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, collections::BTreeMap, sync::Arc};
+use std::sync::Mutex;
 
 use crate::{
     event,
-    hotkey::{ConsumePreference, Hook, Hotkey, KeyCode},
-    HotkeyConfig,
+    hotkey::{ConsumePreference, Error, Hook, Hotkey, KeyCode},
+    platform::prelude::*,
+    settings::{HotkeyGesture, Value},
+    HotkeyConfig, Run, TimeSpan, TimeStamp,
 };
 
 pub use crate::hotkey::Result;
@@ -33,6 +36,18 @@ enum Action {
 }
 
 impl Action {
+    const ALL: [Action; 9] = [
+        Action::Split,
+        Action::Reset,
+        Action::Undo,
+        Action::Skip,
+        Action::Pause,
+        Action::UndoAllPauses,
+        Action::PreviousComparison,
+        Action::NextComparison,
+        Action::ToggleTimingMethod,
+    ];
+
     fn set_hotkey(self, config: &mut HotkeyConfig, hotkey: Option<Hotkey>) {
         match self {
             Action::Split => config.split = hotkey,
@@ -61,52 +76,176 @@ impl Action {
         }
     }
 
-    fn callback<S: event::CommandSink + Send + 'static>(
-        self,
-        command_sink: S,
-    ) -> Box<dyn FnMut() + Send + 'static> {
+    const fn get_gesture(self, config: &HotkeyConfig) -> HotkeyGesture {
         match self {
-            Action::Split => Box::new(move || {
-                drop(command_sink.split_or_start());
-            }),
-            Action::Reset => Box::new(move || {
-                drop(command_sink.reset(None));
-            }),
-            Action::Undo => Box::new(move || {
-                drop(command_sink.undo_split());
-            }),
-            Action::Skip => Box::new(move || {
-                drop(command_sink.skip_split());
-            }),
-            Action::Pause => Box::new(move || {
-                drop(command_sink.toggle_pause_or_start());
-            }),
-            Action::UndoAllPauses => Box::new(move || {
-                drop(command_sink.undo_all_pauses());
-            }),
-            Action::PreviousComparison => Box::new(move || {
-                drop(command_sink.switch_to_previous_comparison());
-            }),
-            Action::NextComparison => Box::new(move || {
-                drop(command_sink.switch_to_next_comparison());
-            }),
-            Action::ToggleTimingMethod => Box::new(move || {
-                drop(command_sink.toggle_timing_method());
-            }),
+            Action::Reset => config.reset_gesture,
+            Action::Undo => config.undo_gesture,
+            _ => HotkeyGesture::Press,
+        }
+    }
+
+    // Whether the action needs to be registered again when switching from one
+    // configuration to the other.
+    fn needs_rebinding(self, previous: &HotkeyConfig, new: &HotkeyConfig) -> bool {
+        let gesture = self.get_gesture(previous);
+        self.get_hotkey(previous) != self.get_hotkey(new)
+            || gesture != self.get_gesture(new)
+            || match gesture {
+                HotkeyGesture::Press => false,
+                HotkeyGesture::Hold => previous.hold_duration != new.hold_duration,
+                HotkeyGesture::DoublePress => {
+                    previous.double_press_interval != new.double_press_interval
+                }
+            }
+    }
+
+    fn execute<S: event::CommandSink>(self, command_sink: &S) {
+        match self {
+            Action::Split => drop(command_sink.split_or_start()),
+            Action::Reset => drop(command_sink.reset(None)),
+            Action::Undo => drop(command_sink.undo_split()),
+            Action::Skip => drop(command_sink.skip_split()),
+            Action::Pause => drop(command_sink.toggle_pause_or_start()),
+            Action::UndoAllPauses => drop(command_sink.undo_all_pauses()),
+            Action::PreviousComparison => drop(command_sink.switch_to_previous_comparison()),
+            Action::NextComparison => drop(command_sink.switch_to_next_comparison()),
+            Action::ToggleTimingMethod => drop(command_sink.toggle_timing_method()),
+        }
+    }
+
+    fn callback<S: event::CommandSink + Send + 'static>(self, command_sink: S) -> SharedCallback {
+        Arc::new(Mutex::new(move || self.execute(&command_sink)))
+    }
+}
+
+// The callback of an action. It is shared, so it can be registered again
+// whenever the hotkeys change and it can be queued up while the hotkeys are
+// suppressed.
+type SharedCallback = Arc<Mutex<dyn FnMut() + Send + 'static>>;
+
+// Only triggers the action while the hotkeys are not suppressed.
+fn suppressible(
+    callback: SharedCallback,
+    suppression: Arc<Mutex<Suppression>>,
+) -> Box<dyn FnMut() + Send + 'static> {
+    Box::new(move || {
+        let mut suppression = suppression.lock().unwrap();
+        if suppression.is_suppressed() {
+            if suppression.mode == SuppressionMode::Queue {
+                suppression.queued_callbacks.push(callback.clone());
+            }
+        } else {
+            drop(suppression);
+            (*callback.lock().unwrap())();
         }
+    })
+}
+
+/// Describes what happens to the hotkeys that are pressed while the
+/// [`HotkeySystem`] is suppressed.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum SuppressionMode {
+    /// The hotkeys are ignored.
+    #[default]
+    Discard,
+    /// The actions of the hotkeys are carried out in order once the
+    /// [`HotkeySystem`] is no longer suppressed.
+    Queue,
+}
+
+// Decides whether the hotkeys are suppressed. This is shared with the
+// callbacks of the hotkeys, as those run on the thread of the hook.
+#[derive(Default)]
+struct Suppression {
+    is_text_input_focused: bool,
+    is_suppressing_process_in_foreground: bool,
+    mode: SuppressionMode,
+    queued_callbacks: Vec<SharedCallback>,
+}
+
+impl Suppression {
+    const fn is_suppressed(&self) -> bool {
+        self.is_text_input_focused || self.is_suppressing_process_in_foreground
     }
 }
 
+// Only triggers the action when the hotkey gets pressed a second time within
+// the interval.
+fn double_press(
+    mut callback: Box<dyn FnMut() + Send + 'static>,
+    interval: TimeSpan,
+) -> impl FnMut() + Send + 'static {
+    let mut last_press = None;
+    move || {
+        let now = TimeStamp::now();
+        if last_press.is_some_and(|last_press| now - last_press <= interval) {
+            last_press = None;
+            callback();
+        } else {
+            last_press = Some(now);
+        }
+    }
+}
+
+// Only triggers the action when the hotkey gets released after being held down
+// for at least the duration. The first closure handles the press and the
+// second one the release.
+fn hold(
+    mut callback: Box<dyn FnMut() + Send + 'static>,
+    duration: TimeSpan,
+) -> (impl FnMut() + Send + 'static, impl FnMut() + Send + 'static) {
+    let pressed_at = Arc::new(Mutex::new(None));
+    let on_press = {
+        let pressed_at = pressed_at.clone();
+        move || *pressed_at.lock().unwrap() = Some(TimeStamp::now())
+    };
+    let on_release = move || {
+        let pressed_at = pressed_at.lock().unwrap().take();
+        if pressed_at.is_some_and(|pressed_at| TimeStamp::now() - pressed_at >= duration) {
+            callback();
+        }
+    };
+    (on_press, on_release)
+}
+
 /// With a `HotkeySystem` the runner can use hotkeys on their keyboard to control
 /// the Timer. The hotkeys are global, so the application doesn't need to be in
 /// focus. The behavior of the hotkeys depends on the platform and is stubbed
 /// out on platforms that don't support hotkeys. You can turn off a `HotkeySystem`
 /// temporarily. By default the `HotkeySystem` is activated.
+///
+/// Actions that would be hard to recover from when triggered by accident, such
+/// as resetting, can be configured to require the hotkey to be held down or to
+/// be pressed twice, instead of reacting to a single press.
+///
+/// While the runner is typing into a text input of the application, such as
+/// when naming a segment, the hotkeys should not trigger any actions. The
+/// application can report this to the `HotkeySystem`, along with the process
+/// that is currently in the foreground, so the hotkeys get suppressed in the
+/// meantime. Depending on the [`SuppressionMode`], the hotkeys pressed while
+/// suppressed are either ignored or carried out afterwards.
+///
+/// In addition to the actions of the timer, the application can register its
+/// own custom actions, such as saving the splits. The hotkeys for those are
+/// part of the same [`HotkeyConfig`], so they are stored in the same profiles
+/// and checked for conflicts with all the other hotkeys.
+///
+/// Besides the default hotkeys, the `HotkeySystem` can store any number of
+/// named hotkey profiles. Only a single profile is in use at a time. This
+/// allows the runner to use different hotkeys for different games, without
+/// having to rebind them whenever they switch games.
 pub struct HotkeySystem<S> {
     config: HotkeyConfig,
+    default_config: HotkeyConfig,
+    profiles: BTreeMap<String, HotkeyConfig>,
+    active_profile: Option<String>,
     hook: Hook,
     command_sink: S,
     is_active: bool,
+    suppression: Arc<Mutex<Suppression>>,
+    suppressing_processes: Vec<String>,
+    foreground_process: Option<String>,
+    custom_actions: BTreeMap<String, SharedCallback>,
 }
 
 impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
@@ -119,10 +258,17 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
     /// the hotkeys.
     pub fn with_config(command_sink: S, config: HotkeyConfig) -> Result<Self> {
         let mut hotkey_system = Self {
-            config,
+            config: config.clone(),
+            default_config: config,
+            profiles: BTreeMap::new(),
+            active_profile: None,
             hook: Hook::with_consume_preference(ConsumePreference::PreferNoConsume)?,
             command_sink,
             is_active: false,
+            suppression: Default::default(),
+            suppressing_processes: Vec::new(),
+            foreground_process: None,
+            custom_actions: BTreeMap::new(),
         };
         hotkey_system.activate()?;
         Ok(hotkey_system)
@@ -133,14 +279,53 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
     fn register_inner(&self, action: Action) -> Result<()> {
         let inner = self.command_sink.clone();
         if let Some(hotkey) = action.get_hotkey(&self.config) {
-            self.hook.register(hotkey, action.callback(inner))?;
+            let callback = suppressible(action.callback(inner), self.suppression.clone());
+            match action.get_gesture(&self.config) {
+                HotkeyGesture::Press => self.hook.register(hotkey, callback)?,
+                HotkeyGesture::Hold => {
+                    let duration = TimeSpan::from_milliseconds(self.config.hold_duration as _);
+                    let (on_press, on_release) = hold(callback, duration);
+                    self.hook
+                        .register_with_release(hotkey, on_press, on_release)?;
+                }
+                HotkeyGesture::DoublePress => {
+                    let interval =
+                        TimeSpan::from_milliseconds(self.config.double_press_interval as _);
+                    self.hook
+                        .register(hotkey, double_press(callback, interval))?;
+                }
+            }
         }
         Ok(())
     }
 
-    fn register(&mut self, action: Action, hotkey: Option<Hotkey>) -> Result<()> {
-        action.set_hotkey(&mut self.config, hotkey);
-        self.register_inner(action)
+    fn custom_action_hotkey(&self, name: &str) -> Option<Hotkey> {
+        if !self.custom_actions.contains_key(name) {
+            return None;
+        }
+        self.config.custom_actions.get(name).copied().flatten()
+    }
+
+    // This method should never be public, because it might mess up the internal
+    // state and we might leak a registered hotkey
+    fn register_custom_inner(&self, name: &str) -> Result<()> {
+        if let (Some(hotkey), Some(callback)) = (
+            self.custom_action_hotkey(name),
+            self.custom_actions.get(name),
+        ) {
+            let callback = suppressible(callback.clone(), self.suppression.clone());
+            self.hook.register(hotkey, callback)?;
+        }
+        Ok(())
+    }
+
+    // This method should never be public, because it might mess up the internal
+    // state and we might leak a registered hotkey
+    fn unregister_custom_inner(&self, name: &str) -> Result<()> {
+        if let Some(hotkey) = self.custom_action_hotkey(name) {
+            self.hook.unregister(hotkey)?;
+        }
+        Ok(())
     }
 
     // This method should never be public, because it might mess up the internal
@@ -152,26 +337,62 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
         Ok(())
     }
 
-    fn unregister(&mut self, action: Action) -> Result<()> {
-        self.unregister_inner(action)?;
-        action.set_hotkey(&mut self.config, None);
+    // Registers the hotkeys of the actions. If any of them can't be registered,
+    // none of them stay registered.
+    fn register_all(&self, actions: &[Action], custom_actions: &[String]) -> Result<()> {
+        for (index, &action) in actions.iter().enumerate() {
+            if let Err(error) = self.register_inner(action) {
+                for &action in &actions[..index] {
+                    let _ = self.unregister_inner(action);
+                }
+                return Err(error);
+            }
+        }
+        for (index, name) in custom_actions.iter().enumerate() {
+            if let Err(error) = self.register_custom_inner(name) {
+                for name in &custom_actions[..index] {
+                    let _ = self.unregister_custom_inner(name);
+                }
+                for &action in actions {
+                    let _ = self.unregister_inner(action);
+                }
+                return Err(error);
+            }
+        }
         Ok(())
     }
 
-    fn set_hotkey(&mut self, action: Action, hotkey: Option<Hotkey>) -> Result<()> {
-        // FIXME: We do not check whether the hotkey is already in use
-        if action.get_hotkey(&self.config) == hotkey {
-            return Ok(());
+    fn unregister_all(&self, actions: &[Action], custom_actions: &[String]) -> Result<()> {
+        for &action in actions {
+            self.unregister_inner(action)?;
         }
-        if self.is_active {
-            self.unregister(action)?;
-            self.register(action, hotkey)?;
-        } else {
-            action.set_hotkey(&mut self.config, hotkey);
+        for name in custom_actions {
+            self.unregister_custom_inner(name)?;
         }
         Ok(())
     }
 
+    fn set_hotkey(&mut self, action: Action, hotkey: Option<Hotkey>) -> Result<()> {
+        // FIXME: We do not check whether the hotkey is already in use
+        let mut config = self.config.clone();
+        action.set_hotkey(&mut config, hotkey);
+        self.set_config(config)
+    }
+
+    // Stores the configuration in use as the configuration of the active
+    // profile, so it doesn't get lost when switching profiles.
+    fn store_config(&mut self) {
+        let config = self.config.clone();
+        match &self.active_profile {
+            Some(name) => {
+                if let Some(profile) = self.profiles.get_mut(name) {
+                    *profile = config;
+                }
+            }
+            None => self.default_config = config,
+        }
+    }
+
     /// Sets the key to use for splitting and starting a new attempt.
     pub fn set_split(&mut self, hotkey: Option<Hotkey>) -> Result<()> {
         self.set_hotkey(Action::Split, hotkey)
@@ -220,37 +441,109 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
         self.set_hotkey(Action::ToggleTimingMethod, hotkey)
     }
 
+    /// Registers a custom action that gets triggered by a hotkey, just like the
+    /// actions of the timer. The hotkey is configured by the name of the action
+    /// in the [`custom_actions`](HotkeyConfig::custom_actions) of the
+    /// configuration. If there already is a custom action with that name, its
+    /// callback gets replaced.
+    pub fn register_custom_action<F>(&mut self, name: &str, callback: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        if self.is_active {
+            self.unregister_custom_inner(name)?;
+        }
+        self.custom_actions
+            .insert(name.into(), Arc::new(Mutex::new(callback)));
+
+        // The action needs to be part of all the configurations, so the
+        // runner can choose a hotkey for it in the settings.
+        for config in [&mut self.config, &mut self.default_config]
+            .into_iter()
+            .chain(self.profiles.values_mut())
+        {
+            config.custom_actions.entry(name.into()).or_insert(None);
+        }
+
+        if self.is_active {
+            if let Err(error) = self.register_custom_inner(name) {
+                self.custom_actions.remove(name);
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the custom action with the name provided. Its hotkey stays in
+    /// the configuration, so it is used again once a custom action with the
+    /// same name gets registered. Returns whether there was a custom action
+    /// with that name.
+    pub fn remove_custom_action(&mut self, name: &str) -> Result<bool> {
+        if self.is_active {
+            self.unregister_custom_inner(name)?;
+        }
+        Ok(self.custom_actions.remove(name).is_some())
+    }
+
+    /// Returns the names of all the custom actions that are registered.
+    pub fn custom_action_names(&self) -> impl Iterator<Item = &str> {
+        self.custom_actions.keys().map(String::as_str)
+    }
+
+    /// Sets the key to use for the custom action with the name provided.
+    pub fn set_custom_action_hotkey(&mut self, name: &str, hotkey: Option<Hotkey>) -> Result<()> {
+        // FIXME: We do not check whether the hotkey is already in use
+        let mut config = self.config.clone();
+        config.custom_actions.insert(name.into(), hotkey);
+        self.set_config(config)
+    }
+
+    /// Sets a setting's value of the hotkey configuration in use by its index
+    /// to the given value. Only the hotkey affected by the setting is
+    /// registered again, all the other hotkeys keep working without
+    /// interruption.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::AlreadyRegistered`] is returned if the hotkey is already in use
+    /// by a different action. This is the only way
+    /// [`HotkeyConfig::set_value`] can fail. Otherwise the errors are the same
+    /// as the ones of [`set_config`](Self::set_config). The previous hotkey
+    /// stays in use in either case.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) -> Result<()> {
+        let mut config = self.config.clone();
+        config
+            .set_value(index, value)
+            .map_err(|()| Error::AlreadyRegistered)?;
+        self.set_config(config)
+    }
+
     /// Deactivates the Hotkey System. No hotkeys will go through until it gets
-    /// activated again. If it's already deactivated, nothing happens.
+    /// activated again. If it's already deactivated, nothing happens. This is
+    /// useful for temporarily turning off the hotkeys, such as while the run
+    /// editor is open. The hotkeys can still be changed in the meantime.
     pub fn deactivate(&mut self) -> Result<()> {
         if self.is_active {
-            self.unregister_inner(Action::Split)?;
-            self.unregister_inner(Action::Reset)?;
-            self.unregister_inner(Action::Undo)?;
-            self.unregister_inner(Action::Skip)?;
-            self.unregister_inner(Action::Pause)?;
-            self.unregister_inner(Action::UndoAllPauses)?;
-            self.unregister_inner(Action::PreviousComparison)?;
-            self.unregister_inner(Action::NextComparison)?;
-            self.unregister_inner(Action::ToggleTimingMethod)?;
+            let custom_actions: Vec<String> = self.custom_actions.keys().cloned().collect();
+            self.unregister_all(&Action::ALL, &custom_actions)?;
         }
         self.is_active = false;
         Ok(())
     }
 
     /// Activates a previously deactivated Hotkey System. If it's already
-    /// active, nothing happens.
+    /// active, nothing happens. If any of the hotkeys can't be registered, none
+    /// of them stay registered and the Hotkey System stays deactivated.
     pub fn activate(&mut self) -> Result<()> {
         if !self.is_active {
-            self.register_inner(Action::Split)?;
-            self.register_inner(Action::Reset)?;
-            self.register_inner(Action::Undo)?;
-            self.register_inner(Action::Skip)?;
-            self.register_inner(Action::Pause)?;
-            self.register_inner(Action::UndoAllPauses)?;
-            self.register_inner(Action::PreviousComparison)?;
-            self.register_inner(Action::NextComparison)?;
-            self.register_inner(Action::ToggleTimingMethod)?;
+            let custom_actions: Vec<String> = self.custom_actions.keys().cloned().collect();
+            self.register_all(&Action::ALL, &custom_actions)?;
         }
         self.is_active = true;
         Ok(())
@@ -261,31 +554,380 @@ impl<S: event::CommandSink + Clone + Send + 'static> HotkeySystem<S> {
         self.is_active
     }
 
+    /// Returns true if the hotkeys are currently suppressed, either because a
+    /// text input is focused or because a suppressing process is in the
+    /// foreground.
+    pub fn is_suppressed(&self) -> bool {
+        self.suppression.lock().unwrap().is_suppressed()
+    }
+
+    /// Marks whether a text input of the application is focused. While it is,
+    /// the hotkeys are suppressed, so typing doesn't trigger any actions.
+    pub fn set_text_input_focused(&mut self, is_focused: bool) {
+        self.update_suppression(|suppression| suppression.is_text_input_focused = is_focused);
+    }
+
+    /// Reports the name of the process that is currently in the foreground,
+    /// such as `notepad.exe`. While it is one of the suppressing processes,
+    /// the hotkeys are suppressed. [`None`] means that the foreground process
+    /// is unknown, which never suppresses the hotkeys.
+    pub fn set_foreground_process(&mut self, name: Option<&str>) {
+        self.foreground_process = name.map(Into::into);
+        self.update_process_suppression();
+    }
+
+    /// Returns the names of the processes that suppress the hotkeys while they
+    /// are in the foreground.
+    pub fn suppressing_processes(&self) -> impl Iterator<Item = &str> {
+        self.suppressing_processes.iter().map(String::as_str)
+    }
+
+    /// Adds a process that suppresses the hotkeys while it is in the
+    /// foreground. The names of the processes are compared case-insensitively.
+    pub fn add_suppressing_process(&mut self, name: &str) {
+        if !self
+            .suppressing_processes
+            .iter()
+            .any(|process| process.eq_ignore_ascii_case(name))
+        {
+            self.suppressing_processes.push(name.into());
+            self.update_process_suppression();
+        }
+    }
+
+    /// Removes a process that suppresses the hotkeys while it is in the
+    /// foreground. Returns whether the process was one of the suppressing
+    /// processes.
+    pub fn remove_suppressing_process(&mut self, name: &str) -> bool {
+        let len = self.suppressing_processes.len();
+        self.suppressing_processes
+            .retain(|process| !process.eq_ignore_ascii_case(name));
+        let was_removed = self.suppressing_processes.len() != len;
+        if was_removed {
+            self.update_process_suppression();
+        }
+        was_removed
+    }
+
+    /// Returns what happens to the hotkeys that are pressed while suppressed.
+    pub fn suppression_mode(&self) -> SuppressionMode {
+        self.suppression.lock().unwrap().mode
+    }
+
+    /// Sets what happens to the hotkeys that are pressed while suppressed.
+    /// Switching to [`SuppressionMode::Discard`] discards the hotkeys that are
+    /// queued up so far.
+    pub fn set_suppression_mode(&mut self, mode: SuppressionMode) {
+        self.update_suppression(|suppression| {
+            suppression.mode = mode;
+            if mode == SuppressionMode::Discard {
+                suppression.queued_callbacks.clear();
+            }
+        });
+    }
+
+    fn update_process_suppression(&self) {
+        let is_in_foreground = self.foreground_process.as_ref().is_some_and(|foreground| {
+            self.suppressing_processes
+                .iter()
+                .any(|process| process.eq_ignore_ascii_case(foreground))
+        });
+        self.update_suppression(|suppression| {
+            suppression.is_suppressing_process_in_foreground = is_in_foreground
+        });
+    }
+
+    // Carries out the queued actions once the hotkeys are no longer
+    // suppressed.
+    fn update_suppression(&self, update: impl FnOnce(&mut Suppression)) {
+        let queued_callbacks = {
+            let mut suppression = self.suppression.lock().unwrap();
+            update(&mut suppression);
+            if suppression.is_suppressed() {
+                return;
+            }
+            core::mem::take(&mut suppression.queued_callbacks)
+        };
+        for callback in queued_callbacks {
+            (*callback.lock().unwrap())();
+        }
+    }
+
     /// Returns the hotkey configuration currently in use by the Hotkey System.
-    pub const fn config(&self) -> HotkeyConfig {
-        self.config
+    /// This is the configuration of the active profile.
+    pub fn config(&self) -> HotkeyConfig {
+        self.config.clone()
     }
 
-    /// Applies a new hotkey configuration to the Hotkey System. Each hotkey is
-    /// changed to the one specified in the configuration. This operation may
-    /// fail if you provide a hotkey configuration where a hotkey is used for
-    /// multiple operations.
+    /// Applies a new hotkey configuration to the Hotkey System. The hotkeys
+    /// that change get registered again according to the configuration, so
+    /// hotkeys can freely be moved between the actions, such as swapping the
+    /// hotkeys of two actions. All the other hotkeys stay registered. The
+    /// configuration is stored as the configuration of the active profile.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a hotkey is used for multiple actions or can't
+    /// be registered, such as when another application already uses it. The
+    /// previous configuration stays in use in that case.
     pub fn set_config(&mut self, config: HotkeyConfig) -> Result<()> {
-        self.set_split(config.split)?;
-        self.set_reset(config.reset)?;
-        self.set_undo(config.undo)?;
-        self.set_skip(config.skip)?;
-        self.set_pause(config.pause)?;
-        self.set_previous_comparison(config.previous_comparison)?;
-        self.set_next_comparison(config.next_comparison)?;
-        self.set_undo_all_pauses(config.undo_all_pauses)?;
-        self.set_toggle_timing_method(config.toggle_timing_method)?;
+        self.apply_config(config)?;
+        self.store_config();
+        Ok(())
+    }
+
+    fn apply_config(&mut self, mut config: HotkeyConfig) -> Result<()> {
+        for name in self.custom_actions.keys() {
+            config.custom_actions.entry(name.clone()).or_insert(None);
+        }
+
+        if config == self.config {
+            return Ok(());
+        }
+
+        if !self.is_active {
+            self.config = config;
+            return Ok(());
+        }
+
+        // Only the hotkeys that actually change get registered again, so all
+        // the other hotkeys keep working without interruption.
+        let actions: Vec<Action> = Action::ALL
+            .into_iter()
+            .filter(|action| action.needs_rebinding(&self.config, &config))
+            .collect();
+        let custom_actions: Vec<String> = self
+            .custom_actions
+            .keys()
+            .filter(|name| {
+                self.config.custom_actions.get(*name) != config.custom_actions.get(*name)
+            })
+            .cloned()
+            .collect();
+
+        self.unregister_all(&actions, &custom_actions)?;
+        let previous_config = core::mem::replace(&mut self.config, config);
+        if let Err(error) = self.register_all(&actions, &custom_actions) {
+            self.config = previous_config;
+            self.register_all(&actions, &custom_actions)?;
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the hotkey configuration that is used when no named profile is
+    /// active.
+    pub fn default_config(&self) -> HotkeyConfig {
+        self.default_config.clone()
+    }
+
+    /// Sets the hotkey configuration that is used when no named profile is
+    /// active. If no named profile is active, this works the same way as
+    /// [`set_config`](Self::set_config).
+    pub fn set_default_config(&mut self, config: HotkeyConfig) -> Result<()> {
+        if self.active_profile.is_none() {
+            self.set_config(config)
+        } else {
+            self.default_config = config;
+            Ok(())
+        }
+    }
+
+    /// Returns the name of the profile that is currently in use. If the default
+    /// hotkeys are in use, [`None`] is returned.
+    pub fn active_profile(&self) -> Option<&str> {
+        self.active_profile.as_deref()
+    }
 
+    /// Returns the names of all the profiles stored in the Hotkey System.
+    pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    /// Returns the hotkey configuration of the profile with the name provided.
+    pub fn profile(&self, name: &str) -> Option<HotkeyConfig> {
+        self.profiles.get(name).cloned()
+    }
+
+    /// Stores a hotkey configuration as the profile with the name provided. If
+    /// the profile already exists, its configuration gets replaced. If the
+    /// profile is currently in use, the hotkeys get registered again, which
+    /// may fail the same way [`set_config`](Self::set_config) can fail. In
+    /// that case the profile stays unchanged.
+    pub fn set_profile(&mut self, name: &str, config: HotkeyConfig) -> Result<()> {
+        if self.active_profile.as_deref() == Some(name) {
+            self.set_config(config)
+        } else {
+            self.profiles.insert(name.into(), config);
+            Ok(())
+        }
+    }
+
+    /// Removes the profile with the name provided. If the profile is currently
+    /// in use, the Hotkey System switches back to the default hotkeys first.
+    /// Returns whether there was a profile with that name.
+    pub fn remove_profile(&mut self, name: &str) -> Result<bool> {
+        if self.active_profile.as_deref() == Some(name) {
+            self.switch_to_default_profile()?;
+        }
+        Ok(self.profiles.remove(name).is_some())
+    }
+
+    /// Switches to the profile with the name provided, registering its hotkeys
+    /// instead of the ones currently in use. If there is no profile with that
+    /// name, the Hotkey System switches to the default hotkeys instead. If the
+    /// hotkeys can't be registered, the previous profile stays in use.
+    pub fn switch_to_profile(&mut self, name: &str) -> Result<()> {
+        let Some((name, config)) = self.profiles.get_key_value(name) else {
+            return self.switch_to_default_profile();
+        };
+        if self.active_profile.as_ref() != Some(name) {
+            let (name, config) = (name.clone(), config.clone());
+            self.apply_config(config)?;
+            self.active_profile = Some(name);
+        }
+        Ok(())
+    }
+
+    /// Switches back to the default hotkeys that are used when no named
+    /// profile is active.
+    pub fn switch_to_default_profile(&mut self) -> Result<()> {
+        if self.active_profile.is_some() {
+            self.apply_config(self.default_config.clone())?;
+            self.active_profile = None;
+        }
         Ok(())
     }
 
+    /// Switches to the profile that is named after the game of the run
+    /// provided. This is meant to be called whenever a different run gets
+    /// loaded, so the hotkeys automatically follow the game that is being
+    /// run. If there is no profile for the game, the default hotkeys are used.
+    pub fn switch_to_profile_for_run(&mut self, run: &Run) -> Result<()> {
+        self.switch_to_profile(run.game_name())
+    }
+
     /// Resolves the key according to the current keyboard layout.
     pub fn resolve(&self, key_code: KeyCode) -> Cow<'static, str> {
         key_code.resolve(&self.hook)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{util::tests_helper::create_timer, TimerPhase};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counter() -> (Arc<AtomicUsize>, Box<dyn FnMut() + Send + 'static>) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let callback = {
+            let count = count.clone();
+            Box::new(move || {
+                count.fetch_add(1, Ordering::SeqCst);
+            })
+        };
+        (count, callback)
+    }
+
+    #[test]
+    fn double_press_needs_two_presses() {
+        let (count, callback) = counter();
+        let mut on_press = double_press(callback, TimeSpan::from_seconds(60.0));
+        on_press();
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        on_press();
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        on_press();
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        let (count, callback) = counter();
+        let mut on_press = double_press(callback, TimeSpan::from_seconds(-1.0));
+        on_press();
+        on_press();
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn hold_needs_to_be_held_long_enough() {
+        let (count, callback) = counter();
+        let (mut on_press, mut on_release) = hold(callback, TimeSpan::zero());
+        on_release();
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        on_press();
+        on_release();
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+
+        let (count, callback) = counter();
+        let (mut on_press, mut on_release) = hold(callback, TimeSpan::from_seconds(60.0));
+        on_press();
+        on_release();
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn suppressed_actions_are_discarded_or_queued() {
+        let timer = create_timer(&["A", "B"]).into_shared();
+        let suppression = Arc::new(Mutex::new(Suppression::default()));
+        let mut split = suppressible(Action::Split.callback(timer.clone()), suppression.clone());
+
+        suppression.lock().unwrap().is_text_input_focused = true;
+        split();
+        assert_eq!(
+            timer.read().unwrap().current_phase(),
+            TimerPhase::NotRunning
+        );
+        assert!(suppression.lock().unwrap().queued_callbacks.is_empty());
+
+        suppression.lock().unwrap().mode = SuppressionMode::Queue;
+        split();
+        assert_eq!(
+            timer.read().unwrap().current_phase(),
+            TimerPhase::NotRunning
+        );
+        assert_eq!(suppression.lock().unwrap().queued_callbacks.len(), 1);
+
+        suppression.lock().unwrap().is_text_input_focused = false;
+        split();
+        assert_eq!(timer.read().unwrap().current_phase(), TimerPhase::Running);
+    }
+
+    #[test]
+    fn only_changed_actions_need_rebinding() {
+        let previous = HotkeyConfig::default();
+        let mut new = previous.clone();
+        new.split = Some(KeyCode::KeyS.into());
+        new.hold_duration = 500;
+
+        let rebound: Vec<_> = Action::ALL
+            .into_iter()
+            .filter(|action| action.needs_rebinding(&previous, &new))
+            .collect();
+        assert_eq!(rebound, [Action::Split]);
+
+        new.reset_gesture = HotkeyGesture::Hold;
+        assert!(Action::Reset.needs_rebinding(&previous, &new));
+        assert!(Action::Reset.needs_rebinding(
+            &new,
+            &HotkeyConfig {
+                hold_duration: 1000,
+                ..new.clone()
+            }
+        ));
+        assert!(!Action::Undo.needs_rebinding(&previous, &new));
+    }
+
+    #[test]
+    fn custom_actions_conflict_with_other_hotkeys() {
+        let mut config = HotkeyConfig::default();
+        config.custom_actions.insert("Save Splits".into(), None);
+        let index = config.settings_description().fields.len() - 1;
+        let hotkey = Some(Hotkey::from(KeyCode::KeyS));
+
+        assert!(config.set_value(index, config.split.into()).is_err());
+        assert!(config.set_value(index, hotkey.into()).is_ok());
+        assert_eq!(config.custom_actions["Save Splits"], hotkey);
+        assert!(config.set_value(0, hotkey.into()).is_err());
+    }
+}
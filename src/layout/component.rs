@@ -1,9 +1,9 @@
 use super::{ComponentSettings, ComponentState, GeneralSettings};
 use crate::{
     component::{
-        blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        blank_space, countdown, current_comparison, current_pace, delta, detailed_timer, graph,
+        pb_chance, possible_time_save, previous_segment, segment_time, segment_tracker, separator,
+        splits, sum_of_best, text, timer, title, total_playtime, world_record,
     },
     platform::prelude::*,
     settings::{ImageCache, SettingsDescription, Value},
@@ -17,6 +17,8 @@ use alloc::borrow::Cow;
 pub enum Component {
     /// The Blank Space Component.
     BlankSpace(blank_space::Component),
+    /// The Countdown Component.
+    Countdown(countdown::Component),
     /// The Current Comparison Component.
     CurrentComparison(current_comparison::Component),
     /// The Current Pace Component.
@@ -35,6 +37,8 @@ pub enum Component {
     PreviousSegment(previous_segment::Component),
     /// The Segment Time Component.
     SegmentTime(segment_time::Component),
+    /// The Segment Tracker Component.
+    SegmentTracker(segment_tracker::Component),
     /// The Separator Component.
     Separator(separator::Component),
     /// The Splits Component.
@@ -49,6 +53,8 @@ pub enum Component {
     Title(title::Component),
     /// The Total Playtime Component.
     TotalPlaytime(total_playtime::Component),
+    /// The World Record Component.
+    WorldRecord(world_record::Component),
 }
 
 impl From<blank_space::Component> for Component {
@@ -57,6 +63,12 @@ impl From<blank_space::Component> for Component {
     }
 }
 
+impl From<countdown::Component> for Component {
+    fn from(component: countdown::Component) -> Self {
+        Self::Countdown(component)
+    }
+}
+
 impl From<current_comparison::Component> for Component {
     fn from(component: current_comparison::Component) -> Self {
         Self::CurrentComparison(component)
@@ -111,6 +123,12 @@ impl From<segment_time::Component> for Component {
     }
 }
 
+impl From<segment_tracker::Component> for Component {
+    fn from(component: segment_tracker::Component) -> Self {
+        Self::SegmentTracker(component)
+    }
+}
+
 impl From<separator::Component> for Component {
     fn from(component: separator::Component) -> Self {
         Self::Separator(component)
@@ -153,6 +171,12 @@ impl From<total_playtime::Component> for Component {
     }
 }
 
+impl From<world_record::Component> for Component {
+    fn from(component: world_record::Component) -> Self {
+        Self::WorldRecord(component)
+    }
+}
+
 impl Component {
     /// Updates the component's state based on the timer and settings provided.
     /// The timer provides the information to visualize and the layout settings
@@ -172,15 +196,25 @@ impl Component {
             (ComponentState::BlankSpace(state), Component::BlankSpace(component)) => {
                 component.update_state(state)
             }
+            (ComponentState::Timer(state), Component::Countdown(component)) => {
+                component.update_state(state, timer, layout_settings)
+            }
             (ComponentState::KeyValue(state), Component::CurrentComparison(component)) => {
                 component.update_state(state, timer)
             }
             (ComponentState::KeyValue(state), Component::CurrentPace(component)) => {
                 component.update_state(state, timer)
             }
-            (ComponentState::KeyValue(state), Component::Delta(component)) => {
+            (ComponentState::KeyValue(state), Component::Delta(component))
+                if !component.settings().display_large =>
+            {
                 component.update_state(state, timer, layout_settings)
             }
+            (ComponentState::Timer(state), Component::Delta(component))
+                if component.settings().display_large =>
+            {
+                component.update_large_state(state, timer, layout_settings)
+            }
             (ComponentState::DetailedTimer(state), Component::DetailedTimer(component)) => {
                 component.update_state(state, image_cache, timer, layout_settings)
             }
@@ -199,6 +233,9 @@ impl Component {
             (ComponentState::KeyValue(state), Component::SegmentTime(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::SegmentTracker(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::Separator(state), Component::Separator(component)) => {
                 component.update_state(state)
             }
@@ -220,6 +257,9 @@ impl Component {
             (ComponentState::KeyValue(state), Component::TotalPlaytime(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::WorldRecord(component)) => {
+                component.update_state(state, timer)
+            }
             (state, component) => *state = component.state(image_cache, timer, layout_settings),
         }
     }
@@ -240,12 +280,19 @@ impl Component {
     ) -> ComponentState {
         match self {
             Component::BlankSpace(component) => ComponentState::BlankSpace(component.state()),
+            Component::Countdown(component) => {
+                ComponentState::Timer(component.state(timer, layout_settings))
+            }
             Component::CurrentComparison(component) => {
                 ComponentState::KeyValue(component.state(timer))
             }
             Component::CurrentPace(component) => ComponentState::KeyValue(component.state(timer)),
             Component::Delta(component) => {
-                ComponentState::KeyValue(component.state(timer, layout_settings))
+                if component.settings().display_large {
+                    ComponentState::Timer(component.large_state(timer, layout_settings))
+                } else {
+                    ComponentState::KeyValue(component.state(timer, layout_settings))
+                }
             }
             Component::DetailedTimer(component) => ComponentState::DetailedTimer(Box::new(
                 component.state(image_cache, timer, layout_settings),
@@ -261,6 +308,9 @@ impl Component {
                 ComponentState::KeyValue(component.state(timer, layout_settings))
             }
             Component::SegmentTime(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::SegmentTracker(component) => {
+                ComponentState::KeyValue(component.state(timer))
+            }
             Component::Separator(component) => ComponentState::Separator(component.state()),
             Component::Splits(component) => {
                 ComponentState::Splits(component.state(image_cache, timer, layout_settings))
@@ -274,6 +324,7 @@ impl Component {
                 ComponentState::Title(component.state(image_cache, timer))
             }
             Component::TotalPlaytime(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::WorldRecord(component) => ComponentState::KeyValue(component.state(timer)),
         }
     }
 
@@ -286,6 +337,9 @@ impl Component {
             Component::BlankSpace(component) => {
                 ComponentSettings::BlankSpace(component.settings().clone())
             }
+            Component::Countdown(component) => {
+                ComponentSettings::Countdown(component.settings().clone())
+            }
             Component::CurrentComparison(component) => {
                 ComponentSettings::CurrentComparison(component.settings().clone())
             }
@@ -309,7 +363,12 @@ impl Component {
             Component::SegmentTime(component) => {
                 ComponentSettings::SegmentTime(component.settings().clone())
             }
-            Component::Separator(_) => ComponentSettings::Separator,
+            Component::SegmentTracker(component) => {
+                ComponentSettings::SegmentTracker(component.settings().clone())
+            }
+            Component::Separator(component) => {
+                ComponentSettings::Separator(component.settings().clone())
+            }
             Component::Splits(component) => ComponentSettings::Splits(component.settings().clone()),
             Component::SumOfBest(component) => {
                 ComponentSettings::SumOfBest(component.settings().clone())
@@ -320,6 +379,28 @@ impl Component {
             Component::TotalPlaytime(component) => {
                 ComponentSettings::TotalPlaytime(component.settings().clone())
             }
+            Component::WorldRecord(component) => {
+                ComponentSettings::WorldRecord(component.settings().clone())
+            }
+        }
+    }
+
+    /// Applies the settings provided to the component. If the settings belong
+    /// to the same kind of component, the runtime state of the component, such
+    /// as the scroll position of the splits or the world record that got
+    /// fetched, is kept. Otherwise the component is replaced by a new
+    /// component created from the settings.
+    pub fn apply_settings(&mut self, settings: ComponentSettings) {
+        match (self, settings) {
+            (Component::Splits(component), ComponentSettings::Splits(settings)) => {
+                *component.settings_mut() = settings;
+            }
+            (Component::WorldRecord(component), ComponentSettings::WorldRecord(settings)) => {
+                *component.settings_mut() = settings;
+            }
+            // All the other components don't have any runtime state that
+            // would need to be kept.
+            (component, settings) => *component = settings.into(),
         }
     }
 
@@ -327,6 +408,7 @@ impl Component {
     pub fn name(&self) -> Cow<'_, str> {
         match self {
             Component::BlankSpace(component) => component.name().into(),
+            Component::Countdown(component) => component.name().into(),
             Component::CurrentComparison(component) => component.name().into(),
             Component::CurrentPace(component) => component.name(),
             Component::Delta(component) => component.name(),
@@ -336,6 +418,7 @@ impl Component {
             Component::PossibleTimeSave(component) => component.name(),
             Component::PreviousSegment(component) => component.name(),
             Component::SegmentTime(component) => component.name(),
+            Component::SegmentTracker(component) => component.name().into(),
             Component::Separator(component) => component.name().into(),
             Component::Splits(component) => component.name().into(),
             Component::SumOfBest(component) => component.name().into(),
@@ -343,6 +426,7 @@ impl Component {
             Component::Timer(component) => component.name().into(),
             Component::Title(component) => component.name().into(),
             Component::TotalPlaytime(component) => component.name().into(),
+            Component::WorldRecord(component) => component.name().into(),
         }
     }
 
@@ -369,6 +453,7 @@ impl Component {
     pub fn settings_description(&self) -> SettingsDescription {
         match self {
             Component::BlankSpace(component) => component.settings_description(),
+            Component::Countdown(component) => component.settings_description(),
             Component::CurrentComparison(component) => component.settings_description(),
             Component::CurrentPace(component) => component.settings_description(),
             Component::Delta(component) => component.settings_description(),
@@ -378,6 +463,7 @@ impl Component {
             Component::PossibleTimeSave(component) => component.settings_description(),
             Component::PreviousSegment(component) => component.settings_description(),
             Component::SegmentTime(component) => component.settings_description(),
+            Component::SegmentTracker(component) => component.settings_description(),
             Component::Separator(component) => component.settings_description(),
             Component::Splits(component) => component.settings_description(),
             Component::SumOfBest(component) => component.settings_description(),
@@ -385,6 +471,7 @@ impl Component {
             Component::Timer(component) => component.settings_description(),
             Component::Title(component) => component.settings_description(),
             Component::TotalPlaytime(component) => component.settings_description(),
+            Component::WorldRecord(component) => component.settings_description(),
         }
     }
 
@@ -399,6 +486,7 @@ impl Component {
     pub fn set_value(&mut self, index: usize, value: Value) {
         match self {
             Component::BlankSpace(component) => component.set_value(index, value),
+            Component::Countdown(component) => component.set_value(index, value),
             Component::CurrentComparison(component) => component.set_value(index, value),
             Component::CurrentPace(component) => component.set_value(index, value),
             Component::Delta(component) => component.set_value(index, value),
@@ -408,6 +496,7 @@ impl Component {
             Component::PossibleTimeSave(component) => component.set_value(index, value),
             Component::PreviousSegment(component) => component.set_value(index, value),
             Component::SegmentTime(component) => component.set_value(index, value),
+            Component::SegmentTracker(component) => component.set_value(index, value),
             Component::Separator(component) => component.set_value(index, value),
             Component::Splits(component) => component.set_value(index, value),
             Component::SumOfBest(component) => component.set_value(index, value),
@@ -415,6 +504,7 @@ impl Component {
             Component::Timer(component) => component.set_value(index, value),
             Component::Title(component) => component.set_value(index, value),
             Component::TotalPlaytime(component) => component.set_value(index, value),
+            Component::WorldRecord(component) => component.set_value(index, value),
         }
     }
 }
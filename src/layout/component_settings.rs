@@ -1,9 +1,9 @@
 use super::Component;
 use crate::{
     component::{
-        blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        blank_space, countdown, current_comparison, current_pace, delta, detailed_timer, graph,
+        pb_chance, possible_time_save, previous_segment, segment_time, segment_tracker, separator,
+        splits, sum_of_best, text, timer, title, total_playtime, world_record,
     },
     platform::prelude::*,
 };
@@ -14,6 +14,8 @@ use serde_derive::{Deserialize, Serialize};
 pub enum ComponentSettings {
     /// The Settings for the Blank Space Component.
     BlankSpace(blank_space::Settings),
+    /// The Settings for the Countdown Component.
+    Countdown(countdown::Settings),
     /// The Settings for the Current Comparison Component.
     CurrentComparison(current_comparison::Settings),
     /// The Settings for the Current Pace Component.
@@ -32,8 +34,10 @@ pub enum ComponentSettings {
     PreviousSegment(previous_segment::Settings),
     /// The Settings for the Segment Time Component.
     SegmentTime(segment_time::Settings),
+    /// The Settings for the Segment Tracker Component.
+    SegmentTracker(segment_tracker::Settings),
     /// The Settings for the Separator Component.
-    Separator,
+    Separator(separator::Settings),
     /// The Settings for the Splits Component.
     Splits(splits::Settings),
     /// The Settings for the Sum Of Best Component.
@@ -46,6 +50,8 @@ pub enum ComponentSettings {
     Title(title::Settings),
     /// The Settings for the Total Playtime Component.
     TotalPlaytime(total_playtime::Settings),
+    /// The Settings for the World Record Component.
+    WorldRecord(world_record::Settings),
 }
 
 impl From<ComponentSettings> for Component {
@@ -54,6 +60,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::BlankSpace(settings) => {
                 Component::BlankSpace(blank_space::Component::with_settings(settings))
             }
+            ComponentSettings::Countdown(settings) => {
+                Component::Countdown(countdown::Component::with_settings(settings))
+            }
             ComponentSettings::CurrentComparison(settings) => {
                 Component::CurrentComparison(current_comparison::Component::with_settings(settings))
             }
@@ -81,7 +90,12 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::SegmentTime(settings) => {
                 Component::SegmentTime(segment_time::Component::with_settings(settings))
             }
-            ComponentSettings::Separator => Component::Separator(separator::Component::new()),
+            ComponentSettings::SegmentTracker(settings) => {
+                Component::SegmentTracker(segment_tracker::Component::with_settings(settings))
+            }
+            ComponentSettings::Separator(settings) => {
+                Component::Separator(separator::Component::with_settings(settings))
+            }
             ComponentSettings::Splits(settings) => {
                 Component::Splits(splits::Component::with_settings(settings))
             }
@@ -100,6 +114,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::TotalPlaytime(settings) => {
                 Component::TotalPlaytime(total_playtime::Component::with_settings(settings))
             }
+            ComponentSettings::WorldRecord(settings) => {
+                Component::WorldRecord(world_record::Component::with_settings(settings))
+            }
         }
     }
 }
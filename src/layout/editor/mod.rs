@@ -4,14 +4,16 @@
 //! editor as state objects that can be visualized by any kind of User
 //! Interface.
 
-use super::{Component, Layout, LayoutState};
+use super::{Component, Layout, LayoutState, Theme};
 use crate::{
-    settings::{ImageCache, Value},
+    settings::{ImageCache, SettingsDescription, Value},
     timing::Snapshot,
 };
 use core::result::Result as StdResult;
 
 mod state;
+#[cfg(test)]
+mod tests;
 
 pub use self::state::{Buttons as ButtonsState, State};
 
@@ -35,6 +37,15 @@ pub enum Error {
 /// The Result type for the Layout Editor.
 pub type Result<T> = StdResult<T, Error>;
 
+/// Describes an Error that occurred while changing the value of a setting.
+#[derive(Debug, snafu::Snafu)]
+pub enum SetValueError {
+    /// There is no setting with the index provided.
+    SettingNotFound,
+    /// The type of the value is not compatible with the type of the setting.
+    WrongType,
+}
+
 impl Editor {
     /// Creates a new Layout Editor that modifies the Layout provided. Creation
     /// of the Layout Editor fails when a Layout with no components is provided.
@@ -97,6 +108,19 @@ impl Editor {
         }
     }
 
+    /// Returns the index of the currently selected component.
+    pub const fn selected_component(&self) -> usize {
+        self.selected_component
+    }
+
+    /// Provides a generic description of the settings available for the
+    /// selected component, their types and their current values. The index of
+    /// a setting in this description is the index to use for changing its
+    /// value.
+    pub fn component_settings(&self) -> SettingsDescription {
+        self.layout.components[self.selected_component].settings_description()
+    }
+
     /// Adds the component provided to the end of the layout. The newly added
     /// component becomes the selected component.
     pub fn add_component<C: Into<Component>>(&mut self, component: C) {
@@ -194,6 +218,25 @@ impl Editor {
         self.layout.components[self.selected_component].set_value(index, value);
     }
 
+    /// Sets a setting's value of the selected component by its setting index
+    /// to the given value. Unlike
+    /// [`set_component_settings_value`](Self::set_component_settings_value),
+    /// this checks whether the setting exists and whether the value is
+    /// compatible with the setting's type, so values coming from a user
+    /// interface can be applied without panicking. The value is converted into
+    /// the setting's type if necessary, such as a color that is used as a
+    /// gradient.
+    pub fn try_set_component_settings_value(
+        &mut self,
+        index: usize,
+        value: Value,
+    ) -> StdResult<(), SetValueError> {
+        let component = &mut self.layout.components[self.selected_component];
+        let value = setting_value(component.settings_description(), index, value)?;
+        component.set_value(index, value);
+        Ok(())
+    }
+
     /// Sets a setting's value of the general settings by its setting index to
     /// the given value.
     ///
@@ -212,4 +255,42 @@ impl Editor {
             .general_settings_mut()
             .set_value(index, value, image_cache);
     }
+
+    /// Applies the [`Theme`] provided to the layout. This replaces the
+    /// background and all the colors of the layout at once.
+    pub fn apply_theme(&mut self, theme: Theme) {
+        self.layout.general_settings_mut().apply_theme(theme);
+    }
+
+    /// Sets a setting's value of the general settings by its setting index to
+    /// the given value. Unlike
+    /// [`set_general_settings_value`](Self::set_general_settings_value), this
+    /// checks whether the setting exists and whether the value is compatible
+    /// with the setting's type, so values coming from a user interface can be
+    /// applied without panicking.
+    pub fn try_set_general_settings_value(
+        &mut self,
+        index: usize,
+        value: Value,
+        image_cache: &mut ImageCache,
+    ) -> StdResult<(), SetValueError> {
+        let settings = self.layout.general_settings_mut();
+        let value = setting_value(settings.settings_description(image_cache), index, value)?;
+        settings.set_value(index, value, image_cache);
+        Ok(())
+    }
+}
+
+fn setting_value(
+    settings: SettingsDescription,
+    index: usize,
+    value: Value,
+) -> StdResult<Value, SetValueError> {
+    let field = settings
+        .fields
+        .get(index)
+        .ok_or(SetValueError::SettingNotFound)?;
+    value
+        .into_type_of(&field.value)
+        .map_err(|_| SetValueError::WrongType)
 }
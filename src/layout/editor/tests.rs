@@ -0,0 +1,113 @@
+use super::{Editor, SetValueError};
+use crate::{
+    component::{separator, timer},
+    layout::{LayoutDirection, Theme},
+    settings::{Color, Font, Gradient, ImageCache, SemanticColor, Value},
+    Layout,
+};
+
+#[test]
+fn describes_the_selected_component() {
+    let mut editor = Editor::new(Layout::default_layout()).unwrap();
+    editor.select(2);
+    assert_eq!(editor.selected_component(), 2);
+
+    let settings = editor.component_settings();
+    assert_eq!(settings.fields[0].text, "Background");
+    assert!(matches!(settings.fields[0].value, Value::DeltaGradient(_)));
+}
+
+#[test]
+fn checks_the_values_being_set() {
+    let mut editor = Editor::new(Layout::default_layout()).unwrap();
+    editor.select(2);
+
+    let index = editor
+        .component_settings()
+        .fields
+        .iter()
+        .position(|field| field.text == "Height")
+        .unwrap();
+    assert!(matches!(
+        editor.try_set_component_settings_value(index, Value::Bool(true)),
+        Err(SetValueError::WrongType)
+    ));
+    assert!(matches!(
+        editor.try_set_component_settings_value(1000, Value::UInt(5)),
+        Err(SetValueError::SettingNotFound)
+    ));
+    editor
+        .try_set_component_settings_value(index, Value::UInt(90))
+        .unwrap();
+    assert!(editor.component_settings().fields[index].value == Value::UInt(90));
+
+    // Colors can be used as gradients.
+    editor
+        .try_set_component_settings_value(0, Value::Color(Color::black()))
+        .unwrap();
+    let layout = editor.close();
+    let crate::layout::Component::Timer(timer) = &layout.components[2] else {
+        panic!("The timer should still be the third component");
+    };
+    assert!(timer.settings().background == Gradient::Plain(Color::black()).into());
+}
+
+#[test]
+fn checks_the_general_settings_being_set() {
+    let mut image_cache = ImageCache::new();
+    let mut editor = Editor::new(Layout::default_layout()).unwrap();
+
+    assert!(matches!(
+        editor.try_set_general_settings_value(0, Value::String("Up".into()), &mut image_cache),
+        Err(SetValueError::WrongType)
+    ));
+    editor
+        .try_set_general_settings_value(
+            0,
+            Value::LayoutDirection(LayoutDirection::Horizontal),
+            &mut image_cache,
+        )
+        .unwrap();
+    assert!(editor.close().general_settings().direction == LayoutDirection::Horizontal);
+}
+
+#[test]
+fn applies_themes() {
+    let mut layout = Layout::default_layout();
+    layout.general_settings_mut().direction = LayoutDirection::Horizontal;
+    layout.general_settings_mut().text_font = Some(Font::default());
+    let mut editor = Editor::new(layout).unwrap();
+
+    editor.apply_theme(Theme::light());
+    let layout = editor.close();
+    let settings = layout.general_settings();
+    assert!(settings.theme() == Theme::light());
+    assert!(settings.direction == LayoutDirection::Horizontal);
+    assert!(settings.text_font.is_some());
+    assert!(
+        SemanticColor::PersonalBest.visualize(settings)
+            == Theme::light().visualize(SemanticColor::PersonalBest)
+    );
+    assert!(Theme::default() == Layout::default_layout().general_settings().theme());
+}
+
+#[test]
+fn reorders_and_duplicates_components() {
+    let mut layout = Layout::new();
+    layout.push(timer::Component::new());
+    layout.push(separator::Component::new());
+    let mut editor = Editor::new(layout).unwrap();
+
+    editor.duplicate_component();
+    assert_eq!(editor.selected_component(), 1);
+    editor.move_component(2);
+    assert_eq!(editor.selected_component(), 2);
+
+    let names = editor
+        .close()
+        .components
+        .iter()
+        .map(|c| c.name().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Timer", "Separator", "Timer"]);
+}
@@ -1,9 +1,11 @@
-use super::LayoutDirection;
+use super::{LayoutDirection, Theme};
 use crate::{
+    analysis::SplitColoring,
     platform::prelude::*,
     settings::{
         Color, Field, Font, Gradient, ImageCache, LayoutBackground, SettingsDescription, Value,
     },
+    TimeSpan,
 };
 use serde_derive::{Deserialize, Serialize};
 
@@ -50,6 +52,15 @@ pub struct GeneralSettings {
     pub separators_color: Color,
     /// The text color to use for text that doesn't specify its own color.
     pub text_color: Color,
+    /// Specifies whether the gaining time and losing time colors are chosen
+    /// based on how much the delta changed compared to the previous split. If
+    /// this is disabled, only the ahead gaining time and behind losing time
+    /// colors are used.
+    pub color_by_segment_delta: bool,
+    /// Deltas and changes of the deltas that don't exceed this threshold are
+    /// considered to be even, so they don't count as being ahead, behind,
+    /// gaining or losing time.
+    pub delta_color_threshold: TimeSpan,
 }
 
 impl Default for GeneralSettings {
@@ -73,11 +84,57 @@ impl Default for GeneralSettings {
             thin_separators_color: Color::hsla(0.0, 0.0, 1.0, 0.09),
             separators_color: Color::hsla(0.0, 0.0, 1.0, 0.35),
             text_color: Color::hsla(0.0, 0.0, 1.0, 1.0),
+            color_by_segment_delta: true,
+            delta_color_threshold: TimeSpan::zero(),
         }
     }
 }
 
 impl GeneralSettings {
+    /// Extracts the background and the colors of the layout as a [`Theme`].
+    pub fn theme(&self) -> Theme {
+        Theme {
+            background: self.background.clone(),
+            best_segment_color: self.best_segment_color,
+            ahead_gaining_time_color: self.ahead_gaining_time_color,
+            ahead_losing_time_color: self.ahead_losing_time_color,
+            behind_gaining_time_color: self.behind_gaining_time_color,
+            behind_losing_time_color: self.behind_losing_time_color,
+            not_running_color: self.not_running_color,
+            personal_best_color: self.personal_best_color,
+            paused_color: self.paused_color,
+            thin_separators_color: self.thin_separators_color,
+            separators_color: self.separators_color,
+            text_color: self.text_color,
+        }
+    }
+
+    /// Replaces the background and all the colors of the layout with the ones
+    /// of the [`Theme`] provided. The direction, the fonts and the way deltas
+    /// are colored are kept.
+    pub fn apply_theme(&mut self, theme: Theme) {
+        self.background = theme.background;
+        self.best_segment_color = theme.best_segment_color;
+        self.ahead_gaining_time_color = theme.ahead_gaining_time_color;
+        self.ahead_losing_time_color = theme.ahead_losing_time_color;
+        self.behind_gaining_time_color = theme.behind_gaining_time_color;
+        self.behind_losing_time_color = theme.behind_losing_time_color;
+        self.not_running_color = theme.not_running_color;
+        self.personal_best_color = theme.personal_best_color;
+        self.paused_color = theme.paused_color;
+        self.thin_separators_color = theme.thin_separators_color;
+        self.separators_color = theme.separators_color;
+        self.text_color = theme.text_color;
+    }
+
+    /// Returns how the deltas should be colored according to these settings.
+    pub const fn split_coloring(&self) -> SplitColoring {
+        SplitColoring {
+            segment_deltas: self.color_by_segment_delta,
+            threshold: self.delta_color_threshold,
+        }
+    }
+
     /// Accesses a generic description of the general settings available for the
     /// layout and their current values. The [`ImageCache`] is updated with all
     /// the images that are part of the state. The images are marked as visited
@@ -173,6 +230,18 @@ impl GeneralSettings {
                 "The color to use for text that doesn't specify its own color.".into(),
                 self.text_color.into(),
             ),
+            Field::new(
+                "Color by Segment Delta".into(),
+                "Specifies whether the colors distinguish between gaining and losing time, based on how much the delta changed compared to the previous split. If this is disabled, only the colors for being ahead and gaining time and being behind and losing time are used."
+                    .into(),
+                self.color_by_segment_delta.into(),
+            ),
+            Field::new(
+                "Delta Color Threshold".into(),
+                "The amount of milliseconds a delta or its change compared to the previous split needs to exceed in order to be colored. Anything smaller is considered to be even."
+                    .into(),
+                ((self.delta_color_threshold.total_milliseconds() + 0.5) as u64).into(),
+            ),
         ])
     }
 
@@ -201,6 +270,11 @@ impl GeneralSettings {
             13 => self.thin_separators_color = value.into(),
             14 => self.separators_color = value.into(),
             15 => self.text_color = value.into(),
+            16 => self.color_by_segment_delta = value.into(),
+            17 => {
+                self.delta_color_threshold =
+                    TimeSpan::from_milliseconds(value.into_uint().unwrap() as f64)
+            }
             _ => panic!("Unsupported Setting Index"),
         }
     }
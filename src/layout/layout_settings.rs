@@ -12,21 +12,91 @@ pub struct LayoutSettings {
     pub general: GeneralSettings,
 }
 
+/// The version of the JSON format that is written by
+/// [`LayoutSettings::write_json`]. It only gets increased for changes that
+/// older versions of livesplit-core can't make sense of. New components and new
+/// settings get added without changing the version.
+#[cfg(feature = "std")]
+const VERSION: u32 = 1;
+
+#[cfg(feature = "std")]
+#[derive(Serialize)]
+struct VersionedLayoutSettings<'a> {
+    version: u32,
+    components: &'a [ComponentSettings],
+    general: &'a GeneralSettings,
+}
+
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+struct VersionedJsonLayoutSettings {
+    // Layouts stored before the format was versioned don't have a version.
+    #[serde(default)]
+    version: u32,
+    components: Vec<serde_json::Value>,
+    #[serde(default)]
+    general: GeneralSettings,
+}
+
 #[cfg(feature = "std")]
 impl LayoutSettings {
-    /// Decodes the layout's settings from JSON.
+    /// Decodes the layout's settings from JSON. Both the versioned format
+    /// written by [`write_json`](Self::write_json) and the unversioned format
+    /// of older versions of livesplit-core are supported. Layouts written by a
+    /// newer version of the format are rejected. Components that can't be
+    /// decoded, such as components that were added in a newer version of
+    /// livesplit-core, are skipped and unknown settings are ignored, so layouts
+    /// can be shared between different versions of livesplit-core.
     pub fn from_json<R>(reader: R) -> serde_json::Result<LayoutSettings>
     where
         R: std::io::Read,
     {
-        serde_json::from_reader(reader)
+        let settings: VersionedJsonLayoutSettings = serde_json::from_reader(reader)?;
+
+        if settings.version > VERSION {
+            return Err(serde::de::Error::custom(format_args!(
+                "unsupported layout version {}",
+                settings.version
+            )));
+        }
+
+        Ok(LayoutSettings {
+            components: settings
+                .components
+                .into_iter()
+                .filter_map(|component| {
+                    let component = match component {
+                        // Components without any settings used to be stored
+                        // as just their name.
+                        serde_json::Value::String(name) => serde_json::Value::Object(
+                            [(name, serde_json::Value::Object(Default::default()))]
+                                .into_iter()
+                                .collect(),
+                        ),
+                        component => component,
+                    };
+                    serde_json::from_value(component).ok()
+                })
+                .collect(),
+            general: settings.general,
+        })
     }
 
-    /// Encodes the layout's settings as JSON.
+    /// Encodes the layout's settings as JSON. The JSON object stores the
+    /// `version` of the format next to the `components` and the `general`
+    /// settings. The format stays compatible across versions of
+    /// livesplit-core, so it is suitable for storing layouts persistently.
     pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
     where
         W: std::io::Write,
     {
-        serde_json::to_writer(writer, self)
+        serde_json::to_writer(
+            writer,
+            &VersionedLayoutSettings {
+                version: VERSION,
+                components: &self.components,
+                general: &self.general,
+            },
+        )
     }
 }
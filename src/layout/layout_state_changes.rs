@@ -0,0 +1,200 @@
+use serde_derive::Serialize;
+use serde_json::{Map, Value};
+
+use super::{LayoutDirection, LayoutState};
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Font, ImageId, LayoutBackground},
+};
+
+/// Tracks the changes of a [`LayoutState`] between updates. Frontends that
+/// update the same [`LayoutState`] every frame can use this to only apply the
+/// parts of the state that actually changed, instead of visualizing the whole
+/// layout again. After the state got updated, the changes are determined by
+/// calling [`update`](Self::update). On the first update, everything is
+/// considered changed.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::layout::{Layout, LayoutState, LayoutStateChanges};
+/// use livesplit_core::settings::ImageCache;
+/// use livesplit_core::{Run, Segment, Timer};
+///
+/// let mut run = Run::new();
+/// run.push_segment(Segment::new("Cap Kingdom"));
+/// let timer = Timer::new(run).unwrap();
+///
+/// let mut layout = Layout::default_layout();
+/// let mut state = LayoutState::default();
+/// let mut image_cache = ImageCache::new();
+/// let mut changes = LayoutStateChanges::new();
+///
+/// layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+/// changes.update(&state);
+/// assert!(changes.component_changed(0));
+///
+/// // Nothing changes while the timer is not running.
+/// layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+/// changes.update(&state);
+/// assert!(!changes.has_changed());
+/// ```
+#[derive(Default)]
+pub struct LayoutStateChanges {
+    components: Vec<Value>,
+    component_changes: Vec<Option<Value>>,
+    general: Map<String, Value>,
+    general_changes: Map<String, Value>,
+}
+
+/// The parts of the layout state that don't belong to any component.
+#[derive(Serialize)]
+struct General<'a> {
+    direction: LayoutDirection,
+    timer_font: &'a Option<Font>,
+    times_font: &'a Option<Font>,
+    text_font: &'a Option<Font>,
+    background: &'a LayoutBackground<ImageId>,
+    thin_separators_color: Color,
+    separators_color: Color,
+    text_color: Color,
+}
+
+/// Serializes the state as JSON. This can only fail for maps with keys that
+/// aren't strings, which the state objects don't have.
+fn to_value<T: serde::Serialize>(state: &T) -> Value {
+    serde_json::to_value(state).unwrap_or_default()
+}
+
+/// Collects the fields of the new object that differ from the old object.
+fn diff_fields(old: &Map<String, Value>, new: &Map<String, Value>) -> Map<String, Value> {
+    new.iter()
+        .filter(|&(key, value)| old.get(key) != Some(value))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+/// Determines the changes of a component's state. The component states are
+/// stored as objects with the kind of the component as their only key. If the
+/// kind of the component stayed the same, only the fields that changed are
+/// part of the changes. Otherwise the whole new state is.
+fn diff_component(old: &Value, new: &Value) -> Option<Value> {
+    if old == new {
+        return None;
+    }
+    if let (Some(old), Some(new)) = (old.as_object(), new.as_object()) {
+        if let (Some((old_kind, Value::Object(old))), Some((new_kind, Value::Object(new)))) =
+            (old.iter().next(), new.iter().next())
+        {
+            if old_kind == new_kind {
+                let mut changes = Map::new();
+                changes.insert(new_kind.clone(), Value::Object(diff_fields(old, new)));
+                return Some(Value::Object(changes));
+            }
+        }
+    }
+    Some(new.clone())
+}
+
+impl LayoutStateChanges {
+    /// Creates a new tracker for the changes of a [`LayoutState`]. The first
+    /// update considers everything as changed.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Forgets about the previous state, so the next update considers
+    /// everything as changed again. This is useful if the frontend needs to
+    /// visualize the whole layout again, for example because it got reloaded.
+    pub fn clear(&mut self) {
+        self.components.clear();
+        self.component_changes.clear();
+        self.general.clear();
+        self.general_changes.clear();
+    }
+
+    /// Determines the changes of the state provided compared to the state of
+    /// the previous update.
+    pub fn update(&mut self, state: &LayoutState) {
+        self.components.truncate(state.components.len());
+        self.component_changes.clear();
+
+        for (index, component) in state.components.iter().enumerate() {
+            let new = to_value(component);
+            let changes = if let Some(old) = self.components.get_mut(index) {
+                let changes = diff_component(old, &new);
+                *old = new;
+                changes
+            } else {
+                self.components.push(new.clone());
+                Some(new)
+            };
+            self.component_changes.push(changes);
+        }
+
+        let general = match to_value(&General {
+            direction: state.direction,
+            timer_font: &state.timer_font,
+            times_font: &state.times_font,
+            text_font: &state.text_font,
+            background: &state.background,
+            thin_separators_color: state.thin_separators_color,
+            separators_color: state.separators_color,
+            text_color: state.text_color,
+        }) {
+            Value::Object(general) => general,
+            _ => Map::new(),
+        };
+        self.general_changes = diff_fields(&self.general, &general);
+        self.general = general;
+    }
+
+    /// Returns whether anything changed in the last update.
+    pub fn has_changed(&self) -> bool {
+        !self.general_changes.is_empty() || self.component_changes.iter().any(Option::is_some)
+    }
+
+    /// Returns whether the state of the component with the given index changed
+    /// in the last update. Components that were added in the last update are
+    /// considered changed.
+    pub fn component_changed(&self, index: usize) -> bool {
+        matches!(self.component_changes.get(index), Some(Some(_)))
+    }
+
+    /// Returns whether any of the parts of the state that don't belong to any
+    /// component, such as the fonts and the background, changed in the last
+    /// update.
+    pub fn general_changed(&self) -> bool {
+        !self.general_changes.is_empty()
+    }
+}
+
+#[cfg(feature = "std")]
+impl LayoutStateChanges {
+    /// Encodes the changes of the last update as JSON. The JSON object contains
+    /// the `components` as a list with an element for each component of the
+    /// state. The element is `null` if the component didn't change. Otherwise
+    /// it is the component's state with only the fields that changed. If the
+    /// component is new or its kind changed, the whole state is stored. The
+    /// fields of the state that don't belong to any component are only stored
+    /// if they changed.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        #[derive(Serialize)]
+        struct Changes<'a> {
+            components: &'a [Option<Value>],
+            #[serde(flatten)]
+            general: &'a Map<String, Value>,
+        }
+
+        serde_json::to_writer(
+            writer,
+            &Changes {
+                components: &self.component_changes,
+                general: &self.general_changes,
+            },
+        )
+    }
+}
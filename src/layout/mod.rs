@@ -10,14 +10,23 @@ mod general_settings;
 mod layout_direction;
 mod layout_settings;
 mod layout_state;
+mod layout_state_changes;
 pub mod parser;
+#[cfg(feature = "std")]
+pub mod reloader;
+pub mod saver;
+mod theme;
 
 pub use self::{
     component::Component, component_settings::ComponentSettings, component_state::ComponentState,
     editor::Editor, general_settings::GeneralSettings, layout_direction::LayoutDirection,
     layout_settings::LayoutSettings, layout_state::LayoutState,
+    layout_state_changes::LayoutStateChanges, theme::Theme,
 };
 
+#[cfg(feature = "std")]
+pub use self::reloader::Reloader;
+
 use crate::{
     component::{previous_segment, splits, timer, title},
     platform::prelude::*,
@@ -25,6 +34,11 @@ use crate::{
     timing::Snapshot,
 };
 
+/// A `SharedLayout` is a wrapper around the [`Layout`] that can be shared across
+/// multiple threads with multiple owners.
+#[cfg(feature = "std")]
+pub type SharedLayout = alloc::sync::Arc<std::sync::RwLock<Layout>>;
+
 /// A Layout allows you to combine multiple components together to visualize a
 /// variety of information the runner is interested in.
 #[derive(Clone, Default)]
@@ -68,6 +82,27 @@ impl Layout {
         }
     }
 
+    /// Creates an independent copy of the layout. Unlike [`Clone`], which also
+    /// copies the runtime state of the components, such as the scroll position
+    /// of the splits, the copy is created purely from the settings of the
+    /// layout, as if it was freshly loaded. All the settings, including the
+    /// fonts, are deeply copied, so modifying the copy never affects the
+    /// original layout. Neither the layout nor its components carry any
+    /// identifiers of their own, so there are no IDs that need to be remapped.
+    /// The only identifiers are those of the images, which are immutable and
+    /// identified by the hash of their contents. They therefore intentionally
+    /// keep their IDs and are shared between both layouts.
+    pub fn duplicate(&self) -> Self {
+        Self::from_settings(self.settings())
+    }
+
+    /// Consumes the layout and creates a Shared Layout that can be shared
+    /// across multiple threads with multiple owners.
+    #[cfg(feature = "std")]
+    pub fn into_shared(self) -> SharedLayout {
+        alloc::sync::Arc::new(std::sync::RwLock::new(self))
+    }
+
     /// Accesses the general settings of the layout that apply to all
     /// components.
     pub const fn general_settings(&self) -> &GeneralSettings {
@@ -90,7 +125,8 @@ impl Layout {
     /// updated with all the images that are part of the state. The images are
     /// marked as visited in the [`ImageCache`]. You still need to manually run
     /// [`ImageCache::collect`] to ensure unused images are removed from the
-    /// cache.
+    /// cache. Use [`LayoutStateChanges`] to determine which parts of the state
+    /// changed.
     pub fn update_state(
         &mut self,
         state: &mut LayoutState,
@@ -141,6 +177,22 @@ impl Layout {
         }
     }
 
+    /// Applies the settings of a whole layout to this layout. Unlike creating
+    /// a new layout from the settings, the runtime state of the components,
+    /// such as the scroll position of the splits, is kept for all the
+    /// components that are of the same kind as the component previously at the
+    /// same position. This is useful for reloading a layout that got modified
+    /// while it is in use.
+    pub fn apply_settings(&mut self, layout_settings: LayoutSettings) {
+        let mut settings = layout_settings.components.into_iter();
+        self.components.truncate(settings.len());
+        for (component, settings) in self.components.iter_mut().zip(settings.by_ref()) {
+            component.apply_settings(settings);
+        }
+        self.components.extend(settings.map(Component::from));
+        self.settings = layout_settings.general;
+    }
+
     /// Scrolls up all the components in the layout that can be scrolled up.
     pub fn scroll_up(&mut self) {
         for component in &mut self.components {
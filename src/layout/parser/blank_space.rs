@@ -1,4 +1,4 @@
-use super::{translate_size, Error, GradientBuilder, Result};
+use super::{translate_size, Error, GradientBuilder, LayoutDirection, Result};
 use crate::util::xml::{
     helper::{end_tag, parse_children, text_parsed},
     Reader,
@@ -6,17 +6,26 @@ use crate::util::xml::{
 
 pub use crate::component::blank_space::Component;
 
-pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()> {
+pub fn settings(
+    reader: &mut Reader<'_>,
+    component: &mut Component,
+    direction: LayoutDirection,
+) -> Result<()> {
     let settings = component.settings_mut();
     let mut background_builder = GradientBuilder::new();
 
+    // The original LiveSplit stores separate sizes for vertical and horizontal
+    // layouts, so we only use the one that matches the layout's direction.
+    let size_tag = match direction {
+        LayoutDirection::Vertical => "SpaceHeight",
+        LayoutDirection::Horizontal => "SpaceWidth",
+    };
+
     parse_children::<_, Error>(reader, |reader, tag, _| {
         if !background_builder.parse_background(reader, tag.name())? {
-            if tag.name() == "SpaceHeight" {
-                text_parsed(reader, |h| settings.size = translate_size(h))
+            if tag.name() == size_tag {
+                text_parsed(reader, |size| settings.size = translate_size(size))
             } else {
-                // FIXME:
-                // SpaceWidth
                 end_tag(reader)
             }
         } else {
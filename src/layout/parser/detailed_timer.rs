@@ -1,9 +1,9 @@
 use super::{
-    accuracy, color, comparison_override, end_tag, parse_bool, parse_children, text_parsed,
-    timer_format, timing_method_override, translate_size, DeltaGradientKind, GradientBuilder,
-    Result,
+    accuracy, color, comparison_override, end_tag, font, icon_size, parse_bool, parse_children,
+    text_parsed, timer_format, timing_method_override, translate_size, DeltaGradientKind,
+    GradientBuilder, Result,
 };
-use crate::{timing::formatter::DigitsFormat, util::xml::Reader};
+use crate::{platform::prelude::*, timing::formatter::DigitsFormat, util::xml::Reader};
 
 pub use crate::component::detailed_timer::Component;
 
@@ -12,6 +12,7 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
     let mut background_builder = GradientBuilder::<DeltaGradientKind>::new_gradient_type();
     let mut timer_override_color = false;
     let (mut total_height, mut segment_timer_ratio) = (65u32, 0.4);
+    let (mut split_name_font, mut font_buf) = (None, Vec::new());
 
     parse_children(reader, |reader, tag, _| {
         if !background_builder.parse_background(reader, tag.name())? {
@@ -68,6 +69,7 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                 }
                 "SegmentTimesColor" => color(reader, |v| settings.comparison_times_color = Some(v)),
                 "DisplayIcon" => parse_bool(reader, |b| settings.display_icon = b),
+                "IconSize" => icon_size(reader, |v| settings.icon_size = v),
                 "ShowSplitName" => parse_bool(reader, |b| settings.show_segment_name = b),
                 "SplitNameColor" => color(reader, |v| settings.segment_name_color = Some(v)),
                 "Comparison" => comparison_override(reader, |v| settings.comparison1 = v),
@@ -76,13 +78,24 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                 "TimingMethod" => {
                     timing_method_override(reader, |v| settings.timer.timing_method = v)
                 }
+                "SegmentTimesFont" => font(reader, &mut font_buf, |font| {
+                    if font.family != "Segoe UI" {
+                        settings.times_font = Some(font);
+                    }
+                }),
+                "SegmentLabelsFont" => font(reader, &mut font_buf, |font| {
+                    if font.family != "Segoe UI" {
+                        settings.text_font = Some(font);
+                    }
+                }),
+                "SplitNameFont" => font(reader, &mut font_buf, |font| {
+                    if font.family != "Segoe UI" {
+                        split_name_font = Some(font);
+                    }
+                }),
                 _ => {
                     // FIXME:
                     // Width
-                    // SegmentLabelsFont
-                    // SegmentTimesFont
-                    // SplitNameFont
-                    // IconSize
                     // DecimalsSize
                     // SegmentTimerDecimalsSize
                     end_tag(reader)
@@ -97,6 +110,11 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
         settings.timer.color_override = None;
     }
     settings.background = background_builder.build();
+    // There's only a single font for all the text of the component, so the
+    // font of the segment name is only used if the labels use the default font.
+    if settings.text_font.is_none() {
+        settings.text_font = split_name_font;
+    }
 
     settings.segment_timer.height = (total_height as f32 * segment_timer_ratio + 0.5) as u32;
     settings.timer.height = total_height - settings.segment_timer.height;
@@ -1,12 +1,30 @@
 //! Provides the parser for layout files of the original LiveSplit.
+//!
+//! The components of the original LiveSplit are mapped onto the equivalent
+//! components of livesplit-core. Components without an equivalent, such as
+//! auto splitters, are skipped and so are settings that livesplit-core doesn't
+//! support. The layouts can be saved in the same format again with the
+//! [`saver`](super::saver).
+//!
+//! # Examples
+//!
+//! Parsing a layout file of the original LiveSplit.
+//!
+//! ```no_run
+//! use livesplit_core::layout::parser;
+//! use std::fs;
+//!
+//! let source = fs::read_to_string("path/to/layout_file.lsl").expect("Failed reading the file");
+//! let layout = parser::parse(&source).expect("Not a valid layout file");
+//! ```
 
 use super::{Component, Layout, LayoutDirection};
 use crate::{
     component::{separator, timer::DeltaGradient},
     platform::{math::f32::powf, prelude::*},
     settings::{
-        Alignment, BackgroundImage, Color, Font, FontStretch, FontStyle, FontWeight, Gradient,
-        Image, LayoutBackground, ListGradient,
+        Alignment, BackgroundImage, BackgroundImageFit, Color, Font, FontStretch, FontStyle,
+        FontWeight, Gradient, Image, LayoutBackground, ListGradient,
     },
     timing::{
         formatter::{Accuracy, DigitsFormat},
@@ -19,6 +37,7 @@ use crate::{
         },
         Reader,
     },
+    Run,
 };
 use core::{mem::MaybeUninit, num::ParseIntError, str};
 
@@ -37,6 +56,7 @@ mod text;
 mod timer;
 mod title;
 mod total_playtime;
+mod world_record;
 
 #[cfg(all(windows, feature = "std"))]
 mod font_resolving;
@@ -51,6 +71,18 @@ fn translate_size(v: u32) -> u32 {
     (v as f32 * PIXEL_SPACE_RATIO + 0.5) as u32
 }
 
+fn icon_size<F>(reader: &mut Reader<'_>, f: F) -> Result<()>
+where
+    F: FnOnce(u32),
+{
+    // The original LiveSplit stores the icon sizes as floating point numbers.
+    text(reader, |v| {
+        if let Ok(v) = v.parse::<f32>() {
+            f((v * PIXEL_SPACE_RATIO + 0.5) as u32);
+        }
+    })
+}
+
 /// The Error type for parsing layout files of the original LiveSplit.
 #[derive(Debug, snafu::Snafu)]
 pub enum Error {
@@ -625,7 +657,7 @@ where
     })
 }
 
-fn component<F>(reader: &mut Reader<'_>, f: F) -> Result<()>
+fn component<F>(reader: &mut Reader<'_>, direction: LayoutDirection, f: F) -> Result<()>
 where
     F: FnOnce(Component),
 {
@@ -649,14 +681,18 @@ where
                     "LiveSplit.PossibleTimeSave.dll" => possible_time_save::Component::new().into(),
                     "LiveSplit.PreviousSegment.dll" => previous_segment::Component::new().into(),
                     "" => separator::Component::new().into(),
-                    "LiveSplit.Splits.dll" | "LiveSplit.Subsplits.dll" => {
-                        splits::Component::new().into()
+                    "LiveSplit.Splits.dll" => splits::Component::new().into(),
+                    "LiveSplit.Subsplits.dll" => {
+                        let mut component = splits::Component::new();
+                        component.settings_mut().group_subsplits = true;
+                        component.into()
                     }
                     "LiveSplit.SumOfBest.dll" => sum_of_best::Component::new().into(),
                     "LiveSplit.Text.dll" => text::Component::new().into(),
                     "LiveSplit.Timer.dll" => timer::Component::new().into(),
                     "LiveSplit.Title.dll" => title::Component::new().into(),
                     "LiveSplit.TotalPlaytime.dll" => total_playtime::Component::new().into(),
+                    "LiveSplit.WorldRecord.dll" => world_record::Component::new().into(),
                     _ => return Ok(()),
                 });
                 Ok(())
@@ -666,7 +702,7 @@ where
                 // Otherwise we need to cache the settings and load them later.
                 if let Some(component) = &mut component {
                     match component {
-                        Component::BlankSpace(c) => blank_space::settings(reader, c),
+                        Component::BlankSpace(c) => blank_space::settings(reader, c, direction),
                         Component::CurrentComparison(c) => current_comparison::settings(reader, c),
                         Component::CurrentPace(c) => current_pace::settings(reader, c),
                         Component::Delta(c) => delta::settings(reader, c),
@@ -675,7 +711,9 @@ where
                         Component::PbChance(c) => pb_chance::settings(reader, c),
                         Component::PossibleTimeSave(c) => possible_time_save::settings(reader, c),
                         Component::PreviousSegment(c) => previous_segment::settings(reader, c),
-                        Component::SegmentTime(_) => end_tag(reader),
+                        Component::Countdown(_)
+                        | Component::SegmentTime(_)
+                        | Component::SegmentTracker(_) => end_tag(reader),
                         Component::Separator(_) => end_tag(reader),
                         Component::Splits(c) => splits::settings(reader, c),
                         Component::SumOfBest(c) => sum_of_best::settings(reader, c),
@@ -683,6 +721,7 @@ where
                         Component::Timer(c) => timer::settings(reader, c),
                         Component::Title(c) => title::settings(reader, c),
                         Component::TotalPlaytime(c) => total_playtime::settings(reader, c),
+                        Component::WorldRecord(c) => world_record::settings(reader, c),
                     }
                 } else {
                     end_tag(reader)
@@ -793,6 +832,7 @@ fn parse_general_settings(layout: &mut Layout, reader: &mut Reader<'_>) -> Resul
                 brightness: image_opacity,
                 opacity: 1.0,
                 blur: image_blur,
+                fit: BackgroundImageFit::Cover,
             }),
             None => return Err(Error::MissingBackgroundImage),
         },
@@ -820,11 +860,15 @@ pub fn parse(source: &str) -> Result<Layout> {
                 Ok(())
             }),
             "Settings" => parse_general_settings(&mut layout, reader),
-            "Components" => parse_children(reader, |reader, _, _| {
-                component(reader, |c| {
-                    layout.push(c);
+            "Components" => {
+                // The layout direction is stored in front of the components.
+                let direction = layout.general_settings().direction;
+                parse_children(reader, |reader, _, _| {
+                    component(reader, direction, |c| {
+                        layout.push(c);
+                    })
                 })
-            }),
+            }
             _ => end_tag(reader),
         })
     })?;
@@ -835,3 +879,13 @@ pub fn parse(source: &str) -> Result<Layout> {
         Ok(layout)
     }
 }
+
+/// Attempts to parse the layout that is embedded in the splits file of the
+/// [`Run`]. Returns [`None`] if the [`Run`] doesn't have an embedded layout.
+pub fn parse_embedded(run: &Run) -> Result<Option<Layout>> {
+    let layout = run.embedded_layout();
+    if layout.is_empty() {
+        return Ok(None);
+    }
+    parse(layout).map(Some)
+}
@@ -23,11 +23,8 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                 "ShowPossibleTimeSave" => {
                     parse_bool(reader, |b| settings.show_possible_time_save = b)
                 }
-                _ => {
-                    // FIXME:
-                    // TimeSaveAccuracy
-                    end_tag(reader)
-                }
+                "TimeSaveAccuracy" => accuracy(reader, |v| settings.time_save_accuracy = v),
+                _ => end_tag(reader),
             }
         } else {
             Ok(())
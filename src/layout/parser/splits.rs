@@ -1,6 +1,7 @@
 use super::{
-    accuracy, comparison_override, end_tag, parse_bool, parse_children, text, text_parsed,
-    timing_method_override, Error, GradientBuilder, GradientKind, ListGradientKind, Result,
+    accuracy, comparison_override, end_tag, icon_size, parse_bool, parse_children, text,
+    text_parsed, timing_method_override, Error, GradientBuilder, GradientKind, ListGradientKind,
+    Result,
 };
 use crate::{
     component::splits::{
@@ -42,6 +43,7 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                     }
                     "Display2Rows" => parse_bool(reader, |b| settings.display_two_rows = b),
                     "ShowColumnLabels" => parse_bool(reader, |b| settings.show_column_labels = b),
+                    "IconSize" => icon_size(reader, |v| settings.icon_size = v),
                     "Columns" => {
                         // Version >= 1.5
                         settings.columns.clear();
@@ -176,14 +178,14 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                         // AfterTimesColor
                         // OverrideTimesColor
                         // LockLastSplit
-                        // IconSize
                         // IconShadows
                         // SplitHeight
                         // OverrideDeltasColor
                         // DeltasColor
                         // LabelsColor
 
-                        // FIXME: Subsplits
+                        // FIXME: Subsplits, which are grouped into sections,
+                        // but the sections can't be configured any further.
                         // MinimumMajorSplits
                         // IndentBlankIcons
                         // IndentSubsplits
@@ -1,15 +1,16 @@
 use super::{
-    color, end_tag, parse_bool, parse_children, text_as_escaped_string_err, Alignment, Error,
+    color, end_tag, font, parse_bool, parse_children, text_as_escaped_string_err, Alignment, Error,
     GradientBuilder, Result,
 };
 
 pub use crate::component::title::Component;
-use crate::util::xml::Reader;
+use crate::{platform::prelude::*, util::xml::Reader};
 
 pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()> {
     let settings = component.settings_mut();
     let mut background_builder = GradientBuilder::new();
     let mut override_title_color = false;
+    let (mut override_title_font, mut title_font, mut font_buf) = (false, None, Vec::new());
 
     parse_children(reader, |reader, tag, _| {
         if !background_builder.parse_background(reader, tag.name())? {
@@ -49,13 +50,19 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                 "ShowRegion" => parse_bool(reader, |b| settings.show_region = b),
                 "ShowPlatform" => parse_bool(reader, |b| settings.show_platform = b),
                 "ShowVariables" => parse_bool(reader, |b| settings.show_variables = b),
-                _ => {
-                    // FIXME:
-                    // OverrideTitleFont // Version >= 1.3
-                    // TitleFont // Version >= 1.2
-                    // UseLayoutSettingsFont // Version >= 1.2 && Version < 1.3
-                    end_tag(reader)
+                "OverrideTitleFont" => {
+                    // Version >= 1.3
+                    parse_bool(reader, |b| override_title_font = b)
                 }
+                "UseLayoutSettingsFont" => {
+                    // Version >= 1.2 && Version < 1.3
+                    parse_bool(reader, |b| override_title_font = !b)
+                }
+                "TitleFont" => {
+                    // Version >= 1.2
+                    font(reader, &mut font_buf, |f| title_font = Some(f))
+                }
+                _ => end_tag(reader),
             }
         } else {
             Ok(())
@@ -65,6 +72,9 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
     if !override_title_color {
         settings.text_color = None;
     }
+    if override_title_font {
+        settings.text_font = title_font;
+    }
     settings.background = background_builder.build();
 
     Ok(())
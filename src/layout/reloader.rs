@@ -0,0 +1,123 @@
+//! The reloader module provides a [`Reloader`] that watches a layout file and
+//! reloads the layout whenever the file gets modified. This allows tweaking a
+//! layout in an external editor while it is in use.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use livesplit_core::layout::{Layout, Reloader};
+//!
+//! let layout = Layout::default_layout().into_shared();
+//! let mut reloader = Reloader::new("layout.ls1l");
+//!
+//! loop {
+//!     // Applies the layout file to the layout if it got modified.
+//!     reloader.reload_if_changed(&layout).ok();
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//! #   break;
+//! }
+//! ```
+
+use super::{parser, LayoutSettings, SharedLayout};
+use core::result::Result as StdResult;
+use snafu::ResultExt;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Describes an Error that occurred while reloading a layout file.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to read the layout file.
+    Io {
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// Failed to parse the layout file as the settings of a layout.
+    Json {
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// Failed to parse the layout file as a layout of the original LiveSplit.
+    LiveSplit {
+        /// The underlying error.
+        source: parser::Error,
+    },
+}
+
+/// The Result type for the Reloader.
+pub type Result<T> = StdResult<T, Error>;
+
+/// The Reloader watches a layout file for modifications. Both the JSON format
+/// of the settings of a layout and the layout files of the original LiveSplit
+/// are supported. The file isn't watched in the background. Instead you need to
+/// regularly check for modifications, such as once a second. Checking is cheap
+/// as long as the file didn't get modified, as only its metadata is queried
+/// then.
+pub struct Reloader {
+    path: PathBuf,
+    modified: Option<(SystemTime, u64)>,
+}
+
+impl Reloader {
+    /// Creates a new Reloader that watches the layout file at the path
+    /// provided. The file in its current state is assumed to already be loaded,
+    /// so only later modifications cause the layout to be reloaded.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        let path = path.into();
+        let modified = modified(&path).ok();
+        Self { path, modified }
+    }
+
+    /// Accesses the path of the layout file that is being watched.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Checks whether the layout file got modified since it was last loaded.
+    /// If that's the case, the file is parsed and the settings of the layout
+    /// are returned. Otherwise [`None`] is returned. If the file can't be
+    /// parsed, for example because it is only partially written, an error is
+    /// returned and the file is checked again on the next call.
+    pub fn check_for_changes(&mut self) -> Result<Option<LayoutSettings>> {
+        let modified = modified(&self.path).context(Io)?;
+        if self.modified == Some(modified) {
+            return Ok(None);
+        }
+
+        let source = fs::read_to_string(&self.path).context(Io)?;
+        let settings = if source.trim_start().starts_with('{') {
+            LayoutSettings::from_json(source.as_bytes()).context(Json)?
+        } else {
+            parser::parse(&source).context(LiveSplit)?.settings()
+        };
+
+        self.modified = Some(modified);
+        Ok(Some(settings))
+    }
+
+    /// Reloads the layout provided if the layout file got modified since it
+    /// was last loaded. The file is parsed before the layout gets locked, so
+    /// the layout is only locked for a short moment while the new settings get
+    /// applied. The runtime state of the components, such as the scroll
+    /// position of the splits, is kept, as described by
+    /// [`Layout::apply_settings`](super::Layout::apply_settings). Returns
+    /// whether the layout got reloaded.
+    pub fn reload_if_changed(&mut self, layout: &SharedLayout) -> Result<bool> {
+        let Some(settings) = self.check_for_changes()? else {
+            return Ok(false);
+        };
+        layout.write().unwrap().apply_settings(settings);
+        Ok(true)
+    }
+}
+
+fn modified(path: &Path) -> std::io::Result<(SystemTime, u64)> {
+    let metadata = fs::metadata(path)?;
+    // The modification time may not be precise enough to notice modifications
+    // that happen in quick succession, so the length is compared as well.
+    Ok((metadata.modified()?, metadata.len()))
+}
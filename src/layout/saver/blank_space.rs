@@ -0,0 +1,14 @@
+use super::{background, number, translate_size};
+use crate::util::xml::Writer;
+use core::fmt;
+
+pub use crate::component::blank_space::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    let size = translate_size(settings.size);
+    number(writer, "SpaceHeight", size)?;
+    number(writer, "SpaceWidth", size)?;
+    background(writer, &settings.background)
+}
@@ -0,0 +1,24 @@
+use super::{background, bool, color_override};
+use crate::util::xml::Writer;
+use core::fmt;
+
+pub use crate::component::current_comparison::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    color_override(
+        writer,
+        "TextColor",
+        "OverrideTextColor",
+        settings.label_color,
+    )?;
+    color_override(
+        writer,
+        "TimeColor",
+        "OverrideTimeColor",
+        settings.value_color,
+    )?;
+    background(writer, &settings.background)?;
+    bool(writer, "Display2Rows", settings.display_two_rows)
+}
@@ -0,0 +1,30 @@
+use super::{accuracy, background, bool, color_override, comparison_override};
+use crate::util::xml::Writer;
+use core::fmt;
+
+pub use crate::component::current_pace::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    color_override(
+        writer,
+        "TextColor",
+        "OverrideTextColor",
+        settings.label_color,
+    )?;
+    color_override(
+        writer,
+        "TimeColor",
+        "OverrideTimeColor",
+        settings.value_color,
+    )?;
+    accuracy(writer, "Accuracy", settings.accuracy)?;
+    background(writer, &settings.background)?;
+    comparison_override(
+        writer,
+        "Comparison",
+        settings.comparison_override.as_deref(),
+    )?;
+    bool(writer, "Display2Rows", settings.display_two_rows)
+}
@@ -0,0 +1,39 @@
+use super::{bool, color, comparison_override, number, translate_size};
+use crate::util::xml::Writer;
+use core::fmt;
+
+pub use crate::component::graph::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    number(writer, "Height", translate_size(settings.height))?;
+    color(writer, "BehindGraphColor", settings.behind_background_color)?;
+    color(writer, "AheadGraphColor", settings.ahead_background_color)?;
+    color(writer, "GridlinesColor", settings.grid_lines_color)?;
+    color(
+        writer,
+        "PartialFillColorBehind",
+        settings.partial_fill_color,
+    )?;
+    color(
+        writer,
+        "CompleteFillColorBehind",
+        settings.complete_fill_color,
+    )?;
+    color(writer, "PartialFillColorAhead", settings.partial_fill_color)?;
+    color(
+        writer,
+        "CompleteFillColorAhead",
+        settings.complete_fill_color,
+    )?;
+    color(writer, "GraphColor", settings.graph_lines_color)?;
+    bool(writer, "LiveGraph", settings.live_graph)?;
+    bool(writer, "FlipGraph", settings.flip_graph)?;
+    comparison_override(
+        writer,
+        "Comparison",
+        settings.comparison_override.as_deref(),
+    )?;
+    bool(writer, "ShowBestSegments", settings.show_best_segments)
+}
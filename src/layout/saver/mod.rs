@@ -0,0 +1,625 @@
+//! Provides the saver for layout files of the original LiveSplit.
+//!
+//! # Examples
+//!
+//! Saving a layout such that it can be opened with the original LiveSplit.
+//!
+//! ```
+//! use livesplit_core::layout::{parser, saver, Layout};
+//!
+//! let layout = Layout::default_layout();
+//!
+//! let mut buf = String::new();
+//! saver::save(&layout, &mut buf).expect("Couldn't save the layout");
+//!
+//! let parsed = parser::parse(&buf).expect("Couldn't parse the layout");
+//! assert_eq!(parsed.components.len(), layout.components.len());
+//! ```
+
+use super::{Component, Layout, LayoutDirection};
+use crate::{
+    component::timer::DeltaGradient,
+    platform::{math::f32::powf, prelude::*},
+    run::saver::livesplit::{image, LSS_IMAGE_HEADER},
+    settings::{
+        Color, Font, FontStretch, FontStyle, FontWeight, Gradient, Image, LayoutBackground,
+        ListGradient,
+    },
+    timing::{
+        formatter::{Accuracy, DigitsFormat},
+        TimingMethod,
+    },
+    util::xml::{DisplayAlreadyEscaped, Text, Writer, NO_ATTRIBUTES},
+    Run,
+};
+use alloc::borrow::Cow;
+use core::{fmt, mem::MaybeUninit};
+
+mod blank_space;
+mod current_comparison;
+mod current_pace;
+mod delta;
+mod detailed_timer;
+mod graph;
+mod pb_chance;
+mod possible_time_save;
+mod previous_segment;
+mod splits;
+mod sum_of_best;
+mod text;
+mod timer;
+mod title;
+mod total_playtime;
+mod world_record;
+
+// The serialized System.Drawing.Font object up until the name of the font. It
+// only refers to the version of the assembly, so it is the same for every font.
+const FONT_HEADER: &[u8; 228] = include_bytes!("lsl_font_header.bin");
+
+// The inverse of the conversion done by the parser. See the parser for more
+// information on the ratio.
+const PIXEL_SPACE_RATIO: f32 = 24.0 / 30.5;
+
+fn translate_size(v: u32) -> u32 {
+    (v as f32 * (1.0 / PIXEL_SPACE_RATIO) + 0.5) as u32
+}
+
+/// A background that can be stored as two colors and the kind of gradient.
+trait Background {
+    fn kind_and_colors(&self) -> (&'static str, Color, Color);
+}
+
+impl Background for Gradient {
+    fn kind_and_colors(&self) -> (&'static str, Color, Color) {
+        match *self {
+            Gradient::Transparent => ("Plain", Color::transparent(), Color::transparent()),
+            Gradient::Plain(c) => ("Plain", c, Color::transparent()),
+            Gradient::Vertical(top, bottom) => ("Vertical", top, bottom),
+            Gradient::Horizontal(left, right) => ("Horizontal", left, right),
+        }
+    }
+}
+
+impl Background for ListGradient {
+    fn kind_and_colors(&self) -> (&'static str, Color, Color) {
+        match self {
+            ListGradient::Same(gradient) => gradient.kind_and_colors(),
+            ListGradient::Alternating(even, odd) => ("Alternating", *even, *odd),
+        }
+    }
+}
+
+impl Background for DeltaGradient {
+    fn kind_and_colors(&self) -> (&'static str, Color, Color) {
+        let transparent = Color::transparent();
+        match self {
+            DeltaGradient::Gradient(gradient) => gradient.kind_and_colors(),
+            DeltaGradient::DeltaPlain => ("PlainWithDeltaColor", transparent, transparent),
+            DeltaGradient::DeltaVertical => ("VerticalWithDeltaColor", transparent, transparent),
+            DeltaGradient::DeltaHorizontal => {
+                ("HorizontalWithDeltaColor", transparent, transparent)
+            }
+        }
+    }
+}
+
+fn background<W: fmt::Write>(writer: &mut Writer<W>, background: &impl Background) -> fmt::Result {
+    background_with_tags(
+        writer,
+        ["BackgroundColor", "BackgroundColor2", "BackgroundGradient"],
+        background,
+    )
+}
+
+fn background_with_tags<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    [tag_color1, tag_color2, tag_kind]: [&str; 3],
+    background: &impl Background,
+) -> fmt::Result {
+    let (kind, first, second) = background.kind_and_colors();
+    color(writer, tag_color1, first)?;
+    color(writer, tag_color2, second)?;
+    writer.tag_with_text_content(tag_kind, NO_ATTRIBUTES, Text::new_escaped(kind))
+}
+
+fn color<W: fmt::Write>(writer: &mut Writer<W>, tag: &str, color: Color) -> fmt::Result {
+    let [r, g, b, _] = color.to_rgba8();
+    let [red, green, blue, _] = Color::rgba8(r, g, b, 0).to_array();
+
+    // The parser adjusts the alpha based on the lightness of the color, so we
+    // need to search for the original alpha that results in the same adjusted
+    // alpha. The adjustment is strictly increasing, so a binary search finds
+    // the closest one.
+    let lightness = (red + green + blue) * (1.0 / 3.0);
+    let adjust = |a: u8| {
+        let a = a as f32 * (1.0 / 255.0);
+        (1.0 - lightness) * (1.0 - powf(1.0 - a, 1.0 / 2.2)) + lightness * powf(a, 1.0 / 1.75)
+    };
+
+    let (mut low, mut high) = (0u8, u8::MAX);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if adjust(mid) < color.alpha {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    let a = if low > 0 && color.alpha - adjust(low - 1) < adjust(low) - color.alpha {
+        low - 1
+    } else {
+        low
+    };
+
+    let argb = u32::from_be_bytes([a, r, g, b]);
+    writer.tag_with_text_content(
+        tag,
+        NO_ATTRIBUTES,
+        DisplayAlreadyEscaped(format_args!("{argb:08X}")),
+    )
+}
+
+/// Stores a color that is only used if the accompanying flag is set. If there
+/// is no color, LiveSplit's default is stored instead.
+fn color_override<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    tag: &str,
+    tag_override: &str,
+    value: Option<Color>,
+) -> fmt::Result {
+    color(writer, tag, value.unwrap_or_else(Color::white))?;
+    bool(writer, tag_override, value.is_some())
+}
+
+fn bool<W: fmt::Write>(writer: &mut Writer<W>, tag: &str, value: bool) -> fmt::Result {
+    writer.tag_with_text_content(
+        tag,
+        NO_ATTRIBUTES,
+        Text::new_escaped(if value { "True" } else { "False" }),
+    )
+}
+
+fn number<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    tag: &str,
+    value: impl fmt::Display,
+) -> fmt::Result {
+    writer.tag_with_text_content(tag, NO_ATTRIBUTES, DisplayAlreadyEscaped(value))
+}
+
+fn icon_size<W: fmt::Write>(writer: &mut Writer<W>, icon_size: u32) -> fmt::Result {
+    // The original LiveSplit uses a default size if it's not specified, which
+    // is the closest to the icons filling the available space.
+    if icon_size == 0 {
+        return Ok(());
+    }
+    number(writer, "IconSize", translate_size(icon_size))
+}
+
+fn comparison_override<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    tag: &str,
+    comparison: Option<&str>,
+) -> fmt::Result {
+    writer.tag_with_text_content(
+        tag,
+        NO_ATTRIBUTES,
+        comparison.unwrap_or("Current Comparison"),
+    )
+}
+
+fn timing_method_override<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    tag: &str,
+    timing_method: Option<TimingMethod>,
+) -> fmt::Result {
+    writer.tag_with_text_content(
+        tag,
+        NO_ATTRIBUTES,
+        Text::new_escaped(match timing_method {
+            None => "Current Timing Method",
+            Some(TimingMethod::RealTime) => "Real Time",
+            Some(TimingMethod::GameTime) => "Game Time",
+        }),
+    )
+}
+
+fn accuracy<W: fmt::Write>(writer: &mut Writer<W>, tag: &str, accuracy: Accuracy) -> fmt::Result {
+    writer.tag_with_text_content(
+        tag,
+        NO_ATTRIBUTES,
+        Text::new_escaped(match accuracy {
+            Accuracy::Seconds => "Seconds",
+            Accuracy::Tenths => "Tenths",
+            // The original LiveSplit doesn't show milliseconds.
+            Accuracy::Hundredths | Accuracy::Milliseconds => "Hundredths",
+        }),
+    )
+}
+
+fn timer_format<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    tag: &str,
+    digits_format: DigitsFormat,
+    accuracy: Accuracy,
+) -> fmt::Result {
+    let digits_format = match digits_format {
+        DigitsFormat::SingleDigitSeconds | DigitsFormat::DoubleDigitSeconds => "1",
+        DigitsFormat::SingleDigitMinutes | DigitsFormat::DoubleDigitMinutes => "00:01",
+        DigitsFormat::SingleDigitHours => "0:00:01",
+        DigitsFormat::DoubleDigitHours => "00:00:01",
+    };
+    let accuracy = match accuracy {
+        Accuracy::Seconds => "",
+        Accuracy::Tenths => ".2",
+        Accuracy::Hundredths | Accuracy::Milliseconds => ".23",
+    };
+    writer.tag_with_text_content(
+        tag,
+        NO_ATTRIBUTES,
+        DisplayAlreadyEscaped(format_args!("{digits_format}{accuracy}")),
+    )
+}
+
+/// The font that the original LiveSplit uses if the layout doesn't specify a
+/// font. Custom fonts are stored with the same size and unit.
+struct DefaultFont {
+    family: &'static str,
+    size: f32,
+    bold: bool,
+    /// The System.Drawing.GraphicsUnit the size is specified in.
+    unit: u8,
+}
+
+const TIMER_FONT: DefaultFont = DefaultFont {
+    family: "Calibri",
+    size: 43.75,
+    bold: true,
+    unit: 2, // Pixel
+};
+
+const TIMES_FONT: DefaultFont = DefaultFont {
+    family: "Segoe UI",
+    size: 12.0,
+    bold: true,
+    unit: 3, // Point
+};
+
+const TEXT_FONT: DefaultFont = DefaultFont {
+    family: "Segoe UI",
+    size: 12.0,
+    bold: false,
+    unit: 3, // Point
+};
+
+fn font<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    tag: &str,
+    font: Option<&Font>,
+    default: &DefaultFont,
+    font_buf: &mut Vec<u8>,
+    base64_buf: &mut Vec<MaybeUninit<u8>>,
+) -> fmt::Result {
+    // See the parser for the documentation of the format. The weights and
+    // stretches that can't be expressed as a style are appended to the name as
+    // tokens, which the parser splits off again.
+    let (name, bold, italic) = match font {
+        Some(font) => {
+            let mut name = font.family.clone();
+            let weight = match font.weight {
+                FontWeight::Thin => Some("Thin"),
+                FontWeight::ExtraLight => Some("ExtraLight"),
+                FontWeight::Light => Some("Light"),
+                FontWeight::SemiLight => Some("SemiLight"),
+                FontWeight::Normal | FontWeight::Bold => None,
+                FontWeight::Medium => Some("Medium"),
+                FontWeight::SemiBold => Some("SemiBold"),
+                FontWeight::ExtraBold => Some("ExtraBold"),
+                FontWeight::Black => Some("Black"),
+                FontWeight::ExtraBlack => Some("ExtraBlack"),
+            };
+            let stretch = match font.stretch {
+                FontStretch::UltraCondensed => Some("UltraCondensed"),
+                FontStretch::ExtraCondensed => Some("ExtraCondensed"),
+                FontStretch::Condensed => Some("Condensed"),
+                FontStretch::SemiCondensed => Some("SemiCondensed"),
+                FontStretch::Normal => None,
+                FontStretch::SemiExpanded => Some("SemiExpanded"),
+                FontStretch::Expanded => Some("Expanded"),
+                FontStretch::ExtraExpanded => Some("ExtraExpanded"),
+                FontStretch::UltraExpanded => Some("UltraExpanded"),
+            };
+            for token in [weight, stretch].into_iter().flatten() {
+                name.push(' ');
+                name.push_str(token);
+            }
+            (
+                Cow::Owned(name),
+                font.weight >= FontWeight::Bold,
+                font.style != FontStyle::Normal,
+            )
+        }
+        None => (Cow::Borrowed(default.family), default.bold, false),
+    };
+
+    font_buf.clear();
+    font_buf.extend_from_slice(FONT_HEADER);
+    font_buf.push(0);
+
+    // Strings are encoded as varint for the length + the UTF-8 string data.
+    let mut len = name.len();
+    loop {
+        let byte = (len & 0b0111_1111) as u8;
+        len >>= 7;
+        if len == 0 {
+            font_buf.push(byte);
+            break;
+        }
+        font_buf.push(byte | 0b1000_0000);
+    }
+    font_buf.extend_from_slice(name.as_bytes());
+
+    font_buf.extend_from_slice(&default.size.to_le_bytes());
+    font_buf.extend_from_slice(b"\x05\xFC\xFF\xFF\xFF\x18System.Drawing.FontStyle");
+    font_buf.extend_from_slice(b"\x01\x00\x00\x00\x07value__\x00\x08\x02\x00\x00\x00");
+    font_buf.extend_from_slice(&(bold as i32 | (italic as i32) << 1).to_le_bytes());
+    font_buf.extend_from_slice(b"\x05\xFB\xFF\xFF\xFF\x1BSystem.Drawing.GraphicsUnit");
+    font_buf.extend_from_slice(b"\x01\x00\x00\x00\x07value__\x00\x08\x02\x00\x00\x00");
+    font_buf.extend_from_slice(&(default.unit as i32).to_le_bytes());
+    font_buf.push(0x0B);
+
+    base64_buf.resize(
+        base64_simd::STANDARD.encoded_length(font_buf.len()),
+        MaybeUninit::uninit(),
+    );
+
+    let encoded = base64_simd::STANDARD
+        .encode_as_str(font_buf, base64_simd::Out::from_uninit_slice(base64_buf));
+
+    writer.tag_with_content(tag, NO_ATTRIBUTES, |writer| {
+        writer.cdata(Text::new_escaped(encoded))
+    })
+}
+
+fn component<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let (path, version) = match component {
+        Component::BlankSpace(_) => ("LiveSplit.BlankSpace.dll", "1.7"),
+        Component::CurrentComparison(_) => ("LiveSplit.CurrentComparison.dll", "1.4"),
+        Component::CurrentPace(_) => ("LiveSplit.RunPrediction.dll", "1.4"),
+        Component::Delta(_) => ("LiveSplit.Delta.dll", "1.4"),
+        Component::DetailedTimer(_) => ("LiveSplit.DetailedTimer.dll", "1.5"),
+        Component::Graph(_) => ("LiveSplit.Graph.dll", "1.5"),
+        Component::PbChance(_) => ("PBChance.dll", "0.1"),
+        Component::PossibleTimeSave(_) => ("LiveSplit.PossibleTimeSave.dll", "1.5"),
+        Component::PreviousSegment(_) => ("LiveSplit.PreviousSegment.dll", "1.6"),
+        // The original LiveSplit has no equivalent of these components.
+        Component::Countdown(_) | Component::SegmentTime(_) | Component::SegmentTracker(_) => {
+            return Ok(())
+        }
+        Component::Separator(_) => ("", ""),
+        Component::Splits(c) if c.settings().group_subsplits => ("LiveSplit.Subsplits.dll", "1.6"),
+        Component::Splits(_) => ("LiveSplit.Splits.dll", "1.6"),
+        Component::SumOfBest(_) => ("LiveSplit.SumOfBest.dll", "1.4"),
+        Component::Text(_) => ("LiveSplit.Text.dll", "1.4"),
+        Component::Timer(_) => ("LiveSplit.Timer.dll", "1.5"),
+        Component::Title(_) => ("LiveSplit.Title.dll", "1.7.3"),
+        Component::TotalPlaytime(_) => ("LiveSplit.TotalPlaytime.dll", "1.6"),
+        Component::WorldRecord(_) => ("LiveSplit.WorldRecord.dll", "1.6"),
+    };
+
+    writer.tag_with_content("Component", NO_ATTRIBUTES, |writer| {
+        writer.tag_with_text_content("Path", NO_ATTRIBUTES, Text::new_escaped(path))?;
+        writer.tag("Settings", |tag| {
+            if version.is_empty() {
+                return Ok(());
+            }
+            tag.content(|writer| {
+                writer.tag_with_text_content(
+                    "Version",
+                    NO_ATTRIBUTES,
+                    Text::new_escaped(version),
+                )?;
+                match component {
+                    Component::BlankSpace(c) => blank_space::settings(writer, c),
+                    Component::CurrentComparison(c) => current_comparison::settings(writer, c),
+                    Component::CurrentPace(c) => current_pace::settings(writer, c),
+                    Component::Delta(c) => delta::settings(writer, c),
+                    Component::DetailedTimer(c) => detailed_timer::settings(writer, c),
+                    Component::Graph(c) => graph::settings(writer, c),
+                    Component::PbChance(c) => pb_chance::settings(writer, c),
+                    Component::PossibleTimeSave(c) => possible_time_save::settings(writer, c),
+                    Component::PreviousSegment(c) => previous_segment::settings(writer, c),
+                    Component::Splits(c) => splits::settings(writer, c),
+                    Component::SumOfBest(c) => sum_of_best::settings(writer, c),
+                    Component::Text(c) => text::settings(writer, c),
+                    Component::Timer(c) => timer::settings(writer, c),
+                    Component::Title(c) => title::settings(writer, c),
+                    Component::TotalPlaytime(c) => total_playtime::settings(writer, c),
+                    Component::WorldRecord(c) => world_record::settings(writer, c),
+                    Component::Countdown(_)
+                    | Component::SegmentTime(_)
+                    | Component::SegmentTracker(_)
+                    | Component::Separator(_) => Ok(()),
+                }
+            })
+        })
+    })
+}
+
+fn general_settings<W: fmt::Write>(writer: &mut Writer<W>, layout: &Layout) -> fmt::Result {
+    let settings = layout.general_settings();
+
+    let font_buf = &mut Vec::new();
+    let base64_buf = &mut Vec::new();
+    let image_buf = &mut Cow::Borrowed(&LSS_IMAGE_HEADER[..]);
+
+    writer.tag_with_content("Settings", NO_ATTRIBUTES, |writer| {
+        let (background_type, background_image) = match &settings.background {
+            LayoutBackground::Gradient(gradient) => (
+                match gradient {
+                    Gradient::Transparent | Gradient::Plain(_) => "SolidColor",
+                    Gradient::Vertical(..) => "VerticalGradient",
+                    Gradient::Horizontal(..) => "HorizontalGradient",
+                },
+                None,
+            ),
+            LayoutBackground::Image(image) => ("Image", Some(image)),
+        };
+        let (_, first, second) = match &settings.background {
+            LayoutBackground::Gradient(gradient) => gradient.kind_and_colors(),
+            LayoutBackground::Image(_) => Gradient::Transparent.kind_and_colors(),
+        };
+
+        color(writer, "TextColor", settings.text_color)?;
+        color(writer, "BackgroundColor", first)?;
+        color(writer, "BackgroundColor2", second)?;
+        color(
+            writer,
+            "ThinSeparatorsColor",
+            settings.thin_separators_color,
+        )?;
+        color(writer, "SeparatorsColor", settings.separators_color)?;
+        color(writer, "PersonalBestColor", settings.personal_best_color)?;
+        color(
+            writer,
+            "AheadGainingTimeColor",
+            settings.ahead_gaining_time_color,
+        )?;
+        color(
+            writer,
+            "AheadLosingTimeColor",
+            settings.ahead_losing_time_color,
+        )?;
+        color(
+            writer,
+            "BehindGainingTimeColor",
+            settings.behind_gaining_time_color,
+        )?;
+        color(
+            writer,
+            "BehindLosingTimeColor",
+            settings.behind_losing_time_color,
+        )?;
+        color(writer, "BestSegmentColor", settings.best_segment_color)?;
+        bool(writer, "UseRainbowColor", false)?;
+        color(writer, "NotRunningColor", settings.not_running_color)?;
+        color(writer, "PausedColor", settings.paused_color)?;
+        writer.tag_with_text_content(
+            "TextOutlineColor",
+            NO_ATTRIBUTES,
+            Text::new_escaped("00000000"),
+        )?;
+        writer.tag_with_text_content(
+            "ShadowsColor",
+            NO_ATTRIBUTES,
+            Text::new_escaped("80000000"),
+        )?;
+        font(
+            writer,
+            "TimesFont",
+            settings.times_font.as_ref(),
+            &TIMES_FONT,
+            font_buf,
+            base64_buf,
+        )?;
+        font(
+            writer,
+            "TimerFont",
+            settings.timer_font.as_ref(),
+            &TIMER_FONT,
+            font_buf,
+            base64_buf,
+        )?;
+        font(
+            writer,
+            "TextFont",
+            settings.text_font.as_ref(),
+            &TEXT_FONT,
+            font_buf,
+            base64_buf,
+        )?;
+        bool(writer, "AlwaysOnTop", true)?;
+        bool(writer, "ShowBestSegments", true)?;
+        bool(writer, "AntiAliasing", true)?;
+        bool(writer, "DropShadows", true)?;
+        writer.tag_with_text_content(
+            "BackgroundType",
+            NO_ATTRIBUTES,
+            Text::new_escaped(background_type),
+        )?;
+        image(
+            writer,
+            "BackgroundImage",
+            background_image.map_or(Image::EMPTY, |i| &i.image),
+            base64_buf,
+            image_buf,
+        )?;
+        // The original LiveSplit doesn't support any transparency, so the
+        // brightness is what the opacity is used as in practice.
+        number(
+            writer,
+            "ImageOpacity",
+            background_image.map_or(1.0, |i| i.brightness),
+        )?;
+        number(
+            writer,
+            "ImageBlur",
+            background_image.map_or(0.0, |i| i.blur),
+        )?;
+        number(writer, "Opacity", 1)
+    })
+}
+
+fn layout<W: fmt::Write>(writer: &mut Writer<W>, layout: &Layout) -> fmt::Result {
+    writer.tag_with_content(
+        "Layout",
+        [("version", Text::new_escaped("1.6.1"))],
+        |writer| {
+            writer.tag_with_text_content(
+                "Mode",
+                NO_ATTRIBUTES,
+                Text::new_escaped(match layout.general_settings().direction {
+                    LayoutDirection::Vertical => "Vertical",
+                    LayoutDirection::Horizontal => "Horizontal",
+                }),
+            )?;
+            // The layout doesn't know where it is shown or how large it is. The
+            // original LiveSplit chooses a size itself if it's -1.
+            number(writer, "X", 0)?;
+            number(writer, "Y", 0)?;
+            number(writer, "VerticalWidth", -1)?;
+            number(writer, "VerticalHeight", -1)?;
+            number(writer, "HorizontalWidth", -1)?;
+            number(writer, "HorizontalHeight", -1)?;
+            general_settings(writer, layout)?;
+            writer.tag_with_content("Components", NO_ATTRIBUTES, |writer| {
+                for c in &layout.components {
+                    component(writer, c)?;
+                }
+                Ok(())
+            })
+        },
+    )
+}
+
+/// Saves a layout as a layout file of the original LiveSplit, so that it can
+/// be opened with the original LiveSplit. Settings that the original LiveSplit
+/// doesn't support are lost, and so are components that it doesn't have an
+/// equivalent for. Settings that livesplit-core doesn't support are stored
+/// with the original LiveSplit's defaults.
+pub fn save<W: fmt::Write>(layout: &Layout, writer: W) -> fmt::Result {
+    let writer = &mut Writer::new_with_default_header(writer)?.with_indentation(' ', 2);
+    self::layout(writer, layout)
+}
+
+/// Embeds a layout in the [`Run`], so that it gets stored in the LiveSplit
+/// splits file alongside the [`Run`]. The layout is stored in the same way as
+/// [`save`] stores it. Any layout that was previously embedded is replaced. The
+/// embedded layout can be removed again by clearing the
+/// [`embedded_layout_mut`](Run::embedded_layout_mut) of the [`Run`].
+pub fn embed(layout: &Layout, run: &mut Run) {
+    let buf = run.embedded_layout_mut();
+    buf.clear();
+    // Writing to a String can't fail.
+    let _ = self::layout(&mut Writer::new_skip_header(buf), layout);
+}
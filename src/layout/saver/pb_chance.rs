@@ -0,0 +1,10 @@
+use crate::util::xml::Writer;
+use core::fmt;
+
+pub use crate::component::pb_chance::Component;
+
+pub fn settings<W: fmt::Write>(_: &mut Writer<W>, _: &Component) -> fmt::Result {
+    // The component doesn't have any settings that the original LiveSplit
+    // supports.
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use super::{accuracy, background, bool, color_override, comparison_override};
+use crate::util::xml::Writer;
+use core::fmt;
+
+pub use crate::component::previous_segment::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    color_override(
+        writer,
+        "TextColor",
+        "OverrideTextColor",
+        settings.label_color,
+    )?;
+    background(writer, &settings.background)?;
+    accuracy(writer, "DeltaAccuracy", settings.accuracy)?;
+    bool(writer, "DropDecimals", settings.drop_decimals)?;
+    comparison_override(
+        writer,
+        "Comparison",
+        settings.comparison_override.as_deref(),
+    )?;
+    bool(writer, "Display2Rows", settings.display_two_rows)?;
+    bool(
+        writer,
+        "ShowPossibleTimeSave",
+        settings.show_possible_time_save,
+    )?;
+    accuracy(writer, "TimeSaveAccuracy", settings.time_save_accuracy)
+}
@@ -0,0 +1,89 @@
+use super::{
+    accuracy, background, background_with_tags, bool, comparison_override, icon_size, number,
+    timing_method_override,
+};
+use crate::{
+    component::splits::{ColumnKind, ColumnStartWith, ColumnUpdateWith},
+    util::xml::{Text, Writer, NO_ATTRIBUTES},
+};
+use core::fmt;
+
+pub use crate::component::splits::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    background_with_tags(
+        writer,
+        [
+            "CurrentSplitTopColor",
+            "CurrentSplitBottomColor",
+            "CurrentSplitGradient",
+        ],
+        &settings.current_split_gradient,
+    )?;
+    number(writer, "VisualSplitCount", settings.visual_split_count)?;
+    number(writer, "SplitPreviewCount", settings.split_preview_count)?;
+    bool(writer, "ShowThinSeparators", settings.show_thin_separators)?;
+    bool(
+        writer,
+        "AlwaysShowLastSplit",
+        settings.always_show_last_split,
+    )?;
+    accuracy(writer, "SplitTimesAccuracy", settings.split_time_accuracy)?;
+    bool(writer, "ShowBlankSplits", settings.fill_with_blank_space)?;
+    background(writer, &settings.background)?;
+    bool(writer, "SeparatorLastSplit", settings.separator_last_split)?;
+    accuracy(writer, "DeltasAccuracy", settings.delta_time_accuracy)?;
+    bool(writer, "DropDecimals", settings.delta_drop_decimals)?;
+    bool(writer, "Display2Rows", settings.display_two_rows)?;
+    bool(writer, "ShowColumnLabels", settings.show_column_labels)?;
+    icon_size(writer, settings.icon_size)?;
+
+    writer.tag_with_content("Columns", NO_ATTRIBUTES, |writer| {
+        // The original LiveSplit lays out the columns from right to left.
+        for column in settings.columns.iter().rev() {
+            // The original LiveSplit doesn't support variable columns.
+            let ColumnKind::Time(time_column) = &column.kind else {
+                continue;
+            };
+
+            // The original LiveSplit only supports a fixed set of column
+            // types, so we choose the type that is closest to what the
+            // column gets updated with, as that's what is shown for most of
+            // the splits once the attempt is running.
+            let kind = match (time_column.start_with, time_column.update_with) {
+                (ColumnStartWith::Empty, ColumnUpdateWith::Delta) => "Delta",
+                (ColumnStartWith::Empty, ColumnUpdateWith::SegmentDelta) => "SegmentDelta",
+                (_, ColumnUpdateWith::Delta | ColumnUpdateWith::DeltaWithFallback) => {
+                    "DeltaorSplitTime"
+                }
+                (
+                    _,
+                    ColumnUpdateWith::SegmentDelta | ColumnUpdateWith::SegmentDeltaWithFallback,
+                ) => "SegmentDeltaorSegmentTime",
+                (_, ColumnUpdateWith::SplitTime) => "SplitTime",
+                (_, ColumnUpdateWith::SegmentTime) => "SegmentTime",
+                (ColumnStartWith::Empty, ColumnUpdateWith::DontUpdate) => "Delta",
+                (ColumnStartWith::ComparisonTime, ColumnUpdateWith::DontUpdate) => "SplitTime",
+                (
+                    ColumnStartWith::ComparisonSegmentTime | ColumnStartWith::PossibleTimeSave,
+                    ColumnUpdateWith::DontUpdate,
+                ) => "SegmentTime",
+            };
+
+            writer.tag_with_content("Settings", NO_ATTRIBUTES, |writer| {
+                writer.tag_with_text_content("Version", NO_ATTRIBUTES, Text::new_escaped("1.5"))?;
+                writer.tag_with_text_content("Name", NO_ATTRIBUTES, column.name.as_str())?;
+                writer.tag_with_text_content("Type", NO_ATTRIBUTES, Text::new_escaped(kind))?;
+                comparison_override(
+                    writer,
+                    "Comparison",
+                    time_column.comparison_override.as_deref(),
+                )?;
+                timing_method_override(writer, "TimingMethod", time_column.timing_method)
+            })?;
+        }
+        Ok(())
+    })
+}
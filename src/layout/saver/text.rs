@@ -0,0 +1,37 @@
+use super::{background, bool, color_override};
+use crate::{
+    component::text::Text,
+    util::xml::{Writer, NO_ATTRIBUTES},
+};
+use core::fmt;
+
+pub use crate::component::text::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    color_override(
+        writer,
+        "TextColor",
+        "OverrideTextColor",
+        settings.left_center_color,
+    )?;
+    color_override(
+        writer,
+        "TimeColor",
+        "OverrideTimeColor",
+        settings.right_color,
+    )?;
+    background(writer, &settings.background)?;
+
+    // The original LiveSplit can't show custom variables, so only their name
+    // is stored.
+    let (left_center, right) = match &settings.text {
+        Text::Center(text) | Text::Variable(text, _) => (text.as_str(), ""),
+        Text::Split(left, right) => (left.as_str(), right.as_str()),
+    };
+    writer.tag_with_text_content("Text1", NO_ATTRIBUTES, left_center)?;
+    writer.tag_with_text_content("Text2", NO_ATTRIBUTES, right)?;
+
+    bool(writer, "Display2Rows", settings.display_two_rows)
+}
@@ -0,0 +1,32 @@
+use super::{
+    background, bool, color, number, timer_format, timing_method_override, translate_size,
+};
+use crate::{settings::Color, util::xml::Writer};
+use core::fmt;
+
+pub use crate::component::timer::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    number(writer, "TimerHeight", translate_size(settings.height))?;
+    timer_format(
+        writer,
+        "TimerFormat",
+        settings.digits_format,
+        settings.accuracy,
+    )?;
+    bool(
+        writer,
+        "OverrideSplitColors",
+        settings.color_override.is_some(),
+    )?;
+    bool(writer, "ShowGradient", settings.show_gradient)?;
+    color(
+        writer,
+        "TimerColor",
+        settings.color_override.unwrap_or_else(Color::white),
+    )?;
+    background(writer, &settings.background)?;
+    timing_method_override(writer, "TimingMethod", settings.timing_method)
+}
@@ -0,0 +1,39 @@
+use super::{background, bool, color_override, number};
+use crate::{settings::Alignment, util::xml::Writer};
+use core::fmt;
+
+pub use crate::component::title::Component;
+
+pub fn settings<W: fmt::Write>(writer: &mut Writer<W>, component: &Component) -> fmt::Result {
+    let settings = component.settings();
+
+    bool(writer, "ShowGameName", settings.show_game_name)?;
+    bool(writer, "ShowCategoryName", settings.show_category_name)?;
+    bool(writer, "ShowAttemptCount", settings.show_attempt_count)?;
+    bool(
+        writer,
+        "ShowFinishedRunsCount",
+        settings.show_finished_runs_count,
+    )?;
+    bool(writer, "SingleLine", settings.display_as_single_line)?;
+    color_override(
+        writer,
+        "TitleColor",
+        "OverrideTitleColor",
+        settings.text_color,
+    )?;
+    background(writer, &settings.background)?;
+    bool(writer, "DisplayGameIcon", settings.display_game_icon)?;
+    bool(writer, "ShowRegion", settings.show_region)?;
+    bool(writer, "ShowPlatform", settings.show_platform)?;
+    bool(writer, "ShowVariables", settings.show_variables)?;
+    number(
+        writer,
+        "TextAlignment",
+        match settings.text_alignment {
+            Alignment::Auto => 0,
+            Alignment::Left => 1,
+            Alignment::Center => 2,
+        },
+    )
+}
@@ -0,0 +1,116 @@
+use super::GeneralSettings;
+use crate::settings::{Color, Gradient, LayoutBackground, SemanticColor};
+use serde_derive::{Deserialize, Serialize};
+
+/// A theme describes the look of a [`Layout`](crate::layout::Layout)
+/// independently of its components. It consists of the background of the
+/// layout and the colors for all the different roles a color can have, such as
+/// the colors for being ahead or behind the comparison. The components don't
+/// store these colors themselves, but refer to them through their roles, so the
+/// look of the whole layout can be switched by applying a different theme to
+/// its [`GeneralSettings`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// The background to show behind the layout.
+    pub background: LayoutBackground,
+    /// The color to use for when the runner achieved a best segment.
+    pub best_segment_color: Color,
+    /// The color to use for when the runner is ahead of the comparison and is
+    /// gaining even more time.
+    pub ahead_gaining_time_color: Color,
+    /// The color to use for when the runner is ahead of the comparison, but is
+    /// losing time.
+    pub ahead_losing_time_color: Color,
+    /// The color to use for when the runner is behind the comparison, but is
+    /// gaining back time.
+    pub behind_gaining_time_color: Color,
+    /// The color to use for when the runner is behind the comparison and is
+    /// losing even more time.
+    pub behind_losing_time_color: Color,
+    /// The color to use for when there is no active attempt.
+    pub not_running_color: Color,
+    /// The color to use for when the runner achieved a new Personal Best.
+    pub personal_best_color: Color,
+    /// The color to use for when the timer is paused.
+    pub paused_color: Color,
+    /// The color of thin separators.
+    pub thin_separators_color: Color,
+    /// The color of normal separators.
+    pub separators_color: Color,
+    /// The text color to use for text that doesn't specify its own color.
+    pub text_color: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// The dark theme that layouts use by default. It shows light text on a
+    /// dark background.
+    pub fn dark() -> Self {
+        GeneralSettings::default().theme()
+    }
+
+    /// A light theme that shows dark text on a light background. The colors
+    /// for being ahead or behind the comparison are darker than the ones of
+    /// the dark theme, so they are still readable on the light background.
+    pub fn light() -> Self {
+        Self {
+            background: LayoutBackground::Gradient(Gradient::Vertical(
+                Color::hsla(0.0, 0.0, 0.97, 1.0),
+                Color::hsla(0.0, 0.0, 0.88, 1.0),
+            )),
+            best_segment_color: Color::hsla(40.0, 1.0, 0.42, 1.0),
+            ahead_gaining_time_color: Color::hsla(136.0, 1.0, 0.3, 1.0),
+            ahead_losing_time_color: Color::hsla(136.0, 0.55, 0.42, 1.0),
+            behind_gaining_time_color: Color::hsla(0.0, 0.55, 0.48, 1.0),
+            behind_losing_time_color: Color::hsla(0.0, 1.0, 0.38, 1.0),
+            not_running_color: Color::hsla(0.0, 0.0, 0.42, 1.0),
+            personal_best_color: Color::hsla(203.0, 1.0, 0.4, 1.0),
+            paused_color: Color::hsla(0.0, 0.0, 0.55, 1.0),
+            thin_separators_color: Color::hsla(0.0, 0.0, 0.0, 0.09),
+            separators_color: Color::hsla(0.0, 0.0, 0.0, 0.35),
+            text_color: Color::hsla(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    /// Converts a Semantic Color to the actual Color that the theme uses for
+    /// it.
+    pub const fn visualize(&self, color: SemanticColor) -> Color {
+        match color {
+            SemanticColor::Default => self.text_color,
+            SemanticColor::AheadGainingTime => self.ahead_gaining_time_color,
+            SemanticColor::AheadLosingTime => self.ahead_losing_time_color,
+            SemanticColor::BehindLosingTime => self.behind_losing_time_color,
+            SemanticColor::BehindGainingTime => self.behind_gaining_time_color,
+            SemanticColor::BestSegment => self.best_segment_color,
+            SemanticColor::NotRunning => self.not_running_color,
+            SemanticColor::Paused => self.paused_color,
+            SemanticColor::PersonalBest => self.personal_best_color,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Theme {
+    /// Decodes a theme from JSON. Colors that are missing use the colors of
+    /// the default theme.
+    pub fn from_json<R>(reader: R) -> serde_json::Result<Theme>
+    where
+        R: std::io::Read,
+    {
+        serde_json::from_reader(reader)
+    }
+
+    /// Encodes the theme as JSON, so it can be shared between layouts.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
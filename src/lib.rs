@@ -73,6 +73,8 @@ mod hotkey_config;
 mod hotkey_system;
 pub mod layout;
 pub mod networking;
+#[cfg(feature = "std")]
+pub mod plugin;
 #[cfg(feature = "rendering")]
 pub mod rendering;
 pub mod run;
@@ -95,4 +97,8 @@ pub use livesplit_hotkey as hotkey;
 pub use crate::platform::{register_clock, Clock, Duration};
 
 #[cfg(feature = "std")]
-pub use crate::{hotkey_config::HotkeyConfig, hotkey_system::HotkeySystem, timing::SharedTimer};
+pub use crate::{
+    hotkey_config::HotkeyConfig,
+    hotkey_system::{HotkeySystem, SuppressionMode},
+    timing::SharedTimer,
+};
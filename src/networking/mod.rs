@@ -1,9 +1,15 @@
 //! The networking module provides functionality to communicate with various
 //! speedrunning related websites, such as Splits.io to upload and download runs
-//! and Speedrun.com to query and submit to the leaderboards of most games. The
-//! module is optional and is not compiled in by default.
+//! and Speedrun.com to query and submit to the leaderboards of most games.
+//! Additionally it allows notifying external services about changes in the
+//! timer via webhooks. The module is optional and is not compiled in by
+//! default.
 
 #[cfg(feature = "std")]
 pub mod server_protocol;
 #[cfg(feature = "networking")]
+pub mod speedrun_com;
+#[cfg(feature = "networking")]
 pub mod splits_io;
+#[cfg(feature = "networking")]
+pub mod webhook;
@@ -206,6 +206,14 @@ pub enum Command<'a> {
         #[serde(serialize_with = "serialize_time_span")]
         time: TimeSpan,
     },
+    /// Informs the timer that the game started loading. The timer then pauses
+    /// the game time until the game stops loading and adds the time spent
+    /// loading to the loading times.
+    StartLoading,
+    /// Informs the timer that the game stopped loading. The game time
+    /// continues from where it was paused at, unless it was already paused
+    /// before the game started loading.
+    EndLoading,
     /// Sets the value of a custom variable with the name specified. If the
     /// variable does not exist, a temporary variable gets created that will not
     /// be stored in the splits file.
@@ -446,6 +454,14 @@ impl Command<'_> {
                     .map_err(Error::timer)?;
                 Response::None
             }
+            Command::StartLoading => {
+                command_sink.start_loading().await.map_err(Error::timer)?;
+                Response::None
+            }
+            Command::EndLoading => {
+                command_sink.end_loading().await.map_err(Error::timer)?;
+                Response::None
+            }
             Command::SetCustomVariable { key, value } => {
                 command_sink
                     .set_custom_variable(key, value)
@@ -0,0 +1,398 @@
+//! The `speedrun_com` module provides helpers for working with the metadata
+//! provided by the [speedrun.com API](https://github.com/speedruncomorg/api).
+//! New splits can be scaffolded from a game and one of its categories, so they
+//! start out with the correct metadata for submitting runs to the
+//! leaderboards.
+//!
+//! Additionally the world record of a category can be retrieved from its
+//! leaderboard, so it can be shown by the
+//! [World Record Component](crate::component::world_record). The
+//! [`WorldRecordCache`] ensures the leaderboards aren't requested more often
+//! than necessary.
+//!
+//! The resources are requested via [`fetch_scaffolded_run`],
+//! [`fetch_world_record`] and [`WorldRecordCache::fetch`]. Like the webhooks,
+//! the actual HTTP requests are performed by a [`Client`] provided by the
+//! embedder. Alternatively the helpers that parse the JSON payloads of the API
+//! can be used directly. The game then needs to be requested from the
+//! `games/{id}` endpoint with `?embed=platforms` and the category from the
+//! `categories/{id}` endpoint with `?embed=variables`. The leaderboard needs to
+//! be requested from the URL returned by [`leaderboard_url`]. Resources that
+//! are not embedded are skipped.
+
+use crate::{
+    platform::prelude::*,
+    run::{
+        parser::speedrun_com::{Embedded, Named, Names, Times, Variable},
+        WorldRecord,
+    },
+    Run, Segment, TimeSpan, TimeStamp,
+};
+use core::{future::Future, result::Result as StdResult};
+use serde_derive::Deserialize;
+use serde_json::Error as JsonError;
+
+/// The name of the permanent custom variable that stores the rules of the
+/// category when scaffolding a run.
+pub const NOTES_VARIABLE: &str = "Notes";
+
+/// The base URL of the speedrun.com API.
+const API_URL: &str = "https://www.speedrun.com/api/v1";
+
+/// Describes an error that happened when requesting or parsing the metadata
+/// of speedrun.com.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to request a resource from the speedrun.com API.
+    Request {
+        /// The URL of the resource.
+        url: String,
+    },
+    /// Failed to parse the game.
+    ParseGame {
+        /// The underlying error.
+        #[cfg_attr(not(feature = "std"), snafu(source(false)))]
+        source: JsonError,
+    },
+    /// Failed to parse the category.
+    ParseCategory {
+        /// The underlying error.
+        #[cfg_attr(not(feature = "std"), snafu(source(false)))]
+        source: JsonError,
+    },
+    /// Failed to parse the leaderboard.
+    ParseLeaderboard {
+        /// The underlying error.
+        #[cfg_attr(not(feature = "std"), snafu(source(false)))]
+        source: JsonError,
+    },
+}
+
+/// The Result type for requesting and parsing the metadata of speedrun.com.
+pub type Result<T> = StdResult<T, Error>;
+
+/// A client is able to send the HTTP requests to the speedrun.com API.
+pub trait Client {
+    /// Requests the resource at the URL provided via an HTTP GET request.
+    /// Returns the body of the response or `None` if the request failed.
+    fn get(&self, url: &str) -> impl Future<Output = Option<String>>;
+}
+
+async fn get<C: Client>(client: &C, url: String) -> Result<String> {
+    match client.get(&url).await {
+        Some(body) => Ok(body),
+        None => Err(Error::Request { url }),
+    }
+}
+
+/// A run that got scaffolded from the metadata of speedrun.com.
+#[derive(Debug, Clone)]
+pub struct ScaffoldedRun {
+    /// The scaffolded run.
+    pub run: Run,
+    /// The names of all the platforms the game is available on. A run only
+    /// stores a single platform, which is only filled in if the game is
+    /// available on a single platform. Otherwise the runner may choose one of
+    /// these.
+    pub platforms: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Payload<T> {
+    Wrapped { data: T },
+    Bare(T),
+}
+
+impl<T> Payload<T> {
+    fn into_inner(self) -> T {
+        match self {
+            Payload::Wrapped { data } | Payload::Bare(data) => data,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Game {
+    names: Names,
+    platforms: Option<Embedded<Vec<Named>>>,
+}
+
+#[derive(Deserialize)]
+struct Category {
+    name: String,
+    rules: Option<String>,
+    variables: Option<Embedded<Vec<Variable>>>,
+}
+
+/// Creates a new run for the game and category provided. The game name,
+/// category name and the default values of the category's variables get
+/// filled in. Variables that only apply to individual levels are skipped. If
+/// `include_rules` is set, the rules of the category are stored in the
+/// permanent custom variable [`NOTES_VARIABLE`]. The run consists of a single
+/// segment, so it can be used right away.
+pub fn scaffold_run(game: &str, category: &str, include_rules: bool) -> Result<ScaffoldedRun> {
+    let game = serde_json::from_str::<Payload<Game>>(game)
+        .map_err(|source| Error::ParseGame { source })?
+        .into_inner();
+    let category = serde_json::from_str::<Payload<Category>>(category)
+        .map_err(|source| Error::ParseCategory { source })?
+        .into_inner();
+
+    let mut run = Run::new();
+    run.set_game_name(game.names.international);
+    run.set_category_name(category.name);
+    run.push_segment(Segment::new("Finish"));
+
+    let platforms = game
+        .platforms
+        .and_then(Embedded::data)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|platform| platform.name)
+        .collect::<Vec<_>>();
+
+    let metadata = run.metadata_mut();
+
+    if let [platform] = &*platforms {
+        metadata.set_platform_name(platform.as_str());
+    }
+
+    for variable in category
+        .variables
+        .and_then(Embedded::data)
+        .unwrap_or_default()
+    {
+        if variable
+            .scope
+            .is_some_and(|scope| scope.kind == "single-level")
+        {
+            continue;
+        }
+        if let Some(value) = variable
+            .values
+            .default
+            .and_then(|default| variable.values.values.get(&default))
+        {
+            metadata.set_speedrun_com_variable(variable.name, value.label.as_str());
+        }
+    }
+
+    if include_rules {
+        if let Some(rules) = category.rules.filter(|rules| !rules.trim().is_empty()) {
+            let notes = metadata.custom_variable_mut(NOTES_VARIABLE);
+            notes.permanent();
+            notes.set_value(rules);
+        }
+    }
+
+    Ok(ScaffoldedRun { run, platforms })
+}
+
+/// Asynchronously requests the game and category with the IDs provided from
+/// the speedrun.com API and scaffolds a new run from them. See
+/// [`scaffold_run`] for how the run is filled in.
+pub async fn fetch_scaffolded_run<C: Client>(
+    client: &C,
+    game_id: &str,
+    category_id: &str,
+    include_rules: bool,
+) -> Result<ScaffoldedRun> {
+    let game = get(client, format!("{API_URL}/games/{game_id}?embed=platforms")).await?;
+    let category = get(
+        client,
+        format!("{API_URL}/categories/{category_id}?embed=variables"),
+    )
+    .await?;
+    scaffold_run(&game, &category, include_rules)
+}
+
+#[derive(Deserialize)]
+struct Leaderboard {
+    runs: Vec<PlacedRun>,
+    players: Option<Embedded<Vec<Player>>>,
+}
+
+#[derive(Deserialize)]
+struct PlacedRun {
+    place: u32,
+    run: LeaderboardRun,
+}
+
+#[derive(Deserialize)]
+struct LeaderboardRun {
+    times: Times,
+    #[serde(default)]
+    players: Vec<Player>,
+}
+
+#[derive(Deserialize)]
+struct Player {
+    id: Option<String>,
+    name: Option<String>,
+    names: Option<Names>,
+}
+
+/// Returns the URL of the speedrun.com API to request the leaderboard of the
+/// game and category with the IDs provided from. Only the world record is
+/// requested and the players are embedded, so the response can be parsed with
+/// [`parse_world_record`].
+pub fn leaderboard_url(game_id: &str, category_id: &str) -> String {
+    format!("{API_URL}/leaderboards/{game_id}/category/{category_id}?top=1&embed=players")
+}
+
+/// Asynchronously requests the leaderboard of the game and category with the
+/// IDs provided from the speedrun.com API and parses its world record. `None`
+/// is returned if the leaderboard doesn't have any runs. Use a
+/// [`WorldRecordCache`] to avoid requesting the leaderboard more often than
+/// necessary.
+pub async fn fetch_world_record<C: Client>(
+    client: &C,
+    game_id: &str,
+    category_id: &str,
+) -> Result<Option<WorldRecord>> {
+    parse_world_record(&get(client, leaderboard_url(game_id, category_id)).await?)
+}
+
+/// Parses the world record from the leaderboard of a category, as requested
+/// from the URL returned by [`leaderboard_url`]. `None` is returned if the
+/// leaderboard doesn't have any runs. Registered runners are only named if the
+/// players are embedded into the leaderboard.
+pub fn parse_world_record(leaderboard: &str) -> Result<Option<WorldRecord>> {
+    let leaderboard = serde_json::from_str::<Payload<Leaderboard>>(leaderboard)
+        .map_err(|source| Error::ParseLeaderboard { source })?
+        .into_inner();
+
+    let Some(placed) = leaderboard.runs.into_iter().min_by_key(|run| run.place) else {
+        return Ok(None);
+    };
+
+    let players = leaderboard
+        .players
+        .and_then(Embedded::data)
+        .unwrap_or_default();
+
+    let runners = placed
+        .run
+        .players
+        .into_iter()
+        .filter_map(|player| {
+            if let Some(name) = player.name {
+                return Some(name);
+            }
+            let id = player.id?;
+            let player = players
+                .iter()
+                .find(|embedded| embedded.id.as_ref() == Some(&id))?;
+            Some(player.names.as_ref()?.international.clone())
+        })
+        .collect();
+
+    Ok(Some(WorldRecord {
+        time: placed.run.times.final_time(),
+        runners,
+    }))
+}
+
+struct CachedWorldRecord {
+    url: String,
+    world_record: Option<WorldRecord>,
+    fetched_at: TimeStamp,
+}
+
+/// Caches the world records of the leaderboards that got requested, so that
+/// they are only requested again once they are outdated. The world records are
+/// identified by the URL of their leaderboard.
+pub struct WorldRecordCache {
+    entries: Vec<CachedWorldRecord>,
+    max_age: TimeSpan,
+}
+
+impl Default for WorldRecordCache {
+    fn default() -> Self {
+        Self::new(TimeSpan::from_seconds(60.0 * 60.0))
+    }
+}
+
+impl WorldRecordCache {
+    /// Creates a new empty cache where the world records are considered
+    /// outdated after the amount of time provided.
+    pub const fn new(max_age: TimeSpan) -> Self {
+        Self {
+            entries: Vec::new(),
+            max_age,
+        }
+    }
+
+    /// Returns whether the leaderboard with the URL provided needs to be
+    /// requested, because it either was never requested or its world record is
+    /// outdated.
+    pub fn needs_update(&self, url: &str) -> bool {
+        self.entries
+            .iter()
+            .find(|entry| entry.url == url)
+            .is_none_or(|entry| TimeStamp::now() - entry.fetched_at >= self.max_age)
+    }
+
+    /// Parses the world record from the leaderboard that got requested from
+    /// the URL provided and stores it in the cache. If the leaderboard can't be
+    /// parsed, the cache is left unchanged.
+    pub fn update(&mut self, url: &str, leaderboard: &str) -> Result<Option<&WorldRecord>> {
+        let world_record = parse_world_record(leaderboard)?;
+        let now = TimeStamp::now();
+
+        let index = match self.entries.iter().position(|entry| entry.url == url) {
+            Some(index) => {
+                let entry = &mut self.entries[index];
+                entry.world_record = world_record;
+                entry.fetched_at = now;
+                index
+            }
+            None => {
+                self.entries.push(CachedWorldRecord {
+                    url: url.into(),
+                    world_record,
+                    fetched_at: now,
+                });
+                self.entries.len() - 1
+            }
+        };
+
+        Ok(self.entries[index].world_record.as_ref())
+    }
+
+    /// Asynchronously requests the leaderboard of the game and category with
+    /// the IDs provided, unless the cached world record is still up to date.
+    /// If the request fails, the cache is left unchanged, so the outdated world
+    /// record can still be accessed via [`get`](Self::get).
+    pub async fn fetch<C: Client>(
+        &mut self,
+        client: &C,
+        game_id: &str,
+        category_id: &str,
+    ) -> Result<Option<&WorldRecord>> {
+        let url = leaderboard_url(game_id, category_id);
+        if !self.needs_update(&url) {
+            return Ok(self.get(&url));
+        }
+        let leaderboard = get(client, url.clone()).await?;
+        self.update(&url, &leaderboard)
+    }
+
+    /// Accesses the cached world record of the leaderboard with the URL
+    /// provided. `None` is returned if the leaderboard has no runs or was never
+    /// requested. Outdated world records are still returned.
+    pub fn get(&self, url: &str) -> Option<&WorldRecord> {
+        self.entries
+            .iter()
+            .find(|entry| entry.url == url)?
+            .world_record
+            .as_ref()
+    }
+
+    /// Removes all the cached world records.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -5,7 +5,7 @@
 
 use crate::{
     run::{
-        parser::composite,
+        parser::{composite, speedrun_com},
         saver::{self, livesplit::IoWrite},
     },
     Run, Timer,
@@ -33,6 +33,24 @@ pub enum DownloadError {
     },
 }
 
+/// Describes an error that happened when importing a run from speedrun.com.
+/// This may either be because the run itself couldn't be parsed or because its
+/// splits couldn't be downloaded from Splits.io.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum ImportError {
+    /// Failed to parse the speedrun.com run.
+    ParseSpeedrunCom {
+        /// The underlying parsing error.
+        source: speedrun_com::Error,
+    },
+    /// Failed to download the splits of the run.
+    DownloadSplits {
+        /// The underlying download error.
+        source: DownloadError,
+    },
+}
+
 /// Describes an error that happened when uploading a run to Splits.io. This may
 /// either be because the upload itself had a problem or because the run itself
 /// couldn't be saved.
@@ -81,3 +99,27 @@ pub async fn upload_timer(client: &Client, timer: &Timer) -> Result<UploadedRun,
         .context(Save)?;
     api::run::upload(client, buf).await.context(Upload)
 }
+
+/// Asynchronously imports a run from the JSON payload of a run retrieved from
+/// the speedrun.com API. If the run links to its splits on Splits.io, they get
+/// downloaded, so the imported run contains the actual split times. The game,
+/// category and metadata of the speedrun.com run are applied to them.
+pub async fn import_speedrun_com_run(client: &Client, source: &str) -> Result<Run, ImportError> {
+    let imported = speedrun_com::parse(source).context(ParseSpeedrunCom)?;
+
+    let Some(id) = imported.splits_io_id else {
+        return Ok(imported.run);
+    };
+
+    let mut run = download_run(client, &id).await.context(DownloadSplits)?.run;
+
+    if !imported.run.game_name().is_empty() {
+        run.set_game_name(imported.run.game_name());
+    }
+    if !imported.run.category_name().is_empty() {
+        run.set_category_name(imported.run.category_name());
+    }
+    *run.metadata_mut() = imported.run.metadata().clone();
+
+    Ok(run)
+}
@@ -0,0 +1,571 @@
+//! The webhook module allows notifying external services about changes in the
+//! timer. A [`Webhook`] describes a URL that a JSON payload is supposed to be
+//! sent to whenever one of its [`Trigger`]s occurs, such as finishing a run or
+//! achieving a new personal best. This can be used to integrate the timer with
+//! home automation, Discord webhooks or custom bots.
+//!
+//! The [`Dispatcher`] does not perform any I/O on its own. It queues up the
+//! requests based on the [`Event`]s that the timer reports and hands them out
+//! while respecting the rate limiting. The actual HTTP requests are performed
+//! by a [`Client`] provided by the embedder. Failed requests are retried with
+//! an exponential backoff.
+
+use core::future::Future;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    analysis::check_best_segment,
+    event::Event,
+    platform::{prelude::*, Arc},
+    timing::{
+        formatter::{self, TimeFormatter},
+        MonotonicClock, TimeSource,
+    },
+    TimeSpan, TimeStamp, Timer,
+};
+
+/// A trigger describes a situation in which a [`Webhook`] is supposed to be
+/// notified.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Trigger {
+    /// A new attempt has been started.
+    Started,
+    /// A split happened. This does not include the final split.
+    Split,
+    /// The final split happened and the run is finished.
+    Finished,
+    /// The run finished with a new personal best.
+    PersonalBest,
+    /// A segment has been finished with a new best segment time.
+    BestSegment,
+    /// The attempt has been reset.
+    Reset,
+}
+
+impl Trigger {
+    /// Returns the name of the trigger as it is used in the payloads.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Trigger::Started => "Started",
+            Trigger::Split => "Split",
+            Trigger::Finished => "Finished",
+            Trigger::PersonalBest => "PersonalBest",
+            Trigger::BestSegment => "BestSegment",
+            Trigger::Reset => "Reset",
+        }
+    }
+}
+
+/// A webhook is a URL that a JSON payload gets posted to whenever one of its
+/// triggers occurs.
+///
+/// The payload is a JSON template. All the strings within it may contain the
+/// following placeholders, which are replaced by the actual values once the
+/// webhook is triggered:
+///
+/// - `{trigger}`: The name of the [`Trigger`].
+/// - `{game}`: The name of the game.
+/// - `{category}`: The name of the category.
+/// - `{segment}`: The name of the segment that the trigger relates to.
+/// - `{time}`: The split time of that segment or the current time of the timer
+///   in the current timing method.
+/// - `{attempt}`: The number of the current attempt.
+///
+/// If no payload is specified, an object with all of these values is sent.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Webhook {
+    /// The URL to post the payload to.
+    pub url: String,
+    /// The triggers that cause the webhook to be notified.
+    pub triggers: Vec<Trigger>,
+    /// The JSON template of the payload that is sent.
+    pub payload: Option<Value>,
+}
+
+impl Webhook {
+    /// Creates a new webhook for the URL and triggers provided that uses the
+    /// default payload.
+    pub fn new(url: impl Into<String>, triggers: impl Into<Vec<Trigger>>) -> Self {
+        Self {
+            url: url.into(),
+            triggers: triggers.into(),
+            payload: None,
+        }
+    }
+}
+
+/// The settings of the [`Dispatcher`] that control the retries and the rate
+/// limiting.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Settings {
+    /// The maximum amount of times a failed request is retried.
+    pub max_retries: u32,
+    /// The delay before the first retry. Every further retry doubles the
+    /// delay.
+    pub retry_delay: TimeSpan,
+    /// The minimum amount of time between two requests to the same URL.
+    pub min_interval: TimeSpan,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_delay: TimeSpan::from_seconds(5.0),
+            min_interval: TimeSpan::from_seconds(1.0),
+        }
+    }
+}
+
+/// Identifies a [`Request`] that is handed out by the [`Dispatcher`], so that
+/// its result can be reported back.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RequestId(u64);
+
+/// A request that is supposed to be sent as an HTTP POST request with a JSON
+/// body.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    /// The identifier to report the result of the request with.
+    pub id: RequestId,
+    /// The URL to post the body to.
+    pub url: String,
+    /// The JSON body of the request.
+    pub body: String,
+}
+
+/// A client is able to send the HTTP requests of the [`Dispatcher`].
+pub trait Client {
+    /// Posts the JSON body to the URL provided. Returns whether the request
+    /// was successful.
+    fn post(&self, url: &str, body: &str) -> impl Future<Output = bool>;
+}
+
+struct Delivery {
+    id: RequestId,
+    url: String,
+    body: String,
+    failed_attempts: u32,
+    queued_at: TimeStamp,
+    delay: TimeSpan,
+}
+
+impl Delivery {
+    fn is_due(&self, now: TimeStamp) -> bool {
+        now - self.queued_at >= self.delay
+    }
+}
+
+/// The dispatcher turns the [`Event`]s of the timer into [`Request`]s for all
+/// the [`Webhook`]s that are interested in them. It keeps track of the requests
+/// that still need to be sent, retries the failed ones and ensures that the
+/// same URL isn't notified too often. The retry delays and the rate limiting
+/// are measured with the [`TimeSource`] of the [`Timer`] whose events are
+/// handled.
+pub struct Dispatcher {
+    webhooks: Vec<Webhook>,
+    settings: Settings,
+    queue: Vec<Delivery>,
+    in_flight: Vec<Delivery>,
+    last_sent: Vec<(String, TimeStamp)>,
+    next_id: u64,
+    time_source: Arc<dyn TimeSource>,
+}
+
+impl Default for Dispatcher {
+    fn default() -> Self {
+        Self::new(Settings::default())
+    }
+}
+
+impl Dispatcher {
+    /// Creates a new dispatcher without any webhooks.
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            webhooks: Vec::new(),
+            settings,
+            queue: Vec::new(),
+            in_flight: Vec::new(),
+            last_sent: Vec::new(),
+            next_id: 0,
+            time_source: Arc::new(MonotonicClock),
+        }
+    }
+
+    /// Accesses the settings of the dispatcher.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the dispatcher.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the webhooks of the dispatcher.
+    pub fn webhooks(&self) -> &[Webhook] {
+        &self.webhooks
+    }
+
+    /// Grants mutable access to the webhooks of the dispatcher. Changing the
+    /// webhooks doesn't affect the requests that are already queued up.
+    pub fn webhooks_mut(&mut self) -> &mut Vec<Webhook> {
+        &mut self.webhooks
+    }
+
+    /// Adds a new webhook to the dispatcher.
+    pub fn push_webhook(&mut self, webhook: Webhook) {
+        self.webhooks.push(webhook);
+    }
+
+    /// Returns the amount of requests that still need to be sent, including
+    /// the ones waiting to be retried.
+    pub fn pending_requests(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Handles an event that the timer reported and queues up requests for all
+    /// the webhooks that are interested in it. This needs to be called right
+    /// after the event happened, so that the timer still reflects the state
+    /// the event relates to.
+    pub fn handle_event(&mut self, event: Event, timer: &Timer) {
+        let method = timer.current_timing_method();
+        let finished_index = match event {
+            Event::Splitted | Event::Finished => timer
+                .current_split_index()
+                .and_then(|index| index.checked_sub(1)),
+            _ => None,
+        };
+
+        let mut triggers = Vec::new();
+        match event {
+            Event::Started => triggers.push(Trigger::Started),
+            Event::Splitted => triggers.push(Trigger::Split),
+            Event::Finished => {
+                triggers.push(Trigger::Finished);
+                if timer.current_attempt_has_new_personal_best(method) {
+                    triggers.push(Trigger::PersonalBest);
+                }
+            }
+            Event::Reset => triggers.push(Trigger::Reset),
+            _ => {}
+        }
+        if finished_index.is_some_and(|index| check_best_segment(timer, index, method)) {
+            triggers.push(Trigger::BestSegment);
+        }
+
+        if triggers.is_empty() {
+            return;
+        }
+
+        let (segment, time) = match finished_index {
+            Some(index) => {
+                let segment = timer.run().segment(index);
+                (segment.name(), segment.split_time()[method])
+            }
+            None => (
+                timer.current_split().map_or("", |s| s.name()),
+                timer.snapshot().current_time()[method],
+            ),
+        };
+        let time = formatter::none_wrapper::NoneWrapper::new(formatter::Complete::new(), "")
+            .format(time)
+            .to_string();
+        let attempt = timer.run().attempt_count().to_string();
+
+        self.time_source = timer.time_source().clone();
+        let now = self.time_source.now();
+
+        for trigger in triggers {
+            let variables = Variables {
+                trigger: trigger.name(),
+                game: timer.run().game_name(),
+                category: timer.run().category_name(),
+                segment,
+                time: &time,
+                attempt: &attempt,
+            };
+
+            for webhook in &self.webhooks {
+                if !webhook.triggers.contains(&trigger) {
+                    continue;
+                }
+
+                let body = match &webhook.payload {
+                    Some(template) => variables.fill(template),
+                    None => variables.default_payload(),
+                };
+
+                self.queue.push(Delivery {
+                    id: RequestId(self.next_id),
+                    url: webhook.url.clone(),
+                    body: body.to_string(),
+                    failed_attempts: 0,
+                    queued_at: now,
+                    delay: TimeSpan::zero(),
+                });
+                self.next_id += 1;
+            }
+        }
+    }
+
+    /// Returns the next request that is supposed to be sent right now, if
+    /// there is any. Requests that are waiting to be retried or that would
+    /// exceed the rate limit of their URL are held back. The result of the
+    /// request needs to be reported back via [`report`](Self::report).
+    pub fn next_request(&mut self) -> Option<Request> {
+        let now = self.time_source.now();
+        let min_interval = self.settings.min_interval;
+        let last_sent = &self.last_sent;
+
+        let index = self.queue.iter().position(|delivery| {
+            delivery.is_due(now)
+                && !last_sent
+                    .iter()
+                    .any(|(url, sent)| *url == delivery.url && now - *sent < min_interval)
+        })?;
+
+        let delivery = self.queue.remove(index);
+
+        match self
+            .last_sent
+            .iter_mut()
+            .find(|(url, _)| *url == delivery.url)
+        {
+            Some((_, sent)) => *sent = now,
+            None => self.last_sent.push((delivery.url.clone(), now)),
+        }
+
+        let request = Request {
+            id: delivery.id,
+            url: delivery.url.clone(),
+            body: delivery.body.clone(),
+        };
+        self.in_flight.push(delivery);
+
+        Some(request)
+    }
+
+    /// Reports the result of a request that was handed out by
+    /// [`next_request`](Self::next_request). A failed request is queued up
+    /// again to be retried later, unless it ran out of retries.
+    pub fn report(&mut self, id: RequestId, success: bool) {
+        let Some(index) = self.in_flight.iter().position(|d| d.id == id) else {
+            return;
+        };
+        let mut delivery = self.in_flight.remove(index);
+
+        if success || delivery.failed_attempts >= self.settings.max_retries {
+            return;
+        }
+
+        let factor = (1u64 << delivery.failed_attempts.min(16)) as f64;
+        delivery.failed_attempts += 1;
+        delivery.queued_at = self.time_source.now();
+        delivery.delay = TimeSpan::from_seconds(self.settings.retry_delay.total_seconds() * factor);
+        self.queue.push(delivery);
+    }
+
+    /// Sends all the requests that are due right now via the client provided
+    /// and reports their results.
+    pub async fn send_pending<C: Client>(&mut self, client: &C) {
+        while let Some(request) = self.next_request() {
+            let success = client.post(&request.url, &request.body).await;
+            self.report(request.id, success);
+        }
+    }
+}
+
+struct Variables<'a> {
+    trigger: &'a str,
+    game: &'a str,
+    category: &'a str,
+    segment: &'a str,
+    time: &'a str,
+    attempt: &'a str,
+}
+
+impl Variables<'_> {
+    fn default_payload(&self) -> Value {
+        serde_json::json!({
+            "trigger": self.trigger,
+            "game": self.game,
+            "category": self.category,
+            "segment": self.segment,
+            "time": self.time,
+            "attempt": self.attempt,
+        })
+    }
+
+    fn fill(&self, template: &Value) -> Value {
+        match template {
+            Value::String(text) => Value::String(self.interpolate(text)),
+            Value::Array(values) => Value::Array(values.iter().map(|v| self.fill(v)).collect()),
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), self.fill(value)))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// Replaces all the placeholders in the text in a single pass, so the
+    /// values that are filled in are never interpreted as placeholders
+    /// themselves.
+    fn interpolate(&self, text: &str) -> String {
+        let mut buf = String::with_capacity(text.len());
+        let mut rem = text;
+        while let Some(start) = rem.find('{') {
+            buf.push_str(&rem[..start]);
+            rem = &rem[start..];
+
+            let Some(end) = rem.find('}') else {
+                break;
+            };
+            let placeholder = &rem[1..end];
+            if placeholder.contains('{') {
+                buf.push('{');
+                rem = &rem[1..];
+                continue;
+            }
+
+            match self.resolve(placeholder) {
+                Some(value) => buf.push_str(value),
+                None => buf.push_str(&rem[..=end]),
+            }
+            rem = &rem[end + 1..];
+        }
+        buf.push_str(rem);
+        buf
+    }
+
+    fn resolve(&self, placeholder: &str) -> Option<&str> {
+        Some(match placeholder {
+            "trigger" => self.trigger,
+            "game" => self.game,
+            "category" => self.category,
+            "segment" => self.segment,
+            "time" => self.time,
+            "attempt" => self.attempt,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::tests_helper::create_timer;
+
+    fn dispatcher() -> Dispatcher {
+        Dispatcher::new(Settings {
+            max_retries: 1,
+            retry_delay: TimeSpan::zero(),
+            min_interval: TimeSpan::zero(),
+        })
+    }
+
+    #[test]
+    fn only_interested_webhooks_are_notified() {
+        let mut dispatcher = dispatcher();
+        dispatcher.push_webhook(Webhook::new("https://a", [Trigger::Finished]));
+        dispatcher.push_webhook(Webhook::new("https://b", [Trigger::Started]));
+
+        let mut timer = create_timer(&["A"]);
+        let event = timer.start().unwrap();
+        dispatcher.handle_event(event, &timer);
+
+        let request = dispatcher.next_request().unwrap();
+        assert_eq!(request.url, "https://b");
+        assert!(dispatcher.next_request().is_none());
+    }
+
+    #[test]
+    fn payload_template_is_filled() {
+        let mut dispatcher = dispatcher();
+        dispatcher.push_webhook(Webhook {
+            url: "https://a".into(),
+            triggers: vec![Trigger::Finished],
+            payload: Some(serde_json::json!({ "content": "Finished {segment}!", "n": 1 })),
+        });
+
+        let mut timer = create_timer(&["Final \"Boss\""]);
+        timer.start().unwrap();
+        let event = timer.split().unwrap();
+        dispatcher.handle_event(event, &timer);
+
+        let request = dispatcher.next_request().unwrap();
+        let body: Value = serde_json::from_str(&request.body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({ "content": "Finished Final \"Boss\"!", "n": 1 })
+        );
+    }
+
+    #[test]
+    fn filled_in_values_are_not_interpolated_again() {
+        let mut dispatcher = dispatcher();
+        dispatcher.push_webhook(Webhook {
+            url: "https://a".into(),
+            triggers: vec![Trigger::Finished],
+            payload: Some(serde_json::json!("{segment} {game} {unknown} {{time}")),
+        });
+
+        let mut timer = create_timer(&["{time}"]);
+        timer.start().unwrap();
+        let event = timer.split().unwrap();
+        dispatcher.handle_event(event, &timer);
+
+        let request = dispatcher.next_request().unwrap();
+        let body: Value = serde_json::from_str(&request.body).unwrap();
+        let time = formatter::Complete::new()
+            .format(timer.run().segment(0).split_time().real_time)
+            .to_string();
+        assert_eq!(body, Value::String(format!("{{time}}  {{unknown}} {{{time}")));
+    }
+
+    #[test]
+    fn failed_requests_are_retried() {
+        let mut dispatcher = dispatcher();
+        dispatcher.push_webhook(Webhook::new("https://a", [Trigger::Reset]));
+
+        let mut timer = create_timer(&["A"]);
+        timer.start().unwrap();
+        let event = timer.reset(false).unwrap();
+        dispatcher.handle_event(event, &timer);
+
+        let request = dispatcher.next_request().unwrap();
+        dispatcher.report(request.id, false);
+        let retry = dispatcher.next_request().unwrap();
+        assert_eq!(retry.body, request.body);
+
+        dispatcher.report(retry.id, false);
+        assert!(dispatcher.next_request().is_none());
+        assert_eq!(dispatcher.pending_requests(), 0);
+    }
+
+    #[test]
+    fn requests_are_rate_limited() {
+        let mut dispatcher = dispatcher();
+        dispatcher.settings_mut().min_interval = TimeSpan::from_seconds(1000.0);
+        dispatcher.push_webhook(Webhook::new(
+            "https://a",
+            [Trigger::Started, Trigger::Reset],
+        ));
+
+        let mut timer = create_timer(&["A"]);
+        let event = timer.start().unwrap();
+        dispatcher.handle_event(event, &timer);
+        let event = timer.reset(false).unwrap();
+        dispatcher.handle_event(event, &timer);
+
+        let request = dispatcher.next_request().unwrap();
+        dispatcher.report(request.id, true);
+        assert!(dispatcher.next_request().is_none());
+        assert_eq!(dispatcher.pending_requests(), 1);
+    }
+}
@@ -6,6 +6,16 @@ cfg_if::cfg_if! {
                 x.abs()
             }
 
+            #[inline(always)]
+            pub fn ceil(x: f32) -> f32 {
+                x.ceil()
+            }
+
+            #[inline(always)]
+            pub fn floor(x: f32) -> f32 {
+                x.floor()
+            }
+
             #[inline(always)]
             pub fn powf(x: f32, y: f32) -> f32 {
                 x.powf(y)
@@ -13,7 +23,7 @@ cfg_if::cfg_if! {
         }
     } else {
         pub mod f32 {
-            pub use libm::{fabsf as abs, powf};
+            pub use libm::{ceilf as ceil, fabsf as abs, floorf as floor, powf};
         }
     }
 }
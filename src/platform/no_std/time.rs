@@ -1,6 +1,6 @@
 use crate::platform::prelude::*;
 use core::{
-    ops::Sub,
+    ops::{Add, Sub},
     sync::atomic::{self, AtomicPtr},
 };
 
@@ -61,6 +61,14 @@ impl Sub for Instant {
     }
 }
 
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, rhs: Duration) -> Instant {
+        Instant(self.0 + rhs)
+    }
+}
+
 pub fn utc_now() -> DateTime {
     let clock = CLOCK.load(atomic::Ordering::SeqCst);
     if clock.is_null() {
@@ -0,0 +1,294 @@
+//! The plugin module provides a host for plugins that extend livesplit-core
+//! with additional functionality. A single plugin can provide comparison
+//! generators, the text of custom components and react to the events of the
+//! timer. Each plugin comes with a [`Manifest`] that declares which of these
+//! capabilities it wants to use. The [`Host`] only grants a plugin access to
+//! the capabilities that are declared as a [`Permission`] in its manifest.
+//!
+//! The plugins themselves are abstracted by the [`Plugin`] trait, so the host
+//! does not care whether a plugin is implemented natively or as a WebAssembly
+//! module. Native plugins are trusted code, so for them the permissions only
+//! decide which of their capabilities the host uses. Plugins provided as
+//! WebAssembly modules are available through [`WasmPlugin`] with the
+//! `auto-splitting` feature. Those are sandboxed and the permissions are
+//! enforced at the boundary of each function they can call.
+
+#[cfg(feature = "auto-splitting")]
+mod wasm;
+
+#[cfg(feature = "auto-splitting")]
+pub use self::wasm::WasmPlugin;
+
+use crate::{comparison::ComparisonGenerator, event::Event, platform::prelude::*, Run, Timer};
+use serde_derive::{Deserialize, Serialize};
+use snafu::{ensure, ResultExt, Snafu};
+
+/// The manifest describes a plugin and declares the permissions it requires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The name of the plugin. It needs to be unique among all the plugins
+    /// loaded into a single host.
+    pub name: String,
+    /// The version of the plugin.
+    #[serde(default)]
+    pub version: String,
+    /// The permissions the plugin requires.
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
+}
+
+impl Manifest {
+    /// Creates a new manifest for a plugin with the name provided and no
+    /// permissions.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            version: String::new(),
+            permissions: Vec::new(),
+        }
+    }
+
+    /// Parses a manifest from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context(ParseManifest)
+    }
+
+    /// Returns whether the manifest grants the permission provided.
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+}
+
+/// A permission a plugin can request in its [`Manifest`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Permission {
+    /// The plugin may register comparison generators with the run.
+    Comparisons,
+    /// The plugin may provide the text of custom components.
+    ComponentText,
+    /// The plugin may observe the events of the timer.
+    Events,
+}
+
+/// The error type for all the operations of the plugin host.
+#[derive(Debug, Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to parse the manifest.
+    ParseManifest {
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// A plugin with the same name is already loaded.
+    AlreadyLoaded,
+    /// Failed to load the WebAssembly module of a plugin.
+    #[cfg(feature = "auto-splitting")]
+    Wasm {
+        /// The underlying error.
+        source: livesplit_auto_splitting::CreationError,
+    },
+}
+
+/// The result type for all the operations of the plugin host.
+pub type Result<T = (), E = Error> = core::result::Result<T, E>;
+
+/// A plugin extends livesplit-core with additional functionality. All of the
+/// methods besides [`manifest`](Self::manifest) are optional and are only
+/// called by the [`Host`] if the plugin has the corresponding [`Permission`].
+pub trait Plugin: Send {
+    /// The manifest of the plugin.
+    fn manifest(&self) -> &Manifest;
+
+    /// Provides the comparison generators of the plugin. This requires the
+    /// [`Comparisons`](Permission::Comparisons) permission.
+    fn comparison_generators(&mut self) -> Vec<Box<dyn ComparisonGenerator>> {
+        Vec::new()
+    }
+
+    /// Provides the text of the plugin's custom components as pairs of names
+    /// and values. This is called whenever the host updates the texts and
+    /// requires the [`ComponentText`](Permission::ComponentText) permission.
+    fn component_texts(&mut self, _timer: &Timer) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Notifies the plugin about an event of the timer. This requires the
+    /// [`Events`](Permission::Events) permission.
+    fn handle_event(&mut self, _event: Event, _timer: &Timer) {}
+}
+
+/// The host manages all the loaded plugins and grants them access to the
+/// functionality declared in their manifests.
+#[derive(Default)]
+pub struct Host {
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+impl Host {
+    /// Creates a new host without any plugins.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Loads the plugin provided. Each plugin needs to have a unique name.
+    pub fn load(&mut self, plugin: Box<dyn Plugin>) -> Result {
+        let name = &plugin.manifest().name;
+        ensure!(
+            !self.plugins.iter().any(|p| p.manifest().name == *name),
+            AlreadyLoaded
+        );
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// Unloads the plugin with the name provided and returns it if it was
+    /// loaded. Comparison generators it registered with a run are not removed.
+    pub fn unload(&mut self, name: &str) -> Option<Box<dyn Plugin>> {
+        let index = self
+            .plugins
+            .iter()
+            .position(|p| p.manifest().name == name)?;
+        Some(self.plugins.remove(index))
+    }
+
+    /// Accesses the manifests of all the loaded plugins.
+    pub fn manifests(&self) -> impl Iterator<Item = &Manifest> + '_ {
+        self.plugins.iter().map(|p| p.manifest())
+    }
+
+    /// Registers the comparison generators of all the plugins with the
+    /// [`Comparisons`](Permission::Comparisons) permission with the run.
+    /// Comparison generators with the same name as an existing one replace it.
+    pub fn register_comparisons(&mut self, run: &mut Run) {
+        for plugin in &mut self.plugins {
+            if !plugin.manifest().has_permission(Permission::Comparisons) {
+                continue;
+            }
+            for generator in plugin.comparison_generators() {
+                let generators = run.comparison_generators_mut();
+                generators.retain(|g| g.name() != generator.name());
+                generators.push(generator);
+            }
+        }
+        run.regenerate_comparisons();
+    }
+
+    /// Updates the texts of the custom components of all the plugins with the
+    /// [`ComponentText`](Permission::ComponentText) permission. The texts are
+    /// stored as custom variables of the timer, so they can be shown by the
+    /// [`Text Component`](crate::component::text).
+    pub fn update_component_texts(&mut self, timer: &mut Timer) {
+        for plugin in &mut self.plugins {
+            if plugin.manifest().has_permission(Permission::ComponentText) {
+                for (name, value) in plugin.component_texts(timer) {
+                    timer.set_custom_variable(name, value);
+                }
+            }
+        }
+    }
+
+    /// Notifies all the plugins with the [`Events`](Permission::Events)
+    /// permission about an event of the timer.
+    pub fn handle_event(&mut self, event: Event, timer: &Timer) {
+        for plugin in &mut self.plugins {
+            if plugin.manifest().has_permission(Permission::Events) {
+                plugin.handle_event(event, timer);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{comparison::Ghost, Segment};
+
+    struct TestPlugin {
+        manifest: Manifest,
+    }
+
+    impl TestPlugin {
+        fn new(permissions: &[Permission]) -> Box<Self> {
+            let mut manifest = Manifest::new("Test");
+            manifest.permissions = permissions.to_vec();
+            Box::new(Self { manifest })
+        }
+    }
+
+    impl Plugin for TestPlugin {
+        fn manifest(&self) -> &Manifest {
+            &self.manifest
+        }
+
+        fn comparison_generators(&mut self) -> Vec<Box<dyn ComparisonGenerator>> {
+            vec![Box::new(Ghost::new("Plugin", Vec::new()))]
+        }
+
+        fn component_texts(&mut self, _timer: &Timer) -> Vec<(String, String)> {
+            vec![("Plugin Text".into(), "Hello".into())]
+        }
+    }
+
+    fn timer() -> Timer {
+        let mut run = Run::new();
+        run.push_segment(Segment::new("A"));
+        Timer::new(run).unwrap()
+    }
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = Manifest::from_json(
+            r#"{"name":"Foo","version":"1.0.0","permissions":["Comparisons","Events"]}"#,
+        )
+        .unwrap();
+        assert_eq!(manifest.name, "Foo");
+        assert!(manifest.has_permission(Permission::Comparisons));
+        assert!(!manifest.has_permission(Permission::ComponentText));
+        assert!(manifest.has_permission(Permission::Events));
+    }
+
+    #[test]
+    fn names_need_to_be_unique() {
+        let mut host = Host::new();
+        host.load(TestPlugin::new(&[])).unwrap();
+        assert!(matches!(
+            host.load(TestPlugin::new(&[])),
+            Err(Error::AlreadyLoaded)
+        ));
+        assert!(host.unload("Test").is_some());
+        assert_eq!(host.manifests().count(), 0);
+    }
+
+    #[test]
+    fn capabilities_require_permissions() {
+        let mut timer = timer();
+        let mut run = timer.run().clone();
+
+        let mut host = Host::new();
+        host.load(TestPlugin::new(&[])).unwrap();
+        host.register_comparisons(&mut run);
+        host.update_component_texts(&mut timer);
+
+        assert!(!run.comparisons().any(|c| c.ends_with("Plugin")));
+        assert!(timer
+            .run()
+            .metadata()
+            .custom_variable("Plugin Text")
+            .is_none());
+
+        let mut host = Host::new();
+        host.load(TestPlugin::new(&[
+            Permission::Comparisons,
+            Permission::ComponentText,
+        ]))
+        .unwrap();
+        host.register_comparisons(&mut run);
+        host.update_component_texts(&mut timer);
+
+        assert!(run.comparisons().any(|c| c.ends_with("Plugin")));
+        assert_eq!(
+            timer.run().metadata().custom_variable_value("Plugin Text"),
+            Some("Hello")
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Provides a [`Plugin`] that is backed by a WebAssembly module running on the
+//! runtime of the auto splitters. The permissions of the plugin's manifest are
+//! enforced by the runtime at the boundary of each host function, so a plugin
+//! can't use a capability it didn't declare, even if it tries to.
+
+use super::{Manifest, Permission, Plugin, Result, Wasm};
+use crate::{
+    comparison::ComparisonGenerator, event::Event, platform::Duration, Attempt, Segment, Time,
+    TimeSpan, Timer, TimingMethod,
+};
+use core::fmt;
+use livesplit_auto_splitting::{
+    Config, Plugin as Instance, PluginPermissions, PluginSegment, Runtime,
+};
+use snafu::ResultExt;
+use std::sync::{Arc, Mutex};
+
+/// A plugin that is provided as a WebAssembly module.
+pub struct WasmPlugin {
+    manifest: Manifest,
+    instance: Arc<Mutex<Instance>>,
+}
+
+impl WasmPlugin {
+    /// Compiles and instantiates the WebAssembly module provided. The module
+    /// is only granted the permissions declared in the manifest.
+    pub fn new(manifest: Manifest, module: &[u8]) -> Result<Self> {
+        let permissions = PluginPermissions {
+            comparisons: manifest.has_permission(Permission::Comparisons),
+            component_text: manifest.has_permission(Permission::ComponentText),
+            events: manifest.has_permission(Permission::Events),
+        };
+        let instance = Runtime::new(Config::default())
+            .context(Wasm)?
+            .compile_plugin(module)
+            .context(Wasm)?
+            .instantiate(permissions)
+            .context(Wasm)?;
+        Ok(Self {
+            manifest,
+            instance: Arc::new(Mutex::new(instance)),
+        })
+    }
+}
+
+impl Plugin for WasmPlugin {
+    fn manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    fn comparison_generators(&mut self) -> Vec<Box<dyn ComparisonGenerator>> {
+        let instance = self.instance.lock().unwrap();
+        instance
+            .comparisons()
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                Box::new(WasmComparison {
+                    name: name.to_string(),
+                    index,
+                    instance: self.instance.clone(),
+                }) as _
+            })
+            .collect()
+    }
+
+    fn component_texts(&mut self, _timer: &Timer) -> Vec<(String, String)> {
+        match self.instance.lock().unwrap().update() {
+            Ok(texts) => texts,
+            Err(e) => {
+                log::error!(target: "Plugin", "{}: {e:?}", self.manifest.name);
+                Vec::new()
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: Event, _timer: &Timer) {
+        if let Err(e) = self.instance.lock().unwrap().handle_event(event as u32) {
+            log::error!(target: "Plugin", "{}: {e:?}", self.manifest.name);
+        }
+    }
+}
+
+/// A comparison generator that is registered by a [`WasmPlugin`].
+#[derive(Clone)]
+struct WasmComparison {
+    name: String,
+    index: usize,
+    instance: Arc<Mutex<Instance>>,
+}
+
+impl fmt::Debug for WasmComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmComparison")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+fn times(time: Time) -> [Option<Duration>; 2] {
+    [
+        time[TimingMethod::RealTime].map(|t| t.to_duration()),
+        time[TimingMethod::GameTime].map(|t| t.to_duration()),
+    ]
+}
+
+impl ComparisonGenerator for WasmComparison {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        let plugin_segments = segments
+            .iter()
+            .map(|segment| PluginSegment {
+                personal_best_split_time: times(segment.personal_best_split_time()),
+                best_segment_time: times(segment.best_segment_time()),
+            })
+            .collect::<Vec<_>>();
+
+        let comparison_times = self
+            .instance
+            .lock()
+            .unwrap()
+            .generate_comparison(self.index, &plugin_segments)
+            .unwrap_or_else(|e| {
+                log::error!(target: "Plugin", "{}: {e:?}", self.name);
+                Vec::new()
+            });
+
+        for (index, segment) in segments.iter_mut().enumerate() {
+            let [real_time, game_time] = comparison_times.get(index).copied().unwrap_or_default();
+            *segment.comparison_mut(&self.name) = Time::new()
+                .with_real_time(real_time.map(TimeSpan::from))
+                .with_game_time(game_time.map(TimeSpan::from));
+        }
+    }
+}
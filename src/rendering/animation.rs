@@ -0,0 +1,103 @@
+use crate::TimeStamp;
+
+use super::Rgba;
+
+/// The clock that drives the animations of a single frame. It knows the point
+/// in time the frame is rendered at and how long animations take. If animations
+/// are disabled, all the transitions immediately show their target values.
+#[derive(Copy, Clone)]
+pub struct Clock {
+    animation: Option<(TimeStamp, f32)>,
+}
+
+impl Clock {
+    /// Creates a clock for a frame. The current point in time is only queried
+    /// if animations are enabled at all.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            animation: (duration > 0.0).then(|| (TimeStamp::now(), duration)),
+        }
+    }
+
+    fn now(&self) -> Option<TimeStamp> {
+        self.animation.map(|(now, _)| now)
+    }
+
+    fn progress(&self, start: Option<TimeStamp>) -> f32 {
+        match (self.animation, start) {
+            (Some((now, duration)), Some(start)) => (now - start).total_seconds() as f32 / duration,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Values that can be interpolated between.
+pub trait Lerp: Copy + PartialEq {
+    /// Interpolates between the two values, with `t` going from 0 to 1.
+    fn lerp(self, other: Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Rgba {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        [0, 1, 2, 3].map(|i| self[i].lerp(other[i], t))
+    }
+}
+
+/// A value that smoothly transitions towards its target whenever the target
+/// changes.
+#[derive(Copy, Clone)]
+pub struct Transition<T> {
+    from: T,
+    to: T,
+    start: Option<TimeStamp>,
+}
+
+impl<T: Lerp> Transition<T> {
+    /// Creates a transition that rests at the value provided.
+    pub const fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            start: None,
+        }
+    }
+
+    /// Creates a transition that starts at the first value and moves towards
+    /// the second value.
+    pub fn between(from: T, to: T, clock: &Clock) -> Self {
+        Self {
+            from,
+            to,
+            start: clock.now(),
+        }
+    }
+
+    /// Moves the transition towards the target provided, starting from the
+    /// value that is currently shown, and returns the value to show.
+    pub fn update(&mut self, target: T, clock: &Clock) -> T {
+        if target != self.to {
+            self.from = self.value(clock);
+            self.to = target;
+            self.start = clock.now();
+        }
+        self.value(clock)
+    }
+
+    /// Returns the value that is currently shown.
+    pub fn value(&self, clock: &Clock) -> T {
+        let t = clock.progress(self.start);
+        if t >= 1.0 {
+            return self.to;
+        }
+        // Ease out cubic, so the movement starts out fast and slowly comes to
+        // a stop.
+        let inv = 1.0 - t.max(0.0);
+        self.from.lerp(self.to, 1.0 - inv * inv * inv)
+    }
+}
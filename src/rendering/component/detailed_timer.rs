@@ -3,7 +3,7 @@ use crate::{
     layout::LayoutState,
     rendering::{
         component::timer,
-        consts::{vertical_padding, BOTH_PADDINGS, PADDING},
+        consts::{limit_icon_size, vertical_padding, BOTH_PADDINGS, PADDING},
         font::CachedLabel,
         resource::ResourceAllocator,
         scene::Layer,
@@ -45,10 +45,12 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     context.render_background([width, height], &component.background);
 
     let vertical_padding = vertical_padding(height);
-    let icon_size = height - 2.0 * vertical_padding;
+    let available_icon_size = height - 2.0 * vertical_padding;
+    let icon_size = limit_icon_size(available_icon_size, component.icon_size);
+    let icon_y = vertical_padding + 0.5 * (available_icon_size - icon_size);
 
     let left_side = if let Some(icon) = context.create_image(&component.icon) {
-        context.render_image([PADDING, vertical_padding], [icon_size, icon_size], icon);
+        context.render_image([PADDING, icon_y], [icon_size, icon_size], icon);
         BOTH_PADDINGS + icon_size
     } else {
         PADDING
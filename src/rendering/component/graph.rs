@@ -1,12 +1,176 @@
 use crate::{
-    component::graph::State,
+    component::graph::{Point, State},
     layout::LayoutState,
-    rendering::{Layer, PathBuilder, RenderContext, ResourceAllocator},
+    platform::prelude::*,
+    rendering::{
+        resource::{Handle, Handles, SharedOwnership},
+        Layer, PathBuilder, RenderContext, ResourceAllocator,
+    },
     settings::Gradient,
 };
+use core::iter;
 
-pub(in crate::rendering) fn render(
-    context: &mut RenderContext<'_, impl ResourceAllocator>,
+const GRID_LINE_WIDTH: f32 = 0.015;
+const LINE_WIDTH: f32 = 0.025;
+const CIRCLE_RADIUS: f32 = 0.035;
+
+/// The paths of the graph are cached across frames, as they only change when
+/// the points of the graph change, which usually only happens on splits. The
+/// graph is the only component that builds paths of its own. The glyphs of the
+/// labels are already cached by their font and glyph across all the labels and
+/// the backgrounds of the components are all drawn with the rectangle that is
+/// shared by the whole scene. So rendering an identical frame doesn't allocate
+/// any resources.
+pub struct Cache<P> {
+    geometry: Vec<u32>,
+    partial_fill: Option<Handle<P>>,
+    fill: Option<Handle<P>>,
+    series_lines: Vec<Handle<P>>,
+    lines: Vec<Handle<P>>,
+    circles: Vec<Handle<P>>,
+}
+
+impl<P> Cache<P> {
+    pub const fn new() -> Self {
+        Self {
+            geometry: Vec::new(),
+            partial_fill: None,
+            fill: None,
+            series_lines: Vec::new(),
+            lines: Vec::new(),
+            circles: Vec::new(),
+        }
+    }
+
+    fn is_up_to_date(&self, width: f32, component: &State) -> bool {
+        !self.geometry.is_empty() && self.geometry.iter().copied().eq(geometry(width, component))
+    }
+
+    fn rebuild<A: ResourceAllocator<Path = P>>(
+        &mut self,
+        handles: &mut Handles<A>,
+        width: f32,
+        component: &State,
+    ) {
+        self.geometry.clear();
+        self.geometry.extend(geometry(width, component));
+
+        let len = if component.is_live_delta_active {
+            let p1 = &component.points[component.points.len() - 2];
+            let p2 = &component.points[component.points.len() - 1];
+
+            let mut builder = handles.path_builder();
+            builder.move_to(width * p1.x, component.middle);
+            builder.line_to(width * p1.x, p1.y);
+            connect(&mut builder, width, component.smooth_lines, p1, p2);
+            builder.line_to(width * p2.x, component.middle);
+            builder.close();
+            self.partial_fill = Some(builder.finish());
+
+            component.points.len() - 1
+        } else {
+            self.partial_fill = None;
+            component.points.len()
+        };
+
+        let mut builder = handles.path_builder();
+        builder.move_to(0.0, component.middle);
+        let first = &component.points[0];
+        builder.line_to(width * first.x, first.y);
+        for points in component.points[..len].windows(2) {
+            connect(
+                &mut builder,
+                width,
+                component.smooth_lines,
+                &points[0],
+                &points[1],
+            );
+        }
+        builder.line_to(width * component.points[len - 1].x, component.middle);
+        builder.close();
+        self.fill = Some(builder.finish());
+
+        self.series_lines.clear();
+        for series in &component.additional_series {
+            for points in series.points.windows(2) {
+                self.series_lines
+                    .push(line(handles, width, component, points));
+            }
+        }
+
+        self.lines.clear();
+        for points in component.points.windows(2) {
+            self.lines.push(line(handles, width, component, points));
+        }
+
+        self.circles.clear();
+        for point in circle_points(component) {
+            self.circles
+                .push(handles.build_circle(width * point.x, point.y, CIRCLE_RADIUS));
+        }
+    }
+}
+
+/// The values that the paths of the graph depend on. The colors are not part
+/// of this, as they are applied when the paths are placed into the scene.
+fn geometry(width: f32, component: &State) -> impl Iterator<Item = u32> + '_ {
+    [
+        width.to_bits(),
+        component.middle.to_bits(),
+        component.smooth_lines as u32,
+        component.is_live_delta_active as u32,
+    ]
+    .into_iter()
+    .chain(coordinates(&component.points))
+    .chain(
+        component
+            .additional_series
+            .iter()
+            .flat_map(|series| coordinates(&series.points)),
+    )
+}
+
+fn coordinates(points: &[Point]) -> impl Iterator<Item = u32> + '_ {
+    iter::once(points.len() as u32).chain(
+        points
+            .iter()
+            .flat_map(|point| [point.x.to_bits(), point.y.to_bits()]),
+    )
+}
+
+/// The points that get a circle drawn on top of them. The first point is the
+/// origin of the graph and the last point is a preview of the next split if the
+/// live delta is active, so neither of them get a circle.
+fn circle_points(component: &State) -> impl Iterator<Item = &Point> {
+    let len = if component.is_live_delta_active {
+        component.points.len() - 1
+    } else {
+        component.points.len()
+    };
+    component.points[..len].iter().skip(1)
+}
+
+fn line<A: ResourceAllocator>(
+    handles: &mut Handles<A>,
+    width: f32,
+    component: &State,
+    points: &[Point],
+) -> Handle<A::Path> {
+    let mut builder = handles.path_builder();
+    builder.move_to(width * points[0].x, points[0].y);
+    connect(
+        &mut builder,
+        width,
+        component.smooth_lines,
+        &points[0],
+        &points[1],
+    );
+    builder.finish()
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Path>,
+    context: &mut RenderContext<'_, A>,
     [width, height]: [f32; 2],
     component: &State,
     _layout_state: &LayoutState,
@@ -15,10 +179,6 @@ pub(in crate::rendering) fn render(
     context.scale(height);
     let width = width / height;
 
-    const GRID_LINE_WIDTH: f32 = 0.015;
-    const LINE_WIDTH: f32 = 0.025;
-    const CIRCLE_RADIUS: f32 = 0.035;
-
     let layer = Layer::from_updates_frequently(component.updates_frequently);
 
     context.render_layer_rectangle(
@@ -52,63 +212,62 @@ pub(in crate::rendering) fn render(
         );
     }
 
-    let len = if component.is_live_delta_active {
-        let p1 = &component.points[component.points.len() - 2];
-        let p2 = &component.points[component.points.len() - 1];
-
-        let mut builder = context.handles.path_builder();
-        builder.move_to(width * p1.x, component.middle);
-        builder.line_to(width * p1.x, p1.y);
-        builder.line_to(width * p2.x, p2.y);
-        builder.line_to(width * p2.x, component.middle);
-        builder.close();
-        let partial_fill_path = builder.finish();
-        context.fill_path(partial_fill_path, component.partial_fill_color, layer);
+    if !cache.is_up_to_date(width, component) {
+        cache.rebuild(&mut context.handles, width, component);
+    }
 
-        component.points.len() - 1
-    } else {
-        component.points.len()
-    };
+    if let Some(partial_fill_path) = &cache.partial_fill {
+        context.fill_path(
+            partial_fill_path.share(),
+            component.partial_fill_color,
+            layer,
+        );
+    }
 
-    let mut builder = context.handles.path_builder();
-    builder.move_to(0.0, component.middle);
-    for p in &component.points[..len] {
-        builder.line_to(width * p.x, p.y);
+    if let Some(fill_path) = &cache.fill {
+        context.fill_path(fill_path.share(), component.complete_fill_color, layer);
     }
-    builder.line_to(width * component.points[len - 1].x, component.middle);
-    builder.close();
-    let fill_path = builder.finish();
-    context.fill_path(fill_path, component.complete_fill_color, layer);
 
-    for points in component.points.windows(2) {
-        let mut builder = context.handles.path_builder();
-        builder.move_to(width * points[0].x, points[0].y);
-        builder.line_to(width * points[1].x, points[1].y);
+    let mut series_lines = cache.series_lines.iter();
+    for series in &component.additional_series {
+        for line_path in series_lines
+            .by_ref()
+            .take(series.points.len().saturating_sub(1))
+        {
+            context.stroke_path(line_path.share(), series.color, LINE_WIDTH, layer);
+        }
+    }
 
+    for (points, line_path) in component.points.windows(2).zip(&cache.lines) {
         let color = if points[1].is_best_segment {
             component.best_segment_color
         } else {
             component.graph_lines_color
         };
 
-        let line_path = builder.finish();
-        context.stroke_path(line_path, color, LINE_WIDTH, layer);
+        context.stroke_path(line_path.share(), color, LINE_WIDTH, layer);
     }
 
-    for (i, point) in component.points.iter().enumerate().skip(1) {
-        if i != component.points.len() - 1 || !component.is_live_delta_active {
-            let color = if point.is_best_segment {
-                component.best_segment_color
-            } else {
-                component.graph_lines_color
-            };
-
-            let circle_path = context
-                .handles
-                .build_circle(width * point.x, point.y, CIRCLE_RADIUS);
-            context.fill_path(circle_path, color, layer);
-        }
+    for (point, circle_path) in circle_points(component).zip(&cache.circles) {
+        let color = if point.is_best_segment {
+            component.best_segment_color
+        } else {
+            component.graph_lines_color
+        };
+
+        context.fill_path(circle_path.share(), color, layer);
     }
 
     context.transform = old_transform;
 }
+
+/// Connects the two points with either a straight line or a smooth curve. The
+/// curve starts and ends horizontally, so it never overshoots either point.
+fn connect(builder: &mut impl PathBuilder, width: f32, smooth: bool, from: &Point, to: &Point) {
+    if smooth {
+        let middle_x = width * 0.5 * (from.x + to.x);
+        builder.curve_to(middle_x, from.y, middle_x, to.y, width * to.x, to.y);
+    } else {
+        builder.line_to(width * to.x, to.y);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     component::key_value::State,
     layout::{LayoutDirection, LayoutState},
+    platform::prelude::*,
     rendering::{
         font::{AbbreviatedLabel, CachedLabel},
         resource::ResourceAllocator,
@@ -11,10 +12,26 @@ use crate::{
 pub struct Cache<L> {
     key: AbbreviatedLabel<L>,
     value: CachedLabel<L>,
+    additional_rows: Vec<RowCache<L>>,
+}
+
+struct RowCache<L> {
+    key: AbbreviatedLabel<L>,
+    value: CachedLabel<L>,
 }
 
 impl<L> Cache<L> {
     pub const fn new() -> Self {
+        Self {
+            key: AbbreviatedLabel::new(),
+            value: CachedLabel::new(),
+            additional_rows: Vec::new(),
+        }
+    }
+}
+
+impl<L> RowCache<L> {
+    const fn new() -> Self {
         Self {
             key: AbbreviatedLabel::new(),
             value: CachedLabel::new(),
@@ -30,6 +47,24 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     layout_state: &LayoutState,
 ) {
     context.render_background(dim, &component.background);
+
+    let key_color = component.key_color.unwrap_or(layout_state.text_color);
+    let value_color = component.value_color.unwrap_or(layout_state.text_color);
+    let is_horizontal = layout_state.direction == LayoutDirection::Horizontal;
+    let display_two_rows = component.display_two_rows || is_horizontal;
+
+    // The additional rows are laid out next to each other in horizontal layouts
+    // and below each other in vertical layouts.
+    let [width, height] = dim;
+    let row_count = (component.additional_rows.len() + 1) as f32;
+    let (row_dim, row_offset) = if is_horizontal {
+        let row_width = width / row_count;
+        ([row_width, height], [row_width, 0.0])
+    } else {
+        let row_height = height / row_count;
+        ([width, row_height], [0.0, row_height])
+    };
+
     context.render_key_value_component(
         &component.key,
         &component.key_abbreviations,
@@ -37,9 +72,36 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         &component.value,
         &mut cache.value,
         component.updates_frequently,
-        dim,
-        component.key_color.unwrap_or(layout_state.text_color),
-        component.value_color.unwrap_or(layout_state.text_color),
-        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal,
+        row_dim,
+        key_color,
+        value_color,
+        display_two_rows,
     );
+
+    cache
+        .additional_rows
+        .resize_with(component.additional_rows.len(), RowCache::new);
+
+    let transform = context.transform;
+    for (row, row_cache) in component
+        .additional_rows
+        .iter()
+        .zip(&mut cache.additional_rows)
+    {
+        let [x, y] = row_offset;
+        context.translate(x, y);
+        context.render_key_value_component(
+            &row.key,
+            &row.key_abbreviations,
+            &mut row_cache.key,
+            &row.value,
+            &mut row_cache.value,
+            component.updates_frequently,
+            row_dim,
+            key_color,
+            value_color,
+            display_two_rows,
+        );
+    }
+    context.transform = transform;
 }
@@ -1,7 +1,8 @@
 use crate::layout::{ComponentState, LayoutState};
 
 use super::{
-    consts::{DEFAULT_COMPONENT_HEIGHT, PSEUDO_PIXELS, SEPARATOR_THICKNESS, TWO_ROW_HEIGHT},
+    consts::{DEFAULT_COMPONENT_HEIGHT, PSEUDO_PIXELS, TWO_ROW_HEIGHT},
+    font::ComponentFonts,
     resource::ResourceAllocator,
     RenderContext,
 };
@@ -16,9 +17,10 @@ pub mod text;
 pub mod timer;
 pub mod title;
 
-pub enum Cache<L> {
+pub enum Cache<P, L> {
     Empty,
     DetailedTimer(detailed_timer::Cache<L>),
+    Graph(graph::Cache<P>),
     KeyValue(key_value::Cache<L>),
     Splits(splits::Cache<L>),
     Text(text::Cache<L>),
@@ -42,10 +44,11 @@ macro_rules! accessors {
     };
 }
 
-impl<L> Cache<L> {
+impl<P, L> Cache<P, L> {
     pub const fn new(component: &ComponentState) -> Self {
         match component {
             ComponentState::DetailedTimer(_) => Self::DetailedTimer(detailed_timer::Cache::new()),
+            ComponentState::Graph(_) => Self::Graph(graph::Cache::new()),
             ComponentState::KeyValue(_) => Self::KeyValue(key_value::Cache::new()),
             ComponentState::Splits(_) => Self::Splits(splits::Cache::new()),
             ComponentState::Text(_) => Self::Text(text::Cache::new()),
@@ -59,6 +62,16 @@ impl<L> Cache<L> {
         *self = Self::Empty;
     }
 
+    fn graph(&mut self) -> &mut graph::Cache<P> {
+        match self {
+            Self::Graph(c) => c,
+            _ => {
+                *self = Self::Graph(graph::Cache::new());
+                self.graph()
+            }
+        }
+    }
+
     accessors! {
         DetailedTimer detailed_timer,
         KeyValue key_value,
@@ -82,8 +95,8 @@ pub fn width(component: &ComponentState) -> f32 {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
         ComponentState::DetailedTimer(_) => 7.0,
         ComponentState::Graph(_) => 7.0,
-        ComponentState::KeyValue(_) => 6.0,
-        ComponentState::Separator(_) => SEPARATOR_THICKNESS,
+        ComponentState::KeyValue(state) => (state.additional_rows.len() + 1) as f32 * 6.0,
+        ComponentState::Separator(state) => separator::thickness(state),
         ComponentState::Splits(state) => {
             let column_count = 2.0; // FIXME: Not always 2.
             let column_width = 2.75; // FIXME: Not always 2.75; difficult to calculate without a renderer.
@@ -104,13 +117,14 @@ pub fn height(component: &ComponentState) -> f32 {
         }
         ComponentState::Graph(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::KeyValue(state) => {
-            if state.display_two_rows {
-                TWO_ROW_HEIGHT
-            } else {
-                DEFAULT_COMPONENT_HEIGHT
-            }
+            (state.additional_rows.len() + 1) as f32
+                * if state.display_two_rows {
+                    TWO_ROW_HEIGHT
+                } else {
+                    DEFAULT_COMPONENT_HEIGHT
+                }
         }
-        ComponentState::Separator(_) => SEPARATOR_THICKNESS,
+        ComponentState::Separator(state) => separator::thickness(state),
         ComponentState::Splits(state) => {
             state.splits.len() as f32
                 * if state.display_two_rows {
@@ -136,13 +150,56 @@ pub fn height(component: &ComponentState) -> f32 {
     }
 }
 
+/// The fonts the component uses instead of the fonts of the layout.
+fn fonts(component: &ComponentState) -> ComponentFonts<'_> {
+    match component {
+        ComponentState::DetailedTimer(component) => ComponentFonts {
+            timer: &component.timer.timer_font,
+            times: &component.times_font,
+            text: &component.text_font,
+        },
+        ComponentState::KeyValue(component) => ComponentFonts {
+            times: &component.times_font,
+            text: &component.text_font,
+            ..ComponentFonts::NONE
+        },
+        ComponentState::Splits(component) => ComponentFonts {
+            times: &component.times_font,
+            text: &component.text_font,
+            ..ComponentFonts::NONE
+        },
+        ComponentState::Text(component) => ComponentFonts {
+            times: &component.times_font,
+            text: &component.text_font,
+            ..ComponentFonts::NONE
+        },
+        ComponentState::Timer(component) => ComponentFonts {
+            timer: &component.timer_font,
+            ..ComponentFonts::NONE
+        },
+        ComponentState::Title(component) => ComponentFonts {
+            times: &component.times_font,
+            text: &component.text_font,
+            ..ComponentFonts::NONE
+        },
+        ComponentState::BlankSpace(_) | ComponentState::Graph(_) | ComponentState::Separator(_) => {
+            ComponentFonts::NONE
+        }
+    }
+}
+
 pub(super) fn render<A: ResourceAllocator>(
-    cache: &mut Cache<A::Label>,
+    cache: &mut Cache<A::Path, A::Label>,
     context: &mut RenderContext<'_, A>,
+    index: usize,
     component: &ComponentState,
     state: &LayoutState,
     dim: [f32; 2],
 ) {
+    context
+        .fonts
+        .use_component_fonts(&mut context.handles, index, fonts(component));
+
     match component {
         ComponentState::BlankSpace(state) => {
             cache.make_empty();
@@ -152,8 +209,7 @@ pub(super) fn render<A: ResourceAllocator>(
             detailed_timer::render(cache.detailed_timer(), context, dim, component, state)
         }
         ComponentState::Graph(component) => {
-            cache.make_empty();
-            graph::render(context, dim, component, state)
+            graph::render(cache.graph(), context, dim, component, state)
         }
         ComponentState::KeyValue(component) => {
             key_value::render(cache.key_value(), context, dim, component, state)
@@ -175,4 +231,6 @@ pub(super) fn render<A: ResourceAllocator>(
             title::render(cache.title(), context, dim, component, state)
         }
     }
+
+    context.fonts.restore_layout_fonts(index);
 }
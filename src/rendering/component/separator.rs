@@ -1,15 +1,35 @@
 use crate::{
     component::separator::State,
     layout::LayoutState,
-    rendering::{resource::ResourceAllocator, RenderContext},
+    rendering::{
+        consts::{PSEUDO_PIXELS, SEPARATOR_THICKNESS},
+        resource::ResourceAllocator,
+        RenderContext,
+    },
     settings::Gradient,
 };
 
+pub(in crate::rendering) fn thickness(component: &State) -> f32 {
+    if component.thickness == 0 {
+        SEPARATOR_THICKNESS
+    } else {
+        component.thickness as f32 * PSEUDO_PIXELS
+    }
+}
+
 pub(in crate::rendering) fn render(
     context: &mut RenderContext<'_, impl ResourceAllocator>,
     dim: [f32; 2],
-    _component: &State,
+    component: &State,
     layout_state: &LayoutState,
 ) {
-    context.render_background(dim, &Gradient::Plain(layout_state.separators_color));
+    let color = component.color.unwrap_or(layout_state.separators_color);
+    let gradient = match component.gradient_color {
+        // The separator fades along its length, which is horizontal in
+        // vertical layouts and vertical in horizontal layouts.
+        Some(gradient_color) if dim[0] >= dim[1] => Gradient::Horizontal(color, gradient_color),
+        Some(gradient_color) => Gradient::Vertical(color, gradient_color),
+        None => Gradient::Plain(color),
+    };
+    context.render_background(dim, &gradient);
 }
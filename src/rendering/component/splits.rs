@@ -3,14 +3,16 @@ use crate::{
     layout::{LayoutDirection, LayoutState},
     platform::prelude::*,
     rendering::{
+        animation::Transition,
         consts::{
-            vertical_padding, BOTH_PADDINGS, DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING,
-            TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP, THIN_SEPARATOR_THICKNESS, TWO_ROW_HEIGHT,
+            limit_icon_size, vertical_padding, BOTH_PADDINGS, DEFAULT_COMPONENT_HEIGHT,
+            DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP,
+            THIN_SEPARATOR_THICKNESS, TWO_ROW_HEIGHT,
         },
         font::CachedLabel,
         resource::ResourceAllocator,
         scene::Layer,
-        solid, RenderContext,
+        FillShader, RenderContext, Rgba,
     },
     settings::{Gradient, ListGradient},
 };
@@ -58,15 +60,23 @@ unsafe impl Send for ShortLivedStr {}
 unsafe impl Sync for ShortLivedStr {}
 
 struct SplitCache<L> {
+    index: usize,
     name: CachedLabel<L>,
     columns: Vec<CachedLabel<L>>,
+    column_colors: Vec<Transition<Rgba>>,
+    position: Transition<f32>,
+    opacity: Transition<f32>,
 }
 
 impl<L> SplitCache<L> {
-    const fn new() -> Self {
+    const fn new(index: usize, position: f32, opacity: Transition<f32>) -> Self {
         Self {
+            index,
             name: CachedLabel::new(),
             columns: Vec::new(),
+            column_colors: Vec::new(),
+            position: Transition::new(position),
+            opacity,
         }
     }
 }
@@ -181,7 +191,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         };
 
     let transform = context.transform;
-    let text_color = solid(&layout_state.text_color);
+    let text_color = layout_state.text_color.to_array();
 
     if let Some(column_labels) = &component.column_labels {
         if layout_state.direction == LayoutDirection::Vertical {
@@ -201,7 +211,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                     Layer::Bottom,
                     [right_x, TEXT_ALIGN_TOP],
                     DEFAULT_TEXT_SIZE,
-                    text_color,
+                    FillShader::SolidColor(text_color),
                 );
                 let label_width = right_x - left_x;
                 if label_width > *max_width {
@@ -219,18 +229,63 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         }
     }
 
-    let icon_size = split_height - 2.0 * vertical_padding;
+    let available_icon_size = split_height - 2.0 * vertical_padding;
+    let icon_size = limit_icon_size(available_icon_size, component.icon_size);
+    let icon_y = icon_y + 0.5 * (available_icon_size - icon_size);
     let icon_right = if component.has_icons {
         BOTH_PADDINGS + icon_size
     } else {
         PADDING
     };
 
-    cache
-        .splits
-        .resize_with(component.splits.len(), SplitCache::new);
+    let clock = context.clock;
+
+    // The cached state of each split stays attached to the split, even if it
+    // moves to another row. This way it can transition from where it was shown
+    // before. Splits that newly show up fade in, unless it's the first frame.
+    let is_first_frame = cache.splits.is_empty();
+    for (row, split) in component.splits.iter().enumerate() {
+        if let Some(found) = cache.splits[row..]
+            .iter()
+            .position(|split_cache| split_cache.index == split.index)
+        {
+            cache.splits.swap(row, row + found);
+        } else {
+            let opacity = if is_first_frame {
+                Transition::new(1.0)
+            } else {
+                Transition::between(0.0, 1.0, &clock)
+            };
+            cache
+                .splits
+                .push(SplitCache::new(split.index, row as f32, opacity));
+            let last = cache.splits.len() - 1;
+            cache.splits.swap(row, last);
+        }
+    }
+    cache.splits.truncate(component.splits.len());
+
+    let last_row = component.splits.len().saturating_sub(1) as f32;
 
     for (i, (split, split_cache)) in component.splits.iter().zip(&mut cache.splits).enumerate() {
+        let row_transform = context.transform;
+        let position = split_cache.position.update(i as f32, &clock);
+        let offset = position - i as f32;
+        if offset != 0.0 {
+            context.translate(delta_x * offset, delta_y * offset);
+        }
+
+        // Rows that move in from outside of the component only show up once
+        // they are within its bounds, as they would overlap other components
+        // otherwise.
+        if !(0.0..=last_row).contains(&position) {
+            context.transform = row_transform;
+            context.translate(delta_x, delta_y);
+            continue;
+        }
+
+        let opacity = split_cache.opacity.value(&clock);
+
         if component.show_thin_separators && i + 1 != component.splits.len() {
             context.render_rectangle(
                 separator_pos,
@@ -245,7 +300,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 &component.current_split_gradient,
             );
         } else if let Some((even, odd)) = &split_background {
-            let color = if split.index % 2 == 0 { even } else { odd };
+            let color = if split.is_odd_row { odd } else { even };
             context.render_background(split_background_bottom_right, color);
         }
 
@@ -260,13 +315,24 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             split_cache
                 .columns
                 .resize_with(split.columns.len(), CachedLabel::new);
+            split_cache.column_colors.truncate(split.columns.len());
+            let known_columns = split_cache.column_colors.len();
+            split_cache.column_colors.extend(
+                split
+                    .columns
+                    .iter()
+                    .skip(known_columns)
+                    .map(|column| Transition::new(column.visual_color.to_array())),
+            );
 
-            for ((column, column_cache), (max_width, _)) in split
+            for (((column, column_cache), column_color), (max_width, _)) in split
                 .columns
                 .iter()
                 .zip(&mut split_cache.columns)
+                .zip(&mut split_cache.column_colors)
                 .zip(&cache.column_width_labels)
             {
+                let color = column_color.update(column.visual_color.to_array(), &clock);
                 if !column.value.is_empty() {
                     left_x = context.render_numbers(
                         &column.value,
@@ -274,7 +340,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                         Layer::from_updates_frequently(column.updates_frequently),
                         [right_x, split_height + TEXT_ALIGN_BOTTOM],
                         DEFAULT_TEXT_SIZE,
-                        solid(&column.visual_color),
+                        fade(color, opacity),
                     );
                 }
                 right_x -= max_width + PADDING;
@@ -284,15 +350,20 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 left_x = split_width;
             }
 
+            // Nested rows, such as the subsplits of the current section, are
+            // indented.
+            let name_x = icon_right + split.nesting_level as f32 * BOTH_PADDINGS;
+
             context.render_text_ellipsis(
                 &split.name,
                 &mut split_cache.name,
-                [icon_right, TEXT_ALIGN_TOP],
+                [name_x, TEXT_ALIGN_TOP],
                 DEFAULT_TEXT_SIZE,
-                text_color,
+                fade(text_color, opacity),
                 left_x - PADDING,
             );
         }
+        context.transform = row_transform;
         context.translate(delta_x, delta_y);
     }
 
@@ -313,3 +384,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
 
     context.transform = transform;
 }
+
+fn fade([r, g, b, a]: Rgba, opacity: f32) -> FillShader {
+    FillShader::SolidColor([r, g, b, a * opacity])
+}
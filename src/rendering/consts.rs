@@ -20,3 +20,14 @@ pub const DEFAULT_VERTICAL_WIDTH: f32 = 11.5;
 pub fn vertical_padding(height: f32) -> f32 {
     (ICON_MIN_VERTICAL_PADDING * height).min(PADDING)
 }
+
+/// Limits the size of an icon that fills the available size to the size in
+/// pixels the icon is supposed to be shown at. A size of 0 means that the icon
+/// fills the available size.
+pub fn limit_icon_size(available_size: f32, icon_size: u32) -> f32 {
+    if icon_size == 0 {
+        available_size
+    } else {
+        (icon_size as f32 * PSEUDO_PIXELS).min(available_size)
+    }
+}
@@ -7,6 +7,10 @@ mod cpal;
 
 const COLR: Tag = Tag::from_bytes(b"COLR");
 const CPAL: Tag = Tag::from_bytes(b"CPAL");
+#[cfg(feature = "font-loading")]
+const CBDT: Tag = Tag::from_bytes(b"CBDT");
+#[cfg(feature = "font-loading")]
+const SBIX: Tag = Tag::from_bytes(b"sbix");
 
 pub struct ColorTables<'f> {
     colr: &'f [u8],
@@ -41,6 +45,13 @@ impl<'f> ColorTables<'f> {
     }
 }
 
+/// Checks whether the font only provides its colored glyphs as bitmaps, like
+/// most emoji fonts do. Those glyphs can't be turned into paths.
+#[cfg(feature = "font-loading")]
+pub fn has_only_bitmap_colors(face: &cosmic_text::rustybuzz::ttf_parser::RawFace<'_>) -> bool {
+    (face.table(CBDT).is_some() || face.table(SBIX).is_some()) && face.table(COLR).is_none()
+}
+
 pub fn iter_colored_glyphs(
     color_tables: &Option<ColorTables<'_>>,
     palette: usize,
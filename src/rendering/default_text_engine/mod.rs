@@ -36,6 +36,7 @@ pub struct TextEngine<P> {
     font_system: FontSystem,
     shape_buffer: ShapeBuffer,
     glyph_cache: HashMap<(ID, u16), CachedGlyph<P>>,
+    visual_order: Vec<(usize, usize)>,
 }
 
 impl<P: SharedOwnership> Default for TextEngine<P> {
@@ -50,8 +51,43 @@ impl<P: SharedOwnership> TextEngine<P> {
         let mut db = Database::new();
 
         #[cfg(feature = "font-loading")]
-        db.load_system_fonts();
+        {
+            db.load_system_fonts();
+
+            // Emoji fonts that only provide bitmaps for their glyphs, such as
+            // Noto Color Emoji on Linux or Apple Color Emoji, would be chosen
+            // as the fallback for emoji, but can't be turned into paths. So we
+            // remove them, which allows the fallback to continue with fonts
+            // that we can render, such as Segoe UI Emoji on Windows.
+            let bitmap_fonts: Vec<_> = db
+                .faces()
+                .filter(|face| {
+                    db.with_face_data(face.id, |data, index| {
+                        cosmic_text::rustybuzz::ttf_parser::RawFace::parse(data, index)
+                            .is_ok_and(|face| color_font::has_only_bitmap_colors(&face))
+                    })
+                    .unwrap_or_default()
+                })
+                .map(|face| face.id)
+                .collect();
+            for id in bitmap_fonts {
+                db.remove_face(id);
+            }
+        }
+
+        Self::with_database(db)
+    }
+
+    /// Creates a new path based text engine that only knows about the fonts
+    /// bundled with livesplit-core, even if the fonts of the operating system
+    /// would get loaded otherwise. This way the text looks the same on every
+    /// system. Additional fonts can still be loaded via
+    /// [`load_font_data`](Self::load_font_data).
+    pub fn with_bundled_fonts() -> Self {
+        Self::with_database(Database::new())
+    }
 
+    fn with_database(mut db: Database) -> Self {
         db.load_font_source(Source::Binary(Arc::<&[u8]>::from(TIMER_FONT)));
         db.load_font_source(Source::Binary(Arc::<&[u8]>::from(TEXT_FONT)));
 
@@ -61,9 +97,22 @@ impl<P: SharedOwnership> TextEngine<P> {
             font_system: FontSystem::new_with_locale_and_db(String::from("en-US"), db),
             shape_buffer: ShapeBuffer::default(),
             glyph_cache: HashMap::new(),
+            visual_order: Vec::new(),
         }
     }
 
+    /// Loads the font data provided, such as the contents of a TrueType or
+    /// OpenType font file or font collection. The fonts it contains can then
+    /// be chosen by their family name in the font settings of the layout and
+    /// the components. They also serve as fallback fonts for characters that
+    /// the chosen fonts don't support, such as CJK characters or emoji. Color
+    /// emoji are supported if the font provides them as colored layers (`COLR`
+    /// and `CPAL` tables), like Twemoji Mozilla does. Only fonts that are
+    /// created afterwards are able to make use of them.
+    pub fn load_font_data(&mut self, data: Vec<u8>) {
+        self.font_system.db_mut().load_font_data(data);
+    }
+
     /// Creates a new font. You can call this directly from a
     /// [`ResourceAllocator`](super::ResourceAllocator).
     pub fn create_font(&mut self, font: Option<&settings::Font>, kind: FontKind) -> Font {
@@ -233,101 +282,102 @@ impl<P: SharedOwnership> TextEngine<P> {
             Shaping::Advanced,
             4,
         );
+        visual_order(&shape_line, &mut self.visual_order);
+        let words = self
+            .visual_order
+            .iter()
+            .map(|&(span, word)| &shape_line.spans[span].words[word]);
+
         let [mut x, mut y] = [0.0; 2];
 
         if let Some(monotonic) = &font.monotonic {
-            for span in &shape_line.spans {
-                for word in &span.words {
-                    if !word.blank {
-                        let mut glyphs = word.glyphs.iter();
-                        while let Some(glyph) = if shape_line.rtl {
-                            glyphs.next_back()
-                        } else {
-                            glyphs.next()
-                        } {
-                            let cached_glyph = cache_glyph(
-                                &mut self.glyph_cache,
-                                &mut self.font_system,
-                                glyph.font_id,
-                                glyph.glyph_id,
-                                &mut path_builder,
-                            );
-
-                            // FIXME: We use the x advance of the individual
-                            // glyph to remove any kerning that happened during
-                            // shaping. This is a workaround for the fact that
-                            // cosmic-text doesn't provide a way to turn off
-                            // kerning (and / or enable tabular nums) at the
-                            // moment.
-                            // https://github.com/pop-os/cosmic-text/issues/229
-                            let unkerned_x_advance = cached_glyph.unkerned_x_advance;
-
-                            let (x_advance, x_offset) = if monotonic
-                                .digit_glyphs
-                                .contains(&(glyph.font_id, glyph.glyph_id))
-                            {
-                                (
-                                    monotonic.digit_width,
-                                    0.5 * (monotonic.digit_width - unkerned_x_advance)
-                                        + glyph.x_offset,
-                                )
-                            } else {
-                                (unkerned_x_advance, glyph.x_offset)
-                            };
-
-                            label
-                                .glyphs
-                                .extend(cached_glyph.paths.iter().map(|(color, path)| Glyph {
-                                    color: *color,
-                                    x: x + x_offset,
-                                    y: y - glyph.y_offset,
-                                    path: path.share(),
-                                    scale: cached_glyph.scale,
-                                }));
-
-                            x += x_advance;
-                            y -= glyph.y_advance;
-                        }
+            for word in words {
+                if !word.blank {
+                    let mut glyphs = word.glyphs.iter();
+                    while let Some(glyph) = if shape_line.rtl {
+                        glyphs.next_back()
                     } else {
-                        x += word.width(1.0);
+                        glyphs.next()
+                    } {
+                        let cached_glyph = cache_glyph(
+                            &mut self.glyph_cache,
+                            &mut self.font_system,
+                            glyph.font_id,
+                            glyph.glyph_id,
+                            &mut path_builder,
+                        );
+
+                        // FIXME: We use the x advance of the individual
+                        // glyph to remove any kerning that happened during
+                        // shaping. This is a workaround for the fact that
+                        // cosmic-text doesn't provide a way to turn off
+                        // kerning (and / or enable tabular nums) at the
+                        // moment.
+                        // https://github.com/pop-os/cosmic-text/issues/229
+                        let unkerned_x_advance = cached_glyph.unkerned_x_advance;
+
+                        let (x_advance, x_offset) = if monotonic
+                            .digit_glyphs
+                            .contains(&(glyph.font_id, glyph.glyph_id))
+                        {
+                            (
+                                monotonic.digit_width,
+                                0.5 * (monotonic.digit_width - unkerned_x_advance) + glyph.x_offset,
+                            )
+                        } else {
+                            (unkerned_x_advance, glyph.x_offset)
+                        };
+
+                        label
+                            .glyphs
+                            .extend(cached_glyph.paths.iter().map(|(color, path)| Glyph {
+                                color: *color,
+                                x: x + x_offset,
+                                y: y - glyph.y_offset,
+                                path: path.share(),
+                                scale: cached_glyph.scale,
+                            }));
+
+                        x += x_advance;
+                        y -= glyph.y_advance;
                     }
+                } else {
+                    x += word.width(1.0);
                 }
             }
         } else {
-            for span in &shape_line.spans {
-                for word in &span.words {
-                    if !word.blank {
-                        let [mut glyph_x, mut glyph_y] = [x, y];
-                        let mut glyphs = word.glyphs.iter();
-                        while let Some(glyph) = if shape_line.rtl {
-                            glyphs.next_back()
-                        } else {
-                            glyphs.next()
-                        } {
-                            let cached_glyph = cache_glyph(
-                                &mut self.glyph_cache,
-                                &mut self.font_system,
-                                glyph.font_id,
-                                glyph.glyph_id,
-                                &mut path_builder,
-                            );
-
-                            label
-                                .glyphs
-                                .extend(cached_glyph.paths.iter().map(|(color, path)| Glyph {
-                                    color: *color,
-                                    x: glyph_x + glyph.x_offset,
-                                    y: glyph_y - glyph.y_offset,
-                                    path: path.share(),
-                                    scale: cached_glyph.scale,
-                                }));
-
-                            glyph_x += glyph.x_advance;
-                            glyph_y -= glyph.y_advance;
-                        }
+            for word in words {
+                if !word.blank {
+                    let [mut glyph_x, mut glyph_y] = [x, y];
+                    let mut glyphs = word.glyphs.iter();
+                    while let Some(glyph) = if shape_line.rtl {
+                        glyphs.next_back()
+                    } else {
+                        glyphs.next()
+                    } {
+                        let cached_glyph = cache_glyph(
+                            &mut self.glyph_cache,
+                            &mut self.font_system,
+                            glyph.font_id,
+                            glyph.glyph_id,
+                            &mut path_builder,
+                        );
+
+                        label
+                            .glyphs
+                            .extend(cached_glyph.paths.iter().map(|(color, path)| Glyph {
+                                color: *color,
+                                x: glyph_x + glyph.x_offset,
+                                y: glyph_y - glyph.y_offset,
+                                path: path.share(),
+                                scale: cached_glyph.scale,
+                            }));
+
+                        glyph_x += glyph.x_advance;
+                        glyph_y -= glyph.y_advance;
                     }
-                    x += word.width(1.0);
                 }
+                x += word.width(1.0);
             }
         }
 
@@ -337,20 +387,43 @@ impl<P: SharedOwnership> TextEngine<P> {
             if x > max_width {
                 let x_to_look_for = max_width - font.ellipsis_width;
 
-                let last_index = label
-                    .glyphs
-                    .iter()
-                    .enumerate()
-                    .rfind(|(_, g)| {
-                        x = g.x;
-                        y = g.y;
-                        g.x <= x_to_look_for
-                    })
-                    .map(|(i, _)| i)
-                    .unwrap_or_default();
-                label.glyphs.drain(last_index..);
-
-                // FIXME: Test RTL text.
+                let ellipsis_x = if shape_line.rtl {
+                    // Right-to-left text ends on the left, so that's where it
+                    // gets shortened. The remaining glyphs are moved over to
+                    // make room for the ellipsis.
+                    let x_to_look_for = x - x_to_look_for;
+                    let first_index = label
+                        .glyphs
+                        .iter()
+                        .position(|g| g.x >= x_to_look_for)
+                        .unwrap_or(label.glyphs.len());
+                    let first_x = label.glyphs.get(first_index).map_or(x, |g| g.x);
+                    label.glyphs.drain(..first_index);
+
+                    let shift = font.ellipsis_width - first_x;
+                    for glyph in &mut label.glyphs {
+                        glyph.x += shift;
+                    }
+                    x += shift;
+                    y = 0.0;
+                    0.0
+                } else {
+                    let last_index = label
+                        .glyphs
+                        .iter()
+                        .enumerate()
+                        .rfind(|(_, g)| {
+                            x = g.x;
+                            y = g.y;
+                            g.x <= x_to_look_for
+                        })
+                        .map(|(i, _)| i)
+                        .unwrap_or_default();
+                    label.glyphs.drain(last_index..);
+                    let ellipsis_x = x;
+                    x += font.ellipsis_width;
+                    ellipsis_x
+                };
 
                 let cached_glyph = cache_glyph(
                     &mut self.glyph_cache,
@@ -360,16 +433,18 @@ impl<P: SharedOwnership> TextEngine<P> {
                     &mut path_builder,
                 );
 
-                label
-                    .glyphs
-                    .extend(cached_glyph.paths.iter().map(|(color, path)| Glyph {
-                        color: *color,
-                        x,
-                        y,
-                        path: path.share(),
-                        scale: cached_glyph.scale,
-                    }));
-                x += font.ellipsis_width;
+                let ellipsis = cached_glyph.paths.iter().map(|(color, path)| Glyph {
+                    color: *color,
+                    x: ellipsis_x,
+                    y,
+                    path: path.share(),
+                    scale: cached_glyph.scale,
+                });
+                if shape_line.rtl {
+                    label.glyphs.splice(0..0, ellipsis);
+                } else {
+                    label.glyphs.extend(ellipsis);
+                }
             }
         }
 
@@ -377,6 +452,46 @@ impl<P: SharedOwnership> TextEngine<P> {
     }
 }
 
+/// Determines the order in which the words of the line need to be laid out
+/// from left to right. The spans of the line are in logical order, so they are
+/// reordered according to their bidirectional embedding levels, as described
+/// by rule L2 of the Unicode Bidirectional Algorithm. The words within the
+/// spans are already in visual order, except for right-to-left lines, where
+/// they need to be reversed.
+fn visual_order(shape_line: &ShapeLine, order: &mut Vec<(usize, usize)>) {
+    order.clear();
+    order.extend((0..shape_line.spans.len()).map(|span| (span, 0)));
+
+    let level = |&(span, _): &(usize, usize)| shape_line.spans[span].level.number();
+    let highest = order.iter().map(level).max().unwrap_or_default();
+    let lowest_odd = order
+        .iter()
+        .map(level)
+        .filter(|level| level % 2 == 1)
+        .min()
+        .unwrap_or(u8::MAX);
+
+    for current in (lowest_odd..=highest).rev() {
+        for run in order.chunk_by_mut(|a, b| (level(a) >= current) == (level(b) >= current)) {
+            if level(&run[0]) >= current {
+                run.reverse();
+            }
+        }
+    }
+
+    let span_count = order.len();
+    for i in 0..span_count {
+        let (span, _) = order[i];
+        let words = 0..shape_line.spans[span].words.len();
+        if shape_line.rtl {
+            order.extend(words.rev().map(|word| (span, word)));
+        } else {
+            order.extend(words.map(|word| (span, word)));
+        }
+    }
+    order.drain(..span_count);
+}
+
 fn cache_glyph<'gc, P, PB: PathBuilder<Path = P>>(
     glyph_cache: &'gc mut HashMap<(ID, u16), CachedGlyph<P>>,
     font_system: &mut FontSystem,
@@ -173,11 +173,13 @@ impl<I> Hash for Background<I> {
                     brightness,
                     opacity,
                     blur,
+                    fit,
                 } = image;
                 image.hash(state);
                 hash_float(*brightness, state);
                 hash_float(*opacity, state);
                 hash_float(*blur, state);
+                fit.hash(state);
                 hash_transform(transform, state);
             }
         }
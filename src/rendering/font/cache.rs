@@ -2,7 +2,8 @@ use super::{
     super::{FontKind, Handle},
     ResourceAllocator,
 };
-use crate::{layout::LayoutState, settings::Font};
+use crate::{layout::LayoutState, platform::prelude::*, settings::Font};
+use core::mem;
 
 pub struct CachedFont<F> {
     setting: Option<Font>,
@@ -30,10 +31,86 @@ impl<F> CachedFont<F> {
     }
 }
 
+/// A font that a component uses instead of the corresponding font of the
+/// layout. While the component is being rendered, it is swapped with the font
+/// of the layout.
+struct OverriddenFont<F> {
+    font: Option<CachedFont<F>>,
+    is_swapped_in: bool,
+}
+
+impl<F> OverriddenFont<F> {
+    const fn new() -> Self {
+        Self {
+            font: None,
+            is_swapped_in: false,
+        }
+    }
+
+    fn swap_in(
+        &mut self,
+        layout_font: &mut CachedFont<F>,
+        allocator: &mut impl ResourceAllocator<Font = Handle<F>>,
+        font_to_use: &Option<Font>,
+        font_kind: FontKind,
+    ) {
+        if font_to_use.is_none() {
+            self.font = None;
+            return;
+        }
+
+        match &mut self.font {
+            Some(font) => font.maybe_reload(allocator, font_to_use, font_kind),
+            None => {
+                self.font = Some(CachedFont {
+                    setting: font_to_use.clone(),
+                    font: allocator.create_font(font_to_use.as_ref(), font_kind),
+                });
+            }
+        }
+
+        if let Some(font) = &mut self.font {
+            mem::swap(layout_font, font);
+            self.is_swapped_in = true;
+        }
+    }
+
+    fn swap_out(&mut self, layout_font: &mut CachedFont<F>) {
+        if mem::take(&mut self.is_swapped_in) {
+            if let Some(font) = &mut self.font {
+                mem::swap(layout_font, font);
+            }
+        }
+    }
+}
+
+/// The fonts of a component that override the fonts of the layout. `None`
+/// means that the font of the layout is used.
+pub struct ComponentFonts<'a> {
+    pub timer: &'a Option<Font>,
+    pub times: &'a Option<Font>,
+    pub text: &'a Option<Font>,
+}
+
+impl ComponentFonts<'_> {
+    pub const NONE: ComponentFonts<'static> = ComponentFonts {
+        timer: &None,
+        times: &None,
+        text: &None,
+    };
+}
+
+struct ComponentFontCache<F> {
+    timer: OverriddenFont<F>,
+    times: OverriddenFont<F>,
+    text: OverriddenFont<F>,
+}
+
 pub struct FontCache<F> {
     pub timer: CachedFont<F>,
     pub times: CachedFont<F>,
     pub text: CachedFont<F>,
+    components: Vec<ComponentFontCache<F>>,
 }
 
 impl<F> FontCache<F> {
@@ -42,6 +119,50 @@ impl<F> FontCache<F> {
             timer: CachedFont::new(allocator.create_font(None, FontKind::Timer)),
             times: CachedFont::new(allocator.create_font(None, FontKind::Times)),
             text: CachedFont::new(allocator.create_font(None, FontKind::Text)),
+            components: Vec::new(),
+        }
+    }
+
+    /// Ensures there are exactly as many cached component fonts as there are
+    /// components.
+    pub fn resize_components(&mut self, len: usize) {
+        self.components.resize_with(len, || ComponentFontCache {
+            timer: OverriddenFont::new(),
+            times: OverriddenFont::new(),
+            text: OverriddenFont::new(),
+        });
+    }
+
+    /// Replaces the fonts of the layout with the fonts of the component with
+    /// the given index. They need to be restored with
+    /// [`restore_layout_fonts`](Self::restore_layout_fonts) once the
+    /// component is rendered.
+    pub fn use_component_fonts(
+        &mut self,
+        allocator: &mut impl ResourceAllocator<Font = Handle<F>>,
+        index: usize,
+        fonts: ComponentFonts<'_>,
+    ) {
+        if let Some(cache) = self.components.get_mut(index) {
+            cache
+                .timer
+                .swap_in(&mut self.timer, allocator, fonts.timer, FontKind::Timer);
+            cache
+                .times
+                .swap_in(&mut self.times, allocator, fonts.times, FontKind::Times);
+            cache
+                .text
+                .swap_in(&mut self.text, allocator, fonts.text, FontKind::Text);
+        }
+    }
+
+    /// Restores the fonts of the layout after rendering the component with the
+    /// given index.
+    pub fn restore_layout_fonts(&mut self, index: usize) {
+        if let Some(cache) = self.components.get_mut(index) {
+            cache.timer.swap_out(&mut self.timer);
+            cache.times.swap_out(&mut self.times);
+            cache.text.swap_out(&mut self.text);
         }
     }
 
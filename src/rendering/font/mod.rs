@@ -6,7 +6,7 @@ use super::{
 };
 use crate::{platform::prelude::*, util::ClearVec};
 
-pub use self::cache::FontCache;
+pub use self::cache::{ComponentFonts, FontCache};
 
 /// The default font to use for the text and the times.
 pub const TEXT_FONT: &[u8] = include_bytes!("assets/FiraSans-Regular.ttf");
@@ -9,7 +9,10 @@
 //! fonts and labels. An optional software renderer is available behind the
 //! `software-rendering` feature that uses tiny-skia to efficiently render the
 //! paths on the CPU. It is surprisingly fast and can be considered the default
-//! renderer.
+//! renderer. A terminal renderer that draws the layout as styled text is
+//! available behind the `terminal-rendering` feature. A renderer that draws the
+//! layout on the GPU with wgpu is available behind the `wgpu-rendering`
+//! feature.
 
 // # Coordinate spaces used in this module
 //
@@ -70,6 +73,7 @@
 // this height in horizontal mode. Separators have a thickness of 0.1, while
 // thin separators have half of this thickness.
 
+mod animation;
 mod component;
 mod consts;
 mod entity;
@@ -81,17 +85,24 @@ mod scene;
 #[cfg(feature = "default-text-engine")]
 pub mod default_text_engine;
 
+#[cfg(feature = "software-rendering")]
+pub mod shared_frame;
 #[cfg(feature = "software-rendering")]
 pub mod software;
 #[cfg(feature = "svg-rendering")]
 pub mod svg;
+#[cfg(feature = "terminal-rendering")]
+pub mod terminal;
 #[cfg(all(target_family = "wasm", feature = "web-rendering"))]
 pub mod web;
+#[cfg(feature = "wgpu-rendering")]
+pub mod wgpu;
 
 use self::{
+    animation::Clock,
     consts::{
-        DEFAULT_TEXT_SIZE, DEFAULT_VERTICAL_WIDTH, PADDING, TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP,
-        TWO_ROW_HEIGHT,
+        DEFAULT_TEXT_SIZE, DEFAULT_VERTICAL_WIDTH, PADDING, SEPARATOR_THICKNESS, TEXT_ALIGN_BOTTOM,
+        TEXT_ALIGN_TOP, TWO_ROW_HEIGHT,
     },
     font::{AbbreviatedLabel, CachedLabel, FontCache},
     icon::{CachedImage, ImageHandle},
@@ -100,7 +111,11 @@ use self::{
 use crate::{
     layout::{LayoutDirection, LayoutState},
     platform::prelude::*,
-    settings::{self, BackgroundImage, Color, Gradient, ImageCache, ImageId, LayoutBackground},
+    settings::{
+        self, BackgroundImage, BackgroundImageFit, Color, Gradient, ImageCache, ImageId,
+        LayoutBackground,
+    },
+    TimeSpan,
 };
 use alloc::borrow::Cow;
 use bytemuck_derive::{Pod, Zeroable};
@@ -113,7 +128,7 @@ pub use self::{
         FontKind, Handle, Image, Label, LabelHandle, PathBuilder, ResourceAllocator,
         SharedOwnership,
     },
-    scene::{Layer, Scene},
+    scene::{DirtyRect, Layer, Scene},
 };
 
 /// Describes a coordinate in 2D space.
@@ -156,6 +171,73 @@ pub enum Background<I> {
     Image(BackgroundImage<Handle<I>>, Transform),
 }
 
+/// Determines how the background of the layout gets rendered. Capture software
+/// can use this to cleanly composite the layout on top of other content.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum BackgroundMode {
+    /// The background of the layout is rendered as configured in the layout.
+    #[default]
+    Layout,
+    /// The background of the layout is not rendered at all, so it stays fully
+    /// transparent. The backgrounds of the components are still rendered,
+    /// keeping their translucency.
+    Transparent,
+    /// The background of the layout is replaced by a solid color, such as a
+    /// chroma key color.
+    ChromaKey(Rgba),
+}
+
+/// The rotation of the layout on the screen. Rotating the layout by 90° allows
+/// placing a thin vertical layout along the edge of a monitor, with all of its
+/// text running along the edge. The layout itself is still laid out as usual,
+/// so the resolution of the layout has its width and height swapped compared to
+/// the resolution on the screen.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Rotation {
+    /// The layout is not rotated.
+    #[default]
+    None,
+    /// The layout is rotated by 90° clockwise, so its top edge is on the right.
+    Clockwise,
+    /// The layout is rotated by 90° counterclockwise, so its top edge is on
+    /// the left.
+    CounterClockwise,
+}
+
+impl Rotation {
+    /// Converts a resolution on the screen into the resolution of the layout
+    /// and vice versa.
+    pub const fn swap_resolution<T: Copy>(self, [width, height]: [T; 2]) -> [T; 2] {
+        match self {
+            Rotation::None => [width, height],
+            Rotation::Clockwise | Rotation::CounterClockwise => [height, width],
+        }
+    }
+
+    /// Converts a point on the screen into the coordinate space of the layout.
+    /// Both are in pixels with the origin at the top left. This allows
+    /// frontends to hit test the layout with the positions of the mouse on the
+    /// screen.
+    pub fn screen_to_layout(self, [x, y]: Pos, [width, height]: [f32; 2]) -> Pos {
+        match self {
+            Rotation::None => [x, y],
+            Rotation::Clockwise => [y, width - x],
+            Rotation::CounterClockwise => [height - y, x],
+        }
+    }
+
+    /// Converts a point in the coordinate space of the layout into a point on
+    /// the screen with the resolution provided. Both are in pixels with the
+    /// origin at the top left.
+    pub fn layout_to_screen(self, [x, y]: Pos, [width, height]: [f32; 2]) -> Pos {
+        match self {
+            Rotation::None => [x, y],
+            Rotation::Clockwise => [width - y, x],
+            Rotation::CounterClockwise => [y, height - x],
+        }
+    }
+}
+
 enum CachedSize {
     Vertical(f32),
     Horizontal(f32),
@@ -173,11 +255,13 @@ enum CachedSize {
 /// curves, fonts and labels.
 pub struct SceneManager<P, I, F, L> {
     scene: Scene<P, I, L>,
-    components: Vec<component::Cache<L>>,
+    components: Vec<component::Cache<P, L>>,
     next_id: usize,
     cached_size: Option<CachedSize>,
     fonts: FontCache<F>,
     images: ImageCache<CachedImage<I>>,
+    background_mode: BackgroundMode,
+    animation_duration: f32,
 }
 
 impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager<P, I, F, L> {
@@ -196,14 +280,42 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             cached_size: None,
             fonts,
             images: ImageCache::new(),
+            background_mode: BackgroundMode::Layout,
+            animation_duration: 0.0,
         }
     }
 
+    /// Accesses how the background of the layout gets rendered.
+    pub const fn background_mode(&self) -> BackgroundMode {
+        self.background_mode
+    }
+
+    /// Sets how the background of the layout gets rendered. By default it is
+    /// rendered as configured in the layout.
+    pub fn set_background_mode(&mut self, background_mode: BackgroundMode) {
+        self.background_mode = background_mode;
+    }
+
     /// Accesses the [`Scene`] in order to render the [`Entities`](Entity).
     pub const fn scene(&self) -> &Scene<P, I, L> {
         &self.scene
     }
 
+    /// Accesses how long the transitions between the states of the layout take.
+    pub fn animation_duration(&self) -> TimeSpan {
+        TimeSpan::from_seconds(self.animation_duration as f64)
+    }
+
+    /// Sets how long the transitions between the states of the layout take.
+    /// This smoothly scrolls the splits, moves inserted and removed rows into
+    /// place and fades between the colors of the deltas. The animations are
+    /// driven by the point in time each frame is rendered at, so the scene
+    /// keeps changing until they are finished. A duration of zero, which is
+    /// the default, disables the animations entirely.
+    pub fn set_animation_duration(&mut self, duration: TimeSpan) {
+        self.animation_duration = duration.total_seconds().max(0.0) as f32;
+    }
+
     /// Updates the [`Scene`] by updating the [`Entities`](Entity) according to
     /// the [`LayoutState`] provided. The [`ResourceAllocator`] is used to
     /// allocate the resources necessary that the [`Entities`](Entity) use. A
@@ -228,6 +340,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         } else {
             self.components.truncate(state.components.len());
         }
+        self.fonts.resize_components(state.components.len());
 
         let new_dimensions = match state.direction {
             LayoutDirection::Vertical => {
@@ -239,6 +352,8 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         };
 
         self.scene.recalculate_if_bottom_layer_changed();
+        self.scene.recalculate_if_top_layer_changed();
+        self.scene.recalculate_dirty_rects(resolution);
 
         self.images.collect();
 
@@ -277,6 +392,8 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
 
         let aspect_ratio = width / height;
 
+        let background_mode = self.background_mode;
+
         let mut context = RenderContext {
             handles: Handles::new(self.next_id, allocator),
             transform: Transform::scale(width, height),
@@ -284,9 +401,11 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             fonts: &mut self.fonts,
             images: &mut self.images,
             image_cache,
+            clock: Clock::new(self.animation_duration),
         };
 
-        let background = context.decode_layout_background(&state.background, resolution);
+        let background =
+            context.decode_layout_background(&state.background, background_mode, resolution);
         context.scene.set_background(background);
 
         context.fonts.maybe_reload(&mut context.handles, state);
@@ -304,10 +423,18 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         // mode, all the components have the same width.
         let width = aspect_ratio * total_height;
 
-        for (component, cache) in state.components.iter().zip(&mut self.components) {
+        for (index, (component, cache)) in state
+            .components
+            .iter()
+            .zip(&mut self.components)
+            .enumerate()
+        {
             let height = component::height(component);
             let dim = [width, height];
-            component::render(cache, &mut context, component, state, dim);
+            let start = context.scene.component_start();
+            let bounds = context.transform.component_bounds(dim);
+            component::render(cache, &mut context, index, component, state, dim);
+            context.scene.track_component(start, bounds);
             // We translate the coordinate space to the Component Coordinate
             // Space of the next component by shifting by the height of the
             // current component in the Component Coordinate Space.
@@ -350,6 +477,8 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
 
         let aspect_ratio = width / height;
 
+        let background_mode = self.background_mode;
+
         let mut context = RenderContext {
             handles: Handles::new(self.next_id, allocator),
             transform: Transform::scale(width, height),
@@ -357,9 +486,11 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             fonts: &mut self.fonts,
             images: &mut self.images,
             image_cache,
+            clock: Clock::new(self.animation_duration),
         };
 
-        let background = context.decode_layout_background(&state.background, resolution);
+        let background =
+            context.decode_layout_background(&state.background, background_mode, resolution);
         context.scene.set_background(background);
 
         context.fonts.maybe_reload(&mut context.handles, state);
@@ -380,11 +511,19 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         // distribute to each of the components. This factor is this adjustment.
         let width_scaling = TWO_ROW_HEIGHT * aspect_ratio / total_width;
 
-        for (component, cache) in state.components.iter().zip(&mut self.components) {
+        for (index, (component, cache)) in state
+            .components
+            .iter()
+            .zip(&mut self.components)
+            .enumerate()
+        {
             let width = component::width(component) * width_scaling;
             let height = TWO_ROW_HEIGHT;
             let dim = [width, height];
-            component::render(cache, &mut context, component, state, dim);
+            let start = context.scene.component_start();
+            let bounds = context.transform.component_bounds(dim);
+            component::render(cache, &mut context, index, component, state, dim);
+            context.scene.track_component(start, bounds);
             // We translate the coordinate space to the Component Coordinate
             // Space of the next component by shifting by the width of the
             // current component in the Component Coordinate Space.
@@ -404,6 +543,7 @@ struct RenderContext<'b, A: ResourceAllocator> {
     fonts: &'b mut FontCache<A::Font>,
     images: &'b mut ImageCache<CachedImage<A::Image>>,
     image_cache: &'b ImageCache,
+    clock: Clock,
 }
 
 impl<A: ResourceAllocator> RenderContext<'_, A> {
@@ -801,8 +941,17 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
     fn decode_layout_background(
         &mut self,
         background: &LayoutBackground<ImageId>,
+        mode: BackgroundMode,
         [mut width, mut height]: [f32; 2],
     ) -> Option<Background<A::Image>> {
+        match mode {
+            BackgroundMode::Layout => {}
+            BackgroundMode::Transparent => return None,
+            BackgroundMode::ChromaKey(color) => {
+                return Some(Background::Shader(FillShader::SolidColor(color)))
+            }
+        }
+
         Some(match background {
             LayoutBackground::Gradient(gradient) => Background::Shader(decode_gradient(gradient)?),
             LayoutBackground::Image(background_image) => {
@@ -812,12 +961,24 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
                 let aspect_ratio_diff = image.handle.aspect_ratio() / box_aspect_ratio;
                 let [mut x, mut y] = [0.0; 2];
 
-                if aspect_ratio_diff > 1.0 {
+                // Covering the background grows the image along the dimension
+                // where it is relatively too small, while containing it within
+                // the background shrinks it along the dimension where it is
+                // relatively too large.
+                let (scale_width, scale_height) = match background_image.fit {
+                    BackgroundImageFit::Cover => (aspect_ratio_diff > 1.0, aspect_ratio_diff < 1.0),
+                    BackgroundImageFit::Contain => {
+                        (aspect_ratio_diff < 1.0, aspect_ratio_diff > 1.0)
+                    }
+                    BackgroundImageFit::Stretch => (false, false),
+                };
+
+                if scale_width {
                     let new_width = width * aspect_ratio_diff;
                     let diff_width = width - new_width;
                     x += 0.5 * diff_width;
                     width = new_width;
-                } else if aspect_ratio_diff < 1.0 {
+                } else if scale_height {
                     let new_height = height / aspect_ratio_diff;
                     let diff_height = height - new_height;
                     y += 0.5 * diff_height;
@@ -885,8 +1046,63 @@ impl Transform {
         }
     }
 
-    #[cfg(feature = "software-rendering")]
-    fn transform_y(&self, y: f32) -> f32 {
-        self.y + self.scale_y * y
+    /// Returns the region in the backend coordinate space that a component of
+    /// the dimensions provided covers. Separators may be drawn slightly
+    /// outside of the component, so the region is padded accordingly.
+    fn component_bounds(&self, [width, height]: [f32; 2]) -> DirtyRect {
+        let [x1, x2] =
+            [-SEPARATOR_THICKNESS, width + SEPARATOR_THICKNESS].map(|x| self.x + self.scale_x * x);
+        let [y1, y2] =
+            [-SEPARATOR_THICKNESS, height + SEPARATOR_THICKNESS].map(|y| self.y + self.scale_y * y);
+        DirtyRect {
+            top_left: [x1.min(x2), y1.min(y2)],
+            bottom_right: [x1.max(x2), y1.max(y2)],
+        }
+    }
+
+    #[cfg(any(feature = "software-rendering", feature = "wgpu-rendering"))]
+    fn transform_point(&self, [x, y]: Pos) -> Pos {
+        [self.x + self.scale_x * x, self.y + self.scale_y * y]
+    }
+}
+
+/// Blurs the premultiplied pixels of a background image with the blur
+/// specified in its settings.
+#[cfg(all(
+    feature = "image",
+    any(feature = "software-rendering", feature = "wgpu-rendering"),
+))]
+fn blur_image(
+    original_image: image::ImageBuffer<image::Rgba<u8>, &[u8]>,
+    blur: f32,
+) -> image::RgbaImage {
+    // Formula to calculate the sigma as specified
+    let dim = original_image.width().max(original_image.height()) as f32;
+    let sigma = settings::BLUR_FACTOR * blur * dim;
+
+    // For large blurs the calculation is actually very expensive, but we can
+    // get around that because large blurs don't require high resolutions in
+    // the first place. So we simply scale down the image based on the sigma
+    // to a smaller size and then blur the image. For the scaled down image we
+    // always use a sigma of 2.0, so scaling the image by 2.0 / sigma should
+    // resulting in the same amount of blur. Of course we never want to scale
+    // the image up, so in case the scale factor would end up in >= 1x, we
+    // simply don't do any scaling and keep the original sigma.
+    const SIGMA_WHEN_SCALED: f32 = 2.0;
+    let scale = SIGMA_WHEN_SCALED / sigma;
+
+    if scale < 1.0 {
+        // The image needs to at least be 1x1, because the renderers don't
+        // allow images to be smaller than that. A triangle filter is probably
+        // fine, the blur will hide most scaling artifacts anyway.
+        let scaled = image::imageops::resize(
+            &original_image,
+            ((scale * original_image.width() as f32) as u32).max(1),
+            ((scale * original_image.height() as f32) as u32).max(1),
+            image::imageops::FilterType::Triangle,
+        );
+        image::imageops::blur(&scaled, SIGMA_WHEN_SCALED)
+    } else {
+        image::imageops::blur(&original_image, sigma)
     }
 }
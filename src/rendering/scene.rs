@@ -1,7 +1,7 @@
 use super::{
     entity::{calculate_hash, Entity},
     resource::{Handle, SharedOwnership},
-    Background,
+    Background, Pos,
 };
 use crate::platform::prelude::*;
 
@@ -27,21 +27,66 @@ impl Layer {
     }
 }
 
+/// A rectangular region of the [`Scene`] that changed since the previous
+/// frame. It is specified in the coordinate space of the backend, i.e. the
+/// resolution that the [`Scene`] got updated with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DirtyRect {
+    /// The top left corner of the region.
+    pub top_left: Pos,
+    /// The bottom right corner of the region.
+    pub bottom_right: Pos,
+}
+
+impl DirtyRect {
+    fn union(self, other: Self) -> Self {
+        Self {
+            top_left: [
+                self.top_left[0].min(other.top_left[0]),
+                self.top_left[1].min(other.top_left[1]),
+            ],
+            bottom_right: [
+                self.bottom_right[0].max(other.bottom_right[0]),
+                self.bottom_right[1].max(other.bottom_right[1]),
+            ],
+        }
+    }
+}
+
+/// The region a component covers and the hashes of the [`Entities`](Entity)
+/// it placed on each of the [`Layers`](Layer).
+#[derive(Copy, Clone, PartialEq)]
+struct ComponentRegion {
+    bounds: DirtyRect,
+    hashes: [u64; 2],
+}
+
 /// A scene describes all the [`Entities`](Entity) to visualize. It consists of
 /// two [`Layers`](Layer) that are supposed to be composited on top of each
 /// other. The bottom [`Layer`] changes infrequently and doesn't need to be
 /// rerendered for most frames. The top [`Layer`] contains all the per frame
-/// changes and needs to be rerendered for every frame. If however it is empty
-/// and both the bottom layer didn't change, then no new frame needs to be
+/// changes and usually needs to be rerendered for every frame. If however
+/// neither of the two [`Layers`](Layer) changed, then no new frame needs to be
 /// rendered. While the top [`Layer`] is inherently transparent, the bottom
 /// [`Layer`] has a background that needs to be considered.
+///
+/// Additionally the scene tracks which of the components changed, so a
+/// renderer can limit itself to redrawing the [`DirtyRects`](DirtyRect) they
+/// cover.
 pub struct Scene<P, I, L> {
     rectangle: Handle<P>,
     background: Option<Background<I>>,
     bottom_hash: u64,
     bottom_layer_changed: bool,
     bottom_layer: Vec<Entity<P, I, L>>,
+    top_hash: u64,
+    top_layer_changed: bool,
     top_layer: Vec<Entity<P, I, L>>,
+    resolution: Pos,
+    background_hash: u64,
+    components: Vec<ComponentRegion>,
+    previous_components: Vec<ComponentRegion>,
+    dirty_rects: Vec<DirtyRect>,
 }
 
 impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L> {
@@ -54,7 +99,14 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
             bottom_hash: calculate_hash::<P, I, L>(&None, &[]),
             bottom_layer_changed: false,
             bottom_layer: Vec::new(),
+            top_hash: calculate_hash::<P, I, L>(&None, &[]),
+            top_layer_changed: false,
             top_layer: Vec::new(),
+            resolution: [0.0; 2],
+            background_hash: calculate_hash::<P, I, L>(&None, &[]),
+            components: Vec::new(),
+            previous_components: Vec::new(),
+            dirty_rects: Vec::new(),
         }
     }
 
@@ -73,6 +125,27 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         self.bottom_layer_changed
     }
 
+    /// Check if the scene's top [`Layer`] changed. While the top [`Layer`]
+    /// contains the frequently changing [`Entities`](Entity), there are still
+    /// lots of frames where none of them changed, such as when the timer is
+    /// not running. If neither this nor the bottom [`Layer`] changed, the
+    /// previous frame can be presented again.
+    pub const fn top_layer_changed(&self) -> bool {
+        self.top_layer_changed
+    }
+
+    /// Get the regions of the scene that changed since the previous frame,
+    /// because the [`Entities`](Entity) of the components covering them
+    /// changed. A renderer that kept the previous frame around only needs to
+    /// redraw these regions. All the [`Entities`](Entity) overlapping them need
+    /// to be redrawn though, not just the ones that changed. If the background,
+    /// the resolution or the components of the layout changed, a single region
+    /// covering the whole scene is reported. If nothing changed, there are no
+    /// regions at all.
+    pub fn dirty_rects(&self) -> &[DirtyRect] {
+        &self.dirty_rects
+    }
+
     /// Get a reference to the scene's bottom [`Layer`]. This [`Layer`] is
     /// intended to infrequently change, so it doesn't need to be rerendered
     /// every frame.
@@ -110,6 +183,57 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
     pub fn clear(&mut self) {
         self.bottom_layer.clear();
         self.top_layer.clear();
+        self.previous_components.clear();
+        core::mem::swap(&mut self.components, &mut self.previous_components);
+    }
+
+    /// Returns how many [`Entities`](Entity) are placed on each of the
+    /// [`Layers`](Layer). This marks where the next component starts placing
+    /// its [`Entities`](Entity).
+    pub(super) fn component_start(&self) -> [usize; 2] {
+        [self.bottom_layer.len(), self.top_layer.len()]
+    }
+
+    /// Tracks the component that placed all the [`Entities`](Entity) since
+    /// the start provided. The bounds are the region it covers in the
+    /// coordinate space of the backend.
+    pub(super) fn track_component(&mut self, [bottom, top]: [usize; 2], bounds: DirtyRect) {
+        let hashes = [
+            calculate_hash::<P, I, L>(&None, &self.bottom_layer[bottom..]),
+            calculate_hash::<P, I, L>(&None, &self.top_layer[top..]),
+        ];
+        self.components.push(ComponentRegion { bounds, hashes });
+    }
+
+    /// Compares the components with the ones of the previous frame to
+    /// determine the regions of the scene that changed.
+    pub(super) fn recalculate_dirty_rects(&mut self, resolution: Pos) {
+        self.dirty_rects.clear();
+
+        let background_hash = calculate_hash::<P, I, L>(&self.background, &[]);
+        let background_changed = background_hash != self.background_hash;
+        self.background_hash = background_hash;
+
+        let resolution_changed = resolution != self.resolution;
+        self.resolution = resolution;
+
+        if background_changed
+            || resolution_changed
+            || self.components.len() != self.previous_components.len()
+        {
+            self.dirty_rects.push(DirtyRect {
+                top_left: [0.0; 2],
+                bottom_right: resolution,
+            });
+            return;
+        }
+
+        for (component, previous) in self.components.iter().zip(&self.previous_components) {
+            if component != previous {
+                self.dirty_rects
+                    .push(component.bounds.union(previous.bounds));
+            }
+        }
     }
 
     /// Recalculates the hash of the bottom [`Layer`] and checks if it changed.
@@ -121,6 +245,13 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         self.bottom_hash = new_hash;
     }
 
+    /// Recalculates the hash of the top [`Layer`] and checks if it changed.
+    pub fn recalculate_if_top_layer_changed(&mut self) {
+        let new_hash = calculate_hash(&None, &self.top_layer);
+        self.top_layer_changed = new_hash != self.top_hash;
+        self.top_hash = new_hash;
+    }
+
     /// Accesses the [`Layer`] specified mutably.
     pub fn layer_mut(&mut self, layer: Layer) -> &mut Vec<Entity<P, I, L>> {
         match layer {
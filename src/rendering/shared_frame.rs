@@ -0,0 +1,201 @@
+//! Provides a [`Writer`] that renders the layout into a block of memory that
+//! is shared with another process, such as an OBS source plugin. This way
+//! overlays don't need to rely on window capture. The memory itself needs to be
+//! provided by the caller, for example by creating a shared memory object via
+//! `shm_open` or `CreateFileMapping` and mapping it into the address space.
+//!
+//! # Memory Layout
+//!
+//! The memory starts with a header of [`HEADER_SIZE`] bytes, followed by the
+//! pixels of the frame. All the values in the header are stored with the
+//! native endianness.
+//!
+//! | Offset | Type      | Description                                       |
+//! |--------|-----------|---------------------------------------------------|
+//! | 0      | `[u8; 4]` | The [`MAGIC`] bytes `LSSF`.                       |
+//! | 4      | `u32`     | The [`VERSION`] of the memory layout.             |
+//! | 8      | `u64`     | The sequence number. Odd while writing a frame.   |
+//! | 16     | `u64`     | The index of the frame. Increases for new frames. |
+//! | 24     | `u64`     | Nanoseconds since the first frame got written.    |
+//! | 32     | `u64`     | Nanoseconds since the previous frame got written. |
+//! | 40     | `u32`     | The width of the frame in pixels.                 |
+//! | 44     | `u32`     | The height of the frame in pixels.                |
+//! | 48     | `u32`     | The stride of the frame in bytes.                 |
+//!
+//! The pixels are stored as premultiplied `RGBA8` (red, green, blue, alpha with
+//! each channel being an u8).
+//!
+//! # Reading Frames
+//!
+//! The header works like a sequence lock. A reader first reads the sequence
+//! number and retries later if it is odd. It then copies the frame and reads
+//! the sequence number again. If it changed, the frame got overwritten while
+//! copying it and needs to be copied again. If the index of the frame didn't
+//! change since the last frame the reader copied, the frame can be skipped
+//! entirely.
+
+use core::{
+    ops::Range,
+    sync::atomic::{self, Ordering},
+};
+
+use crate::{layout::LayoutState, settings::ImageCache, TimeSpan, TimeStamp};
+
+use super::{software::BorrowedRenderer, BackgroundMode, Rotation};
+
+/// The bytes the shared memory starts with.
+pub const MAGIC: [u8; 4] = *b"LSSF";
+/// The version of the memory layout.
+pub const VERSION: u32 = 1;
+/// The size of the header in bytes. The pixels of the frame directly follow
+/// the header.
+pub const HEADER_SIZE: usize = 64;
+
+const SEQUENCE: Range<usize> = 8..16;
+const FRAME_INDEX: Range<usize> = 16..24;
+const TIMESTAMP: Range<usize> = 24..32;
+const FRAME_INTERVAL: Range<usize> = 32..40;
+const WIDTH: Range<usize> = 40..44;
+const HEIGHT: Range<usize> = 44..48;
+const STRIDE: Range<usize> = 48..52;
+
+/// Describes an Error that occurred while writing a frame into shared memory.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// The shared memory is too small to hold the frame.
+    MemoryTooSmall {
+        /// The amount of bytes that are required.
+        required: usize,
+    },
+}
+
+/// The Result type for the shared frame writer.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Calculates the amount of bytes the shared memory needs to have in order to
+/// hold a frame with the resolution provided.
+pub const fn memory_size([width, height]: [u32; 2]) -> usize {
+    HEADER_SIZE + 4 * width as usize * height as usize
+}
+
+/// The writer renders the layout into shared memory by using the software
+/// renderer. New frames are only written if the layout visually changed.
+pub struct Writer {
+    renderer: BorrowedRenderer,
+    sequence: u64,
+    frame_index: u64,
+    first_frame: Option<TimeStamp>,
+    last_frame: Option<TimeStamp>,
+    resolution: [u32; 2],
+}
+
+impl Default for Writer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writer {
+    /// Creates a new writer for shared memory.
+    pub fn new() -> Self {
+        Self {
+            renderer: BorrowedRenderer::new(),
+            sequence: 0,
+            frame_index: 0,
+            first_frame: None,
+            last_frame: None,
+            resolution: [0, 0],
+        }
+    }
+
+    /// Accesses the index of the last frame that got written.
+    pub const fn frame_index(&self) -> u64 {
+        self.frame_index
+    }
+
+    /// Sets how the background of the layout gets rendered. Check out
+    /// [`BorrowedRenderer::set_background_mode`] for more information.
+    pub fn set_background_mode(&mut self, background_mode: BackgroundMode) {
+        self.renderer.set_background_mode(background_mode);
+    }
+
+    /// Sets the rotation of the layout in the frame. Check out
+    /// [`BorrowedRenderer::set_rotation`] for more information.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.renderer.set_rotation(rotation);
+    }
+
+    /// Renders the layout state provided into the shared memory with the
+    /// resolution provided. The memory needs to be at least
+    /// [`memory_size`] bytes large. It is assumed that only this writer writes
+    /// into the memory, as only the parts of the frame that changed get
+    /// redrawn. It may detect that the layout got resized. In that case it
+    /// returns the new ideal size. This is just a hint and can be ignored
+    /// entirely.
+    pub fn write(
+        &mut self,
+        memory: &mut [u8],
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        resolution @ [width, height]: [u32; 2],
+    ) -> Result<Option<[f32; 2]>> {
+        let required = memory_size(resolution);
+        if memory.len() < required {
+            return MemoryTooSmall { required }.fail();
+        }
+        let (header, pixels) = memory[..required].split_at_mut(HEADER_SIZE);
+
+        let force_redraw = self.resolution != resolution
+            || header[..4] != MAGIC
+            || read_u64(header, FRAME_INDEX) != self.frame_index;
+        self.resolution = resolution;
+
+        self.begin(header);
+        let (new_resolution, changed) =
+            self.renderer
+                .render_frame(state, image_cache, pixels, resolution, width, force_redraw);
+
+        if changed {
+            let now = TimeStamp::now();
+            let first_frame = *self.first_frame.get_or_insert(now);
+            let interval = self
+                .last_frame
+                .replace(now)
+                .map_or(0, |last| nanos(now - last));
+            self.frame_index += 1;
+
+            header[..4].copy_from_slice(&MAGIC);
+            header[4..8].copy_from_slice(&VERSION.to_ne_bytes());
+            header[FRAME_INDEX].copy_from_slice(&self.frame_index.to_ne_bytes());
+            header[TIMESTAMP].copy_from_slice(&nanos(now - first_frame).to_ne_bytes());
+            header[FRAME_INTERVAL].copy_from_slice(&interval.to_ne_bytes());
+            header[WIDTH].copy_from_slice(&width.to_ne_bytes());
+            header[HEIGHT].copy_from_slice(&height.to_ne_bytes());
+            header[STRIDE].copy_from_slice(&(4 * width).to_ne_bytes());
+        }
+        self.end(header);
+
+        Ok(new_resolution)
+    }
+
+    fn begin(&mut self, header: &mut [u8]) {
+        self.sequence = read_u64(header, SEQUENCE).max(self.sequence) | 1;
+        header[SEQUENCE].copy_from_slice(&self.sequence.to_ne_bytes());
+        atomic::fence(Ordering::Release);
+    }
+
+    fn end(&mut self, header: &mut [u8]) {
+        atomic::fence(Ordering::Release);
+        self.sequence += 1;
+        header[SEQUENCE].copy_from_slice(&self.sequence.to_ne_bytes());
+    }
+}
+
+fn read_u64(header: &[u8], range: Range<usize>) -> u64 {
+    u64::from_ne_bytes(header[range].try_into().unwrap())
+}
+
+fn nanos(span: TimeSpan) -> u64 {
+    span.to_duration().whole_nanoseconds().max(0) as u64
+}
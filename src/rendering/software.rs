@@ -5,27 +5,43 @@ use super::{
     default_text_engine::{Font, Label, TextEngine},
     entity::Entity,
     resource::{self, ResourceAllocator},
-    FillShader, FontKind, Scene, SceneManager, SharedOwnership, Transform,
+    BackgroundMode, DirtyRect, FillShader, FontKind, Pos, Rotation, Scene, SceneManager,
+    SharedOwnership, Transform,
 };
 use crate::{
-    layout::LayoutState, platform::prelude::*, rendering::Background, settings,
+    layout::LayoutState,
+    platform::{
+        math::f32::{ceil, floor},
+        prelude::*,
+    },
+    rendering::Background,
+    settings,
     settings::ImageCache,
+    TimeSpan,
 };
 use alloc::rc::Rc;
-use core::{mem, ops::Deref};
+use core::ops::Deref;
 use tiny_skia::{
-    BlendMode, Color, FillRule, FilterQuality, GradientStop, LinearGradient, Paint, Path,
+    BlendMode, Color, FillRule, FilterQuality, GradientStop, LinearGradient, Mask, Paint, Path,
     PathBuilder, Pattern, Pixmap, PixmapMut, Point, Rect, Shader, SpreadMode, Stroke,
 };
 use tiny_skia_path::NormalizedF32;
 
 #[cfg(feature = "image")]
-use crate::settings::{BackgroundImage, BLUR_FACTOR};
+use crate::settings::BackgroundImage;
 #[cfg(feature = "image")]
-use image::{imageops::FilterType, ImageBuffer};
+use image::ImageBuffer;
 #[cfg(feature = "image")]
 use tiny_skia_path::IntSize;
 
+#[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+use std::sync::{
+    atomic::{self, AtomicBool},
+    mpsc,
+};
+#[cfg(feature = "std")]
+use std::sync::{Arc, OnceLock};
+
 #[cfg(feature = "image")]
 pub use image::{self, RgbaImage};
 
@@ -37,10 +53,44 @@ type SkiaFont = Font;
 type SkiaLabel = Label<SkiaPath>;
 
 struct Image {
-    pixmap: Pixmap,
+    pixmap: Pixels,
     aspect_ratio: f32,
 }
 
+// The pixels of an image may get decoded on a separate thread, in which case
+// they only become available later.
+#[cfg(feature = "std")]
+type Pixels = Arc<OnceLock<Pixmap>>;
+#[cfg(not(feature = "std"))]
+type Pixels = Pixmap;
+
+impl Image {
+    #[cfg(feature = "image")]
+    fn new(pixmap: Pixmap) -> Self {
+        let aspect_ratio = pixmap.width() as f32 / pixmap.height() as f32;
+        Self {
+            #[cfg(feature = "std")]
+            pixmap: Arc::new(OnceLock::from(pixmap)),
+            #[cfg(not(feature = "std"))]
+            pixmap,
+            aspect_ratio,
+        }
+    }
+
+    /// Accesses the pixels of the image. They are not available while the
+    /// image is still being decoded.
+    fn pixmap(&self) -> Option<&Pixmap> {
+        #[cfg(feature = "std")]
+        {
+            self.pixmap.get()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            Some(&self.pixmap)
+        }
+    }
+}
+
 impl resource::Image for SkiaImage {
     fn aspect_ratio(&self) -> f32 {
         self.aspect_ratio
@@ -92,6 +142,98 @@ fn convert_transform(transform: &Transform) -> tiny_skia::Transform {
 
 struct SkiaAllocator {
     text_engine: TextEngine<SkiaPath>,
+    #[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+    image_decoder: Option<ImageDecoder>,
+}
+
+impl SkiaAllocator {
+    const fn new(text_engine: TextEngine<SkiaPath>) -> Self {
+        Self {
+            text_engine,
+            #[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+            image_decoder: None,
+        }
+    }
+
+    /// Returns whether any images finished decoding in the background since
+    /// the last time this got called.
+    fn take_decoded_images(&self) -> bool {
+        #[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+        if let Some(decoder) = &self.image_decoder {
+            return decoder.decoded.swap(false, atomic::Ordering::Acquire);
+        }
+        false
+    }
+}
+
+/// Decodes images on a worker thread, so decoding many large images, such as
+/// the icons of all the segments, doesn't stall the renderer.
+#[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+struct ImageDecoder {
+    sender: mpsc::Sender<(Vec<u8>, Pixels)>,
+    decoded: Arc<AtomicBool>,
+}
+
+#[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+impl ImageDecoder {
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::channel::<(Vec<u8>, Pixels)>();
+        let decoded = Arc::new(AtomicBool::new(false));
+        let thread_decoded = decoded.clone();
+        // The thread stops once the sender gets dropped.
+        let _ = std::thread::Builder::new()
+            .name("Image Decoder".into())
+            .spawn(move || {
+                for (data, pixels) in receiver {
+                    if let Some(pixmap) = decode_image(&data) {
+                        let _ = pixels.set(pixmap);
+                        thread_decoded.store(true, atomic::Ordering::Release);
+                    }
+                }
+            });
+        Self { sender, decoded }
+    }
+
+    /// Queues up the image to be decoded. Only the dimensions of the image are
+    /// determined right away.
+    fn decode(&self, data: &[u8]) -> Option<Image> {
+        let (width, height) = image::ImageReader::new(std::io::Cursor::new(data))
+            .with_guessed_format()
+            .ok()?
+            .into_dimensions()
+            .ok()?;
+        let pixels = Pixels::default();
+        self.sender.send((data.to_vec(), pixels.clone())).ok()?;
+        Some(Image {
+            pixmap: pixels,
+            aspect_ratio: width as f32 / height as f32,
+        })
+    }
+}
+
+#[cfg(feature = "image")]
+fn decode_image(data: &[u8]) -> Option<Pixmap> {
+    let mut buf = image::load_from_memory(data).ok()?.to_rgba8();
+
+    // Premultiplication
+    for [r, g, b, a] in bytemuck::cast_slice_mut::<u8, [u8; 4]>(&mut buf) {
+        // If it's opaque we can skip the entire pixel. However this
+        // hurts vectorization, so we want to avoid it if the compiler
+        // can vectorize the loop. WASM, PowerPC, and MIPS are
+        // unaffected at the moment.
+        #[cfg(not(any(target_feature = "avx2", target_feature = "neon")))]
+        if *a == 0xFF {
+            continue;
+        }
+        let a = *a as u16;
+        *r = ((*r as u16 * a) / 255) as u8;
+        *g = ((*g as u16 * a) / 255) as u8;
+        *b = ((*b as u16 * a) / 255) as u8;
+    }
+
+    let (width, height) = (buf.width(), buf.height());
+
+    Pixmap::from_vec(buf.into_raw(), IntSize::from_wh(width, height)?)
 }
 
 impl ResourceAllocator for SkiaAllocator {
@@ -108,32 +250,12 @@ impl ResourceAllocator for SkiaAllocator {
     fn create_image(&mut self, _data: &[u8]) -> Option<Self::Image> {
         #[cfg(feature = "image")]
         {
-            let mut buf = image::load_from_memory(_data).ok()?.to_rgba8();
-
-            // Premultiplication
-            for [r, g, b, a] in bytemuck::cast_slice_mut::<u8, [u8; 4]>(&mut buf) {
-                // If it's opaque we can skip the entire pixel. However this
-                // hurts vectorization, so we want to avoid it if the compiler
-                // can vectorize the loop. WASM, PowerPC, and MIPS are
-                // unaffected at the moment.
-                #[cfg(not(any(target_feature = "avx2", target_feature = "neon")))]
-                if *a == 0xFF {
-                    continue;
-                }
-                let a = *a as u16;
-                *r = ((*r as u16 * a) / 255) as u8;
-                *g = ((*g as u16 * a) / 255) as u8;
-                *b = ((*b as u16 * a) / 255) as u8;
+            #[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+            if let Some(decoder) = &self.image_decoder {
+                return decoder.decode(_data).map(UnsafeRc::new);
             }
 
-            let (width, height) = (buf.width(), buf.height());
-
-            let pixmap = Pixmap::from_vec(buf.into_raw(), IntSize::from_wh(width, height)?)?;
-
-            Some(UnsafeRc::new(Image {
-                pixmap,
-                aspect_ratio: width as f32 / height as f32,
-            }))
+            Some(UnsafeRc::new(Image::new(decode_image(_data)?)))
         }
         #[cfg(not(feature = "image"))]
         {
@@ -182,8 +304,9 @@ pub struct BorrowedRenderer {
     #[cfg(feature = "image")]
     blurred_background_image: Option<(BackgroundImage<usize>, Pixmap)>,
     background: Pixmap,
-    min_y: f32,
-    max_y: f32,
+    mask: Mask,
+    rotation: Rotation,
+    unrotated: Option<Pixmap>,
 }
 
 struct UnsafeRc<T>(Rc<T>);
@@ -237,9 +360,19 @@ impl Default for BorrowedRenderer {
 impl BorrowedRenderer {
     /// Creates a new software renderer.
     pub fn new() -> Self {
-        let mut allocator = SkiaAllocator {
-            text_engine: TextEngine::new(),
-        };
+        Self::with_text_engine(TextEngine::new())
+    }
+
+    /// Creates a new software renderer that only uses the fonts bundled with
+    /// livesplit-core and the fonts that get loaded via
+    /// [`load_font_data`](Self::load_font_data), but never the fonts of the
+    /// operating system. This way the layout renders the same on every system.
+    pub fn with_bundled_fonts() -> Self {
+        Self::with_text_engine(TextEngine::with_bundled_fonts())
+    }
+
+    fn with_text_engine(text_engine: TextEngine<SkiaPath>) -> Self {
+        let mut allocator = SkiaAllocator::new(text_engine);
         let scene_manager = SceneManager::new(&mut allocator);
         Self {
             allocator,
@@ -247,8 +380,57 @@ impl BorrowedRenderer {
             #[cfg(feature = "image")]
             blurred_background_image: None,
             background: Pixmap::new(1, 1).unwrap(),
-            min_y: f32::INFINITY,
-            max_y: f32::NEG_INFINITY,
+            mask: Mask::new(1, 1).unwrap(),
+            rotation: Rotation::None,
+            unrotated: None,
+        }
+    }
+
+    /// Loads the font data provided, such as the contents of a TrueType or
+    /// OpenType font file or font collection. The fonts it contains can then
+    /// be chosen by their family name in the font settings of the layout and
+    /// the components. They also serve as fallback fonts for characters that
+    /// the chosen fonts don't support. The next frame is fully rendered again
+    /// to make use of the new fonts.
+    pub fn load_font_data(&mut self, data: Vec<u8>) {
+        self.allocator.text_engine.load_font_data(data);
+        let background_mode = self.scene_manager.background_mode();
+        let animation_duration = self.scene_manager.animation_duration();
+        self.scene_manager = SceneManager::new(&mut self.allocator);
+        self.scene_manager.set_background_mode(background_mode);
+        self.scene_manager
+            .set_animation_duration(animation_duration);
+    }
+
+    /// Sets how the background of the layout gets rendered. It can either be
+    /// left out entirely, so the image stays transparent where the components
+    /// don't draw anything, or it can be replaced by a solid chroma key color.
+    /// As the image consists of premultiplied pixels, capture software can
+    /// composite it cleanly, keeping the translucency of the components. The
+    /// next frame is fully rendered again to make use of the new mode.
+    pub fn set_background_mode(&mut self, background_mode: BackgroundMode) {
+        self.scene_manager.set_background_mode(background_mode);
+    }
+
+    /// Sets how long the transitions between the states of the layout take,
+    /// such as the splits scrolling or the deltas changing their colors. The
+    /// animations are driven by the point in time each frame is rendered at.
+    /// A duration of zero, which is the default, disables the animations.
+    pub fn set_animation_duration(&mut self, duration: TimeSpan) {
+        self.scene_manager.set_animation_duration(duration);
+    }
+
+    /// Sets whether images, such as the icons of the segments, get decoded on
+    /// a separate thread. This way loading a run with lots of large icons
+    /// doesn't stall the rendering of the next frame. Until an image is
+    /// decoded, nothing is drawn in its place. Once it is decoded, the next
+    /// frame is fully redrawn. This only affects images that are not in use
+    /// yet. On platforms that don't support threads, the images are always
+    /// decoded right away. This is disabled by default.
+    pub fn set_decode_images_in_background(&mut self, _enabled: bool) {
+        #[cfg(all(feature = "image", feature = "std", not(target_family = "wasm")))]
+        if _enabled != self.allocator.image_decoder.is_some() {
+            self.allocator.image_decoder = _enabled.then(ImageDecoder::new);
         }
     }
 
@@ -262,8 +444,14 @@ impl BorrowedRenderer {
     /// that the layout got resized. In that case it returns the new ideal size.
     /// This is just a hint and can be ignored entirely. The image is always
     /// rendered with the resolution provided. By default the renderer will try
-    /// not to redraw parts of the image that haven't changed. You can force a
-    /// redraw in case the image provided or its contents have changed.
+    /// not to redraw parts of the image that haven't changed. Only the
+    /// [`DirtyRects`](DirtyRect) of the components that changed are redrawn
+    /// and if nothing changed at all, the image isn't touched. This assumes
+    /// that the image provided is the same one as in the previous call and
+    /// still contains the previous frame. If that's not the case, such as when
+    /// swapping between multiple buffers, you need to force a redraw. The same
+    /// applies if the image's contents have changed in any other way. Use the
+    /// owning [`Renderer`] if you don't want to manage this yourself.
     pub fn render(
         &mut self,
         state: &LayoutState,
@@ -273,10 +461,110 @@ impl BorrowedRenderer {
         stride: u32,
         force_redraw: bool,
     ) -> Option<[f32; 2]> {
+        self.render_frame(
+            state,
+            image_cache,
+            image,
+            [width, height],
+            stride,
+            force_redraw,
+        )
+        .0
+    }
+
+    /// Sets the rotation of the layout in the image. The resolution that is
+    /// passed to [`render`](Self::render), as well as the new ideal size it
+    /// may return, are always the ones of the image, so they are in screen
+    /// space. Use [`Rotation::screen_to_layout`] to hit test the layout. The
+    /// next frame is fully rendered again to make use of the new rotation.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        if rotation != self.rotation {
+            self.rotation = rotation;
+            self.unrotated = None;
+        }
+    }
+
+    /// Renders the frame like [`render`](Self::render), but additionally
+    /// returns whether the image got touched at all.
+    pub(super) fn render_frame(
+        &mut self,
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        image: &mut [u8],
+        resolution: [u32; 2],
+        stride: u32,
+        force_redraw: bool,
+    ) -> (Option<[f32; 2]>, bool) {
+        if self.rotation == Rotation::None {
+            return self.render_unrotated(
+                state,
+                image_cache,
+                image,
+                resolution,
+                stride,
+                force_redraw,
+            );
+        }
+
+        // The layout is rendered into an image of its own, which then gets
+        // rotated into the image provided.
+        let [width, height] = self.rotation.swap_resolution(resolution);
+        let (mut unrotated, is_new) = match self.unrotated.take() {
+            Some(pixmap) if pixmap.width() == width && pixmap.height() == height => (pixmap, false),
+            _ => (Pixmap::new(width, height).unwrap(), true),
+        };
+
+        let (new_resolution, changed) = self.render_unrotated(
+            state,
+            image_cache,
+            unrotated.data_mut(),
+            [width, height],
+            width,
+            is_new,
+        );
+
+        // The unrotated image may have been rendered before, so it only gets
+        // rotated if it changed or the caller asked for it.
+        let changed = changed || force_redraw;
+        if changed {
+            let image = bytemuck::cast_slice_mut::<u8, [u8; 4]>(image);
+            let pixels = bytemuck::cast_slice::<u8, [u8; 4]>(unrotated.data());
+            let stride = stride as usize;
+            let [width, height] = [width as usize, height as usize];
+            for (y, row) in pixels.chunks_exact(width).enumerate() {
+                for (x, &pixel) in row.iter().enumerate() {
+                    let (screen_x, screen_y) = match self.rotation {
+                        Rotation::Clockwise => (height - 1 - y, x),
+                        _ => (y, width - 1 - x),
+                    };
+                    image[screen_y * stride + screen_x] = pixel;
+                }
+            }
+        }
+
+        self.unrotated = Some(unrotated);
+
+        (
+            new_resolution.map(|resolution| self.rotation.swap_resolution(resolution)),
+            changed,
+        )
+    }
+
+    fn render_unrotated(
+        &mut self,
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        image: &mut [u8],
+        [width, height]: [u32; 2],
+        stride: u32,
+        force_redraw: bool,
+    ) -> (Option<[f32; 2]>, bool) {
         let mut frame_buffer = PixmapMut::from_bytes(image, stride, height).unwrap();
 
-        if stride != self.background.width() || height != self.background.height() {
+        let resized = stride != self.background.width() || height != self.background.height();
+        if resized {
             self.background = Pixmap::new(stride, height).unwrap();
+            self.mask = Mask::new(stride, height).unwrap();
         }
 
         let new_resolution = self.scene_manager.update_scene(
@@ -290,11 +578,21 @@ impl BorrowedRenderer {
         let rectangle = scene.rectangle();
         let rectangle = rectangle.as_deref().unwrap();
 
-        let bottom_layer_changed = scene.bottom_layer_changed();
+        // Images that finished decoding in the background need to be drawn,
+        // even if the scene didn't change. They may be anywhere in the scene,
+        // so the whole image needs to be redrawn.
+        let full_redraw = force_redraw || resized || self.allocator.take_decoded_images();
+        let dirty_rects = scene.dirty_rects();
+
+        if !full_redraw && dirty_rects.is_empty() {
+            // The image still contains the previous frame, which looks exactly
+            // the same.
+            return (new_resolution, false);
+        }
 
         let mut background = self.background.as_mut();
 
-        if bottom_layer_changed {
+        if full_redraw || scene.bottom_layer_changed() {
             fill_background(
                 scene,
                 #[cfg(feature = "image")]
@@ -304,31 +602,83 @@ impl BorrowedRenderer {
                 height,
                 rectangle,
             );
-            render_layer(&mut background, scene.bottom_layer(), rectangle);
+            render_layer(&mut background, scene.bottom_layer(), rectangle, None);
         }
 
         let top_layer = scene.top_layer();
 
-        let [min_y, max_y] = calculate_bounds(top_layer);
-        let min_y = mem::replace(&mut self.min_y, min_y).min(min_y);
-        let max_y = mem::replace(&mut self.max_y, max_y).max(max_y);
-
-        if force_redraw || bottom_layer_changed {
+        if full_redraw {
             frame_buffer
                 .data_mut()
                 .copy_from_slice(background.data_mut());
-        } else if min_y <= max_y {
-            let stride = 4 * stride as usize;
-            let min_y = stride * (min_y - 1.0) as usize;
-            let max_y = stride * ((max_y + 2.0) as usize).min(height as usize);
+            render_layer(&mut frame_buffer, top_layer, rectangle, None);
+        } else {
+            // Only the regions that changed get restored from the background
+            // and the top layer gets clipped to them, so the rest of the
+            // previous frame stays intact.
+            let rects = dirty_rects
+                .iter()
+                .filter_map(|rect| PixelRect::new(rect, [width, height]))
+                .collect::<Vec<_>>();
+
+            let mask = self.mask.data_mut();
+            mask.fill(0);
+
+            let stride = stride as usize;
+            let frame_buffer_data = frame_buffer.data_mut();
+            let background_data = background.data_mut();
+            for rect in &rects {
+                for y in rect.top..rect.bottom {
+                    let row = y * stride;
+                    mask[row + rect.left..row + rect.right].fill(u8::MAX);
+                    let [left, right] = [4 * (row + rect.left), 4 * (row + rect.right)];
+                    frame_buffer_data[left..right].copy_from_slice(&background_data[left..right]);
+                }
+            }
 
-            frame_buffer.data_mut()[min_y..max_y]
-                .copy_from_slice(&background.data_mut()[min_y..max_y]);
+            render_layer(
+                &mut frame_buffer,
+                top_layer,
+                rectangle,
+                Some((&self.mask, &rects)),
+            );
         }
 
-        render_layer(&mut frame_buffer, top_layer, rectangle);
+        (new_resolution, true)
+    }
+}
 
-        new_resolution
+/// A [`DirtyRect`] rounded outwards to whole pixels and clamped to the image.
+/// It additionally includes the pixels that anti-aliasing may touch.
+struct PixelRect {
+    left: usize,
+    top: usize,
+    right: usize,
+    bottom: usize,
+}
+
+impl PixelRect {
+    fn new(rect: &DirtyRect, [width, height]: [u32; 2]) -> Option<Self> {
+        let [left, top] = rect.top_left.map(|v| (floor(v) - 1.0).max(0.0));
+        let right = (ceil(rect.bottom_right[0]) + 1.0).min(width as f32);
+        let bottom = (ceil(rect.bottom_right[1]) + 1.0).min(height as f32);
+        if left >= right || top >= bottom {
+            return None;
+        }
+        Some(Self {
+            left: left as usize,
+            top: top as usize,
+            right: right as usize,
+            bottom: bottom as usize,
+        })
+    }
+
+    fn intersects(&self, [[left, top], [right, bottom]]: [Pos; 2]) -> bool {
+        // Anti-aliasing may touch the pixels surrounding the bounds.
+        left - 1.0 < self.right as f32
+            && right + 1.0 > self.left as f32
+            && top - 1.0 < self.bottom as f32
+            && bottom + 1.0 > self.top as f32
     }
 }
 
@@ -356,6 +706,55 @@ impl Renderer {
         }
     }
 
+    /// Creates a new software renderer that only uses the fonts bundled with
+    /// livesplit-core and the fonts that get loaded via
+    /// [`load_font_data`](Self::load_font_data). Check out
+    /// [`BorrowedRenderer::with_bundled_fonts`] for more information.
+    pub fn with_bundled_fonts() -> Self {
+        Self {
+            renderer: BorrowedRenderer::with_bundled_fonts(),
+            frame_buffer: Pixmap::new(1, 1).unwrap(),
+        }
+    }
+
+    /// Loads the font data provided, such as the contents of a TrueType or
+    /// OpenType font file or font collection. The fonts it contains can then
+    /// be chosen by their family name in the font settings of the layout and
+    /// the components. They also serve as fallback fonts for characters that
+    /// the chosen fonts don't support. The next frame is fully rendered again
+    /// to make use of the new fonts.
+    pub fn load_font_data(&mut self, data: Vec<u8>) {
+        self.renderer.load_font_data(data);
+    }
+
+    /// Sets how the background of the layout gets rendered. Check out
+    /// [`BorrowedRenderer::set_background_mode`] for more information.
+    pub fn set_background_mode(&mut self, background_mode: BackgroundMode) {
+        self.renderer.set_background_mode(background_mode);
+    }
+
+    /// Sets the rotation of the layout in the image. Check out
+    /// [`BorrowedRenderer::set_rotation`] for more information.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        self.renderer.set_rotation(rotation);
+    }
+
+    /// Sets how long the transitions between the states of the layout take.
+    /// Check out [`BorrowedRenderer::set_animation_duration`] for more
+    /// information.
+    pub fn set_animation_duration(&mut self, duration: TimeSpan) {
+        self.renderer.set_animation_duration(duration);
+    }
+
+    /// Sets whether images, such as the icons of the segments, get decoded on
+    /// a separate thread. Until an image is decoded, nothing is drawn in its
+    /// place. This is disabled by default. Check out
+    /// [`BorrowedRenderer::set_decode_images_in_background`] for more
+    /// information.
+    pub fn set_decode_images_in_background(&mut self, enabled: bool) {
+        self.renderer.set_decode_images_in_background(enabled);
+    }
+
     /// Renders the layout state provided with the chosen resolution. It may
     /// detect that the layout got resized. In that case it returns the new
     /// ideal size. This is just a hint and can be ignored entirely. The image
@@ -366,7 +765,8 @@ impl Renderer {
         image_cache: &ImageCache,
         [width, height]: [u32; 2],
     ) -> Option<[f32; 2]> {
-        if width != self.frame_buffer.width() || height != self.frame_buffer.height() {
+        let resized = width != self.frame_buffer.width() || height != self.frame_buffer.height();
+        if resized {
             self.frame_buffer = Pixmap::new(width, height).unwrap();
         }
 
@@ -376,10 +776,32 @@ impl Renderer {
             self.frame_buffer.data_mut(),
             [width, height],
             width,
-            false,
+            resized,
         )
     }
 
+    /// Renders the layout state provided with the chosen size in logical
+    /// pixels on a display with the scale factor provided. The image is
+    /// rendered with the physical resolution, which is the size multiplied by
+    /// the scale factor, so the text and the icons stay crisp on HiDPI
+    /// displays instead of the image getting scaled up. Just like with
+    /// [`render`](Self::render), it may return a new ideal size, which is in
+    /// logical pixels as well.
+    pub fn render_scaled(
+        &mut self,
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        [width, height]: [f32; 2],
+        scale_factor: f32,
+    ) -> Option<[f32; 2]> {
+        let resolution = [
+            (width * scale_factor + 0.5) as u32,
+            (height * scale_factor + 0.5) as u32,
+        ];
+        self.render(state, image_cache, resolution)
+            .map(|[width, height]| [width / scale_factor, height / scale_factor])
+    }
+
     /// Accesses the image as a byte slice of RGBA8 encoded pixels (red, green,
     /// blue, alpha with each channel being an u8).
     pub fn image_data(&self) -> &[u8] {
@@ -413,14 +835,71 @@ impl Renderer {
         )
         .unwrap()
     }
+
+    /// Encodes the image as a PNG and writes it into the writer provided. The
+    /// colors of the image data are premultiplied by their alpha, so they get
+    /// converted back to straight alpha for the PNG, which makes sure that
+    /// transparent parts of the layout keep their colors.
+    #[cfg(feature = "image")]
+    pub fn write_png<W: std::io::Write>(&self, writer: W) -> image::ImageResult<()> {
+        use image::{codecs::png::PngEncoder, ExtendedColorType, ImageEncoder};
+
+        let data: Vec<u8> = self
+            .frame_buffer
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect();
+
+        PngEncoder::new(writer).write_image(
+            &data,
+            self.frame_buffer.width(),
+            self.frame_buffer.height(),
+            ExtendedColorType::Rgba8,
+        )
+    }
+}
+
+/// Renders the layout state into a new image with the resolution provided. The
+/// rendering is deterministic, as only the fonts bundled with livesplit-core
+/// and the fonts provided are used, but never the fonts of the operating
+/// system. Animations are disabled and all the images are decoded right away.
+/// This allows comparing the renders of layouts against golden images, even
+/// across different systems and versions of livesplit-core.
+#[cfg(feature = "image")]
+pub fn render_to_image(
+    state: &LayoutState,
+    image_cache: &ImageCache,
+    resolution: [u32; 2],
+    fonts: &[&[u8]],
+) -> RgbaImage {
+    let mut renderer = Renderer::with_bundled_fonts();
+    for font in fonts {
+        renderer.load_font_data(font.to_vec());
+    }
+    renderer.render(state, image_cache, resolution);
+    renderer.into_image()
 }
 
 fn render_layer(
     canvas: &mut PixmapMut<'_>,
     layer: &[Entity<SkiaPath, SkiaImage, SkiaLabel>],
     rectangle: &Path,
+    clip: Option<(&Mask, &[PixelRect])>,
 ) {
+    let mask = clip.map(|(mask, _)| mask);
     for entity in layer {
+        if let Some((_, rects)) = clip {
+            // Entities outside of the regions to redraw can be skipped
+            // entirely, as the mask would discard all their pixels anyway.
+            match calculate_bounds(entity) {
+                Some(bounds) if rects.iter().any(|rect| rect.intersects(bounds)) => {}
+                _ => continue,
+            }
+        }
         match entity {
             Entity::FillPath(path, shader, transform) => {
                 if let Some(path) = path.as_deref() {
@@ -442,7 +921,7 @@ fn render_layer(
                         &paint,
                         FillRule::Winding,
                         convert_transform(transform),
-                        None,
+                        mask,
                     );
                 }
             }
@@ -460,22 +939,26 @@ fn render_layer(
                             ..Default::default()
                         },
                         convert_transform(transform),
-                        None,
+                        mask,
                     );
                 }
             }
             Entity::Image(image, transform) => {
+                // Images that are still being decoded are left out for now.
+                let Some(pixmap) = image.pixmap() else {
+                    continue;
+                };
                 canvas.fill_path(
                     rectangle,
                     &Paint {
                         shader: Pattern::new(
-                            image.pixmap.as_ref(),
+                            pixmap.as_ref(),
                             SpreadMode::Pad,
                             FilterQuality::Bilinear,
                             1.0,
                             tiny_skia::Transform::from_scale(
-                                1.0 / image.pixmap.width() as f32,
-                                1.0 / image.pixmap.height() as f32,
+                                1.0 / pixmap.width() as f32,
+                                1.0 / pixmap.height() as f32,
                             ),
                         ),
                         anti_alias: true,
@@ -483,7 +966,7 @@ fn render_layer(
                     },
                     FillRule::Winding,
                     convert_transform(transform),
-                    None,
+                    mask,
                 );
             }
             Entity::Label(label, shader, transform) => {
@@ -545,7 +1028,7 @@ fn render_layer(
                             paint,
                             FillRule::Winding,
                             convert_transform(&transform),
-                            None,
+                            mask,
                         );
                     }
                 }
@@ -669,15 +1152,18 @@ fn fill_background(
             Background::Image(image, transform) => {
                 #[cfg(feature = "image")]
                 let pixmap = if image.blur != 0.0 {
-                    blurred_background_image
-                        .as_ref()
-                        .map(|(_, pixmap)| pixmap)
-                        .unwrap()
+                    blurred_background_image.as_ref().map(|(_, pixmap)| pixmap)
                 } else {
-                    &image.image.pixmap
+                    image.image.pixmap()
                 };
                 #[cfg(not(feature = "image"))]
-                let pixmap = &image.image.pixmap;
+                let pixmap = image.image.pixmap();
+
+                // The image may still be getting decoded.
+                let Some(pixmap) = pixmap else {
+                    background_layer.data_mut().fill(0);
+                    return;
+                };
 
                 let transform = convert_transform(transform);
                 background_layer.fill_path(
@@ -736,55 +1222,18 @@ fn update_blurred_background_image(
                 .as_ref()
                 .is_some_and(|(key, _)| &current_key == key)
             {
+                let Some(pixmap) = image.image.pixmap() else {
+                    *blurred_background_image = None;
+                    return;
+                };
                 let original_image = ImageBuffer::<image::Rgba<u8>, _>::from_raw(
-                    image.image.pixmap.width(),
-                    image.image.pixmap.height(),
-                    image.image.pixmap.data(),
+                    pixmap.width(),
+                    pixmap.height(),
+                    pixmap.data(),
                 )
                 .unwrap();
 
-                // Formula to calculate the sigma as specified
-                let dim = original_image.width().max(original_image.height()) as f32;
-                let sigma = BLUR_FACTOR * image.blur * dim;
-
-                // For large blurs the calculation is actually very expensive,
-                // but we can get around that because large blurs don't require
-                // high resolutions in the first place. So we simply scale down
-                // the image based on the sigma to a smaller size and then blur
-                // the image. For the scaled down image we always use a sigma of
-                // 2.0, so scaling the image by 2.0 / sigma should resulting in
-                // the same amount of blur. Of course we never want to scale the
-                // image up, so in case the scale factor would end up in >= 1x,
-                // we simply don't do any scaling and keep the original sigma.
-                const SIGMA_WHEN_SCALED: f32 = 2.0;
-                let scale = SIGMA_WHEN_SCALED / sigma;
-
-                let scaled;
-                let (image, sigma) = if scale < 1.0 {
-                    // The image needs to at least be 1x1, because tiny-skia
-                    // doesn't allow images to be smaller than that. A triangle
-                    // filter is probably fine, the blur will hide most scaling
-                    // artifacts anyway.
-                    scaled = image::imageops::resize(
-                        &original_image,
-                        ((scale * original_image.width() as f32) as u32).max(1),
-                        ((scale * original_image.height() as f32) as u32).max(1),
-                        FilterType::Triangle,
-                    );
-                    (
-                        ImageBuffer::<image::Rgba<u8>, _>::from_raw(
-                            scaled.width(),
-                            scaled.height(),
-                            &*scaled,
-                        )
-                        .unwrap(),
-                        SIGMA_WHEN_SCALED,
-                    )
-                } else {
-                    (original_image, sigma)
-                };
-
-                let image_buffer = image::imageops::blur(&image, sigma);
+                let image_buffer = super::blur_image(original_image, image.blur);
                 let size = IntSize::from_wh(image_buffer.width(), image_buffer.height()).unwrap();
                 let pixmap = Pixmap::from_vec(image_buffer.into_raw(), size).unwrap();
                 *blurred_background_image = Some((current_key, pixmap));
@@ -796,55 +1245,37 @@ fn update_blurred_background_image(
     }
 }
 
-fn calculate_bounds(layer: &[Entity<SkiaPath, SkiaImage, SkiaLabel>]) -> [f32; 2] {
-    let (mut min_y, mut max_y) = (f32::INFINITY, f32::NEG_INFINITY);
-    for entity in layer.iter() {
-        match entity {
-            Entity::FillPath(path, _, transform) => {
-                if let Some(path) = &**path {
-                    let bounds = path.bounds();
-                    for y in [bounds.top(), bounds.bottom()] {
-                        let transformed_y = transform.transform_y(y);
-                        min_y = min_y.min(transformed_y);
-                        max_y = max_y.max(transformed_y);
-                    }
-                }
-            }
-            Entity::StrokePath(path, radius, _, transform) => {
-                if let Some(path) = &**path {
-                    let radius = transform.scale_y * radius;
-                    let bounds = path.bounds();
-                    for y in [bounds.top(), bounds.bottom()] {
-                        let transformed_y = transform.transform_y(y);
-                        min_y = min_y.min(transformed_y - radius);
-                        max_y = max_y.max(transformed_y + radius);
-                    }
-                }
-            }
-            Entity::Image(_, transform) => {
-                for y in [0.0, 1.0] {
-                    let transformed_y = transform.transform_y(y);
-                    min_y = min_y.min(transformed_y);
-                    max_y = max_y.max(transformed_y);
-                }
-            }
-            Entity::Label(label, _, transform) => {
-                for glyph in label.read().unwrap().glyphs() {
-                    if let Some(path) = &glyph.path {
-                        let transform = transform
-                            .pre_translate(glyph.x, glyph.y)
-                            .pre_scale(glyph.scale, glyph.scale);
-
-                        let bounds = path.bounds();
-                        for y in [bounds.top(), bounds.bottom()] {
-                            let transformed_y = transform.transform_y(y);
-                            min_y = min_y.min(transformed_y);
-                            max_y = max_y.max(transformed_y);
-                        }
-                    }
+fn calculate_bounds(entity: &Entity<SkiaPath, SkiaImage, SkiaLabel>) -> Option<[Pos; 2]> {
+    let (mut min, mut max) = ([f32::INFINITY; 2], [f32::NEG_INFINITY; 2]);
+    let mut include = |transform: &Transform, [left, top, right, bottom]: [f32; 4], radius: f32| {
+        let [x1, y1] = transform.transform_point([left, top]);
+        let [x2, y2] = transform.transform_point([right, bottom]);
+        let [rx, ry] = [transform.scale_x * radius, transform.scale_y * radius].map(f32::abs);
+        min = [min[0].min(x1.min(x2) - rx), min[1].min(y1.min(y2) - ry)];
+        max = [max[0].max(x1.max(x2) + rx), max[1].max(y1.max(y2) + ry)];
+    };
+    let path_bounds = |path: &Path| {
+        let bounds = path.bounds();
+        [bounds.left(), bounds.top(), bounds.right(), bounds.bottom()]
+    };
+    match entity {
+        Entity::FillPath(path, _, transform) => {
+            include(transform, path_bounds(path.as_deref()?), 0.0);
+        }
+        Entity::StrokePath(path, radius, _, transform) => {
+            include(transform, path_bounds(path.as_deref()?), *radius);
+        }
+        Entity::Image(_, transform) => include(transform, [0.0, 0.0, 1.0, 1.0], 0.0),
+        Entity::Label(label, _, transform) => {
+            for glyph in label.read().unwrap().glyphs() {
+                if let Some(path) = &glyph.path {
+                    let transform = transform
+                        .pre_translate(glyph.x, glyph.y)
+                        .pre_scale(glyph.scale, glyph.scale);
+                    include(&transform, path_bounds(path), 0.0);
                 }
             }
         }
     }
-    [min_y, max_y]
+    (min[0] <= max[0]).then_some([min, max])
 }
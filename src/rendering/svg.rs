@@ -14,12 +14,13 @@ use crate::{
     platform::prelude::*,
     settings::{Font, ImageCache, BLUR_FACTOR},
     util::xml::{AttributeWriter, DisplayAlreadyEscaped, Text, Value, Writer},
+    TimeSpan,
 };
 
 use super::{
     default_text_engine::{self, TextEngine},
-    resource, Background, Entity, FillShader, FontKind, ResourceAllocator, SceneManager,
-    SharedOwnership, Transform,
+    resource, Background, BackgroundMode, Entity, FillShader, FontKind, ResourceAllocator,
+    SceneManager, SharedOwnership, Transform,
 };
 
 type SvgImage = Rc<Image>;
@@ -42,8 +43,20 @@ impl Default for Renderer {
 impl Renderer {
     /// Creates a new SVG renderer.
     pub fn new() -> Self {
+        Self::with_text_engine(TextEngine::new())
+    }
+
+    /// Creates a new SVG renderer that only uses the fonts bundled with
+    /// livesplit-core and the fonts that get loaded via
+    /// [`load_font_data`](Self::load_font_data), but never the fonts of the
+    /// operating system. This way the layout renders the same on every system.
+    pub fn with_bundled_fonts() -> Self {
+        Self::with_text_engine(TextEngine::with_bundled_fonts())
+    }
+
+    fn with_text_engine(text_engine: TextEngine<SvgPath>) -> Self {
         let mut allocator = SvgAllocator {
-            text_engine: TextEngine::new(),
+            text_engine,
             defs: Rc::new(RefCell::new(Defs {
                 ptr_lookup: HashSet::new(),
                 gradients_lookup: HashTable::new(),
@@ -56,6 +69,38 @@ impl Renderer {
         }
     }
 
+    /// Loads the font data provided, such as the contents of a TrueType or
+    /// OpenType font file or font collection. The fonts it contains can then
+    /// be chosen by their family name in the font settings of the layout and
+    /// the components. They also serve as fallback fonts for characters that
+    /// the chosen fonts don't support. Glyphs of these fonts are embedded into
+    /// the SVG as paths, so the fonts don't need to be available wherever the
+    /// SVG is shown.
+    pub fn load_font_data(&mut self, data: Vec<u8>) {
+        self.allocator.text_engine.load_font_data(data);
+        let background_mode = self.scene_manager.background_mode();
+        let animation_duration = self.scene_manager.animation_duration();
+        self.scene_manager = SceneManager::new(&mut self.allocator);
+        self.scene_manager.set_background_mode(background_mode);
+        self.scene_manager
+            .set_animation_duration(animation_duration);
+    }
+
+    /// Sets how the background of the layout gets rendered. It can either be
+    /// left out entirely, so the SVG is transparent where the components don't
+    /// draw anything, or it can be replaced by a solid chroma key color.
+    pub fn set_background_mode(&mut self, background_mode: BackgroundMode) {
+        self.scene_manager.set_background_mode(background_mode);
+    }
+
+    /// Sets how long the transitions between the states of the layout take,
+    /// such as the splits scrolling or the deltas changing their colors. The
+    /// animations are driven by the point in time each frame is rendered at.
+    /// A duration of zero, which is the default, disables the animations.
+    pub fn set_animation_duration(&mut self, duration: TimeSpan) {
+        self.scene_manager.set_animation_duration(duration);
+    }
+
     /// Renders the layout state with the chosen dimensions to the writer
     /// provided. It may detect that the layout got resized. In that case it
     /// returns the new ideal size. This is just a hint and can be ignored
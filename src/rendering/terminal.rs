@@ -0,0 +1,493 @@
+//! Provides a renderer that draws the layout as styled text in a terminal by
+//! using ANSI escape codes. This is useful for practicing over SSH or in other
+//! headless setups, as well as for quickly debugging layouts. Each component
+//! is drawn as one or more lines of text. The components are always stacked
+//! vertically, even for horizontal layouts.
+
+use core::fmt::Write;
+
+use crate::{
+    component::{
+        blank_space, detailed_timer, graph, key_value, separator, splits, text, timer, title,
+    },
+    layout::{ComponentState, LayoutState},
+    platform::prelude::*,
+    settings::{Color, Gradient, LayoutBackground, ListGradient},
+};
+
+use super::consts::PSEUDO_PIXELS;
+
+const SPARKLINE: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// The terminal renderer allows rendering layouts as styled text. Colors are
+/// emitted as 24-bit ANSI escape codes, so the terminal needs to support true
+/// colors.
+#[derive(Default)]
+pub struct Renderer {
+    frame: String,
+}
+
+impl Renderer {
+    /// Creates a new terminal renderer.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Renders the layout state provided with the amount of columns provided.
+    /// The frame starts by moving the cursor to the top left corner of the
+    /// terminal and every line is padded to the full width, so the frame can
+    /// be printed as is to overwrite the previous one.
+    pub fn render(&mut self, state: &LayoutState, width: usize) -> &str {
+        self.frame.clear();
+        self.frame.push_str("\x1b[H");
+
+        let background = match &state.background {
+            LayoutBackground::Gradient(gradient) => gradient_color(gradient, None),
+            LayoutBackground::Image(_) => None,
+        };
+        let mut lines = Lines {
+            out: &mut self.frame,
+            width,
+            layout_background: background,
+        };
+
+        for component in &state.components {
+            match component {
+                ComponentState::BlankSpace(component) => render_blank_space(&mut lines, component),
+                ComponentState::DetailedTimer(component) => {
+                    render_detailed_timer(&mut lines, component, state)
+                }
+                ComponentState::Graph(component) => render_graph(&mut lines, component),
+                ComponentState::KeyValue(component) => {
+                    render_key_value(&mut lines, component, state)
+                }
+                ComponentState::Separator(component) => {
+                    render_separator(&mut lines, component, state)
+                }
+                ComponentState::Splits(component) => render_splits(&mut lines, component, state),
+                ComponentState::Text(component) => render_text(&mut lines, component, state),
+                ComponentState::Timer(component) => render_timer(&mut lines, component),
+                ComponentState::Title(component) => render_title(&mut lines, component, state),
+            }
+        }
+
+        &self.frame
+    }
+}
+
+struct Lines<'a> {
+    out: &'a mut String,
+    width: usize,
+    layout_background: Option<Color>,
+}
+
+impl Lines<'_> {
+    /// Writes a line with the texts on the left side being left aligned and
+    /// the texts on the right side being right aligned. The left side gets
+    /// shortened if both sides don't fit.
+    fn line(&mut self, background: &Gradient, left: &[(&str, Color)], right: &[(&str, Color)]) {
+        let right_len = right.iter().map(|(text, _)| len(text)).sum::<usize>();
+        let right_len = right_len.min(self.width);
+        let left_space = if right_len == 0 {
+            self.width
+        } else {
+            self.width.saturating_sub(right_len + 1)
+        };
+
+        self.start(background);
+        let left_len = self.write_all(left, left_space);
+        self.pad(self.width - right_len - left_len);
+        self.write_all(right, right_len);
+        self.end();
+    }
+
+    /// Writes a line with the text horizontally centered.
+    fn centered(&mut self, background: &Gradient, text: &str, color: Color) {
+        self.start(background);
+        let text_len = len(text).min(self.width);
+        let left = (self.width - text_len) / 2;
+        self.pad(left);
+        self.write(text, color, text_len);
+        self.pad(self.width - left - text_len);
+        self.end();
+    }
+
+    /// Writes a line that consists of a single repeated character.
+    fn filled(&mut self, background: &Gradient, c: char, color: Color) {
+        self.start(background);
+        self.foreground(color);
+        for _ in 0..self.width {
+            self.out.push(c);
+        }
+        self.end();
+    }
+
+    fn start(&mut self, background: &Gradient) {
+        if let Some(color) = gradient_color(background, self.layout_background) {
+            let [r, g, b, _] = color.to_rgba8();
+            let _ = write!(self.out, "\x1b[48;2;{r};{g};{b}m");
+        }
+    }
+
+    fn end(&mut self) {
+        self.out.push_str("\x1b[0m\r\n");
+    }
+
+    fn foreground(&mut self, color: Color) {
+        let [r, g, b, _] = color.to_rgba8();
+        let _ = write!(self.out, "\x1b[38;2;{r};{g};{b}m");
+    }
+
+    fn pad(&mut self, count: usize) {
+        for _ in 0..count {
+            self.out.push(' ');
+        }
+    }
+
+    fn write_all(&mut self, texts: &[(&str, Color)], mut max: usize) -> usize {
+        let mut written = 0;
+        for &(text, color) in texts {
+            let len = self.write(text, color, max);
+            written += len;
+            max -= len;
+        }
+        written
+    }
+
+    /// Writes the text, shortening it with an ellipsis if it is longer than
+    /// the maximum amount of characters provided. Returns the amount of
+    /// characters written.
+    fn write(&mut self, text: &str, color: Color, max: usize) -> usize {
+        if max == 0 || text.is_empty() {
+            return 0;
+        }
+        self.foreground(color);
+        let text_len = len(text);
+        if text_len <= max {
+            self.out.push_str(text);
+            text_len
+        } else {
+            self.out.extend(text.chars().take(max - 1));
+            self.out.push('…');
+            max
+        }
+    }
+}
+
+fn len(text: &str) -> usize {
+    text.chars().count()
+}
+
+/// Chooses the first abbreviation that fits the space provided. If none of
+/// them fit, the shortest one is chosen.
+fn abbreviate<S: AsRef<str>>(abbreviations: &[S], max: usize) -> &str {
+    abbreviations
+        .iter()
+        .map(AsRef::as_ref)
+        .find(|text| len(text) <= max)
+        .or_else(|| {
+            abbreviations
+                .iter()
+                .map(AsRef::as_ref)
+                .min_by_key(|t| len(t))
+        })
+        .unwrap_or_default()
+}
+
+/// Terminals can't show gradients within a single cell, so the average color
+/// of the gradient is used instead. Transparent colors are blended on top of
+/// the background of the layout.
+fn gradient_color(gradient: &Gradient, background: Option<Color>) -> Option<Color> {
+    let color = match *gradient {
+        Gradient::Transparent => return background,
+        Gradient::Plain(color) => color,
+        Gradient::Vertical(a, b) | Gradient::Horizontal(a, b) => mix(a, b, 0.5),
+    };
+    Some(match background {
+        Some(background) => mix(background, color, color.alpha),
+        None => color,
+    })
+}
+
+fn mix(a: Color, b: Color, t: f32) -> Color {
+    let [a, b] = [a.to_array(), b.to_array()];
+    let [red, green, blue, alpha] = [0, 1, 2, 3].map(|i| a[i] + (b[i] - a[i]) * t);
+    Color::rgba(red, green, blue, alpha)
+}
+
+fn render_blank_space(lines: &mut Lines<'_>, component: &blank_space::State) {
+    let count = (component.size as f32 * PSEUDO_PIXELS + 0.5) as usize;
+    for _ in 0..count {
+        lines.line(&component.background, &[], &[]);
+    }
+}
+
+fn render_detailed_timer(
+    lines: &mut Lines<'_>,
+    component: &detailed_timer::State,
+    state: &LayoutState,
+) {
+    let timer = &component.timer;
+    let segment_timer = &component.segment_timer;
+    let segment_name = component.segment_name.as_deref().unwrap_or_default();
+    let segment_name_color = component.segment_name_color.unwrap_or(state.text_color);
+    let names_color = component.comparison_names_color.unwrap_or(state.text_color);
+    let times_color = component.comparison_times_color.unwrap_or(state.text_color);
+
+    lines.line(
+        &component.background,
+        &[(segment_name, segment_name_color)],
+        &[
+            (&timer.time, timer.top_color),
+            (&timer.fraction, timer.top_color),
+        ],
+    );
+
+    let comparisons = component.comparison1.iter().chain(&component.comparison2);
+    let mut left = Vec::new();
+    for (i, comparison) in comparisons.enumerate() {
+        if i != 0 {
+            left.push(("  ", names_color));
+        }
+        left.push((comparison.name.as_str(), names_color));
+        left.push((": ", names_color));
+        left.push((comparison.time.as_str(), times_color));
+    }
+
+    lines.line(
+        &component.background,
+        &left,
+        &[
+            (&segment_timer.time, segment_timer.top_color),
+            (&segment_timer.fraction, segment_timer.top_color),
+        ],
+    );
+}
+
+fn render_graph(lines: &mut Lines<'_>, component: &graph::State) {
+    let background = Gradient::Plain(mix(
+        component.top_background_color,
+        component.bottom_background_color,
+        0.5,
+    ));
+    lines.start(&background);
+    lines.foreground(component.graph_lines_color);
+
+    let points = &component.points;
+    for column in 0..lines.width {
+        let x = (column as f32 + 0.5) / lines.width as f32;
+        let y = match points.iter().position(|p| p.x >= x) {
+            Some(0) => points[0].y,
+            Some(i) => {
+                let (from, to) = (&points[i - 1], &points[i]);
+                let t = (x - from.x) / (to.x - from.x);
+                from.y + (to.y - from.y) * t
+            }
+            None => points.last().map_or(component.middle, |p| p.y),
+        };
+        let level = ((1.0 - y) * SPARKLINE.len() as f32) as usize;
+        lines.out.push(SPARKLINE[level.min(SPARKLINE.len() - 1)]);
+    }
+
+    lines.end();
+}
+
+fn render_key_value(lines: &mut Lines<'_>, component: &key_value::State, state: &LayoutState) {
+    let key_color = component.key_color.unwrap_or(state.text_color);
+    let value_color = component.value_color.unwrap_or(state.text_color);
+
+    let rows = [(
+        component.key.as_str(),
+        &*component.key_abbreviations,
+        component.value.as_str(),
+    )]
+    .into_iter()
+    .chain(component.additional_rows.iter().map(|row| {
+        (
+            row.key.as_str(),
+            &*row.key_abbreviations,
+            row.value.as_str(),
+        )
+    }));
+
+    for (key, abbreviations, value) in rows {
+        if component.display_two_rows {
+            lines.line(&component.background, &[(key, key_color)], &[]);
+            lines.line(&component.background, &[], &[(value, value_color)]);
+        } else {
+            let space = lines.width.saturating_sub(len(value) + 1);
+            let key = if len(key) <= space {
+                key
+            } else {
+                abbreviate(abbreviations, space)
+            };
+            lines.line(
+                &component.background,
+                &[(key, key_color)],
+                &[(value, value_color)],
+            );
+        }
+    }
+}
+
+fn render_separator(lines: &mut Lines<'_>, component: &separator::State, state: &LayoutState) {
+    let color = component.color.unwrap_or(state.separators_color);
+    lines.filled(&Gradient::Transparent, '─', color);
+}
+
+fn render_splits(lines: &mut Lines<'_>, component: &splits::State, state: &LayoutState) {
+    // The columns are stored from right to left.
+    let mut column_widths = Vec::new();
+    let rows = component
+        .column_labels
+        .iter()
+        .map(|labels| labels.iter().map(|label| len(label)).collect::<Vec<_>>());
+    let rows = rows.chain(component.splits.iter().map(|split| {
+        split
+            .columns
+            .iter()
+            .map(|column| len(&column.value))
+            .collect()
+    }));
+    for row in rows {
+        if column_widths.len() < row.len() {
+            column_widths.resize(row.len(), 0);
+        }
+        for (width, len) in column_widths.iter_mut().zip(row) {
+            *width = (*width).max(len);
+        }
+    }
+
+    let mut columns = Vec::new();
+
+    if let Some(labels) = &component.column_labels {
+        let labels = labels
+            .iter()
+            .map(|label| (label.as_str(), state.text_color));
+        format_columns(&mut columns, &column_widths, labels);
+        lines.line(&Gradient::Transparent, &[], &as_texts(&columns));
+    }
+
+    let mut indented = String::new();
+    for split in component.splits.iter() {
+        let background = if split.is_current_split {
+            component.current_split_gradient
+        } else {
+            match component.background {
+                ListGradient::Same(gradient) => gradient,
+                ListGradient::Alternating(even, odd) => {
+                    Gradient::Plain(if split.is_odd_row { odd } else { even })
+                }
+            }
+        };
+
+        if split.is_blank_space {
+            lines.line(&background, &[], &[]);
+            continue;
+        }
+
+        indented.clear();
+        for _ in 0..split.nesting_level {
+            indented.push_str("  ");
+        }
+        indented.push_str(&split.name);
+
+        let values = split
+            .columns
+            .iter()
+            .map(|column| (column.value.as_str(), column.visual_color));
+        format_columns(&mut columns, &column_widths, values);
+        let right = as_texts(&columns);
+
+        if component.display_two_rows {
+            lines.line(&background, &[(&indented, state.text_color)], &[]);
+            lines.line(&background, &[], &right);
+        } else {
+            lines.line(&background, &[(&indented, state.text_color)], &right);
+        }
+    }
+}
+
+/// Right aligns the values of the columns and orders them from left to right.
+fn format_columns<'a>(
+    columns: &mut Vec<(String, Color)>,
+    widths: &[usize],
+    values: impl Iterator<Item = (&'a str, Color)>,
+) {
+    columns.clear();
+    for ((value, color), &width) in values.zip(widths) {
+        if width == 0 {
+            continue;
+        }
+        columns.push((format!(" {value:>width$}"), color));
+    }
+    columns.reverse();
+}
+
+fn as_texts(columns: &[(String, Color)]) -> Vec<(&str, Color)> {
+    columns
+        .iter()
+        .map(|(text, color)| (text.as_str(), *color))
+        .collect()
+}
+
+fn render_text(lines: &mut Lines<'_>, component: &text::State, state: &LayoutState) {
+    let left_color = component.left_center_color.unwrap_or(state.text_color);
+    let right_color = component.right_color.unwrap_or(state.text_color);
+
+    match &component.text {
+        text::TextState::Center(text) => lines.centered(&component.background, text, left_color),
+        text::TextState::Split(left, right) => {
+            if component.display_two_rows {
+                lines.line(&component.background, &[(left, left_color)], &[]);
+                lines.line(&component.background, &[], &[(right, right_color)]);
+            } else {
+                lines.line(
+                    &component.background,
+                    &[(left, left_color)],
+                    &[(right, right_color)],
+                );
+            }
+        }
+    }
+}
+
+fn render_timer(lines: &mut Lines<'_>, component: &timer::State) {
+    lines.line(
+        &component.background,
+        &[],
+        &[
+            (&component.time, component.top_color),
+            (&component.fraction, component.top_color),
+        ],
+    );
+}
+
+fn render_title(lines: &mut Lines<'_>, component: &title::State, state: &LayoutState) {
+    let color = component.text_color.unwrap_or(state.text_color);
+
+    let attempts = match (component.finished_runs, component.attempts) {
+        (Some(finished), Some(attempts)) => format!("{finished}/{attempts}"),
+        (Some(count), None) | (None, Some(count)) => count.to_string(),
+        (None, None) => String::new(),
+    };
+
+    let line1 = abbreviate(&component.line1, lines.width);
+    if component.is_centered {
+        lines.centered(&component.background, line1, color);
+    } else {
+        lines.line(&component.background, &[(line1, color)], &[]);
+    }
+
+    let space = lines.width.saturating_sub(len(&attempts) + 1);
+    let line2 = abbreviate(&component.line2, space);
+    if component.is_centered && attempts.is_empty() {
+        lines.centered(&component.background, line2, color);
+    } else {
+        lines.line(
+            &component.background,
+            &[(line2, color)],
+            &[(&attempts, color)],
+        );
+    }
+}
@@ -19,13 +19,14 @@ use web_sys::{Blob, Element, HtmlCanvasElement, ImageBitmap, Path2d, Window};
 use crate::{
     layout::LayoutState,
     settings::{Font, FontStretch, FontStyle, FontWeight, ImageCache, BLUR_FACTOR},
+    TimeSpan,
 };
 
 use self::bindings::CanvasRenderingContext2d;
 
 use super::{
-    Background, Entity, FillShader, FontKind, Label, PathBuilder, ResourceAllocator, SceneManager,
-    SharedOwnership, Transform,
+    Background, BackgroundMode, Entity, FillShader, FontKind, Label, PathBuilder,
+    ResourceAllocator, SceneManager, SharedOwnership, Transform,
 };
 
 mod bindings;
@@ -558,6 +559,21 @@ impl Renderer {
         &self.div
     }
 
+    /// Sets how the background of the layout gets rendered. It can either be
+    /// left out entirely, so the canvas is transparent where the components
+    /// don't draw anything, or it can be replaced by a solid chroma key color.
+    pub fn set_background_mode(&mut self, background_mode: BackgroundMode) {
+        self.manager.set_background_mode(background_mode);
+    }
+
+    /// Sets how long the transitions between the states of the layout take,
+    /// such as the splits scrolling or the deltas changing their colors. The
+    /// animations are driven by the point in time each frame is rendered at.
+    /// A duration of zero, which is the default, disables the animations.
+    pub fn set_animation_duration(&mut self, duration: TimeSpan) {
+        self.manager.set_animation_duration(duration);
+    }
+
     /// Renders the layout state into the canvas. The image cache is used to
     /// retrieve images that are used in the layout state.
     pub fn render(&mut self, state: &LayoutState, image_cache: &ImageCache) -> Option<[f32; 2]> {
@@ -0,0 +1,934 @@
+//! Provides a renderer that uses [wgpu](https://wgpu.rs) to render the layout
+//! on the GPU. This allows applications that already render with wgpu, such as
+//! games or overlays, to draw the layout directly into their own textures. The
+//! paths are tessellated into triangles with lyon and anti-aliased with
+//! multisampling. The result closely resembles the one of the software
+//! renderer, but it is not identical down to the pixel.
+
+use super::{
+    default_text_engine::{Font, Label, TextEngine},
+    entity::Entity,
+    resource::{self, ResourceAllocator},
+    Background, BackgroundMode, FillShader, FontKind, Pos, Rgba, SceneManager, Transform,
+};
+use crate::{
+    layout::LayoutState,
+    platform::prelude::*,
+    settings::{self, BackgroundImage, ImageCache},
+    TimeSpan,
+};
+use bytemuck_derive::{Pod, Zeroable};
+use core::ops::Range;
+use image::{imageops::FilterType, RgbaImage};
+use lyon_tessellation::{
+    math::{point, Point},
+    path::{builder::WithSvg, path::BuilderImpl},
+    BuffersBuilder, FillOptions, FillTessellator, FillVertex, StrokeOptions, StrokeTessellator,
+    StrokeVertex, VertexBuffers,
+};
+use std::sync::{Arc, Mutex, OnceLock};
+
+pub use wgpu;
+
+/// The number of samples per pixel used for anti-aliasing. WebGPU guarantees
+/// that this is supported by all the color formats that can be rendered to.
+const SAMPLE_COUNT: u32 = 4;
+
+/// The maximum distance in pixels between the curves of the paths and the
+/// triangles that approximate them.
+const TOLERANCE: f32 = 0.2;
+
+type WgpuPath = Option<Arc<Path>>;
+type WgpuImage = Arc<Image>;
+type WgpuFont = Font;
+type WgpuLabel = Label<WgpuPath>;
+
+/// The triangles of a tessellated path in the path's own coordinate space.
+type Geometry = VertexBuffers<Point, u32>;
+
+struct PathBuilder {
+    builder: WithSvg<BuilderImpl>,
+    bounds: [f32; 4],
+}
+
+struct Path {
+    path: lyon_tessellation::path::Path,
+    bounds: [f32; 4],
+    tessellations: Mutex<Vec<(Style, i32, Arc<Geometry>)>>,
+}
+
+/// How the triangles of a path are supposed to cover it.
+#[derive(Copy, Clone, PartialEq)]
+enum Style {
+    Fill,
+    /// The bits of the width of the stroke.
+    Stroke(u32),
+}
+
+impl Path {
+    /// Tessellates the path for the transform provided. The tolerance is
+    /// chosen in powers of two based on how much the path gets scaled up. This
+    /// way the curves stay smooth at every size, while the path doesn't need
+    /// to be tessellated again when the scale changes slightly. The
+    /// tessellations are cached, as most paths, like the glyphs of the text,
+    /// get rendered many times with the same scale.
+    fn tessellate(&self, style: Style, transform: &Transform) -> Option<Arc<Geometry>> {
+        let scale = transform.scale_x.abs().max(transform.scale_y.abs());
+        let level = (scale.log2().ceil() as i32).clamp(-16, 16);
+
+        let mut tessellations = self.tessellations.lock().unwrap();
+        if let Some((_, _, geometry)) = tessellations
+            .iter()
+            .find(|(s, l, _)| *s == style && *l == level)
+        {
+            return Some(geometry.clone());
+        }
+
+        let tolerance = TOLERANCE / (level as f32).exp2();
+        let mut geometry = Geometry::new();
+        match style {
+            Style::Fill => FillTessellator::new()
+                .tessellate_path(
+                    &self.path,
+                    &FillOptions::non_zero().with_tolerance(tolerance),
+                    &mut BuffersBuilder::new(&mut geometry, |vertex: FillVertex<'_>| {
+                        vertex.position()
+                    }),
+                )
+                .ok()?,
+            Style::Stroke(width) => StrokeTessellator::new()
+                .tessellate_path(
+                    &self.path,
+                    &StrokeOptions::default()
+                        .with_line_width(f32::from_bits(width))
+                        .with_tolerance(tolerance),
+                    &mut BuffersBuilder::new(&mut geometry, |vertex: StrokeVertex<'_, '_>| {
+                        vertex.position()
+                    }),
+                )
+                .ok()?,
+        }
+
+        let geometry = Arc::new(geometry);
+        tessellations.push((style, level, geometry.clone()));
+        Some(geometry)
+    }
+}
+
+impl PathBuilder {
+    fn include(&mut self, x: f32, y: f32) {
+        let [left, top, right, bottom] = &mut self.bounds;
+        *left = left.min(x);
+        *top = top.min(y);
+        *right = right.max(x);
+        *bottom = bottom.max(y);
+    }
+}
+
+impl resource::PathBuilder for PathBuilder {
+    type Path = WgpuPath;
+
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.include(x, y);
+        self.builder.move_to(point(x, y));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.include(x, y);
+        self.builder.line_to(point(x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.include(x1, y1);
+        self.include(x, y);
+        self.builder.quadratic_bezier_to(point(x1, y1), point(x, y));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.include(x1, y1);
+        self.include(x2, y2);
+        self.include(x, y);
+        self.builder
+            .cubic_bezier_to(point(x1, y1), point(x2, y2), point(x, y));
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+
+    fn finish(self) -> Self::Path {
+        let [left, _, right, _] = self.bounds;
+        if left > right {
+            return None;
+        }
+        Some(Arc::new(Path {
+            path: self.builder.build(),
+            bounds: self.bounds,
+            tessellations: Mutex::new(Vec::new()),
+        }))
+    }
+}
+
+fn path_builder() -> PathBuilder {
+    PathBuilder {
+        builder: lyon_tessellation::path::Path::svg_builder(),
+        bounds: [
+            f32::INFINITY,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NEG_INFINITY,
+        ],
+    }
+}
+
+struct Image {
+    pixels: RgbaImage,
+    aspect_ratio: f32,
+    texture: OnceLock<Arc<wgpu::BindGroup>>,
+}
+
+impl Image {
+    /// Accesses the texture of the image. It gets uploaded to the GPU the
+    /// first time the image is rendered.
+    fn texture(&self, gpu: &Gpu<'_>) -> &Arc<wgpu::BindGroup> {
+        self.texture
+            .get_or_init(|| gpu.create_texture(&self.pixels))
+    }
+}
+
+impl resource::Image for WgpuImage {
+    fn aspect_ratio(&self) -> f32 {
+        self.aspect_ratio
+    }
+}
+
+fn decode_image(data: &[u8]) -> Option<RgbaImage> {
+    let mut image = image::load_from_memory(data).ok()?.to_rgba8();
+
+    // The textures are premultiplied, so they can be filtered and blended
+    // without any dark fringes around transparent pixels.
+    for pixel in image.pixels_mut() {
+        let [r, g, b, a] = &mut pixel.0;
+        let alpha = *a as u16;
+        *r = ((*r as u16 * alpha) / 255) as u8;
+        *g = ((*g as u16 * alpha) / 255) as u8;
+        *b = ((*b as u16 * alpha) / 255) as u8;
+    }
+
+    Some(image)
+}
+
+struct WgpuAllocator {
+    text_engine: TextEngine<WgpuPath>,
+}
+
+impl ResourceAllocator for WgpuAllocator {
+    type PathBuilder = PathBuilder;
+    type Path = WgpuPath;
+    type Image = WgpuImage;
+    type Font = WgpuFont;
+    type Label = WgpuLabel;
+
+    fn path_builder(&mut self) -> Self::PathBuilder {
+        path_builder()
+    }
+
+    fn create_image(&mut self, data: &[u8]) -> Option<Self::Image> {
+        let pixels = decode_image(data)?;
+        Some(Arc::new(Image {
+            aspect_ratio: pixels.width() as f32 / pixels.height() as f32,
+            pixels,
+            texture: OnceLock::new(),
+        }))
+    }
+
+    fn create_font(&mut self, font: Option<&settings::Font>, kind: FontKind) -> Self::Font {
+        self.text_engine.create_font(font, kind)
+    }
+
+    fn create_label(
+        &mut self,
+        text: &str,
+        font: &mut Self::Font,
+        max_width: Option<f32>,
+    ) -> Self::Label {
+        self.text_engine
+            .create_label(path_builder, text, font, max_width)
+    }
+
+    fn update_label(
+        &mut self,
+        label: &mut Self::Label,
+        text: &str,
+        font: &mut Self::Font,
+        max_width: Option<f32>,
+    ) {
+        self.text_engine
+            .update_label(path_builder, label, text, font, max_width)
+    }
+}
+
+/// Everything needed to upload textures to the GPU.
+struct Gpu<'a> {
+    device: &'a wgpu::Device,
+    queue: &'a wgpu::Queue,
+    layout: &'a wgpu::BindGroupLayout,
+    sampler: &'a wgpu::Sampler,
+}
+
+impl Gpu<'_> {
+    fn create_texture(&self, image: &RgbaImage) -> Arc<wgpu::BindGroup> {
+        // Images that are larger than what the GPU supports get scaled down.
+        let max = self.device.limits().max_texture_dimension_2d;
+        let scaled;
+        let image = if image.width() > max || image.height() > max {
+            let scale = max as f32 / image.width().max(image.height()) as f32;
+            scaled = image::imageops::resize(
+                image,
+                ((scale * image.width() as f32) as u32).clamp(1, max),
+                ((scale * image.height() as f32) as u32).clamp(1, max),
+                FilterType::Triangle,
+            );
+            &scaled
+        } else {
+            image
+        };
+
+        let size = wgpu::Extent3d {
+            width: image.width(),
+            height: image.height(),
+            depth_or_array_layers: 1,
+        };
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("livesplit-core Image"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        self.queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            image,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * image.width()),
+                rows_per_image: None,
+            },
+            size,
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        Arc::new(self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("livesplit-core Image"),
+            layout: self.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(self.sampler),
+                },
+            ],
+        }))
+    }
+}
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+struct Vertex {
+    /// The position in normalized device coordinates.
+    position: [f32; 2],
+    uv: [f32; 2],
+    /// The premultiplied color.
+    color: [f32; 4],
+}
+
+/// A range of triangles that are drawn with the same texture. Triangles
+/// without a texture sample the white texture instead.
+struct Batch {
+    texture: Option<Arc<wgpu::BindGroup>>,
+    indices: Range<u32>,
+}
+
+/// The triangles of the whole frame, in the order they are drawn in.
+#[derive(Default)]
+struct Frame {
+    resolution: [f32; 2],
+    vertices: Vec<Vertex>,
+    indices: Vec<u32>,
+    batches: Vec<Batch>,
+}
+
+impl Frame {
+    fn clear(&mut self, resolution: [f32; 2]) {
+        self.resolution = resolution;
+        self.vertices.clear();
+        self.indices.clear();
+        self.batches.clear();
+    }
+
+    fn use_texture(&mut self, texture: Option<&Arc<wgpu::BindGroup>>) {
+        let is_same = self
+            .batches
+            .last()
+            .is_some_and(|batch| match (&batch.texture, texture) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                _ => false,
+            });
+        if !is_same {
+            let start = self.indices.len() as u32;
+            self.batches.push(Batch {
+                texture: texture.cloned(),
+                indices: start..start,
+            });
+        }
+    }
+
+    fn vertex(&self, transform: &Transform, pos: Pos, uv: [f32; 2], color: Rgba) -> Vertex {
+        let [x, y] = transform.transform_point(pos);
+        let [width, height] = self.resolution;
+        let [r, g, b, a] = color;
+        Vertex {
+            position: [2.0 * x / width - 1.0, 1.0 - 2.0 * y / height],
+            uv,
+            color: [r * a, g * a, b * a, a],
+        }
+    }
+
+    fn push_indices(&mut self, indices: impl IntoIterator<Item = u32>) {
+        self.indices.extend(indices);
+        if let Some(batch) = self.batches.last_mut() {
+            batch.indices.end = self.indices.len() as u32;
+        }
+    }
+
+    fn push_geometry(
+        &mut self,
+        geometry: &Geometry,
+        transform: &Transform,
+        color: impl Fn(Pos) -> Rgba,
+    ) {
+        self.use_texture(None);
+        let start = self.vertices.len() as u32;
+        for position in &geometry.vertices {
+            let pos = [position.x, position.y];
+            let vertex = self.vertex(transform, pos, [0.0; 2], color(pos));
+            self.vertices.push(vertex);
+        }
+        self.push_indices(geometry.indices.iter().map(|index| start + index));
+    }
+
+    /// Pushes the unit square transformed by the transform provided. The colors
+    /// are the ones of the top left, top right, bottom right and bottom left
+    /// corners.
+    fn push_quad(
+        &mut self,
+        texture: Option<&Arc<wgpu::BindGroup>>,
+        transform: &Transform,
+        colors: [Rgba; 4],
+    ) {
+        self.use_texture(texture);
+        let start = self.vertices.len() as u32;
+        for (corner, color) in [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]
+            .into_iter()
+            .zip(colors)
+        {
+            let vertex = self.vertex(transform, corner, corner, color);
+            self.vertices.push(vertex);
+        }
+        self.push_indices([0, 1, 2, 0, 2, 3].map(|index| start + index));
+    }
+
+    fn push_background(
+        &mut self,
+        gpu: &Gpu<'_>,
+        background: &Option<Background<WgpuImage>>,
+        blurred_background_image: Option<&Arc<wgpu::BindGroup>>,
+    ) {
+        match background {
+            Some(Background::Shader(shader)) => {
+                let [width, height] = self.resolution;
+                let colors = match *shader {
+                    FillShader::SolidColor(color) => [color; 4],
+                    FillShader::VerticalGradient(top, bottom) => [top, top, bottom, bottom],
+                    FillShader::HorizontalGradient(left, right) => [left, right, right, left],
+                };
+                let transform = Transform {
+                    scale_x: width,
+                    scale_y: height,
+                    x: 0.0,
+                    y: 0.0,
+                };
+                self.push_quad(None, &transform, colors);
+            }
+            Some(Background::Image(image, transform)) => {
+                let texture = if image.blur != 0.0 {
+                    blurred_background_image
+                } else {
+                    Some(image.image.texture(gpu))
+                };
+                if let Some(texture) = texture {
+                    // The color is multiplied with the premultiplied texture,
+                    // so the brightness only affects the color channels.
+                    let brightness = image.brightness.clamp(0.0, 1.0);
+                    let color = [brightness, brightness, brightness, image.opacity];
+                    self.push_quad(Some(texture), transform, [color; 4]);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn push_entity(&mut self, gpu: &Gpu<'_>, entity: &Entity<WgpuPath, WgpuImage, WgpuLabel>) {
+        match entity {
+            Entity::FillPath(path, shader, transform) => {
+                if let Some(path) = path.as_deref() {
+                    if let Some(geometry) = path.tessellate(Style::Fill, transform) {
+                        self.push_geometry(&geometry, transform, |pos| {
+                            shade(shader, path.bounds, pos)
+                        });
+                    }
+                }
+            }
+            Entity::StrokePath(path, stroke_width, color, transform) => {
+                if let Some(path) = path.as_deref() {
+                    let style = Style::Stroke(stroke_width.to_bits());
+                    if let Some(geometry) = path.tessellate(style, transform) {
+                        self.push_geometry(&geometry, transform, |_| *color);
+                    }
+                }
+            }
+            Entity::Image(image, transform) => {
+                self.push_quad(Some(image.texture(gpu)), transform, [[1.0; 4]; 4]);
+            }
+            Entity::Label(label, shader, transform) => {
+                let label = label.read().unwrap();
+
+                // Just like with the software renderer, the gradients span all
+                // the glyphs in their own coordinate space.
+                let mut bounds = [
+                    f32::INFINITY,
+                    f32::INFINITY,
+                    f32::NEG_INFINITY,
+                    f32::NEG_INFINITY,
+                ];
+                for path in label
+                    .glyphs()
+                    .iter()
+                    .filter_map(|glyph| glyph.path.as_deref())
+                {
+                    let [left, top, right, bottom] = path.bounds;
+                    bounds = [
+                        bounds[0].min(left),
+                        bounds[1].min(top),
+                        bounds[2].max(right),
+                        bounds[3].max(bottom),
+                    ];
+                }
+
+                for glyph in label.glyphs() {
+                    if let Some(path) = glyph.path.as_deref() {
+                        let transform = transform
+                            .pre_translate(glyph.x, glyph.y)
+                            .pre_scale(glyph.scale, glyph.scale);
+                        if let Some(geometry) = path.tessellate(Style::Fill, &transform) {
+                            self.push_geometry(&geometry, &transform, |pos| {
+                                glyph.color.unwrap_or_else(|| shade(shader, bounds, pos))
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Determines the color of a point within the bounds provided.
+fn shade(shader: &FillShader, [left, top, right, bottom]: [f32; 4], [x, y]: Pos) -> Rgba {
+    let (start, end, t) = match *shader {
+        FillShader::SolidColor(color) => return color,
+        FillShader::VerticalGradient(start, end) => (start, end, ratio(y, top, bottom)),
+        FillShader::HorizontalGradient(start, end) => (start, end, ratio(x, left, right)),
+    };
+    [0, 1, 2, 3].map(|i| start[i] + t * (end[i] - start[i]))
+}
+
+fn ratio(value: f32, start: f32, end: f32) -> f32 {
+    if end > start {
+        ((value - start) / (end - start)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// The wgpu renderer renders the layout on the GPU into a texture provided by
+/// the caller. All the resources are created on the device the renderer was
+/// created with, so the same device needs to be used for every frame. The
+/// colors of the layout are already encoded in sRGB, so the texture should
+/// have a format that doesn't encode them again, such as
+/// [`Rgba8Unorm`](wgpu::TextureFormat::Rgba8Unorm) or
+/// [`Bgra8Unorm`](wgpu::TextureFormat::Bgra8Unorm). The pixels are
+/// premultiplied by their alpha, just like the ones of the software renderer.
+pub struct Renderer {
+    allocator: WgpuAllocator,
+    scene_manager: SceneManager<WgpuPath, WgpuImage, WgpuFont, WgpuLabel>,
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    white: Arc<wgpu::BindGroup>,
+    blurred_background_image: Option<(BackgroundImage<usize>, Arc<wgpu::BindGroup>)>,
+    frame: Frame,
+    vertex_buffer: Option<wgpu::Buffer>,
+    index_buffer: Option<wgpu::Buffer>,
+    multisampled: Option<wgpu::Texture>,
+}
+
+impl Renderer {
+    /// Creates a new wgpu renderer that renders into textures of the format
+    /// provided.
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, format: wgpu::TextureFormat) -> Self {
+        Self::with_text_engine(device, queue, format, TextEngine::new())
+    }
+
+    /// Creates a new wgpu renderer that only uses the fonts bundled with
+    /// livesplit-core and the fonts that get loaded via
+    /// [`load_font_data`](Self::load_font_data), but never the fonts of the
+    /// operating system. This way the layout renders the same on every system.
+    pub fn with_bundled_fonts(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+    ) -> Self {
+        Self::with_text_engine(device, queue, format, TextEngine::with_bundled_fonts())
+    }
+
+    fn with_text_engine(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        text_engine: TextEngine<WgpuPath>,
+    ) -> Self {
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("livesplit-core Image"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("livesplit-core Shader"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("shader.wgsl").into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("livesplit-core Pipeline"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("livesplit-core Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                compilation_options: Default::default(),
+                buffers: &[wgpu::VertexBufferLayout {
+                    array_stride: size_of::<Vertex>() as wgpu::BufferAddress,
+                    step_mode: wgpu::VertexStepMode::Vertex,
+                    attributes: &wgpu::vertex_attr_array![
+                        0 => Float32x2,
+                        1 => Float32x2,
+                        2 => Float32x4,
+                    ],
+                }],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                compilation_options: Default::default(),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: SAMPLE_COUNT,
+                ..Default::default()
+            },
+            multiview: None,
+            cache: None,
+        });
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("livesplit-core Sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let gpu = Gpu {
+            device,
+            queue,
+            layout: &layout,
+            sampler: &sampler,
+        };
+        let white = gpu.create_texture(&RgbaImage::from_pixel(1, 1, image::Rgba([0xFF; 4])));
+
+        let mut allocator = WgpuAllocator { text_engine };
+        let scene_manager = SceneManager::new(&mut allocator);
+
+        Self {
+            allocator,
+            scene_manager,
+            format,
+            pipeline,
+            layout,
+            sampler,
+            white,
+            blurred_background_image: None,
+            frame: Frame::default(),
+            vertex_buffer: None,
+            index_buffer: None,
+            multisampled: None,
+        }
+    }
+
+    /// Loads the font data provided, such as the contents of a TrueType or
+    /// OpenType font file or font collection. The fonts it contains can then
+    /// be chosen by their family name in the font settings of the layout and
+    /// the components. They also serve as fallback fonts for characters that
+    /// the chosen fonts don't support.
+    pub fn load_font_data(&mut self, data: Vec<u8>) {
+        self.allocator.text_engine.load_font_data(data);
+        let background_mode = self.scene_manager.background_mode();
+        let animation_duration = self.scene_manager.animation_duration();
+        self.scene_manager = SceneManager::new(&mut self.allocator);
+        self.scene_manager.set_background_mode(background_mode);
+        self.scene_manager
+            .set_animation_duration(animation_duration);
+    }
+
+    /// Sets how the background of the layout gets rendered. It can either be
+    /// left out entirely, so the texture stays transparent where the
+    /// components don't draw anything, or it can be replaced by a solid chroma
+    /// key color.
+    pub fn set_background_mode(&mut self, background_mode: BackgroundMode) {
+        self.scene_manager.set_background_mode(background_mode);
+    }
+
+    /// Sets how long the transitions between the states of the layout take,
+    /// such as the splits scrolling or the deltas changing their colors. A
+    /// duration of zero, which is the default, disables the animations.
+    pub fn set_animation_duration(&mut self, duration: TimeSpan) {
+        self.scene_manager.set_animation_duration(duration);
+    }
+
+    /// Renders the layout state provided into the texture view provided. The
+    /// texture needs to have the format the renderer was created with, the
+    /// resolution provided and it needs to be usable as a
+    /// [`RENDER_ATTACHMENT`](wgpu::TextureUsages::RENDER_ATTACHMENT). The
+    /// whole texture gets replaced every time. The commands are submitted to
+    /// the queue provided before this returns. It may detect that the layout
+    /// got resized. In that case it returns the new ideal size. This is just a
+    /// hint and can be ignored entirely. The texture is always rendered with
+    /// the resolution provided.
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        state: &LayoutState,
+        image_cache: &ImageCache,
+        target: &wgpu::TextureView,
+        [width, height]: [u32; 2],
+    ) -> Option<[f32; 2]> {
+        let new_resolution = self.scene_manager.update_scene(
+            &mut self.allocator,
+            [width as _, height as _],
+            state,
+            image_cache,
+        );
+
+        if width == 0 || height == 0 {
+            return new_resolution;
+        }
+
+        let gpu = Gpu {
+            device,
+            queue,
+            layout: &self.layout,
+            sampler: &self.sampler,
+        };
+        let scene = self.scene_manager.scene();
+
+        update_blurred_background_image(
+            &gpu,
+            scene.background(),
+            &mut self.blurred_background_image,
+        );
+
+        self.frame.clear([width as _, height as _]);
+        self.frame.push_background(
+            &gpu,
+            scene.background(),
+            self.blurred_background_image
+                .as_ref()
+                .map(|(_, texture)| texture),
+        );
+        for entity in scene.bottom_layer().iter().chain(scene.top_layer()) {
+            self.frame.push_entity(&gpu, entity);
+        }
+
+        let multisampled = match &self.multisampled {
+            Some(texture) if texture.width() == width && texture.height() == height => texture,
+            _ => self
+                .multisampled
+                .insert(device.create_texture(&wgpu::TextureDescriptor {
+                    label: Some("livesplit-core Multisampled"),
+                    size: wgpu::Extent3d {
+                        width,
+                        height,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: SAMPLE_COUNT,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: self.format,
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                    view_formats: &[],
+                })),
+        };
+        let multisampled = multisampled.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("livesplit-core Encoder"),
+        });
+
+        {
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("livesplit-core Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &multisampled,
+                    resolve_target: Some(target),
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                        store: wgpu::StoreOp::Discard,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            if !self.frame.indices.is_empty() {
+                let vertex_buffer = upload(
+                    device,
+                    queue,
+                    &mut self.vertex_buffer,
+                    wgpu::BufferUsages::VERTEX,
+                    bytemuck::cast_slice(&self.frame.vertices),
+                );
+                let index_buffer = upload(
+                    device,
+                    queue,
+                    &mut self.index_buffer,
+                    wgpu::BufferUsages::INDEX,
+                    bytemuck::cast_slice(&self.frame.indices),
+                );
+
+                pass.set_pipeline(&self.pipeline);
+                pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+                pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+                for batch in &self.frame.batches {
+                    let texture = batch.texture.as_ref().unwrap_or(&self.white);
+                    pass.set_bind_group(0, &**texture, &[]);
+                    pass.draw_indexed(batch.indices.clone(), 0, 0..1);
+                }
+            }
+        }
+
+        queue.submit([encoder.finish()]);
+
+        new_resolution
+    }
+}
+
+/// Writes the data into the buffer, which gets replaced by a larger one if it
+/// is too small.
+fn upload<'b>(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    buffer: &'b mut Option<wgpu::Buffer>,
+    usage: wgpu::BufferUsages,
+    data: &[u8],
+) -> &'b wgpu::Buffer {
+    let size = data.len() as wgpu::BufferAddress;
+    if buffer.as_ref().is_none_or(|buffer| buffer.size() < size) {
+        *buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("livesplit-core Buffer"),
+            size: size.next_power_of_two(),
+            usage: usage | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        }));
+    }
+    let buffer = buffer.as_ref().unwrap();
+    queue.write_buffer(buffer, 0, data);
+    buffer
+}
+
+fn update_blurred_background_image(
+    gpu: &Gpu<'_>,
+    background: &Option<Background<WgpuImage>>,
+    blurred_background_image: &mut Option<(BackgroundImage<usize>, Arc<wgpu::BindGroup>)>,
+) {
+    match background {
+        Some(Background::Image(image, _)) if image.blur != 0.0 => {
+            let current_key = image.map(image.image.id);
+            if !blurred_background_image
+                .as_ref()
+                .is_some_and(|(key, _)| &current_key == key)
+            {
+                let pixels = &image.image.pixels;
+                let original_image = image::ImageBuffer::from_raw(
+                    pixels.width(),
+                    pixels.height(),
+                    pixels.as_raw().as_slice(),
+                )
+                .unwrap();
+                let blurred = super::blur_image(original_image, image.blur);
+                *blurred_background_image = Some((current_key, gpu.create_texture(&blurred)));
+            }
+        }
+        _ => *blurred_background_image = None,
+    }
+}
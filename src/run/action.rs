@@ -0,0 +1,113 @@
+use crate::TimeSpan;
+
+/// An `Action` describes a single command that was applied to the timer during
+/// an attempt. The actions of an attempt form a log that allows auditing how
+/// the times of the attempt came to be.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Action {
+    /// The kind of command that was applied.
+    pub kind: ActionKind,
+    /// The point in time the command was applied at, measured from the point
+    /// in time the attempt started. This includes all the pauses and does not
+    /// take the timer's offset into account.
+    pub time: TimeSpan,
+}
+
+/// Describes the kind of command an [`Action`] represents.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ActionKind {
+    /// The attempt was started.
+    Start,
+    /// A split time was stored.
+    Split,
+    /// The time of the last split was adjusted by the time provided.
+    AdjustSplit(TimeSpan),
+    /// A split was skipped.
+    SkipSplit,
+    /// The last split was undone.
+    UndoSplit,
+    /// The timer was paused.
+    Pause,
+    /// The timer was resumed.
+    Resume,
+    /// All the pauses were undone.
+    UndoAllPauses,
+    /// Game Time was initialized.
+    InitializeGameTime,
+    /// The Game Time was set to the time provided.
+    SetGameTime(TimeSpan),
+    /// The Game Timer was paused.
+    PauseGameTime,
+    /// The Game Timer was resumed.
+    ResumeGameTime,
+    /// The loading times were set to the time provided.
+    SetLoadingTimes(TimeSpan),
+    /// The game started loading.
+    StartLoading,
+    /// The game stopped loading.
+    EndLoading,
+    /// The attempt was reset.
+    Reset,
+}
+
+impl ActionKind {
+    /// Returns the name of the kind of action. This is also the name used when
+    /// the action is stored in a splits file.
+    pub const fn name(self) -> &'static str {
+        match self {
+            ActionKind::Start => "Start",
+            ActionKind::Split => "Split",
+            ActionKind::AdjustSplit(_) => "AdjustSplit",
+            ActionKind::SkipSplit => "SkipSplit",
+            ActionKind::UndoSplit => "UndoSplit",
+            ActionKind::Pause => "Pause",
+            ActionKind::Resume => "Resume",
+            ActionKind::UndoAllPauses => "UndoAllPauses",
+            ActionKind::InitializeGameTime => "InitializeGameTime",
+            ActionKind::SetGameTime(_) => "SetGameTime",
+            ActionKind::PauseGameTime => "PauseGameTime",
+            ActionKind::ResumeGameTime => "ResumeGameTime",
+            ActionKind::SetLoadingTimes(_) => "SetLoadingTimes",
+            ActionKind::StartLoading => "StartLoading",
+            ActionKind::EndLoading => "EndLoading",
+            ActionKind::Reset => "Reset",
+        }
+    }
+
+    /// Returns the time that was provided along with the command, if there is
+    /// any.
+    pub const fn value(self) -> Option<TimeSpan> {
+        match self {
+            ActionKind::AdjustSplit(time)
+            | ActionKind::SetGameTime(time)
+            | ActionKind::SetLoadingTimes(time) => Some(time),
+            _ => None,
+        }
+    }
+
+    /// Creates the kind of action from its name and the time that was provided
+    /// along with the command. Returns `None` if the name is unknown or the
+    /// value is missing.
+    pub fn from_name(name: &str, value: Option<TimeSpan>) -> Option<Self> {
+        Some(match name {
+            "Start" => ActionKind::Start,
+            "Split" => ActionKind::Split,
+            "AdjustSplit" => ActionKind::AdjustSplit(value?),
+            "SkipSplit" => ActionKind::SkipSplit,
+            "UndoSplit" => ActionKind::UndoSplit,
+            "Pause" => ActionKind::Pause,
+            "Resume" => ActionKind::Resume,
+            "UndoAllPauses" => ActionKind::UndoAllPauses,
+            "InitializeGameTime" => ActionKind::InitializeGameTime,
+            "SetGameTime" => ActionKind::SetGameTime(value?),
+            "PauseGameTime" => ActionKind::PauseGameTime,
+            "ResumeGameTime" => ActionKind::ResumeGameTime,
+            "SetLoadingTimes" => ActionKind::SetLoadingTimes(value?),
+            "StartLoading" => ActionKind::StartLoading,
+            "EndLoading" => ActionKind::EndLoading,
+            "Reset" => ActionKind::Reset,
+            _ => return None,
+        })
+    }
+}
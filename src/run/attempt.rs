@@ -1,4 +1,5 @@
-use crate::{AtomicDateTime, Time, TimeSpan};
+use super::Action;
+use crate::{platform::prelude::*, AtomicDateTime, Time, TimeSpan};
 
 /// An `Attempt` describes information about an attempt to run a specific category
 /// by a specific runner in the past. Every time a new attempt is started and
@@ -10,6 +11,20 @@ pub struct Attempt {
     started: Option<AtomicDateTime>,
     ended: Option<AtomicDateTime>,
     pause_time: Option<TimeSpan>,
+    pause_count: Option<u32>,
+    longest_pause: Option<TimeSpan>,
+    actions: Vec<Action>,
+}
+
+/// Describes how often and for how long an attempt has been paused.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PauseStatistics {
+    /// The total amount of time the attempt has been paused for.
+    pub total: TimeSpan,
+    /// The number of times the attempt has been paused.
+    pub count: u32,
+    /// The duration of the longest pause.
+    pub longest: TimeSpan,
 }
 
 impl Attempt {
@@ -35,6 +50,9 @@ impl Attempt {
             started,
             ended,
             pause_time,
+            pause_count: None,
+            longest_pause: None,
+            actions: Vec::new(),
         }
     }
 
@@ -65,6 +83,12 @@ impl Attempt {
         self.time
     }
 
+    /// Sets the split time of the last segment.
+    #[inline]
+    pub fn set_time(&mut self, time: Time) {
+        self.time = time;
+    }
+
     /// Accesses the amount of time the attempt has been paused for. If it is
     /// not known, this returns `None`. This means that it may not necessarily
     /// be possible to differentiate whether a Run has not been paused or it
@@ -74,6 +98,48 @@ impl Attempt {
         self.pause_time
     }
 
+    /// Accesses the number of times the attempt has been paused. If it is not
+    /// known, this returns `None`.
+    #[inline]
+    pub const fn pause_count(&self) -> Option<u32> {
+        self.pause_count
+    }
+
+    /// Accesses the duration of the longest pause of the attempt. If it is not
+    /// known, this returns `None`.
+    #[inline]
+    pub const fn longest_pause(&self) -> Option<TimeSpan> {
+        self.longest_pause
+    }
+
+    /// Returns the statistics about the pauses of the attempt. This returns
+    /// `None` if not all of them are known.
+    pub fn pause_statistics(&self) -> Option<PauseStatistics> {
+        Some(PauseStatistics {
+            total: self.pause_time?,
+            count: self.pause_count?,
+            longest: self.longest_pause?,
+        })
+    }
+
+    /// Sets the amount of time the attempt has been paused for.
+    #[inline]
+    pub fn set_pause_time(&mut self, pause_time: Option<TimeSpan>) {
+        self.pause_time = pause_time;
+    }
+
+    /// Sets the number of times the attempt has been paused.
+    #[inline]
+    pub fn set_pause_count(&mut self, pause_count: Option<u32>) {
+        self.pause_count = pause_count;
+    }
+
+    /// Sets the duration of the longest pause of the attempt.
+    #[inline]
+    pub fn set_longest_pause(&mut self, longest_pause: Option<TimeSpan>) {
+        self.longest_pause = longest_pause;
+    }
+
     /// Accesses the point in time the attempt was started at. This returns
     /// `None` if this information is not known.
     #[inline]
@@ -87,4 +153,30 @@ impl Attempt {
     pub const fn ended(&self) -> Option<AtomicDateTime> {
         self.ended
     }
+
+    /// Sets the point in time the attempt was started at.
+    #[inline]
+    pub fn set_started(&mut self, started: Option<AtomicDateTime>) {
+        self.started = started;
+    }
+
+    /// Sets the point in time the attempt was ended at.
+    #[inline]
+    pub fn set_ended(&mut self, ended: Option<AtomicDateTime>) {
+        self.ended = ended;
+    }
+
+    /// Accesses the log of all the commands that were applied to the timer
+    /// during the attempt. This is empty if the log is not known.
+    #[inline]
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Sets the log of all the commands that were applied to the timer during
+    /// the attempt.
+    #[inline]
+    pub fn set_actions(&mut self, actions: Vec<Action>) {
+        self.actions = actions;
+    }
 }
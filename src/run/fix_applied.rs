@@ -0,0 +1,95 @@
+use crate::{platform::prelude::*, TimeSpan, TimingMethod};
+
+/// A `FixApplied` describes a single change that was made to a Run while fixing
+/// it with [`Run::fix_splits_reporting`](super::Run::fix_splits_reporting).
+/// Segments are referred to by their index and segment history elements by
+/// their attempt index, so editors can show the user what was changed instead
+/// of silently modifying the splits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FixApplied {
+    /// A negative best segment time was removed.
+    NegativeBestSegmentTimeRemoved {
+        /// The index of the segment.
+        segment: usize,
+        /// The timing method of the time.
+        method: TimingMethod,
+        /// The time that was removed.
+        time: TimeSpan,
+    },
+    /// A segment time was removed from the segment history, because the
+    /// segment doesn't have a best segment time for the timing method.
+    SegmentTimeWithoutBestSegmentRemoved {
+        /// The index of the segment.
+        segment: usize,
+        /// The index of the segment history element.
+        index: i32,
+        /// The timing method that doesn't have a best segment time.
+        method: TimingMethod,
+    },
+    /// A comparison time was lower than the one of a previous segment, so it
+    /// was raised to that time.
+    ComparisonTimeClamped {
+        /// The index of the segment.
+        segment: usize,
+        /// The name of the comparison.
+        comparison: String,
+        /// The timing method of the time.
+        method: TimingMethod,
+        /// The time before it was clamped.
+        from: TimeSpan,
+        /// The time after it was clamped.
+        to: TimeSpan,
+    },
+    /// The personal best contains a segment time that is faster than the best
+    /// segment time, so the best segment time was lowered to it.
+    BestSegmentTimeLowered {
+        /// The index of the segment.
+        segment: usize,
+        /// The timing method of the time.
+        method: TimingMethod,
+        /// The best segment time before it was lowered, if there was one.
+        from: Option<TimeSpan>,
+        /// The best segment time after it was lowered.
+        to: TimeSpan,
+    },
+    /// A segment time in the segment history was faster than the best segment
+    /// time, so it was raised to it.
+    SegmentTimeClamped {
+        /// The index of the segment.
+        segment: usize,
+        /// The index of the segment history element.
+        index: i32,
+        /// The timing method of the time.
+        method: TimingMethod,
+        /// The time before it was clamped.
+        from: TimeSpan,
+        /// The time after it was clamped.
+        to: TimeSpan,
+    },
+    /// A segment time that doesn't belong to any attempt was removed from the
+    /// segment history, because the same time is already stored for the
+    /// segment.
+    DuplicateSegmentTimeRemoved {
+        /// The index of the segment.
+        segment: usize,
+        /// The index of the segment history element.
+        index: i32,
+    },
+    /// An empty segment time was removed from the segment history, because
+    /// none of the following segments have a time for the same attempt.
+    EmptySegmentTimeRemoved {
+        /// The index of the segment.
+        segment: usize,
+        /// The index of the segment history element.
+        index: i32,
+    },
+    /// The segment history elements with an index that doesn't belong to any
+    /// attempt were moved to a new index preceding all the other ones.
+    SegmentHistoryReattached {
+        /// The index the segment history elements had before.
+        from: i32,
+        /// The index the segment history elements have now.
+        to: i32,
+    },
+}
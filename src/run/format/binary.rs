@@ -0,0 +1,22 @@
+//! The constants describing the layout of livesplit-core's binary splits
+//! files, shared by the parser and the saver.
+
+/// The magic bytes every binary splits file starts with.
+pub(in crate::run) const MAGIC: [u8; 4] = *b"LSCR";
+
+/// The version of the format that is written by the saver.
+pub(in crate::run) const VERSION: u32 = 1;
+
+/// The linked layout is stored as a tag, followed by the path of the layout.
+pub(in crate::run) const LINKED_LAYOUT_NONE: u8 = 0;
+pub(in crate::run) const LINKED_LAYOUT_DEFAULT: u8 = 1;
+pub(in crate::run) const LINKED_LAYOUT_PATH: u8 = 2;
+
+/// Atomic date times are stored as a tag, followed by the date time.
+pub(in crate::run) const DATE_TIME_NONE: u8 = 0;
+pub(in crate::run) const DATE_TIME_UNSYNCED: u8 = 1;
+pub(in crate::run) const DATE_TIME_SYNCED: u8 = 2;
+
+/// The bits describing which timing methods of a time are present.
+pub(in crate::run) const REAL_TIME: u8 = 1 << 0;
+pub(in crate::run) const GAME_TIME: u8 = 1 << 1;
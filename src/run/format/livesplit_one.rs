@@ -0,0 +1,269 @@
+//! The types describing LiveSplit One's JSON splits files, shared by the
+//! parser and the savers that write parts of the format.
+
+use crate::{
+    platform::prelude::*, run::LinkedLayout, settings::Image, AtomicDateTime, DateTime, Time,
+    TimeSpan,
+};
+use alloc::borrow::Cow;
+use core::{fmt, result::Result as StdResult};
+use serde::{de, Deserializer, Serializer};
+use serde_derive::{Deserialize, Serialize};
+use time::{Date, Month, PrimitiveDateTime, UtcOffset};
+
+/// The version of the format that is written by the saver.
+pub(in crate::run) const VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) enum Format {
+    #[serde(rename = "LiveSplit One")]
+    LiveSplitOne,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct SplitsFile<'a> {
+    pub format: Format,
+    pub version: u32,
+    #[serde(borrow)]
+    pub game_name: Cow<'a, str>,
+    pub game_icon: Image,
+    #[serde(borrow)]
+    pub category_name: Cow<'a, str>,
+    pub offset: JsonTimeSpan,
+    pub attempt_count: u32,
+    #[serde(borrow)]
+    pub metadata: Metadata<'a>,
+    pub linked_layout: Option<LinkedLayout>,
+    #[serde(borrow)]
+    pub custom_comparisons: Vec<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub attempt_history: Vec<JsonAttempt<'a>>,
+    #[serde(borrow)]
+    pub segments: Vec<JsonSegment<'a>>,
+    #[serde(borrow)]
+    pub auto_splitter_settings: Cow<'a, str>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct Metadata<'a> {
+    #[serde(borrow)]
+    pub run_id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub platform_name: Cow<'a, str>,
+    pub uses_emulator: bool,
+    #[serde(borrow)]
+    pub region_name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub speedrun_com_variables: Vec<Variable<'a>>,
+    #[serde(borrow)]
+    pub custom_variables: Vec<Variable<'a>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct Variable<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub value: Cow<'a, str>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct JsonAttempt<'a> {
+    pub index: i32,
+    pub time: JsonTime,
+    pub started: Option<JsonAtomicDateTime>,
+    pub ended: Option<JsonAtomicDateTime>,
+    pub pause_time: Option<JsonTimeSpan>,
+    pub pause_count: Option<u32>,
+    pub longest_pause: Option<JsonTimeSpan>,
+    #[serde(borrow)]
+    pub actions: Vec<JsonAction<'a>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct JsonAction<'a> {
+    #[serde(rename = "type", borrow)]
+    pub kind: Cow<'a, str>,
+    pub time: JsonTimeSpan,
+    pub value: Option<JsonTimeSpan>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct JsonSegment<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    pub icon: Image,
+    #[serde(borrow)]
+    pub comparisons: Vec<JsonComparison<'a>>,
+    pub best_segment_time: JsonTime,
+    pub segment_history: Vec<JsonSegmentHistoryElement>,
+    #[serde(borrow)]
+    pub variables: Vec<Variable<'a>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct JsonComparison<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    pub time: JsonTime,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct JsonSegmentHistoryElement {
+    pub index: i32,
+    pub time: JsonTime,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct JsonTime {
+    pub real_time: Option<JsonTimeSpan>,
+    pub game_time: Option<JsonTimeSpan>,
+}
+
+impl From<Time> for JsonTime {
+    fn from(time: Time) -> Self {
+        Self {
+            real_time: time.real_time.map(JsonTimeSpan),
+            game_time: time.game_time.map(JsonTimeSpan),
+        }
+    }
+}
+
+impl From<JsonTime> for Time {
+    fn from(time: JsonTime) -> Self {
+        Time {
+            real_time: time.real_time.map(|t| t.0),
+            game_time: time.game_time.map(|t| t.0),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(in crate::run) struct JsonAtomicDateTime {
+    pub time: JsonDateTime,
+    pub synced: bool,
+}
+
+impl From<AtomicDateTime> for JsonAtomicDateTime {
+    fn from(date_time: AtomicDateTime) -> Self {
+        Self {
+            time: JsonDateTime(date_time.time),
+            synced: date_time.synced_with_atomic_clock,
+        }
+    }
+}
+
+impl From<JsonAtomicDateTime> for AtomicDateTime {
+    fn from(date_time: JsonAtomicDateTime) -> Self {
+        AtomicDateTime::new(date_time.time.0, date_time.synced)
+    }
+}
+
+/// A time span that is stored as a string of the whole seconds and the
+/// nanoseconds.
+pub(in crate::run) struct JsonTimeSpan(pub TimeSpan);
+
+impl serde::Serialize for JsonTimeSpan {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (seconds, nanos) = self.0.to_seconds_and_subsec_nanoseconds();
+        let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+        serializer.collect_str(&format_args!(
+            "{sign}{}.{:09}",
+            seconds.unsigned_abs(),
+            nanos.unsigned_abs()
+        ))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for JsonTimeSpan {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = Cow::<'de, str>::deserialize(deserializer)?;
+        text.parse().map(JsonTimeSpan).map_err(de::Error::custom)
+    }
+}
+
+/// A date time that is stored as a string in the RFC 3339 format in UTC.
+pub(in crate::run) struct JsonDateTime(pub DateTime);
+
+impl fmt::Display for JsonDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.0.to_offset(UtcOffset::UTC);
+        let (year, month, day) = date.to_calendar_date();
+        let month = month as u8;
+        let (hour, minute, second, nanos) = date.to_hms_nano();
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{nanos:09}Z"
+        )
+    }
+}
+
+impl serde::Serialize for JsonDateTime {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for JsonDateTime {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = Cow::<'de, str>::deserialize(deserializer)?;
+        parse_date_time(&text)
+            .map(JsonDateTime)
+            .ok_or_else(|| de::Error::custom(InvalidDate))
+    }
+}
+
+struct InvalidDate;
+
+impl fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid RFC 3339 date")
+    }
+}
+
+pub(in crate::run) fn parse_date_time(text: &str) -> Option<DateTime> {
+    let text = text.strip_suffix('Z')?;
+    let (date, time) = text.split_once('T')?;
+
+    let (year, rem) = date.split_once('-')?;
+    let (month, day) = rem.split_once('-')?;
+
+    let (hour, rem) = time.split_once(':')?;
+    let (minute, rem) = rem.split_once(':')?;
+    let (second, nanos) = rem.split_once('.').unwrap_or((rem, "0"));
+    if nanos.len() > 9 {
+        return None;
+    }
+    let nanos = nanos.parse::<u32>().ok()? * 10_u32.pow(9 - nanos.len() as u32);
+
+    Some(
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(
+                year.parse().ok()?,
+                Month::try_from(month.parse::<u8>().ok()?).ok()?,
+                day.parse().ok()?,
+            )
+            .ok()?,
+            time::Time::from_hms_nano(
+                hour.parse().ok()?,
+                minute.parse().ok()?,
+                second.parse().ok()?,
+                nanos,
+            )
+            .ok()?,
+        )
+        .assume_utc(),
+    )
+}
@@ -0,0 +1,6 @@
+//! The definitions of the splits file formats that are shared by their
+//! parsers and savers, so the savers are available regardless of which
+//! parsers are enabled.
+
+pub mod binary;
+pub mod livesplit_one;
@@ -14,26 +14,33 @@
 //! run.push_segment(Segment::new("Cascade Kingdom"));
 //! ```
 
+mod action;
 mod attempt;
 mod comparisons;
 pub mod editor;
+mod fix_applied;
+mod format;
 mod linked_layout;
 pub mod parser;
 mod run_metadata;
 pub mod saver;
 mod segment;
 mod segment_history;
+mod world_record;
 
 #[cfg(test)]
 mod tests;
 
-pub use attempt::Attempt;
+pub use action::{Action, ActionKind};
+pub use attempt::{Attempt, PauseStatistics};
 pub use comparisons::Comparisons;
 pub use editor::{Editor, RenameError};
+pub use fix_applied::FixApplied;
 pub use linked_layout::LinkedLayout;
 pub use run_metadata::{CustomVariable, RunMetadata};
 pub use segment::Segment;
 pub use segment_history::SegmentHistory;
+pub use world_record::WorldRecord;
 
 use crate::{
     comparison::{default_generators, personal_best, ComparisonGenerator, RACE_COMPARISON_PREFIX},
@@ -76,6 +83,7 @@ pub struct Run {
     comparison_generators: ComparisonGenerators,
     auto_splitter_settings: String,
     linked_layout: Option<LinkedLayout>,
+    embedded_layout: String,
 }
 
 #[derive(Clone, Debug)]
@@ -129,6 +137,7 @@ impl Run {
             comparison_generators: ComparisonGenerators(default_generators()),
             auto_splitter_settings: String::new(),
             linked_layout: None,
+            embedded_layout: String::new(),
         }
     }
 
@@ -266,6 +275,24 @@ impl Run {
         &self.attempt_history
     }
 
+    /// Grants mutable access to the Attempt History. Attempts can't be added
+    /// or removed this way, as their indices need to stay unique.
+    #[inline]
+    pub fn attempt_history_mut(&mut self) -> &mut [Attempt] {
+        &mut self.attempt_history
+    }
+
+    /// Removes all the attempts from the Attempt History, where the given
+    /// closure returns `false`. The segment times of the attempts are not
+    /// removed from the Segment Histories.
+    #[inline]
+    pub fn retain_attempts<F>(&mut self, f: F)
+    where
+        F: FnMut(&Attempt) -> bool,
+    {
+        self.attempt_history.retain(f);
+    }
+
     /// Accesses the custom comparisons that are stored in this Run. This
     /// includes `Personal Best` but excludes all the other Comparison
     /// Generators.
@@ -370,6 +397,29 @@ impl Run {
         self.linked_layout = linked_layout;
     }
 
+    /// Accesses the [`Layout`](crate::Layout) that is embedded in the splits
+    /// file, encoded as a layout file of the original LiveSplit. This allows a
+    /// single file to store both the `Run` and how it's supposed to be
+    /// visualized. It is empty if no [`Layout`](crate::Layout) is embedded. Use
+    /// [`parse_embedded`](crate::layout::parser::parse_embedded) to parse it.
+    #[inline]
+    pub fn embedded_layout(&self) -> &str {
+        &self.embedded_layout
+    }
+
+    /// Grants mutable access to the XML encoded embedded
+    /// [`Layout`](crate::Layout). Use [`embed`](crate::layout::saver::embed) to
+    /// embed a [`Layout`](crate::Layout).
+    ///
+    /// # Warning
+    ///
+    /// You need to ensure that the embedded [`Layout`](crate::Layout) is
+    /// encoded as a single `Layout` XML element.
+    #[inline]
+    pub fn embedded_layout_mut(&mut self) -> &mut String {
+        &mut self.embedded_layout
+    }
+
     /// Returns the amount of segments stored in this Run.
     #[inline]
     pub fn len(&self) -> usize {
@@ -542,12 +592,22 @@ impl Run {
     /// comparison times and history, removing duplicates in the segment
     /// histories and removing empty times.
     pub fn fix_splits(&mut self) {
+        self.fix_splits_reporting(|_| {});
+    }
+
+    /// Applies the same fixing algorithms as [`fix_splits`](Self::fix_splits),
+    /// but reports every change that is made to the Run to the closure
+    /// provided.
+    pub fn fix_splits_reporting<F>(&mut self, mut report: F)
+    where
+        F: FnMut(FixApplied),
+    {
         for method in TimingMethod::all() {
-            self.fix_comparison_times_and_history(method);
+            self.fix_comparison_times_and_history(method, &mut report);
         }
-        self.remove_duplicates();
-        self.remove_none_values();
-        self.reattach_unattached_segment_history_elements();
+        self.remove_duplicates(&mut report);
+        self.remove_none_values(&mut report);
+        self.reattach_unattached_segment_history_elements(&mut report);
     }
 
     /// Clears out the Attempt History and the Segment Histories of all the segments.
@@ -573,24 +633,42 @@ impl Run {
         self.clear_run_id();
     }
 
-    fn fix_comparison_times_and_history(&mut self, method: TimingMethod) {
+    fn fix_comparison_times_and_history(
+        &mut self,
+        method: TimingMethod,
+        report: &mut impl FnMut(FixApplied),
+    ) {
         // Remove negative Best Segment Times
-        for segment in &mut self.segments {
-            if segment.best_segment_time_mut()[method].is_some_and(|t| t < TimeSpan::zero()) {
-                segment.best_segment_time_mut()[method] = None;
+        for (index, segment) in self.segments.iter_mut().enumerate() {
+            if let Some(time) = segment.best_segment_time()[method] {
+                if time < TimeSpan::zero() {
+                    segment.best_segment_time_mut()[method] = None;
+                    report(FixApplied::NegativeBestSegmentTimeRemoved {
+                        segment: index,
+                        method,
+                        time,
+                    });
+                }
             }
         }
 
-        for segment in &mut self.segments {
-            fix_history_from_none_best_segments(segment, method);
+        for (index, segment) in self.segments.iter_mut().enumerate() {
+            fix_history_from_none_best_segments(index, segment, method, report);
         }
 
         for comparison in &self.custom_comparisons {
             let mut previous_time = TimeSpan::zero();
-            for segment in &mut self.segments {
+            for (index, segment) in self.segments.iter_mut().enumerate() {
                 if let Some(mut time) = segment.comparison_mut(comparison)[method] {
                     // Prevent comparison times from decreasing from one split to the next
                     if time < previous_time {
+                        report(FixApplied::ComparisonTimeClamped {
+                            segment: index,
+                            comparison: comparison.clone(),
+                            method,
+                            from: time,
+                            to: previous_time,
+                        });
                         time = previous_time;
                         segment.comparison_mut(comparison)[method] = Some(time);
                     }
@@ -598,8 +676,15 @@ impl Run {
                     // Fix Best Segment time if the PB segment is faster
                     if comparison == personal_best::NAME {
                         let current_segment = time - previous_time;
-                        if segment.best_segment_time()[method].is_none_or(|t| t > current_segment) {
+                        let best_segment = segment.best_segment_time()[method];
+                        if best_segment.is_none_or(|t| t > current_segment) {
                             segment.best_segment_time_mut()[method] = Some(current_segment);
+                            report(FixApplied::BestSegmentTimeLowered {
+                                segment: index,
+                                method,
+                                from: best_segment,
+                                to: current_segment,
+                            });
                         }
                     }
 
@@ -608,12 +693,12 @@ impl Run {
             }
         }
 
-        for segment in &mut self.segments {
-            fix_history_from_best_segment_times(segment, method);
+        for (index, segment) in self.segments.iter_mut().enumerate() {
+            fix_history_from_best_segment_times(index, segment, method, report);
         }
     }
 
-    fn remove_none_values(&mut self) {
+    fn remove_none_values(&mut self, report: &mut impl FnMut(FixApplied)) {
         let mut cache = Vec::new();
         if let Some(min_index) = self.min_segment_history_index() {
             let max_index = self.max_attempt_history_index().unwrap_or(0) + 1;
@@ -627,20 +712,20 @@ impl Run {
                         }
                     } else {
                         // Remove None times in history that aren't followed by a non-None time
-                        self.remove_items_from_cache(index, &mut cache);
+                        self.remove_items_from_cache(index, &mut cache, report);
                     }
                 }
                 let len = self.len();
-                self.remove_items_from_cache(len, &mut cache);
+                self.remove_items_from_cache(len, &mut cache, report);
             }
         }
     }
 
-    fn remove_duplicates(&mut self) {
+    fn remove_duplicates(&mut self, report: &mut impl FnMut(FixApplied)) {
         let mut rta_set = HashSet::new();
         let mut igt_set = HashSet::new();
 
-        for segment in self.segments_mut() {
+        for (segment_index, segment) in self.segments_mut().iter_mut().enumerate() {
             let history = segment.segment_history_mut();
 
             rta_set.clear();
@@ -671,15 +756,35 @@ impl Run {
                     is_none = false;
                 }
 
-                is_none || is_unique
+                let keep = is_none || is_unique;
+                if !keep {
+                    report(FixApplied::DuplicateSegmentTimeRemoved {
+                        segment: segment_index,
+                        index,
+                    });
+                }
+                keep
             });
         }
     }
 
-    fn remove_items_from_cache(&mut self, index: usize, cache: &mut Vec<i32>) {
+    fn remove_items_from_cache(
+        &mut self,
+        index: usize,
+        cache: &mut Vec<i32>,
+        report: &mut impl FnMut(FixApplied),
+    ) {
         let ind = index - cache.len();
-        for (index, segment) in cache.drain(..).zip(self.segments_mut()[ind..].iter_mut()) {
+        for (segment_index, (index, segment)) in cache
+            .drain(..)
+            .zip(self.segments_mut()[ind..].iter_mut())
+            .enumerate()
+        {
             segment.segment_history_mut().remove(index);
+            report(FixApplied::EmptySegmentTimeRemoved {
+                segment: ind + segment_index,
+                index,
+            });
         }
     }
 
@@ -774,7 +879,10 @@ impl Run {
         }
     }
 
-    fn reattach_unattached_segment_history_elements(&mut self) {
+    fn reattach_unattached_segment_history_elements(
+        &mut self,
+        report: &mut impl FnMut(FixApplied),
+    ) {
         let max_id = self.max_attempt_history_index().unwrap_or_default();
         let mut min_id = self.min_segment_history_index().unwrap_or_default();
 
@@ -794,6 +902,10 @@ impl Run {
                 }
             }
 
+            report(FixApplied::SegmentHistoryReattached {
+                from: unattached_id,
+                to: reassign_id,
+            });
             min_id = reassign_id;
         }
     }
@@ -805,22 +917,47 @@ impl Default for Run {
     }
 }
 
-fn fix_history_from_none_best_segments(segment: &mut Segment, method: TimingMethod) {
+fn fix_history_from_none_best_segments(
+    segment_index: usize,
+    segment: &mut Segment,
+    method: TimingMethod,
+    report: &mut impl FnMut(FixApplied),
+) {
     // Only do anything if the Best Segment Time is gone for the Segment in question
     if segment.best_segment_time()[method].is_none() {
         // Keep only the skipped segments
-        segment
-            .segment_history_mut()
-            .retain(|&(_, time)| time[method].is_none());
+        segment.segment_history_mut().retain(|&(index, time)| {
+            let keep = time[method].is_none();
+            if !keep {
+                report(FixApplied::SegmentTimeWithoutBestSegmentRemoved {
+                    segment: segment_index,
+                    index,
+                    method,
+                });
+            }
+            keep
+        });
     }
 }
 
-fn fix_history_from_best_segment_times(segment: &mut Segment, method: TimingMethod) {
+fn fix_history_from_best_segment_times(
+    segment_index: usize,
+    segment: &mut Segment,
+    method: TimingMethod,
+    report: &mut impl FnMut(FixApplied),
+) {
     if let Some(best_segment) = segment.best_segment_time()[method] {
-        for (_, time) in segment.segment_history_mut().iter_mut() {
+        for &mut (index, ref mut time) in segment.segment_history_mut().iter_mut() {
             // Make sure no times in the history are lower than the Best Segment
             if let Some(time) = &mut time[method] {
                 if *time < best_segment {
+                    report(FixApplied::SegmentTimeClamped {
+                        segment: segment_index,
+                        index,
+                        method,
+                        from: *time,
+                        to: best_segment,
+                    });
                     *time = best_segment;
                 }
             }
@@ -0,0 +1,288 @@
+//! Provides the parser for CSV files that store the attempt history or the
+//! segment histories of a run, as exported by the corresponding
+//! [saver](crate::run::saver::attempt_history_csv). Instead of creating a new
+//! run, the histories of an existing run are replaced by the ones in the file,
+//! so that corrections made in a spreadsheet can be imported again.
+//!
+//! The columns are recognized by their names in the header, so they may be
+//! reordered and additional columns, such as calculated ones, are ignored. A
+//! tab is used as the delimiter if the header contains one.
+//!
+//! # Examples
+//!
+//! ```
+//! use livesplit_core::run::parser::attempt_history_csv;
+//! use livesplit_core::{Run, Segment, TimeSpan};
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//! run.push_segment(Segment::new("Cascade Kingdom"));
+//!
+//! let source = "Attempt,Segment,Segment Name,Real Time,Game Time
+//! 1,1,Cap Kingdom,4:13.5,
+//! 1,2,Cascade Kingdom,5:41.6,
+//! ";
+//!
+//! attempt_history_csv::import_segment_history(source, &mut run)
+//!     .expect("Couldn't import the segment history");
+//! assert_eq!(
+//!     run.segment(1).segment_history().get(1).unwrap().real_time,
+//!     Some(TimeSpan::from_seconds(341.6)),
+//! );
+//! ```
+
+use super::{
+    csv::{detect_delimiter, Fields},
+    located::Lines,
+    Located,
+};
+use crate::{
+    platform::prelude::*, run::format::livesplit_one::parse_date_time, timing, AtomicDateTime, Run,
+    Time, TimeSpan,
+};
+use core::{num::ParseIntError, result::Result as StdResult};
+use hashbrown::HashSet;
+use snafu::ResultExt;
+
+/// The Error type for CSV files that couldn't be imported by the Attempt
+/// History CSV Parser.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// The header is missing a required column.
+    MissingColumn {
+        /// The name of the column.
+        name: &'static str,
+    },
+    /// A quoted field is missing its closing quote.
+    UnterminatedQuote,
+    /// Failed to parse the index of an attempt.
+    ParseAttemptIndex {
+        /// The underlying error.
+        source: ParseIntError,
+    },
+    /// The same attempt is listed more than once.
+    DuplicateAttempt,
+    /// Failed to parse the number of a segment.
+    ParseSegment {
+        /// The underlying error.
+        source: ParseIntError,
+    },
+    /// The number of the segment doesn't refer to a segment of the run.
+    InvalidSegment,
+    /// The same segment of an attempt is listed more than once.
+    DuplicateSegmentTime,
+    /// Failed to parse a date.
+    ParseDate,
+    /// Failed to parse a time.
+    ParseTime {
+        /// The underlying error.
+        source: timing::ParseError,
+    },
+    /// Failed to parse the number of pauses.
+    ParsePauseCount {
+        /// The underlying error.
+        source: ParseIntError,
+    },
+}
+
+/// The Result type for the Attempt History CSV Parser.
+pub type Result<T> = StdResult<T, Error>;
+
+/// Determines the indices of the columns with the given names in the header.
+fn columns<const N: usize>(
+    header: &str,
+    delimiter: char,
+    names: [&'static str; N],
+) -> Result<[usize; N]> {
+    let mut fields = Vec::new();
+    for field in Fields::new(header, delimiter) {
+        fields.push(field.map_err(|_| Error::UnterminatedQuote)?);
+    }
+
+    let mut columns = [0; N];
+    for (column, name) in columns.iter_mut().zip(names) {
+        *column = fields
+            .iter()
+            .position(|field| field.trim().eq_ignore_ascii_case(name))
+            .ok_or(Error::MissingColumn { name })?;
+    }
+    Ok(columns)
+}
+
+/// Parses all the rows that follow the header, skipping empty lines.
+fn rows<const N: usize>(
+    lines: &mut Lines<'_>,
+    names: [&'static str; N],
+    mut row: impl FnMut([&str; N]) -> Result<()>,
+) -> Result<()> {
+    let header = lines.next().unwrap_or_default();
+    let delimiter = detect_delimiter(header, None);
+    let columns = columns(header, delimiter, names)?;
+
+    let mut fields = Vec::new();
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        fields.clear();
+        for field in Fields::new(line, delimiter) {
+            fields.push(field.map_err(|_| Error::UnterminatedQuote)?);
+        }
+        row(columns.map(|column| fields.get(column).map_or("", |field| field.trim())))?;
+    }
+
+    Ok(())
+}
+
+fn parse_time(text: &str) -> Result<Option<TimeSpan>> {
+    TimeSpan::parse_opt(text).context(ParseTime)
+}
+
+/// Parses the date, keeping whether the previous date was synchronized with an
+/// atomic clock if it didn't change.
+fn parse_date(text: &str, previous: Option<AtomicDateTime>) -> Result<Option<AtomicDateTime>> {
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let date = parse_date_time(text).ok_or(Error::ParseDate)?;
+    Ok(Some(match previous {
+        Some(previous) if previous.time == date => previous,
+        _ => AtomicDateTime::new(date, false),
+    }))
+}
+
+/// Replaces the attempt history of the run with the attempts stored in the CSV
+/// file. Attempts that are not listed in the file are removed, while the log of
+/// the commands applied to the timer is kept for the attempts that are. The
+/// segment histories and the attempt count are not changed. If the file can't
+/// be parsed, the run is left unchanged.
+pub fn import_attempts(source: &str, run: &mut Run) -> StdResult<(), Located<Error>> {
+    let mut lines = Lines::new(source);
+    import_attempts_from_lines(&mut lines, run).map_err(|error| lines.locate(error))
+}
+
+fn import_attempts_from_lines(lines: &mut Lines<'_>, run: &mut Run) -> Result<()> {
+    let mut indices = HashSet::new();
+    let mut attempts = Vec::new();
+
+    rows(
+        lines,
+        [
+            "Attempt",
+            "Started",
+            "Ended",
+            "Real Time",
+            "Game Time",
+            "Pause Time",
+            "Pause Count",
+            "Longest Pause",
+        ],
+        |[index, started, ended, real_time, game_time, pause_time, pause_count, longest_pause]| {
+            let index: i32 = index.parse().context(ParseAttemptIndex)?;
+            if !indices.insert(index) {
+                return Err(Error::DuplicateAttempt);
+            }
+            let previous = run
+                .attempt_history()
+                .iter()
+                .find(|attempt| attempt.index() == index);
+
+            let started = parse_date(started, previous.and_then(|a| a.started()))?;
+            let ended = parse_date(ended, previous.and_then(|a| a.ended()))?;
+            let time = Time::new()
+                .with_real_time(parse_time(real_time)?)
+                .with_game_time(parse_time(game_time)?);
+            let pause_time = parse_time(pause_time)?;
+            let pause_count = if pause_count.is_empty() {
+                None
+            } else {
+                Some(pause_count.parse().context(ParsePauseCount)?)
+            };
+            let longest_pause = parse_time(longest_pause)?;
+
+            attempts.push((
+                index,
+                time,
+                started,
+                ended,
+                pause_time,
+                pause_count,
+                longest_pause,
+            ));
+            Ok(())
+        },
+    )?;
+
+    run.retain_attempts(|attempt| indices.contains(&attempt.index()));
+
+    for (index, time, started, ended, pause_time, pause_count, longest_pause) in attempts {
+        let attempt = match run
+            .attempt_history()
+            .iter()
+            .position(|attempt| attempt.index() == index)
+        {
+            Some(position) => &mut run.attempt_history_mut()[position],
+            None => {
+                run.add_attempt_with_index(time, index, started, ended, pause_time);
+                run.attempt_history_mut().last_mut().unwrap()
+            }
+        };
+        attempt.set_time(time);
+        attempt.set_started(started);
+        attempt.set_ended(ended);
+        attempt.set_pause_time(pause_time);
+        attempt.set_pause_count(pause_count);
+        attempt.set_longest_pause(longest_pause);
+    }
+
+    Ok(())
+}
+
+/// Replaces the segment histories of all the segments of the run with the
+/// segment times stored in the CSV file. The segments are referred to by their
+/// number, starting at 1, while the names of the segments are ignored. Neither
+/// the attempt history nor the best segments are changed. If the file can't be
+/// parsed, the run is left unchanged.
+pub fn import_segment_history(source: &str, run: &mut Run) -> StdResult<(), Located<Error>> {
+    let mut lines = Lines::new(source);
+    import_segment_history_from_lines(&mut lines, run).map_err(|error| lines.locate(error))
+}
+
+fn import_segment_history_from_lines(lines: &mut Lines<'_>, run: &mut Run) -> Result<()> {
+    let mut keys = HashSet::new();
+    let mut segment_times = Vec::new();
+
+    rows(
+        lines,
+        ["Attempt", "Segment", "Real Time", "Game Time"],
+        |[index, segment, real_time, game_time]| {
+            let index: i32 = index.parse().context(ParseAttemptIndex)?;
+            let segment: usize = segment.parse().context(ParseSegment)?;
+            let segment = segment
+                .checked_sub(1)
+                .filter(|&segment| segment < run.len())
+                .ok_or(Error::InvalidSegment)?;
+            if !keys.insert((index, segment)) {
+                return Err(Error::DuplicateSegmentTime);
+            }
+            let time = Time::new()
+                .with_real_time(parse_time(real_time)?)
+                .with_game_time(parse_time(game_time)?);
+            segment_times.push((index, segment, time));
+            Ok(())
+        },
+    )?;
+
+    for segment in run.segments_mut() {
+        segment.segment_history_mut().clear();
+    }
+    for (index, segment, time) in segment_times {
+        run.segment_mut(segment)
+            .segment_history_mut()
+            .insert(index, time);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,295 @@
+//! Provides the parser for livesplit-core's binary splits files.
+//!
+//! The binary format is a compact serialization of a [`Run`] that is optimized
+//! for loading quickly. It is meant to be used as a local cache by frontends
+//! that need to open lots of splits files at once, such as a splits browser,
+//! and not as a format for exchanging splits with other applications. Use the
+//! [saver](crate::run::saver::binary) to create binary splits files. Just like
+//! LiveSplit One's [JSON splits files](super::livesplit_one), they store all
+//! the information of a [`Run`] without any loss of precision. The format is
+//! not detected by the [composite parser](super::composite).
+//!
+//! A binary splits file starts with the magic bytes `LSCR`, followed by the
+//! version of the format as a little endian `u32`. All other integers are
+//! stored as LEB128 variable length integers, with signed integers being zigzag
+//! encoded first. Strings and images are stored as their length in bytes,
+//! followed by the bytes themselves. Time spans are stored as their whole
+//! seconds and the nanoseconds past them. Dates are stored as the seconds and
+//! nanoseconds since the Unix epoch. Optional values and the timing methods of
+//! times are preceded by a byte that describes which of them are present.
+
+use crate::{
+    platform::prelude::*,
+    run::{
+        format::binary::{
+            DATE_TIME_NONE, DATE_TIME_SYNCED, DATE_TIME_UNSYNCED, GAME_TIME, LINKED_LAYOUT_DEFAULT,
+            LINKED_LAYOUT_NONE, LINKED_LAYOUT_PATH, MAGIC, REAL_TIME, VERSION,
+        },
+        Action, ActionKind, LinkedLayout,
+    },
+    settings::Image,
+    AtomicDateTime, DateTime, Run, Segment, Time, TimeSpan,
+};
+use core::{result::Result as StdResult, str};
+use time::Duration;
+
+/// The Error type for binary splits files that couldn't be parsed.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// The file is not a binary splits file.
+    Magic,
+    /// The splits file was created by a newer version of the format.
+    UnsupportedVersion,
+    /// The splits file ended unexpectedly.
+    UnexpectedEnd,
+    /// A string is not valid UTF-8.
+    Utf8,
+    /// A value is out of the range of valid values.
+    InvalidValue,
+}
+
+/// The Result type for the binary splits file parser.
+pub type Result<T> = StdResult<T, Error>;
+
+struct Reader<'a> {
+    cursor: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn u8(&mut self) -> Result<u8> {
+        let (&byte, rest) = self.cursor.split_first().ok_or(Error::UnexpectedEnd)?;
+        self.cursor = rest;
+        Ok(byte)
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.cursor.len() < len {
+            return Err(Error::UnexpectedEnd);
+        }
+        let (bytes, rest) = self.cursor.split_at(len);
+        self.cursor = rest;
+        Ok(bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(Error::InvalidValue)
+    }
+
+    fn i64(&mut self) -> Result<i64> {
+        let value = self.u64()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        self.u64()?.try_into().map_err(|_| Error::InvalidValue)
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        self.i64()?.try_into().map_err(|_| Error::InvalidValue)
+    }
+
+    fn bool(&mut self) -> Result<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::InvalidValue),
+        }
+    }
+
+    /// Reads the amount of elements of a list. As each element takes up at
+    /// least a byte, this never exceeds the remaining bytes, which limits the
+    /// memory that is allocated upfront for corrupted files.
+    fn len(&mut self) -> Result<usize> {
+        let len = self.u64()?;
+        if len > self.cursor.len() as u64 {
+            return Err(Error::UnexpectedEnd);
+        }
+        Ok(len as usize)
+    }
+
+    fn str(&mut self) -> Result<&'a str> {
+        let len = self.len()?;
+        str::from_utf8(self.bytes(len)?).map_err(|_| Error::Utf8)
+    }
+
+    fn image(&mut self) -> Result<Image> {
+        let len = self.len()?;
+        let data = self.bytes(len)?;
+        // The images already got shrunk before they got saved.
+        Ok(Image::new_inner(data.into()))
+    }
+
+    fn time_span(&mut self) -> Result<TimeSpan> {
+        let seconds = self.i64()?;
+        let nanos = self.i32()?;
+        if !(-999_999_999..=999_999_999).contains(&nanos) {
+            return Err(Error::InvalidValue);
+        }
+        Ok(Duration::new(seconds, nanos).into())
+    }
+
+    fn optional_time_span(&mut self) -> Result<Option<TimeSpan>> {
+        Ok(if self.bool()? {
+            Some(self.time_span()?)
+        } else {
+            None
+        })
+    }
+
+    fn time(&mut self) -> Result<Time> {
+        let flags = self.u8()?;
+        if flags & !(REAL_TIME | GAME_TIME) != 0 {
+            return Err(Error::InvalidValue);
+        }
+        let mut time = Time::new();
+        if flags & REAL_TIME != 0 {
+            time.real_time = Some(self.time_span()?);
+        }
+        if flags & GAME_TIME != 0 {
+            time.game_time = Some(self.time_span()?);
+        }
+        Ok(time)
+    }
+
+    fn date_time(&mut self) -> Result<Option<AtomicDateTime>> {
+        let synced = match self.u8()? {
+            DATE_TIME_NONE => return Ok(None),
+            DATE_TIME_UNSYNCED => false,
+            DATE_TIME_SYNCED => true,
+            _ => return Err(Error::InvalidValue),
+        };
+        let seconds = self.i64()?;
+        let nanos = self.u32()?;
+        let time = DateTime::from_unix_timestamp(seconds)
+            .ok()
+            .and_then(|time| time.replace_nanosecond(nanos).ok())
+            .ok_or(Error::InvalidValue)?;
+        Ok(Some(AtomicDateTime::new(time, synced)))
+    }
+}
+
+/// Attempts to parse a binary splits file.
+pub fn parse(source: &[u8]) -> Result<Run> {
+    let reader = &mut Reader { cursor: source };
+
+    if reader.bytes(MAGIC.len()).ok() != Some(&MAGIC[..]) {
+        return Err(Error::Magic);
+    }
+    let version = reader.bytes(4)?;
+    if u32::from_le_bytes(version.try_into().unwrap()) > VERSION {
+        return Err(Error::UnsupportedVersion);
+    }
+
+    let mut run = Run::new();
+
+    run.set_game_name(reader.str()?);
+    run.set_game_icon(reader.image()?);
+    run.set_category_name(reader.str()?);
+    run.set_offset(reader.time_span()?);
+    run.set_attempt_count(reader.u32()?);
+
+    let metadata = run.metadata_mut();
+    metadata.set_run_id(reader.str()?);
+    metadata.set_platform_name(reader.str()?);
+    metadata.set_emulator_usage(reader.bool()?);
+    metadata.set_region_name(reader.str()?);
+    for _ in 0..reader.len()? {
+        let name = reader.str()?;
+        metadata.set_speedrun_com_variable(name, reader.str()?);
+    }
+    for _ in 0..reader.len()? {
+        let name = reader.str()?;
+        metadata
+            .custom_variable_mut(name)
+            .permanent()
+            .set_value(reader.str()?);
+    }
+
+    run.set_linked_layout(match reader.u8()? {
+        LINKED_LAYOUT_NONE => None,
+        LINKED_LAYOUT_DEFAULT => Some(LinkedLayout::Default),
+        LINKED_LAYOUT_PATH => Some(LinkedLayout::Path(reader.str()?.into())),
+        _ => return Err(Error::InvalidValue),
+    });
+
+    let len = reader.len()?;
+    let mut custom_comparisons = Vec::with_capacity(len);
+    for _ in 0..len {
+        let comparison = reader.str()?;
+        if !run.custom_comparisons().iter().any(|c| c == comparison) {
+            run.custom_comparisons_mut().push(comparison.into());
+        }
+        custom_comparisons.push(comparison);
+    }
+
+    for _ in 0..reader.len()? {
+        let index = reader.i32()?;
+        let time = reader.time()?;
+        let started = reader.date_time()?;
+        let ended = reader.date_time()?;
+        let pause_time = reader.optional_time_span()?;
+        run.add_attempt_with_index(time, index, started, ended, pause_time);
+
+        let pause_count = if reader.bool()? {
+            Some(reader.u32()?)
+        } else {
+            None
+        };
+        let longest_pause = reader.optional_time_span()?;
+        let len = reader.len()?;
+        let mut actions = Vec::with_capacity(len);
+        for _ in 0..len {
+            let kind = reader.str()?;
+            let time = reader.time_span()?;
+            let value = reader.optional_time_span()?;
+            actions.push(Action {
+                kind: ActionKind::from_name(kind, value).ok_or(Error::InvalidValue)?,
+                time,
+            });
+        }
+
+        if let Some(attempt) = run.attempt_history_mut().last_mut() {
+            attempt.set_pause_count(pause_count);
+            attempt.set_longest_pause(longest_pause);
+            attempt.set_actions(actions);
+        }
+    }
+
+    for _ in 0..reader.len()? {
+        let mut segment = Segment::new(reader.str()?);
+        segment.set_icon(reader.image()?);
+
+        for comparison in &custom_comparisons {
+            segment.comparisons_mut().set(comparison, reader.time()?);
+        }
+
+        segment.set_best_segment_time(reader.time()?);
+
+        for _ in 0..reader.len()? {
+            let index = reader.i32()?;
+            segment.segment_history_mut().insert(index, reader.time()?);
+        }
+
+        for _ in 0..reader.len()? {
+            let name = reader.str()?;
+            segment
+                .variables_mut()
+                .insert(name.into(), reader.str()?.into());
+        }
+
+        run.push_segment(segment);
+    }
+
+    *run.auto_splitter_settings_mut() = reader.str()?.into();
+
+    Ok(run)
+}
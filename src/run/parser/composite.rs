@@ -1,6 +1,7 @@
 //! The composite parser can be used, if the file type of the splits file is not
 //! known, which tries to figure out which splits file format is used and parses
-//! it with the parser for that format.
+//! it with the parser for that format. Only the parsers that are enabled via
+//! their cargo features are taken into account.
 //!
 //! # Examples
 //!
@@ -26,14 +27,78 @@
 //! // Get out the Run object.
 //! let run = parsed.run;
 //! ```
+//!
+//! Splits files that are compressed with gzip, such as the ones written by the
+//! LiveSplit Saver's `save_run_compressed`, are decompressed transparently if
+//! the `gzip` feature is enabled.
+//!
+//! If it's unclear which splits file format is used, the splits file can be
+//! analyzed with all the parsers instead. This results in all the matching
+//! formats with a confidence score and a diagnostic for each parser that wasn't
+//! able to parse it.
+//!
+//! ```no_run
+//! use livesplit_core::run::parser::composite;
+//! use std::fs;
+//! use std::path::Path;
+//!
+//! let path = Path::new("path/to/splits_file");
+//! let file = fs::read(path).expect("Failed reading the file.");
+//!
+//! let analysis = composite::analyze(&file, Some(path));
+//! match analysis.best() {
+//!     Some(best) => println!(
+//!         "Splits File Format: {} ({:.0}% confident)",
+//!         best.parsed.kind,
+//!         100.0 * best.confidence,
+//!     ),
+//!     None => {
+//!         for diagnostic in &analysis.diagnostics {
+//!             println!("Not a {} splits file: {}", diagnostic.kind, diagnostic.error);
+//!         }
+//!     }
+//! }
+//! ```
 
-use super::{
-    face_split, flitter, livesplit, llanfair, llanfair_gered, portal2_live_timer, shit_split,
-    source_live_timer, speedrun_igt, splits_io, splitterino, splitterz, splitty,
-    time_split_tracker, urn, wsplit, TimerKind,
+#[cfg(feature = "parser-face-split")]
+use super::face_split;
+#[cfg(feature = "parser-flitter")]
+use super::flitter;
+#[cfg(feature = "parser-livesplit-one")]
+use super::livesplit_one;
+#[cfg(feature = "parser-llanfair")]
+use super::llanfair;
+#[cfg(feature = "parser-llanfair-gered")]
+use super::llanfair_gered;
+#[cfg(feature = "parser-portal2-live-timer")]
+use super::portal2_live_timer;
+#[cfg(feature = "parser-shit-split")]
+use super::shit_split;
+#[cfg(feature = "parser-source-live-timer")]
+use super::source_live_timer;
+#[cfg(feature = "parser-speedrun-igt")]
+use super::speedrun_igt;
+#[cfg(feature = "parser-splits-io")]
+use super::splits_io;
+#[cfg(feature = "parser-splitterino")]
+use super::splitterino;
+#[cfg(feature = "parser-splitterz")]
+use super::splitterz;
+#[cfg(feature = "parser-splitty")]
+use super::splitty;
+#[cfg(feature = "parser-time-split-tracker")]
+use super::time_split_tracker;
+#[cfg(feature = "parser-urn")]
+use super::urn;
+#[cfg(feature = "parser-wsplit")]
+use super::wsplit;
+use super::{livesplit, TimerKind};
+use crate::{
+    platform::{path::Path, prelude::*},
+    run::FixApplied,
+    Run,
 };
-use crate::{platform::path::Path, Run};
-use core::{result::Result as StdResult, str};
+use core::{fmt::Display, result::Result as StdResult, str};
 
 /// The Error type for splits files that couldn't be parsed by the Composite
 /// Parser.
@@ -42,6 +107,12 @@ use core::{result::Result as StdResult, str};
 pub enum Error {
     /// No parser was able to parse the splits file.
     NoParserParsedIt,
+    /// Failed to read the splits file.
+    #[cfg(feature = "async")]
+    Io {
+        /// The underlying error.
+        source: std::io::Error,
+    },
 }
 
 /// The Result type for the Composite Parser.
@@ -49,6 +120,7 @@ pub type Result<T> = StdResult<T, Error>;
 
 /// A run parsed by the Composite Parser. This contains the Run itself and
 /// information about which parser parsed it.
+#[derive(Debug)]
 pub struct ParsedRun<'a> {
     /// The parsed run.
     pub run: Run,
@@ -87,6 +159,20 @@ pub fn parse_and_fix<'source>(
     Ok(run)
 }
 
+/// Attempts to parse and fix a splits file, just like [`parse_and_fix`].
+/// Additionally all the changes that were made to the Run while fixing it are
+/// returned as warnings, so they can be shown to the user instead of silently
+/// modifying the splits.
+pub fn parse_and_fix_with_warnings<'source>(
+    source: &'source [u8],
+    load_files_path: Option<&Path>,
+) -> Result<(ParsedRun<'source>, Vec<FixApplied>)> {
+    let mut run = parse(source, load_files_path)?;
+    let mut warnings = Vec::new();
+    run.run.fix_splits_reporting(|fix| warnings.push(fix));
+    Ok((run, warnings))
+}
+
 /// Attempts to parse a splits file by invoking the corresponding parser for the
 /// file format detected. Additionally you can provide the path of the splits
 /// file so additional files, like external images, can be loaded. If you are
@@ -95,46 +181,104 @@ pub fn parse_and_fix<'source>(
 pub fn parse<'source>(
     source: &'source [u8],
     load_files_path: Option<&Path>,
+) -> Result<ParsedRun<'source>> {
+    #[cfg(feature = "gzip")]
+    if let Some(decompressed) = decompress(source) {
+        return Ok(parse_uncompressed(&decompressed, load_files_path)?.into_owned());
+    }
+    parse_uncompressed(source, load_files_path)
+}
+
+/// Icons that refer to image files can only be loaded if the path of the
+/// splits file is known.
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
+fn parse_livesplit(
+    source: &str,
+    load_files_path: Option<&Path>,
+) -> StdResult<Run, super::Located<livesplit::Error>> {
+    #[cfg(feature = "std")]
+    if let Some(path) = load_files_path {
+        return livesplit::parse_loading_icons(source, path);
+    }
+    livesplit::parse(source)
+}
+
+#[cfg(feature = "gzip")]
+fn decompress(source: &[u8]) -> Option<Vec<u8>> {
+    if crate::util::gzip::is_gzip(source) {
+        crate::util::gzip::decompress(source)
+    } else {
+        None
+    }
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "std",
+        feature = "parser-face-split",
+        feature = "parser-splitterz",
+        feature = "parser-time-split-tracker",
+        feature = "parser-wsplit",
+    )),
+    allow(unused_variables)
+)]
+fn parse_uncompressed<'source>(
+    source: &'source [u8],
+    load_files_path: Option<&Path>,
 ) -> Result<ParsedRun<'source>> {
     if let Ok(source) = simdutf8::basic::from_utf8(source) {
-        if let Ok(run) = livesplit::parse(source) {
+        if let Ok(run) = parse_livesplit(source, load_files_path) {
             return Ok(parsed(run, TimerKind::LiveSplit));
         }
 
+        #[cfg(feature = "parser-livesplit-one")]
+        if let Ok(run) = livesplit_one::parse(source) {
+            return Ok(parsed(run, TimerKind::LiveSplitOne));
+        }
+
+        #[cfg(feature = "parser-wsplit")]
         if let Ok(run) = wsplit::parse(source, load_files_path.is_some()) {
             return Ok(parsed(run, TimerKind::WSplit));
         }
 
+        #[cfg(feature = "parser-splitterz")]
         if let Ok(run) = splitterz::parse(source, load_files_path.is_some()) {
             return Ok(parsed(run, TimerKind::SplitterZ));
         }
 
+        #[cfg(feature = "parser-shit-split")]
         if let Ok(run) = shit_split::parse(source) {
             return Ok(parsed(run, TimerKind::ShitSplit));
         }
 
+        #[cfg(feature = "parser-splitty")]
         if let Ok(run) = splitty::parse(source) {
             return Ok(parsed(run, TimerKind::Splitty));
         }
 
+        #[cfg(feature = "parser-time-split-tracker")]
         if let Ok(run) = time_split_tracker::parse(source, load_files_path) {
             return Ok(parsed(run, TimerKind::TimeSplitTracker));
         }
 
+        #[cfg(feature = "parser-portal2-live-timer")]
         if let Ok(run) = portal2_live_timer::parse(source) {
             return Ok(parsed(run, TimerKind::Portal2LiveTimer));
         }
 
+        #[cfg(feature = "parser-face-split")]
         if let Ok(run) = face_split::parse(source, load_files_path.is_some()) {
             return Ok(parsed(run, TimerKind::FaceSplit));
         }
 
         // Should be parsed after LiveSplit's parser, as it also parses all
         // LiveSplit files with the current implementation.
+        #[cfg(feature = "parser-llanfair-gered")]
         if let Ok(run) = llanfair_gered::parse(source) {
             return Ok(parsed(run, TimerKind::LlanfairGered));
         }
 
+        #[cfg(feature = "parser-splits-io")]
         if let Ok((run, timer)) = splits_io::parse(source) {
             return Ok(parsed(run, TimerKind::Generic(timer)));
         }
@@ -142,31 +286,471 @@ pub fn parse<'source>(
         // Splitterino, SourceLiveTimer, Flitter, and SpeedRunIGT need to be
         // before Urn because of a false positive due to the nature of parsing
         // JSON files.
+        #[cfg(feature = "parser-splitterino")]
         if let Ok(run) = splitterino::parse(source) {
             return Ok(parsed(run, TimerKind::Splitterino));
         }
 
+        #[cfg(feature = "parser-flitter")]
         if let Ok(run) = flitter::parse(source) {
             return Ok(parsed(run, TimerKind::Flitter));
         }
 
+        #[cfg(feature = "parser-source-live-timer")]
         if let Ok(run) = source_live_timer::parse(source) {
             return Ok(parsed(run, TimerKind::SourceLiveTimer));
         }
 
+        #[cfg(feature = "parser-speedrun-igt")]
         if let Ok(run) = speedrun_igt::parse(source) {
             return Ok(parsed(run, TimerKind::SpeedRunIGT));
         }
 
         // Urn accepts entirely empty JSON files.
+        #[cfg(feature = "parser-urn")]
         if let Ok(run) = urn::parse(source) {
             return Ok(parsed(run, TimerKind::Urn));
         }
     }
 
+    #[cfg(feature = "parser-llanfair")]
     if let Ok(run) = llanfair::parse(source) {
         return Ok(parsed(run, TimerKind::Llanfair));
     }
 
     Err(Error::NoParserParsedIt)
 }
+
+/// Attempts to parse a splits file that is read from an asynchronous reader,
+/// just like [`parse`]. The thread isn't blocked while waiting for the reader.
+/// Only the beginning of the splits file is read to check whether it is a
+/// LiveSplit splits file. If it is and no path to load additional files from is
+/// provided, it gets parsed with [`livesplit::parse_async`] while it is being
+/// read, without a fallback to the other parsers. Every other splits file is
+/// read into memory in its entirety before the file format gets detected and
+/// the splits file gets parsed.
+#[cfg(feature = "async")]
+pub async fn parse_async<R>(
+    mut source: R,
+    load_files_path: Option<&Path>,
+) -> Result<ParsedRun<'static>>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    let io = |source| Error::Io { source };
+    let mut buf = Vec::new();
+
+    if load_files_path.is_none() {
+        let mut is_livesplit = None;
+        while is_livesplit.is_none() && buf.len() < MAX_ROOT_SEARCH_LEN {
+            if read_chunk(&mut source, &mut buf).await.map_err(io)? == 0 {
+                break;
+            }
+            is_livesplit = starts_with_livesplit_root(&buf);
+        }
+
+        if is_livesplit == Some(true) {
+            let source = Prefixed {
+                source,
+                buf,
+                pos: 0,
+            };
+            return match livesplit::parse_async(source).await {
+                Ok(run) => Ok(parsed(run, TimerKind::LiveSplit)),
+                Err(livesplit::Error::Io { source }) => Err(Error::Io { source }),
+                Err(_) => Err(Error::NoParserParsedIt),
+            };
+        }
+    }
+
+    while read_chunk(&mut source, &mut buf).await.map_err(io)? != 0 {}
+    Ok(parse(&buf, load_files_path)?.into_owned())
+}
+
+#[cfg(feature = "async")]
+const CHUNK_LEN: usize = 8 << 10;
+
+/// How much of the splits file is read at most while looking for the root
+/// element of a LiveSplit splits file.
+#[cfg(feature = "async")]
+const MAX_ROOT_SEARCH_LEN: usize = 64 << 10;
+
+/// Reads the next chunk of the reader into the buffer. Returns the amount of
+/// bytes read, which is 0 once the end of the reader is reached.
+#[cfg(feature = "async")]
+async fn read_chunk<R>(source: &mut R, buf: &mut Vec<u8>) -> std::io::Result<usize>
+where
+    R: futures_io::AsyncRead + Unpin,
+{
+    let mut chunk = [0; CHUNK_LEN];
+    loop {
+        let result =
+            core::future::poll_fn(|cx| core::pin::Pin::new(&mut *source).poll_read(cx, &mut chunk))
+                .await;
+        match result {
+            Ok(len) => {
+                buf.extend_from_slice(&chunk[..len]);
+                return Ok(len);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Checks whether the root element of the splits file is the one of a LiveSplit
+/// splits file. Llanfair Gered uses a `Run` element as well, so the root element
+/// either needs to have no attributes, like in LiveSplit 1.0, or a `version`
+/// attribute. Returns [`None`] if more of the splits file is needed to tell.
+#[cfg(feature = "async")]
+fn starts_with_livesplit_root(source: &[u8]) -> Option<bool> {
+    const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+    if BOM.starts_with(source) {
+        return None;
+    }
+    let mut source = source.strip_prefix(BOM).unwrap_or(source);
+
+    loop {
+        source = source.trim_ascii_start();
+        let end_marker: &[u8] = if source.starts_with(b"<?") {
+            b"?>"
+        } else if source.starts_with(b"<!--") {
+            b"-->"
+        } else {
+            break;
+        };
+        let end = source
+            .windows(end_marker.len())
+            .position(|window| window == end_marker)?;
+        source = &source[end + end_marker.len()..];
+    }
+
+    match source.first()? {
+        b'<' => {}
+        _ => return Some(false),
+    }
+    let tag = &source[..source.iter().position(|&b| b == b'>')?];
+
+    Some(match tag.strip_prefix(b"<Run") {
+        Some([]) => true,
+        Some([first, attributes @ ..]) if first.is_ascii_whitespace() => attributes
+            .windows(b"version=".len())
+            .any(|window| window == b"version="),
+        _ => false,
+    })
+}
+
+/// Provides the part of the splits file that was already read, before
+/// continuing with the rest of the reader.
+#[cfg(feature = "async")]
+struct Prefixed<R> {
+    source: R,
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncRead + Unpin> futures_io::AsyncRead for Prefixed<R> {
+    fn poll_read(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+        out: &mut [u8],
+    ) -> core::task::Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let remaining = &this.buf[this.pos..];
+        if remaining.is_empty() {
+            return core::pin::Pin::new(&mut this.source).poll_read(cx, out);
+        }
+        let len = remaining.len().min(out.len());
+        out[..len].copy_from_slice(&remaining[..len]);
+        this.pos += len;
+        core::task::Poll::Ready(Ok(len))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncRead + Unpin> futures_io::AsyncBufRead for Prefixed<R> {
+    fn poll_fill_buf(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<std::io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if this.pos == this.buf.len() {
+            this.buf.resize(CHUNK_LEN, 0);
+            this.pos = 0;
+            let result = core::pin::Pin::new(&mut this.source).poll_read(cx, &mut this.buf);
+            this.buf.truncate(match result {
+                core::task::Poll::Ready(Ok(len)) => len,
+                _ => 0,
+            });
+            core::task::ready!(result)?;
+        }
+        core::task::Poll::Ready(Ok(&this.buf[this.pos..]))
+    }
+
+    fn consume(self: core::pin::Pin<&mut Self>, amount: usize) {
+        self.get_mut().pos += amount;
+    }
+}
+
+/// A splits file format that matched the splits file analyzed by [`analyze`].
+#[derive(Debug)]
+pub struct Match<'a> {
+    /// The run parsed in this format and the parser that parsed it.
+    pub parsed: ParsedRun<'a>,
+    /// How confident the Composite Parser is that the splits file actually is
+    /// in this format, ranging from 0 to 1. Some formats are much less strict
+    /// than others, so they also successfully parse splits files that are in
+    /// an entirely different format.
+    pub confidence: f64,
+}
+
+/// Describes why a parser wasn't able to parse the splits file analyzed by
+/// [`analyze`].
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// The splits file format of the parser.
+    pub kind: TimerKind<'static>,
+    /// A description of the error that the parser encountered.
+    pub error: String,
+}
+
+/// The result of analyzing a splits file with all the parsers.
+#[derive(Debug, Default)]
+pub struct Analysis<'a> {
+    /// All the splits file formats that matched, ordered by their confidence,
+    /// starting with the best match.
+    pub matches: Vec<Match<'a>>,
+    /// A diagnostic for each parser that wasn't able to parse the splits file.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Analysis<'a> {
+    /// Returns the best match, if any parser was able to parse the splits file.
+    pub fn best(&self) -> Option<&Match<'a>> {
+        self.matches.first()
+    }
+
+    /// Turns the analysis into the best match, if any parser was able to parse
+    /// the splits file.
+    pub fn into_best(self) -> Result<Match<'a>> {
+        self.matches
+            .into_iter()
+            .next()
+            .ok_or(Error::NoParserParsedIt)
+    }
+
+    #[cfg(feature = "gzip")]
+    fn into_owned(self) -> Analysis<'static> {
+        Analysis {
+            matches: self
+                .matches
+                .into_iter()
+                .map(|m| Match {
+                    parsed: m.parsed.into_owned(),
+                    confidence: m.confidence,
+                })
+                .collect(),
+            diagnostics: self.diagnostics,
+        }
+    }
+
+    fn check<E: Display>(
+        &mut self,
+        kind: TimerKind<'a>,
+        confidence: f64,
+        result: StdResult<Run, E>,
+    ) {
+        match result {
+            Ok(run) => {
+                // A run without any segments is most likely the result of a
+                // lenient parser accepting a file that isn't meant for it.
+                let confidence = if run.is_empty() {
+                    0.5 * confidence
+                } else {
+                    confidence
+                };
+                self.matches.push(Match {
+                    parsed: parsed(run, kind),
+                    confidence,
+                });
+            }
+            Err(error) => self.diagnostics.push(Diagnostic {
+                kind: kind.into_owned(),
+                error: error.to_string(),
+            }),
+        }
+    }
+}
+
+/// The name used in diagnostics for the parser of the generic splits file
+/// format of Splits.io.
+#[cfg(feature = "parser-splits-io")]
+const SPLITS_IO: &str = "Splits.io Exchange Format";
+
+/// Analyzes a splits file by invoking all the parsers on it, instead of
+/// stopping at the first parser that succeeds like [`parse`] does. Each
+/// splits file format that matches gets a confidence score, so the best match
+/// can be chosen. For every parser that fails, a diagnostic is provided that
+/// describes why the splits file couldn't be parsed, so frontends can explain
+/// why a splits file wasn't recognized. Additionally you can provide the path
+/// of the splits file so additional files, like external images, can be
+/// loaded. If you are using livesplit-core in a server-like environment, set
+/// this to `None`. Only client-side applications should provide a path here.
+pub fn analyze<'source>(
+    source: &'source [u8],
+    load_files_path: Option<&Path>,
+) -> Analysis<'source> {
+    #[cfg(feature = "gzip")]
+    if let Some(decompressed) = decompress(source) {
+        return analyze_uncompressed(&decompressed, load_files_path).into_owned();
+    }
+    analyze_uncompressed(source, load_files_path)
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "std",
+        feature = "parser-face-split",
+        feature = "parser-splitterz",
+        feature = "parser-time-split-tracker",
+        feature = "parser-wsplit",
+    )),
+    allow(unused_variables)
+)]
+fn analyze_uncompressed<'source>(
+    source: &'source [u8],
+    load_files_path: Option<&Path>,
+) -> Analysis<'source> {
+    let mut analysis = Analysis::default();
+
+    // The confidence reflects how strictly each format is detected. Formats
+    // with a distinct structure or an explicit marker are trusted the most,
+    // while some of the simpler formats accept a lot of unrelated files.
+    match simdutf8::basic::from_utf8(source) {
+        Ok(source) => {
+            analysis.check(
+                TimerKind::LiveSplit,
+                1.0,
+                parse_livesplit(source, load_files_path),
+            );
+            #[cfg(feature = "parser-livesplit-one")]
+            analysis.check(TimerKind::LiveSplitOne, 1.0, livesplit_one::parse(source));
+            #[cfg(feature = "parser-wsplit")]
+            analysis.check(
+                TimerKind::WSplit,
+                0.75,
+                wsplit::parse(source, load_files_path.is_some()),
+            );
+            #[cfg(feature = "parser-splitterz")]
+            analysis.check(
+                TimerKind::SplitterZ,
+                0.75,
+                splitterz::parse(source, load_files_path.is_some()),
+            );
+            #[cfg(feature = "parser-shit-split")]
+            analysis.check(TimerKind::ShitSplit, 0.75, shit_split::parse(source));
+            #[cfg(feature = "parser-splitty")]
+            analysis.check(TimerKind::Splitty, 0.75, splitty::parse(source));
+            #[cfg(feature = "parser-time-split-tracker")]
+            analysis.check(
+                TimerKind::TimeSplitTracker,
+                0.75,
+                time_split_tracker::parse(source, load_files_path),
+            );
+            #[cfg(feature = "parser-portal2-live-timer")]
+            analysis.check(
+                TimerKind::Portal2LiveTimer,
+                0.75,
+                portal2_live_timer::parse(source),
+            );
+            #[cfg(feature = "parser-face-split")]
+            analysis.check(
+                TimerKind::FaceSplit,
+                0.75,
+                face_split::parse(source, load_files_path.is_some()),
+            );
+            // Llanfair (Gered's fork) also parses all LiveSplit files.
+            #[cfg(feature = "parser-llanfair-gered")]
+            analysis.check(TimerKind::LlanfairGered, 0.9, llanfair_gered::parse(source));
+            #[cfg(feature = "parser-splits-io")]
+            {
+                let (kind, result) = match splits_io::parse(source) {
+                    Ok((run, timer)) => (TimerKind::Generic(timer), Ok(run)),
+                    Err(error) => (TimerKind::Generic(SPLITS_IO.into()), Err(error)),
+                };
+                analysis.check(kind, 1.0, result);
+            }
+            #[cfg(feature = "parser-splitterino")]
+            analysis.check(TimerKind::Splitterino, 0.8, splitterino::parse(source));
+            #[cfg(feature = "parser-flitter")]
+            analysis.check(TimerKind::Flitter, 0.8, flitter::parse(source));
+            #[cfg(feature = "parser-source-live-timer")]
+            analysis.check(
+                TimerKind::SourceLiveTimer,
+                0.8,
+                source_live_timer::parse(source),
+            );
+            #[cfg(feature = "parser-speedrun-igt")]
+            analysis.check(TimerKind::SpeedRunIGT, 0.8, speedrun_igt::parse(source));
+            // Urn accepts entirely empty JSON files.
+            #[cfg(feature = "parser-urn")]
+            analysis.check(TimerKind::Urn, 0.5, urn::parse(source));
+        }
+        Err(error) => {
+            let error = error.to_string();
+            analysis.diagnostics.extend(
+                [
+                    TimerKind::LiveSplit,
+                    #[cfg(feature = "parser-livesplit-one")]
+                    TimerKind::LiveSplitOne,
+                    #[cfg(feature = "parser-wsplit")]
+                    TimerKind::WSplit,
+                    #[cfg(feature = "parser-splitterz")]
+                    TimerKind::SplitterZ,
+                    #[cfg(feature = "parser-shit-split")]
+                    TimerKind::ShitSplit,
+                    #[cfg(feature = "parser-splitty")]
+                    TimerKind::Splitty,
+                    #[cfg(feature = "parser-time-split-tracker")]
+                    TimerKind::TimeSplitTracker,
+                    #[cfg(feature = "parser-portal2-live-timer")]
+                    TimerKind::Portal2LiveTimer,
+                    #[cfg(feature = "parser-face-split")]
+                    TimerKind::FaceSplit,
+                    #[cfg(feature = "parser-llanfair-gered")]
+                    TimerKind::LlanfairGered,
+                    #[cfg(feature = "parser-splits-io")]
+                    TimerKind::Generic(SPLITS_IO.into()),
+                    #[cfg(feature = "parser-splitterino")]
+                    TimerKind::Splitterino,
+                    #[cfg(feature = "parser-flitter")]
+                    TimerKind::Flitter,
+                    #[cfg(feature = "parser-source-live-timer")]
+                    TimerKind::SourceLiveTimer,
+                    #[cfg(feature = "parser-speedrun-igt")]
+                    TimerKind::SpeedRunIGT,
+                    #[cfg(feature = "parser-urn")]
+                    TimerKind::Urn,
+                ]
+                .into_iter()
+                .map(|kind| Diagnostic {
+                    kind,
+                    error: error.clone(),
+                }),
+            );
+        }
+    }
+
+    #[cfg(feature = "parser-llanfair")]
+    analysis.check(TimerKind::Llanfair, 1.0, llanfair::parse(source));
+
+    // The sort is stable, so on equal confidence the order in which the parsers
+    // are tried by `parse` is kept.
+    analysis
+        .matches
+        .sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+    analysis
+}
@@ -0,0 +1,346 @@
+//! Provides the parser for simple CSV and TSV files that list the segments of a
+//! run, such as spreadsheets used for planning a route. Each row describes a
+//! single segment. Which columns contain the name of the segment and its times
+//! is configured with a [`ColumnMapping`]. Fields may be quoted with `"` in
+//! order to contain the delimiter, with `""` representing a single `"`.
+//!
+//! # Examples
+//!
+//! ```
+//! use livesplit_core::run::parser::csv::{self, ColumnMapping};
+//!
+//! let source = "Segment,Split Time,Best Segment
+//! Cap Kingdom,4:13.5,4:10.2
+//! Cascade Kingdom,9:55.1,5:35.8
+//! ";
+//!
+//! let mapping = ColumnMapping::detect(source);
+//! let run = csv::parse(source, &mapping).expect("Couldn't parse the CSV file");
+//! assert_eq!(run.len(), 2);
+//! assert_eq!(run.segment(1).name(), "Cascade Kingdom");
+//! ```
+
+use super::{located::Lines, Located};
+use crate::{platform::prelude::*, timing, Run, Segment, TimeSpan, TimingMethod};
+use alloc::borrow::Cow;
+use core::result::Result as StdResult;
+use snafu::ResultExt;
+
+/// The Error type for CSV files that couldn't be parsed by the CSV Parser.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// The file doesn't contain any segments.
+    Empty,
+    /// Expected the name of the segment, but didn't find it.
+    ExpectedSegmentName,
+    /// A quoted field is missing its closing quote.
+    UnterminatedQuote,
+    /// Failed to parse a split time.
+    ParseSplitTime {
+        /// The underlying error.
+        source: timing::ParseError,
+    },
+    /// Failed to parse a segment time.
+    ParseSegmentTime {
+        /// The underlying error.
+        source: timing::ParseError,
+    },
+    /// Failed to parse a best segment time.
+    ParseBestSegmentTime {
+        /// The underlying error.
+        source: timing::ParseError,
+    },
+}
+
+/// The Result type for the CSV Parser.
+pub type Result<T> = StdResult<T, Error>;
+
+/// Describes how the columns of a CSV file map to the information about the
+/// segments. Columns are referred to by their index, starting at 0. If neither
+/// split times nor segment times are provided, only the names of the segments
+/// are imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMapping {
+    /// The character separating the columns. If this is [`None`], a tab is
+    /// used if the first line contains one. Otherwise a comma is used.
+    pub delimiter: Option<char>,
+    /// Whether the first line is a header that doesn't describe a segment.
+    pub has_header: bool,
+    /// The column containing the name of the segment.
+    pub name: usize,
+    /// The column containing the personal best split time of the segment.
+    pub split_time: Option<usize>,
+    /// The column containing the personal best segment time of the segment.
+    /// This is only used if there is no column for the split times, in which
+    /// case the split times get calculated from the segment times.
+    pub segment_time: Option<usize>,
+    /// The column containing the best segment time of the segment.
+    pub best_segment_time: Option<usize>,
+    /// The timing method that the times are stored as.
+    pub timing_method: TimingMethod,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            delimiter: None,
+            has_header: false,
+            name: 0,
+            split_time: Some(1),
+            segment_time: None,
+            best_segment_time: None,
+            timing_method: TimingMethod::RealTime,
+        }
+    }
+}
+
+impl ColumnMapping {
+    /// Detects the column mapping based on the header of the CSV file. Columns
+    /// are recognized by common names such as `Segment`, `Split Time`,
+    /// `Segment Time` and `Best Segment`, ignoring their case. If the first
+    /// line doesn't contain any recognized names, it is assumed that there is
+    /// no header and the default mapping is used.
+    pub fn detect(source: &str) -> Self {
+        let mut mapping = Self::default();
+        let Some(header) = source.lines().next() else {
+            return mapping;
+        };
+        let delimiter = detect_delimiter(header, None);
+        mapping.delimiter = Some(delimiter);
+
+        let (mut name, mut split_time, mut segment_time, mut best_segment_time) =
+            (None, None, None, None);
+
+        for (index, field) in Fields::new(header, delimiter).enumerate() {
+            let Ok(field) = field else {
+                return mapping;
+            };
+            let field = field.trim().to_lowercase();
+            let column = match &*field {
+                "name" | "segment" | "segment name" | "split" | "split name" => &mut name,
+                "split time" | "time" | "pb" | "personal best" => &mut split_time,
+                "segment time" | "pb segment" => &mut segment_time,
+                "best segment" | "best segment time" | "best" | "gold" => &mut best_segment_time,
+                _ => continue,
+            };
+            column.get_or_insert(index);
+        }
+
+        if name.is_none() && split_time.is_none() && segment_time.is_none() {
+            return mapping;
+        }
+
+        mapping.has_header = true;
+        mapping.name = name.unwrap_or(0);
+        mapping.split_time = split_time;
+        mapping.segment_time = segment_time;
+        mapping.best_segment_time = best_segment_time;
+        mapping
+    }
+}
+
+pub(super) fn detect_delimiter(first_line: &str, delimiter: Option<char>) -> char {
+    delimiter.unwrap_or(if first_line.contains('\t') { '\t' } else { ',' })
+}
+
+/// An iterator over the fields of a single row.
+pub(super) struct Fields<'a> {
+    rest: Option<&'a str>,
+    delimiter: char,
+}
+
+impl<'a> Fields<'a> {
+    pub(super) const fn new(line: &'a str, delimiter: char) -> Self {
+        Self {
+            rest: Some(line),
+            delimiter,
+        }
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<Cow<'a, str>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+
+        let Some(quoted) = rest.strip_prefix('"') else {
+            return Some(Ok(Cow::Borrowed(match rest.split_once(self.delimiter) {
+                Some((field, rest)) => {
+                    self.rest = Some(rest);
+                    field
+                }
+                None => {
+                    self.rest = None;
+                    rest
+                }
+            })));
+        };
+
+        let mut field = String::new();
+        let mut remaining = quoted;
+        loop {
+            let Some((before, after)) = remaining.split_once('"') else {
+                self.rest = None;
+                return Some(Err(Error::UnterminatedQuote));
+            };
+            field.push_str(before);
+            if let Some(after) = after.strip_prefix('"') {
+                field.push('"');
+                remaining = after;
+                continue;
+            }
+            self.rest = after
+                .find(self.delimiter)
+                .map(|i| &after[i + self.delimiter.len_utf8()..]);
+            return Some(Ok(Cow::Owned(field)));
+        }
+    }
+}
+
+fn parse_time<F>(text: Option<&str>, context: F) -> Result<Option<TimeSpan>>
+where
+    F: snafu::IntoError<Error, Source = timing::ParseError>,
+{
+    match text.map(str::trim) {
+        Some(text) if !text.is_empty() => Ok(Some(text.parse().context(context)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Attempts to parse a CSV or TSV file that lists the segments of a run. The
+/// column mapping describes which columns contain which information. Empty
+/// lines are skipped, just like empty cells are treated as missing times.
+pub fn parse(source: &str, mapping: &ColumnMapping) -> StdResult<Run, Located<Error>> {
+    let mut lines = Lines::new(source);
+    parse_lines(&mut lines, source, mapping).map_err(|error| lines.locate(error))
+}
+
+fn parse_lines(lines: &mut Lines<'_>, source: &str, mapping: &ColumnMapping) -> Result<Run> {
+    let delimiter = detect_delimiter(source.lines().next().unwrap_or_default(), mapping.delimiter);
+
+    if mapping.has_header {
+        lines.next();
+    }
+
+    let mut run = Run::new();
+    let method = mapping.timing_method;
+    let mut total_time = Some(TimeSpan::zero());
+    let mut fields = Vec::new();
+
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        fields.clear();
+        for field in Fields::new(line, delimiter) {
+            fields.push(field?);
+        }
+        let field = |column: Option<usize>| fields.get(column?).map(|field| &**field);
+
+        let name = field(Some(mapping.name)).ok_or(Error::ExpectedSegmentName)?;
+        let mut segment = Segment::new(name.trim());
+
+        let split_time = if mapping.split_time.is_some() {
+            parse_time(field(mapping.split_time), ParseSplitTime)?
+        } else if mapping.segment_time.is_some() {
+            let segment_time = parse_time(field(mapping.segment_time), ParseSegmentTime)?;
+            total_time = catch! { total_time? + segment_time? };
+            total_time
+        } else {
+            None
+        };
+
+        let mut time = segment.personal_best_split_time();
+        time[method] = split_time;
+        segment.set_personal_best_split_time(time);
+
+        let mut time = segment.best_segment_time();
+        time[method] = parse_time(field(mapping.best_segment_time), ParseBestSegmentTime)?;
+        segment.set_best_segment_time(time);
+
+        run.push_segment(segment);
+    }
+
+    if run.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    Ok(run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detected_columns() {
+        const CSV: &str = "Notes,Split,Segment Time,Gold
+Grab the key,\"Hub, Part 1\",1:30.00,1:25.00
+,\"The \"\"Tower\"\"\",2:00.50,
+";
+
+        let mapping = ColumnMapping::detect(CSV);
+        assert_eq!(
+            mapping,
+            ColumnMapping {
+                delimiter: Some(','),
+                has_header: true,
+                name: 1,
+                split_time: None,
+                segment_time: Some(2),
+                best_segment_time: Some(3),
+                timing_method: TimingMethod::RealTime,
+            }
+        );
+
+        let run = parse(CSV, &mapping).unwrap();
+        assert_eq!(run.len(), 2);
+        assert_eq!(run.segment(0).name(), "Hub, Part 1");
+        assert_eq!(run.segment(1).name(), "The \"Tower\"");
+        assert_eq!(
+            run.segment(1).personal_best_split_time().real_time,
+            Some(TimeSpan::from_seconds(210.5))
+        );
+        assert_eq!(
+            run.segment(0).best_segment_time().real_time,
+            Some(TimeSpan::from_seconds(85.0))
+        );
+        assert_eq!(run.segment(1).best_segment_time().real_time, None);
+    }
+
+    #[test]
+    fn tab_separated_game_time() {
+        const TSV: &str = "Start\t0:10\n\nMiddle\t\nEnd\t1:00\n";
+
+        let mapping = ColumnMapping {
+            timing_method: TimingMethod::GameTime,
+            ..ColumnMapping::detect(TSV)
+        };
+        assert!(!mapping.has_header);
+
+        let run = parse(TSV, &mapping).unwrap();
+        assert_eq!(run.len(), 3);
+        assert_eq!(
+            run.segment(0).personal_best_split_time().game_time,
+            Some(TimeSpan::from_seconds(10.0))
+        );
+        assert_eq!(run.segment(0).personal_best_split_time().real_time, None);
+        assert_eq!(run.segment(1).personal_best_split_time().game_time, None);
+    }
+
+    #[test]
+    fn errors() {
+        let error = parse("A,1:00\nB,oops\n", &ColumnMapping::default()).unwrap_err();
+        assert!(matches!(error.error, Error::ParseSplitTime { .. }));
+        assert_eq!(error.position.line, 2);
+
+        let error = parse("\"A,1:00\n", &ColumnMapping::default()).unwrap_err();
+        assert!(matches!(error.error, Error::UnterminatedQuote));
+
+        let error = parse("\n\n", &ColumnMapping::default()).unwrap_err();
+        assert!(matches!(error.error, Error::Empty));
+    }
+}
@@ -1,5 +1,6 @@
 //! Provides the parser for FaceSplit splits files.
 
+use super::{located::Lines, Located};
 use crate::{timing, RealTime, Run, Segment, Time, TimeSpan};
 use alloc::borrow::Cow;
 use core::{num::ParseIntError, result::Result as StdResult};
@@ -66,11 +67,15 @@ fn replace<'text>(text: &'text str, a: &str, b: &str) -> Cow<'text, str> {
 /// loaded from the file system. If you are using livesplit-core in a
 /// server-like environment, set this to `false`. Only client-side applications
 /// should set this to `true`.
-pub fn parse(source: &str, #[allow(unused)] load_icons: bool) -> Result<Run> {
+pub fn parse(source: &str, load_icons: bool) -> StdResult<Run, Located<Error>> {
+    let mut lines = Lines::new(source);
+    parse_lines(&mut lines, load_icons).map_err(|error| lines.locate(error))
+}
+
+fn parse_lines(lines: &mut Lines<'_>, #[allow(unused)] load_icons: bool) -> Result<Run> {
     let mut run = Run::new();
     #[cfg(feature = "std")]
     let mut icon_buf = Vec::new();
-    let mut lines = source.lines();
 
     run.set_category_name(lines.next().context(ExpectedTitle)?);
 
@@ -0,0 +1,69 @@
+use crate::{platform::prelude::*, Run, TimingMethod};
+
+/// Imports the history of another run into the run, such as the history of an
+/// older backup of the same splits. The attempts of the other run are appended
+/// to the attempt history with new indices following the ones of the run,
+/// while the segment history elements that don't belong to any attempt get
+/// indices preceding the ones of the run. The segment histories are merged by
+/// matching the segments by their names. If multiple segments share the same
+/// name, they are matched in the order they appear in. Segments of the other
+/// run without a matching segment are ignored. The attempt count of the other
+/// run is added to the one of the run and faster best segment times of
+/// matching segments are taken over, so the best segments stay consistent with
+/// the segment histories.
+pub fn import_history_from(run: &mut Run, other: Run) {
+    let attempt_offset = run.max_attempt_history_index().unwrap_or(0).max(0);
+    let unattached_offset = run.min_segment_history_index().unwrap_or(1).min(1) - 1;
+    let map_index = |index: i32| {
+        if index > 0 {
+            index + attempt_offset
+        } else {
+            index + unattached_offset
+        }
+    };
+
+    run.set_attempt_count(run.attempt_count().saturating_add(other.attempt_count()));
+
+    for attempt in other.attempt_history() {
+        run.add_attempt_with_index(
+            attempt.time(),
+            map_index(attempt.index()),
+            attempt.started(),
+            attempt.ended(),
+            attempt.pause_time(),
+        );
+        if let Some(imported) = run.attempt_history_mut().last_mut() {
+            imported.set_pause_count(attempt.pause_count());
+            imported.set_longest_pause(attempt.longest_pause());
+            imported.set_actions(attempt.actions().to_vec());
+        }
+    }
+
+    let mut matched = vec![false; run.len()];
+
+    for other in other.segments() {
+        let Some(index) = run
+            .segments()
+            .iter()
+            .zip(&matched)
+            .position(|(segment, &matched)| !matched && segment.name() == other.name())
+        else {
+            continue;
+        };
+        matched[index] = true;
+        let segment = run.segment_mut(index);
+
+        for &(index, time) in other.segment_history() {
+            segment.segment_history_mut().insert(map_index(index), time);
+        }
+
+        let best_segment_time = segment.best_segment_time_mut();
+        for method in TimingMethod::all() {
+            if let Some(time) = other.best_segment_time()[method] {
+                if best_segment_time[method].is_none_or(|best| time < best) {
+                    best_segment_time[method] = Some(time);
+                }
+            }
+        }
+    }
+}
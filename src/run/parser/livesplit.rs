@@ -1,18 +1,19 @@
 //! Provides the parser for LiveSplit splits files.
 
+use super::Located;
 use crate::{
     platform::prelude::*,
-    run::{AddComparisonError, LinkedLayout},
+    run::{Action, ActionKind, AddComparisonError, LinkedLayout},
     settings::Image,
     util::{
         ascii_char::AsciiChar,
         xml::{
             helper::{
                 attribute, attribute_escaped_err, end_tag, image, optional_attribute_escaped_err,
-                parse_attributes, parse_base, parse_children, reencode_children, text,
-                text_as_escaped_string_err, text_parsed, Error as XmlError,
+                parse_attributes, parse_base, parse_children, reencode_children, reencode_element,
+                text, text_as_escaped_string_err, text_parsed, Error as XmlError,
             },
-            Reader,
+            Attributes, Reader, TagName,
         },
     },
     AtomicDateTime, DateTime, Run, RunMetadata, Segment, Time, TimeSpan,
@@ -21,6 +22,11 @@ use alloc::borrow::Cow;
 use core::{mem::MaybeUninit, str};
 use time::{Date, Duration, PrimitiveDateTime};
 
+#[cfg(feature = "async")]
+use crate::util::xml::stream::Async;
+#[cfg(feature = "std")]
+use crate::util::xml::stream::{complete, parse_element, Blocking, Chunk, ElementStream, Source};
+
 /// The Error type for splits files that couldn't be parsed by the LiveSplit
 /// Parser.
 #[derive(Debug, snafu::Snafu)]
@@ -56,6 +62,12 @@ pub enum Error {
     },
     /// Failed to parse a boolean.
     ParseBool,
+    /// Failed to read the splits file.
+    #[cfg(feature = "std")]
+    Io {
+        /// The underlying error.
+        source: std::io::Error,
+    },
 }
 
 impl From<XmlError> for Error {
@@ -64,6 +76,13 @@ impl From<XmlError> for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(source: std::io::Error) -> Self {
+        Self::Io { source }
+    }
+}
+
 impl From<core::num::ParseIntError> for Error {
     fn from(source: core::num::ParseIntError) -> Self {
         Self::ParseInt { source }
@@ -271,19 +290,94 @@ fn parse_metadata(
     }
 }
 
+/// Decodes the images stored in a splits file, unless their decoding gets
+/// deferred until they are accessed. Images that refer to an image file are
+/// loaded from the directory of the splits file, if its path is known.
+#[derive(Default)]
+struct Images {
+    buf: Vec<MaybeUninit<u8>>,
+    #[cfg(feature = "std")]
+    defer: bool,
+    #[cfg(feature = "std")]
+    splits_path: Option<std::path::PathBuf>,
+}
+
+impl Images {
+    fn parse(
+        &mut self,
+        reader: &mut Reader<'_>,
+        #[allow(unused)] attributes: Attributes<'_>,
+        f: impl FnOnce(Image),
+    ) -> Result<()> {
+        #[cfg(feature = "std")]
+        if let Some(splits_path) = &self.splits_path {
+            let mut path = None;
+            type_hint(parse_attributes(attributes, |k, v| {
+                if k == "path" {
+                    path = Some(v.unescape_cow());
+                    Ok(false)
+                } else {
+                    Ok(true)
+                }
+            }))?;
+            if let Some(path) = path {
+                let path = splits_path.with_file_name(&*path);
+                let image = Image::from_file(path, &mut Vec::new(), Image::ICON);
+                f(image.unwrap_or_default());
+                return end_tag(reader);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        if self.defer {
+            return text_as_escaped_string_err(reader, |text| {
+                f(if text.is_empty() {
+                    Image::new([].into(), Image::ICON)
+                } else {
+                    Image::new_deferred(
+                        text.as_bytes().into(),
+                        |text| {
+                            crate::util::xml::helper::decode_image(text, &mut Vec::new()).to_vec()
+                        },
+                        Image::ICON,
+                    )
+                });
+                Ok(())
+            });
+        }
+        image(reader, &mut self.buf, |i| {
+            f(Image::new(i.into(), Image::ICON))
+        })
+    }
+}
+
 fn parse_segment(
     version: Version,
     reader: &mut Reader<'_>,
-    image_buf: &mut Vec<MaybeUninit<u8>>,
+    images: &mut Images,
     run: &mut Run,
 ) -> Result<Segment> {
     let mut segment = Segment::new("");
 
-    parse_children(reader, |reader, tag, _| match tag.name() {
+    parse_children(reader, |reader, tag, attributes| {
+        parse_segment_child(version, reader, tag, attributes, &mut segment, images, run)
+    })?;
+
+    Ok(segment)
+}
+
+fn parse_segment_child(
+    version: Version,
+    reader: &mut Reader<'_>,
+    tag: TagName<'_>,
+    attributes: Attributes<'_>,
+    segment: &mut Segment,
+    images: &mut Images,
+    run: &mut Run,
+) -> Result<()> {
+    match tag.name() {
         "Name" => text(reader, |t| segment.set_name(t)),
-        "Icon" => image(reader, image_buf, |i| {
-            segment.set_icon(Image::new(i.into(), Image::ICON))
-        }),
+        "Icon" => images.parse(reader, attributes, |i| segment.set_icon(i)),
         "SplitTimes" => {
             if version >= Version(1, 3, 0, 0) {
                 parse_children(reader, |reader, tag, attributes| {
@@ -324,21 +418,28 @@ fn parse_segment(
             }
         }
         "SegmentHistory" => parse_children(reader, |reader, _, attributes| {
-            let mut index = 0;
-            type_hint(attribute_escaped_err(attributes, "id", |t| {
-                index = t.parse()?;
-                Ok(())
-            }))?;
-            if version >= Version(1, 4, 1, 0) {
-                time(reader, |t| segment.segment_history_mut().insert(index, t))
-            } else {
-                time_old(reader, |t| segment.segment_history_mut().insert(index, t))
-            }
+            parse_segment_history_element(version, reader, attributes, segment)
         }),
         _ => end_tag(reader),
-    })?;
+    }
+}
 
-    Ok(segment)
+fn parse_segment_history_element(
+    version: Version,
+    reader: &mut Reader<'_>,
+    attributes: Attributes<'_>,
+    segment: &mut Segment,
+) -> Result<()> {
+    let mut index = 0;
+    type_hint(attribute_escaped_err(attributes, "id", |t| {
+        index = t.parse()?;
+        Ok(())
+    }))?;
+    if version >= Version(1, 4, 1, 0) {
+        time(reader, |t| segment.segment_history_mut().insert(index, t))
+    } else {
+        time_old(reader, |t| segment.segment_history_mut().insert(index, t))
+    }
 }
 
 fn parse_run_history(version: Version, reader: &mut Reader<'_>, run: &mut Run) -> Result<()> {
@@ -372,132 +473,646 @@ fn parse_run_history(version: Version, reader: &mut Reader<'_>, run: &mut Run) -
 fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Run) -> Result<()> {
     if version >= Version(1, 5, 0, 0) {
         parse_children(reader, |reader, _, attributes| {
-            let mut time = Time::new();
-            let mut pause_time = None;
-            let mut index = None;
-            let (mut started, mut started_synced) = (None, false);
-            let (mut ended, mut ended_synced) = (None, false);
+            parse_attempt(version, reader, attributes, run)
+        })
+    } else {
+        end_tag(reader)
+    }
+}
+
+fn parse_attempt(
+    version: Version,
+    reader: &mut Reader<'_>,
+    attributes: Attributes<'_>,
+    run: &mut Run,
+) -> Result<()> {
+    let mut time = Time::new();
+    let mut pause_time = None;
+    let (mut pause_count, mut longest_pause) = (None, None);
+    let mut actions = Vec::new();
+    let mut index = None;
+    let (mut started, mut started_synced) = (None, false);
+    let (mut ended, mut ended_synced) = (None, false);
+
+    type_hint(parse_attributes(attributes, |k, v| {
+        match k {
+            "id" => index = Some(v.escaped().parse()?),
+            "started" => started = Some(parse_date_time(v.escaped())?),
+            "isStartedSynced" => started_synced = parse_bool(v.escaped())?,
+            "ended" => ended = Some(parse_date_time(v.escaped())?),
+            "isEndedSynced" => ended_synced = parse_bool(v.escaped())?,
+            _ => {}
+        }
+        Ok(true)
+    }))?;
 
+    let index = index.ok_or(Error::Xml {
+        source: XmlError::AttributeNotFound,
+    })?;
+
+    parse_children(reader, |reader, tag, _| match tag.name() {
+        "RealTime" => time_span_opt(reader, |t| time.real_time = t),
+        "GameTime" => time_span_opt(reader, |t| time.game_time = t),
+        "PauseTime" => time_span_opt(reader, |t| pause_time = t),
+        "PauseCount" => text_parsed(reader, |t| pause_count = Some(t)),
+        "LongestPause" => time_span_opt(reader, |t| longest_pause = t),
+        "Actions" => parse_children(reader, |reader, _, attributes| {
+            let (mut kind, mut time, mut value) = (None, None, None);
             type_hint(parse_attributes(attributes, |k, v| {
                 match k {
-                    "id" => index = Some(v.escaped().parse()?),
-                    "started" => started = Some(parse_date_time(v.escaped())?),
-                    "isStartedSynced" => started_synced = parse_bool(v.escaped())?,
-                    "ended" => ended = Some(parse_date_time(v.escaped())?),
-                    "isEndedSynced" => ended_synced = parse_bool(v.escaped())?,
+                    "type" => kind = Some(v),
+                    "time" => time = Some(parse_time_span(v.escaped())?),
+                    "value" => value = Some(parse_time_span(v.escaped())?),
                     _ => {}
                 }
                 Ok(true)
             }))?;
+            if let (Some(kind), Some(time)) = (
+                kind.and_then(|k| ActionKind::from_name(k.escaped(), value)),
+                time,
+            ) {
+                actions.push(Action { kind, time });
+            }
+            end_tag(reader)
+        }),
+        _ => end_tag(reader),
+    })?;
 
-            let index = index.ok_or(Error::Xml {
-                source: XmlError::AttributeNotFound,
-            })?;
-
-            parse_children(reader, |reader, tag, _| match tag.name() {
-                "RealTime" => time_span_opt(reader, |t| time.real_time = t),
-                "GameTime" => time_span_opt(reader, |t| time.game_time = t),
-                "PauseTime" => time_span_opt(reader, |t| pause_time = t),
-                _ => end_tag(reader),
-            })?;
-
-            let started = started.map(|t| AtomicDateTime::new(t, started_synced));
-            let ended = if version <= Version(1, 7, 0, 0)
-                && catch! { ended? < started?.time }.unwrap_or(false)
-            {
+    let started = started.map(|t| AtomicDateTime::new(t, started_synced));
+    let ended =
+        if version <= Version(1, 7, 0, 0) && catch! { ended? < started?.time }.unwrap_or(false) {
+            None
+        } else {
+            ended.map(|t| AtomicDateTime::new(t, ended_synced))
+        };
+
+    run.add_attempt_with_index(time, index, started, ended, pause_time);
+    if let Some(attempt) = run.attempt_history_mut().last_mut() {
+        attempt.set_pause_count(pause_count);
+        attempt.set_longest_pause(longest_pause);
+        attempt.set_actions(actions);
+    }
+
+    Ok(())
+}
+
+fn parse_version_attribute(attributes: Attributes<'_>) -> Result<Version> {
+    let mut version = Version(1, 0, 0, 0);
+    type_hint(optional_attribute_escaped_err(attributes, "version", |t| {
+        version = parse_version(t)?;
+        Ok(())
+    }))?;
+    Ok(version)
+}
+
+/// Keeps track of which of the elements that every splits file needs to have
+/// have been parsed so far.
+#[derive(Default)]
+struct RequiredElements(u8);
+
+impl RequiredElements {
+    const SEGMENTS: u8 = 1 << 5;
+    const ALL: u8 = (1 << 6) - 1;
+
+    fn found(&mut self, flag: u8) {
+        self.0 |= flag;
+    }
+
+    #[cfg(feature = "std")]
+    fn missing(&self) -> impl Iterator<Item = &'static str> + '_ {
+        [
+            "GameIcon",
+            "GameName",
+            "CategoryName",
+            "Offset",
+            "AttemptCount",
+            "Segments",
+        ]
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, _)| self.0 & (1 << i) == 0)
+        .map(|(_, name)| name)
+    }
+
+    const fn check(&self) -> Result<()> {
+        if self.0 != Self::ALL {
+            return Err(Error::Xml {
+                source: XmlError::ElementNotFound,
+            });
+        }
+        Ok(())
+    }
+}
+
+fn parse_run_child(
+    version: Version,
+    reader: &mut Reader<'_>,
+    tag: TagName<'_>,
+    attributes: Attributes<'_>,
+    images: &mut Images,
+    run: &mut Run,
+    required: &mut RequiredElements,
+) -> Result<()> {
+    match tag.name() {
+        "GameIcon" => {
+            required.found(1);
+            images.parse(reader, attributes, |i| run.set_game_icon(i))
+        }
+        "GameName" => {
+            required.found(1 << 1);
+            text(reader, |t| run.set_game_name(t))
+        }
+        "CategoryName" => {
+            required.found(1 << 2);
+            text(reader, |t| run.set_category_name(t))
+        }
+        "Offset" => {
+            required.found(1 << 3);
+            time_span(reader, |t| run.set_offset(t))
+        }
+        "AttemptCount" => {
+            required.found(1 << 4);
+            text_parsed(reader, |t| run.set_attempt_count(t))
+        }
+        "AttemptHistory" => parse_attempt_history(version, reader, run),
+        "RunHistory" => parse_run_history(version, reader, run),
+        "Metadata" => parse_metadata(version, reader, run.metadata_mut()),
+        "Segments" => {
+            required.found(RequiredElements::SEGMENTS);
+            parse_children(reader, |reader, tag, _| {
+                if tag.name() == "Segment" {
+                    let segment = parse_segment(version, reader, images, run)?;
+                    run.push_segment(segment);
+                    Ok(())
+                } else {
+                    end_tag(reader)
+                }
+            })
+        }
+        "AutoSplitterSettings" => {
+            let settings = run.auto_splitter_settings_mut();
+            reencode_children(reader, settings).map_err(Into::into)
+        }
+        "Layout" => {
+            let layout = run.embedded_layout_mut();
+            layout.clear();
+            reencode_element(reader, tag, attributes, layout).map_err(Into::into)
+        }
+        "LayoutPath" => text(reader, |t| {
+            run.set_linked_layout(if t == "?default" {
+                Some(LinkedLayout::Default)
+            } else if t.is_empty() {
                 None
             } else {
-                ended.map(|t| AtomicDateTime::new(t, ended_synced))
-            };
+                Some(LinkedLayout::Path(t.into_owned()))
+            });
+        }),
+        _ => end_tag(reader),
+    }
+}
 
-            run.add_attempt_with_index(time, index, started, ended, pause_time);
+/// Attempts to parse a LiveSplit splits file. If the splits file can't be
+/// parsed, the error is reported along with the position in the splits file
+/// where it occurred.
+pub fn parse(source: &str) -> core::result::Result<Run, Located<Error>> {
+    parse_with_images(source, Images::default())
+}
 
-            Ok(())
-        })
-    } else {
-        end_tag(reader)
-    }
+/// Attempts to parse a LiveSplit splits file, just like [`parse`], but without
+/// decoding the icons. Instead they are kept in their encoded form and only get
+/// decoded once they are accessed for the first time. This speeds up parsing
+/// splits files with lots of icons when the icons are never shown, such as when
+/// processing the splits on a server.
+#[cfg(feature = "std")]
+pub fn parse_deferring_icons(source: &str) -> core::result::Result<Run, Located<Error>> {
+    parse_with_images(
+        source,
+        Images {
+            defer: true,
+            ..Images::default()
+        },
+    )
 }
 
-/// Attempts to parse a LiveSplit splits file.
-pub fn parse(source: &str) -> Result<Run> {
-    let mut reader = Reader::new(source);
+/// Attempts to parse a LiveSplit splits file, just like [`parse`]. Icons that
+/// refer to an image file instead of storing the image, as written by
+/// [`save_run_with_icon_paths`](crate::run::saver::livesplit::save_run_with_icon_paths),
+/// are loaded relative to the path of the splits file provided. Icons that
+/// can't be loaded are left empty. Only client-side applications should load
+/// files like this.
+#[cfg(feature = "std")]
+pub fn parse_loading_icons(
+    source: &str,
+    splits_path: &std::path::Path,
+) -> core::result::Result<Run, Located<Error>> {
+    parse_with_images(
+        source,
+        Images {
+            splits_path: Some(splits_path.to_path_buf()),
+            ..Images::default()
+        },
+    )
+}
 
-    let mut image_buf = Vec::new();
+fn parse_with_images(
+    source: &str,
+    mut images: Images,
+) -> core::result::Result<Run, Located<Error>> {
+    let mut reader = Reader::new(source);
 
     let mut run = Run::new();
 
-    let mut required_flags = 0u8;
+    let mut required = RequiredElements::default();
 
     parse_base(&mut reader, "Run", |reader, attributes| {
-        let mut version = Version(1, 0, 0, 0);
-        type_hint(optional_attribute_escaped_err(attributes, "version", |t| {
-            version = parse_version(t)?;
-            Ok(())
-        }))?;
+        let version = parse_version_attribute(attributes)?;
 
-        parse_children(reader, |reader, tag, _| match tag.name() {
-            "GameIcon" => {
-                required_flags |= 1;
-                image(reader, &mut image_buf, |i| {
-                    run.set_game_icon(Image::new(i.into(), Image::ICON))
-                })
-            }
-            "GameName" => {
-                required_flags |= 1 << 1;
-                text(reader, |t| run.set_game_name(t))
+        parse_children(reader, |reader, tag, attributes| {
+            parse_run_child(
+                version,
+                reader,
+                tag,
+                attributes,
+                &mut images,
+                &mut run,
+                &mut required,
+            )
+        })
+    })
+    .and_then(|()| required.check())
+    .map_err(|error| Located::new(error, source, reader.remaining()))?;
+
+    Ok(run)
+}
+
+/// Attempts to parse a LiveSplit splits file while reading it incrementally.
+/// Unlike [`parse`], this doesn't require the whole file to be loaded into
+/// memory. Instead only a single element is kept in memory at a time, with the
+/// segments, their segment history and the attempt history being processed
+/// entry by entry. This significantly cuts down the memory usage for splits
+/// files with a long history.
+#[cfg(feature = "std")]
+pub fn parse_reader<R: std::io::BufRead>(source: R) -> Result<Run> {
+    complete(parse_stream(Blocking(source), None))
+}
+
+/// Attempts to parse a LiveSplit splits file while reading it incrementally
+/// from an asynchronous reader. Just like [`parse_reader`], only a single
+/// element is kept in memory at a time. Whenever the reader has to wait for
+/// more data, the parser yields instead of blocking the thread.
+#[cfg(feature = "async")]
+pub async fn parse_async<R>(source: R) -> Result<Run>
+where
+    R: futures_io::AsyncBufRead + Unpin,
+{
+    parse_stream(Async(source), None).await
+}
+
+/// A Run that got recovered from a potentially corrupted LiveSplit splits file
+/// by [`parse_lenient`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct RecoveredRun {
+    /// The Run, consisting of everything that could be recovered.
+    pub run: Run,
+    /// Everything that had to be repaired or dropped while recovering the Run.
+    /// This is empty if the splits file wasn't corrupted.
+    pub recoveries: Vec<Recovery>,
+}
+
+/// Describes how [`parse_lenient`] recovered from a corruption in a LiveSplit
+/// splits file.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum Recovery {
+    /// The splits file contained bytes that are not valid UTF-8. They got
+    /// replaced by the replacement character.
+    ReplacedInvalidUtf8,
+    /// The splits file contained `&` or `<` characters that weren't escaped,
+    /// such as in names. They got escaped.
+    EscapedCharacters {
+        /// The amount of characters that got escaped.
+        count: usize,
+    },
+    /// The splits file ended early or the rest of it couldn't be read. All the
+    /// elements before that point are kept.
+    Truncated {
+        /// The error that stopped the parser.
+        error: Error,
+    },
+    /// An element couldn't be parsed, so it got dropped.
+    DroppedElement {
+        /// The path of the element, starting from the `Run` element.
+        element: String,
+        /// The error that occurred while parsing the element.
+        error: Error,
+    },
+    /// An element that every splits file needs to have is missing, so the
+    /// default value is used instead.
+    MissingElement {
+        /// The name of the element.
+        element: &'static str,
+    },
+}
+
+/// Attempts to parse a LiveSplit splits file that may be corrupted. Instead of
+/// failing on the first error like [`parse`], this tolerates common kinds of
+/// corruption, such as truncated files, bytes that are not valid UTF-8 and
+/// characters that are not escaped. Elements that can't be parsed get dropped,
+/// while the rest of the splits file is kept. Everything that got repaired or
+/// dropped is reported alongside the recovered Run. An error is only returned
+/// if the file isn't a LiveSplit splits file at all.
+#[cfg(feature = "std")]
+pub fn parse_lenient(source: &[u8]) -> Result<RecoveredRun> {
+    let mut recoveries = Vec::new();
+
+    let source = String::from_utf8_lossy(source);
+    if let Cow::Owned(_) = source {
+        recoveries.push(Recovery::ReplacedInvalidUtf8);
+    }
+
+    let (source, count) = escape_stray_characters(&source);
+    if count != 0 {
+        recoveries.push(Recovery::EscapedCharacters { count });
+    }
+
+    let run = complete(parse_stream(
+        Blocking(source.as_bytes()),
+        Some(&mut recoveries),
+    ))?;
+
+    Ok(RecoveredRun { run, recoveries })
+}
+
+/// Escapes every `&` that doesn't start a reference and every `<` that doesn't
+/// start a tag. Comments and CDATA sections are left untouched.
+#[cfg(feature = "std")]
+fn escape_stray_characters(source: &str) -> (Cow<'_, str>, usize) {
+    let mut escaped = String::new();
+    let (mut count, mut copied, mut index) = (0, 0, 0);
+
+    while let Some(pos) = memchr::memchr2(b'&', b'<', &source.as_bytes()[index..]) {
+        let pos = index + pos;
+        let rest = &source[pos + 1..];
+        index = pos + 1;
+
+        let is_markup = source.as_bytes()[pos] == b'<';
+        let is_comment = is_markup && rest.starts_with("!--");
+
+        let replacement = if is_comment || (is_markup && rest.starts_with("![CDATA[")) {
+            let end = if is_comment { "-->" } else { "]]>" };
+            index = rest
+                .find(end)
+                .map_or(source.len(), |i| index + i + end.len());
+            continue;
+        } else if is_markup {
+            if rest.starts_with(|c: char| c.is_alphabetic() || "_:/!?".contains(c)) {
+                continue;
             }
-            "CategoryName" => {
-                required_flags |= 1 << 2;
-                text(reader, |t| run.set_category_name(t))
+            "&lt;"
+        } else {
+            if is_reference(rest) {
+                continue;
             }
-            "Offset" => {
-                required_flags |= 1 << 3;
-                time_span(reader, |t| run.set_offset(t))
+            "&amp;"
+        };
+
+        escaped.push_str(&source[copied..pos]);
+        escaped.push_str(replacement);
+        copied = index;
+        count += 1;
+    }
+
+    if count == 0 {
+        return (Cow::Borrowed(source), 0);
+    }
+    escaped.push_str(&source[copied..]);
+    (Cow::Owned(escaped), count)
+}
+
+#[cfg(feature = "std")]
+fn is_reference(rest: &str) -> bool {
+    let Some(end) = rest.bytes().take(12).position(|b| b == b';') else {
+        return false;
+    };
+    match &rest.as_bytes()[..end] {
+        [b'#', b'x', hex @ ..] => !hex.is_empty() && hex.iter().all(u8::is_ascii_hexdigit),
+        [b'#', dec @ ..] => !dec.is_empty() && dec.iter().all(u8::is_ascii_digit),
+        name => matches!(name, b"lt" | b"gt" | b"amp" | b"apos" | b"quot"),
+    }
+}
+
+#[cfg(feature = "std")]
+async fn parse_stream<S: Source>(
+    source: S,
+    mut recoveries: Option<&mut Vec<Recovery>>,
+) -> Result<Run> {
+    let mut stream = ElementStream::new(source);
+
+    let mut images = Images::default();
+
+    let mut run = Run::new();
+
+    let mut required = RequiredElements::default();
+
+    let Chunk::Open(tag) = type_hint(stream.next_child(|_| true).await)? else {
+        return Err(Error::Xml {
+            source: XmlError::ElementNotFound,
+        });
+    };
+    let version = parse_element(tag, |_, tag, attributes| {
+        if tag.name() == "Run" {
+            parse_version_attribute(attributes)
+        } else {
+            Err(Error::Xml {
+                source: XmlError::ElementNotFound,
+            })
+        }
+    })?;
+
+    let result = parse_run_children_stream(
+        version,
+        &mut stream,
+        &mut images,
+        &mut run,
+        &mut required,
+        recoveries.as_deref_mut(),
+    )
+    .await;
+
+    match recoveries {
+        Some(recoveries) => {
+            // Without any of the required elements, this is most likely not a
+            // LiveSplit splits file at all.
+            if required.0 == 0 {
+                result?;
+                return Err(Error::Xml {
+                    source: XmlError::ElementNotFound,
+                });
             }
-            "AttemptCount" => {
-                required_flags |= 1 << 4;
-                text_parsed(reader, |t| run.set_attempt_count(t))
+            if let Err(error) = result {
+                recoveries.push(Recovery::Truncated { error });
             }
-            "AttemptHistory" => parse_attempt_history(version, reader, &mut run),
-            "RunHistory" => parse_run_history(version, reader, &mut run),
-            "Metadata" => parse_metadata(version, reader, run.metadata_mut()),
-            "Segments" => {
-                required_flags |= 1 << 5;
-                parse_children(reader, |reader, tag, _| {
-                    if tag.name() == "Segment" {
-                        let segment = parse_segment(version, reader, &mut image_buf, &mut run)?;
-                        run.push_segment(segment);
-                        Ok(())
-                    } else {
-                        end_tag(reader)
-                    }
+            recoveries.extend(
+                required
+                    .missing()
+                    .map(|element| Recovery::MissingElement { element }),
+            );
+        }
+        None => {
+            result?;
+            required.check()?;
+        }
+    }
+
+    Ok(run)
+}
+
+/// Drops the element if it couldn't be parsed while recovering from
+/// corruption. Otherwise the error is passed along.
+#[cfg(feature = "std")]
+fn recover(
+    recoveries: Option<&mut Vec<Recovery>>,
+    path: &str,
+    element: &str,
+    result: Result<()>,
+) -> Result<()> {
+    match (result, recoveries) {
+        (Err(error), Some(recoveries)) => {
+            let name = type_hint(parse_element(element, |_, tag, _| {
+                Ok(tag.name().to_owned())
+            }))
+            .unwrap_or_default();
+            recoveries.push(Recovery::DroppedElement {
+                element: format!("{path}{name}"),
+                error,
+            });
+            Ok(())
+        }
+        (result, _) => result,
+    }
+}
+
+#[cfg(feature = "std")]
+async fn parse_run_children_stream<S: Source>(
+    version: Version,
+    stream: &mut ElementStream<S>,
+    images: &mut Images,
+    run: &mut Run,
+    required: &mut RequiredElements,
+    mut recoveries: Option<&mut Vec<Recovery>>,
+) -> Result<()> {
+    loop {
+        match type_hint(
+            stream
+                .next_child(|name| {
+                    name == "Segments"
+                        || (name == "AttemptHistory" && version >= Version(1, 5, 0, 0))
                 })
-            }
-            "AutoSplitterSettings" => {
-                let settings = run.auto_splitter_settings_mut();
-                reencode_children(reader, settings).map_err(Into::into)
-            }
-            "LayoutPath" => text(reader, |t| {
-                run.set_linked_layout(if t == "?default" {
-                    Some(LinkedLayout::Default)
-                } else if t.is_empty() {
-                    None
+                .await,
+        )? {
+            Chunk::Open(tag) => {
+                if type_hint(parse_element(tag, |_, tag, _| Ok(tag.name() == "Segments")))? {
+                    required.found(RequiredElements::SEGMENTS);
+                    parse_segments_stream(version, stream, images, run, recoveries.as_deref_mut())
+                        .await?;
                 } else {
-                    Some(LinkedLayout::Path(t.into_owned()))
+                    while let Some(element) = type_hint(stream.next_element().await)? {
+                        let result = parse_element(element, |reader, _, attributes| {
+                            parse_attempt(version, reader, attributes, run)
+                        });
+                        recover(
+                            recoveries.as_deref_mut(),
+                            "AttemptHistory/",
+                            element,
+                            result,
+                        )?;
+                    }
+                }
+            }
+            Chunk::Element(element) => {
+                let result = parse_element(element, |reader, tag, attributes| {
+                    parse_run_child(version, reader, tag, attributes, images, run, required)
                 });
-            }),
-            _ => end_tag(reader),
-        })
-    })?;
+                recover(recoveries.as_deref_mut(), "", element, result)?;
+            }
+            Chunk::Close => return Ok(()),
+        }
+    }
+}
 
-    if required_flags != (1 << 6) - 1 {
-        return Err(Error::Xml {
-            source: XmlError::ElementNotFound,
-        });
+#[cfg(feature = "std")]
+async fn parse_segments_stream<S: Source>(
+    version: Version,
+    stream: &mut ElementStream<S>,
+    images: &mut Images,
+    run: &mut Run,
+    mut recoveries: Option<&mut Vec<Recovery>>,
+) -> Result<()> {
+    loop {
+        match type_hint(stream.next_child(|name| name == "Segment").await)? {
+            Chunk::Open(_) => {
+                let mut segment = Segment::new("");
+                let result = parse_segment_stream(
+                    version,
+                    stream,
+                    &mut segment,
+                    images,
+                    run,
+                    recoveries.as_deref_mut(),
+                )
+                .await;
+                // Keep what we have of a segment that got cut off while
+                // recovering from corruption.
+                if result.is_ok() || recoveries.is_some() {
+                    run.push_segment(segment);
+                }
+                result?;
+            }
+            Chunk::Element(_) => {}
+            Chunk::Close => return Ok(()),
+        }
     }
+}
 
-    Ok(run)
+#[cfg(feature = "std")]
+async fn parse_segment_stream<S: Source>(
+    version: Version,
+    stream: &mut ElementStream<S>,
+    segment: &mut Segment,
+    images: &mut Images,
+    run: &mut Run,
+    mut recoveries: Option<&mut Vec<Recovery>>,
+) -> Result<()> {
+    loop {
+        match type_hint(stream.next_child(|name| name == "SegmentHistory").await)? {
+            Chunk::Open(_) => {
+                while let Some(element) = type_hint(stream.next_element().await)? {
+                    let result = parse_element(element, |reader, _, attributes| {
+                        parse_segment_history_element(version, reader, attributes, segment)
+                    });
+                    recover(
+                        recoveries.as_deref_mut(),
+                        "Segments/Segment/SegmentHistory/",
+                        element,
+                        result,
+                    )?;
+                }
+            }
+            Chunk::Element(element) => {
+                let result = parse_element(element, |reader, tag, attributes| {
+                    parse_segment_child(version, reader, tag, attributes, segment, images, run)
+                });
+                recover(
+                    recoveries.as_deref_mut(),
+                    "Segments/Segment/",
+                    element,
+                    result,
+                )?;
+            }
+            Chunk::Close => return Ok(()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -524,4 +1139,78 @@ mod tests {
         parse_time_span("NaN.23:34:56.789").unwrap_err();
         parse_time_span("Inf.23:34:56.789").unwrap_err();
     }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn parse_async_yields_while_waiting() {
+        use std::{
+            future::Future,
+            io,
+            pin::{pin, Pin},
+            sync::Arc,
+            task::{Context, Poll, Wake, Waker},
+        };
+
+        /// Provides a single byte at a time, having to wait for each one.
+        struct Trickle<'a> {
+            data: &'a [u8],
+            ready: bool,
+        }
+
+        impl futures_io::AsyncRead for Trickle<'_> {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                _: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                unreachable!()
+            }
+        }
+
+        impl futures_io::AsyncBufRead for Trickle<'_> {
+            fn poll_fill_buf(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<io::Result<&[u8]>> {
+                let this = self.get_mut();
+                if !this.ready {
+                    this.ready = true;
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                Poll::Ready(Ok(&this.data[..this.data.len().min(1)]))
+            }
+
+            fn consume(self: Pin<&mut Self>, amount: usize) {
+                let this = self.get_mut();
+                this.data = &this.data[amount..];
+                this.ready = false;
+            }
+        }
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let source = include_str!("../../../tests/run_files/livesplit1.6.lss");
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(parse_async(Trickle {
+            data: source.as_bytes(),
+            ready: false,
+        }));
+
+        let mut pending = 0;
+        let run = loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(run) => break run.unwrap(),
+                Poll::Pending => pending += 1,
+            }
+        };
+
+        assert!(pending >= source.trim_end().len());
+        assert_eq!(run, parse(source).unwrap());
+    }
 }
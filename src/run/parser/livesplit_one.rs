@@ -0,0 +1,190 @@
+//! Provides the parser for LiveSplit One's JSON splits files.
+//!
+//! The JSON splits format is an alternative to LiveSplit's XML based splits
+//! format that is easy to work with in web frontends. It stores all the
+//! information of a [`Run`] that is stored in LiveSplit splits files as well,
+//! including the attempt history, the segment history, the icons and the auto
+//! splitter settings. Unlike LiveSplit splits files, it stores all times and
+//! dates without any loss of precision, so a run survives a round trip through
+//! the [saver](crate::run::saver::livesplit_one) unchanged.
+//!
+//! A splits file is a single JSON object with the following structure:
+//!
+//! ```json
+//! {
+//!   "format": "LiveSplit One",
+//!   "version": 1,
+//!   "game_name": "Super Mario Odyssey",
+//!   "game_icon": "<base64 encoded image>",
+//!   "category_name": "Any%",
+//!   "offset": "0.000000000",
+//!   "attempt_count": 1,
+//!   "metadata": {
+//!     "run_id": "",
+//!     "platform_name": "Nintendo Switch",
+//!     "uses_emulator": false,
+//!     "region_name": "",
+//!     "speedrun_com_variables": [{ "name": "Amiibo", "value": "No" }],
+//!     "custom_variables": [{ "name": "Controller", "value": "Pro" }]
+//!   },
+//!   "linked_layout": { "Path": "layout.ls1l" },
+//!   "custom_comparisons": ["Personal Best"],
+//!   "attempt_history": [
+//!     {
+//!       "index": 1,
+//!       "time": { "real_time": "3600.500000000", "game_time": null },
+//!       "started": { "time": "2024-01-02T03:04:05.000000000Z", "synced": true },
+//!       "ended": { "time": "2024-01-02T04:04:05.500000000Z", "synced": true },
+//!       "pause_time": null,
+//!       "pause_count": 0,
+//!       "longest_pause": "0.000000000",
+//!       "actions": [{ "type": "Start", "time": "0.000000000", "value": null }]
+//!     }
+//!   ],
+//!   "segments": [
+//!     {
+//!       "name": "Cap Kingdom",
+//!       "icon": "",
+//!       "comparisons": [
+//!         { "name": "Personal Best", "time": { "real_time": "3600.500000000", "game_time": null } }
+//!       ],
+//!       "best_segment_time": { "real_time": "3600.500000000", "game_time": null },
+//!       "segment_history": [
+//!         { "index": 1, "time": { "real_time": "3600.500000000", "game_time": null } }
+//!       ],
+//!       "variables": []
+//!     }
+//!   ],
+//!   "auto_splitter_settings": ""
+//! }
+//! ```
+//!
+//! Time spans are stored as strings of the whole seconds followed by the nine
+//! digits of the nanoseconds. Dates are stored as UTC dates in the RFC 3339
+//! format with nanosecond precision. Images are stored base64 encoded and
+//! empty images are stored as empty strings. The auto splitter settings are
+//! stored as the XML they are stored as in LiveSplit splits files.
+
+use crate::{
+    run::{
+        format::livesplit_one::{SplitsFile, Variable, VERSION},
+        Action, ActionKind,
+    },
+    Run, Segment,
+};
+use core::result::Result as StdResult;
+use serde_json::Error as JsonError;
+
+/// The Error type for splits files that couldn't be parsed by the LiveSplit
+/// One Parser.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to parse JSON.
+    Json {
+        /// The underlying error.
+        #[cfg_attr(not(feature = "std"), snafu(source(false)))]
+        source: JsonError,
+    },
+    /// The splits file was created by a newer version of the format.
+    UnsupportedVersion,
+}
+
+/// The Result type for the LiveSplit One Parser.
+pub type Result<T> = StdResult<T, Error>;
+
+/// Attempts to parse a LiveSplit One JSON splits file.
+pub fn parse(source: &str) -> Result<Run> {
+    let file: SplitsFile<'_> =
+        serde_json::from_str(source).map_err(|source| Error::Json { source })?;
+
+    if file.version > VERSION {
+        return Err(Error::UnsupportedVersion);
+    }
+
+    let mut run = Run::new();
+
+    run.set_game_name(file.game_name);
+    run.set_game_icon(file.game_icon);
+    run.set_category_name(file.category_name);
+    run.set_offset(file.offset.0);
+    run.set_attempt_count(file.attempt_count);
+
+    let metadata = run.metadata_mut();
+    metadata.set_run_id(file.metadata.run_id);
+    metadata.set_platform_name(file.metadata.platform_name);
+    metadata.set_emulator_usage(file.metadata.uses_emulator);
+    metadata.set_region_name(file.metadata.region_name);
+    for Variable { name, value } in file.metadata.speedrun_com_variables {
+        metadata.set_speedrun_com_variable(name, value);
+    }
+    for Variable { name, value } in file.metadata.custom_variables {
+        metadata
+            .custom_variable_mut(name)
+            .permanent()
+            .set_value(value);
+    }
+
+    run.set_linked_layout(file.linked_layout);
+
+    for comparison in file.custom_comparisons {
+        if !run.custom_comparisons().iter().any(|c| *c == comparison) {
+            run.custom_comparisons_mut().push(comparison.into_owned());
+        }
+    }
+
+    for attempt in file.attempt_history {
+        run.add_attempt_with_index(
+            attempt.time.into(),
+            attempt.index,
+            attempt.started.map(Into::into),
+            attempt.ended.map(Into::into),
+            attempt.pause_time.map(|t| t.0),
+        );
+        if let Some(parsed) = run.attempt_history_mut().last_mut() {
+            parsed.set_pause_count(attempt.pause_count);
+            parsed.set_longest_pause(attempt.longest_pause.map(|t| t.0));
+            parsed.set_actions(
+                attempt
+                    .actions
+                    .into_iter()
+                    .filter_map(|action| {
+                        Some(Action {
+                            kind: ActionKind::from_name(&action.kind, action.value.map(|t| t.0))?,
+                            time: action.time.0,
+                        })
+                    })
+                    .collect(),
+            );
+        }
+    }
+
+    for json_segment in file.segments {
+        let mut segment = Segment::new(json_segment.name);
+        segment.set_icon(json_segment.icon);
+
+        for comparison in json_segment.comparisons {
+            segment
+                .comparisons_mut()
+                .set(&comparison.name, comparison.time.into());
+        }
+
+        segment.set_best_segment_time(json_segment.best_segment_time.into());
+
+        let history = segment.segment_history_mut();
+        for element in json_segment.segment_history {
+            history.insert(element.index, element.time.into());
+        }
+
+        let variables = segment.variables_mut();
+        for Variable { name, value } in json_segment.variables {
+            variables.insert(name.into_owned(), value.into_owned());
+        }
+
+        run.push_segment(segment);
+    }
+
+    *run.auto_splitter_settings_mut() = file.auto_splitter_settings.into_owned();
+
+    Ok(run)
+}
@@ -1,13 +1,42 @@
 //! Provides the parser for splits files used by Gered's Llanfair fork.
+//!
+//! Besides the splits, these files store the appearance settings of Llanfair.
+//! They can optionally be imported as a [`Layout`] with
+//! [`parse_with_layout`]. The following settings are taken over, while all
+//! other settings are ignored:
+//!
+//! | Llanfair Setting     | Layout Setting                                   |
+//! |----------------------|--------------------------------------------------|
+//! | `colorBackground`    | Background                                       |
+//! | `colorForeground`    | Text Color                                       |
+//! | `colorTimer`         | Color of the Timer                               |
+//! | `colorNegative`      | Ahead (Gaining / Losing Time) Colors             |
+//! | `colorPositive`      | Behind (Gaining / Losing Time) Colors            |
+//! | `colorNewRec`        | Best Segment Color                               |
+//! | `colorTitle`         | Text Color of the Title                          |
+//! | `colorHighlight`     | Current Split Gradient of the Splits             |
+//! | `colorSeparators`    | Separators Color                                 |
+//! | `timerFont`          | Timer Font                                       |
+//! | `historyTimeFont`    | Times Font                                       |
+//! | `historySegmentFont` | Text Font                                        |
+//! | `historyRowCount`    | Visual Split Count of the Splits                 |
+//! | `headerShowTitle`    | Whether the Title shows the Game Name            |
+//! | `headerShowSubtitle` | Whether the Title shows the Category Name        |
+//! | `headerShowAttempts` | Whether the Title shows the Attempt Count        |
 
 use core::mem::MaybeUninit;
 
+use super::Located;
+
 #[cfg(feature = "std")]
 use crate::util::byte_parsing::big_endian::strip_u32;
 #[cfg(feature = "std")]
 use crate::util::xml::helper::text_as_str_err;
 use crate::{
+    component::{previous_segment, splits, timer, title},
+    layout::{GeneralSettings, Layout},
     platform::prelude::*,
+    settings::{Color, Font, FontStyle, FontWeight, Gradient, LayoutBackground},
     util::xml::{
         helper::{
             end_tag, optional_attribute_escaped_err, parse_base, parse_children, single_child,
@@ -133,6 +162,177 @@ where
     })
 }
 
+/// The appearance settings of Llanfair, collected into the settings of the
+/// components of the Layout that matches them most closely.
+#[derive(Default)]
+struct Appearance {
+    general: GeneralSettings,
+    title: title::Settings,
+    splits: splits::Settings,
+    timer: timer::Settings,
+}
+
+impl Appearance {
+    fn into_layout(self) -> Layout {
+        let mut layout = Layout::new();
+        *layout.general_settings_mut() = self.general;
+        layout.push(title::Component::with_settings(self.title));
+        layout.push(splits::Component::with_settings(self.splits));
+        layout.push(timer::Component::with_settings(self.timer));
+        layout.push(previous_segment::Component::new());
+        layout
+    }
+}
+
+/// A value of Llanfair's configuration. Values that are malformed or of an
+/// unknown type are ignored, so they never prevent the splits from being
+/// imported.
+enum Value {
+    Color(Color),
+    Font(Font),
+    Bool(bool),
+    Int(i64),
+    Unknown,
+}
+
+fn parse_color(reader: &mut Reader<'_>) -> Result<Value> {
+    let [mut red, mut green, mut blue, mut alpha] = [None, None, None, Some(255)];
+    type_hint(parse_children(reader, |reader, tag, _| {
+        let channel = match tag.name() {
+            "red" => &mut red,
+            "green" => &mut green,
+            "blue" => &mut blue,
+            "alpha" => &mut alpha,
+            _ => return end_tag(reader),
+        };
+        text(reader, |t| *channel = t.parse::<u8>().ok())
+    }))?;
+
+    Ok(match (red, green, blue, alpha) {
+        (Some(red), Some(green), Some(blue), Some(alpha)) => {
+            Value::Color(Color::rgba8(red, green, blue, alpha))
+        }
+        _ => Value::Unknown,
+    })
+}
+
+fn parse_font(reader: &mut Reader<'_>) -> Result<Value> {
+    let mut font = Font::default();
+    type_hint(parse_children(reader, |reader, tag, _| match tag.name() {
+        "family" => text(reader, |t| font.family = t.into_owned()),
+        "name" => text(reader, |t| {
+            if font.family.is_empty() {
+                font.family = t.into_owned();
+            }
+        }),
+        // Java stores the style as a bit set of bold (1) and italic (2).
+        "style" => text(reader, |t| {
+            let style = t.parse::<u8>().unwrap_or_default();
+            if style & 1 != 0 {
+                font.weight = FontWeight::Bold;
+            }
+            if style & 2 != 0 {
+                font.style = FontStyle::Italic;
+            }
+        }),
+        _ => end_tag(reader),
+    }))?;
+
+    Ok(if font.family.is_empty() {
+        Value::Unknown
+    } else {
+        Value::Font(font)
+    })
+}
+
+fn parse_value(reader: &mut Reader<'_>, tag: &str) -> Result<Value> {
+    let mut value = Value::Unknown;
+    type_hint(match tag {
+        "java.awt.Color" => return parse_color(reader),
+        "java.awt.Font" => return parse_font(reader),
+        "boolean" => text(reader, |t| {
+            if let Ok(v) = t.parse() {
+                value = Value::Bool(v);
+            }
+        }),
+        "int" => text(reader, |t| {
+            if let Ok(v) = t.parse() {
+                value = Value::Int(v);
+            }
+        }),
+        _ => end_tag(reader),
+    })?;
+    Ok(value)
+}
+
+fn apply_setting(appearance: &mut Appearance, key: &str, value: Value) {
+    let general = &mut appearance.general;
+    match (key, value) {
+        ("colorBackground", Value::Color(c)) => {
+            general.background = LayoutBackground::Gradient(Gradient::Plain(c));
+        }
+        ("colorForeground", Value::Color(c)) => general.text_color = c,
+        ("colorTimer", Value::Color(c)) => appearance.timer.color_override = Some(c),
+        ("colorNegative", Value::Color(c)) => {
+            general.ahead_gaining_time_color = c;
+            general.ahead_losing_time_color = c;
+        }
+        ("colorPositive", Value::Color(c)) => {
+            general.behind_gaining_time_color = c;
+            general.behind_losing_time_color = c;
+        }
+        ("colorNewRec", Value::Color(c)) => general.best_segment_color = c,
+        ("colorTitle", Value::Color(c)) => appearance.title.text_color = Some(c),
+        ("colorHighlight", Value::Color(c)) => {
+            appearance.splits.current_split_gradient = Gradient::Plain(c);
+        }
+        ("colorSeparators", Value::Color(c)) => general.separators_color = c,
+        ("timerFont", Value::Font(f)) => general.timer_font = Some(f),
+        ("historyTimeFont", Value::Font(f)) => general.times_font = Some(f),
+        ("historySegmentFont", Value::Font(f)) => general.text_font = Some(f),
+        ("historyRowCount", Value::Int(count)) => {
+            if let Ok(count) = count.try_into() {
+                appearance.splits.visual_split_count = count;
+            }
+        }
+        ("headerShowTitle", Value::Bool(b)) => appearance.title.show_game_name = b,
+        ("headerShowSubtitle", Value::Bool(b)) => appearance.title.show_category_name = b,
+        ("headerShowAttempts", Value::Bool(b)) => appearance.title.show_attempt_count = b,
+        _ => {}
+    }
+}
+
+fn parse_configuration(reader: &mut Reader<'_>, appearance: &mut Appearance) -> Result<()> {
+    let mut key = String::new();
+    single_child(reader, "Config", |reader, _| {
+        single_child(reader, "default", |reader, _| {
+            parse_children(reader, |reader, tag, _| {
+                if tag.name() != "values" {
+                    return end_tag(reader);
+                }
+                parse_children(reader, |reader, _, _| {
+                    parse_children(reader, |reader, tag, _| {
+                        if tag.name() != "map" {
+                            return end_tag(reader);
+                        }
+                        parse_children(reader, |reader, _, _| {
+                            key.clear();
+                            parse_children(reader, |reader, tag, _| match tag.name() {
+                                "string" if key.is_empty() => text(reader, |t| key.push_str(&t)),
+                                name => {
+                                    let value = parse_value(reader, name)?;
+                                    apply_setting(appearance, &key, value);
+                                    Ok(())
+                                }
+                            })
+                        })
+                    })
+                })
+            })
+        })
+    })
+}
+
 fn parse_segment(
     total_time: &mut TimeSpan,
     reader: &mut Reader<'_>,
@@ -195,7 +395,24 @@ fn parse_segment(
 }
 
 /// Attempts to parse a splits file used by Gered's Llanfair fork.
-pub fn parse(source: &str) -> Result<Run> {
+pub fn parse(source: &str) -> core::result::Result<Run, Located<Error>> {
+    parse_run(source, None)
+}
+
+/// Attempts to parse a splits file used by Gered's Llanfair fork, along with a
+/// [`Layout`] that resembles the appearance settings stored in it. The layout
+/// consists of a Title, Splits, Timer and Previous Segment component. Settings
+/// that are missing from the file keep their default values.
+pub fn parse_with_layout(source: &str) -> core::result::Result<(Run, Layout), Located<Error>> {
+    let mut appearance = Appearance::default();
+    let run = parse_run(source, Some(&mut appearance))?;
+    Ok((run, appearance.into_layout()))
+}
+
+fn parse_run(
+    source: &str,
+    mut appearance: Option<&mut Appearance>,
+) -> core::result::Result<Run, Located<Error>> {
     let reader = &mut Reader::new(source);
 
     let mut raw_buf = Vec::new();
@@ -220,11 +437,16 @@ pub fn parse(source: &str) -> Result<Run> {
                             Ok(())
                         })
                     }
+                    "configuration" => match &mut appearance {
+                        Some(appearance) => parse_configuration(reader, appearance),
+                        None => end_tag(reader),
+                    },
                     _ => end_tag(reader),
                 })
             })
         })
-    })?;
+    })
+    .map_err(|error| Located::new(error, source, reader.remaining()))?;
 
     Ok(run)
 }
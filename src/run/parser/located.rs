@@ -0,0 +1,182 @@
+use core::fmt;
+
+/// A position within a splits file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// The offset in bytes from the start of the splits file.
+    pub byte_offset: usize,
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column within the line, counted in characters and starting at 1.
+    pub column: usize,
+}
+
+impl Position {
+    /// Determines the position of the start of `rest` within `source`. The
+    /// `rest` needs to be a slice of `source`. Otherwise the position is
+    /// clamped to the bounds of `source`.
+    pub(super) fn of(source: &str, rest: &str) -> Self {
+        let byte_offset = (rest.as_ptr() as usize)
+            .saturating_sub(source.as_ptr() as usize)
+            .min(source.len());
+        let before = &source.as_bytes()[..byte_offset];
+        let line_start = memchr::memrchr(b'\n', before).map_or(0, |i| i + 1);
+        let line = memchr::memchr_iter(b'\n', before).count() + 1;
+        let column = source
+            .get(line_start..byte_offset)
+            .map_or(0, |line| line.chars().count())
+            + 1;
+
+        Self {
+            byte_offset,
+            line,
+            column,
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// An error of a parser along with the position in the splits file where it
+/// occurred, so it can be shown to the user, for example in an editor.
+#[derive(Debug)]
+pub struct Located<E> {
+    /// The error that occurred.
+    pub error: E,
+    /// The position in the splits file where the error occurred.
+    pub position: Position,
+}
+
+impl<E> Located<E> {
+    /// Determines the position of the start of `rest` within `source` and
+    /// associates it with the error.
+    pub(super) fn new(error: E, source: &str, rest: &str) -> Self {
+        Self {
+            error,
+            position: Position::of(source, rest),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Located<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.error, self.position)
+    }
+}
+
+impl<E: snafu::Error + 'static> snafu::Error for Located<E> {
+    fn source(&self) -> Option<&(dyn snafu::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(any(
+    feature = "parser-csv",
+    feature = "parser-face-split",
+    feature = "parser-portal2-live-timer",
+    feature = "parser-shit-split",
+    feature = "parser-splitterz",
+    feature = "parser-time-split-tracker",
+    feature = "parser-wsplit",
+))]
+/// An iterator over the lines of a text based splits file that keeps track of
+/// the current line, so errors can be associated with it.
+pub(super) struct Lines<'a> {
+    source: &'a str,
+    lines: core::str::Lines<'a>,
+    current: &'a str,
+}
+
+#[cfg(any(
+    feature = "parser-csv",
+    feature = "parser-face-split",
+    feature = "parser-portal2-live-timer",
+    feature = "parser-shit-split",
+    feature = "parser-splitterz",
+    feature = "parser-time-split-tracker",
+    feature = "parser-wsplit",
+))]
+impl<'a> Lines<'a> {
+    pub(super) fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            lines: source.lines(),
+            current: &source[..0],
+        }
+    }
+
+    /// Associates the error with the line that was returned last.
+    pub(super) fn locate<E>(&self, error: E) -> Located<E> {
+        Located::new(error, self.source, self.current)
+    }
+}
+
+#[cfg(any(
+    feature = "parser-csv",
+    feature = "parser-face-split",
+    feature = "parser-portal2-live-timer",
+    feature = "parser-shit-split",
+    feature = "parser-splitterz",
+    feature = "parser-time-split-tracker",
+    feature = "parser-wsplit",
+))]
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let line = self.lines.next()?;
+        self.current = line;
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of() {
+        let source = "first\nsecönd\nthird";
+        let position = Position::of(source, &source[source.find("nd").unwrap()..]);
+        assert_eq!(
+            position,
+            Position {
+                byte_offset: 11,
+                line: 2,
+                column: 5,
+            },
+        );
+        assert_eq!(position.to_string(), "line 2, column 5");
+
+        let position = Position::of(source, &source[..0]);
+        assert_eq!((position.line, position.column), (1, 1));
+
+        let position = Position::of(source, &source[source.len()..]);
+        assert_eq!((position.line, position.column), (3, 6));
+    }
+
+    #[cfg(any(
+        feature = "parser-csv",
+        feature = "parser-face-split",
+        feature = "parser-portal2-live-timer",
+        feature = "parser-shit-split",
+        feature = "parser-splitterz",
+        feature = "parser-time-split-tracker",
+        feature = "parser-wsplit",
+    ))]
+    #[test]
+    fn lines_locate_the_current_line() {
+        let mut lines = Lines::new("a\nb\nc");
+        assert_eq!(lines.locate(()).position.line, 1);
+        lines.next();
+        lines.next();
+        assert_eq!(lines.locate(()).position.line, 2);
+        lines.next();
+        lines.next();
+        assert_eq!(lines.locate(()).position.line, 3);
+    }
+}
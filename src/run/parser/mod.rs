@@ -27,27 +27,65 @@
 //! // Get out the Run object.
 //! let run = parsed.run;
 //! ```
+//!
+//! Every parser other than the one for LiveSplit's own splits files is behind
+//! a cargo feature of the form `parser-<format>`, such as `parser-urn`. All of
+//! them are enabled by the `parsers` feature, which is part of the default
+//! features. The composite parser only tries the parsers that are enabled.
 
+#[cfg(feature = "parser-attempt-history-csv")]
+pub mod attempt_history_csv;
+#[cfg(feature = "parser-binary")]
+pub mod binary;
 pub mod composite;
+#[cfg(feature = "parser-csv")]
+pub mod csv;
+#[cfg(feature = "parser-face-split")]
 pub mod face_split;
+#[cfg(feature = "parser-flitter")]
 pub mod flitter;
 pub mod livesplit;
+#[cfg(feature = "parser-livesplit-one")]
+pub mod livesplit_one;
+#[cfg(feature = "parser-llanfair")]
 pub mod llanfair;
+#[cfg(feature = "parser-llanfair-gered")]
 pub mod llanfair_gered;
+#[cfg(feature = "parser-portal2-live-timer")]
 pub mod portal2_live_timer;
+#[cfg(feature = "parser-shit-split")]
 pub mod shit_split;
+#[cfg(feature = "parser-source-live-timer")]
 pub mod source_live_timer;
+#[cfg(feature = "parser-speedrun-com")]
+pub mod speedrun_com;
+#[cfg(feature = "parser-speedrun-igt")]
 pub mod speedrun_igt;
+#[cfg(feature = "parser-splits-io")]
 pub mod splits_io;
+#[cfg(feature = "parser-splitterino")]
 pub mod splitterino;
+#[cfg(feature = "parser-splitterz")]
 pub mod splitterz;
+#[cfg(feature = "parser-splitty")]
 pub mod splitty;
+#[cfg(feature = "parser-time-split-tracker")]
 pub mod time_split_tracker;
+#[cfg(feature = "parser-urn")]
 pub mod urn;
+#[cfg(feature = "parser-wsplit")]
 pub mod wsplit;
+#[cfg(feature = "zip-import")]
+pub mod zip;
 
+mod import_history;
+mod located;
 mod timer_kind;
 
-pub use self::timer_kind::TimerKind;
+pub use self::{
+    import_history::import_history_from,
+    located::{Located, Position},
+    timer_kind::TimerKind,
+};
 
-pub use composite::{parse, parse_and_fix};
+pub use composite::{parse, parse_and_fix, parse_and_fix_with_warnings};
@@ -1,5 +1,6 @@
 //! Provides the parser for Portal 2 Live Timer splits files.
 
+use super::{located::Lines, Located};
 use crate::{platform::prelude::*, GameTime, Run, Segment, TimeSpan};
 use core::{num::ParseFloatError, result::Result as StdResult};
 use snafu::{OptionExt, ResultExt};
@@ -151,13 +152,17 @@ static CHAPTERS: [(&str, &[&str]); 9] = [
 ];
 
 /// Attempts to parse a Portal 2 Live Timer splits file.
-pub fn parse(source: &str) -> Result<Run> {
+pub fn parse(source: &str) -> StdResult<Run, Located<Error>> {
+    let mut lines = Lines::new(source);
+    parse_lines(&mut lines).map_err(|error| lines.locate(error))
+}
+
+fn parse_lines(lines: &mut Lines<'_>) -> Result<Run> {
     let mut run = Run::new();
 
     run.set_game_name("Portal 2");
     run.set_category_name("Any%");
 
-    let mut lines = source.lines();
     lines.next(); // Skip the header
 
     let mut aggregate_ticks = 0.0;
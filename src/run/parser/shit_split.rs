@@ -1,5 +1,6 @@
 //! Provides the parser for ShitSplit splits files.
 
+use super::{located::Lines, Located};
 use crate::{timing, GameTime, Run, Segment, TimeSpan};
 use core::{num::ParseIntError, result::Result as StdResult};
 use snafu::{OptionExt, ResultExt};
@@ -35,9 +36,12 @@ pub enum Error {
 pub type Result<T> = StdResult<T, Error>;
 
 /// Attempts to parse a ShitSplit splits file.
-pub fn parse(source: &str) -> Result<Run> {
-    let mut lines = source.lines();
+pub fn parse(source: &str) -> StdResult<Run, Located<Error>> {
+    let mut lines = Lines::new(source);
+    parse_lines(&mut lines).map_err(|error| lines.locate(error))
+}
 
+fn parse_lines(lines: &mut Lines<'_>) -> Result<Run> {
     let line = lines.next().context(Empty)?;
 
     let mut splits = line.split('|');
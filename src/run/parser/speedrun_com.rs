@@ -0,0 +1,227 @@
+//! Provides the parser for runs retrieved from the
+//! [speedrun.com API](https://github.com/speedruncomorg/api). This allows
+//! runners to bootstrap their splits from a run on the leaderboards.
+//!
+//! The parser expects the JSON payload of the `runs/{id}` endpoint. The game,
+//! the category, the platform and the region only get imported if they are
+//! embedded into the payload. So the run should be requested with
+//! `?embed=game,category.variables,platform,region`. The speedrun.com API does
+//! not provide any split times. Instead runs may link to their splits on
+//! Splits.io, whose ID is provided alongside the parsed run. Without splits,
+//! the run consists of a single segment storing the final time of the run.
+
+use crate::{platform::prelude::*, Run, Segment, Time, TimeSpan};
+use alloc::collections::BTreeMap;
+use core::result::Result as StdResult;
+use serde::de::IgnoredAny;
+use serde_derive::Deserialize;
+use serde_json::Error as JsonError;
+
+/// The Error type for runs that couldn't be parsed by the speedrun.com Parser.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// Failed to parse JSON.
+    Json {
+        /// The underlying error.
+        #[cfg_attr(not(feature = "std"), snafu(source(false)))]
+        source: JsonError,
+    },
+}
+
+/// The Result type for the speedrun.com Parser.
+pub type Result<T> = StdResult<T, Error>;
+
+/// A run that got imported from speedrun.com.
+#[derive(Debug, Clone)]
+pub struct ImportedRun {
+    /// The imported run.
+    pub run: Run,
+    /// The ID of the run's splits on Splits.io, if the run links to them.
+    /// These can be downloaded to get the actual split times of the run.
+    pub splits_io_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Payload {
+    Wrapped { data: ApiRun },
+    Bare(ApiRun),
+}
+
+#[derive(Deserialize)]
+struct ApiRun {
+    id: String,
+    game: Option<Embedded<Game>>,
+    category: Option<Embedded<Category>>,
+    times: Times,
+    #[serde(default)]
+    values: BTreeMap<String, String>,
+    system: Option<System>,
+    platform: Option<Embedded<Named>>,
+    region: Option<Embedded<Named>>,
+    splits: Option<Splits>,
+}
+
+/// Resources are either referred to by their ID or embedded into the run.
+/// Resources that are missing are embedded as empty arrays.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Embedded<T> {
+    Data { data: T },
+    NotEmbedded(IgnoredAny),
+}
+
+impl<T> Embedded<T> {
+    pub(crate) fn data(self) -> Option<T> {
+        match self {
+            Embedded::Data { data } => Some(data),
+            Embedded::NotEmbedded(_) => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Game {
+    names: Names,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Names {
+    pub(crate) international: String,
+}
+
+#[derive(Deserialize)]
+struct Category {
+    name: String,
+    variables: Option<Embedded<Vec<Variable>>>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Variable {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) values: VariableValues,
+    #[cfg_attr(not(feature = "networking"), allow(dead_code))]
+    pub(crate) scope: Option<Scope>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(not(feature = "networking"), allow(dead_code))]
+pub(crate) struct Scope {
+    #[serde(rename = "type")]
+    pub(crate) kind: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct VariableValues {
+    pub(crate) values: BTreeMap<String, VariableValue>,
+    #[cfg_attr(not(feature = "networking"), allow(dead_code))]
+    pub(crate) default: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct VariableValue {
+    pub(crate) label: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Named {
+    pub(crate) name: String,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub(crate) struct Times {
+    realtime_t: f64,
+    realtime_noloads_t: f64,
+    ingame_t: f64,
+}
+
+impl Times {
+    /// The final time of the run. Runs without in-game time use the real time
+    /// without loads as their game time.
+    pub(crate) fn final_time(&self) -> Time {
+        Time::new()
+            .with_real_time(time(self.realtime_t))
+            .with_game_time(time(self.ingame_t).or(time(self.realtime_noloads_t)))
+    }
+}
+
+#[derive(Deserialize)]
+struct System {
+    #[serde(default)]
+    emulated: bool,
+}
+
+#[derive(Deserialize)]
+struct Splits {
+    rel: String,
+    uri: String,
+}
+
+fn time(seconds: f64) -> Option<TimeSpan> {
+    if seconds > 0.0 {
+        Some(TimeSpan::from_seconds(seconds))
+    } else {
+        None
+    }
+}
+
+/// Attempts to parse a run retrieved from the speedrun.com API.
+pub fn parse(source: &str) -> Result<ImportedRun> {
+    let (Payload::Wrapped { data: api_run } | Payload::Bare(api_run)) =
+        serde_json::from_str(source).map_err(|source| Error::Json { source })?;
+
+    let mut run = Run::new();
+
+    if let Some(game) = api_run.game.and_then(Embedded::data) {
+        run.set_game_name(game.names.international);
+    }
+
+    let metadata = run.metadata_mut();
+    metadata.set_run_id(api_run.id);
+
+    if let Some(system) = api_run.system {
+        metadata.set_emulator_usage(system.emulated);
+    }
+    if let Some(platform) = api_run.platform.and_then(Embedded::data) {
+        metadata.set_platform_name(platform.name);
+    }
+    if let Some(region) = api_run.region.and_then(Embedded::data) {
+        metadata.set_region_name(region.name);
+    }
+
+    if let Some(category) = api_run.category.and_then(Embedded::data) {
+        if let Some(variables) = category.variables.and_then(Embedded::data) {
+            for (variable_id, value_id) in &api_run.values {
+                if let Some((variable, value)) = variables
+                    .iter()
+                    .find(|v| v.id == *variable_id)
+                    .and_then(|v| Some((v, v.values.values.get(value_id)?)))
+                {
+                    metadata
+                        .set_speedrun_com_variable(variable.name.as_str(), value.label.as_str());
+                }
+            }
+        }
+        run.set_category_name(category.name);
+    }
+
+    let final_time = api_run.times.final_time();
+
+    let mut segment = Segment::new("Finish");
+    segment.set_personal_best_split_time(final_time);
+    segment.set_best_segment_time(final_time);
+    run.push_segment(segment);
+
+    let splits_io_id = api_run
+        .splits
+        .filter(|splits| splits.rel == "splits.io")
+        .and_then(|splits| {
+            let id = splits.uri.trim_end_matches('/').rsplit('/').next()?;
+            Some(id.to_owned()).filter(|id| !id.is_empty())
+        });
+
+    Ok(ImportedRun { run, splits_io_id })
+}
@@ -6,7 +6,7 @@ use time::Duration;
 
 use crate::{
     platform::{prelude::*, DateTime},
-    AtomicDateTime, Run, Segment, Time,
+    AtomicDateTime, Run, Segment, Time, TimingMethod,
 };
 
 /// The Error type for splits files that couldn't be parsed by the SpeedRunIGT
@@ -42,6 +42,7 @@ struct Splits<'a> {
     is_hardcore: bool,
     #[serde(borrow)]
     world_name: Cow<'a, str>,
+    is_completed: Option<bool>,
     date: i64,
     final_igt: i64,
     final_rta: i64,
@@ -434,48 +435,7 @@ pub fn parse(source: &str) -> Result<Run> {
     world_name.is_permanent = true;
 
     for timeline in splits.timelines {
-        let name: Cow<'_, str> = match &*timeline.name {
-            "crafted_ender_eye" => "Crafted Ender Eye".into(),
-            "enter_bastion" => "Found Bastion".into(),
-            "enter_end" => "Enter The End".into(),
-            "enter_fortress" => "Found Fortress".into(),
-            "enter_nether" => "Enter Nether".into(),
-            "enter_stronghold" => "Enter Stronghold".into(),
-            "found_villager" => "Found Villager".into(),
-            "got_trident" => "Got Trident".into(),
-            "kill_elder_guardian" => "Defeat Elder Guardian".into(),
-            "kill_ender_dragon" => "Defeat Ender Dragon".into(),
-            "kill_warden" => "Defeat Warden".into(),
-            "kill_wither" => "Defeat Wither".into(),
-            "nether_travel" => "Nether Travel".into(),
-            "pick_gold_block" => "Pick Gold Block".into(),
-            "pickup_book" => "Pickup Book".into(),
-            "sleep_on_tower" => "Sleep on Tower".into(),
-            "trade_with_villager" => "Trade with Villager".into(),
-            name => {
-                if let Some(rem) = name.strip_prefix("portal_no_") {
-                    format!("Portal No. {rem}").into()
-                } else if let Some(rem) = name.strip_prefix("got_shell_") {
-                    format!("Got Nautilus Shell {rem}").into()
-                } else {
-                    let mut new_name = String::with_capacity(name.len());
-                    let mut last_is_space = true;
-                    for c in name.chars() {
-                        if c == '_' {
-                            new_name.push(' ');
-                            last_is_space = true;
-                        } else if last_is_space {
-                            new_name.extend(c.to_uppercase());
-                            last_is_space = false;
-                        } else {
-                            new_name.push(c);
-                        }
-                    }
-                    new_name.into()
-                }
-            }
-        };
-        let mut segment = Segment::new(name);
+        let mut segment = Segment::new(segment_name(&timeline.name));
         segment.set_personal_best_split_time(time(timeline.rta, timeline.igt));
         run.push_segment(segment);
     }
@@ -489,6 +449,125 @@ pub fn parse(source: &str) -> Result<Run> {
     Ok(run)
 }
 
+/// Imports a SpeedRunIGT record as an attempt into an existing run, such as a
+/// run parsed from another record of the same category. This allows
+/// backfilling the history of a run from all the records that SpeedRunIGT
+/// logged. The events of the record's timeline are matched to the segments by
+/// their names, with the final time of the record belonging to the last
+/// segment. Segments without a matching event are treated as skipped. Records
+/// of runs that weren't completed are imported as reset attempts. The best
+/// segments are updated if the attempt improved upon them, while the personal
+/// best is left unchanged.
+pub fn import_attempt(source: &str, run: &mut Run) -> Result<()> {
+    let splits: Splits<'_> =
+        serde_json::from_str(source).map_err(|source| Error::Json { source })?;
+
+    let mut split_times = vec![None; run.len()];
+    let mut next_index = 0;
+    for timeline in &splits.timelines {
+        let name = segment_name(&timeline.name);
+        if let Some(index) = run.segments()[next_index..]
+            .iter()
+            .position(|segment| segment.name() == name)
+        {
+            split_times[next_index + index] = Some(time(timeline.rta, timeline.igt));
+            next_index += index + 1;
+        }
+    }
+
+    let final_time = time(splits.final_rta, splits.final_igt);
+    let is_completed = splits.is_completed.unwrap_or(true);
+    if is_completed {
+        if let Some(last) = split_times.last_mut() {
+            *last = Some(final_time);
+        }
+    }
+
+    let index = run.max_attempt_history_index().unwrap_or(0).max(0) + 1;
+    let reached = split_times
+        .iter()
+        .rposition(Option::is_some)
+        .map_or(0, |i| i + 1);
+    let mut previous_split = Time::zero();
+
+    for (segment, split_time) in run.segments_mut()[..reached].iter_mut().zip(split_times) {
+        let Some(split_time) = split_time else {
+            segment.segment_history_mut().insert(index, Time::default());
+            continue;
+        };
+        let segment_time = split_time - previous_split;
+        segment.segment_history_mut().insert(index, segment_time);
+
+        let best_segment_time = segment.best_segment_time_mut();
+        for method in TimingMethod::all() {
+            if let Some(time) = segment_time[method] {
+                if best_segment_time[method].is_none_or(|best| time < best) {
+                    best_segment_time[method] = Some(time);
+                }
+            }
+        }
+
+        previous_split = split_time;
+    }
+
+    let (started, ended) = attempt_dates(splits.date, final_time);
+    let time = if is_completed {
+        final_time
+    } else {
+        Time::default()
+    };
+    run.add_attempt_with_index(time, index, started, ended, None);
+    run.set_attempt_count(run.attempt_count() + 1);
+
+    Ok(())
+}
+
+/// Determines the name of the segment that an event of the timeline of a
+/// record is split at.
+fn segment_name(name: &str) -> Cow<'_, str> {
+    match name {
+        "crafted_ender_eye" => "Crafted Ender Eye".into(),
+        "enter_bastion" => "Found Bastion".into(),
+        "enter_end" => "Enter The End".into(),
+        "enter_fortress" => "Found Fortress".into(),
+        "enter_nether" => "Enter Nether".into(),
+        "enter_stronghold" => "Enter Stronghold".into(),
+        "found_villager" => "Found Villager".into(),
+        "got_trident" => "Got Trident".into(),
+        "kill_elder_guardian" => "Defeat Elder Guardian".into(),
+        "kill_ender_dragon" => "Defeat Ender Dragon".into(),
+        "kill_warden" => "Defeat Warden".into(),
+        "kill_wither" => "Defeat Wither".into(),
+        "nether_travel" => "Nether Travel".into(),
+        "pick_gold_block" => "Pick Gold Block".into(),
+        "pickup_book" => "Pickup Book".into(),
+        "sleep_on_tower" => "Sleep on Tower".into(),
+        "trade_with_villager" => "Trade with Villager".into(),
+        name => {
+            if let Some(rem) = name.strip_prefix("portal_no_") {
+                format!("Portal No. {rem}").into()
+            } else if let Some(rem) = name.strip_prefix("got_shell_") {
+                format!("Got Nautilus Shell {rem}").into()
+            } else {
+                let mut new_name = String::with_capacity(name.len());
+                let mut last_is_space = true;
+                for c in name.chars() {
+                    if c == '_' {
+                        new_name.push(' ');
+                        last_is_space = true;
+                    } else if last_is_space {
+                        new_name.extend(c.to_uppercase());
+                        last_is_space = false;
+                    } else {
+                        new_name.push(c);
+                    }
+                }
+                new_name.into()
+            }
+        }
+    }
+}
+
 fn kill_bosses<'a>(splits: &Splits<'a>, boss: &'static str) -> (Cow<'a, str>, Vars, CategoryType) {
     (
         "Kill Bosses".into(),
@@ -519,16 +598,24 @@ fn process_segments(run: &mut Run, ended: i64) {
         previous_split = split_time;
     }
 
+    let (started, ended) = attempt_dates(ended, previous_split);
+
+    run.add_attempt_with_index(previous_split, 1, started, ended, None);
+}
+
+/// Determines when an attempt started and ended, based on the date of the
+/// record, which is when the attempt ended, and the duration of the attempt.
+fn attempt_dates(ended: i64, duration: Time) -> (Option<AtomicDateTime>, Option<AtomicDateTime>) {
     let ended = DateTime::from_unix_timestamp(ended / 1000)
         .ok()
         .map(|date| AtomicDateTime::new(date + Duration::milliseconds(ended % 1000), false));
 
     let started = ended.and_then(|ended| {
         Some(AtomicDateTime::new(
-            ended.time - previous_split.real_time?.to_duration(),
+            ended.time - duration.real_time?.to_duration(),
             false,
         ))
     });
 
-    run.add_attempt_with_index(previous_split, 1, started, ended, None);
+    (started, ended)
 }
@@ -1,5 +1,6 @@
 //! Provides the parser for SplitterZ splits files.
 
+use super::{located::Lines, Located};
 use crate::{timing, RealTime, Run, Segment, TimeSpan};
 use alloc::borrow::Cow;
 use core::{num::ParseIntError, result::Result as StdResult};
@@ -55,13 +56,17 @@ fn unescape(text: &str) -> Cow<'_, str> {
 /// loaded from the file system. If you are using livesplit-core in a
 /// server-like environment, set this to `false`. Only client-side applications
 /// should set this to `true`.
-pub fn parse(source: &str, #[allow(unused)] load_icons: bool) -> Result<Run> {
+pub fn parse(source: &str, load_icons: bool) -> StdResult<Run, Located<Error>> {
+    let mut lines = Lines::new(source);
+    parse_lines(&mut lines, load_icons).map_err(|error| lines.locate(error))
+}
+
+fn parse_lines(lines: &mut Lines<'_>, #[allow(unused)] load_icons: bool) -> Result<Run> {
     let mut run = Run::new();
 
     #[cfg(feature = "std")]
     let mut icon_buf = Vec::new();
 
-    let mut lines = source.lines();
     let line = lines.next().ok_or(Error::Empty)?;
     let mut splits = line.split(',');
     run.set_category_name(unescape(splits.next().ok_or(Error::ExpectedCategoryName)?));
@@ -73,7 +78,7 @@ pub fn parse(source: &str, #[allow(unused)] load_icons: bool) -> Result<Run> {
             .context(ParseAttemptCount)?,
     );
 
-    for line in &mut lines {
+    for line in &mut *lines {
         if line.is_empty() {
             break;
         }
@@ -1,6 +1,6 @@
 //! Provides the parser for Time Split Tracker splits files.
 
-use super::super::AddComparisonError;
+use super::{super::AddComparisonError, located::Lines, Located};
 use crate::{
     comparison::RACE_COMPARISON_PREFIX,
     platform::{path::Path, prelude::*},
@@ -76,14 +76,20 @@ fn parse_time_optional(time: &str) -> StdResult<Option<TimeSpan>, timing::ParseE
 /// this to `None`. Only client-side applications should provide the path here.
 pub fn parse(
     source: &str,
+    path_for_loading_other_files: Option<&Path>,
+) -> StdResult<Run, Located<Error>> {
+    let mut lines = Lines::new(source);
+    parse_lines(&mut lines, path_for_loading_other_files).map_err(|error| lines.locate(error))
+}
+
+fn parse_lines(
+    lines: &mut Lines<'_>,
     #[allow(unused)] path_for_loading_other_files: Option<&Path>,
 ) -> Result<Run> {
     let mut run = Run::new();
     #[cfg(feature = "std")]
     let mut buf = Vec::new();
 
-    let mut lines = source.lines();
-
     let line = lines.next().context(Empty)?;
     let mut splits = line.split('\t');
 
@@ -7,6 +7,8 @@ use core::fmt;
 pub enum TimerKind<'a> {
     /// LiveSplit
     LiveSplit,
+    /// LiveSplit One
+    LiveSplitOne,
     /// WSplit
     WSplit,
     /// SplitterZ
@@ -45,6 +47,7 @@ impl TimerKind<'_> {
     pub fn into_owned(self) -> TimerKind<'static> {
         match self {
             TimerKind::LiveSplit => TimerKind::LiveSplit,
+            TimerKind::LiveSplitOne => TimerKind::LiveSplitOne,
             TimerKind::WSplit => TimerKind::WSplit,
             TimerKind::SplitterZ => TimerKind::SplitterZ,
             TimerKind::ShitSplit => TimerKind::ShitSplit,
@@ -68,6 +71,7 @@ impl fmt::Display for TimerKind<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(match self {
             TimerKind::LiveSplit => "LiveSplit",
+            TimerKind::LiveSplitOne => "LiveSplit One",
             TimerKind::WSplit => "WSplit",
             TimerKind::SplitterZ => "SplitterZ",
             TimerKind::ShitSplit => "ShitSplit",
@@ -1,5 +1,6 @@
 //! Provides the parser for WSplit splits files.
 
+use super::{located::Lines, Located};
 use crate::{RealTime, Run, Segment, TimeSpan};
 use core::{
     num::{ParseFloatError, ParseIntError},
@@ -55,7 +56,12 @@ pub type Result<T> = StdResult<T, Error>;
 /// the file system. If you are using livesplit-core in a server-like
 /// environment, set this to `false`. Only client-side applications should set
 /// this to `true`.
-pub fn parse(source: &str, #[allow(unused)] load_icons: bool) -> Result<Run> {
+pub fn parse(source: &str, load_icons: bool) -> StdResult<Run, Located<Error>> {
+    let mut lines = Lines::new(source);
+    parse_lines(&mut lines, load_icons).map_err(|error| lines.locate(error))
+}
+
+fn parse_lines(lines: &mut Lines<'_>, #[allow(unused)] load_icons: bool) -> Result<Run> {
     let mut run = Run::new();
     #[cfg(feature = "std")]
     let mut icon_buf = Vec::new();
@@ -64,7 +70,7 @@ pub fn parse(source: &str, #[allow(unused)] load_icons: bool) -> Result<Run> {
     let mut old_run_exists = false;
     let mut goal = None;
 
-    for line in source.lines() {
+    for line in lines {
         if line.is_empty() {
             continue;
         }
@@ -0,0 +1,281 @@
+//! Provides the parser for ZIP archives containing multiple splits files, such
+//! as backups of all the splits of a runner. Each file in the archive is parsed
+//! with the [composite](super::composite) parser. Optionally the runs of the
+//! same game and category can be merged into a single run, combining their
+//! histories.
+//!
+//! Only files that are stored uncompressed or compressed with Deflate are
+//! supported, which covers the archives created by almost all tools. Encrypted
+//! files are skipped.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use livesplit_core::run::parser::zip;
+//! use std::fs;
+//!
+//! let file = fs::read("path/to/backup.zip").expect("Failed reading the file.");
+//! let archive = zip::parse(&file, true).expect("Not a valid ZIP archive.");
+//!
+//! for imported in &archive.runs {
+//!     println!(
+//!         "{} from {}",
+//!         imported.run.extended_name(false),
+//!         imported.file_names.join(", "),
+//!     );
+//! }
+//! ```
+
+use super::{composite, import_history_from, TimerKind};
+use crate::{
+    platform::prelude::*,
+    util::byte_parsing::{
+        little_endian::{strip_u16, strip_u32},
+        strip_slice,
+    },
+    Run, TimingMethod,
+};
+use core::result::Result as StdResult;
+
+/// The Error type for ZIP archives that couldn't be parsed by the ZIP Parser.
+#[derive(Debug, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum Error {
+    /// The file is not a ZIP archive.
+    NotAnArchive,
+    /// The ZIP archive is corrupted.
+    Corrupted,
+    /// The ZIP archive uses the ZIP64 extensions, which are not supported.
+    Zip64,
+}
+
+/// The Result type for the ZIP Parser.
+pub type Result<T> = StdResult<T, Error>;
+
+/// The splits files contained in a ZIP archive.
+#[derive(Debug)]
+pub struct Archive {
+    /// The runs that got imported from the splits files.
+    pub runs: Vec<ImportedRun>,
+    /// The names of the files that couldn't be imported, either because they
+    /// are not splits files or because they couldn't be extracted.
+    pub skipped: Vec<String>,
+}
+
+/// A run that got imported from a ZIP archive.
+#[derive(Debug)]
+pub struct ImportedRun {
+    /// The imported run.
+    pub run: Run,
+    /// The parser that parsed the run. If multiple runs got merged, this is the
+    /// parser of the first one.
+    pub kind: TimerKind<'static>,
+    /// The names of the files that the run got imported from, including the
+    /// directories within the archive. There are multiple file names if
+    /// multiple runs got merged.
+    pub file_names: Vec<String>,
+}
+
+const LOCAL_FILE_HEADER: u32 = 0x04034b50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054b50;
+const END_OF_CENTRAL_DIRECTORY_LEN: usize = 22;
+
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+struct Entry {
+    name: String,
+    flags: u16,
+    method: u16,
+    compressed_size: u32,
+    uncompressed_size: u32,
+    local_header_offset: u32,
+}
+
+/// Finds the central directory by searching for its end record, which is
+/// followed by a comment of up to 64 KiB.
+fn central_directory(source: &[u8]) -> Result<(u16, &[u8])> {
+    let search_start = source
+        .len()
+        .saturating_sub(END_OF_CENTRAL_DIRECTORY_LEN + u16::MAX as usize);
+    let end = (search_start..=source.len().saturating_sub(END_OF_CENTRAL_DIRECTORY_LEN))
+        .rev()
+        .find(|&i| source[i..].starts_with(&END_OF_CENTRAL_DIRECTORY.to_le_bytes()))
+        .ok_or(Error::NotAnArchive)?;
+
+    let mut cursor = &source[end + 10..];
+    let entries = strip_u16(&mut cursor).ok_or(Error::Corrupted)?;
+    let size = strip_u32(&mut cursor).ok_or(Error::Corrupted)?;
+    let offset = strip_u32(&mut cursor).ok_or(Error::Corrupted)?;
+
+    if entries == u16::MAX || size == u32::MAX || offset == u32::MAX {
+        return Err(Error::Zip64);
+    }
+
+    let directory = source
+        .get(offset as usize..)
+        .and_then(|rest| rest.get(..size as usize))
+        .ok_or(Error::Corrupted)?;
+
+    Ok((entries, directory))
+}
+
+fn parse_entry(cursor: &mut &[u8]) -> Option<Entry> {
+    if strip_u32(cursor)? != CENTRAL_DIRECTORY_HEADER {
+        return None;
+    }
+    let _versions = strip_u32(cursor)?;
+    let flags = strip_u16(cursor)?;
+    let method = strip_u16(cursor)?;
+    let _modified = strip_u32(cursor)?;
+    let _crc = strip_u32(cursor)?;
+    let compressed_size = strip_u32(cursor)?;
+    let uncompressed_size = strip_u32(cursor)?;
+    let name_len = strip_u16(cursor)?;
+    let extra_len = strip_u16(cursor)?;
+    let comment_len = strip_u16(cursor)?;
+    let _disk = strip_u16(cursor)?;
+    let _attributes = strip_u16(cursor)?;
+    let _external_attributes = strip_u32(cursor)?;
+    let local_header_offset = strip_u32(cursor)?;
+    let name = strip_slice::<u8>(cursor, name_len as usize)?;
+    strip_slice::<u8>(cursor, extra_len as usize + comment_len as usize)?;
+
+    Some(Entry {
+        name: String::from_utf8_lossy(name).into_owned(),
+        flags,
+        method,
+        compressed_size,
+        uncompressed_size,
+        local_header_offset,
+    })
+}
+
+/// Locates the data of the entry, which follows its local header.
+fn locate_data<'a>(source: &'a [u8], entry: &Entry) -> Option<&'a [u8]> {
+    let mut cursor = source.get(entry.local_header_offset as usize..)?;
+    if strip_u32(&mut cursor)? != LOCAL_FILE_HEADER {
+        return None;
+    }
+    strip_slice::<u8>(&mut cursor, 22)?;
+    let name_len = strip_u16(&mut cursor)?;
+    let extra_len = strip_u16(&mut cursor)?;
+    strip_slice::<u8>(&mut cursor, name_len as usize + extra_len as usize)?;
+    cursor.get(..entry.compressed_size as usize)
+}
+
+fn extract(entry: &Entry, data: &[u8]) -> Option<Vec<u8>> {
+    // Encrypted files can't be extracted.
+    if entry.flags & 1 != 0 {
+        return None;
+    }
+    match entry.method {
+        STORED => Some(data.to_vec()),
+        DEFLATED => miniz_oxide::inflate::decompress_to_vec_with_limit(
+            data,
+            entry.uncompressed_size as usize,
+        )
+        .ok(),
+        _ => None,
+    }
+}
+
+/// Attempts to parse a ZIP archive containing splits files. Each file in the
+/// archive is parsed on its own. Files that aren't splits files are skipped. If
+/// `merge` is `true`, the runs of the same game and category with the same
+/// segments are merged into a single run. The attempt histories and segment
+/// histories get combined and the best segments and the personal best are
+/// chosen from all of the runs.
+pub fn parse(source: &[u8], merge: bool) -> Result<Archive> {
+    let (entry_count, mut directory) = central_directory(source)?;
+
+    let mut archive = Archive {
+        runs: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for _ in 0..entry_count {
+        let entry = parse_entry(&mut directory).ok_or(Error::Corrupted)?;
+        if entry.compressed_size == u32::MAX
+            || entry.uncompressed_size == u32::MAX
+            || entry.local_header_offset == u32::MAX
+        {
+            return Err(Error::Zip64);
+        }
+        if entry.name.ends_with('/') {
+            continue;
+        }
+        let data = locate_data(source, &entry).ok_or(Error::Corrupted)?;
+
+        let parsed = extract(&entry, data).and_then(|data| {
+            composite::parse(&data, None)
+                .ok()
+                .map(composite::ParsedRun::into_owned)
+        });
+
+        let Some(parsed) = parsed else {
+            archive.skipped.push(entry.name);
+            continue;
+        };
+
+        if merge {
+            if let Some(imported) = archive.runs.iter_mut().find(|imported| {
+                imported.run.game_name() == parsed.run.game_name()
+                    && imported.run.category_name() == parsed.run.category_name()
+                    && has_same_segments(&imported.run, &parsed.run)
+            }) {
+                merge_runs(&mut imported.run, parsed.run);
+                imported.file_names.push(entry.name);
+                continue;
+            }
+        }
+
+        archive.runs.push(ImportedRun {
+            run: parsed.run,
+            kind: parsed.kind,
+            file_names: vec![entry.name],
+        });
+    }
+
+    Ok(archive)
+}
+
+fn has_same_segments(a: &Run, b: &Run) -> bool {
+    a.len() == b.len()
+        && a.segments()
+            .iter()
+            .zip(b.segments())
+            .all(|(a, b)| a.name() == b.name())
+}
+
+/// Merges the other run into the run by importing its history. The personal
+/// best of the other run is taken over if it is faster.
+fn merge_runs(run: &mut Run, other: Run) {
+    // The personal bests are compared by their real time, unless neither of
+    // them has one.
+    let final_time = |run: &Run| {
+        run.segments()
+            .last()
+            .map(|segment| segment.personal_best_split_time())
+            .unwrap_or_default()
+    };
+    let method = if final_time(run).real_time.is_some() || final_time(&other).real_time.is_some() {
+        TimingMethod::RealTime
+    } else {
+        TimingMethod::GameTime
+    };
+    let has_better_pb = match (final_time(run)[method], final_time(&other)[method]) {
+        (Some(current), Some(other)) => other < current,
+        (None, other) => other.is_some(),
+        _ => false,
+    };
+
+    if has_better_pb {
+        for (segment, other) in run.segments_mut().iter_mut().zip(other.segments()) {
+            segment.set_personal_best_split_time(other.personal_best_split_time());
+        }
+    }
+
+    import_history_from(run, other);
+}
@@ -0,0 +1,127 @@
+//! The Attempt History CSV Saver exports the attempt history and the segment
+//! histories of a Run as CSV files, so they can be analyzed in spreadsheets.
+//! Corrections made to them can be imported again with the corresponding
+//! [parser](crate::run::parser::attempt_history_csv).
+//!
+//! The attempt history is stored with one row per attempt and the columns
+//! `Attempt`, `Started`, `Ended`, `Real Time`, `Game Time`, `Pause Time`,
+//! `Pause Count` and `Longest Pause`. The segment histories are stored with one
+//! row per segment of each attempt and the columns `Attempt`, `Segment`,
+//! `Segment Name`, `Real Time` and `Game Time`, where `Segment` is the number of
+//! the segment, starting at 1. The times of the segment histories are segment
+//! times, not split times. Dates are stored in the RFC 3339 format in UTC and
+//! missing information is stored as empty cells.
+//!
+//! # Examples
+//!
+//! ```
+//! use livesplit_core::run::saver::attempt_history_csv;
+//! use livesplit_core::{Run, Segment, Time, TimeSpan};
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//! run.add_attempt(
+//!     Time::new().with_real_time(Some(TimeSpan::from_seconds(250.0))),
+//!     None,
+//!     None,
+//!     None,
+//! );
+//!
+//! let mut csv = String::new();
+//! attempt_history_csv::save_attempts(&run, &mut csv).expect("Couldn't save the attempts");
+//! assert_eq!(csv.lines().nth(1), Some("1,,,00:04:10.000000000,,,,"));
+//! ```
+
+use crate::{
+    platform::prelude::*,
+    run::format::livesplit_one::JsonDateTime,
+    timing::formatter::{Complete, TimeFormatter},
+    AtomicDateTime, Run, TimeSpan,
+};
+use core::fmt::{self, Write};
+
+fn time<W: Write>(writer: &mut W, time: Option<TimeSpan>) -> fmt::Result {
+    writer.write_char(',')?;
+    if let Some(time) = time {
+        write!(writer, "{}", Complete.format(time))?;
+    }
+    Ok(())
+}
+
+fn date<W: Write>(writer: &mut W, date: Option<AtomicDateTime>) -> fmt::Result {
+    writer.write_char(',')?;
+    if let Some(date) = date {
+        write!(writer, "{}", JsonDateTime(date.time))?;
+    }
+    Ok(())
+}
+
+/// Writes the text as a single field, quoting it if necessary.
+fn field<W: Write>(writer: &mut W, text: &str) -> fmt::Result {
+    if !text.contains([',', '"', '\n', '\r']) {
+        return writer.write_str(text);
+    }
+    writer.write_char('"')?;
+    for (i, part) in text.split('"').enumerate() {
+        if i != 0 {
+            writer.write_str("\"\"")?;
+        }
+        writer.write_str(part)?;
+    }
+    writer.write_char('"')
+}
+
+/// Saves the attempt history of a Run as a CSV file with one row per attempt.
+pub fn save_attempts<W: Write>(run: &Run, mut writer: W) -> fmt::Result {
+    writer.write_str(
+        "Attempt,Started,Ended,Real Time,Game Time,Pause Time,Pause Count,Longest Pause\n",
+    )?;
+
+    for attempt in run.attempt_history() {
+        write!(writer, "{}", attempt.index())?;
+        date(&mut writer, attempt.started())?;
+        date(&mut writer, attempt.ended())?;
+        time(&mut writer, attempt.time().real_time)?;
+        time(&mut writer, attempt.time().game_time)?;
+        time(&mut writer, attempt.pause_time())?;
+        writer.write_char(',')?;
+        if let Some(pause_count) = attempt.pause_count() {
+            write!(writer, "{pause_count}")?;
+        }
+        time(&mut writer, attempt.longest_pause())?;
+        writer.write_char('\n')?;
+    }
+
+    Ok(())
+}
+
+/// Saves the segment histories of all the segments of a Run as a CSV file with
+/// one row per segment of each attempt. The rows are ordered by the attempts.
+/// Segment history elements that don't belong to any attempt are included as
+/// well, with their non-positive indices.
+pub fn save_segment_history<W: Write>(run: &Run, mut writer: W) -> fmt::Result {
+    writer.write_str("Attempt,Segment,Segment Name,Real Time,Game Time\n")?;
+
+    let mut rows = run
+        .segments()
+        .iter()
+        .enumerate()
+        .flat_map(|(segment_index, segment)| {
+            segment
+                .segment_history()
+                .iter()
+                .map(move |&(index, time)| (index, segment_index, time))
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by_key(|&(index, segment_index, _)| (index, segment_index));
+
+    for (index, segment_index, segment_time) in rows {
+        write!(writer, "{index},{},", segment_index + 1)?;
+        field(&mut writer, run.segment(segment_index).name())?;
+        time(&mut writer, segment_time.real_time)?;
+        time(&mut writer, segment_time.game_time)?;
+        writer.write_char('\n')?;
+    }
+
+    Ok(())
+}
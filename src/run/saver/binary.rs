@@ -0,0 +1,225 @@
+//! The Binary Saver saves Runs as livesplit-core's binary splits files. The
+//! format is documented in the corresponding
+//! [parser](crate::run::parser::binary).
+//!
+//! # Examples
+//!
+//! Using the Binary Saver to cache a Run as a binary splits file.
+//!
+//! ```
+//! use livesplit_core::run::{parser, saver};
+//! use livesplit_core::{Run, Segment};
+//!
+//! let mut run = Run::new();
+//! run.set_game_name("Super Mario Odyssey");
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! let cached = saver::binary::save_run(&run);
+//! # #[cfg(feature = "parser-binary")]
+//! # {
+//! let parsed = parser::binary::parse(&cached).expect("Couldn't parse the splits file");
+//! assert_eq!(parsed.game_name(), "Super Mario Odyssey");
+//! # }
+//! ```
+
+use crate::{
+    platform::prelude::*,
+    run::{
+        format::binary::{
+            DATE_TIME_NONE, DATE_TIME_SYNCED, DATE_TIME_UNSYNCED, GAME_TIME, LINKED_LAYOUT_DEFAULT,
+            LINKED_LAYOUT_NONE, LINKED_LAYOUT_PATH, MAGIC, REAL_TIME, VERSION,
+        },
+        LinkedLayout,
+    },
+    settings::Image,
+    AtomicDateTime, Run, Time, TimeSpan, Timer, TimerPhase,
+};
+
+struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    fn u64(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn i64(&mut self, value: i64) {
+        self.u64(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u64(len as u64);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.len(bytes.len());
+        self.buf.extend_from_slice(bytes);
+    }
+
+    fn str(&mut self, text: &str) {
+        self.bytes(text.as_bytes());
+    }
+
+    fn image(&mut self, image: &Image) {
+        self.bytes(image.data());
+    }
+
+    fn time_span(&mut self, time: TimeSpan) {
+        let (seconds, nanos) = time.to_seconds_and_subsec_nanoseconds();
+        self.i64(seconds);
+        self.i64(nanos.into());
+    }
+
+    fn optional_time_span(&mut self, time: Option<TimeSpan>) {
+        self.bool(time.is_some());
+        if let Some(time) = time {
+            self.time_span(time);
+        }
+    }
+
+    fn time(&mut self, time: Time) {
+        let mut flags = 0;
+        if time.real_time.is_some() {
+            flags |= REAL_TIME;
+        }
+        if time.game_time.is_some() {
+            flags |= GAME_TIME;
+        }
+        self.u8(flags);
+        for time in [time.real_time, time.game_time].into_iter().flatten() {
+            self.time_span(time);
+        }
+    }
+
+    fn date_time(&mut self, date_time: Option<AtomicDateTime>) {
+        let Some(date_time) = date_time else {
+            self.u8(DATE_TIME_NONE);
+            return;
+        };
+        self.u8(if date_time.synced_with_atomic_clock {
+            DATE_TIME_SYNCED
+        } else {
+            DATE_TIME_UNSYNCED
+        });
+        self.i64(date_time.time.unix_timestamp());
+        self.u64(date_time.time.nanosecond().into());
+    }
+}
+
+/// Saves the Run in use by the Timer provided as a binary splits file.
+pub fn save_timer(timer: &Timer) -> Vec<u8> {
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        timer.run()
+    } else {
+        &timer.clone().into_run(true)
+    };
+    save_run(run)
+}
+
+/// Saves a Run as a binary splits file. Use the `save_timer` function if the
+/// Run is in use by a timer in order to properly save the current attempt as
+/// well.
+pub fn save_run(run: &Run) -> Vec<u8> {
+    let writer = &mut Writer { buf: Vec::new() };
+
+    writer.buf.extend_from_slice(&MAGIC);
+    writer.buf.extend_from_slice(&VERSION.to_le_bytes());
+
+    writer.str(run.game_name());
+    writer.image(run.game_icon());
+    writer.str(run.category_name());
+    writer.time_span(run.offset());
+    writer.u64(run.attempt_count().into());
+
+    let metadata = run.metadata();
+    writer.str(metadata.run_id());
+    writer.str(metadata.platform_name());
+    writer.bool(metadata.uses_emulator());
+    writer.str(metadata.region_name());
+    writer.len(metadata.speedrun_com_variables().count());
+    for (name, value) in metadata.speedrun_com_variables() {
+        writer.str(name);
+        writer.str(value);
+    }
+    let custom_variables = || {
+        metadata
+            .custom_variables()
+            .filter(|(_, var)| var.is_permanent)
+    };
+    writer.len(custom_variables().count());
+    for (name, var) in custom_variables() {
+        writer.str(name);
+        writer.str(&var.value);
+    }
+
+    match run.linked_layout() {
+        None => writer.u8(LINKED_LAYOUT_NONE),
+        Some(LinkedLayout::Default) => writer.u8(LINKED_LAYOUT_DEFAULT),
+        Some(LinkedLayout::Path(path)) => {
+            writer.u8(LINKED_LAYOUT_PATH);
+            writer.str(path);
+        }
+    }
+
+    writer.len(run.custom_comparisons().len());
+    for comparison in run.custom_comparisons() {
+        writer.str(comparison);
+    }
+
+    writer.len(run.attempt_history().len());
+    for attempt in run.attempt_history() {
+        writer.i64(attempt.index().into());
+        writer.time(attempt.time());
+        writer.date_time(attempt.started());
+        writer.date_time(attempt.ended());
+        writer.optional_time_span(attempt.pause_time());
+        writer.bool(attempt.pause_count().is_some());
+        if let Some(pause_count) = attempt.pause_count() {
+            writer.u64(pause_count.into());
+        }
+        writer.optional_time_span(attempt.longest_pause());
+        writer.len(attempt.actions().len());
+        for action in attempt.actions() {
+            writer.str(action.kind.name());
+            writer.time_span(action.time);
+            writer.optional_time_span(action.kind.value());
+        }
+    }
+
+    writer.len(run.len());
+    for segment in run.segments() {
+        writer.str(segment.name());
+        writer.image(segment.icon());
+        for comparison in run.custom_comparisons() {
+            writer.time(segment.comparison(comparison));
+        }
+        writer.time(segment.best_segment_time());
+        writer.len(segment.segment_history().iter().len());
+        for &(index, time) in segment.segment_history() {
+            writer.i64(index.into());
+            writer.time(time);
+        }
+        writer.len(segment.variables().len());
+        for (name, value) in segment.variables() {
+            writer.str(name);
+            writer.str(value);
+        }
+    }
+
+    writer.str(run.auto_splitter_settings());
+
+    core::mem::take(&mut writer.buf)
+}
@@ -0,0 +1,141 @@
+//! The Journal Saver appends finished attempts to an attempt journal, a compact
+//! log that is meant to be kept alongside the splits file. Analytics tools can
+//! consume the attempts from the journal without parsing the whole splits file
+//! after every attempt.
+//!
+//! The journal is stored in the [JSON Lines](https://jsonlines.org) format,
+//! with one JSON object per attempt. Times and dates are stored just like in
+//! LiveSplit One's [JSON splits files](crate::run::parser::livesplit_one). The
+//! splits are the split times of the segments the attempt reached, in the
+//! order of the segments. Skipped segments have no split time. Each attempt is
+//! written with a single write, so if saving gets interrupted, only the last
+//! line may be incomplete. Tools reading the journal should ignore such a
+//! line.
+//!
+//! ```json
+//! {"index":7,"time":{"real_time":"3600.500000000","game_time":null},"started":{"time":"2024-01-02T03:04:05.000000000Z","synced":true},"ended":{"time":"2024-01-02T04:04:05.500000000Z","synced":true},"pause_time":null,"splits":[{"name":"Cap Kingdom","real_time":"300.250000000","game_time":null}]}
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use livesplit_core::run::saver::journal;
+//! use livesplit_core::{Run, Segment, Timer};
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//! let mut timer = Timer::new(run).expect("The run has a segment");
+//!
+//! timer.start().unwrap();
+//! timer.split().unwrap();
+//! timer.reset(true).unwrap();
+//!
+//! let run = timer.run();
+//! let attempt = run.attempt_history().last().expect("An attempt was stored");
+//! let mut journal = String::new();
+//! journal::save_attempt(run, attempt, &mut journal).expect("Couldn't save the attempt");
+//! assert!(journal.ends_with('\n'));
+//! ```
+
+use crate::{
+    platform::prelude::*,
+    run::format::livesplit_one::{JsonAtomicDateTime, JsonTime, JsonTimeSpan},
+    Attempt, Run, Time, TimeSpan, TimingMethod,
+};
+use alloc::borrow::Cow;
+use core::fmt;
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+struct Entry<'a> {
+    index: i32,
+    time: JsonTime,
+    started: Option<JsonAtomicDateTime>,
+    ended: Option<JsonAtomicDateTime>,
+    pause_time: Option<JsonTimeSpan>,
+    splits: Vec<Split<'a>>,
+}
+
+#[derive(Serialize)]
+struct Split<'a> {
+    name: Cow<'a, str>,
+    real_time: Option<JsonTimeSpan>,
+    game_time: Option<JsonTimeSpan>,
+}
+
+/// Saves a single attempt of the Run as a line of an attempt journal. The line
+/// is terminated with a line break, so the attempts can be appended to the
+/// journal one after another.
+pub fn save_attempt<W: fmt::Write>(run: &Run, attempt: &Attempt, mut writer: W) -> fmt::Result {
+    let index = attempt.index();
+    let mut total = Time::new();
+    let mut splits = Vec::new();
+
+    for segment in run.segments() {
+        let Some(segment_time) = segment.segment_history().get(index) else {
+            break;
+        };
+        let mut split_time = Time::new();
+        for method in TimingMethod::all() {
+            if let Some(time) = segment_time[method] {
+                let total = total[method].get_or_insert(TimeSpan::zero());
+                *total += time;
+                split_time[method] = Some(*total);
+            }
+        }
+        splits.push(Split {
+            name: segment.name().into(),
+            real_time: split_time.real_time.map(JsonTimeSpan),
+            game_time: split_time.game_time.map(JsonTimeSpan),
+        });
+    }
+
+    let entry = Entry {
+        index,
+        time: attempt.time().into(),
+        started: attempt.started().map(Into::into),
+        ended: attempt.ended().map(Into::into),
+        pause_time: attempt.pause_time().map(JsonTimeSpan),
+        splits,
+    };
+
+    let json = serde_json::to_string(&entry).map_err(|_| fmt::Error)?;
+    writer.write_str(&json)?;
+    writer.write_char('\n')
+}
+
+/// Saves the whole attempt history of the Run as an attempt journal. This can
+/// be used to start a journal for splits that already have attempts.
+pub fn save_run<W: fmt::Write>(run: &Run, mut writer: W) -> fmt::Result {
+    for attempt in run.attempt_history() {
+        save_attempt(run, attempt, &mut writer)?;
+    }
+    Ok(())
+}
+
+/// Returns the path of the attempt journal that belongs to the splits file at
+/// the path provided. The journal is stored next to the splits file with the
+/// extension `journal`.
+#[cfg(feature = "std")]
+pub fn journal_path(splits_path: &std::path::Path) -> std::path::PathBuf {
+    splits_path.with_extension("journal")
+}
+
+/// Appends a single attempt of the Run to the attempt journal at the path
+/// provided. The journal is created if it doesn't exist yet.
+#[cfg(feature = "std")]
+pub fn append_attempt<P: AsRef<std::path::Path>>(
+    run: &Run,
+    attempt: &Attempt,
+    path: P,
+) -> std::io::Result<()> {
+    use std::{fs::OpenOptions, io::Write};
+
+    let mut line = String::new();
+    save_attempt(run, attempt, &mut line)
+        .map_err(|_| std::io::Error::other("Couldn't save the attempt"))?;
+
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+    file.write_all(line.as_bytes())?;
+    file.sync_data()
+}
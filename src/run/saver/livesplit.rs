@@ -23,6 +23,43 @@
 //! // Save the splits file as a LiveSplit splits file.
 //! livesplit::save_run(&run, IoWrite(writer)).expect("Couldn't save the splits file");
 //! ```
+//!
+//! Writing to the splits file directly truncates it first, so a crash while
+//! saving may leave it incomplete. The splits file can instead be replaced
+//! atomically with [`save_run_to_path`].
+//!
+//! ```no_run
+//! use livesplit_core::run::saver::livesplit;
+//! use livesplit_core::{Run, Segment};
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! livesplit::save_run_to_path(&run, "path/to/splits_file.lss")
+//!     .expect("Couldn't save the splits file");
+//! ```
+//!
+//! Splits files that are kept in version control can be formatted such that
+//! saving them only changes a few lines.
+//!
+//! ```
+//! use livesplit_core::run::saver::livesplit::{self, Indentation, Options};
+//! use livesplit_core::{Run, Segment};
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! let options = Options {
+//!     indentation: Indentation::Spaces(2),
+//!     time_digits: 7,
+//!     write_default_elements: false,
+//!     ..Options::default()
+//! };
+//! let mut buf = String::new();
+//! livesplit::save_run_with_options(&run, &mut buf, &options)
+//!     .expect("Couldn't save the splits file");
+//! assert!(buf.contains("\n    <Segment>\n"));
+//! ```
 
 use crate::{
     platform::prelude::*,
@@ -30,13 +67,131 @@ use crate::{
     settings::Image,
     timing::formatter::{Complete, TimeFormatter},
     util::xml::{AttributeWriter, DisplayAlreadyEscaped, Text, Writer, NO_ATTRIBUTES},
-    DateTime, Run, Time, Timer, TimerPhase,
+    DateTime, Run, Time, TimeSpan, Timer, TimerPhase,
 };
 use alloc::borrow::Cow;
-use core::{fmt, mem::MaybeUninit};
+use core::{
+    fmt::{self, Write},
+    mem::MaybeUninit,
+};
 use time::UtcOffset;
 
-const LSS_IMAGE_HEADER: &[u8; 156] = include_bytes!("lss_image_header.bin");
+pub(crate) const LSS_IMAGE_HEADER: &[u8; 156] = include_bytes!("lss_image_header.bin");
+
+/// Describes how the elements of a splits file are indented.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Indentation {
+    /// The whole splits file is written on a single line.
+    #[default]
+    None,
+    /// Every element is put on its own line and indented by the amount of
+    /// spaces provided for each element it is nested in.
+    Spaces(u8),
+    /// Every element is put on its own line and indented by a tab for each
+    /// element it is nested in.
+    Tabs,
+}
+
+/// The options that control how a splits file is formatted. The default
+/// options write the splits file in the same way as [`save_run`]. Users that
+/// keep their splits files in version control can indent the splits file and
+/// leave out information that doesn't carry any meaning, so that saving the
+/// splits after an attempt only changes a few lines.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    /// How the elements are indented.
+    pub indentation: Indentation,
+    /// The amount of fractional digits of the times, up to 9. Any further
+    /// digits are truncated. LiveSplit itself stores 7 digits.
+    pub time_digits: u8,
+    /// Whether elements that only store default values, such as an empty
+    /// segment history or a segment without an icon, are written. The
+    /// elements that every splits file needs to have are always written.
+    /// Applications other than livesplit-core may not be able to open splits
+    /// files without the optional elements.
+    pub write_default_elements: bool,
+    /// Whether the game icon and the segment icons are stored in the splits
+    /// file. Without the icons, the splits file is a lot smaller, which is
+    /// useful for sharing it publicly.
+    pub write_icons: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            indentation: Indentation::None,
+            time_digits: 9,
+            write_default_elements: true,
+            write_icons: true,
+        }
+    }
+}
+
+/// Formats a time with the amount of fractional digits chosen in the
+/// [`Options`].
+#[derive(Copy, Clone)]
+struct TimeFormat {
+    digits: u8,
+}
+
+impl TimeFormat {
+    const fn format(self, time: TimeSpan) -> DisplayAlreadyEscaped<FormattedTime> {
+        DisplayAlreadyEscaped(FormattedTime {
+            time,
+            digits: self.digits,
+        })
+    }
+}
+
+struct FormattedTime {
+    time: TimeSpan,
+    digits: u8,
+}
+
+impl fmt::Display for FormattedTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let complete = Complete.format(self.time);
+        if self.digits >= 9 {
+            return fmt::Display::fmt(&complete, f);
+        }
+
+        // The complete format always ends with the dot and 9 fractional
+        // digits, so we only need to cut off the end.
+        let mut len = Counter(0);
+        write!(len, "{complete}")?;
+        let cut_off = 9 - self.digits as usize + (self.digits == 0) as usize;
+        write!(
+            Truncated {
+                f,
+                remaining: len.0 - cut_off,
+            },
+            "{complete}"
+        )
+    }
+}
+
+struct Counter(usize);
+
+impl fmt::Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+struct Truncated<'a, 'f> {
+    f: &'a mut fmt::Formatter<'f>,
+    remaining: usize,
+}
+
+impl fmt::Write for Truncated<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // The complete format is ASCII only, so this never splits a character.
+        let s = &s[..s.len().min(self.remaining)];
+        self.remaining -= s.len();
+        self.f.write_str(s)
+    }
+}
 
 const fn bool(value: bool) -> Text<'static> {
     Text::new_escaped(if value { "True" } else { "False" })
@@ -62,7 +217,7 @@ where
     })
 }
 
-fn image<W: fmt::Write>(
+pub(crate) fn image<W: fmt::Write>(
     writer: &mut Writer<W>,
     tag: &str,
     image: &Image,
@@ -96,6 +251,44 @@ fn image<W: fmt::Write>(
     })
 }
 
+/// Describes how the icons are stored in the splits file.
+enum Icons<F> {
+    Embed,
+    Omit,
+    Paths(F),
+}
+
+type NoIconPaths = fn(Option<usize>, &Image) -> Option<String>;
+
+#[allow(clippy::too_many_arguments)]
+fn icon<W, F>(
+    writer: &mut Writer<W>,
+    tag: &str,
+    index: Option<usize>,
+    icon: &Image,
+    icons: &mut Icons<F>,
+    write_empty: bool,
+    base64_buf: &mut Vec<MaybeUninit<u8>>,
+    image_buf: &mut Cow<'_, [u8]>,
+) -> fmt::Result
+where
+    W: fmt::Write,
+    F: FnMut(Option<usize>, &Image) -> Option<String>,
+{
+    let path = match icons {
+        Icons::Embed if !icon.is_empty() => {
+            return image(writer, tag, icon, base64_buf, image_buf);
+        }
+        Icons::Paths(icon_path) if !icon.is_empty() => icon_path(index, icon),
+        _ => None,
+    };
+    match path {
+        Some(path) => writer.empty_tag(tag, [("path", path.as_str())]),
+        None if write_empty => writer.empty_tag(tag, NO_ATTRIBUTES),
+        None => Ok(()),
+    }
+}
+
 fn date<W: fmt::Write>(
     writer: &mut AttributeWriter<'_, W>,
     key: &str,
@@ -114,29 +307,29 @@ fn date<W: fmt::Write>(
     )
 }
 
-fn time_inner<W: fmt::Write>(writer: &mut Writer<W>, time: Time) -> fmt::Result {
+fn time_inner<W: fmt::Write>(
+    writer: &mut Writer<W>,
+    time: Time,
+    format: TimeFormat,
+) -> fmt::Result {
     if let Some(time) = time.real_time {
-        writer.tag_with_text_content(
-            "RealTime",
-            NO_ATTRIBUTES,
-            DisplayAlreadyEscaped(Complete.format(time)),
-        )?;
+        writer.tag_with_text_content("RealTime", NO_ATTRIBUTES, format.format(time))?;
     }
 
     if let Some(time) = time.game_time {
-        writer.tag_with_text_content(
-            "GameTime",
-            NO_ATTRIBUTES,
-            DisplayAlreadyEscaped(Complete.format(time)),
-        )?;
+        writer.tag_with_text_content("GameTime", NO_ATTRIBUTES, format.format(time))?;
     }
 
     Ok(())
 }
 
-fn time<W: fmt::Write>(writer: AttributeWriter<'_, W>, time: Time) -> fmt::Result {
+fn time<W: fmt::Write>(
+    writer: AttributeWriter<'_, W>,
+    time: Time,
+    format: TimeFormat,
+) -> fmt::Result {
     if time.real_time.is_some() || time.game_time.is_some() {
-        writer.content(|writer| time_inner(writer, time))
+        writer.content(|writer| time_inner(writer, time, format))
     } else {
         Ok(())
     }
@@ -165,116 +358,388 @@ pub fn save_timer<W: fmt::Write>(timer: &Timer, writer: W) -> fmt::Result {
     save_run(run, writer)
 }
 
+/// Saves the Run in use by the Timer provided as a gzip-compressed LiveSplit
+/// splits file (*.lss). See [`save_run_compressed`] for more information.
+#[cfg(feature = "gzip")]
+pub fn save_timer_compressed(timer: &Timer) -> Result<Vec<u8>, fmt::Error> {
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        timer.run()
+    } else {
+        &timer.clone().into_run(true)
+    };
+    save_run_compressed(run)
+}
+
+/// Saves a Run as a gzip-compressed LiveSplit splits file (*.lss). The segment
+/// histories make up most of a large splits file and compress very well, so
+/// this often shrinks them by about an order of magnitude. The composite
+/// parser detects compressed splits files and decompresses them
+/// transparently. LiveSplit itself can't read them though.
+#[cfg(feature = "gzip")]
+pub fn save_run_compressed(run: &Run) -> Result<Vec<u8>, fmt::Error> {
+    let mut buf = String::new();
+    save_run(run, &mut buf)?;
+    Ok(crate::util::gzip::compress(buf.as_bytes()))
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss) at the path provided. The
+/// splits file is first written to a temporary file in the same directory,
+/// which is flushed to the disk and then renamed to replace the splits file.
+/// So if saving gets interrupted, the previous splits file stays intact.
+#[cfg(feature = "std")]
+pub fn save_run_to_path<P: AsRef<std::path::Path>>(run: &Run, path: P) -> std::io::Result<()> {
+    use std::{
+        fs::{self, File},
+        io::{self, Write},
+    };
+
+    let path = path.as_ref();
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "The path has no file name"))?;
+    let mut temp_name = std::ffi::OsString::from(".");
+    temp_name.push(file_name);
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let mut buf = String::new();
+    save_run(run, &mut buf).map_err(|_| io::Error::other("Couldn't save the run"))?;
+
+    let result = (|| {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(buf.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&temp_path, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+        return result;
+    }
+
+    // The rename itself is only durable once the directory is flushed as well.
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        let dir = if dir.as_os_str().is_empty() {
+            std::path::Path::new(".")
+        } else {
+            dir
+        };
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+
+    Ok(())
+}
+
+/// Saves the Run in use by the Timer provided as a LiveSplit splits file
+/// (*.lss) to an asynchronous writer. The writer is flushed afterwards.
+#[cfg(feature = "async")]
+pub async fn save_timer_async<W>(timer: &Timer, writer: W) -> std::io::Result<()>
+where
+    W: futures_io::AsyncWrite + Unpin,
+{
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        Cow::Borrowed(timer.run())
+    } else {
+        Cow::Owned(timer.clone().into_run(true))
+    };
+    save_run_async(&run, writer).await
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss) to an asynchronous writer.
+/// The splits file is formatted in memory first, so the thread is never
+/// blocked while waiting for the writer. The writer is flushed afterwards.
+#[cfg(feature = "async")]
+pub async fn save_run_async<W>(run: &Run, mut writer: W) -> std::io::Result<()>
+where
+    W: futures_io::AsyncWrite + Unpin,
+{
+    let mut buf = String::new();
+    save_run(run, &mut buf).map_err(|_| std::io::Error::other("Couldn't save the run"))?;
+    super::write_all_async(&mut writer, buf.as_bytes()).await
+}
+
 /// Saves a Run as a LiveSplit splits file (*.lss). Use the `save_timer`
 /// function if the Run is in use by a timer in order to properly save the
 /// current attempt as well.
 pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
-    let writer = &mut Writer::new_with_default_header(writer)?;
+    save_run_with_options(run, writer, &Options::default())
+}
+
+/// Saves the Run in use by the Timer provided as a LiveSplit splits file
+/// (*.lss) that is formatted according to the options provided.
+pub fn save_timer_with_options<W: fmt::Write>(
+    timer: &Timer,
+    writer: W,
+    options: &Options,
+) -> fmt::Result {
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        timer.run()
+    } else {
+        &timer.clone().into_run(true)
+    };
+    save_run_with_options(run, writer, options)
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss) that is formatted according
+/// to the options provided. Use the `save_timer_with_options` function if the
+/// Run is in use by a timer in order to properly save the current attempt as
+/// well.
+pub fn save_run_with_options<W: fmt::Write>(
+    run: &Run,
+    writer: W,
+    options: &Options,
+) -> fmt::Result {
+    let icons: Icons<NoIconPaths> = if options.write_icons {
+        Icons::Embed
+    } else {
+        Icons::Omit
+    };
+    save(run, writer, options, icons)
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss) that refers to the icons by
+/// the paths of their image files instead of storing them. The `icon_path`
+/// function is called with the index of each segment that has an icon and
+/// the icon itself to determine the path of its image file. It's called with
+/// `None` as the index for the game icon. Icons without a path are not stored
+/// at all. The [`write_icons`](Options::write_icons) option is ignored. The
+/// icons are loaded again by
+/// [`parse_loading_icons`](crate::run::parser::livesplit::parse_loading_icons),
+/// while LiveSplit itself doesn't show them.
+pub fn save_run_with_icon_paths<W, F>(
+    run: &Run,
+    icon_path: F,
+    writer: W,
+    options: &Options,
+) -> fmt::Result
+where
+    W: fmt::Write,
+    F: FnMut(Option<usize>, &Image) -> Option<String>,
+{
+    save(run, writer, options, Icons::Paths(icon_path))
+}
+
+fn save<W, F>(run: &Run, writer: W, options: &Options, mut icons: Icons<F>) -> fmt::Result
+where
+    W: fmt::Write,
+    F: FnMut(Option<usize>, &Image) -> Option<String>,
+{
+    let writer = Writer::new_with_default_header(writer)?;
+    let writer = &mut match options.indentation {
+        Indentation::None => writer,
+        Indentation::Spaces(width) => writer.with_indentation(' ', width as usize),
+        Indentation::Tabs => writer.with_indentation('\t', 1),
+    };
+    let format = TimeFormat {
+        digits: options.time_digits.min(9),
+    };
+    let write_defaults = options.write_default_elements;
 
     let base64_buf = &mut Vec::new();
     let image_buf = &mut Cow::Borrowed(&LSS_IMAGE_HEADER[..]);
 
     writer.tag_with_content("Run", [("version", Text::new_escaped("1.8.0"))], |writer| {
-        image(writer, "GameIcon", run.game_icon(), base64_buf, image_buf)?;
+        icon(
+            writer,
+            "GameIcon",
+            None,
+            run.game_icon(),
+            &mut icons,
+            true,
+            base64_buf,
+            image_buf,
+        )?;
         writer.tag_with_text_content("GameName", NO_ATTRIBUTES, run.game_name())?;
         writer.tag_with_text_content("CategoryName", NO_ATTRIBUTES, run.category_name())?;
 
-        writer.tag_with_content("Metadata", NO_ATTRIBUTES, |writer| {
-            let metadata = run.metadata();
-            writer.empty_tag("Run", [("id", metadata.run_id())])?;
+        let metadata = run.metadata();
+        let has_speedrun_com_variables = metadata.speedrun_com_variables().next().is_some();
+        let has_custom_variables = metadata.custom_variables().any(|(_, var)| var.is_permanent);
+        let has_platform = !metadata.platform_name().is_empty() || metadata.uses_emulator();
+
+        if write_defaults
+            || !metadata.run_id().is_empty()
+            || has_platform
+            || !metadata.region_name().is_empty()
+            || has_speedrun_com_variables
+            || has_custom_variables
+        {
+            writer.tag_with_content("Metadata", NO_ATTRIBUTES, |writer| {
+                if write_defaults || !metadata.run_id().is_empty() {
+                    writer.empty_tag("Run", [("id", metadata.run_id())])?;
+                }
+                if write_defaults || has_platform {
+                    writer.tag_with_text_content(
+                        "Platform",
+                        [("usesEmulator", bool(metadata.uses_emulator()))],
+                        metadata.platform_name(),
+                    )?;
+                }
+                if write_defaults || !metadata.region_name().is_empty() {
+                    writer.tag_with_text_content(
+                        "Region",
+                        NO_ATTRIBUTES,
+                        metadata.region_name(),
+                    )?;
+                }
+                if write_defaults || has_speedrun_com_variables {
+                    scoped_iter(
+                        writer,
+                        "SpeedrunComVariables",
+                        metadata.speedrun_com_variables(),
+                        |writer, (name, value)| {
+                            writer.tag_with_text_content(
+                                "Variable",
+                                [("name", name)],
+                                value.as_str(),
+                            )
+                        },
+                    )?;
+                }
+                if write_defaults || has_custom_variables {
+                    scoped_iter(
+                        writer,
+                        "CustomVariables",
+                        metadata
+                            .custom_variables()
+                            .filter(|(_, var)| var.is_permanent),
+                        |writer, (name, var)| {
+                            writer.tag_with_text_content(
+                                "Variable",
+                                [("name", name)],
+                                var.value.as_str(),
+                            )
+                        },
+                    )?;
+                }
+                Ok::<_, fmt::Error>(())
+            })?;
+        }
+
+        if write_defaults || run.linked_layout().is_some() {
             writer.tag_with_text_content(
-                "Platform",
-                [("usesEmulator", bool(metadata.uses_emulator()))],
-                metadata.platform_name(),
-            )?;
-            writer.tag_with_text_content("Region", NO_ATTRIBUTES, metadata.region_name())?;
-            scoped_iter(
-                writer,
-                "SpeedrunComVariables",
-                metadata.speedrun_com_variables(),
-                |writer, (name, value)| {
-                    writer.tag_with_text_content("Variable", [("name", name)], value.as_str())
+                "LayoutPath",
+                NO_ATTRIBUTES,
+                match run.linked_layout() {
+                    Some(LinkedLayout::Default) => "?default",
+                    Some(LinkedLayout::Path(path)) => path,
+                    None => "",
                 },
             )?;
-            scoped_iter(
-                writer,
-                "CustomVariables",
-                metadata
-                    .custom_variables()
-                    .filter(|(_, var)| var.is_permanent),
-                |writer, (name, var)| {
-                    writer.tag_with_text_content("Variable", [("name", name)], var.value.as_str())
-                },
-            )
-        })?;
-
-        writer.tag_with_text_content(
-            "LayoutPath",
-            NO_ATTRIBUTES,
-            match run.linked_layout() {
-                Some(LinkedLayout::Default) => "?default",
-                Some(LinkedLayout::Path(path)) => path,
-                None => "",
-            },
-        )?;
+        }
 
-        writer.tag_with_text_content(
-            "Offset",
-            NO_ATTRIBUTES,
-            DisplayAlreadyEscaped(Complete.format(run.offset())),
-        )?;
+        writer.tag_with_text_content("Offset", NO_ATTRIBUTES, format.format(run.offset()))?;
         writer.tag_with_text_content(
             "AttemptCount",
             NO_ATTRIBUTES,
             DisplayAlreadyEscaped(run.attempt_count()),
         )?;
 
-        scoped_iter(
-            writer,
-            "AttemptHistory",
-            run.attempt_history(),
-            |writer, attempt| {
-                writer.tag("Attempt", |mut tag| {
-                    tag.attribute("id", DisplayAlreadyEscaped(attempt.index()))?;
-
-                    if let Some(started) = attempt.started() {
-                        date(&mut tag, "started", started.time)?;
-                        tag.attribute("isStartedSynced", bool(started.synced_with_atomic_clock))?;
-                    }
-                    if let Some(ended) = attempt.ended() {
-                        date(&mut tag, "ended", ended.time)?;
-                        tag.attribute("isEndedSynced", bool(ended.synced_with_atomic_clock))?;
-                    }
-
-                    let is_empty = attempt.time().real_time.is_none()
-                        && attempt.time().game_time.is_none()
-                        && attempt.pause_time().is_none();
-
-                    if !is_empty {
-                        tag.content(|writer| {
-                            time_inner(writer, attempt.time())?;
-
-                            if let Some(pause_time) = attempt.pause_time() {
-                                writer.tag_with_text_content(
-                                    "PauseTime",
-                                    NO_ATTRIBUTES,
-                                    DisplayAlreadyEscaped(Complete.format(pause_time)),
-                                )?;
-                            }
-
-                            Ok(())
-                        })?;
-                    }
-
-                    Ok(())
-                })
-            },
-        )?;
+        if write_defaults || !run.attempt_history().is_empty() {
+            scoped_iter(
+                writer,
+                "AttemptHistory",
+                run.attempt_history(),
+                |writer, attempt| {
+                    writer.tag("Attempt", |mut tag| {
+                        tag.attribute("id", DisplayAlreadyEscaped(attempt.index()))?;
+
+                        if let Some(started) = attempt.started() {
+                            date(&mut tag, "started", started.time)?;
+                            tag.attribute(
+                                "isStartedSynced",
+                                bool(started.synced_with_atomic_clock),
+                            )?;
+                        }
+                        if let Some(ended) = attempt.ended() {
+                            date(&mut tag, "ended", ended.time)?;
+                            tag.attribute("isEndedSynced", bool(ended.synced_with_atomic_clock))?;
+                        }
+
+                        let is_empty = attempt.time().real_time.is_none()
+                            && attempt.time().game_time.is_none()
+                            && attempt.pause_time().is_none()
+                            && attempt.pause_count().is_none()
+                            && attempt.longest_pause().is_none()
+                            && attempt.actions().is_empty();
+
+                        if !is_empty {
+                            tag.content(|writer| {
+                                time_inner(writer, attempt.time(), format)?;
+
+                                if let Some(pause_time) = attempt.pause_time() {
+                                    writer.tag_with_text_content(
+                                        "PauseTime",
+                                        NO_ATTRIBUTES,
+                                        format.format(pause_time),
+                                    )?;
+                                }
+
+                                if let Some(pause_count) = attempt.pause_count() {
+                                    writer.tag_with_text_content(
+                                        "PauseCount",
+                                        NO_ATTRIBUTES,
+                                        DisplayAlreadyEscaped(pause_count),
+                                    )?;
+                                }
+
+                                if let Some(longest_pause) = attempt.longest_pause() {
+                                    writer.tag_with_text_content(
+                                        "LongestPause",
+                                        NO_ATTRIBUTES,
+                                        format.format(longest_pause),
+                                    )?;
+                                }
+
+                                if !attempt.actions().is_empty() {
+                                    scoped_iter(
+                                        writer,
+                                        "Actions",
+                                        attempt.actions(),
+                                        |writer, action| {
+                                            writer.tag("Action", |mut tag| {
+                                                tag.attribute(
+                                                    "type",
+                                                    Text::new_escaped(action.kind.name()),
+                                                )?;
+                                                tag.attribute("time", format.format(action.time))?;
+                                                if let Some(value) = action.kind.value() {
+                                                    tag.attribute("value", format.format(value))?;
+                                                }
+                                                Ok(())
+                                            })
+                                        },
+                                    )?;
+                                }
+
+                                Ok(())
+                            })?;
+                        }
+
+                        Ok(())
+                    })
+                },
+            )?;
+        }
 
-        scoped_iter(writer, "Segments", run.segments(), |writer, segment| {
+        let segments = run.segments().iter().enumerate();
+        scoped_iter(writer, "Segments", segments, |writer, (index, segment)| {
             writer.tag_with_content("Segment", NO_ATTRIBUTES, |writer| {
                 writer.tag_with_text_content("Name", NO_ATTRIBUTES, segment.name())?;
-                image(writer, "Icon", segment.icon(), base64_buf, image_buf)?;
+                icon(
+                    writer,
+                    "Icon",
+                    Some(index),
+                    segment.icon(),
+                    &mut icons,
+                    write_defaults,
+                    base64_buf,
+                    image_buf,
+                )?;
 
                 scoped_iter(
                     writer,
@@ -283,33 +748,146 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                     |writer, comparison| {
                         writer.tag("SplitTime", |mut tag| {
                             tag.attribute("name", comparison.as_str())?;
-                            time(tag, segment.comparison(comparison))
+                            time(tag, segment.comparison(comparison), format)
                         })
                     },
                 )?;
 
-                writer.tag("BestSegmentTime", |tag| {
-                    time(tag, segment.best_segment_time())
-                })?;
+                let best_segment_time = segment.best_segment_time();
+                if write_defaults
+                    || best_segment_time.real_time.is_some()
+                    || best_segment_time.game_time.is_some()
+                {
+                    writer.tag("BestSegmentTime", |tag| {
+                        time(tag, best_segment_time, format)
+                    })?;
+                }
 
-                scoped_iter(
-                    writer,
-                    "SegmentHistory",
-                    segment.segment_history(),
-                    |writer, &(index, history_time)| {
-                        writer.tag("Time", |mut tag| {
-                            tag.attribute("id", DisplayAlreadyEscaped(index))?;
-                            time(tag, history_time)
-                        })
-                    },
-                )
+                if write_defaults || segment.segment_history().iter().next().is_some() {
+                    scoped_iter(
+                        writer,
+                        "SegmentHistory",
+                        segment.segment_history(),
+                        |writer, &(index, history_time)| {
+                            writer.tag("Time", |mut tag| {
+                                tag.attribute("id", DisplayAlreadyEscaped(index))?;
+                                time(tag, history_time, format)
+                            })
+                        },
+                    )?;
+                }
+
+                Ok::<_, fmt::Error>(())
             })
         })?;
 
-        writer.tag_with_text_content(
-            "AutoSplitterSettings",
-            NO_ATTRIBUTES,
-            Text::new_escaped(run.auto_splitter_settings()),
-        )
-    })
+        if write_defaults || !run.auto_splitter_settings().is_empty() {
+            writer.tag_with_text_content(
+                "AutoSplitterSettings",
+                NO_ATTRIBUTES,
+                Text::new_escaped(run.auto_splitter_settings()),
+            )?;
+        }
+
+        // LiveSplit ignores the embedded layout, but keeps using the linked
+        // layout.
+        if !run.embedded_layout().is_empty() {
+            writer.encoded_element(Text::new_escaped(run.embedded_layout()))?;
+        }
+
+        Ok::<_, fmt::Error>(())
+    })?;
+
+    // Text editors and version control systems expect the last line to be
+    // terminated.
+    if writer.is_indented() {
+        writer.text("\n")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "async")]
+    #[test]
+    fn save_async_yields_while_waiting() {
+        use super::{save_run, save_run_async};
+        use std::{
+            future::Future,
+            io,
+            pin::{pin, Pin},
+            sync::Arc,
+            task::{Context, Poll, Wake, Waker},
+        };
+
+        /// Accepts a single byte at a time, having to wait for each one.
+        struct Trickle {
+            data: Vec<u8>,
+            ready: bool,
+            flushed: bool,
+        }
+
+        impl futures_io::AsyncWrite for Trickle {
+            fn poll_write(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<io::Result<usize>> {
+                let this = self.get_mut();
+                if !this.ready {
+                    this.ready = true;
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                this.ready = false;
+                this.data.extend_from_slice(&buf[..buf.len().min(1)]);
+                Poll::Ready(Ok(buf.len().min(1)))
+            }
+
+            fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                self.get_mut().flushed = true;
+                Poll::Ready(Ok(()))
+            }
+
+            fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let run = crate::run::parser::livesplit::parse(include_str!(
+            "../../../tests/run_files/livesplit1.6.lss"
+        ))
+        .unwrap();
+        let mut expected = String::new();
+        save_run(&run, &mut expected).unwrap();
+
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut writer = Trickle {
+            data: Vec::new(),
+            ready: false,
+            flushed: false,
+        };
+        let mut pending = 0;
+        {
+            let mut future = pin!(save_run_async(&run, &mut writer));
+            loop {
+                match future.as_mut().poll(&mut cx) {
+                    Poll::Ready(result) => break result.unwrap(),
+                    Poll::Pending => pending += 1,
+                }
+            }
+        }
+
+        assert_eq!(pending, expected.len());
+        assert!(writer.flushed);
+        assert_eq!(writer.data, expected.as_bytes());
+    }
 }
@@ -0,0 +1,180 @@
+//! The LiveSplit One Saver saves Runs as LiveSplit One's JSON splits files. The
+//! format is documented in the corresponding
+//! [parser](crate::run::parser::livesplit_one).
+//!
+//! # Examples
+//!
+//! Using the LiveSplit One Saver to save a Run as a JSON splits file.
+//!
+//! ```
+//! use livesplit_core::run::{parser, saver};
+//! use livesplit_core::{Run, Segment};
+//!
+//! // Create a run object that we can use.
+//! let mut run = Run::new();
+//! run.set_game_name("Super Mario Odyssey");
+//! run.set_category_name("Any%");
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! // Save the run as a JSON splits file.
+//! let mut json = String::new();
+//! saver::livesplit_one::save_run(&run, &mut json).expect("Couldn't save the splits file");
+//!
+//! # #[cfg(feature = "parser-livesplit-one")]
+//! # {
+//! // The run can be parsed again without losing any information.
+//! let parsed = parser::livesplit_one::parse(&json).expect("Couldn't parse the splits file");
+//! assert_eq!(parsed.game_name(), "Super Mario Odyssey");
+//! # }
+//! ```
+
+use crate::{
+    run::format::livesplit_one::{
+        Format, JsonAction, JsonAttempt, JsonComparison, JsonSegment, JsonSegmentHistoryElement,
+        JsonTimeSpan, Metadata, SplitsFile, Variable, VERSION,
+    },
+    Run, Timer, TimerPhase,
+};
+use core::fmt;
+
+/// Saves the Run in use by the Timer provided as a LiveSplit One JSON splits
+/// file.
+pub fn save_timer<W: fmt::Write>(timer: &Timer, writer: W) -> fmt::Result {
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        timer.run()
+    } else {
+        &timer.clone().into_run(true)
+    };
+    save_run(run, writer)
+}
+
+/// Saves the Run in use by the Timer provided as a LiveSplit One JSON splits
+/// file to an asynchronous writer. The writer is flushed afterwards.
+#[cfg(feature = "async")]
+pub async fn save_timer_async<W>(timer: &Timer, writer: W) -> std::io::Result<()>
+where
+    W: futures_io::AsyncWrite + Unpin,
+{
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        alloc::borrow::Cow::Borrowed(timer.run())
+    } else {
+        alloc::borrow::Cow::Owned(timer.clone().into_run(true))
+    };
+    save_run_async(&run, writer).await
+}
+
+/// Saves a Run as a LiveSplit One JSON splits file to an asynchronous writer.
+/// The splits file is formatted in memory first, so the thread is never
+/// blocked while waiting for the writer. The writer is flushed afterwards.
+#[cfg(feature = "async")]
+pub async fn save_run_async<W>(run: &Run, mut writer: W) -> std::io::Result<()>
+where
+    W: futures_io::AsyncWrite + Unpin,
+{
+    let mut buf = String::new();
+    save_run(run, &mut buf).map_err(|_| std::io::Error::other("Couldn't save the run"))?;
+    super::write_all_async(&mut writer, buf.as_bytes()).await
+}
+
+/// Saves a Run as a LiveSplit One JSON splits file. Use the `save_timer`
+/// function if the Run is in use by a timer in order to properly save the
+/// current attempt as well.
+pub fn save_run<W: fmt::Write>(run: &Run, mut writer: W) -> fmt::Result {
+    let metadata = run.metadata();
+
+    let file = SplitsFile {
+        format: Format::LiveSplitOne,
+        version: VERSION,
+        game_name: run.game_name().into(),
+        game_icon: run.game_icon().clone(),
+        category_name: run.category_name().into(),
+        offset: JsonTimeSpan(run.offset()),
+        attempt_count: run.attempt_count(),
+        metadata: Metadata {
+            run_id: metadata.run_id().into(),
+            platform_name: metadata.platform_name().into(),
+            uses_emulator: metadata.uses_emulator(),
+            region_name: metadata.region_name().into(),
+            speedrun_com_variables: metadata
+                .speedrun_com_variables()
+                .map(|(name, value)| Variable {
+                    name: name.into(),
+                    value: value.as_str().into(),
+                })
+                .collect(),
+            custom_variables: metadata
+                .custom_variables()
+                .filter(|(_, var)| var.is_permanent)
+                .map(|(name, var)| Variable {
+                    name: name.into(),
+                    value: var.value.as_str().into(),
+                })
+                .collect(),
+        },
+        linked_layout: run.linked_layout().cloned(),
+        custom_comparisons: run
+            .custom_comparisons()
+            .iter()
+            .map(|c| c.as_str().into())
+            .collect(),
+        attempt_history: run
+            .attempt_history()
+            .iter()
+            .map(|attempt| JsonAttempt {
+                index: attempt.index(),
+                time: attempt.time().into(),
+                started: attempt.started().map(Into::into),
+                ended: attempt.ended().map(Into::into),
+                pause_time: attempt.pause_time().map(JsonTimeSpan),
+                pause_count: attempt.pause_count(),
+                longest_pause: attempt.longest_pause().map(JsonTimeSpan),
+                actions: attempt
+                    .actions()
+                    .iter()
+                    .map(|action| JsonAction {
+                        kind: action.kind.name().into(),
+                        time: JsonTimeSpan(action.time),
+                        value: action.kind.value().map(JsonTimeSpan),
+                    })
+                    .collect(),
+            })
+            .collect(),
+        segments: run
+            .segments()
+            .iter()
+            .map(|segment| JsonSegment {
+                name: segment.name().into(),
+                icon: segment.icon().clone(),
+                comparisons: run
+                    .custom_comparisons()
+                    .iter()
+                    .map(|comparison| JsonComparison {
+                        name: comparison.as_str().into(),
+                        time: segment.comparison(comparison).into(),
+                    })
+                    .collect(),
+                best_segment_time: segment.best_segment_time().into(),
+                segment_history: segment
+                    .segment_history()
+                    .iter()
+                    .map(|&(index, time)| JsonSegmentHistoryElement {
+                        index,
+                        time: time.into(),
+                    })
+                    .collect(),
+                variables: segment
+                    .variables()
+                    .iter()
+                    .map(|(name, value)| Variable {
+                        name: name.as_str().into(),
+                        value: value.as_str().into(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+        auto_splitter_settings: run.auto_splitter_settings().into(),
+    };
+
+    let json = serde_json::to_string(&file).map_err(|_| fmt::Error)?;
+    writer.write_str(&json)
+}
@@ -24,5 +24,46 @@
 //! // Save the splits file as a LiveSplit splits file.
 //! livesplit::save_run(&run, IoWrite(writer)).expect("Couldn't save the splits file");
 //! ```
+//!
+//! For exchanging runs with web tools and scripts, the [`json`] saver stores
+//! them in a versioned JSON format that is documented in the corresponding
+//! [parser](crate::run::parser::livesplit_one). Unlike the serde
+//! implementations of the individual types, the format stays stable across
+//! versions of livesplit-core.
+//!
+//! With the `async` feature, the LiveSplit and JSON savers can also write to
+//! an asynchronous writer, such as a socket on a server. The splits file is
+//! formatted in memory first and then written without blocking the thread.
 
+pub mod attempt_history_csv;
+pub mod binary;
+pub mod journal;
 pub mod livesplit;
+pub mod livesplit_one;
+pub mod urn;
+pub mod wsplit;
+
+/// The JSON Saver saves Runs in a versioned and documented JSON format meant
+/// for interchange with web tools and scripts. This is the splits file format
+/// of LiveSplit One, so this is the same as the [`livesplit_one`] saver.
+pub use self::livesplit_one as json;
+
+/// Writes the whole buffer to the asynchronous writer and flushes it.
+#[cfg(feature = "async")]
+async fn write_all_async<W>(writer: &mut W, mut buf: &[u8]) -> std::io::Result<()>
+where
+    W: futures_io::AsyncWrite + Unpin,
+{
+    use core::{future::poll_fn, pin::Pin};
+    use std::io::ErrorKind;
+
+    while !buf.is_empty() {
+        match poll_fn(|cx| Pin::new(&mut *writer).poll_write(cx, buf)).await {
+            Ok(0) => return Err(ErrorKind::WriteZero.into()),
+            Ok(len) => buf = &buf[len..],
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    poll_fn(|cx| Pin::new(&mut *writer).poll_flush(cx)).await
+}
@@ -0,0 +1,150 @@
+//! The Urn Saver saves Runs as Urn splits files. Urn only stores the real time
+//! of the personal best, the best segments and the best split times. All the
+//! times are stored with microsecond precision.
+//!
+//! # Examples
+//!
+//! Using the Urn Saver to save a Run as an Urn splits file.
+//!
+//! ```
+//! use livesplit_core::run::{parser, saver};
+//! use livesplit_core::{Run, Segment};
+//!
+//! // Create a run object that we can use.
+//! let mut run = Run::new();
+//! run.set_category_name("Any%");
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! // Save the run as an Urn splits file.
+//! let mut json = String::new();
+//! saver::urn::save_run(&run, &mut json).expect("Couldn't save the splits file");
+//!
+//! # #[cfg(feature = "parser-urn")]
+//! # {
+//! let parsed = parser::urn::parse(&json).expect("Couldn't parse the splits file");
+//! assert_eq!(parsed.category_name(), "Any%");
+//! # }
+//! ```
+
+use crate::{platform::prelude::*, Run, TimeSpan, Timer, TimerPhase};
+use core::fmt;
+use serde::Serializer;
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+struct Splits<'a> {
+    title: &'a str,
+    attempt_count: u32,
+    start_delay: UrnTime,
+    splits: Vec<Split<'a>>,
+}
+
+#[derive(Serialize)]
+struct Split<'a> {
+    title: &'a str,
+    time: UrnTime,
+    best_time: UrnTime,
+    best_segment: UrnTime,
+}
+
+/// A time that is stored the way Urn formats it. Empty times are stored as
+/// zero.
+struct UrnTime(Option<TimeSpan>);
+
+impl serde::Serialize for UrnTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (seconds, nanos) = self
+            .0
+            .unwrap_or_default()
+            .to_seconds_and_subsec_nanoseconds();
+        let sign = if seconds < 0 || nanos < 0 { "-" } else { "" };
+        let micros = nanos.unsigned_abs() / 1_000;
+        let seconds = seconds.unsigned_abs();
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+        if hours > 0 {
+            serializer.collect_str(&format_args!(
+                "{sign}{hours}:{minutes:02}:{seconds:02}.{micros:06}"
+            ))
+        } else if minutes > 0 {
+            serializer.collect_str(&format_args!("{sign}{minutes}:{seconds:02}.{micros:06}"))
+        } else {
+            serializer.collect_str(&format_args!("{sign}{seconds}.{micros:06}"))
+        }
+    }
+}
+
+/// Calculates the best real time split times of the run, just like the Best
+/// Split Times comparison does.
+fn best_split_times(run: &Run) -> Vec<Option<TimeSpan>> {
+    let segments = run.segments();
+
+    let mut best_split_times: Vec<_> = segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            if index == 0 {
+                segment.best_segment_time().real_time
+            } else {
+                segment.personal_best_split_time().real_time
+            }
+        })
+        .collect();
+
+    for attempt in run.attempt_history() {
+        let mut total_time = TimeSpan::zero();
+
+        for (segment, best) in segments.iter().zip(&mut best_split_times) {
+            let Some(time) = segment.segment_history().get(attempt.index()) else {
+                break;
+            };
+            if let Some(time) = time.real_time {
+                total_time += time;
+                if best.is_none_or(|best| total_time < best) {
+                    *best = Some(total_time);
+                }
+            }
+        }
+    }
+
+    best_split_times
+}
+
+/// Saves the Run in use by the Timer provided as an Urn splits file.
+pub fn save_timer<W: fmt::Write>(timer: &Timer, writer: W) -> fmt::Result {
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        timer.run()
+    } else {
+        &timer.clone().into_run(true)
+    };
+    save_run(run, writer)
+}
+
+/// Saves a Run as an Urn splits file. Use the `save_timer` function if the Run
+/// is in use by a timer in order to properly save the current attempt as well.
+pub fn save_run<W: fmt::Write>(run: &Run, mut writer: W) -> fmt::Result {
+    let title = run.extended_name(false);
+
+    let splits = Splits {
+        title: &title,
+        attempt_count: run.attempt_count(),
+        start_delay: UrnTime(Some(-run.offset())),
+        splits: run
+            .segments()
+            .iter()
+            .zip(best_split_times(run))
+            .map(|(segment, best_time)| Split {
+                title: segment.name(),
+                time: UrnTime(segment.personal_best_split_time().real_time),
+                best_time: UrnTime(best_time),
+                best_segment: UrnTime(segment.best_segment_time().real_time),
+            })
+            .collect(),
+    };
+
+    let json = serde_json::to_string_pretty(&splits).map_err(|_| fmt::Error)?;
+    writer.write_str(&json)
+}
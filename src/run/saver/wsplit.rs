@@ -0,0 +1,104 @@
+//! The WSplit Saver saves Runs as WSplit splits files. WSplit only stores the
+//! real time of the personal best, the best segments and the "Old Run"
+//! comparison. Icons are referred to by the paths of their image files, so
+//! they can only be saved if the paths are provided.
+//!
+//! # Examples
+//!
+//! Using the WSplit Saver to save a Run as a WSplit splits file.
+//!
+//! ```
+//! use livesplit_core::run::{parser, saver};
+//! use livesplit_core::{Run, Segment};
+//!
+//! // Create a run object that we can use.
+//! let mut run = Run::new();
+//! run.set_category_name("Any%");
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! // Save the run as a WSplit splits file.
+//! let mut text = String::new();
+//! saver::wsplit::save_run(&run, &mut text).expect("Couldn't save the splits file");
+//!
+//! # #[cfg(feature = "parser-wsplit")]
+//! # {
+//! let parsed = parser::wsplit::parse(&text, false).expect("Couldn't parse the splits file");
+//! assert_eq!(parsed.category_name(), "Any%");
+//! # }
+//! ```
+
+use crate::{platform::prelude::*, Run, Segment, TimeSpan, Timer, TimerPhase};
+use core::fmt::{self, Write};
+
+fn seconds(time: Option<TimeSpan>) -> f64 {
+    time.map_or(0.0, |time| time.total_seconds())
+}
+
+/// Saves the Run in use by the Timer provided as a WSplit splits file.
+pub fn save_timer<W: Write>(timer: &Timer, writer: W) -> fmt::Result {
+    let run = if timer.current_phase() == TimerPhase::NotRunning {
+        timer.run()
+    } else {
+        &timer.clone().into_run(true)
+    };
+    save_run(run, writer)
+}
+
+/// Saves a Run as a WSplit splits file without any icons. Use the `save_timer`
+/// function if the Run is in use by a timer in order to properly save the
+/// current attempt as well.
+pub fn save_run<W: Write>(run: &Run, writer: W) -> fmt::Result {
+    save_run_with_icons(run, |_, _| None, writer)
+}
+
+/// Saves a Run as a WSplit splits file. As WSplit refers to the icons by the
+/// paths of their image files, the `icon_path` function is called with the
+/// index of each segment and the segment itself to determine the path of its
+/// icon. Segments without a path don't have an icon. The commas in the names of
+/// the segments are removed, as WSplit can't store them.
+pub fn save_run_with_icons<W, F>(run: &Run, mut icon_path: F, mut writer: W) -> fmt::Result
+where
+    W: Write,
+    F: FnMut(usize, &Segment) -> Option<String>,
+{
+    writeln!(writer, "Title={}", run.extended_name(false))?;
+    writeln!(writer, "Attempts={}", run.attempt_count())?;
+    writeln!(writer, "Offset={}", (-run.offset()).total_milliseconds())?;
+
+    let icon_paths = run
+        .segments()
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| icon_path(index, segment))
+        .collect::<Vec<_>>();
+
+    if icon_paths.iter().any(Option::is_some) {
+        writer.write_str("Icons=")?;
+        for (index, path) in icon_paths.iter().enumerate() {
+            if index != 0 {
+                writer.write_char(',')?;
+            }
+            write!(writer, "\"{}\"", path.as_deref().unwrap_or_default())?;
+        }
+        writer.write_char('\n')?;
+    }
+
+    if let Some(goal) = run.metadata().custom_variable_value("Goal") {
+        writeln!(writer, "Goal={goal}")?;
+    }
+
+    for segment in run.segments() {
+        for c in segment.name().chars().filter(|&c| c != ',') {
+            writer.write_char(c)?;
+        }
+        writeln!(
+            writer,
+            ",{},{},{}",
+            seconds(segment.comparison("Old Run").real_time),
+            seconds(segment.personal_best_split_time().real_time),
+            seconds(segment.best_segment_time().real_time),
+        )?;
+    }
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{
+    run::FixApplied,
     util::tests_helper::{create_timer, run_with_splits},
-    Timer,
+    Run, Segment, Time, TimeSpan, Timer, TimingMethod,
 };
 
 #[test]
@@ -25,6 +26,81 @@ fn reattaches_unattached_segment_history_elements_by_using_negative_ids() {
     assert_eq!(segments[1].segment_history().try_get_max_index(), Some(1));
 }
 
+#[test]
+fn reports_reattached_segment_history_elements() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[3.0, 6.0]);
+    run_with_splits(&mut timer, &[2.0, 4.0]);
+    let mut run = timer.into_run(true);
+    run.attempt_history.pop().unwrap();
+
+    let mut fixes = Vec::new();
+    run.fix_splits_reporting(|fix| fixes.push(fix));
+
+    assert_eq!(
+        fixes,
+        [FixApplied::SegmentHistoryReattached { from: 2, to: 0 }]
+    );
+}
+
+#[test]
+fn reports_fixed_times() {
+    let seconds = |seconds| Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)));
+
+    let mut run = Run::new();
+    let mut segment = Segment::new("A");
+    segment.set_personal_best_split_time(seconds(5.0));
+    segment.set_best_segment_time(seconds(6.0));
+    segment.segment_history_mut().insert(1, seconds(4.0));
+    run.push_segment(segment);
+    let mut segment = Segment::new("B");
+    segment.set_personal_best_split_time(seconds(4.0));
+    segment.set_best_segment_time(seconds(-1.0));
+    run.push_segment(segment);
+    run.add_attempt(Time::new(), None, None, None);
+
+    let mut fixes = Vec::new();
+    run.fix_splits_reporting(|fix| fixes.push(fix));
+
+    assert_eq!(
+        fixes,
+        [
+            FixApplied::NegativeBestSegmentTimeRemoved {
+                segment: 1,
+                method: TimingMethod::RealTime,
+                time: TimeSpan::from_seconds(-1.0),
+            },
+            FixApplied::BestSegmentTimeLowered {
+                segment: 0,
+                method: TimingMethod::RealTime,
+                from: Some(TimeSpan::from_seconds(6.0)),
+                to: TimeSpan::from_seconds(5.0),
+            },
+            FixApplied::ComparisonTimeClamped {
+                segment: 1,
+                comparison: "Personal Best".into(),
+                method: TimingMethod::RealTime,
+                from: TimeSpan::from_seconds(4.0),
+                to: TimeSpan::from_seconds(5.0),
+            },
+            FixApplied::BestSegmentTimeLowered {
+                segment: 1,
+                method: TimingMethod::RealTime,
+                from: None,
+                to: TimeSpan::zero(),
+            },
+            FixApplied::SegmentTimeClamped {
+                segment: 0,
+                index: 1,
+                method: TimingMethod::RealTime,
+                from: TimeSpan::from_seconds(4.0),
+                to: TimeSpan::from_seconds(5.0),
+            },
+        ]
+    );
+    assert_eq!(run.segment(1).personal_best_split_time(), seconds(5.0));
+}
+
 // The below tests should be in crate::timing::timer::tests, but we ended up
 // having to put them here due to run.attempt_history being private.
 
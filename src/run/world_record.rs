@@ -0,0 +1,14 @@
+use crate::{platform::prelude::*, Time};
+
+/// A `WorldRecord` describes the fastest run on the leaderboard of a specific
+/// game and category. It can be retrieved from speedrun.com via the
+/// `networking` module or specified manually and is shown by the World Record
+/// Component.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorldRecord {
+    /// The time of the world record for all the timing methods that the
+    /// leaderboard tracks.
+    pub time: Time,
+    /// The names of the runners who achieved the world record.
+    pub runners: Vec<String>,
+}
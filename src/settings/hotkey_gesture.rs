@@ -0,0 +1,17 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Describes how a hotkey needs to be pressed for its action to be triggered.
+/// Requiring more than a single press protects actions such as resetting from
+/// being triggered by accident.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum HotkeyGesture {
+    /// The action is triggered as soon as the hotkey is pressed.
+    #[default]
+    Press,
+    /// The action is triggered when the hotkey is released after it was held
+    /// down for at least the hold duration.
+    Hold,
+    /// The action is triggered when the hotkey is pressed a second time within
+    /// the double press interval.
+    DoublePress,
+}
@@ -15,7 +15,7 @@ pub trait HasImageId {
 
 impl HasImageId for Image {
     fn image_id(&self) -> &ImageId {
-        &self.id
+        self.id()
     }
 }
 
@@ -19,18 +19,31 @@ pub use image_id::ImageId;
 
 /// Images can be used to store segment and game icons. Each image object comes
 /// with a strong hash to quickly compare images. There's no specific image
-/// format you need to use for the images.
+/// format you need to use for the images. The decoding of an image can also be
+/// deferred until it is accessed for the first time.
 #[derive(Clone)]
 pub struct Image {
     data: Option<Arc<[u8]>>,
     id: ImageId,
+    #[cfg(feature = "std")]
+    deferred: Option<Arc<Deferred>>,
+}
+
+/// The encoded data of an image whose decoding is deferred until it is
+/// accessed for the first time.
+#[cfg(feature = "std")]
+struct Deferred {
+    encoded: Box<[u8]>,
+    decode: fn(&[u8]) -> Vec<u8>,
+    max_image_size: u32,
+    image: std::sync::OnceLock<Image>,
 }
 
 impl fmt::Debug for Image {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Image")
             .field("is_empty", &self.is_empty())
-            .field("id", &self.id)
+            .field("id", self.id())
             .finish()
     }
 }
@@ -38,7 +51,7 @@ impl fmt::Debug for Image {
 impl Deref for Image {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
-        self.data.as_deref().unwrap_or_default()
+        self.loaded().data.as_deref().unwrap_or_default()
     }
 }
 
@@ -115,7 +128,7 @@ impl<'de> Visitor<'de> for ImageVisitor {
 
 impl PartialEq for Image {
     fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+        self.id() == other.id()
     }
 }
 
@@ -135,13 +148,17 @@ impl Image {
     pub const EMPTY: &'static Self = &Self {
         data: None,
         id: *ImageId::EMPTY,
+        #[cfg(feature = "std")]
+        deferred: None,
     };
 
-    fn new_inner(data: Arc<[u8]>) -> Self {
+    pub(crate) fn new_inner(data: Arc<[u8]>) -> Self {
         let hash = Sha256::digest(&*data);
         Self {
             data: Some(data),
             id: ImageId(hash.as_slice().try_into().unwrap()),
+            #[cfg(feature = "std")]
+            deferred: None,
         }
     }
 
@@ -158,6 +175,55 @@ impl Image {
         Self::new_inner(data)
     }
 
+    /// Creates a new image from encoded image data, such as base64 encoded
+    /// data, without decoding it yet. The `decode` function turns the encoded
+    /// data into the actual image data, once the image's data or its ID are
+    /// accessed for the first time. If the encoded data can't be decoded, the
+    /// function is expected to return no data, resulting in an empty image.
+    #[cfg(feature = "std")]
+    pub fn new_deferred(
+        encoded: Box<[u8]>,
+        decode: fn(&[u8]) -> Vec<u8>,
+        max_image_size: u32,
+    ) -> Self {
+        Self {
+            data: None,
+            id: *ImageId::EMPTY,
+            deferred: Some(Arc::new(Deferred {
+                encoded,
+                decode,
+                max_image_size,
+                image: std::sync::OnceLock::new(),
+            })),
+        }
+    }
+
+    /// Returns the image that holds the decoded data, decoding it first if
+    /// that has been deferred.
+    fn loaded(&self) -> &Self {
+        #[cfg(feature = "std")]
+        if let Some(deferred) = &self.deferred {
+            return deferred.image.get_or_init(|| {
+                Self::new(
+                    (deferred.decode)(&deferred.encoded).into(),
+                    deferred.max_image_size,
+                )
+            });
+        }
+        self
+    }
+
+    /// Checks whether the image's data has been decoded already. This is only
+    /// not the case for images created with [`Image::new_deferred`] that
+    /// haven't been accessed yet.
+    pub fn is_decoded(&self) -> bool {
+        #[cfg(feature = "std")]
+        if let Some(deferred) = &self.deferred {
+            return deferred.image.get().is_some();
+        }
+        true
+    }
+
     /// Loads an image from the file system. You need to provide a buffer used
     /// for temporarily storing the image's data.
     #[cfg(feature = "std")]
@@ -182,10 +248,11 @@ impl Image {
     }
 
     /// Accesses the image's ID. This is a unique identifier for the image. It
-    /// is implemented via a SHA-256 hash.
+    /// is implemented via a SHA-256 hash. If the decoding of the image has
+    /// been deferred, the image gets decoded in order to determine its ID.
     #[inline]
-    pub const fn id(&self) -> &ImageId {
-        &self.id
+    pub fn id(&self) -> &ImageId {
+        &self.loaded().id
     }
 
     /// Checks if the image data is empty.
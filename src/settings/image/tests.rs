@@ -5,3 +5,16 @@ fn serializes_to_json_as_data_url() {
     let json = serde_json::to_string(&Image::new([1, 2, 3].into(), Image::ICON)).unwrap();
     assert_eq!(r#""AQID""#, json);
 }
+
+#[test]
+fn deferred_decoding() {
+    let image = Image::new_deferred([3, 2, 1].into(), |data| data.to_vec(), Image::ICON);
+    let copy = image.clone();
+    assert!(!image.is_decoded());
+    assert_eq!(image, Image::new([3, 2, 1].into(), Image::ICON));
+    assert!(copy.is_decoded());
+    assert_eq!(copy.data(), [3, 2, 1]);
+
+    let image = Image::new_deferred([3, 2, 1].into(), |_| Vec::new(), Image::ICON);
+    assert!(image.is_empty());
+}
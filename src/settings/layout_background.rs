@@ -7,14 +7,14 @@ use serde_derive::{Deserialize, Serialize};
 pub enum LayoutBackground<I = Image> {
     /// A gradient that describes the background coloration.
     Gradient(Gradient),
-    /// An image that is stretched to fill the background. The stretch is meant
-    /// to preserve the aspect ratio of the image, but always fill the full
-    /// background.
+    /// An image that is fit into the background according to its
+    /// [`BackgroundImageFit`].
     Image(BackgroundImage<I>),
 }
 
-/// An image that is stretched to fill the background. The stretch is meant to
-/// preserve the aspect ratio of the image, but always fill the full background.
+/// An image that is fit into the background according to its
+/// [`BackgroundImageFit`]. By default the image is stretched in a way that
+/// preserves its aspect ratio, but always fills the full background.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BackgroundImage<I> {
     /// The image itself.
@@ -36,6 +36,24 @@ pub struct BackgroundImage<I> {
     /// sigma = BLUR_FACTOR * blur * max(width, height)
     /// ```
     pub blur: f32,
+    /// Describes how the image is fit into the background.
+    #[serde(default)]
+    pub fit: BackgroundImageFit,
+}
+
+/// Describes how a [`BackgroundImage`] is fit into the background.
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum BackgroundImageFit {
+    /// The image is scaled to fill the full background while preserving its
+    /// aspect ratio. Parts of the image may get cut off.
+    #[default]
+    Cover,
+    /// The image is scaled to fit into the background while preserving its
+    /// aspect ratio. Parts of the background may not be covered by the image.
+    Contain,
+    /// The image is stretched to fill the full background without preserving
+    /// its aspect ratio.
+    Stretch,
 }
 
 /// A constant that is part of the formula to calculate the sigma of a gaussian
@@ -52,6 +70,7 @@ impl<I> BackgroundImage<I> {
             brightness: self.brightness,
             opacity: self.opacity,
             blur: self.blur,
+            fit: self.fit,
         }
     }
 }
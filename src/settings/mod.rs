@@ -6,6 +6,7 @@ mod color;
 mod field;
 mod font;
 mod gradient;
+mod hotkey_gesture;
 mod image;
 mod layout_background;
 mod semantic_color;
@@ -18,8 +19,9 @@ pub use self::{
     field::Field,
     font::{Font, Stretch as FontStretch, Style as FontStyle, Weight as FontWeight},
     gradient::{Gradient, ListGradient},
+    hotkey_gesture::HotkeyGesture,
     image::{HasImageId, Image, ImageCache, ImageId},
-    layout_background::{BackgroundImage, LayoutBackground, BLUR_FACTOR},
+    layout_background::{BackgroundImage, BackgroundImageFit, LayoutBackground, BLUR_FACTOR},
     semantic_color::SemanticColor,
     settings_description::SettingsDescription,
     value::{ColumnKind, Error as ValueError, Result as ValueResult, Value},
@@ -6,7 +6,9 @@ use crate::{
     hotkey::Hotkey,
     layout::LayoutDirection,
     platform::prelude::*,
-    settings::{Alignment, Color, Font, Gradient, ImageId, LayoutBackground, ListGradient},
+    settings::{
+        Alignment, Color, Font, Gradient, HotkeyGesture, ImageId, LayoutBackground, ListGradient,
+    },
     timing::formatter::{Accuracy, DigitsFormat},
     TimingMethod,
 };
@@ -66,6 +68,9 @@ pub enum Value {
     ColumnUpdateTrigger(ColumnUpdateTrigger),
     /// A value describing what hotkey to press to trigger a certain action.
     Hotkey(Option<Hotkey>),
+    /// A value describing how a hotkey needs to be pressed to trigger its
+    /// action.
+    HotkeyGesture(HotkeyGesture),
     /// A value describing the direction of a layout.
     LayoutDirection(LayoutDirection),
     /// A value describing a font to use. [`None`] if a default font should be
@@ -180,6 +185,12 @@ impl From<Option<Hotkey>> for Value {
     }
 }
 
+impl From<HotkeyGesture> for Value {
+    fn from(x: HotkeyGesture) -> Self {
+        Value::HotkeyGesture(x)
+    }
+}
+
 impl From<LayoutDirection> for Value {
     fn from(x: LayoutDirection) -> Self {
         Value::LayoutDirection(x)
@@ -367,6 +378,14 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into a hotkey gesture.
+    pub fn into_hotkey_gesture(self) -> Result<HotkeyGesture> {
+        match self {
+            Value::HotkeyGesture(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into a layout direction.
     pub fn into_layout_direction(self) -> Result<LayoutDirection> {
         match self {
@@ -410,6 +429,113 @@ impl Value {
             _ => Err(Error::WrongType),
         }
     }
+
+    /// Tries to convert the value into a value of the same type as the value
+    /// provided. This is useful for checking whether a value can be used for a
+    /// setting, based on the setting's current value.
+    pub fn into_type_of(self, other: &Value) -> Result<Value> {
+        Ok(match other {
+            Value::Bool(_) => Value::Bool(self.into_bool()?),
+            Value::UInt(_) => Value::UInt(self.into_uint()?),
+            Value::Int(_) => Value::Int(self.into_int()?),
+            Value::String(_) => Value::String(self.into_string()?),
+            Value::OptionalString(_) => Value::OptionalString(self.into_optional_string()?),
+            Value::Accuracy(_) => Value::Accuracy(self.into_accuracy()?),
+            Value::DigitsFormat(_) => Value::DigitsFormat(self.into_digits_format()?),
+            Value::OptionalTimingMethod(_) => {
+                Value::OptionalTimingMethod(self.into_optional_timing_method()?)
+            }
+            Value::Color(_) => Value::Color(self.into_color()?),
+            Value::OptionalColor(_) => Value::OptionalColor(self.into_optional_color()?),
+            Value::Gradient(_) => Value::Gradient(self.into_gradient()?),
+            Value::ListGradient(_) => Value::ListGradient(self.into_list_gradient()?),
+            Value::Alignment(_) => Value::Alignment(self.into_alignment()?),
+            Value::ColumnKind(_) => Value::ColumnKind(self.into_column_kind()?),
+            Value::ColumnStartWith(_) => Value::ColumnStartWith(self.into_column_start_with()?),
+            Value::ColumnUpdateWith(_) => Value::ColumnUpdateWith(self.into_column_update_with()?),
+            Value::ColumnUpdateTrigger(_) => {
+                Value::ColumnUpdateTrigger(self.into_column_update_trigger()?)
+            }
+            Value::Hotkey(_) => Value::Hotkey(self.into_hotkey()?),
+            Value::HotkeyGesture(_) => Value::HotkeyGesture(self.into_hotkey_gesture()?),
+            Value::LayoutDirection(_) => Value::LayoutDirection(self.into_layout_direction()?),
+            Value::Font(_) => Value::Font(self.into_font()?),
+            Value::DeltaGradient(_) => Value::DeltaGradient(self.into_delta_gradient()?),
+            Value::LayoutBackground(_) => Value::LayoutBackground(self.into_layout_background()?),
+        })
+    }
+
+    /// Returns the name of the type of the value. This is the same name that
+    /// the value is tagged with when it gets serialized.
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Value::Bool(_) => "Bool",
+            Value::UInt(_) => "UInt",
+            Value::Int(_) => "Int",
+            Value::String(_) => "String",
+            Value::OptionalString(_) => "OptionalString",
+            Value::Accuracy(_) => "Accuracy",
+            Value::DigitsFormat(_) => "DigitsFormat",
+            Value::OptionalTimingMethod(_) => "OptionalTimingMethod",
+            Value::Color(_) => "Color",
+            Value::OptionalColor(_) => "OptionalColor",
+            Value::Gradient(_) => "Gradient",
+            Value::ListGradient(_) => "ListGradient",
+            Value::Alignment(_) => "Alignment",
+            Value::ColumnKind(_) => "ColumnKind",
+            Value::ColumnStartWith(_) => "ColumnStartWith",
+            Value::ColumnUpdateWith(_) => "ColumnUpdateWith",
+            Value::ColumnUpdateTrigger(_) => "ColumnUpdateTrigger",
+            Value::Hotkey(_) => "Hotkey",
+            Value::HotkeyGesture(_) => "HotkeyGesture",
+            Value::LayoutDirection(_) => "LayoutDirection",
+            Value::Font(_) => "Font",
+            Value::DeltaGradient(_) => "DeltaGradient",
+            Value::LayoutBackground(_) => "LayoutBackground",
+        }
+    }
+
+    /// Returns the names of all the values that a setting of the same type as
+    /// this value may be set to. These are the names the values get serialized
+    /// with. An empty list is returned if the type isn't restricted to a fixed
+    /// set of values. Optional types may additionally be set to no value.
+    pub const fn allowed_values(&self) -> &'static [&'static str] {
+        match self {
+            Value::Accuracy(_) => &["Seconds", "Tenths", "Hundredths", "Milliseconds"],
+            Value::DigitsFormat(_) => &[
+                "SingleDigitSeconds",
+                "DoubleDigitSeconds",
+                "SingleDigitMinutes",
+                "DoubleDigitMinutes",
+                "SingleDigitHours",
+                "DoubleDigitHours",
+            ],
+            Value::OptionalTimingMethod(_) => &["RealTime", "GameTime"],
+            Value::Alignment(_) => &["Auto", "Left", "Center"],
+            Value::ColumnKind(_) => &["Time", "Variable"],
+            Value::ColumnStartWith(_) => &[
+                "Empty",
+                "ComparisonTime",
+                "ComparisonSegmentTime",
+                "PossibleTimeSave",
+            ],
+            Value::ColumnUpdateWith(_) => &[
+                "DontUpdate",
+                "SplitTime",
+                "Delta",
+                "DeltaWithFallback",
+                "SegmentTime",
+                "SegmentDelta",
+                "SegmentDeltaWithFallback",
+            ],
+            Value::ColumnUpdateTrigger(_) => {
+                &["OnStartingSegment", "Contextual", "OnEndingSegment"]
+            }
+            Value::HotkeyGesture(_) => &["Press", "Hold", "DoublePress"],
+            Value::LayoutDirection(_) => &["Vertical", "Horizontal"],
+            _ => &[],
+        }
+    }
 }
 
 impl From<Value> for bool {
@@ -514,6 +640,12 @@ impl From<Value> for Option<Hotkey> {
     }
 }
 
+impl From<Value> for HotkeyGesture {
+    fn from(value: Value) -> Self {
+        value.into_hotkey_gesture().unwrap()
+    }
+}
+
 impl From<Value> for LayoutDirection {
     fn from(value: Value) -> Self {
         value.into_layout_direction().unwrap()
@@ -4,6 +4,7 @@
 mod atomic_date_time;
 pub mod formatter;
 mod time;
+mod time_source;
 mod time_span;
 mod time_stamp;
 mod timer;
@@ -15,11 +16,16 @@ pub use self::timer::SharedTimer;
 pub use self::{
     atomic_date_time::AtomicDateTime,
     time::{GameTime, RealTime, Time},
+    time_source::{MonotonicClock, TimeSource},
     time_span::{ParseError, TimeSpan},
     time_stamp::TimeStamp,
-    timer::{CreationError as TimerCreationError, Snapshot, Timer},
+    timer::{
+        CreationError as TimerCreationError, PendingReset, PracticeStatistics, RaceTimer,
+        SegmentStatistics, Snapshot, Timer,
+    },
     timer_phase::TimerPhase,
     timing_method::TimingMethod,
 };
 
+#[cfg(feature = "parser-flitter")]
 pub(crate) use self::time_span::{parse_custom, CustomParser};
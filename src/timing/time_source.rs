@@ -0,0 +1,25 @@
+use crate::TimeStamp;
+use core::fmt::Debug;
+
+/// A `TimeSource` provides the current point in time to the
+/// [`Timer`](crate::Timer). By default the timer uses the [`MonotonicClock`],
+/// but a different source can be used to drive the timer with a virtual clock,
+/// for example in tests, or to provide the time on platforms where the
+/// monotonic clock is unavailable or unsuitable.
+pub trait TimeSource: Debug + Send + Sync + 'static {
+    /// Returns the current point in time. The time stamps returned need to be
+    /// monotonic, i.e. they may never go backwards.
+    fn now(&self) -> TimeStamp;
+}
+
+/// The `MonotonicClock` is the default [`TimeSource`]. It uses the monotonic
+/// clock of the platform.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct MonotonicClock;
+
+impl TimeSource for MonotonicClock {
+    #[inline]
+    fn now(&self) -> TimeStamp {
+        TimeStamp::now()
+    }
+}
@@ -248,7 +248,30 @@ impl Neg for TimeSpan {
 }
 
 use core::fmt;
-use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    Serialize, Serializer,
+};
+
+impl Serialize for TimeSpan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // The time is stored as seconds, as the parser doesn't support days.
+        let (seconds, nanoseconds) = self.to_seconds_and_subsec_nanoseconds();
+        let sign = if (seconds | nanoseconds as i64) < 0 {
+            "-"
+        } else {
+            ""
+        };
+        serializer.collect_str(&format_args!(
+            "{sign}{}.{:09}",
+            seconds.unsigned_abs(),
+            nanoseconds.unsigned_abs(),
+        ))
+    }
+}
 
 impl<'de> Deserialize<'de> for TimeSpan {
     fn deserialize<D>(deserializer: D) -> Result<TimeSpan, D::Error>
@@ -354,4 +377,13 @@ mod tests {
             (10, 0)
         );
     }
+
+    #[test]
+    fn serde_round_trip() {
+        for time in ["-0.5", "-1:02:03.45", "0", "100:00:00.123456789", "30.12"] {
+            let time = TimeSpan::from_str(time).unwrap();
+            let json = serde_json::to_string(&time).unwrap();
+            assert_eq!(serde_json::from_str::<TimeSpan>(&json).unwrap(), time);
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use crate::{platform::Instant, TimeSpan};
-use core::ops::Sub;
+use core::ops::{Add, Sub};
 
 /// A `TimeStamp` stores a point in time that can be used to calculate a
 /// [`TimeSpan`].
@@ -23,3 +23,12 @@ impl Sub for TimeStamp {
         TimeSpan::from(self.0 - rhs.0)
     }
 }
+
+impl Add<TimeSpan> for TimeStamp {
+    type Output = TimeStamp;
+
+    #[inline]
+    fn add(self, rhs: TimeSpan) -> TimeStamp {
+        TimeStamp(self.0 + rhs.to_duration())
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
     event::{Error, Event, Result},
-    AtomicDateTime, Run, Time, TimeSpan, TimeStamp, TimingMethod,
+    platform::prelude::*,
+    run::{Action, ActionKind, PauseStatistics},
+    AtomicDateTime, Attempt, Run, Time, TimeSpan, TimeStamp, TimingMethod,
 };
 
 #[derive(Debug, Clone)]
@@ -16,6 +18,31 @@ pub struct ActiveAttempt {
     pub adjusted_offset: TimeSpan,
     pub game_time_paused_at: Option<TimeSpan>,
     pub loading_times: Option<TimeSpan>,
+    /// The real time at which the game started loading, if it is currently
+    /// loading.
+    pub loading_started_at: Option<TimeSpan>,
+    /// Whether the game time was already paused when the game started
+    /// loading, so that it stays paused once the loading ends.
+    pub game_time_paused_before_loading: bool,
+    /// The time stamps of the splits of the attempt, one for each segment that
+    /// got passed. Skipped segments repeat the time stamp of the split before
+    /// them, so undoing a split restores the time stamp of the previous one.
+    /// This is used to reject splits that happen too quickly after each other.
+    pub split_time_stamps: Vec<TimeStamp>,
+    /// The times at which each segment after the first one was entered, one
+    /// for each segment that got passed. Unlike the split times, these are
+    /// also stored for skipped segments, so the segment following a skipped
+    /// segment still has a start time.
+    pub segment_start_times: Vec<Time>,
+    /// The index of the most recent split, as long as it can still be
+    /// adjusted. Undoing or skipping a split prevents further adjustments.
+    pub provisional_split: Option<usize>,
+    /// The number of times the attempt has been paused.
+    pub pause_count: u32,
+    /// The duration of the longest pause that has already been resumed.
+    pub longest_pause: TimeSpan,
+    /// The log of all the commands that were applied during the attempt.
+    pub actions: Vec<Action>,
 }
 
 #[derive(Debug, Clone)]
@@ -44,7 +71,7 @@ impl From<TimerTime> for Time {
 }
 
 impl ActiveAttempt {
-    pub fn current_time(&self, run: &Run) -> TimerTime {
+    pub fn current_time(&self, run: &Run, now: TimeStamp) -> TimerTime {
         let real_time = match self.state {
             State::Ended { .. } => {
                 let Time {
@@ -57,8 +84,9 @@ impl ActiveAttempt {
                     game_time,
                 };
             }
-            State::NotEnded { time_paused_at, .. } => time_paused_at
-                .unwrap_or_else(|| TimeStamp::now() - self.start_time + self.adjusted_offset),
+            State::NotEnded { time_paused_at, .. } => {
+                time_paused_at.unwrap_or_else(|| now - self.start_time + self.adjusted_offset)
+            }
         };
 
         let game_time = self
@@ -71,13 +99,13 @@ impl ActiveAttempt {
         }
     }
 
-    pub fn get_pause_time(&self) -> Option<TimeSpan> {
+    pub fn get_pause_time(&self, now: TimeStamp) -> Option<TimeSpan> {
         if let State::NotEnded {
             time_paused_at: Some(pause_time),
             ..
         } = self.state
         {
-            return Some(TimeStamp::now() - self.start_time + self.original_offset - pause_time);
+            return Some(now - self.start_time + self.original_offset - pause_time);
         }
 
         if self.original_offset != self.adjusted_offset {
@@ -87,14 +115,45 @@ impl ActiveAttempt {
         }
     }
 
-    pub fn set_loading_times(&mut self, time: TimeSpan, run: &Run) {
+    pub fn pause_statistics(&self, now: TimeStamp) -> PauseStatistics {
+        let mut longest = self.longest_pause;
+        if let State::NotEnded {
+            time_paused_at: Some(pause_time),
+            ..
+        } = self.state
+        {
+            longest = longest.max(now - self.start_time + self.adjusted_offset - pause_time);
+        }
+
+        PauseStatistics {
+            total: self.get_pause_time(now).unwrap_or_default(),
+            count: self.pause_count,
+            longest,
+        }
+    }
+
+    pub fn log_action(&mut self, kind: ActionKind, now: TimeStamp) {
+        self.actions.push(Action {
+            kind,
+            time: now - self.start_time,
+        });
+    }
+
+    pub fn set_loading_times(&mut self, time: TimeSpan, run: &Run, now: TimeStamp) {
         self.loading_times = Some(time);
         if self.game_time_paused_at.is_some() {
-            self.game_time_paused_at = Some(self.current_time(run).real_time - time);
+            self.game_time_paused_at = Some(self.current_time(run, now).real_time - time);
         }
     }
 
-    pub fn prepare_split(&mut self, run: &Run) -> Result<(usize, Time, Event)> {
+    pub fn prepare_split(
+        &mut self,
+        run: &Run,
+        minimum_split_interval: TimeSpan,
+        now: TimeStamp,
+    ) -> Result<(usize, Time, Event)> {
+        let last_split_at = self.last_split_at();
+
         let State::NotEnded {
             current_split_index,
             time_paused_at,
@@ -107,16 +166,27 @@ impl ActiveAttempt {
             return Err(Error::TimerPaused);
         }
 
-        let real_time = TimeStamp::now() - self.start_time + self.adjusted_offset;
+        let real_time = now - self.start_time + self.adjusted_offset;
 
         if real_time < TimeSpan::zero() {
             return Err(Error::NegativeTime);
         }
 
+        if now - last_split_at < minimum_split_interval {
+            return Err(Error::SplitTooSoon);
+        }
+        self.split_time_stamps.push(now);
+
         let game_time = self
             .game_time_paused_at
             .or_else(|| Some(real_time - self.loading_times?));
 
+        let split_time = Time {
+            real_time: Some(real_time),
+            game_time,
+        };
+        self.segment_start_times.push(split_time);
+
         let previous_split_index = *current_split_index;
         *current_split_index += 1;
 
@@ -129,14 +199,16 @@ impl ActiveAttempt {
             Event::Splitted
         };
 
-        Ok((
-            previous_split_index,
-            Time {
-                real_time: Some(real_time),
-                game_time,
-            },
-            event,
-        ))
+        Ok((previous_split_index, split_time, event))
+    }
+
+    /// Returns the time stamp of the most recent split, or the start of the
+    /// attempt if there is none.
+    pub fn last_split_at(&self) -> TimeStamp {
+        self.split_time_stamps
+            .last()
+            .copied()
+            .unwrap_or(self.start_time)
     }
 
     pub const fn current_split_index(&self) -> Option<usize> {
@@ -169,22 +241,42 @@ impl ActiveAttempt {
         }
     }
 
-    pub fn update_times(&self, run: &mut Run, timing_method: TimingMethod) {
-        self.update_attempt_history(run);
+    pub fn update_times(&self, run: &mut Run, timing_method: TimingMethod, now: TimeStamp) {
+        self.update_attempt_history(run, now);
         update_best_segments(run);
         update_pb_splits(run, timing_method);
         run.update_segment_history(self.current_split_index_overflowing(run));
     }
 
-    pub fn update_attempt_history(&self, run: &mut Run) {
-        let (attempt_ended, time) = match self.state {
+    fn attempt_ended_and_time(&self, run: &Run) -> (AtomicDateTime, Time) {
+        match self.state {
             State::NotEnded { .. } => (AtomicDateTime::now(), Time::new()),
             State::Ended { attempt_ended } => {
                 (attempt_ended, run.segments().last().unwrap().split_time())
             }
-        };
+        }
+    }
+
+    pub fn attempt(&self, run: &Run, now: TimeStamp) -> Attempt {
+        let (attempt_ended, time) = self.attempt_ended_and_time(run);
+        let index = (run.max_attempt_history_index().unwrap_or(0) + 1).max(0);
+
+        let mut attempt = Attempt::new(
+            index,
+            time,
+            Some(self.attempt_started),
+            Some(attempt_ended),
+            self.get_pause_time(now),
+        );
+        self.store_pause_statistics(&mut attempt, now);
+        attempt.set_actions(self.actions.clone());
+        attempt
+    }
+
+    pub fn update_attempt_history(&self, run: &mut Run, now: TimeStamp) {
+        let (attempt_ended, time) = self.attempt_ended_and_time(run);
 
-        let pause_time = self.get_pause_time();
+        let pause_time = self.get_pause_time(now);
 
         run.add_attempt(
             time,
@@ -192,6 +284,17 @@ impl ActiveAttempt {
             Some(attempt_ended),
             pause_time,
         );
+
+        if let Some(attempt) = run.attempt_history_mut().last_mut() {
+            self.store_pause_statistics(attempt, now);
+            attempt.set_actions(self.actions.clone());
+        }
+    }
+
+    fn store_pause_statistics(&self, attempt: &mut Attempt, now: TimeStamp) {
+        let statistics = self.pause_statistics(now);
+        attempt.set_pause_count(Some(statistics.count));
+        attempt.set_longest_pause(Some(statistics.longest));
     }
 }
 
@@ -2,7 +2,9 @@ use crate::{
     analysis::check_best_segment,
     comparison::personal_best,
     event::{Error, Event},
-    platform::prelude::*,
+    platform::{prelude::*, Arc, DateTime},
+    run::{Action, ActionKind, PauseStatistics},
+    timing::{MonotonicClock, TimeSource},
     util::PopulateString,
     AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp,
     TimerPhase::{self, *},
@@ -14,8 +16,15 @@ use core::{mem, ops::Deref};
 mod tests;
 
 mod active_attempt;
+mod pending_reset;
+mod practice;
+mod race;
 use active_attempt::{ActiveAttempt, State};
 
+pub use pending_reset::PendingReset;
+pub use practice::{PracticeStatistics, SegmentStatistics};
+pub use race::RaceTimer;
+
 /// A `Timer` provides all the capabilities necessary for doing speedrun attempts.
 ///
 /// # Examples
@@ -54,6 +63,28 @@ pub struct Timer {
     current_comparison: String,
     current_timing_method: TimingMethod,
     active_attempt: Option<ActiveAttempt>,
+    scheduled_start: Option<ScheduledStart>,
+    minimum_split_interval: TimeSpan,
+    split_correction_window: TimeSpan,
+    practice_mode: bool,
+    practice_statistics: PracticeStatistics,
+    time_source: Arc<dyn TimeSource>,
+}
+
+/// A start of the timer that is scheduled to happen at a specific point in
+/// time. The delay is measured with the monotonic time stamps, so that changes
+/// to the system's clock don't affect the countdown once it is armed.
+#[derive(Debug, Copy, Clone)]
+struct ScheduledStart {
+    date_time: DateTime,
+    armed_at: TimeStamp,
+    delay: TimeSpan,
+}
+
+impl ScheduledStart {
+    fn time_until_start(&self, now: TimeStamp) -> TimeSpan {
+        self.delay - (now - self.armed_at)
+    }
 }
 
 /// A snapshot represents a specific point in time that the timer was observed
@@ -111,6 +142,12 @@ impl Timer {
             current_comparison: personal_best::NAME.into(),
             current_timing_method: TimingMethod::RealTime,
             active_attempt: None,
+            scheduled_start: None,
+            minimum_split_interval: TimeSpan::zero(),
+            split_correction_window: TimeSpan::zero(),
+            practice_mode: false,
+            practice_statistics: PracticeStatistics::default(),
+            time_source: Arc::new(MonotonicClock),
         })
     }
 
@@ -198,10 +235,18 @@ impl Timer {
     /// work with an entirely consistent view of the timer without the current
     /// time changing underneath.
     pub fn snapshot(&self) -> Snapshot<'_> {
+        let now = self.now();
         let time = match &self.active_attempt {
-            Some(active_attempt) => active_attempt.current_time(&self.run).into(),
+            Some(active_attempt) => active_attempt.current_time(&self.run, now).into(),
             None => {
-                let offset = Some(self.run.offset());
+                // While a start is scheduled, the time counts up towards the
+                // offset, so that the timer shows a countdown to the start.
+                let offset = Some(match &self.scheduled_start {
+                    Some(scheduled_start) => {
+                        self.run.offset() - scheduled_start.time_until_start(now)
+                    }
+                    None => self.run.offset(),
+                });
                 Time {
                     real_time: offset,
                     game_time: offset,
@@ -277,39 +322,157 @@ impl Timer {
     }
 
     /// Starts the Timer if there is no attempt in progress. If that's not the
-    /// case, nothing happens.
+    /// case, nothing happens. A start that is scheduled via
+    /// [`start_at`](Self::start_at) is replaced by starting right away.
     pub fn start(&mut self) -> Result {
-        if self.active_attempt.is_none() {
-            let attempt_started = AtomicDateTime::now();
-            let start_time = TimeStamp::now();
-            let offset = self.run.offset();
-
-            self.active_attempt = Some(ActiveAttempt {
-                state: State::NotEnded {
-                    current_split_index: 0,
-                    time_paused_at: None,
-                },
-                attempt_started,
-                start_time,
-                original_offset: offset,
-                adjusted_offset: offset,
-                game_time_paused_at: None,
-                loading_times: None,
-            });
-            self.run.start_next_run();
+        self.start_at_time_stamp(AtomicDateTime::now(), self.now())
+    }
 
+    fn start_at_time_stamp(
+        &mut self,
+        attempt_started: AtomicDateTime,
+        start_time: TimeStamp,
+    ) -> Result {
+        if self.active_attempt.is_none() {
+            self.scheduled_start = None;
+            self.start_with_offset(attempt_started, start_time, self.run.offset());
             Ok(Event::Started)
         } else {
             Err(Error::RunAlreadyInProgress)
         }
     }
 
+    fn start_with_offset(
+        &mut self,
+        attempt_started: AtomicDateTime,
+        start_time: TimeStamp,
+        offset: TimeSpan,
+    ) {
+        self.active_attempt = Some(ActiveAttempt {
+            state: State::NotEnded {
+                current_split_index: 0,
+                time_paused_at: None,
+            },
+            attempt_started,
+            start_time,
+            original_offset: offset,
+            adjusted_offset: offset,
+            game_time_paused_at: None,
+            loading_times: None,
+            loading_started_at: None,
+            game_time_paused_before_loading: false,
+            split_time_stamps: Vec::new(),
+            segment_start_times: Vec::new(),
+            provisional_split: None,
+            pause_count: 0,
+            longest_pause: TimeSpan::zero(),
+            actions: vec![Action {
+                kind: ActionKind::Start,
+                time: TimeSpan::zero(),
+            }],
+        });
+        if !self.practice_mode {
+            self.run.start_next_run();
+        }
+    }
+
+    /// Arms the Timer to start automatically at the date and time specified.
+    /// This is useful for synchronizing the start of a race between multiple
+    /// runners. Until the start happens, the time of the Timer counts up
+    /// towards the start offset of the run, which allows the layout to show a
+    /// countdown. The start itself is applied by
+    /// [`update_scheduled_start`](Self::update_scheduled_start), which is
+    /// expected to be called regularly, for example before every frame. If the
+    /// date and time is already in the past, the attempt starts right away.
+    /// Arming the Timer again replaces the previously scheduled start.
+    pub fn start_at(&mut self, date_time: DateTime) -> Result {
+        let now = self.now();
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+
+        self.scheduled_start = Some(ScheduledStart {
+            date_time,
+            armed_at: now,
+            delay: -(AtomicDateTime::now() - date_time),
+        });
+
+        Ok(self
+            .update_scheduled_start()
+            .unwrap_or(Event::StartScheduled))
+    }
+
+    /// Cancels the start that is scheduled via [`start_at`](Self::start_at).
+    pub fn cancel_scheduled_start(&mut self) -> Result {
+        if self.scheduled_start.take().is_some() {
+            Ok(Event::ScheduledStartCancelled)
+        } else {
+            Err(Error::NoStartScheduled)
+        }
+    }
+
+    /// Starts the attempt if the start that is scheduled via
+    /// [`start_at`](Self::start_at) is due. The attempt is treated as if it
+    /// had started exactly at the scheduled point in time, regardless of how
+    /// late this method is called. Returns [`Event::Started`] if the attempt
+    /// got started by this call.
+    pub fn update_scheduled_start(&mut self) -> Option<Event> {
+        let now = self.now();
+        let scheduled_start = self.scheduled_start?;
+        let time_until_start = scheduled_start.time_until_start(now);
+        if time_until_start > TimeSpan::zero() {
+            return None;
+        }
+
+        self.scheduled_start = None;
+        self.start_with_offset(
+            AtomicDateTime::new(scheduled_start.date_time, false),
+            now,
+            self.run.offset() - time_until_start,
+        );
+
+        Some(Event::Started)
+    }
+
+    /// Returns the date and time the Timer is scheduled to start at, if a start
+    /// is scheduled via [`start_at`](Self::start_at).
+    #[inline]
+    pub fn scheduled_start(&self) -> Option<DateTime> {
+        Some(self.scheduled_start.as_ref()?.date_time)
+    }
+
+    /// Returns the time that is left until the scheduled start happens. This
+    /// may be negative if the start is due, but
+    /// [`update_scheduled_start`](Self::update_scheduled_start) has not been
+    /// called yet.
+    #[inline]
+    pub fn time_until_scheduled_start(&self) -> Option<TimeSpan> {
+        let now = self.now();
+        Some(self.scheduled_start.as_ref()?.time_until_start(now))
+    }
+
+    /// Returns whether the Timer is armed to start at a specific point in
+    /// time.
+    #[inline]
+    pub const fn is_start_scheduled(&self) -> bool {
+        self.scheduled_start.is_some()
+    }
+
     /// If an attempt is in progress, stores the current time as the time of the
-    /// current split. The attempt ends if the last split time is stored.
+    /// current split. The attempt ends if the last split time is stored. If the
+    /// split happens sooner after the previous split or the start of the
+    /// attempt than the [minimum split
+    /// interval](Self::set_minimum_split_interval) allows, it is rejected with
+    /// [`Error::SplitTooSoon`].
     pub fn split(&mut self) -> Result {
+        self.split_at_time_stamp(self.now())
+    }
+
+    fn split_at_time_stamp(&mut self, now: TimeStamp) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
-        let (split_index, current_time, event) = active_attempt.prepare_split(&self.run)?;
+        let (split_index, current_time, event) =
+            active_attempt.prepare_split(&self.run, self.minimum_split_interval, now)?;
 
         // FIXME: We shouldn't need to collect here.
         let variables = self
@@ -323,11 +486,132 @@ impl Timer {
         segment.set_split_time(current_time);
         *segment.variables_mut() = variables;
 
-        self.run.mark_as_modified();
+        if !self.practice_mode {
+            self.run.mark_as_modified();
+        }
+        active_attempt.provisional_split = Some(split_index);
+        self.log_action(ActionKind::Split, now);
 
         Ok(event)
     }
 
+    /// Returns the minimum amount of time that needs to pass between two splits,
+    /// as well as between the start of the attempt and the first split.
+    #[inline]
+    pub const fn minimum_split_interval(&self) -> TimeSpan {
+        self.minimum_split_interval
+    }
+
+    /// Sets the minimum amount of time that needs to pass between two splits,
+    /// as well as between the start of the attempt and the first split. Splits
+    /// that happen sooner are rejected. This protects against a bouncing key
+    /// recording an extremely short segment that would otherwise replace the
+    /// best segment. The time is measured between the actual presses,
+    /// regardless of the start offset. Undoing a split measures the time from
+    /// the split before it again, while skipped splits don't count as presses.
+    /// A time of zero disables this, which is the default.
+    #[inline]
+    pub fn set_minimum_split_interval(&mut self, interval: TimeSpan) {
+        self.minimum_split_interval = interval;
+    }
+
+    /// Returns the amount of time after a split during which the split time can
+    /// still be adjusted via [`adjust_last_split`](Self::adjust_last_split).
+    #[inline]
+    pub const fn split_correction_window(&self) -> TimeSpan {
+        self.split_correction_window
+    }
+
+    /// Sets the amount of time after a split during which the split time can
+    /// still be adjusted via [`adjust_last_split`](Self::adjust_last_split).
+    /// A time of zero disables adjusting splits, which is the default.
+    #[inline]
+    pub fn set_split_correction_window(&mut self, window: TimeSpan) {
+        self.split_correction_window = window;
+    }
+
+    /// Replaces the source the Timer uses for retrieving the current point in
+    /// time. By default the [`MonotonicClock`] is used. The time source can't
+    /// be changed while an attempt is in progress, as the time stamps of the
+    /// current attempt may not be comparable to the ones of the new source.
+    pub fn set_time_source(&mut self, time_source: impl TimeSource) -> Result<(), Error> {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+        self.time_source = Arc::new(time_source);
+        Ok(())
+    }
+
+    /// Accesses the source the Timer uses for retrieving the current point in
+    /// time.
+    #[inline]
+    pub const fn time_source(&self) -> &Arc<dyn TimeSource> {
+        &self.time_source
+    }
+
+    /// Returns the current point in time according to the Timer's
+    /// [`TimeSource`].
+    #[inline]
+    pub fn now(&self) -> TimeStamp {
+        self.time_source.now()
+    }
+
+    /// Adjusts the time of the most recent split by the time provided. This
+    /// can be used to correct a split time shortly after the split happened,
+    /// for example to account for a known input delay by adjusting it by
+    /// -0.2s. This is only possible within the [split correction
+    /// window](Self::set_split_correction_window) and as long as the split
+    /// was neither undone nor followed by a skipped split. The split time
+    /// can't be moved before the previous split time or past the current time
+    /// for either timing method. Once the attempt is finished, the current
+    /// time is the final split time, so the final split can only be moved
+    /// back.
+    pub fn adjust_last_split(&mut self, delta: TimeSpan) -> Result {
+        let now = self.now();
+        let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
+
+        let split_index = active_attempt
+            .provisional_split
+            .filter(|_| now - active_attempt.last_split_at() < self.split_correction_window)
+            .ok_or(Error::SplitCorrectionWindowClosed)?;
+
+        let current_time = Time::from(active_attempt.current_time(&self.run, now));
+        let segments = self.run.segments();
+        for method in TimingMethod::all() {
+            let Some(split_time) = segments[split_index].split_time()[method] else {
+                continue;
+            };
+
+            let previous_split_time = segments[..split_index]
+                .iter()
+                .rev()
+                .find_map(|s| s.split_time()[method])
+                .unwrap_or_default();
+
+            if split_time + delta < previous_split_time {
+                return Err(Error::NegativeTime);
+            }
+            if current_time[method].is_some_and(|t| split_time + delta > t) {
+                return Err(Error::SplitTimeInFuture);
+            }
+        }
+
+        let delta_time = Time::new()
+            .with_real_time(Some(delta))
+            .with_game_time(Some(delta));
+        *self.run.segment_mut(split_index).split_time_mut() += delta_time;
+        if let Some(start_time) = active_attempt.segment_start_times.last_mut() {
+            *start_time += delta_time;
+        }
+
+        active_attempt.log_action(ActionKind::AdjustSplit(delta), now);
+        if !self.practice_mode {
+            self.run.mark_as_modified();
+        }
+
+        Ok(Event::SplitAdjusted)
+    }
+
     /// Starts a new attempt or stores the current time as the time of the
     /// current split. The attempt ends if the last split time is stored.
     pub fn split_or_start(&mut self) -> Result {
@@ -341,6 +625,7 @@ impl Timer {
     /// Skips the current split if an attempt is in progress and the
     /// current split is not the last split.
     pub fn skip_split(&mut self) -> Result {
+        let now = self.now();
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         let Some(current_split_index) = active_attempt.current_split_index_mut() else {
@@ -353,8 +638,16 @@ impl Timer {
                 .clear_split_info();
 
             *current_split_index += 1;
-
-            self.run.mark_as_modified();
+            let last_split_at = active_attempt.last_split_at();
+            active_attempt.split_time_stamps.push(last_split_at);
+            let current_time = active_attempt.current_time(&self.run, now).into();
+            active_attempt.segment_start_times.push(current_time);
+            active_attempt.provisional_split = None;
+            active_attempt.log_action(ActionKind::SkipSplit, now);
+
+            if !self.practice_mode {
+                self.run.mark_as_modified();
+            }
 
             Ok(Event::SplitSkipped)
         } else {
@@ -366,6 +659,7 @@ impl Timer {
     /// and there is a previous split. The Timer Phase also switches to
     /// [`Running`] if it previously was [`Ended`].
     pub fn undo_split(&mut self) -> Result {
+        let now = self.now();
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         if let Some(previous_split_index) = active_attempt
@@ -381,12 +675,18 @@ impl Timer {
                 current_split_index: previous_split_index,
                 time_paused_at,
             };
+            active_attempt.split_time_stamps.pop();
+            active_attempt.segment_start_times.pop();
+            active_attempt.provisional_split = None;
+            active_attempt.log_action(ActionKind::UndoSplit, now);
 
             self.run
                 .segment_mut(previous_split_index)
                 .clear_split_info();
 
-            self.run.mark_as_modified();
+            if !self.practice_mode {
+                self.run.mark_as_modified();
+            }
 
             Ok(Event::SplitUndone)
         } else {
@@ -454,13 +754,63 @@ impl Timer {
         }
     }
 
+    /// Returns whether the Timer is in practice mode. See
+    /// [`set_practice_mode`](Self::set_practice_mode) for more information.
+    #[inline]
+    pub const fn is_practice_mode(&self) -> bool {
+        self.practice_mode
+    }
+
+    /// Enables or disables practice mode. In practice mode attempts never
+    /// touch the Run's data. Neither the attempt count, the Attempt History,
+    /// the Segment History, the Best Segments nor the Personal Best get
+    /// updated when resetting. Instead the segment times are recorded in the
+    /// separate [`PracticeStatistics`], so individual segments can be
+    /// practiced without polluting the comparisons. Practice mode can't be
+    /// changed while an attempt is in progress.
+    pub fn set_practice_mode(&mut self, enabled: bool) -> Result<(), Error> {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+        self.practice_mode = enabled;
+        Ok(())
+    }
+
+    /// Accesses the statistics of the segments practiced in practice mode.
+    /// The statistics are indexed by the segments of the Run.
+    #[inline]
+    pub const fn practice_statistics(&self) -> &PracticeStatistics {
+        &self.practice_statistics
+    }
+
+    /// Clears the statistics of the segments practiced in practice mode.
+    #[inline]
+    pub fn clear_practice_statistics(&mut self) {
+        self.practice_statistics.clear();
+    }
+
+    /// Requests the current attempt to be reset. Instead of resetting right
+    /// away, this returns a [`PendingReset`] that allows inspecting the
+    /// attempt that would be stored in the Run's history, so the runner can
+    /// be asked for confirmation before anything is lost. The reset is then
+    /// either confirmed or cancelled through the [`PendingReset`].
+    pub fn request_reset(&mut self) -> Result<PendingReset<'_>, Error> {
+        if self.active_attempt.is_some() {
+            Ok(PendingReset { timer: self })
+        } else {
+            Err(Error::NoRunInProgress)
+        }
+    }
+
     /// Resets the current attempt if there is one in progress. The splits are
     /// updated such that the current attempt's split times are being stored as
     /// the new Personal Best.
     pub fn reset_and_set_attempt_as_pb(&mut self) -> Result {
         if self.active_attempt.is_some() {
             self.reset_state(true);
-            set_run_as_pb(&mut self.run);
+            if !self.practice_mode {
+                set_run_as_pb(&mut self.run);
+            }
             self.reset_splits();
             Ok(Event::Reset)
         } else {
@@ -469,12 +819,22 @@ impl Timer {
     }
 
     fn reset_state(&mut self, update_times: bool) {
-        let Some(active_attempt) = self.active_attempt.take() else {
+        let now = self.now();
+        let Some(mut active_attempt) = self.active_attempt.take() else {
             return;
         };
-
-        if update_times {
-            active_attempt.update_times(&mut self.run, self.current_timing_method);
+        active_attempt.log_action(ActionKind::Reset, now);
+
+        if self.practice_mode {
+            let reached_segments =
+                (active_attempt.current_split_index_overflowing(&self.run) + 1).min(self.run.len());
+            self.practice_statistics.record_attempt(
+                &self.run,
+                &active_attempt.segment_start_times,
+                reached_segments,
+            );
+        } else if update_times {
+            active_attempt.update_times(&mut self.run, self.current_timing_method, now);
         }
     }
 
@@ -490,6 +850,10 @@ impl Timer {
 
     /// Pauses an active attempt that is not paused.
     pub fn pause(&mut self) -> Result {
+        self.pause_at_time_stamp(self.now())
+    }
+
+    fn pause_at_time_stamp(&mut self, now: TimeStamp) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         let State::NotEnded { time_paused_at, .. } = &mut active_attempt.state else {
@@ -498,7 +862,9 @@ impl Timer {
 
         if time_paused_at.is_none() {
             *time_paused_at =
-                Some(TimeStamp::now() - active_attempt.start_time + active_attempt.adjusted_offset);
+                Some(now - active_attempt.start_time + active_attempt.adjusted_offset);
+            active_attempt.pause_count += 1;
+            active_attempt.log_action(ActionKind::Pause, now);
             Ok(Event::Paused)
         } else {
             Err(Error::AlreadyPaused)
@@ -507,6 +873,10 @@ impl Timer {
 
     /// Resumes an attempt that is paused.
     pub fn resume(&mut self) -> Result {
+        self.resume_at_time_stamp(self.now())
+    }
+
+    fn resume_at_time_stamp(&mut self, now: TimeStamp) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         let State::NotEnded { time_paused_at, .. } = &mut active_attempt.state else {
@@ -514,9 +884,12 @@ impl Timer {
         };
 
         if let Some(pause_time) = *time_paused_at {
-            active_attempt.adjusted_offset =
-                pause_time - (TimeStamp::now() - active_attempt.start_time);
+            let pause_duration =
+                now - active_attempt.start_time + active_attempt.adjusted_offset - pause_time;
+            active_attempt.longest_pause = active_attempt.longest_pause.max(pause_duration);
+            active_attempt.adjusted_offset = pause_time - (now - active_attempt.start_time);
             *time_paused_at = None;
+            active_attempt.log_action(ActionKind::Resume, now);
             Ok(Event::Resumed)
         } else {
             Err(Error::NotPaused)
@@ -580,8 +953,12 @@ impl Timer {
             _ => Event::PausesUndone,
         };
 
+        let now = self.now();
         if let Some(active_attempt) = &mut self.active_attempt {
             active_attempt.adjusted_offset = active_attempt.original_offset;
+            active_attempt.pause_count = 0;
+            active_attempt.longest_pause = TimeSpan::zero();
+            active_attempt.log_action(ActionKind::UndoAllPauses, now);
             Ok(event)
         } else {
             Err(Error::NoRunInProgress)
@@ -626,6 +1003,7 @@ impl Timer {
     /// duration only counts the time the Timer Phase has actually been
     /// `Running`.
     pub fn current_attempt_duration(&self) -> TimeSpan {
+        let now = self.now();
         let Some(active_attempt) = &self.active_attempt else {
             return TimeSpan::zero();
         };
@@ -633,14 +1011,43 @@ impl Timer {
         if let State::Ended { attempt_ended } = active_attempt.state {
             attempt_ended - active_attempt.attempt_started
         } else {
-            TimeStamp::now() - active_attempt.start_time
+            now - active_attempt.start_time
         }
     }
 
     /// Returns the total amount of time the current attempt has been paused
     /// for. None is returned if there have not been any pauses.
     pub fn get_pause_time(&self) -> Option<TimeSpan> {
-        self.active_attempt.as_ref()?.get_pause_time()
+        let now = self.now();
+        self.active_attempt.as_ref()?.get_pause_time(now)
+    }
+
+    /// Returns the statistics about the pauses of the current attempt, which
+    /// include the total amount of time it has been paused for, the number of
+    /// pauses and the duration of the longest pause. A pause that is still
+    /// ongoing is taken into account as well. None is returned if there is no
+    /// attempt in progress.
+    pub fn pause_statistics(&self) -> Option<PauseStatistics> {
+        let now = self.now();
+        Some(self.active_attempt.as_ref()?.pause_statistics(now))
+    }
+
+    /// Accesses the log of all the commands that were applied to the current
+    /// attempt, such as splits, pauses and changes to the Game Time. The log
+    /// is stored along with the attempt in the Run's history once the attempt
+    /// is reset, so it can be audited later on. This is empty if there is no
+    /// attempt in progress.
+    pub fn actions(&self) -> &[Action] {
+        match &self.active_attempt {
+            Some(active_attempt) => &active_attempt.actions,
+            None => &[],
+        }
+    }
+
+    fn log_action(&mut self, kind: ActionKind, now: TimeStamp) {
+        if let Some(active_attempt) = &mut self.active_attempt {
+            active_attempt.log_action(kind, now);
+        }
     }
 
     /// Returns whether Game Time is currently initialized. Game Time
@@ -657,10 +1064,12 @@ impl Timer {
     /// gets uninitialized for each new attempt.
     #[inline]
     pub fn initialize_game_time(&mut self) -> Result {
+        let now = self.now();
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         if active_attempt.loading_times.is_none() {
             active_attempt.loading_times = Some(TimeSpan::zero());
+            active_attempt.log_action(ActionKind::InitializeGameTime, now);
             Ok(Event::GameTimeInitialized)
         } else {
             Err(Error::GameTimeAlreadyInitialized)
@@ -688,13 +1097,15 @@ impl Timer {
     /// Pauses the Game Timer such that it doesn't automatically increment
     /// similar to Real Time.
     pub fn pause_game_time(&mut self) -> Result {
+        let now = self.now();
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         if active_attempt.game_time_paused_at.is_none() {
-            let current_time = active_attempt.current_time(&self.run);
+            let current_time = active_attempt.current_time(&self.run, now);
 
             active_attempt.game_time_paused_at =
                 current_time.game_time.or(Some(current_time.real_time));
+            active_attempt.log_action(ActionKind::PauseGameTime, now);
 
             Ok(Event::GameTimePaused)
         } else {
@@ -705,14 +1116,16 @@ impl Timer {
     /// Resumes the Game Timer such that it automatically increments similar to
     /// Real Time, starting from the Game Time it was paused at.
     pub fn resume_game_time(&mut self) -> Result {
+        let now = self.now();
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         if active_attempt.game_time_paused_at.is_some() {
-            let current_time = active_attempt.current_time(&self.run);
+            let current_time = active_attempt.current_time(&self.run, now);
 
             let diff = catch! { current_time.real_time - current_time.game_time? };
-            active_attempt.set_loading_times(diff.unwrap_or_default(), &self.run);
+            active_attempt.set_loading_times(diff.unwrap_or_default(), &self.run, now);
             active_attempt.game_time_paused_at = None;
+            active_attempt.log_action(ActionKind::ResumeGameTime, now);
 
             Ok(Event::GameTimeResumed)
         } else {
@@ -726,13 +1139,15 @@ impl Timer {
     /// the Game Timer never shows any time that is not coming from the game.
     #[inline]
     pub fn set_game_time(&mut self, game_time: TimeSpan) -> Result {
+        let now = self.now();
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         if active_attempt.game_time_paused_at.is_some() {
             active_attempt.game_time_paused_at = Some(game_time);
         }
         active_attempt.loading_times =
-            Some(active_attempt.current_time(&self.run).real_time - game_time);
+            Some(active_attempt.current_time(&self.run, now).real_time - game_time);
+        active_attempt.log_action(ActionKind::SetGameTime(game_time), now);
 
         Ok(Event::GameTimeSet)
     }
@@ -751,14 +1166,104 @@ impl Timer {
     /// is then automatically determined by Real Time - Loading Times.
     #[inline]
     pub fn set_loading_times(&mut self, time: TimeSpan) -> Result {
+        let now = self.now();
         if let Some(active_attempt) = &mut self.active_attempt {
-            active_attempt.set_loading_times(time, &self.run);
+            active_attempt.set_loading_times(time, &self.run, now);
+            active_attempt.log_action(ActionKind::SetLoadingTimes(time), now);
             Ok(Event::LoadingTimesSet)
         } else {
             Err(Error::NoRunInProgress)
         }
     }
 
+    /// Returns whether the game is currently loading, as reported through
+    /// [`start_loading`](Self::start_loading).
+    #[inline]
+    pub const fn is_loading(&self) -> bool {
+        match &self.active_attempt {
+            Some(active_attempt) => active_attempt.loading_started_at.is_some(),
+            None => false,
+        }
+    }
+
+    /// Informs the Timer that the game started loading. This is meant to be
+    /// used by load removal tools, which only need to report when loading
+    /// starts and ends. The Timer then maintains the loads removed Game Time
+    /// on its own by pausing the Game Timer until
+    /// [`end_loading`](Self::end_loading) is called and adding the time spent
+    /// loading to the loading times. Game Time automatically gets initialized
+    /// if it isn't already.
+    pub fn start_loading(&mut self) -> Result {
+        self.start_loading_at(self.now())
+    }
+
+    /// Informs the Timer that the game started loading at the time stamp
+    /// specified. This allows load removal tools to report the precise moment
+    /// the loading was detected, even if reporting it to the Timer is
+    /// delayed. See [`start_loading`](Self::start_loading) for more
+    /// information.
+    pub fn start_loading_at(&mut self, time_stamp: TimeStamp) -> Result {
+        let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
+
+        let State::NotEnded { time_paused_at, .. } = active_attempt.state else {
+            return Err(Error::RunFinished);
+        };
+
+        if active_attempt.loading_started_at.is_some() {
+            return Err(Error::AlreadyLoading);
+        }
+
+        let real_time = time_paused_at.unwrap_or_else(|| {
+            time_stamp - active_attempt.start_time + active_attempt.adjusted_offset
+        });
+        let loading_times = *active_attempt.loading_times.get_or_insert(TimeSpan::zero());
+
+        active_attempt.game_time_paused_before_loading =
+            active_attempt.game_time_paused_at.is_some();
+        if !active_attempt.game_time_paused_before_loading {
+            active_attempt.game_time_paused_at = Some(real_time - loading_times);
+        }
+        active_attempt.loading_started_at = Some(real_time);
+        active_attempt.log_action(ActionKind::StartLoading, time_stamp);
+
+        Ok(Event::LoadingStarted)
+    }
+
+    /// Informs the Timer that the game stopped loading. The time spent loading
+    /// is added to the loading times and the Game Timer continues from where
+    /// it was paused at. If the Game Timer was already paused before the game
+    /// started loading, it stays paused.
+    pub fn end_loading(&mut self) -> Result {
+        self.end_loading_at(self.now())
+    }
+
+    /// Informs the Timer that the game stopped loading at the time stamp
+    /// specified. See [`end_loading`](Self::end_loading) for more
+    /// information.
+    pub fn end_loading_at(&mut self, time_stamp: TimeStamp) -> Result {
+        let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
+
+        let loading_started_at = active_attempt
+            .loading_started_at
+            .take()
+            .ok_or(Error::NotLoading)?;
+
+        if !active_attempt.game_time_paused_before_loading {
+            if let State::NotEnded { time_paused_at, .. } = active_attempt.state {
+                let real_time = time_paused_at.unwrap_or_else(|| {
+                    time_stamp - active_attempt.start_time + active_attempt.adjusted_offset
+                });
+                let loading_time = (real_time - loading_started_at).max(TimeSpan::zero());
+                active_attempt.loading_times =
+                    Some(active_attempt.loading_times.unwrap_or_default() + loading_time);
+            }
+            active_attempt.game_time_paused_at = None;
+        }
+        active_attempt.log_action(ActionKind::EndLoading, time_stamp);
+
+        Ok(Event::LoadingEnded)
+    }
+
     /// Sets the value of a custom variable with the name specified. If the
     /// variable does not exist, a temporary variable gets created that will not
     /// be stored in the splits file.
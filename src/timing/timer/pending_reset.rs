@@ -0,0 +1,56 @@
+use super::{Result, Timer};
+use crate::{event::Error, run::ActionKind, Attempt, Run};
+
+/// A `PendingReset` is a reset of the current attempt that was requested via
+/// [`Timer::request_reset`], but is not applied yet. It allows the frontend to
+/// show what would be stored and what would be lost by the reset, before the
+/// runner either confirms or cancels it. Dropping it cancels the reset.
+#[derive(Debug)]
+pub struct PendingReset<'timer> {
+    pub(super) timer: &'timer mut Timer,
+}
+
+impl PendingReset<'_> {
+    /// Accesses the Timer that is about to be reset.
+    pub fn timer(&self) -> &Timer {
+        self.timer
+    }
+
+    /// Returns the attempt that gets stored in the Run's history if the reset
+    /// is confirmed with the splits being updated. If the splits are not
+    /// updated, the attempt is discarded instead.
+    pub fn attempt(&self) -> Attempt {
+        let timer = &*self.timer;
+        let mut active_attempt = timer.active_attempt.clone().unwrap();
+        let now = timer.now();
+        active_attempt.log_action(ActionKind::Reset, now);
+        active_attempt.attempt(&timer.run, now)
+    }
+
+    /// Returns whether the current attempt contains any new best times, such as
+    /// new best segments or a new Personal Best. These would be lost if the
+    /// splits are not updated.
+    pub fn has_new_best_times(&self) -> bool {
+        self.timer.current_attempt_has_new_best_times()
+    }
+
+    /// Returns the Run as it would look like after the reset, depending on
+    /// whether the splits are to be updated.
+    pub fn run_after_reset(&self, update_splits: bool) -> Run {
+        self.timer.clone().into_run(update_splits)
+    }
+
+    /// Confirms the reset. If the splits are to be updated, all the information
+    /// of the current attempt is stored in the Run's history. Otherwise the
+    /// current attempt's information is discarded.
+    pub fn confirm(self, update_splits: bool) -> Result {
+        self.timer.reset(update_splits)
+    }
+
+    /// Cancels the reset, keeping the current attempt going. The error that is
+    /// returned can be used to report the cancellation as the result of the
+    /// reset command.
+    pub const fn cancel(self) -> Error {
+        Error::RunnerDecidedAgainstReset
+    }
+}
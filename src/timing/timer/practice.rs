@@ -0,0 +1,130 @@
+use crate::{platform::prelude::*, Run, Time, TimeSpan, TimingMethod};
+
+/// The statistics of all the segments practiced while the Timer is in
+/// [practice mode](super::Timer::set_practice_mode). These are kept separate
+/// from the Run, so practicing never affects the Personal Best, the Best
+/// Segments, the Attempt History or the Segment History.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PracticeStatistics {
+    segments: Vec<SegmentStatistics>,
+}
+
+/// The practice statistics of a single segment.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SegmentStatistics {
+    attempts: u32,
+    completions: u32,
+    best_time: Time,
+    last_time: Time,
+    total_time: Time,
+    timed_completions: [u32; 2],
+}
+
+impl PracticeStatistics {
+    /// Accesses the statistics of the segment with the index provided. If the
+    /// segment has never been practiced, `None` is returned.
+    pub fn segment(&self, index: usize) -> Option<&SegmentStatistics> {
+        self.segments.get(index)
+    }
+
+    /// Accesses the statistics of all the segments that have been practiced so
+    /// far, in the order of the segments.
+    pub fn segments(&self) -> &[SegmentStatistics] {
+        &self.segments
+    }
+
+    /// Clears all the statistics.
+    pub fn clear(&mut self) {
+        self.segments.clear();
+    }
+
+    /// Records the split times of the current attempt of the Run provided.
+    /// Every segment the attempt reached counts as attempted. Each segment is
+    /// timed from the point in time it was entered, which is provided for each
+    /// segment after the first one, so segments following a skipped segment
+    /// are timed as well.
+    pub(super) fn record_attempt(
+        &mut self,
+        run: &Run,
+        segment_start_times: &[Time],
+        reached_segments: usize,
+    ) {
+        if self.segments.len() < reached_segments {
+            self.segments
+                .resize(reached_segments, SegmentStatistics::default());
+        }
+
+        for (index, (segment, statistics)) in run
+            .segments()
+            .iter()
+            .zip(&mut self.segments)
+            .enumerate()
+            .take(reached_segments)
+        {
+            statistics.attempts += 1;
+
+            let split_time = segment.split_time();
+            if split_time.real_time.is_some() || split_time.game_time.is_some() {
+                let start_time = match index.checked_sub(1) {
+                    Some(previous) => segment_start_times
+                        .get(previous)
+                        .copied()
+                        .unwrap_or_default(),
+                    None => Time::zero(),
+                };
+                statistics.record_completion(split_time - start_time);
+            }
+        }
+    }
+}
+
+impl SegmentStatistics {
+    fn record_completion(&mut self, segment_time: Time) {
+        self.completions += 1;
+        self.last_time = segment_time;
+
+        for method in TimingMethod::all() {
+            let Some(time) = segment_time[method] else {
+                continue;
+            };
+            self.timed_completions[method as usize] += 1;
+            self.total_time[method] = Some(self.total_time[method].unwrap_or_default() + time);
+            if self.best_time[method].is_none_or(|best| time < best) {
+                self.best_time[method] = Some(time);
+            }
+        }
+    }
+
+    /// The number of times the segment was attempted.
+    pub const fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// The number of times the segment was completed.
+    pub const fn completions(&self) -> u32 {
+        self.completions
+    }
+
+    /// The fastest time the segment was completed in.
+    pub const fn best_time(&self) -> Time {
+        self.best_time
+    }
+
+    /// The time of the most recent completion of the segment.
+    pub const fn last_time(&self) -> Time {
+        self.last_time
+    }
+
+    /// The average time of all the completions of the segment.
+    pub fn average_time(&self) -> Time {
+        let mut average = Time::new();
+        for method in TimingMethod::all() {
+            let count = self.timed_completions[method as usize];
+            if count > 0 {
+                average[method] = self.total_time[method]
+                    .map(|total| TimeSpan::from_seconds(total.total_seconds() / count as f64));
+            }
+        }
+        average
+    }
+}
@@ -0,0 +1,160 @@
+use super::{Result, Timer};
+use crate::{
+    comparison::{personal_best, ComparisonGenerator, Ghost, RACE_COMPARISON_PREFIX},
+    platform::prelude::*,
+    AtomicDateTime, Run, TimeStamp,
+};
+
+/// A `RaceTimer` manages multiple [`Timer`]s that are controlled in lockstep.
+/// Every command is applied to all of the timers at the exact same point in
+/// time, so that their times are directly comparable. This can be used for
+/// local races, where multiple runners share a single command stream.
+/// Additionally ghosts can be added, which are previously recorded runs that
+/// are shown as comparisons in all of the timers, so that they can be raced
+/// against in real time.
+///
+/// All the commands return the result of each individual timer in the order
+/// the timers were added.
+#[derive(Debug, Clone, Default)]
+pub struct RaceTimer {
+    timers: Vec<Timer>,
+}
+
+impl RaceTimer {
+    /// Creates a new race timer without any timers.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a timer to the race.
+    pub fn push(&mut self, timer: Timer) {
+        self.timers.push(timer);
+    }
+
+    /// Accesses the timers that are part of the race.
+    pub fn timers(&self) -> &[Timer] {
+        &self.timers
+    }
+
+    /// Grants mutable access to the timers that are part of the race. This
+    /// can be used to control the timers individually.
+    pub fn timers_mut(&mut self) -> &mut [Timer] {
+        &mut self.timers
+    }
+
+    /// Consumes the race timer and returns all of its timers.
+    pub fn into_timers(self) -> Vec<Timer> {
+        self.timers
+    }
+
+    /// Adds a ghost to all of the timers. The Personal Best of the run
+    /// provided is shown as a comparison with the name provided, prefixed by
+    /// [`RACE_COMPARISON_PREFIX`]. The split times are matched to the segments
+    /// by their index. A ghost with the same name gets replaced.
+    pub fn add_ghost(&mut self, name: &str, ghost: &Run) {
+        let ghost = Ghost::from_run(name, ghost, personal_best::NAME);
+        for timer in &mut self.timers {
+            let generators = timer.run.comparison_generators_mut();
+            generators.retain(|g| g.name() != ghost.name());
+            generators.push(Box::new(ghost.clone()));
+            timer.run.regenerate_comparisons();
+        }
+    }
+
+    /// Removes the ghost with the name provided from all of the timers. The
+    /// ghost's times are removed from all the segments, so they don't end up
+    /// getting saved. If a timer currently compares against the ghost, it
+    /// switches back to the Personal Best.
+    pub fn remove_ghost(&mut self, name: &str) {
+        let name = format!("{RACE_COMPARISON_PREFIX} {name}");
+        for timer in &mut self.timers {
+            timer
+                .run
+                .comparison_generators_mut()
+                .retain(|g| g.name() != name);
+            for segment in timer.run.segments_mut() {
+                segment.comparisons_mut().remove(&name);
+            }
+            if timer.current_comparison == name {
+                timer.current_comparison = personal_best::NAME.into();
+            }
+        }
+    }
+
+    /// Returns the current point in time according to the time source of the
+    /// first timer. All the timers are expected to share the same time source.
+    fn now(&self) -> TimeStamp {
+        self.timers.first().map_or_else(TimeStamp::now, Timer::now)
+    }
+
+    /// Starts all of the timers at the same point in time.
+    pub fn start(&mut self) -> Vec<Result> {
+        let (attempt_started, start_time) = (AtomicDateTime::now(), self.now());
+        self.timers
+            .iter_mut()
+            .map(|t| t.start_at_time_stamp(attempt_started, start_time))
+            .collect()
+    }
+
+    /// Splits all of the timers at the same point in time.
+    pub fn split(&mut self) -> Vec<Result> {
+        let now = self.now();
+        self.timers
+            .iter_mut()
+            .map(|t| t.split_at_time_stamp(now))
+            .collect()
+    }
+
+    /// Starts all of the timers that are not running and splits the others at
+    /// the same point in time.
+    pub fn split_or_start(&mut self) -> Vec<Result> {
+        let (attempt_started, now) = (AtomicDateTime::now(), self.now());
+        self.timers
+            .iter_mut()
+            .map(|t| {
+                if t.active_attempt.is_none() {
+                    t.start_at_time_stamp(attempt_started, now)
+                } else {
+                    t.split_at_time_stamp(now)
+                }
+            })
+            .collect()
+    }
+
+    /// Skips the current split of all of the timers.
+    pub fn skip_split(&mut self) -> Vec<Result> {
+        self.timers.iter_mut().map(Timer::skip_split).collect()
+    }
+
+    /// Undoes the last split of all of the timers.
+    pub fn undo_split(&mut self) -> Vec<Result> {
+        self.timers.iter_mut().map(Timer::undo_split).collect()
+    }
+
+    /// Pauses all of the timers at the same point in time.
+    pub fn pause(&mut self) -> Vec<Result> {
+        let now = self.now();
+        self.timers
+            .iter_mut()
+            .map(|t| t.pause_at_time_stamp(now))
+            .collect()
+    }
+
+    /// Resumes all of the timers at the same point in time.
+    pub fn resume(&mut self) -> Vec<Result> {
+        let now = self.now();
+        self.timers
+            .iter_mut()
+            .map(|t| t.resume_at_time_stamp(now))
+            .collect()
+    }
+
+    /// Resets all of the timers. If the splits are to be updated, all the
+    /// information of the current attempts is stored in the runs' histories.
+    pub fn reset(&mut self, update_splits: bool) -> Vec<Result> {
+        self.timers
+            .iter_mut()
+            .map(|t| t.reset(update_splits))
+            .collect()
+    }
+}
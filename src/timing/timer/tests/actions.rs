@@ -0,0 +1,79 @@
+use crate::{
+    run::{parser, saver, ActionKind},
+    TimeSpan,
+};
+
+use super::timer;
+
+fn kinds(actions: &[crate::run::Action]) -> Vec<ActionKind> {
+    actions.iter().map(|a| a.kind).collect()
+}
+
+#[test]
+fn commands_are_logged() {
+    let mut timer = timer();
+    assert!(timer.actions().is_empty());
+
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    timer.split().unwrap();
+    timer.undo_split().unwrap();
+    timer.pause().unwrap();
+    timer.resume().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+    timer.skip_split().unwrap();
+
+    assert_eq!(
+        kinds(timer.actions()),
+        [
+            ActionKind::Start,
+            ActionKind::InitializeGameTime,
+            ActionKind::Split,
+            ActionKind::UndoSplit,
+            ActionKind::Pause,
+            ActionKind::Resume,
+            ActionKind::SetGameTime(TimeSpan::from_seconds(5.0)),
+            ActionKind::SkipSplit,
+        ]
+    );
+    assert!(timer.actions().windows(2).all(|w| w[0].time <= w[1].time));
+}
+
+#[test]
+fn failed_commands_are_not_logged() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.resume().unwrap_err();
+    timer.undo_split().unwrap_err();
+
+    assert_eq!(kinds(timer.actions()), [ActionKind::Start]);
+}
+
+#[test]
+fn log_is_stored_with_the_attempt() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.split().unwrap();
+    timer
+        .set_loading_times(TimeSpan::from_seconds(1.5))
+        .unwrap();
+    timer.reset(true).unwrap();
+
+    assert!(timer.actions().is_empty());
+
+    let expected = [
+        ActionKind::Start,
+        ActionKind::Split,
+        ActionKind::SetLoadingTimes(TimeSpan::from_seconds(1.5)),
+        ActionKind::Reset,
+    ];
+    let attempt = timer.run().attempt_history().last().unwrap();
+    assert_eq!(kinds(attempt.actions()), expected);
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(timer.run(), &mut buf).unwrap();
+    let run = parser::livesplit::parse(&buf).unwrap();
+
+    let parsed = run.attempt_history().last().unwrap();
+    assert_eq!(kinds(parsed.actions()), expected);
+}
@@ -0,0 +1,192 @@
+use crate::{
+    event::{Error, Event},
+    run::ActionKind,
+    TimeSpan, TimerPhase,
+};
+
+use super::{time_source::VirtualClock, timer};
+
+fn timer_with_window() -> crate::Timer {
+    let mut timer = timer();
+    timer.set_split_correction_window(TimeSpan::from_seconds(3600.0));
+    timer
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.split().unwrap();
+
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::from_seconds(-0.2)),
+        Err(Error::SplitCorrectionWindowClosed)
+    );
+}
+
+#[test]
+fn requires_a_split() {
+    let mut timer = timer_with_window();
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::zero()),
+        Err(Error::NoRunInProgress)
+    );
+
+    timer.start().unwrap();
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::zero()),
+        Err(Error::SplitCorrectionWindowClosed)
+    );
+}
+
+#[test]
+fn adjusts_the_last_split_time() {
+    let clock = VirtualClock::new();
+    let mut timer = timer_with_window();
+    timer.set_time_source(clock.clone()).unwrap();
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    clock.advance(10.0);
+    timer.split().unwrap();
+    clock.advance(6.0);
+
+    let before = timer.run().segment(0).split_time();
+    let delta = TimeSpan::from_seconds(5.0);
+    assert_eq!(timer.adjust_last_split(delta), Ok(Event::SplitAdjusted));
+
+    let after = timer.run().segment(0).split_time();
+    assert_eq!(after.real_time, before.real_time.map(|t| t + delta));
+    assert_eq!(after.game_time, before.game_time.map(|t| t + delta));
+    assert_eq!(
+        timer.actions().last().unwrap().kind,
+        ActionKind::AdjustSplit(delta)
+    );
+}
+
+#[test]
+fn cant_move_before_the_previous_split() {
+    let mut timer = timer_with_window();
+    timer.start().unwrap();
+    timer.split().unwrap();
+
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::from_seconds(-3600.0)),
+        Err(Error::NegativeTime)
+    );
+}
+
+#[test]
+fn cant_move_before_the_previous_split_in_game_time() {
+    let clock = VirtualClock::new();
+    let mut timer = timer_with_window();
+    timer.set_time_source(clock.clone()).unwrap();
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    clock.advance(10.0);
+    timer.set_game_time(TimeSpan::zero()).unwrap();
+    timer.split().unwrap();
+    clock.advance(10.0);
+    timer.set_game_time(TimeSpan::from_seconds(1.0)).unwrap();
+    timer.split().unwrap();
+
+    let before = timer.run().segment(1).split_time();
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::from_seconds(-5.0)),
+        Err(Error::NegativeTime)
+    );
+    assert_eq!(timer.run().segment(1).split_time(), before);
+}
+
+#[test]
+fn cant_move_past_the_current_time() {
+    let clock = VirtualClock::new();
+    let mut timer = timer_with_window();
+    timer.set_time_source(clock.clone()).unwrap();
+    timer.start().unwrap();
+    clock.advance(10.0);
+    timer.split().unwrap();
+    clock.advance(2.0);
+
+    let before = timer.run().segment(0).split_time();
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::from_seconds(3.0)),
+        Err(Error::SplitTimeInFuture)
+    );
+    assert_eq!(timer.run().segment(0).split_time(), before);
+
+    let delta = TimeSpan::from_seconds(2.0);
+    assert_eq!(timer.adjust_last_split(delta), Ok(Event::SplitAdjusted));
+    assert_eq!(
+        timer.run().segment(0).split_time().real_time,
+        before.real_time.map(|t| t + delta)
+    );
+}
+
+#[test]
+fn cant_move_past_the_current_time_in_game_time() {
+    let clock = VirtualClock::new();
+    let mut timer = timer_with_window();
+    timer.set_time_source(clock.clone()).unwrap();
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    clock.advance(10.0);
+    timer.split().unwrap();
+    timer.pause_game_time().unwrap();
+    clock.advance(5.0);
+
+    // The real time would allow it, but the game time is paused.
+    let before = timer.run().segment(0).split_time();
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::from_seconds(1.0)),
+        Err(Error::SplitTimeInFuture)
+    );
+    assert_eq!(timer.run().segment(0).split_time(), before);
+}
+
+#[test]
+fn undoing_or_skipping_closes_the_window() {
+    let mut timer = timer_with_window();
+    timer.start().unwrap();
+    timer.split().unwrap();
+    timer.undo_split().unwrap();
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::zero()),
+        Err(Error::SplitCorrectionWindowClosed)
+    );
+
+    timer.split().unwrap();
+    timer.skip_split().unwrap();
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::zero()),
+        Err(Error::SplitCorrectionWindowClosed)
+    );
+}
+
+#[test]
+fn final_split_can_be_adjusted() {
+    let clock = VirtualClock::new();
+    let mut timer = timer_with_window();
+    timer.set_time_source(clock.clone()).unwrap();
+    timer.start().unwrap();
+    for _ in 0..3 {
+        clock.advance(1.0);
+        timer.split().unwrap();
+    }
+    clock.advance(1.0);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    // The current time is the final split time, so it can only be moved
+    // back.
+    assert_eq!(
+        timer.adjust_last_split(TimeSpan::from_seconds(1.0)),
+        Err(Error::SplitTimeInFuture)
+    );
+
+    let before = timer.snapshot().current_time().real_time.unwrap();
+    let delta = TimeSpan::from_seconds(-0.5);
+    assert_eq!(timer.adjust_last_split(delta), Ok(Event::SplitAdjusted));
+    assert_eq!(
+        timer.snapshot().current_time().real_time,
+        Some(before + delta)
+    );
+}
@@ -4,7 +4,7 @@ use crate::{
     TimeSpan, Timer,
 };
 
-use super::{run, timer};
+use super::{run, time_source::VirtualClock, timer};
 
 mod start {
     use super::*;
@@ -98,6 +98,77 @@ mod split {
 
         assert_eq!(error, Error::TimerPaused);
     }
+
+    #[test]
+    fn too_soon_after_start_fails() {
+        let mut timer = timer();
+        timer.set_minimum_split_interval(TimeSpan::from_seconds(1000.0));
+
+        timer.start().unwrap();
+        let error = timer.split().unwrap_err();
+
+        assert_eq!(error, Error::SplitTooSoon);
+        assert_eq!(timer.current_split_index(), Some(0));
+    }
+
+    #[test]
+    fn too_soon_after_previous_split_fails() {
+        let mut timer = timer();
+
+        timer.start().unwrap();
+        timer.split().unwrap();
+        timer.set_minimum_split_interval(TimeSpan::from_seconds(1000.0));
+        let error = timer.split().unwrap_err();
+
+        assert_eq!(error, Error::SplitTooSoon);
+        assert_eq!(timer.current_split_index(), Some(1));
+    }
+
+    fn debounced_timer() -> (Timer, VirtualClock) {
+        let clock = VirtualClock::new();
+        let mut timer = timer();
+        timer.set_time_source(clock.clone()).unwrap();
+        timer.set_minimum_split_interval(TimeSpan::from_seconds(1.0));
+        timer.start().unwrap();
+        clock.advance(5.0);
+        timer.split().unwrap();
+        clock.advance(0.5);
+        (timer, clock)
+    }
+
+    #[test]
+    fn undo_restores_the_previous_split() {
+        let (mut timer, _) = debounced_timer();
+
+        timer.undo_split().unwrap();
+        let event = timer.split().unwrap();
+
+        assert_eq!(event, Event::Splitted);
+    }
+
+    #[test]
+    fn skip_keeps_the_previous_split() {
+        let (mut timer, clock) = debounced_timer();
+
+        timer.skip_split().unwrap();
+        assert_eq!(timer.split().unwrap_err(), Error::SplitTooSoon);
+
+        timer.undo_split().unwrap();
+        clock.advance(0.5);
+        assert_eq!(timer.split().unwrap(), Event::Splitted);
+    }
+
+    #[test]
+    fn reset_clears_the_previous_split() {
+        let (mut timer, clock) = debounced_timer();
+
+        timer.reset(false).unwrap();
+        timer.start().unwrap();
+        assert_eq!(timer.split().unwrap_err(), Error::SplitTooSoon);
+
+        clock.advance(1.0);
+        assert_eq!(timer.split().unwrap(), Event::Splitted);
+    }
 }
 
 mod split_or_start {
@@ -0,0 +1,81 @@
+use crate::{
+    event::{Error, Event},
+    TimeSpan, TimeStamp,
+};
+
+use super::timer;
+
+#[test]
+fn requires_a_run_in_progress() {
+    let mut timer = timer();
+
+    assert_eq!(timer.start_loading(), Err(Error::NoRunInProgress));
+    assert_eq!(timer.end_loading(), Err(Error::NoRunInProgress));
+    assert!(!timer.is_loading());
+}
+
+#[test]
+fn initializes_and_pauses_game_time() {
+    let mut timer = timer();
+    timer.start().unwrap();
+
+    assert!(!timer.is_game_time_initialized());
+    assert_eq!(timer.start_loading(), Ok(Event::LoadingStarted));
+
+    assert!(timer.is_loading());
+    assert!(timer.is_game_time_initialized());
+    assert!(timer.is_game_time_paused());
+    assert_eq!(timer.start_loading(), Err(Error::AlreadyLoading));
+}
+
+#[test]
+fn accumulates_the_time_spent_loading() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+
+    let mut expected = TimeSpan::zero();
+    for _ in 0..2 {
+        let started = TimeStamp::now();
+        let ended = TimeStamp::now();
+
+        assert_eq!(timer.start_loading_at(started), Ok(Event::LoadingStarted));
+        assert_eq!(timer.end_loading_at(ended), Ok(Event::LoadingEnded));
+        expected += ended - started;
+
+        assert!(!timer.is_loading());
+        assert!(!timer.is_game_time_paused());
+        assert_eq!(timer.loading_times(), expected);
+    }
+
+    assert_eq!(timer.end_loading(), Err(Error::NotLoading));
+}
+
+#[test]
+fn time_spent_paused_is_not_counted_as_loading() {
+    let mut timer = timer();
+    timer.start().unwrap();
+
+    timer.start_loading().unwrap();
+    timer.pause().unwrap();
+    let paused_game_time = timer.snapshot().current_time().game_time;
+    timer.end_loading().unwrap();
+
+    assert_eq!(timer.snapshot().current_time().game_time, paused_game_time);
+}
+
+#[test]
+fn keeps_game_time_paused_if_it_was_paused_before() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    timer.pause_game_time().unwrap();
+    let paused_game_time = timer.snapshot().current_time().game_time;
+
+    timer.start_loading().unwrap();
+    assert_eq!(timer.snapshot().current_time().game_time, paused_game_time);
+    timer.end_loading().unwrap();
+
+    assert!(timer.is_game_time_paused());
+    assert_eq!(timer.snapshot().current_time().game_time, paused_game_time);
+}
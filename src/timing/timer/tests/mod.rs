@@ -6,8 +6,17 @@ use crate::{
     Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 
+mod actions;
+mod adjust_split;
 mod events;
+mod loading;
 mod mark_as_modified;
+mod pause_statistics;
+mod pending_reset;
+mod practice;
+mod race;
+mod scheduled_start;
+mod time_source;
 mod variables;
 
 fn run() -> Run {
@@ -686,6 +695,6 @@ fn paused_then_resumed_game_time_lags_behind_real_time() {
         .active_attempt
         .as_ref()
         .unwrap()
-        .current_time(timer.run());
+        .current_time(timer.run(), timer.now());
     assert!(time.game_time.unwrap() < time.real_time);
 }
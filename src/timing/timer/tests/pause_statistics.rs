@@ -0,0 +1,95 @@
+use crate::{
+    run::{parser, saver, PauseStatistics},
+    TimeSpan,
+};
+
+use super::{time_source::VirtualClock, timer};
+
+fn seconds(seconds: f64) -> TimeSpan {
+    TimeSpan::from_seconds(seconds)
+}
+
+fn paused_timer() -> (crate::Timer, VirtualClock) {
+    let clock = VirtualClock::new();
+    let mut timer = timer();
+    timer.set_time_source(clock.clone()).unwrap();
+
+    timer.start().unwrap();
+    clock.advance(1.0);
+    timer.pause().unwrap();
+    clock.advance(4.0);
+    timer.resume().unwrap();
+    clock.advance(1.0);
+    timer.pause().unwrap();
+    clock.advance(2.0);
+    timer.resume().unwrap();
+
+    (timer, clock)
+}
+
+#[test]
+fn no_statistics_without_attempt() {
+    assert_eq!(timer().pause_statistics(), None);
+}
+
+#[test]
+fn counts_pauses() {
+    let (timer, _) = paused_timer();
+
+    assert_eq!(
+        timer.pause_statistics(),
+        Some(PauseStatistics {
+            total: seconds(6.0),
+            count: 2,
+            longest: seconds(4.0),
+        })
+    );
+}
+
+#[test]
+fn includes_ongoing_pause() {
+    let (mut timer, clock) = paused_timer();
+    timer.pause().unwrap();
+    clock.advance(5.0);
+
+    assert_eq!(
+        timer.pause_statistics(),
+        Some(PauseStatistics {
+            total: seconds(11.0),
+            count: 3,
+            longest: seconds(5.0),
+        })
+    );
+}
+
+#[test]
+fn undoing_pauses_clears_statistics() {
+    let (mut timer, _) = paused_timer();
+    timer.undo_all_pauses().unwrap();
+
+    assert_eq!(timer.pause_statistics(), Some(PauseStatistics::default()));
+}
+
+#[test]
+fn statistics_are_stored_in_attempt_history() {
+    let (mut timer, _) = paused_timer();
+    timer.reset(true).unwrap();
+
+    let attempt = timer.run().attempt_history().last().unwrap();
+    assert_eq!(attempt.pause_count(), Some(2));
+    assert_eq!(attempt.longest_pause(), Some(seconds(4.0)));
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(timer.run(), &mut buf).unwrap();
+    let run = parser::livesplit::parse(&buf).unwrap();
+
+    let attempt = run.attempt_history().last().unwrap();
+    assert_eq!(
+        attempt.pause_statistics(),
+        Some(PauseStatistics {
+            total: seconds(6.0),
+            count: 2,
+            longest: seconds(4.0),
+        })
+    );
+}
@@ -0,0 +1,55 @@
+use crate::{
+    event::{Error, Event},
+    util::tests_helper::{make_progress_run_with_splits_opt, start_run},
+    TimeSpan, TimerPhase,
+};
+
+use super::timer;
+
+#[test]
+fn requires_a_run_in_progress() {
+    let mut timer = timer();
+    assert_eq!(timer.request_reset().unwrap_err(), Error::NoRunInProgress);
+}
+
+#[test]
+fn cancelling_keeps_the_attempt() {
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    let pending = timer.request_reset().unwrap();
+    assert_eq!(pending.cancel(), Error::RunnerDecidedAgainstReset);
+
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert!(timer.run().attempt_history().is_empty());
+}
+
+#[test]
+fn exposes_the_attempt_that_would_be_stored() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0)]);
+
+    let pending = timer.request_reset().unwrap();
+    assert!(pending.has_new_best_times());
+
+    let attempt = pending.attempt();
+    assert_eq!(attempt.index(), 1);
+    assert_eq!(attempt.time().game_time, None);
+
+    let updated = pending.run_after_reset(true);
+    assert_eq!(updated.attempt_history().len(), 1);
+    assert_eq!(
+        updated.segment(0).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(1.0)),
+    );
+    assert!(pending.run_after_reset(false).attempt_history().is_empty());
+
+    assert_eq!(pending.confirm(true), Ok(Event::Reset));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.run().attempt_history()[0].index(), attempt.index());
+    assert_eq!(
+        timer.run().attempt_history()[0].actions().len(),
+        attempt.actions().len(),
+    );
+}
@@ -0,0 +1,130 @@
+use crate::{
+    event::Error,
+    util::tests_helper::{make_progress_run_with_splits_opt, run_with_splits, start_run},
+    TimeSpan, TimingMethod,
+};
+
+use super::timer;
+
+#[test]
+fn cant_be_changed_during_an_attempt() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    assert_eq!(
+        timer.set_practice_mode(true),
+        Err(Error::RunAlreadyInProgress)
+    );
+    assert!(!timer.is_practice_mode());
+}
+
+#[test]
+fn doesnt_touch_the_run() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[3.0, 6.0, 9.0]);
+    let run = timer.run().clone();
+
+    timer.set_practice_mode(true).unwrap();
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    timer.start().unwrap();
+    timer.split().unwrap();
+    timer.reset_and_set_attempt_as_pb().unwrap();
+
+    assert_eq!(timer.run().attempt_count(), run.attempt_count());
+    assert_eq!(timer.run().attempt_history(), run.attempt_history());
+    for (segment, original) in timer.run().segments().iter().zip(run.segments()) {
+        assert_eq!(segment.best_segment_time(), original.best_segment_time());
+        assert_eq!(
+            segment.personal_best_split_time(),
+            original.personal_best_split_time()
+        );
+        assert_eq!(segment.segment_history(), original.segment_history());
+    }
+}
+
+#[test]
+fn records_segment_statistics() {
+    let mut timer = timer();
+    timer.set_practice_mode(true).unwrap();
+
+    run_with_splits(&mut timer, &[2.0, 5.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0), None, Some(12.0)]);
+    timer.reset(true).unwrap();
+
+    let statistics = timer.practice_statistics();
+
+    let first = statistics.segment(0).unwrap();
+    assert_eq!(first.attempts(), 2);
+    assert_eq!(first.completions(), 2);
+    assert_eq!(
+        first.best_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(2.0))
+    );
+    assert_eq!(
+        first.last_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(4.0))
+    );
+    assert_eq!(
+        first.average_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(3.0))
+    );
+
+    let second = statistics.segment(1).unwrap();
+    assert_eq!(second.attempts(), 2);
+    assert_eq!(second.completions(), 1);
+    assert_eq!(
+        second.best_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(3.0))
+    );
+
+    // The last segment is timed from when the previous one got skipped.
+    let third = statistics.segment(2).unwrap();
+    assert_eq!(third.attempts(), 2);
+    assert_eq!(third.completions(), 1);
+    assert_eq!(
+        third.best_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(8.0))
+    );
+
+    timer.clear_practice_statistics();
+    assert!(timer.practice_statistics().segments().is_empty());
+}
+
+#[test]
+fn times_segments_from_when_they_were_entered() {
+    let mut timer = timer();
+    timer.set_practice_mode(true).unwrap();
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(30.0)).unwrap();
+    timer.skip_split().unwrap();
+    timer.set_game_time(TimeSpan::from_seconds(45.0)).unwrap();
+    timer.split().unwrap();
+    timer.reset(true).unwrap();
+
+    let statistics = timer.practice_statistics();
+    assert_eq!(statistics.segment(0).unwrap().completions(), 0);
+
+    let second = statistics.segment(1).unwrap();
+    assert_eq!(second.attempts(), 1);
+    assert_eq!(
+        second.last_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(15.0))
+    );
+}
+
+#[test]
+fn doesnt_mark_the_run_as_modified() {
+    let mut timer = timer();
+    timer.set_practice_mode(true).unwrap();
+    timer.mark_as_unmodified();
+
+    start_run(&mut timer);
+    timer.split().unwrap();
+    timer.skip_split().unwrap();
+    timer.undo_split().unwrap();
+    timer.reset(true).unwrap();
+
+    assert!(!timer.run().has_been_modified());
+}
@@ -0,0 +1,77 @@
+use crate::{
+    comparison::RACE_COMPARISON_PREFIX, event::Event, util::tests_helper::run_with_splits,
+    TimeSpan, TimerPhase,
+};
+
+use super::{run, timer};
+
+fn race() -> crate::timing::RaceTimer {
+    let mut race = crate::timing::RaceTimer::new();
+    race.push(timer());
+    race.push(timer());
+    race
+}
+
+#[test]
+fn commands_are_applied_in_lockstep() {
+    let mut race = race();
+
+    assert_eq!(race.start(), [Ok(Event::Started), Ok(Event::Started)]);
+    assert_eq!(race.split(), [Ok(Event::Splitted), Ok(Event::Splitted)]);
+
+    let [a, b] = race.timers() else {
+        unreachable!()
+    };
+    assert_eq!(
+        a.run().segment(0).split_time(),
+        b.run().segment(0).split_time()
+    );
+
+    race.reset(false);
+    assert!(race
+        .timers()
+        .iter()
+        .all(|t| t.current_phase() == TimerPhase::NotRunning));
+}
+
+#[test]
+fn results_are_reported_per_timer() {
+    let mut race = race();
+
+    race.timers_mut()[0].start().unwrap();
+    let results = race.start();
+
+    assert!(results[0].is_err());
+    assert_eq!(results[1], Ok(Event::Started));
+}
+
+#[test]
+fn ghosts_are_added_as_race_comparisons() {
+    let mut ghost = crate::Timer::new(run()).unwrap();
+    run_with_splits(&mut ghost, &[1.0, 2.0, 3.0]);
+    let ghost = ghost.into_run(true);
+
+    let mut race = race();
+    race.add_ghost("Alice", &ghost);
+
+    let name = format!("{RACE_COMPARISON_PREFIX} Alice");
+    for timer in race.timers_mut() {
+        assert!(timer.run().comparisons().any(|c| c == name));
+        assert_eq!(
+            timer.run().segment(2).comparison(&name).game_time,
+            Some(TimeSpan::from_seconds(3.0))
+        );
+        timer.set_current_comparison(name.as_str()).unwrap();
+    }
+
+    race.remove_ghost("Alice");
+    for timer in race.timers() {
+        assert!(!timer.run().comparisons().any(|c| c == name));
+        let mut run = timer.run().clone();
+        assert!(run
+            .segments_mut()
+            .iter_mut()
+            .all(|s| s.comparisons_mut().remove(&name).is_none()));
+        assert_eq!(timer.current_comparison(), "Personal Best");
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    event::{Error, Event},
+    platform::Duration,
+    AtomicDateTime, TimeSpan, TimerPhase,
+};
+
+use super::timer;
+
+#[test]
+fn start_in_the_future_is_only_armed() {
+    let mut timer = timer();
+
+    let start = AtomicDateTime::now().time + Duration::hours(1);
+    assert_eq!(timer.start_at(start), Ok(Event::StartScheduled));
+
+    assert!(timer.is_start_scheduled());
+    assert_eq!(timer.scheduled_start(), Some(start));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.update_scheduled_start(), None);
+
+    let remaining = timer.time_until_scheduled_start().unwrap();
+    assert!(remaining > TimeSpan::from_seconds(3590.0));
+
+    // The time counts up towards the start, so it can be shown as a countdown.
+    let time = timer.snapshot().current_time().real_time.unwrap();
+    assert!(time < TimeSpan::from_seconds(-3590.0));
+}
+
+#[test]
+fn start_in_the_past_starts_immediately() {
+    let mut timer = timer();
+
+    let start = AtomicDateTime::now().time - Duration::seconds(10);
+    assert_eq!(timer.start_at(start), Ok(Event::Started));
+
+    assert!(!timer.is_start_scheduled());
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+
+    // The attempt is treated as if it started at the scheduled time.
+    let time = timer.snapshot().current_time().real_time.unwrap();
+    assert!(time >= TimeSpan::from_seconds(10.0));
+}
+
+#[test]
+fn can_be_cancelled() {
+    let mut timer = timer();
+
+    assert_eq!(timer.cancel_scheduled_start(), Err(Error::NoStartScheduled));
+
+    timer
+        .start_at(AtomicDateTime::now().time + Duration::hours(1))
+        .unwrap();
+    assert_eq!(
+        timer.cancel_scheduled_start(),
+        Ok(Event::ScheduledStartCancelled)
+    );
+    assert!(!timer.is_start_scheduled());
+    assert_eq!(timer.time_until_scheduled_start(), None);
+}
+
+#[test]
+fn manual_start_replaces_scheduled_start() {
+    let mut timer = timer();
+
+    timer
+        .start_at(AtomicDateTime::now().time + Duration::hours(1))
+        .unwrap();
+    timer.start().unwrap();
+
+    assert!(!timer.is_start_scheduled());
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+}
+
+#[test]
+fn cant_be_armed_during_an_attempt() {
+    let mut timer = timer();
+
+    timer.start().unwrap();
+    assert_eq!(
+        timer.start_at(AtomicDateTime::now().time + Duration::hours(1)),
+        Err(Error::RunAlreadyInProgress)
+    );
+}
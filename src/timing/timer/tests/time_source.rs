@@ -0,0 +1,97 @@
+use crate::{event::Error, platform::Arc, timing::TimeSource, Time, TimeSpan, TimeStamp};
+use std::sync::Mutex;
+
+use super::timer;
+
+/// A virtual clock that only advances when told to.
+#[derive(Debug, Clone)]
+pub(super) struct VirtualClock {
+    base: TimeStamp,
+    elapsed: Arc<Mutex<TimeSpan>>,
+}
+
+impl VirtualClock {
+    pub(super) fn new() -> Self {
+        Self {
+            base: TimeStamp::now(),
+            elapsed: Arc::new(Mutex::new(TimeSpan::zero())),
+        }
+    }
+
+    pub(super) fn advance(&self, seconds: f64) {
+        *self.elapsed.lock().unwrap() += TimeSpan::from_seconds(seconds);
+    }
+}
+
+impl TimeSource for VirtualClock {
+    fn now(&self) -> TimeStamp {
+        self.base + *self.elapsed.lock().unwrap()
+    }
+}
+
+fn real_time(seconds: f64) -> Option<TimeSpan> {
+    Some(TimeSpan::from_seconds(seconds))
+}
+
+#[test]
+fn virtual_clock_drives_split_times() {
+    let clock = VirtualClock::new();
+    let mut timer = timer();
+    timer.set_time_source(clock.clone()).unwrap();
+
+    timer.start().unwrap();
+    clock.advance(10.0);
+    assert_eq!(timer.snapshot().current_time().real_time, real_time(10.0));
+
+    timer.split().unwrap();
+    clock.advance(5.0);
+    timer.pause().unwrap();
+    clock.advance(100.0);
+    timer.resume().unwrap();
+    clock.advance(2.5);
+    timer.split().unwrap();
+
+    let segments = timer.run().segments();
+    assert_eq!(segments[0].split_time().real_time, real_time(10.0));
+    assert_eq!(segments[1].split_time().real_time, real_time(17.5));
+    assert_eq!(timer.get_pause_time(), real_time(100.0));
+    assert_eq!(
+        timer.current_attempt_duration(),
+        TimeSpan::from_seconds(117.5)
+    );
+}
+
+#[test]
+fn attempt_history_uses_time_source() {
+    let clock = VirtualClock::new();
+    let mut timer = timer();
+    timer.set_time_source(clock.clone()).unwrap();
+
+    timer.start().unwrap();
+    clock.advance(3.0);
+    timer.pause().unwrap();
+    clock.advance(7.0);
+    timer.reset(true).unwrap();
+
+    let attempt = timer.run().attempt_history().last().unwrap();
+    assert_eq!(attempt.pause_time(), real_time(7.0));
+    assert_eq!(attempt.time(), Time::new());
+}
+
+#[test]
+fn time_source_cant_change_during_attempt() {
+    let clock = VirtualClock::new();
+    let mut timer = timer();
+
+    timer.start().unwrap();
+    assert!(matches!(
+        timer.set_time_source(clock.clone()),
+        Err(Error::RunAlreadyInProgress),
+    ));
+
+    timer.reset(true).unwrap();
+    timer.set_time_source(clock.clone()).unwrap();
+    timer.start().unwrap();
+    clock.advance(4.0);
+    assert_eq!(timer.snapshot().current_time().real_time, real_time(4.0));
+}
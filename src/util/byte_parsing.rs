@@ -62,6 +62,19 @@ pub mod big_endian {
     }
 }
 
+#[cfg(any(feature = "gzip", feature = "zip-import"))]
+pub mod little_endian {
+    use super::strip_pod;
+
+    pub fn strip_u16(cursor: &mut &[u8]) -> Option<u16> {
+        Some(u16::from_le_bytes(*strip_pod(cursor)?))
+    }
+
+    pub fn strip_u32(cursor: &mut &[u8]) -> Option<u32> {
+        Some(u32::from_le_bytes(*strip_pod(cursor)?))
+    }
+}
+
 pub fn strip_u8(cursor: &mut &[u8]) -> Option<u8> {
     strip_pod(cursor).copied()
 }
@@ -0,0 +1,103 @@
+//! Minimal support for the gzip file format (RFC 1952), which wraps a single
+//! DEFLATE stream with a header and a checksum.
+
+use crate::platform::prelude::*;
+
+use super::byte_parsing::{
+    little_endian::{strip_u16, strip_u32},
+    strip_slice, strip_u8,
+};
+
+const MAGIC: [u8; 2] = [0x1F, 0x8B];
+const DEFLATE: u8 = 8;
+const FHCRC: u8 = 1 << 1;
+const FEXTRA: u8 = 1 << 2;
+const FNAME: u8 = 1 << 3;
+const FCOMMENT: u8 = 1 << 4;
+const OS_UNKNOWN: u8 = 255;
+const COMPRESSION_LEVEL: u8 = 6;
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &byte| {
+        CRC_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Checks whether the data starts like a gzip file.
+pub fn is_gzip(data: &[u8]) -> bool {
+    data.starts_with(&MAGIC)
+}
+
+/// Compresses the data as a gzip file.
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(data.len() / 4 + 18);
+    buf.extend_from_slice(&MAGIC);
+    // Compression method, flags, modification time, extra flags and the OS.
+    buf.extend_from_slice(&[DEFLATE, 0, 0, 0, 0, 0, 0, OS_UNKNOWN]);
+    buf.extend_from_slice(&miniz_oxide::deflate::compress_to_vec(
+        data,
+        COMPRESSION_LEVEL,
+    ));
+    buf.extend_from_slice(&crc32(data).to_le_bytes());
+    buf.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    buf
+}
+
+/// Decompresses a gzip file. Only the first member of the file is
+/// decompressed. [`None`] is returned if the data is not a valid gzip file or
+/// if its checksum doesn't match.
+pub fn decompress(data: &[u8]) -> Option<Vec<u8>> {
+    let cursor = &mut data.strip_prefix(&MAGIC)?;
+    if strip_u8(cursor)? != DEFLATE {
+        return None;
+    }
+    let flags = strip_u8(cursor)?;
+    strip_slice::<u8>(cursor, 6)?;
+    if flags & FEXTRA != 0 {
+        let len = strip_u16(cursor)?;
+        strip_slice::<u8>(cursor, len as usize)?;
+    }
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let len = cursor.iter().position(|&b| b == 0)?;
+            strip_slice::<u8>(cursor, len + 1)?;
+        }
+    }
+    if flags & FHCRC != 0 {
+        strip_u16(cursor)?;
+    }
+
+    let (compressed, trailer) = cursor.split_at(cursor.len().checked_sub(8)?);
+    let trailer = &mut &*trailer;
+    let crc = strip_u32(trailer)?;
+    let len = strip_u32(trailer)?;
+
+    // The length stored in the trailer limits how much memory a malicious
+    // file can make us allocate.
+    let decompressed =
+        miniz_oxide::inflate::decompress_to_vec_with_limit(compressed, len as usize).ok()?;
+    if decompressed.len() != len as usize || crc32(&decompressed) != crc {
+        return None;
+    }
+    Some(decompressed)
+}
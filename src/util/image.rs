@@ -1,6 +1,11 @@
-use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+#[cfg(any(
+    feature = "image-shrinking",
+    feature = "parser-llanfair",
+    feature = "parser-llanfair-gered"
+))]
+pub fn create_reencoder(target_buf: &mut Vec<u8>) -> image::codecs::png::PngEncoder<&mut Vec<u8>> {
+    use image::codecs::png::{CompressionType, FilterType, PngEncoder};
 
-pub fn create_reencoder(target_buf: &mut Vec<u8>) -> PngEncoder<&mut Vec<u8>> {
     PngEncoder::new_with_quality(target_buf, CompressionType::Best, FilterType::default())
 }
 
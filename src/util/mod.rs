@@ -2,9 +2,15 @@
 
 pub(crate) mod ascii_char;
 pub(crate) mod ascii_set;
+#[cfg_attr(
+    not(all(feature = "parser-llanfair", feature = "parser-llanfair-gered")),
+    allow(dead_code)
+)]
 pub(crate) mod byte_parsing;
 pub(crate) mod caseless;
 mod clear_vec;
+#[cfg(feature = "gzip")]
+pub(crate) mod gzip;
 #[cfg(feature = "std")]
 pub(crate) mod image;
 pub(crate) mod not_nan;
@@ -166,6 +166,28 @@ pub fn reencode_children(reader: &mut Reader<'_>, target_buf: &mut String) -> Re
     }
 }
 
+/// Reencodes the element that was just opened, including its own start and
+/// end tag.
+pub fn reencode_element(
+    reader: &mut Reader<'_>,
+    tag: TagName<'_>,
+    attributes: Attributes<'_>,
+    target_buf: &mut String,
+) -> Result<(), Error> {
+    Writer::new_skip_header(&mut *target_buf)
+        .just_start_tag(tag.name(), |tag| {
+            for (k, v) in attributes.iter() {
+                tag.attribute(k, v)?;
+            }
+            Ok(())
+        })
+        .map_err(|fmt::Error| Error::Xml)?;
+    reencode_children(reader, target_buf)?;
+    Writer::new_skip_header(target_buf)
+        .just_end_tag(tag.name())
+        .map_err(|_| Error::Xml)
+}
+
 pub fn end_tag<E>(reader: &mut Reader<'_>) -> Result<(), E>
 where
     E: From<Error>,
@@ -188,6 +210,7 @@ where
     }
 }
 
+#[cfg(feature = "parser-llanfair-gered")]
 pub fn single_child<F, T, E>(reader: &mut Reader<'_>, tag: &str, mut f: F) -> Result<T, E>
 where
     F: FnMut(&mut Reader<'_>, Attributes<'_>) -> Result<T, E>,
@@ -341,22 +364,27 @@ where
     E: From<Error>,
 {
     text_as_escaped_string_err(reader, |text| {
-        if text.len() >= 216 {
-            let src = &text.as_bytes()[212..];
+        f(decode_image(text.as_bytes(), image_buf));
+        Ok(())
+    })
+}
 
-            image_buf.resize(
-                base64_simd::STANDARD.estimated_decoded_length(src.len()),
-                MaybeUninit::uninit(),
-            );
+/// Decodes the image data of an image stored as a base64 encoded .NET object.
+/// If the image can't be decoded, no data is returned.
+pub fn decode_image<'b>(text: &[u8], image_buf: &'b mut Vec<MaybeUninit<u8>>) -> &'b [u8] {
+    if text.len() >= 216 {
+        let src = &text[212..];
 
-            if let Ok(decoded) =
-                base64_simd::STANDARD.decode(src, base64_simd::Out::from_uninit_slice(image_buf))
-            {
-                f(&decoded[2..decoded.len() - 1]);
-                return Ok(());
-            }
+        image_buf.resize(
+            base64_simd::STANDARD.estimated_decoded_length(src.len()),
+            MaybeUninit::uninit(),
+        );
+
+        if let Ok(decoded) =
+            base64_simd::STANDARD.decode(src, base64_simd::Out::from_uninit_slice(image_buf))
+        {
+            return &decoded[2..decoded.len() - 1];
         }
-        f(&[]);
-        Ok(())
-    })
+    }
+    &[]
 }
@@ -7,6 +7,8 @@ use core::{
 
 pub mod helper;
 mod reader;
+#[cfg(feature = "std")]
+pub mod stream;
 mod writer;
 
 pub use self::{
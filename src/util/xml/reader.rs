@@ -34,6 +34,11 @@ impl<'a> Reader<'a> {
         }
     }
 
+    /// Returns the part of the source that hasn't been read yet.
+    pub const fn remaining(&self) -> &'a str {
+        self.source
+    }
+
     pub fn read_event(&mut self) -> Option<Event<'a>> {
         match self.state {
             TagState::Closed => Some(self.read_until_open()),
@@ -0,0 +1,289 @@
+use std::{
+    future::{poll_fn, Future},
+    io::{self, BufRead},
+    pin::pin,
+    str,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+use super::{
+    helper::Error,
+    reader::{Event, Reader},
+    Attributes, TagName,
+};
+
+/// A piece of an XML document that is read by an [`ElementStream`].
+pub enum Chunk<'a> {
+    /// The start tag of an element whose children get streamed individually.
+    Open(&'a str),
+    /// A complete element, including all of its children.
+    Element(&'a str),
+    /// The end of the element that got opened last.
+    Close,
+}
+
+/// A buffered source of bytes that may have to wait for more bytes to become
+/// available. This allows an [`ElementStream`] to read both synchronously and
+/// asynchronously.
+pub trait Source {
+    fn poll_fill_buf(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>>;
+    fn consume(&mut self, amount: usize);
+}
+
+/// A [`Source`] reading from a synchronous reader, so it never has to wait.
+pub struct Blocking<R>(pub R);
+
+impl<R: BufRead> Source for Blocking<R> {
+    fn poll_fill_buf(&mut self, _: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        Poll::Ready(self.0.fill_buf())
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.0.consume(amount);
+    }
+}
+
+/// A [`Source`] reading from an asynchronous reader.
+#[cfg(feature = "async")]
+pub struct Async<R>(pub R);
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncBufRead + Unpin> Source for Async<R> {
+    fn poll_fill_buf(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        std::pin::Pin::new(&mut self.0).poll_fill_buf(cx)
+    }
+
+    fn consume(&mut self, amount: usize) {
+        std::pin::Pin::new(&mut self.0).consume(amount);
+    }
+}
+
+/// Runs a future to completion that never has to wait, such as one reading
+/// from a [`Blocking`] source.
+pub fn complete<F: Future>(future: F) -> F::Output {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    match pin!(future).poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("The future is not supposed to wait"),
+    }
+}
+
+/// Splits an XML document that is read incrementally into individual elements.
+/// Only the element that is currently being read is kept in memory. Elements
+/// can be opened instead, so that their children are streamed individually as
+/// well.
+pub struct ElementStream<S> {
+    source: S,
+    buf: Vec<u8>,
+    depth: usize,
+    pending_close: bool,
+}
+
+impl<S: Source> ElementStream<S> {
+    pub const fn new(source: S) -> Self {
+        Self {
+            source,
+            buf: Vec::new(),
+            depth: 0,
+            pending_close: false,
+        }
+    }
+
+    /// Reads the next child of the element that got opened last. The `open`
+    /// callback decides based on the name of the child whether it gets opened
+    /// or read as a whole.
+    pub async fn next_child<E>(&mut self, open: impl FnOnce(&str) -> bool) -> Result<Chunk<'_>, E>
+    where
+        E: From<Error> + From<io::Error>,
+    {
+        self.buf.clear();
+
+        if self.pending_close {
+            self.pending_close = false;
+            return Ok(Chunk::Close);
+        }
+
+        let is_self_closing = loop {
+            self.skip_text::<E>().await?;
+            self.buf.clear();
+            self.buf.push(b'<');
+            self.read_tag::<E>().await?;
+            match self.buf[1] {
+                b'/' => {
+                    self.depth = self.depth.checked_sub(1).ok_or(Error::Xml)?;
+                    return Ok(Chunk::Close);
+                }
+                b'!' | b'?' if is_valid_markup(&self.buf) => continue,
+                b'!' | b'?' => return Err(Error::Xml.into()),
+                _ => break self.buf.ends_with(b"/>"),
+            }
+        };
+
+        let name_end = self.buf[1..]
+            .iter()
+            .position(|&b| b.is_ascii_whitespace() || b == b'/' || b == b'>')
+            .map_or(self.buf.len(), |i| i + 1);
+
+        if open(str::from_utf8(&self.buf[1..name_end]).map_err(|_| Error::Xml)?) {
+            if is_self_closing {
+                self.pending_close = true;
+            } else {
+                self.depth += 1;
+            }
+            return Ok(Chunk::Open(self.buf_as_str()?));
+        }
+
+        let mut depth = usize::from(!is_self_closing);
+        while depth > 0 {
+            self.read_text::<E>().await?;
+            let tag_start = self.buf.len();
+            self.buf.push(b'<');
+            self.read_tag::<E>().await?;
+            match self.buf[tag_start + 1] {
+                b'/' => depth -= 1,
+                b'!' | b'?' if is_valid_markup(&self.buf[tag_start..]) => {}
+                b'!' | b'?' => return Err(Error::Xml.into()),
+                _ if self.buf.ends_with(b"/>") => {}
+                _ => depth += 1,
+            }
+        }
+
+        Ok(Chunk::Element(self.buf_as_str()?))
+    }
+
+    /// Reads the next child of the element that got opened last as a whole.
+    /// Returns [`None`] once the end of the element has been reached.
+    pub async fn next_element<E>(&mut self) -> Result<Option<&str>, E>
+    where
+        E: From<Error> + From<io::Error>,
+    {
+        Ok(match self.next_child::<E>(|_| false).await? {
+            Chunk::Element(element) => Some(element),
+            Chunk::Open(_) | Chunk::Close => None,
+        })
+    }
+
+    fn buf_as_str(&self) -> Result<&str, Error> {
+        str::from_utf8(&self.buf).map_err(|_| Error::Xml)
+    }
+
+    /// Consumes the bytes up to and including the next occurrence of `byte`,
+    /// appending them to the buffer if they should be kept. Returns whether
+    /// the byte was found before reaching the end of the source.
+    async fn read_until(&mut self, byte: u8, keep: bool) -> io::Result<bool> {
+        poll_fn(|cx| loop {
+            let available = match self.source.poll_fill_buf(cx) {
+                Poll::Ready(Ok(available)) => available,
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            };
+            if available.is_empty() {
+                return Poll::Ready(Ok(false));
+            }
+            let (len, found) = match memchr::memchr(byte, available) {
+                Some(i) => (i + 1, true),
+                None => (available.len(), false),
+            };
+            if keep {
+                self.buf.extend_from_slice(&available[..len]);
+            }
+            self.source.consume(len);
+            if found {
+                return Poll::Ready(Ok(true));
+            }
+        })
+        .await
+    }
+
+    /// Skips over text until the next tag, which is consumed up to and
+    /// including its `<`.
+    async fn skip_text<E>(&mut self) -> Result<(), E>
+    where
+        E: From<Error> + From<io::Error>,
+    {
+        if !self.read_until(b'<', false).await? {
+            return Err(Error::UnexpectedEndOfFile.into());
+        }
+        Ok(())
+    }
+
+    /// Appends the text up to the next tag to the buffer and consumes the
+    /// `<` of that tag.
+    async fn read_text<E>(&mut self) -> Result<(), E>
+    where
+        E: From<Error> + From<io::Error>,
+    {
+        if !self.read_until(b'<', true).await? {
+            return Err(Error::UnexpectedEndOfFile.into());
+        }
+        self.buf.pop();
+        Ok(())
+    }
+
+    /// Appends the rest of the tag to the buffer. Just like the [`Reader`],
+    /// this ignores any `>` within the quoted attribute values of an element.
+    async fn read_tag<E>(&mut self) -> Result<(), E>
+    where
+        E: From<Error> + From<io::Error>,
+    {
+        let tag_start = self.buf.len() - 1;
+        loop {
+            if !self.read_until(b'>', true).await? {
+                return Err(Error::UnexpectedEndOfFile.into());
+            }
+            let tag = &self.buf[tag_start + 1..];
+            if matches!(tag.first(), Some(b'/' | b'!' | b'?')) || !has_open_quote(tag) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Checks whether a tag starting with `<!` or `<?` is a comment, CDATA, a
+/// DOCTYPE or a processing instruction, just like the [`Reader`] does.
+fn is_valid_markup(tag: &[u8]) -> bool {
+    (tag.starts_with(b"<!--") && tag.ends_with(b"-->") && tag.len() >= 7)
+        || (tag.starts_with(b"<![CDATA[") && tag.ends_with(b"]]>"))
+        || tag.starts_with(b"<!DOCTYPE")
+        || (tag.starts_with(b"<?") && tag.ends_with(b"?>") && tag.len() >= 4)
+}
+
+fn has_open_quote(tag: &[u8]) -> bool {
+    let mut quote = None;
+    for &b in tag {
+        match quote {
+            None if b == b'"' || b == b'\'' => quote = Some(b),
+            Some(q) if b == q => quote = None,
+            _ => {}
+        }
+    }
+    quote.is_some()
+}
+
+/// Parses an element that got read by an [`ElementStream`]. The callback
+/// receives a reader that is positioned right after the element's start tag.
+pub fn parse_element<F, T, E>(element: &str, f: F) -> Result<T, E>
+where
+    F: FnOnce(&mut Reader<'_>, TagName<'_>, Attributes<'_>) -> Result<T, E>,
+    E: From<Error>,
+{
+    let mut reader = Reader::new(element);
+    loop {
+        match reader.read_event().ok_or(Error::Xml)? {
+            Event::Start(start) => {
+                let (name, attributes) = start.name_and_attributes();
+                return f(&mut reader, name, attributes);
+            }
+            Event::Ended => return Err(Error::UnexpectedEndOfFile.into()),
+            _ => {}
+        }
+    }
+}
@@ -6,16 +6,54 @@ use super::Text;
 
 pub struct Writer<T> {
     sink: T,
+    indentation: Option<(char, usize)>,
+    depth: usize,
+    is_at_start: bool,
+    has_child_elements: bool,
 }
 
 impl<T: fmt::Write> Writer<T> {
     pub const fn new_skip_header(sink: T) -> Self {
-        Self { sink }
+        Self {
+            sink,
+            indentation: None,
+            depth: 0,
+            is_at_start: true,
+            has_child_elements: false,
+        }
     }
 
     pub fn new_with_default_header(mut sink: T) -> Result<Self, fmt::Error> {
         sink.write_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
-        Ok(Self { sink })
+        Ok(Self {
+            is_at_start: false,
+            ..Self::new_skip_header(sink)
+        })
+    }
+
+    /// Puts every element on its own line, indented by `width` times the
+    /// character provided for each element it is nested in. Elements that only
+    /// contain text stay on a single line.
+    pub const fn with_indentation(mut self, char: char, width: usize) -> Self {
+        self.indentation = Some((char, width));
+        self
+    }
+
+    pub const fn is_indented(&self) -> bool {
+        self.indentation.is_some()
+    }
+
+    fn new_line(&mut self) -> fmt::Result {
+        if let Some((char, width)) = self.indentation {
+            if !self.is_at_start {
+                self.sink.write_char('\n')?;
+            }
+            for _ in 0..self.depth * width {
+                self.sink.write_char(char)?;
+            }
+        }
+        self.is_at_start = false;
+        Ok(())
     }
 
     pub fn text(&mut self, text: impl Value) -> fmt::Result {
@@ -27,20 +65,29 @@ impl<T: fmt::Write> Writer<T> {
         tag: &str,
         f: F,
     ) -> Result<O, E> {
+        self.new_line()?;
         self.sink.write_str("<")?;
         self.sink.write_str(tag)?;
         let mut has_content = false;
+        self.has_child_elements = false;
+        self.depth += 1;
         let res = f(AttributeWriter {
             writer: self,
             has_content: &mut has_content,
-        })?;
+        });
+        self.depth -= 1;
+        let res = res?;
         if has_content {
+            if self.has_child_elements {
+                self.new_line()?;
+            }
             self.sink.write_str("</")?;
             self.sink.write_str(tag)?;
             self.sink.write_str(">")?;
         } else {
             self.sink.write_str("/>")?;
         }
+        self.has_child_elements = true;
         Ok(res)
     }
 
@@ -91,6 +138,14 @@ impl<T: fmt::Write> Writer<T> {
         })
     }
 
+    /// Writes an element that is already encoded as XML.
+    pub fn encoded_element(&mut self, element: Text<'_>) -> fmt::Result {
+        self.new_line()?;
+        element.write_escaped(&mut self.sink)?;
+        self.has_child_elements = true;
+        Ok(())
+    }
+
     pub fn comment(&mut self, text: impl Value) -> fmt::Result {
         self.sink.write_str("<!--")?;
         text.write_escaped(&mut self.sink)?;
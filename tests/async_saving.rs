@@ -0,0 +1,174 @@
+#![cfg(all(feature = "async", feature = "parser-livesplit-one"))]
+
+mod run_files;
+#[path = "../src/util/tests_helper.rs"]
+mod tests_helper;
+
+use livesplit_core::{
+    run::{
+        parser::{livesplit, livesplit_one},
+        saver,
+    },
+    Run, Segment, TimeSpan, Timer, TimingMethod,
+};
+use std::{
+    future::Future,
+    io,
+    pin::{pin, Pin},
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+/// Accepts short chunks of varying length, having to wait for each one of
+/// them.
+#[derive(Default)]
+struct Trickle {
+    data: Vec<u8>,
+    chunk_len: usize,
+    ready: bool,
+    flushed: bool,
+}
+
+impl futures_io::AsyncWrite for Trickle {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if !this.ready {
+            this.ready = true;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+        this.ready = false;
+        this.flushed = false;
+        this.chunk_len = this.chunk_len % 7 + 1;
+        let len = this.chunk_len.min(buf.len());
+        this.data.extend_from_slice(&buf[..len]);
+        Poll::Ready(Ok(len))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().flushed = true;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Drives the future to completion, returning its output and how often it had
+/// to wait.
+fn block_on<F: Future>(future: F) -> (F::Output, usize) {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    let mut pending = 0;
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return (output, pending),
+            Poll::Pending => pending += 1,
+        }
+    }
+}
+
+#[track_caller]
+fn assert_written(writer: Trickle, pending: usize, expected: &str) {
+    // The writer makes the saver wait before every chunk of at most 7 bytes.
+    assert!(pending >= expected.len() / 7);
+    assert!(writer.flushed);
+    assert_eq!(String::from_utf8(writer.data).unwrap(), expected);
+}
+
+fn runs() -> impl Iterator<Item = Run> {
+    [
+        run_files::CELESTE,
+        run_files::LIVESPLIT_1_6,
+        run_files::LIVESPLIT_1_6_GAMETIME,
+    ]
+    .into_iter()
+    .map(|source| livesplit::parse(source).unwrap())
+}
+
+#[test]
+fn livesplit_round_trip() {
+    for run in runs() {
+        let mut expected = String::new();
+        saver::livesplit::save_run(&run, &mut expected).unwrap();
+
+        let mut writer = Trickle::default();
+        let (result, pending) = block_on(saver::livesplit::save_run_async(&run, &mut writer));
+        result.unwrap();
+        assert_written(writer, pending, &expected);
+
+        assert_eq!(livesplit::parse(&expected).unwrap(), run);
+    }
+}
+
+#[test]
+fn livesplit_one_round_trip() {
+    for run in runs() {
+        let mut expected = String::new();
+        saver::livesplit_one::save_run(&run, &mut expected).unwrap();
+
+        let mut writer = Trickle::default();
+        let (result, pending) = block_on(saver::livesplit_one::save_run_async(&run, &mut writer));
+        result.unwrap();
+        assert_written(writer, pending, &expected);
+
+        let parsed = livesplit_one::parse(&expected).unwrap();
+        let mut saved_again = String::new();
+        saver::livesplit_one::save_run(&parsed, &mut saved_again).unwrap();
+        assert_eq!(saved_again, expected);
+    }
+}
+
+#[test]
+fn timer_round_trip() {
+    let mut timer = Timer::new(tests_helper::create_run(&["A", "B", "C"])).unwrap();
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None]);
+
+    // The attempt in progress is ended while saving, so the exact time the
+    // attempt ended at differs between the splits files.
+    let mut writer = Trickle::default();
+    let (result, pending) = block_on(saver::livesplit::save_timer_async(&timer, &mut writer));
+    result.unwrap();
+    assert!(pending > 0);
+    assert!(writer.flushed);
+    let run = livesplit::parse(std::str::from_utf8(&writer.data).unwrap()).unwrap();
+    assert_eq!(run.attempt_history().len(), 1);
+    assert_eq!(
+        run.segment(0).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(5.0)),
+    );
+
+    let mut writer = Trickle::default();
+    let (result, pending) = block_on(saver::livesplit_one::save_timer_async(&timer, &mut writer));
+    result.unwrap();
+    assert!(pending > 0);
+    assert!(writer.flushed);
+    let parsed = livesplit_one::parse(std::str::from_utf8(&writer.data).unwrap()).unwrap();
+    assert_eq!(parsed.attempt_history().len(), 1);
+    assert_eq!(
+        parsed.segment(0).best_segment_time(),
+        run.segment(0).best_segment_time(),
+    );
+
+    // Once the attempt is over, the splits files are identical.
+    timer.reset(true).unwrap();
+    let mut expected = String::new();
+    saver::livesplit::save_timer(&timer, &mut expected).unwrap();
+    let mut writer = Trickle::default();
+    let (result, pending) = block_on(saver::livesplit::save_timer_async(&timer, &mut writer));
+    result.unwrap();
+    assert_written(writer, pending, &expected);
+}
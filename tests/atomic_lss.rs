@@ -0,0 +1,52 @@
+use livesplit_core::{
+    run::{parser, saver},
+    Run, Segment,
+};
+use std::fs;
+
+#[test]
+fn save_run_to_path_replaces_splits_file() {
+    let dir = std::env::temp_dir().join(format!("livesplit-core-atomic-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("splits.lss");
+    fs::write(
+        &path,
+        "a previous splits file that is a lot longer than the new one".repeat(100),
+    )
+    .unwrap();
+
+    let mut run = Run::new();
+    run.set_game_name("Super Mario Odyssey");
+    run.push_segment(Segment::new("Cap Kingdom"));
+    saver::livesplit::save_run_to_path(&run, &path).unwrap();
+
+    let mut expected = String::new();
+    saver::livesplit::save_run(&run, &mut expected).unwrap();
+    let saved = fs::read_to_string(&path).unwrap();
+    assert_eq!(saved, expected);
+    assert_eq!(
+        parser::livesplit::parse(&saved).unwrap().game_name(),
+        "Super Mario Odyssey"
+    );
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn save_run_to_path_keeps_nothing_behind_on_failure() {
+    let dir = std::env::temp_dir().join(format!(
+        "livesplit-core-atomic-failure-{}",
+        std::process::id()
+    ));
+    fs::create_dir_all(dir.join("splits.lss")).unwrap();
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Cap Kingdom"));
+    saver::livesplit::save_run_to_path(&run, dir.join("splits.lss")).unwrap_err();
+
+    assert!(dir.join("splits.lss").is_dir());
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
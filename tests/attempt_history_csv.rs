@@ -0,0 +1,131 @@
+#![cfg(feature = "parser-attempt-history-csv")]
+
+mod run_files;
+
+use livesplit_core::{
+    run::{
+        parser::{attempt_history_csv, livesplit},
+        saver,
+    },
+    Run, TimeSpan,
+};
+
+fn attempts_csv(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::attempt_history_csv::save_attempts(run, &mut buf).unwrap();
+    buf
+}
+
+fn segment_history_csv(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::attempt_history_csv::save_segment_history(run, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn round_trips_histories() {
+    let run = livesplit::parse(run_files::CELESTE).unwrap();
+    let attempts = attempts_csv(&run);
+    let segment_history = segment_history_csv(&run);
+
+    assert_eq!(attempts.lines().count(), run.attempt_history().len() + 1);
+    assert_eq!(
+        segment_history.lines().count(),
+        run.segments()
+            .iter()
+            .map(|segment| segment.segment_history().iter().len())
+            .sum::<usize>()
+            + 1,
+    );
+
+    let mut imported = run.clone();
+    attempt_history_csv::import_attempts(&attempts, &mut imported).unwrap();
+    attempt_history_csv::import_segment_history(&segment_history, &mut imported).unwrap();
+    assert_eq!(imported, run);
+
+    let mut cleared = run.clone();
+    cleared.clear_history();
+    attempt_history_csv::import_attempts(&attempts, &mut cleared).unwrap();
+    attempt_history_csv::import_segment_history(&segment_history, &mut cleared).unwrap();
+    assert_eq!(attempts_csv(&cleared), attempts);
+    assert_eq!(segment_history_csv(&cleared), segment_history);
+    for (cleared, segment) in cleared.segments().iter().zip(run.segments()) {
+        assert_eq!(cleared.segment_history(), segment.segment_history());
+    }
+}
+
+#[test]
+fn imports_corrections() {
+    let mut run = livesplit::parse(run_files::LIVESPLIT_1_6).unwrap();
+    let first = run.attempt_history()[0].index();
+    let last = run.max_attempt_history_index().unwrap();
+
+    let attempts = format!(
+        "Game Time,Attempt,Real Time,Notes,Started,Ended,Pause Time,Pause Count,Longest Pause\n\
+         ,{first},1:02:03.5,\"Fixed, finally\",2024-01-02T03:04:05Z,,,2,0:10\n\
+         \n\
+         ,{},,,,,,,\n",
+        last + 1,
+    );
+    attempt_history_csv::import_attempts(&attempts, &mut run).unwrap();
+
+    let history = run.attempt_history();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].index(), first);
+    assert_eq!(
+        history[0].time().real_time,
+        Some(TimeSpan::from_seconds(3723.5))
+    );
+    assert_eq!(history[0].pause_count(), Some(2));
+    assert_eq!(
+        history[0].longest_pause(),
+        Some(TimeSpan::from_seconds(10.0))
+    );
+    assert!(history[0].ended().is_none());
+    assert_eq!(history[1].index(), last + 1);
+
+    let segment_history = "Attempt\tSegment\tReal Time\tGame Time\n\
+                           -3\t2\t12.25\t\n";
+    attempt_history_csv::import_segment_history(segment_history, &mut run).unwrap();
+    assert!(run.segment(0).segment_history().iter().next().is_none());
+    assert_eq!(
+        run.segment(1).segment_history().get(-3).unwrap().real_time,
+        Some(TimeSpan::from_seconds(12.25))
+    );
+}
+
+#[test]
+fn rejects_invalid_files() {
+    let original = livesplit::parse(run_files::LIVESPLIT_1_6).unwrap();
+    let mut run = original.clone();
+
+    let error =
+        attempt_history_csv::import_attempts("Attempt,Started\n1,\n", &mut run).unwrap_err();
+    assert!(matches!(
+        error.error,
+        attempt_history_csv::Error::MissingColumn { name: "Ended" }
+    ));
+
+    let error = attempt_history_csv::import_segment_history(
+        "Attempt,Segment,Real Time,Game Time\n1,1,1.0,\n1,99,1.0,\n",
+        &mut run,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error.error,
+        attempt_history_csv::Error::InvalidSegment
+    ));
+    assert_eq!(error.position.line, 3);
+
+    let error = attempt_history_csv::import_segment_history(
+        "Attempt,Segment,Real Time,Game Time\n1,1,1.0,\n1,1,2.0,\n",
+        &mut run,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        error.error,
+        attempt_history_csv::Error::DuplicateSegmentTime
+    ));
+
+    assert_eq!(run, original);
+}
@@ -0,0 +1,69 @@
+mod run_files;
+
+use livesplit_core::{
+    run::{parser::livesplit, saver::journal},
+    Run, Segment, Time, TimeSpan,
+};
+use std::fs;
+
+fn seconds(seconds: f64) -> Time {
+    Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)))
+}
+
+#[test]
+fn journals_split_times_of_reached_segments() {
+    let mut run = Run::new();
+    for name in ["A", "B", "C"] {
+        run.push_segment(Segment::new(name));
+    }
+    run.add_attempt(seconds(15.5), None, None, None);
+    let index = run.attempt_history()[0].index();
+    run.segment_mut(0)
+        .segment_history_mut()
+        .insert(index, seconds(10.0));
+    run.segment_mut(1)
+        .segment_history_mut()
+        .insert(index, Time::new());
+    run.segment_mut(2)
+        .segment_history_mut()
+        .insert(index, seconds(5.5));
+
+    let mut buf = String::new();
+    journal::save_attempt(&run, &run.attempt_history()[0], &mut buf).unwrap();
+
+    assert_eq!(
+        buf,
+        format!(
+            concat!(
+                r#"{{"index":{},"time":{{"real_time":"15.500000000","game_time":null}},"#,
+                r#""started":null,"ended":null,"pause_time":null,"splits":["#,
+                r#"{{"name":"A","real_time":"10.000000000","game_time":null}},"#,
+                r#"{{"name":"B","real_time":null,"game_time":null}},"#,
+                r#"{{"name":"C","real_time":"15.500000000","game_time":null}}]}}"#,
+                "\n",
+            ),
+            index,
+        ),
+    );
+}
+
+#[test]
+fn appends_attempts_to_journal() {
+    let run = livesplit::parse(run_files::CELESTE).unwrap();
+
+    let mut whole = String::new();
+    journal::save_run(&run, &mut whole).unwrap();
+    assert_eq!(whole.lines().count(), run.attempt_history().len());
+
+    let dir = std::env::temp_dir().join(format!("livesplit-core-journal-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = journal::journal_path(&dir.join("Celeste.lss"));
+    assert_eq!(path, dir.join("Celeste.journal"));
+
+    for attempt in run.attempt_history() {
+        journal::append_attempt(&run, attempt, &path).unwrap();
+    }
+    assert_eq!(fs::read_to_string(&path).unwrap(), whole);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
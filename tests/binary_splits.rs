@@ -0,0 +1,44 @@
+#![cfg(feature = "parser-binary")]
+
+mod run_files;
+
+use livesplit_core::run::{
+    parser::{binary, livesplit},
+    saver,
+};
+
+#[test]
+fn round_trips_livesplit_splits_files() {
+    for source in [
+        run_files::CELESTE,
+        run_files::LIVESPLIT_1_6,
+        run_files::LIVESPLIT_1_6_GAMETIME,
+    ] {
+        let run = livesplit::parse(source).unwrap();
+        let cached = saver::binary::save_run(&run);
+        assert!(cached.len() < source.len());
+        assert_eq!(binary::parse(&cached).unwrap(), run);
+    }
+}
+
+#[test]
+fn rejects_truncated_and_foreign_files() {
+    let run = livesplit::parse(run_files::LIVESPLIT_1_6).unwrap();
+    let cached = saver::binary::save_run(&run);
+
+    for len in 0..cached.len() {
+        binary::parse(&cached[..len]).unwrap_err();
+    }
+
+    assert!(matches!(
+        binary::parse(run_files::LIVESPLIT_1_6.as_bytes()),
+        Err(binary::Error::Magic),
+    ));
+
+    let mut newer = cached;
+    newer[4] += 1;
+    assert!(matches!(
+        binary::parse(&newer),
+        Err(binary::Error::UnsupportedVersion),
+    ));
+}
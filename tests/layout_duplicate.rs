@@ -0,0 +1,44 @@
+mod layout_files;
+
+use livesplit_core::{
+    component::separator,
+    layout::{parser, Layout},
+    settings::{Color, Font, Value},
+};
+
+fn to_json(layout: &Layout) -> String {
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn duplicate_shares_no_state_with_the_original() {
+    let original = parser::parse(layout_files::WITH_BACKGROUND_IMAGE).unwrap();
+    let before = to_json(&original);
+
+    let mut duplicate = original.duplicate();
+    assert_eq!(to_json(&duplicate), before);
+
+    let general = duplicate.general_settings_mut();
+    general.text_color = Color::rgba(0.1, 0.2, 0.3, 1.0);
+    general.text_font = Some(Font {
+        family: "Duplicated".into(),
+        ..Default::default()
+    });
+    let component = &mut duplicate.components[0];
+    let index = component
+        .settings_description()
+        .fields
+        .iter()
+        .position(|field| matches!(field.value, Value::Bool(_)))
+        .unwrap();
+    let Value::Bool(value) = component.settings_description().fields[index].value else {
+        unreachable!()
+    };
+    component.set_value(index, Value::Bool(!value));
+    duplicate.push(separator::Component::new());
+
+    assert_eq!(to_json(&original), before);
+    assert_ne!(to_json(&duplicate), before);
+}
@@ -0,0 +1,109 @@
+mod layout_files;
+
+use livesplit_core::{
+    layout::{parser, Layout, LayoutSettings, Theme},
+    settings::{Color, ImageCache},
+};
+
+fn to_json(layout: &Layout) -> String {
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+fn from_json(json: &str) -> Layout {
+    Layout::from_settings(LayoutSettings::from_json(json.as_bytes()).unwrap())
+}
+
+#[test]
+fn round_trips_layout_files() {
+    for file in [
+        layout_files::ALL,
+        layout_files::DARK,
+        layout_files::SUBSPLITS,
+        layout_files::WSPLIT,
+        layout_files::WITH_TIMER_DELTA_BACKGROUND,
+        layout_files::WITH_BACKGROUND_IMAGE,
+    ] {
+        let layout = parser::parse(file).unwrap();
+        let json = to_json(&layout);
+        assert!(json.starts_with(r#"{"version":1,"components":["#));
+        assert_eq!(to_json(&from_json(&json)), json);
+    }
+}
+
+#[test]
+fn parses_unversioned_layouts() {
+    let layout = Layout::default_layout();
+    let mut buf = Vec::new();
+    serde_json::to_writer(&mut buf, &layout.settings()).unwrap();
+    let json = String::from_utf8(buf).unwrap();
+    assert!(!json.contains("version"));
+
+    assert_eq!(to_json(&from_json(&json)), to_json(&layout));
+}
+
+#[test]
+fn skips_unknown_components_and_settings() {
+    let layout = from_json(
+        r#"{
+            "version": 1,
+            "components": [
+                { "Hologram": { "opacity": 0.5 } },
+                "Separator",
+                { "Timer": { "height": 90, "glow": true } }
+            ],
+            "general": { "direction": "Vertical", "blur": 5 }
+        }"#,
+    );
+
+    let settings = layout.settings();
+    assert_eq!(settings.components.len(), 2);
+    let json = to_json(&layout);
+    assert!(json.contains(r#""Separator""#));
+    assert!(json.contains(r#""height":90"#));
+    assert!(!json.contains("glow"));
+}
+
+#[test]
+fn rejects_newer_versions() {
+    let json = to_json(&Layout::default_layout()).replace(r#""version":1"#, r#""version":2"#);
+    assert!(LayoutSettings::from_json(json.as_bytes()).is_err());
+}
+
+#[test]
+fn round_trips_themes() {
+    let mut buf = Vec::new();
+    Theme::light().write_json(&mut buf).unwrap();
+    assert_eq!(Theme::from_json(&*buf).unwrap(), Theme::light());
+
+    let theme = Theme::from_json(&br#"{ "text_color": [1, 0, 0, 1] }"#[..]).unwrap();
+    assert_eq!(theme.text_color, Color::rgba(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(theme.background, Theme::dark().background);
+}
+
+#[test]
+fn describes_the_types_and_allowed_values_of_the_settings() {
+    let layout = parser::parse(layout_files::ALL).unwrap();
+    let fields = layout
+        .components
+        .iter()
+        .flat_map(|component| component.settings_description().fields)
+        .chain(
+            layout
+                .general_settings()
+                .settings_description(&mut ImageCache::new())
+                .fields,
+        );
+
+    for field in fields {
+        let json = serde_json::to_value(&field.value).unwrap();
+        let (type_name, value) = json.as_object().unwrap().iter().next().unwrap();
+        assert_eq!(field.value.type_name(), type_name);
+
+        let allowed_values = field.value.allowed_values();
+        if let Some(value) = value.as_str().filter(|_| !allowed_values.is_empty()) {
+            assert!(allowed_values.contains(&value), "{type_name}: {value}");
+        }
+    }
+}
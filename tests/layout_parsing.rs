@@ -34,6 +34,150 @@ mod parse {
         livesplit(layout_files::WITH_TIMER_DELTA_BACKGROUND);
     }
 
+    #[test]
+    fn with_background_image() {
+        use livesplit_core::{
+            layout::LayoutSettings,
+            settings::{BackgroundImageFit, LayoutBackground},
+        };
+
+        let layout = livesplit(layout_files::WITH_BACKGROUND_IMAGE);
+        let LayoutBackground::Image(background) = &layout.general_settings().background else {
+            panic!("Expected a background image");
+        };
+        assert!(!background.image.is_empty());
+        assert_eq!(background.fit, BackgroundImageFit::Cover);
+
+        let mut settings = layout.settings();
+        if let LayoutBackground::Image(background) = &mut settings.general.background {
+            background.fit = BackgroundImageFit::Contain;
+        }
+        let mut json = Vec::new();
+        settings.write_json(&mut json).unwrap();
+        let settings = LayoutSettings::from_json(&*json).unwrap();
+        let LayoutBackground::Image(background) = &settings.general.background else {
+            panic!("Expected a background image");
+        };
+        assert_eq!(background.fit, BackgroundImageFit::Contain);
+    }
+
+    #[test]
+    fn detailed_timer_fonts() {
+        use livesplit_core::layout::Component;
+
+        fn font_data<'a>(layout: &'a str, tag: &str) -> &'a str {
+            let start = layout.find(&format!("<{tag}><![CDATA[")).unwrap() + tag.len() + 11;
+            let len = layout[start..].find("]]>").unwrap();
+            &layout[start..start + len]
+        }
+
+        // The fonts of the Detailed Timer all use the default font, so we swap
+        // in the timer font, which uses a different one.
+        let data = layout_files::DARK.replace(
+            font_data(layout_files::DARK, "SegmentTimesFont"),
+            font_data(layout_files::DARK, "TimerFont"),
+        );
+
+        let default_layout = livesplit(layout_files::DARK);
+        let layout = livesplit(&data);
+        for (layout, family) in [(&default_layout, None), (&layout, Some("Calibri"))] {
+            let Some(Component::DetailedTimer(component)) = layout
+                .components
+                .iter()
+                .find(|c| matches!(c, Component::DetailedTimer(_)))
+            else {
+                panic!("Expected a Detailed Timer");
+            };
+            let settings = component.settings();
+            assert_eq!(settings.times_font.as_ref().map(|f| &*f.family), family);
+            assert!(settings.text_font.is_none());
+        }
+    }
+
+    #[test]
+    fn title_font() {
+        use livesplit_core::layout::Component;
+
+        fn title_font_family(layout: &Layout) -> Option<&str> {
+            layout.components.iter().find_map(|c| match c {
+                Component::Title(c) => Some(c.settings().text_font.as_ref()?.family.as_str()),
+                _ => None,
+            })
+        }
+
+        let layout = livesplit(layout_files::DARK);
+        assert_eq!(title_font_family(&layout), None);
+
+        let layout = livesplit(&layout_files::DARK.replace(
+            "<OverrideTitleFont>False</OverrideTitleFont>",
+            "<OverrideTitleFont>True</OverrideTitleFont>",
+        ));
+        assert_eq!(title_font_family(&layout), Some("Segoe UI"));
+    }
+
+    #[test]
+    fn blank_space_size_depends_on_direction() {
+        use livesplit_core::layout::Component;
+
+        fn blank_space_size(layout: &Layout) -> u32 {
+            layout
+                .components
+                .iter()
+                .find_map(|c| match c {
+                    Component::BlankSpace(c) => Some(c.settings().size),
+                    _ => None,
+                })
+                .unwrap()
+        }
+
+        // The sizes get translated from LiveSplit's pixels: 30.5 pixels are 24
+        // units.
+        let data = layout_files::ALL
+            .replace(
+                "<SpaceHeight>100</SpaceHeight>",
+                "<SpaceHeight>122</SpaceHeight>",
+            )
+            .replace(
+                "<SpaceWidth>100</SpaceWidth>",
+                "<SpaceWidth>61</SpaceWidth>",
+            );
+
+        let layout = livesplit(&data);
+        assert_eq!(blank_space_size(&layout), 96);
+
+        let layout = livesplit(&data.replace("<Mode>Vertical</Mode>", "<Mode>Horizontal</Mode>"));
+        assert_eq!(blank_space_size(&layout), 48);
+    }
+
+    #[test]
+    fn icon_sizes() {
+        use livesplit_core::layout::Component;
+
+        fn icon_sizes(layout: &Layout) -> Vec<u32> {
+            layout
+                .components
+                .iter()
+                .filter_map(|c| match c {
+                    Component::Splits(c) => Some(c.settings().icon_size),
+                    Component::DetailedTimer(c) => Some(c.settings().icon_size),
+                    _ => None,
+                })
+                .collect()
+        }
+
+        // The sizes get translated from LiveSplit's pixels, just like the
+        // heights of the components.
+        let layout = livesplit(layout_files::SUBSPLITS);
+        assert_eq!(icon_sizes(&layout), [17, 31]);
+
+        // The original LiveSplit stores the sizes as floating point numbers.
+        let layout = livesplit(
+            &layout_files::SUBSPLITS
+                .replace("<IconSize>22</IconSize>", "<IconSize>22.5</IconSize>"),
+        );
+        assert_eq!(icon_sizes(&layout), [18, 31]);
+    }
+
     #[test]
     fn assert_order_of_default_columns() {
         use livesplit_core::component::splits;
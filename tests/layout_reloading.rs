@@ -0,0 +1,87 @@
+mod layout_files;
+mod run_files;
+
+use livesplit_core::{
+    component::{splits, timer},
+    layout::{Component, ComponentState, Layout, LayoutDirection, Reloader},
+    run::parser::livesplit,
+    Timer,
+};
+use std::{fs, path::PathBuf};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("livesplit-core-{}-{name}", std::process::id()))
+}
+
+fn write_json(path: &PathBuf, layout: &Layout) {
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    fs::write(path, buf).unwrap();
+}
+
+#[test]
+fn only_reloads_after_modifications() {
+    let path = temp_path("only-reloads-after-modifications.ls1l");
+    write_json(&path, &Layout::default_layout());
+
+    let layout = Layout::default_layout().into_shared();
+    let mut reloader = Reloader::new(&path);
+    assert!(!reloader.reload_if_changed(&layout).unwrap());
+
+    let mut modified = Layout::new();
+    modified.push(timer::Component::new());
+    write_json(&path, &modified);
+
+    assert!(reloader.reload_if_changed(&layout).unwrap());
+    let components = &layout.read().unwrap().components;
+    assert_eq!(components.len(), 1);
+    assert!(matches!(components[0], Component::Timer(_)));
+    assert!(!reloader.reload_if_changed(&layout).unwrap());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn reloads_original_livesplit_layouts() {
+    let path = temp_path("reloads-original-livesplit-layouts.lsl");
+    let mut reloader = Reloader::new(&path);
+    assert!(reloader.check_for_changes().is_err());
+
+    fs::write(&path, layout_files::DARK).unwrap();
+    let settings = reloader.check_for_changes().unwrap().unwrap();
+    assert!(!settings.components.is_empty());
+    assert!(reloader.check_for_changes().unwrap().is_none());
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn keeps_the_scroll_position_of_the_splits() {
+    let run = livesplit::parse(run_files::LIVESPLIT_1_6).unwrap();
+    let timer = Timer::new(run).unwrap();
+
+    let mut layout = Layout::new();
+    let mut splits = splits::Component::new();
+    splits.settings_mut().visual_split_count = 2;
+    layout.push(splits);
+    let first_split = |layout: &mut Layout| {
+        let mut image_cache = Default::default();
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+        let ComponentState::Splits(splits) = &state.components[0] else {
+            unreachable!()
+        };
+        splits.splits[0].name.clone()
+    };
+    let initial = first_split(&mut layout);
+    layout.scroll_down();
+    let after_scrolling = first_split(&mut layout);
+    assert_ne!(initial, after_scrolling);
+
+    let mut settings = layout.settings();
+    settings.general.direction = LayoutDirection::Horizontal;
+    layout.apply_settings(settings);
+    assert_eq!(first_split(&mut layout), after_scrolling);
+
+    layout.apply_settings(Layout::new().settings());
+    assert!(layout.components.is_empty());
+}
@@ -0,0 +1,222 @@
+mod layout_files;
+mod run_files;
+
+use livesplit_core::{
+    component::{
+        segment_time,
+        splits::{self, ColumnKind, ColumnSettings, ColumnStartWith, ColumnUpdateWith, TimeColumn},
+        text,
+    },
+    layout::{parser, saver, Layout, LayoutDirection},
+    run::{
+        parser::livesplit as lss_parser,
+        saver::livesplit::{self as lss_saver, Indentation, Options},
+        LinkedLayout,
+    },
+    settings::{Color, Font, FontStretch, FontStyle, FontWeight, Gradient},
+};
+
+fn save(layout: &Layout) -> String {
+    let mut buf = String::new();
+    saver::save(layout, &mut buf).unwrap();
+    buf
+}
+
+fn settings_json(layout: &Layout) -> String {
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[track_caller]
+fn assert_round_trip(layout: &Layout) {
+    let saved = save(layout);
+    let parsed = parser::parse(&saved).unwrap();
+    assert_eq!(settings_json(&parsed), settings_json(layout));
+    assert_eq!(save(&parsed), saved);
+}
+
+#[test]
+fn round_trip_of_layout_files() {
+    for file in [
+        layout_files::ALL,
+        layout_files::DARK,
+        layout_files::SUBSPLITS,
+        layout_files::WSPLIT,
+        layout_files::WITH_TIMER_DELTA_BACKGROUND,
+        layout_files::WITH_BACKGROUND_IMAGE,
+    ] {
+        assert_round_trip(&parser::parse(file).unwrap());
+    }
+}
+
+#[test]
+fn default_layout() {
+    let layout = Layout::default_layout();
+    let saved = save(&layout);
+    assert!(saved.starts_with(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Layout version=\"1.6.1\">\n  <Mode>Vertical</Mode>\n"
+    ));
+
+    // The colors get rounded to 8 bits, so only the saved layout round trips
+    // exactly.
+    let parsed = parser::parse(&saved).unwrap();
+    assert_eq!(parsed.components.len(), layout.components.len());
+    assert_round_trip(&parsed);
+}
+
+#[test]
+fn fonts_colors_and_direction() {
+    let timer_font = Some(Font {
+        family: String::from("Bahnschrift"),
+        style: FontStyle::Italic,
+        weight: FontWeight::SemiLight,
+        stretch: FontStretch::Condensed,
+    });
+    let text_font = Some(Font {
+        family: String::from("Fira Sans"),
+        weight: FontWeight::Bold,
+        ..Font::default()
+    });
+    let text_color = Color::rgba(1.0, 0.5, 0.0, 0.5);
+
+    let mut layout = Layout::default_layout();
+    let settings = layout.general_settings_mut();
+    settings.direction = LayoutDirection::Horizontal;
+    settings.timer_font.clone_from(&timer_font);
+    settings.text_font.clone_from(&text_font);
+    settings.text_color = text_color;
+
+    let mut text = text::Component::new();
+    let text_settings = text.settings_mut();
+    text_settings.text = text::Text::Split(String::from("A & B"), String::from("<C>"));
+    text_settings.background = Gradient::Horizontal(Color::black(), Color::white());
+    layout.push(text);
+
+    let saved = save(&layout);
+    assert!(saved.contains("<Mode>Horizontal</Mode>"));
+    assert!(saved.contains("<Text1>A &amp; B</Text1>"));
+
+    let parsed = parser::parse(&saved).unwrap();
+    let parsed_settings = parsed.general_settings();
+    assert_eq!(parsed_settings.timer_font, timer_font);
+    assert_eq!(parsed_settings.text_font, text_font);
+    assert_eq!(parsed_settings.times_font, None);
+    assert_eq!(parsed_settings.text_color.to_rgba8()[..3], [255, 128, 0]);
+    assert!((parsed_settings.text_color.alpha - text_color.alpha).abs() < 0.01);
+    assert_round_trip(&parsed);
+}
+
+#[test]
+fn components_without_equivalent_are_skipped() {
+    let mut layout = Layout::default_layout();
+    let len = layout.components.len();
+    layout.push(segment_time::Component::new());
+
+    let parsed = parser::parse(&save(&layout)).unwrap();
+    assert_eq!(parsed.components.len(), len);
+}
+
+#[test]
+fn split_columns_keep_their_closest_type() {
+    let cases = [
+        (ColumnStartWith::Empty, ColumnUpdateWith::Delta, "Delta"),
+        (
+            ColumnStartWith::Empty,
+            ColumnUpdateWith::SegmentDelta,
+            "SegmentDelta",
+        ),
+        (
+            ColumnStartWith::Empty,
+            ColumnUpdateWith::SplitTime,
+            "SplitTime",
+        ),
+        (
+            ColumnStartWith::Empty,
+            ColumnUpdateWith::SegmentTime,
+            "SegmentTime",
+        ),
+        (
+            ColumnStartWith::Empty,
+            ColumnUpdateWith::DontUpdate,
+            "Delta",
+        ),
+        (
+            ColumnStartWith::ComparisonTime,
+            ColumnUpdateWith::Delta,
+            "DeltaorSplitTime",
+        ),
+        (
+            ColumnStartWith::ComparisonSegmentTime,
+            ColumnUpdateWith::SegmentDelta,
+            "SegmentDeltaorSegmentTime",
+        ),
+        (
+            ColumnStartWith::ComparisonSegmentTime,
+            ColumnUpdateWith::SplitTime,
+            "SplitTime",
+        ),
+        (
+            ColumnStartWith::PossibleTimeSave,
+            ColumnUpdateWith::DontUpdate,
+            "SegmentTime",
+        ),
+    ];
+
+    for (start_with, update_with, kind) in cases {
+        let mut component = splits::Component::new();
+        component.settings_mut().columns = vec![ColumnSettings {
+            name: String::from("Column"),
+            kind: ColumnKind::Time(TimeColumn {
+                start_with,
+                update_with,
+                ..TimeColumn::default()
+            }),
+        }];
+        let mut layout = Layout::new();
+        layout.push(component);
+
+        let saved = save(&layout);
+        assert!(
+            saved.contains(&format!("<Type>{kind}</Type>")),
+            "expected the column to be saved as {kind}",
+        );
+        assert_round_trip(&parser::parse(&saved).unwrap());
+    }
+}
+
+#[test]
+fn embedded_layout() {
+    let mut run = lss_parser::parse(run_files::LIVESPLIT_1_6).unwrap();
+    assert_eq!(parser::parse_embedded(&run).unwrap().map(|_| ()), None);
+    run.set_linked_layout(Some(LinkedLayout::Default));
+
+    let layout = parser::parse(layout_files::ALL).unwrap();
+    saver::embed(&layout, &mut run);
+
+    for indentation in [Indentation::None, Indentation::Spaces(2)] {
+        let mut saved = String::new();
+        lss_saver::save_run_with_options(
+            &run,
+            &mut saved,
+            &Options {
+                indentation,
+                ..Options::default()
+            },
+        )
+        .unwrap();
+
+        let parsed = lss_parser::parse(&saved).unwrap();
+        assert!(parsed
+            .embedded_layout()
+            .starts_with("<Layout version=\"1.6.1\">"));
+        assert_eq!(parsed.linked_layout(), Some(&LinkedLayout::Default));
+        let embedded = parser::parse_embedded(&parsed).unwrap().unwrap();
+        assert_eq!(settings_json(&embedded), settings_json(&layout));
+    }
+
+    run.embedded_layout_mut().clear();
+    let mut saved = String::new();
+    lss_saver::save_run(&run, &mut saved).unwrap();
+    assert!(!saved.contains("<Layout "));
+}
@@ -0,0 +1,90 @@
+use livesplit_core::{
+    component::{separator, text, timer},
+    layout::{Component, Layout, LayoutState, LayoutStateChanges},
+    settings::{Color, ImageCache},
+    Run, Segment, Timer,
+};
+use serde_json::Value;
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Cap Kingdom"));
+    Timer::new(run).unwrap()
+}
+
+fn changes_json(changes: &LayoutStateChanges) -> Value {
+    let mut buf = Vec::new();
+    changes.write_json(&mut buf).unwrap();
+    serde_json::from_slice(&buf).unwrap()
+}
+
+#[test]
+fn only_reports_what_changed() {
+    let timer = timer();
+    let mut layout = Layout::new();
+    layout.push(text::Component::new());
+    layout.push(separator::Component::new());
+    let mut state = LayoutState::default();
+    let mut image_cache = ImageCache::new();
+    let mut changes = LayoutStateChanges::new();
+
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    changes.update(&state);
+    assert!(changes.component_changed(0) && changes.component_changed(1));
+    assert!(changes.general_changed());
+    let json = changes_json(&changes);
+    assert!(json["components"][0]["Text"]["background"].is_object());
+    assert!(json["direction"].is_string());
+
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    changes.update(&state);
+    assert!(!changes.has_changed());
+    assert_eq!(
+        changes_json(&changes),
+        serde_json::json!({ "components": [null, null] })
+    );
+
+    let Component::Text(component) = &mut layout.components[0] else {
+        unreachable!()
+    };
+    component.settings_mut().text = text::Text::Center(String::from("Hello"));
+    layout.general_settings_mut().text_color = Color::black();
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    changes.update(&state);
+    assert!(changes.component_changed(0) && !changes.component_changed(1));
+    let json = changes_json(&changes);
+    assert_eq!(
+        json["components"][0],
+        serde_json::json!({ "Text": { "text": { "Center": "Hello" } } })
+    );
+    assert!(json["components"][1].is_null());
+    assert!(json["text_color"].is_array());
+    assert!(json.get("direction").is_none());
+}
+
+#[test]
+fn new_components_are_reported_completely() {
+    let timer = timer();
+    let mut layout = Layout::new();
+    layout.push(separator::Component::new());
+    let mut state = LayoutState::default();
+    let mut image_cache = ImageCache::new();
+    let mut changes = LayoutStateChanges::new();
+
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    changes.update(&state);
+
+    layout.components[0] = timer::Component::new().into();
+    layout.push(separator::Component::new());
+    layout.update_state(&mut state, &mut image_cache, &timer.snapshot());
+    changes.update(&state);
+    assert!(changes.component_changed(0) && changes.component_changed(1));
+    assert!(!changes.general_changed());
+    let json = changes_json(&changes);
+    assert!(json["components"][0]["Timer"]["height"].is_number());
+    assert!(json["components"][1].get("Separator").is_some());
+
+    changes.clear();
+    changes.update(&state);
+    assert!(changes.general_changed());
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "parser-livesplit-one")]
+
+mod run_files;
+
+use livesplit_core::{
+    run::{
+        parser::{composite, livesplit, livesplit_one, TimerKind},
+        saver, Action, ActionKind,
+    },
+    AtomicDateTime, DateTime, Run, Segment, Time, TimeSpan,
+};
+
+fn to_json(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::livesplit_one::save_run(run, &mut buf).unwrap();
+    buf
+}
+
+fn to_lss(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn round_trips_livesplit_splits_files() {
+    for source in [
+        run_files::CELESTE,
+        run_files::LIVESPLIT_1_6,
+        run_files::LIVESPLIT_1_6_GAMETIME,
+    ] {
+        let run = livesplit::parse(source).unwrap();
+        let parsed = livesplit_one::parse(&to_json(&run)).unwrap();
+        assert_eq!(to_lss(&parsed), to_lss(&run));
+        assert_eq!(parsed.game_icon(), run.game_icon());
+        assert_eq!(
+            parsed.auto_splitter_settings(),
+            run.auto_splitter_settings()
+        );
+    }
+}
+
+#[test]
+fn is_available_as_json_saver() {
+    let run = livesplit::parse(run_files::LIVESPLIT_1_6).unwrap();
+    let mut json = String::new();
+    saver::json::save_run(&run, &mut json).unwrap();
+    assert_eq!(json, to_json(&run));
+    assert!(json.contains(r#""version":1"#));
+}
+
+#[test]
+fn preserves_full_precision() {
+    let time = TimeSpan::from_seconds(-1.123456789);
+    let date = DateTime::from_unix_timestamp_nanos(1_700_000_000_123_456_789).unwrap();
+
+    let mut run = Run::new();
+    run.set_offset(time);
+    let mut segment = Segment::new("A");
+    segment.set_best_segment_time(Time::new().with_game_time(Some(time)));
+    segment
+        .segment_history_mut()
+        .insert(1, Time::new().with_real_time(Some(time)));
+    segment.variables_mut().insert("Key".into(), "Value".into());
+    run.push_segment(segment);
+    run.add_attempt_with_index(
+        Time::new(),
+        1,
+        Some(AtomicDateTime::new(date, true)),
+        Some(AtomicDateTime::new(date, false)),
+        Some(time),
+    );
+    let attempt = &mut run.attempt_history_mut()[0];
+    attempt.set_pause_count(Some(2));
+    attempt.set_longest_pause(Some(time));
+    attempt.set_actions(vec![Action {
+        kind: ActionKind::SetGameTime(time),
+        time,
+    }]);
+
+    let parsed = livesplit_one::parse(&to_json(&run)).unwrap();
+
+    assert_eq!(parsed.offset(), time);
+    assert_eq!(parsed.attempt_history(), run.attempt_history());
+    let segment = parsed.segment(0);
+    assert_eq!(segment.best_segment_time().game_time, Some(time));
+    assert_eq!(
+        segment.segment_history().get(1).unwrap().real_time,
+        Some(time)
+    );
+    assert_eq!(
+        segment.variables().get("Key").map(String::as_str),
+        Some("Value")
+    );
+}
+
+#[test]
+fn rejects_newer_versions() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let json = to_json(&run).replace("\"version\":1", "\"version\":2");
+
+    assert!(matches!(
+        livesplit_one::parse(&json),
+        Err(livesplit_one::Error::UnsupportedVersion)
+    ));
+}
+
+#[test]
+fn prefers_parsing_as_itself() {
+    let run = livesplit::parse(run_files::CELESTE).unwrap();
+    let json = to_json(&run);
+
+    let parsed = composite::parse(json.as_bytes(), None).unwrap();
+    assert_eq!(parsed.kind, TimerKind::LiveSplitOne);
+}
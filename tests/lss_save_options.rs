@@ -0,0 +1,188 @@
+mod run_files;
+
+use livesplit_core::{
+    run::{
+        parser,
+        saver::livesplit::{self, Indentation, Options},
+    },
+    Run, Segment, Time, TimeSpan,
+};
+use std::fs;
+
+fn save(run: &Run, options: &Options) -> String {
+    let mut buf = String::new();
+    livesplit::save_run_with_options(run, &mut buf, options).unwrap();
+    buf
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.set_game_name("Super Mario Odyssey");
+    run.set_category_name("Any%");
+    let mut segment = Segment::new("Cap Kingdom");
+    segment.set_personal_best_split_time(
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(123.456_789_123))),
+    );
+    run.push_segment(segment);
+    run
+}
+
+#[test]
+fn default_options_match_save_run() {
+    let run = parser::livesplit::parse(run_files::LIVESPLIT_1_6).unwrap();
+    let mut expected = String::new();
+    livesplit::save_run(&run, &mut expected).unwrap();
+    assert_eq!(save(&run, &Options::default()), expected);
+}
+
+#[test]
+fn indentation() {
+    let run = run();
+    let saved = save(
+        &run,
+        &Options {
+            indentation: Indentation::Spaces(2),
+            ..Options::default()
+        },
+    );
+
+    assert!(saved.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Run version=\"1.8.0\">\n  <GameIcon/>\n  <GameName>Super Mario Odyssey</GameName>\n"));
+    assert!(saved.contains("\n    <Segment>\n      <Name>Cap Kingdom</Name>\n"));
+    assert!(saved.ends_with("\n  <AutoSplitterSettings/>\n</Run>\n"));
+
+    let tabs = save(
+        &run,
+        &Options {
+            indentation: Indentation::Tabs,
+            ..Options::default()
+        },
+    );
+    assert!(tabs.contains("\n\t\t<Segment>\n\t\t\t<Name>Cap Kingdom</Name>\n"));
+
+    let mut expected = String::new();
+    livesplit::save_run(&run, &mut expected).unwrap();
+    let mut reparsed = String::new();
+    livesplit::save_run(&parser::livesplit::parse(&saved).unwrap(), &mut reparsed).unwrap();
+    assert_eq!(reparsed, expected);
+}
+
+#[test]
+fn time_digits() {
+    let run = run();
+
+    let saved = save(
+        &run,
+        &Options {
+            time_digits: 7,
+            ..Options::default()
+        },
+    );
+    assert!(saved.contains("<RealTime>00:02:03.4567891</RealTime>"));
+    assert!(saved.contains("<Offset>00:00:00.0000000</Offset>"));
+
+    let saved = save(
+        &run,
+        &Options {
+            time_digits: 0,
+            ..Options::default()
+        },
+    );
+    assert!(saved.contains("<RealTime>00:02:03</RealTime>"));
+    let parsed = parser::livesplit::parse(&saved).unwrap();
+    assert_eq!(
+        parsed.segment(0).personal_best_split_time().real_time,
+        Some(TimeSpan::from_seconds(123.0)),
+    );
+}
+
+#[test]
+fn skip_default_elements() {
+    let run = run();
+    let saved = save(
+        &run,
+        &Options {
+            write_default_elements: false,
+            ..Options::default()
+        },
+    );
+
+    for element in [
+        "<Metadata",
+        "<LayoutPath",
+        "<AttemptHistory",
+        "<Icon",
+        "<BestSegmentTime",
+        "<SegmentHistory",
+        "<AutoSplitterSettings",
+    ] {
+        assert!(!saved.contains(element), "{element} was written");
+    }
+    assert!(saved.contains("<GameIcon/>"));
+    assert!(saved.contains("<Offset>"));
+
+    let mut expected = String::new();
+    livesplit::save_run(&run, &mut expected).unwrap();
+    let mut reparsed = String::new();
+    livesplit::save_run(&parser::livesplit::parse(&saved).unwrap(), &mut reparsed).unwrap();
+    assert_eq!(reparsed, expected);
+}
+
+#[test]
+fn omit_icons() {
+    let run = parser::livesplit::parse(run_files::CELESTE).unwrap();
+    assert!(run.segments().iter().any(|s| !s.icon().is_empty()));
+
+    let saved = save(
+        &run,
+        &Options {
+            write_icons: false,
+            ..Options::default()
+        },
+    );
+    assert!(!saved.contains("CDATA"));
+    assert!(saved.len() < run_files::CELESTE.len() / 2);
+
+    let parsed = parser::livesplit::parse(&saved).unwrap();
+    assert!(parsed.game_icon().is_empty());
+    assert!(parsed.segments().iter().all(|s| s.icon().is_empty()));
+    assert_eq!(parsed.len(), run.len());
+}
+
+#[test]
+fn icon_paths() {
+    let dir = std::env::temp_dir().join(format!("livesplit-core-icons-{}", std::process::id()));
+    fs::create_dir_all(dir.join("icons")).unwrap();
+
+    let run = parser::livesplit::parse(run_files::CELESTE).unwrap();
+    let mut saved = String::new();
+    livesplit::save_run_with_icon_paths(
+        &run,
+        |index, icon| {
+            let index = index?;
+            let path = format!("icons/{index}.png");
+            fs::write(dir.join(&path), icon.data()).unwrap();
+            Some(path)
+        },
+        &mut saved,
+        &Options::default(),
+    )
+    .unwrap();
+    assert!(!saved.contains("CDATA"));
+    assert!(saved.contains(r#"<Icon path="icons/0.png"/>"#));
+
+    let splits_path = dir.join("splits.lss");
+    let parsed = parser::livesplit::parse_loading_icons(&saved, &splits_path).unwrap();
+    assert!(parsed.game_icon().is_empty());
+    for (parsed, segment) in parsed.segments().iter().zip(run.segments()) {
+        assert_eq!(parsed.icon().data(), segment.icon().data());
+    }
+
+    // Without the path of the splits file, the icons can't be loaded.
+    let parsed = parser::livesplit::parse(&saved).unwrap();
+    assert!(parsed.segments().iter().all(|s| s.icon().is_empty()));
+
+    let parsed = parser::composite::parse(saved.as_bytes(), Some(&splits_path)).unwrap();
+    assert!(!parsed.run.segment(0).icon().is_empty());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
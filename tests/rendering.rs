@@ -44,8 +44,8 @@ fn default() {
     check(
         &state,
         &image_cache,
-        "e32259a84233e364",
-        "0520a7a32958470a",
+        "ab6fcc833bccb24b",
+        "508b941b6528b512",
         "default",
     );
 }
@@ -173,6 +173,60 @@ fn font_fallback() {
     );
 }
 
+// The bundled fonts don't support Hebrew and Arabic, so every character of
+// these scripts renders as the same placeholder glyph. The words differ in
+// length though, so their order, the position of the left-to-right parts and
+// the position of the ellipsis are still visible.
+#[track_caller]
+fn check_segment_name(segment_name: &str, png_hash: &str, svg_hash: &str, name: &str) {
+    let timer = Timer::new(tests_helper::create_run(&[segment_name])).unwrap();
+    let mut layout = Layout::new();
+    let mut splits = component::splits::Component::new();
+    splits.settings_mut().visual_split_count = 0;
+    layout.push(splits);
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    check_with_bundled_fonts(&state, &image_cache, [400, 40], png_hash, svg_hash, name);
+}
+
+#[test]
+fn right_to_left_words() {
+    check_segment_name(
+        "שלב ראשון ביער",
+        "ede345e01bb557fe",
+        "30d00f60170e79f2",
+        "right_to_left_words_hebrew",
+    );
+    check_segment_name(
+        "المرحلة الأولى",
+        "c611f36e007ba916",
+        "4ead8c2745ff74c1",
+        "right_to_left_words_arabic",
+    );
+}
+
+#[test]
+fn mixed_left_to_right_and_right_to_left() {
+    check_segment_name(
+        "World – עולם ראשון 100%",
+        "62029572b673ebaf",
+        "29f9ef5954c602fa",
+        "mixed_left_to_right_and_right_to_left",
+    );
+}
+
+#[test]
+fn truncated_right_to_left() {
+    check_segment_name(
+        "שלב ראשון בעולם הגדול והרחב של המשחק הזה, לפני הבוס הראשון",
+        "f5667bdbd0133e7d",
+        "336d8d66730c529b",
+        "truncated_right_to_left",
+    );
+}
+
 #[test]
 fn actual_split_file() {
     let run = lss(run_files::LIVESPLIT_1_0);
@@ -183,8 +237,8 @@ fn actual_split_file() {
     check(
         &layout.state(&mut image_cache, &timer.snapshot()),
         &image_cache,
-        "c1e9e757c15a35ae",
-        "4fe65a630b531c54",
+        "ec0705a5e76c73f7",
+        "d140943538a0b555",
         "actual_split_file",
     );
 }
@@ -220,8 +274,8 @@ fn timer_delta_background() {
         &layout.state(&mut image_cache, &timer.snapshot()),
         &image_cache,
         [250, 300],
-        "4d9c5c580b0c435f",
-        "bf8e5c596684688d",
+        "4bc77591882b1f4a",
+        "f472967461cade37",
         "timer_delta_background_ahead",
     );
 
@@ -231,8 +285,8 @@ fn timer_delta_background() {
         &layout.state(&mut image_cache, &timer.snapshot()),
         &image_cache,
         [250, 300],
-        "748fa26a41a8d5a3",
-        "8bae1351d0dd52d7",
+        "5f3dc8c9825a8db8",
+        "d3c20370f5a43ef8",
         "timer_delta_background_stopped",
     );
 }
@@ -256,8 +310,8 @@ fn all_components() {
         &state,
         &image_cache,
         [300, 800],
-        "7e7aa83a3b80e1da",
-        "fa3c0357219389d8",
+        "713949f4e7d02025",
+        "99f2a03affb6cedf",
         "all_components",
     );
 
@@ -265,8 +319,8 @@ fn all_components() {
         &state,
         &image_cache,
         [150, 800],
-        "97afa51bfd8a8597",
-        "eda169eec3995eeb",
+        "41ab70a442918497",
+        "d1cdf198c69dce4c",
         "all_components_thin",
     );
 }
@@ -293,8 +347,8 @@ fn score_split() {
         &state,
         &image_cache,
         [300, 400],
-        "f5dadfe58e621e7b",
-        "c7fec6cb25b993bc",
+        "943b7cc3b8ab7eb3",
+        "942144873914f69b",
         "score_split",
     );
 }
@@ -310,8 +364,8 @@ fn dark_layout() {
     check(
         &layout.state(&mut image_cache, &timer.snapshot()),
         &image_cache,
-        "a47c590792c1bab5",
-        "91a89f563eb4f43d",
+        "5a23aa0455f99e54",
+        "40a5f867d46c8206",
         "dark_layout",
     );
 }
@@ -334,8 +388,8 @@ fn subsplits_layout() {
         &layout.state(&mut image_cache, &timer.snapshot()),
         &image_cache,
         [300, 800],
-        "39ab965781d0ceee",
-        "405baac87e52acc5",
+        "a246d641f758629d",
+        "6e0dfb7845ed3e69",
         "subsplits_layout",
     );
 }
@@ -456,6 +510,467 @@ fn horizontal() {
     );
 }
 
+#[cfg(feature = "software-rendering")]
+#[test]
+fn encodes_pngs() {
+    use rendering::software::image;
+
+    let timer = tests_helper::create_timer(&["A", "B"]);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [300, 500]);
+
+    let mut png = Vec::new();
+    renderer.write_png(&mut png).unwrap();
+    let decoded = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+        .unwrap()
+        .into_rgba8();
+
+    assert_eq!(decoded.dimensions(), (300, 500));
+    assert_eq!(decoded.as_raw(), renderer.image_data());
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn loading_fonts_rerenders_the_layout() {
+    let timer = tests_helper::create_timer(&["A", "B"]);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [300, 500]);
+    let before = renderer.image_data().to_vec();
+
+    // Loading a font that is already loaded doesn't change which fonts get
+    // chosen, so the layout needs to look the same after rendering it again
+    // from scratch.
+    renderer.load_font_data(
+        include_bytes!("../src/rendering/font/assets/FiraSans-Regular.ttf").to_vec(),
+    );
+    renderer.render(&state, &image_cache, [300, 500]);
+    assert_eq!(renderer.image_data(), before);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn skips_unchanged_frames() {
+    let timer = tests_helper::create_timer(&["A", "B"]);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::BorrowedRenderer::new();
+    let mut image = vec![0; 300 * 500 * 4];
+    renderer.render(&state, &image_cache, &mut image, [300, 500], 300, false);
+    let rendered = image.clone();
+
+    // Nothing changed, so the image is left alone.
+    image.fill(0);
+    renderer.render(&state, &image_cache, &mut image, [300, 500], 300, false);
+    assert!(image.iter().all(|&b| b == 0));
+
+    renderer.render(&state, &image_cache, &mut image, [300, 500], 300, true);
+    assert_eq!(image, rendered);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn only_redraws_the_components_that_changed() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    tests_helper::start_run(&mut timer);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::BorrowedRenderer::new();
+    let mut image = vec![0; 300 * 500 * 4];
+    renderer.render(&state, &image_cache, &mut image, [300, 500], 300, false);
+
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(10.0)]);
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut expected = vec![0; 300 * 500 * 4];
+    rendering::software::BorrowedRenderer::new().render(
+        &state,
+        &image_cache,
+        &mut expected,
+        [300, 500],
+        300,
+        false,
+    );
+
+    // The title at the top didn't change, so it isn't redrawn.
+    let title = 300 * 10 * 4;
+    image[..title].fill(0);
+    renderer.render(&state, &image_cache, &mut image, [300, 500], 300, false);
+    assert!(image[..title].iter().all(|&b| b == 0));
+
+    // Clipping the redraw to the regions that changed may round the colors of
+    // gradients slightly differently.
+    assert!(image[title..]
+        .iter()
+        .zip(&expected[title..])
+        .all(|(a, b)| a.abs_diff(*b) <= 1));
+}
+
+/// Counts how many resources the scene manager allocates.
+#[derive(Default)]
+struct CountingAllocator {
+    allocations: std::rc::Rc<std::cell::Cell<usize>>,
+}
+
+impl CountingAllocator {
+    fn count(&self) {
+        self.allocations.set(self.allocations.get() + 1);
+    }
+}
+
+impl rendering::PathBuilder for CountingAllocator {
+    type Path = ();
+
+    fn move_to(&mut self, _: f32, _: f32) {}
+    fn line_to(&mut self, _: f32, _: f32) {}
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+    fn close(&mut self) {}
+    fn finish(self) -> Self::Path {}
+}
+
+impl rendering::ResourceAllocator for CountingAllocator {
+    type PathBuilder = Self;
+    type Path = ();
+    type Image = Dummy;
+    type Font = ();
+    type Label = Dummy;
+
+    fn path_builder(&mut self) -> Self::PathBuilder {
+        self.count();
+        Self::default()
+    }
+    fn create_image(&mut self, _: &[u8]) -> Option<Self::Image> {
+        self.count();
+        Some(Dummy)
+    }
+    fn create_font(&mut self, _: Option<&livesplit_core::settings::Font>, _: rendering::FontKind) {
+        self.count();
+    }
+    fn create_label(&mut self, _: &str, _: &mut (), _: Option<f32>) -> Self::Label {
+        self.count();
+        Dummy
+    }
+    fn update_label(&mut self, _: &mut Dummy, _: &str, _: &mut (), _: Option<f32>) {
+        self.count();
+    }
+}
+
+struct Dummy;
+
+impl rendering::Label for Dummy {
+    fn width(&self, _: f32) -> f32 {
+        0.0
+    }
+    fn width_without_max_width(&self, _: f32) -> f32 {
+        0.0
+    }
+}
+
+impl rendering::Image for Dummy {
+    fn aspect_ratio(&self) -> f32 {
+        1.0
+    }
+}
+
+impl rendering::SharedOwnership for Dummy {
+    fn share(&self) -> Self {
+        Dummy
+    }
+}
+
+#[test]
+fn identical_frames_allocate_nothing() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), Some(20.0)]);
+    let mut layout = Layout::default_layout();
+    layout.push(component::graph::Component::new());
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let allocator = CountingAllocator::default();
+    let allocations = allocator.allocations.clone();
+    let mut manager = rendering::SceneManager::new(allocator);
+    manager.update_scene(
+        CountingAllocator {
+            allocations: allocations.clone(),
+        },
+        [300.0, 600.0],
+        &state,
+        &image_cache,
+    );
+    assert_ne!(allocations.get(), 0);
+
+    // The paths of the graph are cached by the component, the glyphs by the
+    // text engine and the backgrounds of the components all share the same
+    // rectangle, so nothing needs to be built again.
+    allocations.set(0);
+    manager.update_scene(
+        CountingAllocator {
+            allocations: allocations.clone(),
+        },
+        [300.0, 600.0],
+        &state,
+        &image_cache,
+    );
+    assert_eq!(allocations.get(), 0);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn reuses_the_paths_of_the_graph() {
+    let mut timer = tests_helper::create_timer(&["A", "B", "C"]);
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), Some(20.0)]);
+    let mut layout = Layout::new();
+    layout.push(component::graph::Component::new());
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::BorrowedRenderer::new();
+    let mut image = vec![0; 300 * 120 * 4];
+    renderer.render(&state, &image_cache, &mut image, [300, 120], 300, false);
+
+    // The graph didn't change, so its paths are reused and the frame is
+    // skipped.
+    image.fill(0);
+    renderer.render(&state, &image_cache, &mut image, [300, 120], 300, false);
+    assert!(image.iter().all(|&b| b == 0));
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn renders_with_the_physical_resolution() {
+    let timer = tests_helper::create_timer(&["A", "B"]);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [300, 500]);
+    let expected = renderer.image_data().to_vec();
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render_scaled(&state, &image_cache, [150.0, 250.0], 2.0);
+    assert_eq!(renderer.image().dimensions(), (300, 500));
+    assert_eq!(renderer.image_data(), expected);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn decodes_images_in_the_background() {
+    let run = lss(run_files::LIVESPLIT_1_0);
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [300, 500]);
+    let expected = renderer.image_data().to_vec();
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.set_decode_images_in_background(true);
+    renderer.render(&state, &image_cache, [300, 500]);
+
+    // The icons show up as soon as they are decoded.
+    for _ in 0..500 {
+        if renderer.image_data() == expected {
+            return;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        renderer.render(&state, &image_cache, [300, 500]);
+    }
+    panic!("The icons never got decoded");
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn replaces_the_layout_background() {
+    use rendering::BackgroundMode;
+
+    let timer = tests_helper::create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(component::blank_space::Component::new());
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [50, 50]);
+    assert!(renderer.image_data().chunks(4).all(|p| p[3] == 0xFF));
+
+    renderer.set_background_mode(BackgroundMode::Transparent);
+    renderer.render(&state, &image_cache, [50, 50]);
+    assert!(renderer.image_data().iter().all(|&b| b == 0));
+
+    renderer.set_background_mode(BackgroundMode::ChromaKey([0.0, 1.0, 0.0, 1.0]));
+    renderer.render(&state, &image_cache, [50, 50]);
+    assert!(renderer
+        .image_data()
+        .chunks(4)
+        .all(|p| p == [0x00, 0xFF, 0x00, 0xFF]));
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn animates_scrolling_the_splits() {
+    let names: Vec<String> = (0..30).map(|i| i.to_string()).collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    let mut timer = tests_helper::create_timer(&names);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+
+    let mut animated = rendering::software::Renderer::new();
+    animated.set_animation_duration(TimeSpan::from_seconds(3600.0));
+    let mut snapping = rendering::software::Renderer::new();
+
+    // The initial frame doesn't animate anything.
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    animated.render(&state, &image_cache, [300, 500]);
+    snapping.render(&state, &image_cache, [300, 500]);
+    assert_eq!(animated.image_data(), snapping.image_data());
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(1.0); 20]);
+
+    // The splits are still shown where they were before scrolling.
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    animated.render(&state, &image_cache, [300, 500]);
+    snapping.render(&state, &image_cache, [300, 500]);
+    assert_ne!(animated.image_data(), snapping.image_data());
+
+    // Once the animations are disabled, the splits immediately snap into place.
+    animated.set_animation_duration(TimeSpan::zero());
+    animated.render(&state, &image_cache, [300, 500]);
+    assert_eq!(animated.image_data(), snapping.image_data());
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn renders_golden_images() {
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    run.set_attempt_count(1337);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    // This needs to match the default test, regardless of the system's fonts.
+    let image = rendering::software::render_to_image(&state, &image_cache, [300, 500], &[]);
+    assert_eq!(
+        format!("{:016x}", seahash::hash(&image)),
+        "ab6fcc833bccb24b"
+    );
+
+    // Loading fonts that are already bundled doesn't change anything.
+    let image = rendering::software::render_to_image(
+        &state,
+        &image_cache,
+        [300, 500],
+        &[rendering::TEXT_FONT, rendering::TIMER_FONT],
+    );
+    assert_eq!(
+        format!("{:016x}", seahash::hash(&image)),
+        "ab6fcc833bccb24b"
+    );
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn rotates_the_layout() {
+    use rendering::Rotation;
+
+    let timer = tests_helper::create_timer(&["A", "B"]);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [300, 500]);
+    let unrotated = renderer.image().to_owned();
+
+    for rotation in [Rotation::Clockwise, Rotation::CounterClockwise] {
+        let mut renderer = rendering::software::Renderer::new();
+        renderer.set_rotation(rotation);
+        let screen_resolution = rotation.swap_resolution([300, 500]);
+        renderer.render(&state, &image_cache, screen_resolution);
+        let rotated = renderer.image();
+        assert_eq!(rotated.dimensions(), (500, 300));
+
+        let screen_resolution = screen_resolution.map(|v| v as f32);
+        for (x, y, pixel) in unrotated.enumerate_pixels() {
+            let [screen_x, screen_y] =
+                rotation.layout_to_screen([x as f32 + 0.5, y as f32 + 0.5], screen_resolution);
+            assert_eq!(rotated.get_pixel(screen_x as u32, screen_y as u32), pixel);
+            assert_eq!(
+                rotation.screen_to_layout([screen_x, screen_y], screen_resolution),
+                [x as f32 + 0.5, y as f32 + 0.5],
+            );
+        }
+    }
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn writes_frames_into_shared_memory() {
+    use rendering::shared_frame::{self, Writer, HEADER_SIZE, MAGIC};
+
+    let timer = tests_helper::create_timer(&["A", "B"]);
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [300, 500]);
+
+    let mut writer = Writer::new();
+    let mut memory = vec![0; shared_frame::memory_size([300, 500])];
+    assert!(writer
+        .write(&mut memory[1..], &state, &image_cache, [300, 500])
+        .is_err());
+
+    writer
+        .write(&mut memory, &state, &image_cache, [300, 500])
+        .unwrap();
+    let header = |range: std::ops::Range<usize>| {
+        let mut bytes = [0; 8];
+        bytes[..range.len()].copy_from_slice(&memory[range]);
+        u64::from_ne_bytes(bytes)
+    };
+    assert_eq!(memory[..4], MAGIC);
+    assert_eq!(header(8..16), 2);
+    assert_eq!(header(16..24), 1);
+    assert_eq!(
+        [header(40..44), header(44..48), header(48..52)],
+        [300, 500, 1200]
+    );
+    assert_eq!(&memory[HEADER_SIZE..], renderer.image_data());
+
+    // Nothing changed, so no new frame is written.
+    writer
+        .write(&mut memory, &state, &image_cache, [300, 500])
+        .unwrap();
+    assert_eq!(writer.frame_index(), 1);
+}
+
 #[track_caller]
 fn check(
     state: &LayoutState,
@@ -477,9 +992,27 @@ fn check_dims(
     name: &str,
 ) {
     #[cfg(feature = "software-rendering")]
-    check_software(state, image_cache, dims, _png_hash, name);
+    check_software(state, image_cache, dims, false, _png_hash, name);
+    #[cfg(feature = "svg-rendering")]
+    check_svg(state, image_cache, dims, false, _svg_hash, name);
+}
+
+/// Checks the rendered layout like [`check_dims`], but without ever falling
+/// back to the fonts of the operating system, so characters the bundled fonts
+/// don't support render the same on every system.
+#[track_caller]
+fn check_with_bundled_fonts(
+    state: &LayoutState,
+    image_cache: &ImageCache,
+    dims: [u32; 2],
+    _png_hash: &str,
+    _svg_hash: &str,
+    name: &str,
+) {
+    #[cfg(feature = "software-rendering")]
+    check_software(state, image_cache, dims, true, _png_hash, name);
     #[cfg(feature = "svg-rendering")]
-    check_svg(state, image_cache, dims, _svg_hash, name);
+    check_svg(state, image_cache, dims, true, _svg_hash, name);
 }
 
 #[cfg(feature = "software-rendering")]
@@ -488,10 +1021,15 @@ fn check_software(
     state: &LayoutState,
     image_cache: &ImageCache,
     dims: [u32; 2],
+    bundled_fonts: bool,
     expected_hash: &str,
     name: &str,
 ) {
-    let mut renderer = rendering::software::Renderer::new();
+    let mut renderer = if bundled_fonts {
+        rendering::software::Renderer::with_bundled_fonts()
+    } else {
+        rendering::software::Renderer::new()
+    };
     renderer.render(state, image_cache, dims);
 
     let hash_image = renderer.image();
@@ -551,11 +1089,16 @@ fn check_svg(
     state: &LayoutState,
     image_cache: &ImageCache,
     dims: [u32; 2],
+    bundled_fonts: bool,
     expected_hash: &str,
     name: &str,
 ) {
     let mut hash_image = String::new();
-    let mut renderer = rendering::svg::Renderer::new();
+    let mut renderer = if bundled_fonts {
+        rendering::svg::Renderer::with_bundled_fonts()
+    } else {
+        rendering::svg::Renderer::new()
+    };
     renderer
         .render(&mut hash_image, state, image_cache, dims.map(|v| v as f32))
         .unwrap();
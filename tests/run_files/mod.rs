@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+pub const BACKUP_ZIP: &[u8] = include_bytes!("backup.zip");
 pub const CELESTE: &str = include_str!("Celeste - Any% (1.2.1.5).lss");
 pub const FLITTER: &str = include_str!("flitter.json");
 pub const GENERIC_SPLITS_IO: &str = include_str!("generic_splits_io.json");
@@ -8,17 +9,23 @@ pub const LIVESPLIT_1_4: &str = include_str!("livesplit1.4.lss");
 pub const LIVESPLIT_1_5: &str = include_str!("livesplit1.5.lss");
 pub const LIVESPLIT_1_6_GAMETIME: &str = include_str!("livesplit1.6_gametime.lss");
 pub const LIVESPLIT_1_6: &str = include_str!("livesplit1.6.lss");
+pub const LIVESPLIT_1_6_GZIP: &[u8] = include_bytes!("livesplit1.6.lss.gz");
 pub const LIVESPLIT_ATTEMPT_ENDED_BUG: &str = include_str!("livesplit_attempt_ended_bug.lss");
 pub const LIVESPLIT_FUZZ_CRASH_UTF8: &str = include_str!("livesplit_fuzz_crash_utf8.lss");
 pub const LIVESPLIT_FUZZ_CRASH: &str = include_str!("livesplit_fuzz_crash.lss");
 pub const LLANFAIR_GERED_ICONS: &str = include_str!("llanfair_gered_icons.lfs");
 pub const LLANFAIR_GERED_WITH_REFS: &str = include_str!("llanfair_gered_with_refs.lfs");
 pub const LLANFAIR_GERED: &str = include_str!("llanfair_gered.lfs");
+pub const LLANFAIR_GERED_APPEARANCE: &str = include_str!("llanfair_gered_appearance.lfs");
 pub const LLANFAIR: &[u8] = include_bytes!("llanfair");
 pub const PORTAL2_LIVE_TIMER1: &str = include_str!("portal2_live_timer1.csv");
 pub const PORTAL2_LIVE_TIMER2: &str = include_str!("portal2_live_timer2.csv");
 pub const SOURCE_LIVE_TIMER: &str = include_str!("source_live_timer.json");
 pub const SOURCE_LIVE_TIMER2: &str = include_str!("source_live_timer2.json");
+pub const SPEEDRUN_COM_CATEGORY: &str = include_str!("speedrun_com_category.json");
+pub const SPEEDRUN_COM_GAME: &str = include_str!("speedrun_com_game.json");
+pub const SPEEDRUN_COM_LEADERBOARD: &str = include_str!("speedrun_com_leaderboard.json");
+pub const SPEEDRUN_COM_RUN: &str = include_str!("speedrun_com_run.json");
 pub const SPEEDRUN_IGT: &str = include_str!("speedrun_igt.json");
 pub const SPLITTERINO: &str = include_str!("splitterino.splits");
 pub const SPLITTERZ: &str = include_str!("splitterz");
@@ -0,0 +1,241 @@
+#![cfg(feature = "networking")]
+
+mod run_files;
+
+use livesplit_core::{
+    component::world_record,
+    networking::speedrun_com::{self, Client, WorldRecordCache, NOTES_VARIABLE},
+    Run, Segment, TimeSpan, Timer, TimingMethod,
+};
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+/// Serves the resources from the run files and counts the requests.
+#[derive(Default)]
+struct MockClient {
+    requests: Cell<usize>,
+}
+
+impl Client for MockClient {
+    async fn get(&self, url: &str) -> Option<String> {
+        self.requests.set(self.requests.get() + 1);
+        let body = if url.contains("/games/") {
+            run_files::SPEEDRUN_COM_GAME
+        } else if url.contains("/categories/") {
+            run_files::SPEEDRUN_COM_CATEGORY
+        } else if url.contains("/leaderboards/76r55vd8/") {
+            run_files::SPEEDRUN_COM_LEADERBOARD
+        } else {
+            return None;
+        };
+        Some(body.into())
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    match pin!(future).poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => output,
+        Poll::Pending => unreachable!("The mock client never has to wait"),
+    }
+}
+
+#[test]
+fn scaffolds_run() {
+    let scaffolded = speedrun_com::scaffold_run(
+        run_files::SPEEDRUN_COM_GAME,
+        run_files::SPEEDRUN_COM_CATEGORY,
+        true,
+    )
+    .unwrap();
+    let run = scaffolded.run;
+
+    assert_eq!(run.game_name(), "Super Mario Odyssey");
+    assert_eq!(run.category_name(), "Any%");
+    assert_eq!(run.len(), 1);
+    assert_eq!(scaffolded.platforms, ["Nintendo Switch"]);
+
+    let metadata = run.metadata();
+    assert_eq!(metadata.platform_name(), "Nintendo Switch");
+    assert_eq!(
+        metadata
+            .speedrun_com_variables()
+            .map(|(name, value)| (name, value.as_str()))
+            .collect::<Vec<_>>(),
+        [("Platform", "Switch"), ("Version", "1.3")],
+    );
+
+    let notes = metadata.custom_variable(NOTES_VARIABLE).unwrap();
+    assert!(notes.is_permanent);
+    assert!(notes.value.starts_with("Timing starts"));
+}
+
+#[test]
+fn scaffolds_run_without_rules_or_embedded_resources() {
+    let scaffolded = speedrun_com::scaffold_run(
+        r#"{ "data": { "names": { "international": "Celeste" }, "platforms": ["8gej2n93"] } }"#,
+        r#"{ "data": { "name": "Any%", "rules": "No rules", "variables": [] } }"#,
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(scaffolded.run.game_name(), "Celeste");
+    assert!(scaffolded.platforms.is_empty());
+    assert!(scaffolded.run.metadata().platform_name().is_empty());
+    assert!(scaffolded
+        .run
+        .metadata()
+        .custom_variable(NOTES_VARIABLE)
+        .is_none());
+}
+
+#[test]
+fn rejects_invalid_payloads() {
+    assert!(matches!(
+        speedrun_com::scaffold_run("{}", run_files::SPEEDRUN_COM_CATEGORY, false),
+        Err(speedrun_com::Error::ParseGame { .. }),
+    ));
+    assert!(matches!(
+        speedrun_com::scaffold_run(run_files::SPEEDRUN_COM_GAME, "[]", false),
+        Err(speedrun_com::Error::ParseCategory { .. }),
+    ));
+}
+
+#[test]
+fn parses_world_records() {
+    let world_record = speedrun_com::parse_world_record(run_files::SPEEDRUN_COM_LEADERBOARD)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        world_record.time.real_time,
+        Some(TimeSpan::from_seconds(3493.5))
+    );
+    assert_eq!(
+        world_record.time.game_time,
+        Some(TimeSpan::from_seconds(3421.0))
+    );
+    assert_eq!(world_record.runners, ["Mario", "Luigi"]);
+
+    assert_eq!(
+        speedrun_com::parse_world_record(r#"{ "data": { "runs": [] } }"#).unwrap(),
+        None,
+    );
+    assert!(matches!(
+        speedrun_com::parse_world_record("{}"),
+        Err(speedrun_com::Error::ParseLeaderboard { .. }),
+    ));
+}
+
+#[test]
+fn caches_world_records() {
+    let url = speedrun_com::leaderboard_url("76r55vd8", "w20w1lzd");
+    assert_eq!(
+        url,
+        "https://www.speedrun.com/api/v1/leaderboards/76r55vd8/category/w20w1lzd?top=1&embed=players"
+    );
+
+    let mut cache = WorldRecordCache::default();
+    assert!(cache.needs_update(&url));
+    assert!(cache.get(&url).is_none());
+
+    assert!(cache.update(&url, "[]").is_err());
+    assert!(cache.needs_update(&url));
+
+    cache
+        .update(&url, run_files::SPEEDRUN_COM_LEADERBOARD)
+        .unwrap();
+    assert!(!cache.needs_update(&url));
+    assert_eq!(cache.get(&url).unwrap().runners, ["Mario", "Luigi"]);
+
+    let mut cache = WorldRecordCache::new(TimeSpan::zero());
+    cache
+        .update(&url, run_files::SPEEDRUN_COM_LEADERBOARD)
+        .unwrap();
+    assert!(cache.needs_update(&url));
+    assert!(cache.get(&url).is_some());
+}
+
+#[test]
+fn fetches_scaffolded_runs() {
+    let client = MockClient::default();
+    let scaffolded = block_on(speedrun_com::fetch_scaffolded_run(
+        &client, "76r55vd8", "w20w1lzd", false,
+    ))
+    .unwrap();
+    assert_eq!(client.requests.get(), 2);
+    assert_eq!(scaffolded.run.game_name(), "Super Mario Odyssey");
+    assert_eq!(scaffolded.run.category_name(), "Any%");
+}
+
+#[test]
+fn fetches_world_records() {
+    let client = MockClient::default();
+    let world_record = block_on(speedrun_com::fetch_world_record(
+        &client, "76r55vd8", "w20w1lzd",
+    ))
+    .unwrap()
+    .unwrap();
+    assert_eq!(world_record.runners, ["Mario", "Luigi"]);
+
+    assert!(matches!(
+        block_on(speedrun_com::fetch_world_record(
+            &client, "o1y9wo6q", "wkpoo02r"
+        )),
+        Err(speedrun_com::Error::Request { .. }),
+    ));
+}
+
+#[test]
+fn only_fetches_outdated_world_records() {
+    let client = MockClient::default();
+    let mut cache = WorldRecordCache::default();
+
+    for _ in 0..2 {
+        let world_record = block_on(cache.fetch(&client, "76r55vd8", "w20w1lzd")).unwrap();
+        assert_eq!(world_record.unwrap().runners, ["Mario", "Luigi"]);
+    }
+    assert_eq!(client.requests.get(), 1);
+
+    assert!(block_on(cache.fetch(&client, "o1y9wo6q", "wkpoo02r")).is_err());
+    assert!(cache
+        .get(&speedrun_com::leaderboard_url("o1y9wo6q", "wkpoo02r"))
+        .is_none());
+    assert_eq!(client.requests.get(), 2);
+}
+
+#[test]
+fn shows_world_records() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Finish"));
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut component = world_record::Component::new();
+    assert_eq!(component.state(&timer).value, "—");
+
+    component.set_world_record(
+        speedrun_com::parse_world_record(run_files::SPEEDRUN_COM_LEADERBOARD).unwrap(),
+    );
+    assert_eq!(component.state(&timer).value, "58:13.50 by Mario, Luigi");
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    assert_eq!(component.state(&timer).value, "57:01.00 by Mario, Luigi");
+
+    let settings = component.settings_mut();
+    settings.manual_time = Some(TimeSpan::from_seconds(3000.0));
+    settings.manual_runner = String::from("Peach");
+    assert_eq!(component.state(&timer).value, "50:00.00 by Peach");
+
+    component.settings_mut().show_runners = false;
+    assert_eq!(component.state(&timer).value, "50:00.00");
+}
@@ -1,20 +1,31 @@
+#![cfg(feature = "parsers")]
+
 mod run_files;
 
 mod parse {
     use crate::run_files;
     use livesplit_core::{
         analysis::total_playtime,
+        layout::ComponentSettings,
+        run::parser,
         run::parser::{
             composite, flitter, livesplit, llanfair, llanfair_gered, portal2_live_timer,
-            source_live_timer, speedrun_igt, splits_io, splitterino, splitterz, time_split_tracker,
-            urn, wsplit, TimerKind,
+            source_live_timer, speedrun_com, speedrun_igt, splits_io, splitterino, splitterz,
+            time_split_tracker, urn, wsplit, TimerKind,
         },
-        Run, TimeSpan,
+        settings::{Color, FontStyle, FontWeight, Gradient, LayoutBackground},
+        Run, Segment, Time, TimeSpan,
     };
+    use std::io::BufReader;
 
     #[track_caller]
     fn livesplit(data: &str) -> Run {
-        livesplit::parse(data).unwrap()
+        let run = livesplit::parse(data).unwrap();
+        // A tiny buffer ensures that elements get split across many reads.
+        let streamed =
+            livesplit::parse_reader(BufReader::with_capacity(7, data.as_bytes())).unwrap();
+        assert_eq!(run, streamed);
+        run
     }
 
     #[track_caller]
@@ -32,6 +43,129 @@ mod parse {
         livesplit::parse(run_files::LIVESPLIT_FUZZ_CRASH_UTF8).unwrap_err();
     }
 
+    #[test]
+    fn livesplit_reader_fuzz_crash() {
+        livesplit::parse_reader(run_files::LIVESPLIT_FUZZ_CRASH.as_bytes()).unwrap_err();
+        livesplit::parse_reader(run_files::LIVESPLIT_FUZZ_CRASH_UTF8.as_bytes()).unwrap_err();
+        livesplit::parse_reader(run_files::LLANFAIR_GERED.as_bytes()).unwrap_err();
+    }
+
+    #[test]
+    fn livesplit_deferred_icons() {
+        let icon = format!("<Icon>{}AAABAgML</Icon>", "A".repeat(212));
+        let data = run_files::LIVESPLIT_1_6.replacen("<Icon />", &icon, 1);
+
+        let run = livesplit::parse_deferring_icons(&data).unwrap();
+        assert!(!run.segment(0).icon().is_decoded());
+        assert!(run.segment(1).icon().is_decoded());
+        assert_eq!(run.segment(0).icon().data(), [1, 2, 3]);
+        assert!(run.segment(0).icon().is_decoded());
+        assert_eq!(run, livesplit(&data));
+    }
+
+    #[test]
+    fn livesplit_lenient_without_corruption() {
+        let recovered = livesplit::parse_lenient(run_files::CELESTE.as_bytes()).unwrap();
+        assert!(recovered.recoveries.is_empty());
+        assert_eq!(recovered.run, livesplit(run_files::CELESTE));
+    }
+
+    #[test]
+    fn livesplit_lenient_truncated() {
+        let data = run_files::LIVESPLIT_1_6;
+        let end = data.find("<Name>Hole 10</Name>").unwrap() + "<Name>Hole 10</Name>".len();
+        let recovered = livesplit::parse_lenient(data[..end].as_bytes()).unwrap();
+
+        assert!(matches!(
+            recovered.recoveries[..],
+            [livesplit::Recovery::Truncated { .. }],
+        ));
+        let run = recovered.run;
+        assert_eq!(run.game_name(), "NES Open Tournament Golf");
+        assert_eq!(run.attempt_history().len(), 55);
+        assert_eq!(run.len(), 10);
+        assert_eq!(run.segment(9).name(), "Hole 10");
+    }
+
+    #[test]
+    fn livesplit_lenient_invalid_characters() {
+        let mut data = run_files::LIVESPLIT_1_6
+            .replace("Tournament", "& Tournament <3")
+            .into_bytes();
+        let pos = data.windows(9).position(|w| w == b"US Course").unwrap() + 3;
+        data.insert(pos, 0xFF);
+
+        let recovered = livesplit::parse_lenient(&data).unwrap();
+
+        assert!(matches!(
+            recovered.recoveries[..],
+            [
+                livesplit::Recovery::ReplacedInvalidUtf8,
+                livesplit::Recovery::EscapedCharacters { count: 2 },
+            ],
+        ));
+        let run = recovered.run;
+        assert_eq!(run.game_name(), "NES Open & Tournament <3 Golf");
+        assert_eq!(run.category_name(), "US \u{FFFD}Course");
+        assert_eq!(run.len(), 18);
+    }
+
+    #[test]
+    fn livesplit_lenient_invalid_characters_after_comment() {
+        let data = run_files::LIVESPLIT_1_6
+            .replace("<Segments>", "<!-- Segments -->\n  <Segments>")
+            .replace("<Name>Hole 2</Name>", "<Name>Hole 2 & 3</Name>");
+
+        let recovered = livesplit::parse_lenient(data.as_bytes()).unwrap();
+
+        assert!(matches!(
+            recovered.recoveries[..],
+            [livesplit::Recovery::EscapedCharacters { count: 1 }],
+        ));
+        assert_eq!(recovered.run.segment(1).name(), "Hole 2 & 3");
+        assert_eq!(recovered.run.len(), 18);
+    }
+
+    #[test]
+    fn livesplit_lenient_dropped_attempt() {
+        let data = run_files::LIVESPLIT_1_6.replace("<Attempt id=\"2\"", "<Attempt id=\"x\"");
+        let recovered = livesplit::parse_lenient(data.as_bytes()).unwrap();
+
+        assert!(matches!(
+            recovered.recoveries[..],
+            [livesplit::Recovery::DroppedElement { ref element, .. }]
+                if element == "AttemptHistory/Attempt",
+        ));
+        assert_eq!(recovered.run.attempt_history().len(), 54);
+        assert_eq!(recovered.run.len(), 18);
+    }
+
+    #[test]
+    fn livesplit_lenient_not_livesplit() {
+        livesplit::parse_lenient(run_files::LLANFAIR_GERED.as_bytes()).unwrap_err();
+    }
+
+    #[test]
+    fn livesplit_error_position() {
+        let data = run_files::LIVESPLIT_1_6.replacen(
+            "<RealTime>00:15:12.2960000</RealTime>",
+            "<RealTime>00:15:1x.2960000</RealTime>",
+            1,
+        );
+        let error = livesplit::parse(&data).unwrap_err();
+        assert_eq!(error.position.line, 18);
+        assert!(matches!(error.error, livesplit::Error::ParseTime { .. }));
+        assert!(error.to_string().contains(" at line 18, column "));
+    }
+
+    #[test]
+    fn text_error_position() {
+        let error = wsplit::parse("Title=Any%\nA,0,1.5,1.5\nB,0,x,1.5\n", false).unwrap_err();
+        assert_eq!(error.position.line, 3);
+        assert_eq!(error.position.column, 1);
+        assert!(matches!(error.error, wsplit::Error::PbTime { .. }));
+    }
+
     #[test]
     fn livesplit_1_0() {
         livesplit(run_files::LIVESPLIT_1_0);
@@ -99,6 +233,38 @@ mod parse {
         parse_llanfair_gered(run_files::LLANFAIR_GERED_ICONS);
     }
 
+    #[test]
+    fn llanfair_gered_appearance() {
+        let (run, layout) =
+            llanfair_gered::parse_with_layout(run_files::LLANFAIR_GERED_APPEARANCE).unwrap();
+        assert_eq!(
+            run,
+            llanfair_gered::parse(run_files::LLANFAIR_GERED).unwrap()
+        );
+
+        let general = layout.general_settings();
+        assert_eq!(
+            general.background,
+            LayoutBackground::Gradient(Gradient::Plain(Color::rgba8(16, 32, 48, 255)))
+        );
+        assert_eq!(general.best_segment_color, Color::rgba8(255, 215, 0, 255));
+        let timer_font = general.timer_font.as_ref().unwrap();
+        assert_eq!(timer_font.family, "Digitalism");
+        assert_eq!(timer_font.style, FontStyle::Italic);
+        assert_eq!(timer_font.weight, FontWeight::Bold);
+
+        let settings = layout.settings();
+        let [ComponentSettings::Title(title), ComponentSettings::Splits(splits), ComponentSettings::Timer(_), ComponentSettings::PreviousSegment(_)] =
+            &settings.components[..]
+        else {
+            panic!("Unexpected components");
+        };
+        // The malformed title color is ignored.
+        assert_eq!(title.text_color, None);
+        assert!(!title.show_attempt_count);
+        assert_eq!(splits.visual_split_count, 8);
+    }
+
     #[test]
     fn time_split_tracker() {
         let run = time_split_tracker::parse(run_files::TIME_SPLIT_TRACKER, None).unwrap();
@@ -132,7 +298,26 @@ mod parse {
 
     #[test]
     fn splitterino() {
-        splitterino::parse(run_files::SPLITTERINO).unwrap();
+        let run = splitterino::parse(run_files::SPLITTERINO).unwrap();
+        assert_eq!(run.game_name(), "Some Game Name");
+        assert_eq!(run.category_name(), "Some Category Name");
+        assert_eq!(run.metadata().platform_name(), "Wii U");
+        assert_eq!(run.len(), 4);
+
+        let segments = run.segments();
+        assert_eq!(segments[0].name(), "Segment 1");
+        assert_eq!(
+            segments[0].personal_best_split_time().real_time,
+            Some(TimeSpan::from_milliseconds(1282.0))
+        );
+        assert_eq!(
+            segments[1].personal_best_split_time().real_time,
+            Some(TimeSpan::from_milliseconds(1476.0))
+        );
+        assert_eq!(
+            segments[0].best_segment_time().real_time,
+            Some(TimeSpan::from_milliseconds(321.0))
+        );
     }
 
     #[test]
@@ -170,11 +355,268 @@ mod parse {
         splits_io::parse(run_files::GENERIC_SPLITS_IO).unwrap();
     }
 
+    #[test]
+    fn speedrun_com() {
+        let imported = speedrun_com::parse(run_files::SPEEDRUN_COM_RUN).unwrap();
+        let run = imported.run;
+        assert_eq!(run.game_name(), "Super Mario Odyssey");
+        assert_eq!(run.category_name(), "Any%");
+        assert_eq!(run.metadata().run_id(), "z0ovkx4m");
+        assert_eq!(run.metadata().platform_name(), "Nintendo Switch");
+        assert_eq!(run.metadata().region_name(), "");
+        assert!(!run.metadata().uses_emulator());
+        assert_eq!(
+            run.metadata()
+                .speedrun_com_variables()
+                .map(|(name, value)| (name, value.as_str()))
+                .collect::<Vec<_>>(),
+            [("Platform", "Switch")]
+        );
+        assert_eq!(run.len(), 1);
+        assert_eq!(
+            run.segment(0).personal_best_split_time().real_time,
+            Some(TimeSpan::from_seconds(3536.0))
+        );
+        assert_eq!(run.segment(0).personal_best_split_time().game_time, None);
+        assert_eq!(imported.splits_io_id.as_deref(), Some("5f2f"));
+    }
+
     #[test]
     fn speedrun_igt() {
         speedrun_igt::parse(run_files::SPEEDRUN_IGT).unwrap();
     }
 
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzip_compressed_livesplit() {
+        use livesplit_core::run::saver;
+
+        let parsed = composite::parse(run_files::LIVESPLIT_1_6_GZIP, None).unwrap();
+        assert!(matches!(parsed.kind, TimerKind::LiveSplit));
+        assert_eq!(parsed.run, livesplit(run_files::LIVESPLIT_1_6));
+
+        let run = livesplit(run_files::CELESTE);
+        let mut uncompressed = String::new();
+        saver::livesplit::save_run(&run, &mut uncompressed).unwrap();
+        let compressed = saver::livesplit::save_run_compressed(&run).unwrap();
+        assert!(compressed.len() * 4 < uncompressed.len());
+
+        let analysis = composite::analyze(&compressed, None);
+        let best = analysis.best().unwrap();
+        assert!(matches!(best.parsed.kind, TimerKind::LiveSplit));
+        assert_eq!(best.parsed.run, livesplit(&uncompressed));
+
+        let mut corrupted = compressed;
+        let checksum = corrupted.len() - 8;
+        corrupted[checksum] ^= 1;
+        composite::parse(&corrupted, None).unwrap_err();
+    }
+
+    #[cfg(feature = "zip-import")]
+    #[test]
+    fn zip_archive() {
+        use livesplit_core::run::parser::zip;
+
+        let archive = zip::parse(run_files::BACKUP_ZIP, false).unwrap();
+        assert_eq!(archive.skipped, ["notes.txt"]);
+        let file_names: Vec<_> = archive
+            .runs
+            .iter()
+            .map(|imported| imported.file_names.join(", "))
+            .collect();
+        assert_eq!(
+            file_names,
+            [
+                "Splits/Golf.lss",
+                "Splits/Golf (Backup).lss",
+                "Splits/SotN.json"
+            ]
+        );
+        assert_eq!(archive.runs[0].run, livesplit(run_files::LIVESPLIT_1_6));
+        assert!(matches!(archive.runs[2].kind, TimerKind::Urn));
+
+        let golf = archive.runs.into_iter().next().unwrap().run;
+        let merged = zip::parse(run_files::BACKUP_ZIP, true).unwrap();
+        assert_eq!(merged.runs.len(), 2);
+        assert_eq!(
+            merged.runs[0].file_names,
+            ["Splits/Golf.lss", "Splits/Golf (Backup).lss"]
+        );
+
+        let run = &merged.runs[0].run;
+        assert_eq!(run.attempt_count(), 2 * golf.attempt_count());
+        assert_eq!(
+            run.attempt_history().len(),
+            2 * golf.attempt_history().len()
+        );
+        let max_index = golf.max_attempt_history_index().unwrap();
+        assert_eq!(run.max_attempt_history_index(), Some(2 * max_index));
+        for (merged, segment) in run.segments().iter().zip(golf.segments()) {
+            assert_eq!(
+                merged.segment_history().iter().len(),
+                2 * segment.segment_history().iter().len()
+            );
+            assert_eq!(merged.best_segment_time(), segment.best_segment_time());
+            assert_eq!(
+                merged.personal_best_split_time(),
+                segment.personal_best_split_time()
+            );
+        }
+
+        assert!(matches!(
+            zip::parse(run_files::LIVESPLIT_1_6.as_bytes(), false),
+            Err(zip::Error::NotAnArchive)
+        ));
+    }
+
+    #[test]
+    fn import_history_from_backup() {
+        let backup = livesplit(run_files::LIVESPLIT_1_6);
+        let first_segment = backup.segment(0).clone();
+        let max_index = backup.max_attempt_history_index().unwrap();
+
+        // The current splits have an additional segment at the start and their
+        // first segment got renamed, so its history can't be matched.
+        let mut run = Run::new();
+        run.push_segment(Segment::new("Intro"));
+        for segment in &backup.segments()[1..] {
+            run.push_segment(Segment::new(segment.name()));
+        }
+        run.push_segment(Segment::new(format!("{} (New)", first_segment.name())));
+        run.set_attempt_count(1);
+        run.add_attempt(Time::new(), None, None, None);
+        run.segment_mut(0)
+            .segment_history_mut()
+            .insert(1, Time::new());
+        run.segment_mut(1)
+            .segment_history_mut()
+            .insert(0, Time::new());
+
+        parser::import_history_from(&mut run, backup.clone());
+
+        assert_eq!(run.attempt_count(), 1 + backup.attempt_count());
+        assert_eq!(
+            run.attempt_history().len(),
+            1 + backup.attempt_history().len()
+        );
+        assert_eq!(run.max_attempt_history_index(), Some(1 + max_index));
+        assert_eq!(
+            run.attempt_history()[1].time(),
+            backup.attempt_history()[0].time()
+        );
+
+        assert_eq!(run.segment(0).segment_history().iter().len(), 1);
+        assert!(run
+            .segment(run.len() - 1)
+            .segment_history()
+            .iter()
+            .next()
+            .is_none());
+
+        let min_index = backup.segment(1).segment_history().min_index();
+        let imported = run.segment(1);
+        assert_eq!(
+            imported.segment_history().iter().len(),
+            1 + backup.segment(1).segment_history().iter().len()
+        );
+        assert_eq!(imported.segment_history().min_index(), min_index - 1);
+        assert_eq!(
+            imported.best_segment_time(),
+            backup.segment(1).best_segment_time()
+        );
+        for &(index, time) in backup.segment(1).segment_history() {
+            let index = if index > 0 { index + 1 } else { index - 1 };
+            assert_eq!(imported.segment_history().get(index), Some(time));
+        }
+    }
+
+    #[test]
+    fn speedrun_igt_import_attempts() {
+        let mut run = speedrun_igt::parse(run_files::SPEEDRUN_IGT).unwrap();
+
+        // A faster run that didn't find a bastion.
+        let faster = r#"{
+            "mc_version": "1.19",
+            "speedrunigt_version": "10.12+1.19",
+            "category": "ANY",
+            "run_type": "random_seed",
+            "is_coop": false,
+            "is_hardcore": false,
+            "world_name": "RandomSpeedrun #142",
+            "is_completed": true,
+            "date": 1657280000000,
+            "final_igt": 2900000,
+            "final_rta": 3300000,
+            "timelines": [
+                { "name": "enter_nether", "igt": 500000, "rta": 550000 },
+                { "name": "enter_fortress", "igt": 1900000, "rta": 2100000 }
+            ]
+        }"#;
+        speedrun_igt::import_attempt(faster, &mut run).unwrap();
+
+        // A reset after entering the nether.
+        let reset = r#"{
+            "mc_version": "1.19",
+            "speedrunigt_version": "10.12+1.19",
+            "category": "ANY",
+            "run_type": "random_seed",
+            "is_coop": false,
+            "is_hardcore": false,
+            "world_name": "RandomSpeedrun #142",
+            "is_completed": false,
+            "date": 1657290000000,
+            "final_igt": 700000,
+            "final_rta": 800000,
+            "timelines": [{ "name": "enter_nether", "igt": 600000, "rta": 650000 }]
+        }"#;
+        speedrun_igt::import_attempt(reset, &mut run).unwrap();
+
+        assert_eq!(run.attempt_count(), 3);
+        let indices: Vec<_> = run.attempt_history().iter().map(|a| a.index()).collect();
+        assert_eq!(indices, [1, 2, 3]);
+        assert_eq!(
+            run.attempt_history()[1].time().real_time,
+            Some(TimeSpan::from_seconds(3300.0))
+        );
+        assert_eq!(run.attempt_history()[2].time().real_time, None);
+
+        let nether = run.segment(0);
+        assert_eq!(nether.name(), "Enter Nether");
+        assert_eq!(
+            nether.best_segment_time().real_time,
+            Some(TimeSpan::from_seconds(550.0))
+        );
+        assert_eq!(
+            nether.segment_history().get(3).unwrap().game_time,
+            Some(TimeSpan::from_seconds(600.0))
+        );
+
+        let bastion = run.segment(1);
+        assert_eq!(bastion.segment_history().get(2).unwrap().real_time, None);
+        assert_eq!(bastion.segment_history().get(3), None);
+
+        let fortress = run.segment(2);
+        assert_eq!(
+            fortress.segment_history().get(2).unwrap().real_time,
+            Some(TimeSpan::from_seconds(1550.0))
+        );
+
+        let finish = run.segment(run.len() - 1);
+        assert_eq!(finish.name(), "Finish");
+        assert_eq!(
+            finish.segment_history().get(2).unwrap().real_time,
+            Some(TimeSpan::from_seconds(1200.0))
+        );
+        assert_eq!(
+            run.segment(run.len() - 2)
+                .segment_history()
+                .get(2)
+                .unwrap()
+                .real_time,
+            None
+        );
+    }
+
     #[test]
     fn speedrun_igt_prefers_parsing_as_itself() {
         let run = composite::parse(run_files::SPEEDRUN_IGT.as_bytes(), None).unwrap();
@@ -216,4 +658,217 @@ mod parse {
         let run = composite::parse(run_files::FLITTER.as_bytes(), None).unwrap();
         assert_eq!(run.kind, TimerKind::Flitter);
     }
+
+    #[test]
+    fn analysis_agrees_with_composite_parser() {
+        for source in [
+            run_files::LIVESPLIT_1_6,
+            run_files::CELESTE,
+            run_files::LLANFAIR_GERED,
+            run_files::SPEEDRUN_IGT,
+            run_files::GENERIC_SPLITS_IO,
+            run_files::PORTAL2_LIVE_TIMER1,
+            run_files::SPLITTERINO,
+            run_files::URN,
+            run_files::SOURCE_LIVE_TIMER,
+            run_files::FLITTER,
+        ] {
+            let parsed = composite::parse(source.as_bytes(), None).unwrap();
+            let analysis = composite::analyze(source.as_bytes(), None);
+            let best = analysis.best().unwrap();
+            assert_eq!(best.parsed.kind, parsed.kind);
+            assert_eq!(best.parsed.run, parsed.run);
+        }
+    }
+
+    #[test]
+    fn analysis_scores_and_diagnostics() {
+        let analysis = composite::analyze(run_files::LIVESPLIT_1_6.as_bytes(), None);
+
+        let best = analysis.best().unwrap();
+        assert_eq!(best.parsed.kind, TimerKind::LiveSplit);
+        assert_eq!(best.confidence, 1.0);
+
+        assert!(analysis
+            .matches
+            .windows(2)
+            .all(|w| w[0].confidence >= w[1].confidence));
+
+        assert!(analysis
+            .diagnostics
+            .iter()
+            .any(|d| d.kind == TimerKind::LiveSplitOne && !d.error.is_empty()));
+        assert_eq!(
+            analysis.matches.len() + analysis.diagnostics.len(),
+            composite::analyze(&[0xFF; 64], None).diagnostics.len(),
+        );
+    }
+
+    #[test]
+    fn analysis_without_match() {
+        let analysis = composite::analyze(&[0xFF; 64], None);
+        assert!(analysis.matches.is_empty());
+        assert!(analysis.diagnostics.iter().all(|d| !d.error.is_empty()));
+        assert!(analysis.into_best().is_err());
+    }
+}
+
+#[cfg(feature = "async")]
+mod parse_async {
+    use crate::run_files;
+    use livesplit_core::run::parser::{
+        composite::{self, Error, ParsedRun},
+        TimerKind,
+    };
+    use std::{
+        future::Future,
+        io,
+        pin::{pin, Pin},
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    /// Provides the data in short chunks of varying length, having to wait for
+    /// each one of them. The reader fails once the data is exhausted, if
+    /// configured to.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        chunk_len: usize,
+        ready: bool,
+        fail_at_end: bool,
+    }
+
+    impl futures_io::AsyncRead for Trickle<'_> {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = self.get_mut();
+            if !this.ready {
+                this.ready = true;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            this.ready = false;
+            if this.data.is_empty() && this.fail_at_end {
+                return Poll::Ready(Err(io::ErrorKind::ConnectionReset.into()));
+            }
+            this.chunk_len = this.chunk_len % 7 + 1;
+            let len = this.chunk_len.min(buf.len()).min(this.data.len());
+            let (chunk, rest) = this.data.split_at(len);
+            buf[..len].copy_from_slice(chunk);
+            this.data = rest;
+            Poll::Ready(Ok(len))
+        }
+    }
+
+    fn trickle(data: &[u8]) -> Trickle<'_> {
+        Trickle {
+            data,
+            chunk_len: 0,
+            ready: false,
+            fail_at_end: false,
+        }
+    }
+
+    #[track_caller]
+    fn parse(source: Trickle<'_>) -> composite::Result<ParsedRun<'static>> {
+        struct NoopWaker;
+
+        impl Wake for NoopWaker {
+            fn wake(self: Arc<Self>) {}
+        }
+
+        let len = source.data.len();
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(composite::parse_async(source, None));
+
+        let mut pending = 0;
+        let result = loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => break result,
+                Poll::Pending => pending += 1,
+            }
+        };
+        // The reader makes the parser wait before every chunk of at most 7
+        // bytes.
+        assert!(pending >= len / 7);
+        result
+    }
+
+    #[track_caller]
+    fn parses_like_sync(data: &[u8], kind: TimerKind<'_>) {
+        let parsed = parse(trickle(data)).unwrap();
+        let expected = composite::parse(data, None).unwrap();
+        assert_eq!(parsed.kind, kind);
+        assert_eq!(expected.kind, kind);
+        assert_eq!(parsed.run, expected.run);
+    }
+
+    #[test]
+    fn livesplit() {
+        parses_like_sync(run_files::LIVESPLIT_1_0.as_bytes(), TimerKind::LiveSplit);
+        parses_like_sync(run_files::LIVESPLIT_1_6.as_bytes(), TimerKind::LiveSplit);
+        parses_like_sync(run_files::CELESTE.as_bytes(), TimerKind::LiveSplit);
+    }
+
+    #[test]
+    fn llanfair_gered() {
+        parses_like_sync(
+            run_files::LLANFAIR_GERED.as_bytes(),
+            TimerKind::LlanfairGered,
+        );
+    }
+
+    #[test]
+    fn other_formats() {
+        parses_like_sync(run_files::LLANFAIR, TimerKind::Llanfair);
+        parses_like_sync(run_files::URN.as_bytes(), TimerKind::Urn);
+        parses_like_sync(run_files::WSPLIT.as_bytes(), TimerKind::WSplit);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzip_compressed_livesplit() {
+        parses_like_sync(run_files::LIVESPLIT_1_6_GZIP, TimerKind::LiveSplit);
+    }
+
+    #[test]
+    fn not_a_splits_file() {
+        assert!(matches!(
+            parse(trickle(&[0xFF; 64])),
+            Err(Error::NoParserParsedIt),
+        ));
+    }
+
+    #[test]
+    fn read_error() {
+        for data in [run_files::LIVESPLIT_1_6, run_files::URN] {
+            let source = Trickle {
+                fail_at_end: true,
+                ..trickle(&data.as_bytes()[..data.len() / 2])
+            };
+            assert!(matches!(parse(source), Err(Error::Io { .. })));
+        }
+    }
+
+    #[test]
+    fn livesplit_is_parsed_while_reading() {
+        // The LiveSplit parser is done once the root element is closed, so it
+        // never gets to the failure at the end of the reader. Every other
+        // splits file needs to be read entirely first.
+        let source = Trickle {
+            fail_at_end: true,
+            ..trickle(run_files::LIVESPLIT_1_6.as_bytes())
+        };
+        assert_eq!(parse(source).unwrap().kind, TimerKind::LiveSplit);
+
+        let source = Trickle {
+            fail_at_end: true,
+            ..trickle(run_files::LLANFAIR_GERED.as_bytes())
+        };
+        assert!(matches!(parse(source), Err(Error::Io { .. })));
+    }
 }
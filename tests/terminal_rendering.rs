@@ -0,0 +1,62 @@
+#![cfg(feature = "terminal-rendering")]
+
+use livesplit_core::{
+    component, layout::Layout, rendering::terminal::Renderer, settings::ImageCache, Run, Segment,
+    Timer,
+};
+
+fn timer(names: &[&str]) -> Timer {
+    let mut run = Run::new();
+    for name in names {
+        run.push_segment(Segment::new(*name));
+    }
+    Timer::new(run).unwrap()
+}
+
+/// Removes the escape codes, so only the visible text remains.
+fn visible_lines(frame: &str) -> Vec<String> {
+    let mut text = String::new();
+    let mut chars = frame.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else if c != '\r' {
+            text.push(c);
+        }
+    }
+    text.lines().map(String::from).collect()
+}
+
+#[test]
+fn renders_the_default_layout() {
+    let mut timer = timer(&["First Split", "Second Split"]);
+    timer.start().unwrap();
+    timer.split().unwrap();
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = Renderer::new();
+    let frame = renderer.render(&state, 40);
+    assert!(frame.starts_with("\x1b[H"));
+
+    let lines = visible_lines(frame);
+    assert!(lines.iter().all(|line| line.chars().count() == 40));
+    assert!(lines.iter().any(|line| line.starts_with("First Split")));
+    assert!(lines.iter().any(|line| line.starts_with("Second Split")));
+}
+
+#[test]
+fn shortens_texts_that_dont_fit() {
+    let timer = timer(&["A Really Long Segment Name"]);
+    let mut layout = Layout::new();
+    layout.push(component::splits::Component::new());
+    layout.push(component::graph::Component::new());
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = Renderer::new();
+    let lines = visible_lines(renderer.render(&state, 12));
+    assert!(lines.iter().all(|line| line.chars().count() == 12));
+    assert!(lines.iter().any(|line| line.starts_with("A Really…")));
+}
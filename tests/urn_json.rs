@@ -0,0 +1,61 @@
+#![cfg(feature = "parser-urn")]
+
+mod run_files;
+
+use livesplit_core::{
+    run::{parser::urn, saver},
+    Run, Segment, Time, TimeSpan,
+};
+
+fn to_urn(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::urn::save_run(run, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn round_trips_urn_splits_files() {
+    let run = urn::parse(run_files::URN).unwrap();
+    let json = to_urn(&run);
+    let parsed = urn::parse(&json).unwrap();
+
+    assert_eq!(to_urn(&parsed), json);
+    assert_eq!(parsed.category_name(), "SotN Any% NSC");
+    assert_eq!(parsed.attempt_count(), 69);
+    assert_eq!(parsed.offset(), run.offset());
+    assert_eq!(parsed.len(), run.len());
+    for (parsed, segment) in parsed.segments().iter().zip(run.segments()) {
+        assert_eq!(parsed.name(), segment.name());
+        assert_eq!(
+            parsed.personal_best_split_time(),
+            segment.personal_best_split_time()
+        );
+        assert_eq!(parsed.best_segment_time(), segment.best_segment_time());
+    }
+    assert!(json.contains(r#""best_time": "14:16.933388""#));
+}
+
+#[test]
+fn formats_times_like_urn() {
+    let mut run = Run::new();
+    run.set_game_name("Game");
+    run.set_category_name("Any%");
+    run.set_offset(TimeSpan::from_seconds(-2.5));
+
+    let mut segment = Segment::new("A");
+    segment.set_personal_best_split_time(
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(3723.0000019))),
+    );
+    run.push_segment(segment);
+    run.push_segment(Segment::new("B"));
+
+    let json = to_urn(&run);
+    assert!(json.contains(r#""title": "Game - Any%""#));
+    assert!(json.contains(r#""start_delay": "2.500000""#));
+    assert!(json.contains(r#""time": "1:02:03.000001""#));
+    assert!(json.contains(r#""best_segment": "0.000000""#));
+
+    let parsed = urn::parse(&json).unwrap();
+    assert_eq!(parsed.offset(), run.offset());
+    assert_eq!(parsed.segment(1).personal_best_split_time(), Time::new());
+}
@@ -0,0 +1,167 @@
+#![cfg(feature = "wgpu-rendering")]
+
+#[path = "../src/util/tests_helper.rs"]
+mod tests_helper;
+
+use livesplit_core::{
+    layout::{Layout, LayoutState},
+    rendering::wgpu::{wgpu, Renderer},
+    settings::ImageCache,
+    Run, Segment, TimeSpan, Timer, TimingMethod,
+};
+use std::{
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread,
+};
+
+const WIDTH: u32 = 256;
+const HEIGHT: u32 = 500;
+const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            return output;
+        }
+        thread::yield_now();
+    }
+}
+
+fn device() -> Option<(wgpu::Device, wgpu::Queue)> {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+    let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))?;
+    block_on(adapter.request_device(
+        &wgpu::DeviceDescriptor {
+            required_limits:
+                wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits()),
+            ..Default::default()
+        },
+        None,
+    ))
+    .ok()
+}
+
+fn layout_state() -> (LayoutState, ImageCache) {
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    (state, image_cache)
+}
+
+fn render(device: &wgpu::Device, queue: &wgpu::Queue, renderer: &mut Renderer) -> Vec<u8> {
+    let (state, image_cache) = layout_state();
+
+    let size = wgpu::Extent3d {
+        width: WIDTH,
+        height: HEIGHT,
+        depth_or_array_layers: 1,
+    };
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    renderer.render(device, queue, &state, &image_cache, &view, [WIDTH, HEIGHT]);
+
+    // The width is chosen such that the rows are already aligned the way
+    // copying a texture into a buffer requires.
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: None,
+        size: (4 * WIDTH * HEIGHT) as _,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * WIDTH),
+                rows_per_image: None,
+            },
+        },
+        size,
+    );
+    queue.submit([encoder.finish()]);
+
+    let slice = buffer.slice(..);
+    slice.map_async(wgpu::MapMode::Read, |result| result.unwrap());
+    device.poll(wgpu::Maintain::Wait);
+    let pixels = slice.get_mapped_range().to_vec();
+    pixels
+}
+
+#[test]
+fn renders_the_layout() {
+    // Not every system has a GPU, or at least a software implementation of
+    // one, that wgpu can make use of.
+    let Some((device, queue)) = device() else {
+        eprintln!("Skipping the wgpu test, as there is no adapter available.");
+        return;
+    };
+
+    let mut renderer = Renderer::with_bundled_fonts(&device, &queue, FORMAT);
+    let pixels = render(&device, &queue, &mut renderer);
+
+    // The default layout has an opaque background, with the components drawn
+    // on top of it.
+    let pixels = pixels.chunks_exact(4).collect::<Vec<_>>();
+    assert!(pixels.iter().all(|pixel| pixel[3] == 0xFF));
+    assert!(pixels.iter().any(|pixel| pixel != &pixels[0]));
+
+    // Rendering the same state again results in the same image.
+    let again = render(&device, &queue, &mut renderer);
+    assert!(again.chunks_exact(4).eq(pixels.iter().copied()));
+
+    #[cfg(feature = "software-rendering")]
+    {
+        // The anti-aliasing works differently, so only the overall image is
+        // expected to look the same as the one of the software renderer.
+        use livesplit_core::rendering::software;
+
+        let mut software = software::Renderer::with_bundled_fonts();
+        let expected = render_with_software(&mut software);
+        let difference = expected
+            .iter()
+            .zip(pixels.iter().flat_map(|pixel| pixel.iter()))
+            .map(|(&a, &b)| a.abs_diff(b) as u64)
+            .sum::<u64>() as f64
+            / expected.len() as f64;
+        assert!(difference < 2.0, "{difference}");
+    }
+}
+
+#[cfg(feature = "software-rendering")]
+fn render_with_software(renderer: &mut livesplit_core::rendering::software::Renderer) -> Vec<u8> {
+    let (state, image_cache) = layout_state();
+    renderer.render(&state, &image_cache, [WIDTH, HEIGHT]);
+    renderer.image_data().to_vec()
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "parser-wsplit")]
+
+mod run_files;
+
+use livesplit_core::{
+    run::{parser::wsplit, saver},
+    Run, Segment, Time, TimeSpan,
+};
+
+fn to_wsplit(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::wsplit::save_run(run, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn round_trips_wsplit_splits_files() {
+    let run = wsplit::parse(run_files::WSPLIT, false).unwrap();
+    let text = to_wsplit(&run);
+    let parsed = wsplit::parse(&text, false).unwrap();
+
+    assert_eq!(to_wsplit(&parsed), text);
+    assert_eq!(parsed.category_name(), "WarioWare, Inc");
+    assert_eq!(parsed.len(), run.len());
+    for (parsed, segment) in parsed.segments().iter().zip(run.segments()) {
+        assert_eq!(parsed.name(), segment.name());
+        assert_eq!(
+            parsed.personal_best_split_time(),
+            segment.personal_best_split_time()
+        );
+        assert_eq!(parsed.best_segment_time(), segment.best_segment_time());
+    }
+    assert!(text.contains("\nJimmy,0,219.68,134.2\n"));
+}
+
+#[test]
+fn saves_old_run_goal_and_icons() {
+    let mut run = Run::new();
+    run.set_category_name("Any%");
+    run.set_offset(TimeSpan::from_seconds(-1.5));
+    run.metadata_mut()
+        .custom_variable_mut("Goal")
+        .permanent()
+        .set_value("sub 2h");
+
+    let mut segment = Segment::new("A, B");
+    *segment.comparison_mut("Old Run") =
+        Time::new().with_real_time(Some(TimeSpan::from_seconds(12.5)));
+    run.push_segment(segment);
+    run.push_segment(Segment::new("C"));
+    run.add_custom_comparison("Old Run").unwrap();
+
+    let mut text = String::new();
+    saver::wsplit::save_run_with_icons(
+        &run,
+        |index, _| (index == 1).then(|| "icons/c.png".to_owned()),
+        &mut text,
+    )
+    .unwrap();
+
+    assert_eq!(
+        text,
+        "Title=Any%\nAttempts=0\nOffset=1500\nIcons=\"\",\"icons/c.png\"\nGoal=sub 2h\nA B,12.5,0,0\nC,0,0,0\n",
+    );
+
+    let parsed = wsplit::parse(&text, false).unwrap();
+    assert_eq!(parsed.offset(), run.offset());
+    assert_eq!(
+        parsed.metadata().custom_variable_value("Goal"),
+        Some("sub 2h")
+    );
+    assert_eq!(
+        parsed.segment(0).comparison("Old Run"),
+        run.segment(0).comparison("Old Run")
+    );
+}